@@ -2,6 +2,8 @@ import 'dart:async';
 import 'package:flutter/material.dart';
 import 'package:convex_flutter/convex_flutter.dart';
 
+import '../widgets/connection_status_indicator.dart';
+
 class ConnectionScreen extends StatefulWidget {
   const ConnectionScreen({super.key});
 
@@ -68,7 +70,7 @@ class _ConnectionScreenState extends State<ConnectionScreen> {
               initialData: ConvexClient.instance.currentConnectionState,
               builder: (context, snapshot) {
                 final state = snapshot.data!;
-                final isConnected = state == WebSocketConnectionState.connected;
+                final isConnected = state is WebSocketConnectionState_Connected;
                 return Container(
                   padding: const EdgeInsets.all(16),
                   decoration: BoxDecoration(
@@ -85,7 +87,7 @@ class _ConnectionScreenState extends State<ConnectionScreen> {
                         child: Column(
                           crossAxisAlignment: CrossAxisAlignment.start,
                           children: [
-                            Text(state.name.toUpperCase(),
+                            Text(connectionStateLabel(state).toUpperCase(),
                               style: TextStyle(fontSize: 20, fontWeight: FontWeight.bold,
                                 color: isConnected ? Colors.green : Colors.orange)),
                             Text(isConnected ? 'WebSocket is open' : 'WebSocket connecting',
@@ -161,11 +163,11 @@ class _ConnectionScreenState extends State<ConnectionScreen> {
                 separatorBuilder: (_, __) => const Divider(),
                 itemBuilder: (context, index) {
                   final event = _stateHistory[index];
-                  final isConnected = event.state == WebSocketConnectionState.connected;
+                  final isConnected = event.state is WebSocketConnectionState_Connected;
                   return ListTile(
                     leading: Icon(isConnected ? Icons.cloud_done : Icons.cloud_sync,
                       color: isConnected ? Colors.green : Colors.orange),
-                    title: Text(event.state.name.toUpperCase()),
+                    title: Text(connectionStateLabel(event.state).toUpperCase()),
                     subtitle: Text(_formatTime(event.timestamp)),
                     trailing: Text(_timeAgo(event.timestamp),
                       style: const TextStyle(fontSize: 11, color: Colors.grey)),
@@ -1,6 +1,8 @@
 import 'package:flutter/material.dart';
 import 'package:convex_flutter/convex_flutter.dart';
 
+import '../widgets/connection_status_indicator.dart';
+
 class HomeScreen extends StatefulWidget {
   const HomeScreen({super.key});
 
@@ -91,8 +93,8 @@ class _HomeScreenState extends State<HomeScreen> {
               builder: (context, snapshot) {
                 final state = snapshot.data;
                 return _StatusRow(icon: Icons.wifi, label: 'Connection',
-                  value: state?.name ?? 'Unknown',
-                  color: state == WebSocketConnectionState.connected
+                  value: state == null ? 'Unknown' : connectionStateLabel(state),
+                  color: state is WebSocketConnectionState_Connected
                       ? Colors.green : Colors.orange);
               }),
             const Divider(),
@@ -15,7 +15,7 @@ class ConnectionStatusIndicator extends StatelessWidget {
       initialData: ConvexClient.instance.currentConnectionState,
       builder: (context, snapshot) {
         print('snapshot: ${snapshot.data}');
-        final state = snapshot.data ?? WebSocketConnectionState.connecting;
+        final state = snapshot.data ?? const WebSocketConnectionState.connecting();
 
         return Padding(
           padding: const EdgeInsets.symmetric(horizontal: 8, vertical: 12),
@@ -38,29 +38,34 @@ class ConnectionStatusIndicator extends StatelessWidget {
   }
 
   IconData _getIcon(WebSocketConnectionState state) {
-    switch (state) {
-      case WebSocketConnectionState.connected:
-        return Icons.cloud_done;
-      case WebSocketConnectionState.connecting:
-        return Icons.cloud_sync;
-    }
+    return switch (state) {
+      WebSocketConnectionState_Connected() => Icons.cloud_done,
+      WebSocketConnectionState_Failed() => Icons.cloud_off,
+      _ => Icons.cloud_sync,
+    };
   }
 
   Color _getColor(WebSocketConnectionState state) {
-    switch (state) {
-      case WebSocketConnectionState.connected:
-        return Colors.green;
-      case WebSocketConnectionState.connecting:
-        return Colors.orange;
-    }
+    return switch (state) {
+      WebSocketConnectionState_Connected() => Colors.green,
+      WebSocketConnectionState_Failed() => Colors.red,
+      _ => Colors.orange,
+    };
   }
 
   String _getLabel(WebSocketConnectionState state) {
-    switch (state) {
-      case WebSocketConnectionState.connected:
-        return 'Connected';
-      case WebSocketConnectionState.connecting:
-        return 'Connecting';
-    }
+    return connectionStateLabel(state);
   }
 }
+
+/// Short label of a connection state.
+String connectionStateLabel(WebSocketConnectionState state) {
+  return switch (state) {
+    WebSocketConnectionState_Connected() => 'Connected',
+    WebSocketConnectionState_Connecting() => 'Connecting',
+    WebSocketConnectionState_Closed() => 'Closed',
+    WebSocketConnectionState_Backoff(:final retryInMs) =>
+      'Retrying in ${retryInMs}ms',
+    WebSocketConnectionState_Failed() => 'Failed',
+  };
+}
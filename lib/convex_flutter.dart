@@ -1,6 +1,61 @@
 library;
 
 export 'src/rust/lib.dart';
+export 'src/rust/audit.dart';
+export 'src/rust/auth_changes.dart';
+export 'src/rust/auth_refresh.dart';
+export 'src/rust/backpressure.dart';
+export 'src/rust/batch_query.dart';
+export 'src/rust/budget.dart';
+export 'src/rust/call_metrics.dart';
+export 'src/rust/cancellation.dart';
+export 'src/rust/chunked.dart';
+export 'src/rust/codecs.dart';
+export 'src/rust/commit_token.dart';
+export 'src/rust/connection.dart';
+export 'src/rust/convex_value.dart';
+export 'src/rust/deferred.dart';
+export 'src/rust/derived.dart';
+export 'src/rust/errors.dart';
+export 'src/rust/events.dart';
+export 'src/rust/failover.dart';
+export 'src/rust/faults.dart';
+export 'src/rust/file_storage.dart';
+export 'src/rust/hints.dart';
+export 'src/rust/http_actions.dart';
+export 'src/rust/interceptors.dart';
+export 'src/rust/jobs.dart';
+export 'src/rust/list_diff.dart';
+export 'src/rust/logging.dart';
+export 'src/rust/logout.dart';
+export 'src/rust/memory.dart';
+export 'src/rust/metrics.dart';
+export 'src/rust/mutation_status.dart';
+export 'src/rust/optimistic.dart';
+export 'src/rust/options.dart';
+export 'src/rust/outbox.dart';
+export 'src/rust/pagination.dart';
+export 'src/rust/panics.dart';
+export 'src/rust/patches.dart';
+export 'src/rust/persisted_results.dart';
+export 'src/rust/placeholder.dart';
+export 'src/rust/presence.dart';
+export 'src/rust/pressure.dart';
+export 'src/rust/preview.dart';
+export 'src/rust/quality.dart';
+export 'src/rust/query_cache.dart';
+export 'src/rust/registry.dart';
+export 'src/rust/request_ids.dart';
+export 'src/rust/resubscribe.dart';
+export 'src/rust/retry.dart';
+export 'src/rust/sampling.dart';
+export 'src/rust/schema_check.dart';
+export 'src/rust/sequence.dart';
+export 'src/rust/sharding.dart';
+export 'src/rust/spans.dart';
+export 'src/rust/subscription.dart';
+export 'src/rust/subscription_stream.dart';
+export 'src/rust/supervisor.dart';
 export 'src/rust/frb_generated.dart' show RustLib;
 export 'src/convex_client.dart'
     show ConvexClient, AuthHandleWrapper, TokenFetcher, AuthStateCallback;
@@ -3,6 +3,7 @@ import 'dart:async';
 import 'package:convex_flutter/src/impl/convex_client_interface.dart';
 import 'package:convex_flutter/src/impl/convex_client_factory.dart';
 import 'package:convex_flutter/src/rust/lib.dart' show WebSocketConnectionState, SubscriptionHandle, AuthHandle;
+import 'package:convex_flutter/src/rust/subscription.dart' show SubscribeOptions;
 import 'package:convex_flutter/src/connection_status.dart';
 import 'package:convex_flutter/src/convex_config.dart';
 import 'package:convex_flutter/src/app_lifecycle_event.dart';
@@ -161,37 +162,52 @@ class ConvexClient {
   ///
   /// [name] - Name of the query function to execute (e.g., "messages:list")
   /// [args] - Map of arguments to pass to the query
+  /// [timeout] - Optional limit for this call; on native platforms the Rust
+  /// client gives up after it with [ClientError.timeout]
   ///
   /// Returns the query result as a JSON string.
   /// Throws [TimeoutException] if the operation exceeds [config.operationTimeout].
-  Future<String> query(String name, Map<String, String> args) =>
-      _impl.query(name, args);
+  Future<String> query(String name, Map<String, String> args,
+          {Duration? timeout}) =>
+      _impl.query(name, args, timeout: timeout);
 
   /// Executes a Convex mutation operation with timeout.
   ///
   /// [name] - Name of the mutation function to execute
   /// [args] - Map of arguments to pass to the mutation
+  /// [timeout] - Optional limit for this call, as for [query]
   ///
   /// Returns the mutation result as a JSON string.
   /// Throws [TimeoutException] if the operation exceeds [config.operationTimeout].
   Future<String> mutation({
     required String name,
     required Map<String, dynamic> args,
+    Duration? timeout,
   }) =>
-      _impl.mutation(name: name, args: args.map((k, v) => MapEntry(k, v.toString())));
+      _impl.mutation(
+        name: name,
+        args: args.map((k, v) => MapEntry(k, v.toString())),
+        timeout: timeout,
+      );
 
   /// Executes a Convex action operation with timeout.
   ///
   /// [name] - Name of the action function to execute
   /// [args] - Map of arguments to pass to the action
+  /// [timeout] - Optional limit for this call, as for [query]
   ///
   /// Returns the action result as a JSON string.
   /// Throws [TimeoutException] if the operation exceeds [config.operationTimeout].
   Future<String> action({
     required String name,
     required Map<String, dynamic> args,
+    Duration? timeout,
   }) =>
-      _impl.action(name: name, args: args.map((k, v) => MapEntry(k, v.toString())));
+      _impl.action(
+        name: name,
+        args: args.map((k, v) => MapEntry(k, v.toString())),
+        timeout: timeout,
+      );
 
   /// Creates a real-time subscription to a Convex query.
   ///
@@ -199,6 +215,8 @@ class ConvexClient {
   /// [args] - Map of arguments for the subscription
   /// [onUpdate] - Callback function called when new data arrives
   /// [onError] - Callback function called when an error occurs
+  /// [onDone] - Callback function called once no more updates will arrive
+  /// [options] - Priority, projection, backpressure and more; native only
   ///
   /// Returns a handle that can be used to cancel the subscription.
   Future<SubscriptionHandle> subscribe({
@@ -206,12 +224,16 @@ class ConvexClient {
     required Map<String, String> args,
     required void Function(String) onUpdate,
     required void Function(String, String?) onError,
+    void Function()? onDone,
+    SubscribeOptions? options,
   }) =>
       _impl.subscribe(
         name: name,
         args: args,
         onUpdate: onUpdate,
         onError: onError,
+        onDone: onDone,
+        options: options,
       );
 
   // ============================================================================
@@ -302,14 +324,15 @@ class ConvexClient {
 
   /// Stream of WebSocket connection state changes.
   ///
-  /// Emits state whenever the underlying WebSocket connection changes
-  /// between Connected and Connecting states. This provides real-time
-  /// connection monitoring without manual polling.
+  /// Emits state whenever the underlying WebSocket connection changes,
+  /// e.g. between connected, connecting and backing off after a failed
+  /// attempt. This provides real-time connection monitoring without manual
+  /// polling.
   ///
   /// Example usage:
   /// ```dart
   /// ConvexClient.instance.connectionState.listen((state) {
-  ///   if (state == WebSocketConnectionState.connected) {
+  ///   if (state is WebSocketConnectionState_Connected) {
   ///     print('Connected to Convex!');
   ///   }
   /// });
@@ -347,7 +370,7 @@ class ConvexClient {
   /// Recommended alternative - use the real-time connection state stream:
   /// ```dart
   /// ConvexClient.instance.connectionState.listen((state) {
-  ///   if (state == WebSocketConnectionState.connected) {
+  ///   if (state is WebSocketConnectionState_Connected) {
   ///     print('Connected!');
   ///   }
   /// });
@@ -1,6 +1,7 @@
 import 'dart:async';
 
 import 'package:convex_flutter/src/rust/lib.dart' show WebSocketConnectionState, SubscriptionHandle, AuthHandle;
+import 'package:convex_flutter/src/rust/subscription.dart' show SubscribeOptions;
 import 'package:convex_flutter/src/connection_status.dart';
 import 'package:convex_flutter/src/convex_config.dart';
 import 'package:convex_flutter/src/app_lifecycle_event.dart';
@@ -25,18 +26,22 @@ abstract class IConvexClient {
   ///
   /// [name] - Name of the query function to execute (e.g., "messages:list")
   /// [args] - Map of arguments to pass to the query
+  /// [timeout] - Optional limit for this call; on native platforms the Rust
+  /// client gives up after it with [ClientError.timeout]
   ///
   /// Returns the query result as a JSON string.
   ///
   /// Throws:
   /// - [TimeoutException] if operation exceeds configured timeout
   /// - [ClientError] for Convex-specific errors
-  Future<String> query(String name, Map<String, String> args);
+  Future<String> query(String name, Map<String, String> args,
+      {Duration? timeout});
 
   /// Executes a Convex mutation operation.
   ///
   /// [name] - Name of the mutation function to execute
   /// [args] - Map of arguments to pass to the mutation
+  /// [timeout] - Optional limit for this call, as for [query]
   ///
   /// Returns the mutation result as a JSON string.
   ///
@@ -46,12 +51,14 @@ abstract class IConvexClient {
   Future<String> mutation({
     required String name,
     required Map<String, String> args,
+    Duration? timeout,
   });
 
   /// Executes a Convex action operation.
   ///
   /// [name] - Name of the action function to execute
   /// [args] - Map of arguments to pass to the action
+  /// [timeout] - Optional limit for this call, as for [query]
   ///
   /// Returns the action result as a JSON string.
   ///
@@ -61,6 +68,7 @@ abstract class IConvexClient {
   Future<String> action({
     required String name,
     required Map<String, String> args,
+    Duration? timeout,
   });
 
   /// Creates a real-time subscription to a Convex query.
@@ -69,6 +77,8 @@ abstract class IConvexClient {
   /// [args] - Map of arguments for the subscription
   /// [onUpdate] - Callback function called when new data arrives
   /// [onError] - Callback function called when an error occurs
+  /// [onDone] - Callback function called once no more updates will arrive
+  /// [options] - Priority, projection, backpressure and more; native only
   ///
   /// Returns a handle that can be used to cancel the subscription.
   Future<SubscriptionHandle> subscribe({
@@ -76,6 +86,8 @@ abstract class IConvexClient {
     required Map<String, String> args,
     required void Function(String) onUpdate,
     required void Function(String, String?) onError,
+    void Function()? onDone,
+    SubscribeOptions? options,
   });
 
   // ============================================================================
@@ -118,7 +130,9 @@ abstract class IConvexClient {
   /// Stream of WebSocket connection state changes.
   ///
   /// Emits [WebSocketConnectionState.connected] when connection is established,
-  /// [WebSocketConnectionState.connecting] when connecting or reconnecting.
+  /// [WebSocketConnectionState.connecting] when connecting or reconnecting,
+  /// and on native platforms also [WebSocketConnectionState.closed],
+  /// [WebSocketConnectionState.backoff] and [WebSocketConnectionState.failed].
   ///
   /// This is the recommended way to monitor connection status.
   Stream<WebSocketConnectionState> get connectionState;
@@ -4,6 +4,7 @@ import 'package:flutter/foundation.dart';
 import 'package:convex_flutter/src/impl/convex_client_interface.dart';
 import 'package:convex_flutter/src/rust/lib.dart';
 import 'package:convex_flutter/src/rust/frb_generated.dart';
+import 'package:convex_flutter/src/rust/subscription.dart';
 import 'package:convex_flutter/src/utils.dart';
 import 'package:convex_flutter/src/connection_status.dart';
 import 'package:convex_flutter/src/convex_config.dart';
@@ -39,7 +40,7 @@ class NativeConvexClient implements IConvexClient {
 
   /// Current connection state (cached for sync access)
   WebSocketConnectionState _currentConnectionState =
-      WebSocketConnectionState.connecting;
+      const WebSocketConnectionState.connecting();
 
   /// Current auth handle (if using refresh-based auth)
   AuthHandle? _currentAuthHandle;
@@ -88,12 +89,12 @@ class NativeConvexClient implements IConvexClient {
   /// This must be called before any queries/mutations to capture all state changes.
   Future<void> _setupConnectionStateListener() async {
     debugPrint('=== [NativeConvexClient] Setting up WebSocket state listener ===');
-    debugPrint('=== [NativeConvexClient] Current state: ${_currentConnectionState.name} ===');
+    debugPrint('=== [NativeConvexClient] Current state: $_currentConnectionState ===');
 
     try {
       await _rustClient.onWebsocketStateChange(
         onStateChange: (state) async {
-          debugPrint('=== [NativeConvexClient] State changed: ${state.name} ===');
+          debugPrint('=== [NativeConvexClient] State changed: $state ===');
           _currentConnectionState = state;
           _connectionStateController.add(state);
           debugPrint('=== [NativeConvexClient] Stream emission complete ===');
@@ -110,11 +111,16 @@ class NativeConvexClient implements IConvexClient {
   // IConvexClient Implementation - Core Operations
   // ============================================================================
 
+  /// Converts a per-call [timeout] into the `timeoutMs` of the Rust client.
+  static BigInt? _timeoutMs(Duration? timeout) =>
+      timeout == null ? null : BigInt.from(timeout.inMilliseconds);
+
   @override
-  Future<String> query(String name, Map<String, String> args) async {
+  Future<String> query(String name, Map<String, String> args,
+      {Duration? timeout}) async {
     final formattedArgs = buildArgs(args);
     return await _rustClient
-        .query(name: name, args: formattedArgs)
+        .query(name: name, args: formattedArgs, timeoutMs: _timeoutMs(timeout))
         .timeout(config.operationTimeout);
   }
 
@@ -122,10 +128,12 @@ class NativeConvexClient implements IConvexClient {
   Future<String> mutation({
     required String name,
     required Map<String, String> args,
+    Duration? timeout,
   }) async {
     final formattedArgs = buildArgs(args);
     return await _rustClient
-        .mutation(name: name, args: formattedArgs)
+        .mutation(
+            name: name, args: formattedArgs, timeoutMs: _timeoutMs(timeout))
         .timeout(config.operationTimeout);
   }
 
@@ -133,10 +141,11 @@ class NativeConvexClient implements IConvexClient {
   Future<String> action({
     required String name,
     required Map<String, String> args,
+    Duration? timeout,
   }) async {
     final formattedArgs = buildArgs(args);
     return await _rustClient
-        .action(name: name, args: formattedArgs)
+        .action(name: name, args: formattedArgs, timeoutMs: _timeoutMs(timeout))
         .timeout(config.operationTimeout);
   }
 
@@ -146,6 +155,8 @@ class NativeConvexClient implements IConvexClient {
     required Map<String, String> args,
     required void Function(String) onUpdate,
     required void Function(String, String?) onError,
+    void Function()? onDone,
+    SubscribeOptions? options,
   }) async {
     final formattedArgs = buildArgs(args);
     return await _rustClient.subscribe(
@@ -153,6 +164,8 @@ class NativeConvexClient implements IConvexClient {
       args: formattedArgs,
       onUpdate: (value) => onUpdate(value),
       onError: (message, value) => onError(message, value),
+      onDone: () => onDone?.call(),
+      options: options,
     );
   }
 
@@ -217,7 +230,7 @@ class NativeConvexClient implements IConvexClient {
 
   @override
   bool get isConnected =>
-      _currentConnectionState == WebSocketConnectionState.connected;
+      _currentConnectionState is WebSocketConnectionState_Connected;
 
   @override
   @Deprecated('Use connectionState stream for real-time monitoring')
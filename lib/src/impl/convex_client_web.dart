@@ -6,7 +6,8 @@ import 'dart:math' as math;
 import 'package:flutter/foundation.dart';
 import 'package:web/web.dart' as web;
 import 'package:convex_flutter/src/impl/convex_client_interface.dart';
-import 'package:convex_flutter/src/rust/lib.dart' show WebSocketConnectionState, SubscriptionHandle, AuthHandle;
+import 'package:convex_flutter/src/rust/lib.dart' show WebSocketConnectionState, WebSocketConnectionState_Connected, SubscriptionHandle, AuthHandle;
+import 'package:convex_flutter/src/rust/subscription.dart' show SubscribeOptions;
 import 'package:convex_flutter/src/connection_status.dart';
 import 'package:convex_flutter/src/convex_config.dart';
 import 'package:convex_flutter/src/app_lifecycle_event.dart';
@@ -42,7 +43,7 @@ class WebConvexClient implements IConvexClient {
 
   /// Current connection state (cached for sync access)
   WebSocketConnectionState _currentConnectionState =
-      WebSocketConnectionState.connecting;
+      const WebSocketConnectionState.connecting();
 
   /// Current auth token
   String? _currentAuthToken;
@@ -132,7 +133,7 @@ class WebConvexClient implements IConvexClient {
       debugPrint('=== [WebConvexClient] WebSocket URL: $fullUrl ===');
 
       // Update state to connecting
-      _updateConnectionState(WebSocketConnectionState.connecting);
+      _updateConnectionState(const WebSocketConnectionState.connecting());
 
       // Create WebSocket connection
       _ws = web.WebSocket(fullUrl);
@@ -157,7 +158,7 @@ class WebConvexClient implements IConvexClient {
       debugPrint('=== [WebConvexClient] WebSocket opened ===');
       _reconnectAttempts = 0; // Reset reconnection counter
       _querySetVersion = 0; // Reset query set version for new connection
-      _updateConnectionState(WebSocketConnectionState.connected);
+      _updateConnectionState(const WebSocketConnectionState.connected());
 
       // Send Connect handshake (required by Convex protocol)
       _sendConnectMessage();
@@ -175,7 +176,7 @@ class WebConvexClient implements IConvexClient {
       final wasClean = event.wasClean;
       debugPrint('=== [WebConvexClient] WebSocket closed ===');
       debugPrint('=== [WebConvexClient] Close code: $code, reason: "$reason", wasClean: $wasClean ===');
-      _updateConnectionState(WebSocketConnectionState.connecting);
+      _updateConnectionState(const WebSocketConnectionState.connecting());
 
       // Attempt reconnection if not disposed
       if (!_isDisposed) {
@@ -187,7 +188,7 @@ class WebConvexClient implements IConvexClient {
     ws.onerror = (web.Event event) {
       debugPrint('ERROR: [WebConvexClient] WebSocket error occurred');
       debugPrint('ERROR: [WebConvexClient] Event type: ${event.type}');
-      _updateConnectionState(WebSocketConnectionState.connecting);
+      _updateConnectionState(const WebSocketConnectionState.connecting());
     }.toJS;
 
     // Message received
@@ -389,7 +390,7 @@ class WebConvexClient implements IConvexClient {
   /// Updates connection state and emits to stream.
   void _updateConnectionState(WebSocketConnectionState newState) {
     if (_currentConnectionState != newState) {
-      debugPrint('=== [WebConvexClient] State transition: ${_currentConnectionState.name} → ${newState.name} ===');
+      debugPrint('=== [WebConvexClient] State transition: $_currentConnectionState → $newState ===');
       _currentConnectionState = newState;
       _connectionStateController.add(newState);
     }
@@ -456,7 +457,8 @@ class WebConvexClient implements IConvexClient {
   // ============================================================================
 
   @override
-  Future<String> query(String name, Map<String, String> args) async {
+  Future<String> query(String name, Map<String, String> args,
+      {Duration? timeout}) async {
     // Queries in Convex protocol use ModifyQuerySet (like subscriptions)
     // We subscribe, wait for first result, then unsubscribe
     final queryId = _queryIdCounter++;
@@ -502,7 +504,7 @@ class WebConvexClient implements IConvexClient {
       });
 
       return await completer.future.timeout(
-        config.operationTimeout,
+        timeout ?? config.operationTimeout,
         onTimeout: () {
           _subscriptions.remove(queryIdStr);
           throw TimeoutException('Query timeout: $name');
@@ -518,6 +520,7 @@ class WebConvexClient implements IConvexClient {
   Future<String> mutation({
     required String name,
     required Map<String, String> args,
+    Duration? timeout,
   }) async {
     final requestId = _generateMessageId();
     final completer = Completer<String>();
@@ -533,7 +536,7 @@ class WebConvexClient implements IConvexClient {
       });
 
       return await completer.future.timeout(
-        config.operationTimeout,
+        timeout ?? config.operationTimeout,
         onTimeout: () {
           _pendingRequests.remove(requestId);
           throw TimeoutException('Mutation timeout: $name');
@@ -549,6 +552,7 @@ class WebConvexClient implements IConvexClient {
   Future<String> action({
     required String name,
     required Map<String, String> args,
+    Duration? timeout,
   }) async {
     final requestId = _generateMessageId();
     final completer = Completer<String>();
@@ -564,7 +568,7 @@ class WebConvexClient implements IConvexClient {
       });
 
       return await completer.future.timeout(
-        config.operationTimeout,
+        timeout ?? config.operationTimeout,
         onTimeout: () {
           _pendingRequests.remove(requestId);
           throw TimeoutException('Action timeout: $name');
@@ -582,6 +586,8 @@ class WebConvexClient implements IConvexClient {
     required Map<String, String> args,
     required void Function(String) onUpdate,
     required void Function(String, String?) onError,
+    void Function()? onDone,
+    SubscribeOptions? options,
   }) async {
     // Use incrementing query ID (Convex protocol requirement)
     final queryId = _queryIdCounter++;
@@ -723,7 +729,7 @@ class WebConvexClient implements IConvexClient {
 
   @override
   bool get isConnected =>
-      _currentConnectionState == WebSocketConnectionState.connected;
+      _currentConnectionState is WebSocketConnectionState_Connected;
 
   @override
   @Deprecated('Use connectionState stream for real-time monitoring')
@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `append`, `begin`, `clear`, `export`, `file_path`, `finish`, `hash_args`, `new`, `of`, `rotate`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AuditEntry`, `AuditLog`, `AuditOperation`, `AuditStatus`, `PendingAudit`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// Where and how much audit data is kept.
+class AuditLogOptions  {
+                /// Directory holding the log files; created if missing.
+final String directory;
+/// Size at which the current file is rotated.
+final BigInt maxFileBytes;
+/// Number of files kept, including the current one.
+final int maxFiles;
+
+                const AuditLogOptions({required this.directory ,required this.maxFileBytes ,required this.maxFiles ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => directory.hashCode^maxFileBytes.hashCode^maxFiles.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AuditLogOptions &&
+                runtimeType == other.runtimeType
+                && directory == other.directory&& maxFileBytes == other.maxFileBytes&& maxFiles == other.maxFiles;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,61 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `emit`, `is_expired`, `next_reconnect`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AuthChanges`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            /// A change of the auth state.
+class AuthChange  {
+                /// Whether the client is authenticated after the change.
+final bool authenticated;
+final AuthChangeReason reason;
+
+                const AuthChange({required this.authenticated ,required this.reason ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => authenticated.hashCode^reason.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AuthChange &&
+                runtimeType == other.runtimeType
+                && authenticated == other.authenticated&& reason == other.reason;
+        
+            }
+
+/// Why the auth state changed.
+enum AuthChangeReason {
+                    /// A token was applied by `set_auth` or an auth refresh session.
+signedIn,
+/// The WebSocket reconnected and the session's token was applied again.
+connectedReauth,
+/// The session's token expired, e.g. while the WebSocket was down; a
+/// new one is being fetched.
+tokenExpired,
+/// The token fetcher returned no token, which cleared auth.
+tokenRefreshFailed,
+/// Auth was cleared by `set_auth`, `logout` or by disposing the auth
+/// handle.
+signedOut,
+                    ;
+                    
+                }
+            
\ No newline at end of file
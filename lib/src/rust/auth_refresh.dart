@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `delay`, `random_fraction`, `refresh_delay`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            
+
+            /// When an auth refresh session fetches a new token.
+class AuthRefreshConfig  {
+                /// Seconds before the token's expiry at which it is refreshed.
+final BigInt bufferSecs;
+/// Shortest wait between two refreshes, e.g. when a new token is due
+/// right away, so a fetcher returning expired tokens is not called in
+/// a tight loop.
+final BigInt minIntervalSecs;
+/// Wait before refreshing tokens whose expiry cannot be read.
+final BigInt defaultIntervalSecs;
+/// Largest share of the interval, in percent, by which a refresh is
+/// moved earlier at random. 0 refreshes exactly on schedule.
+final int jitterPct;
+
+                const AuthRefreshConfig({required this.bufferSecs ,required this.minIntervalSecs ,required this.defaultIntervalSecs ,required this.jitterPct ,});
+
+                static Future<AuthRefreshConfig>  default_()=>RustLib.instance.api.crateAuthRefreshAuthRefreshConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => bufferSecs.hashCode^minIntervalSecs.hashCode^defaultIntervalSecs.hashCode^jitterPct.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AuthRefreshConfig &&
+                runtimeType == other.runtimeType
+                && bufferSecs == other.bufferSecs&& minIntervalSecs == other.minIntervalSecs&& defaultIntervalSecs == other.defaultIntervalSecs&& jitterPct == other.jitterPct;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,37 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'backpressure.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `backpressure_subscriber`, `deliver`, `keep_latest_updates`, `validate_backpressure`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BackpressureSubscriber`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `on_done`, `on_error`, `on_update`
+
+
+            
+
+            @freezed
+                sealed class Backpressure with _$Backpressure  {
+                    const Backpressure._();
+
+                     /// Only the latest update that arrived while the previous callback ran.
+const factory Backpressure.latestOnly() = Backpressure_LatestOnly;
+ /// The latest update, once none arrived for `ms` milliseconds.
+const factory Backpressure.debounce({   required int ms , }) = Backpressure_Debounce;
+ /// The latest update, at most once per `ms` milliseconds.
+const factory Backpressure.throttle({   required int ms , }) = Backpressure_Throttle;
+ /// Every update, in order, keeping at most `size` waiting; the oldest
+/// waiting updates are dropped first.
+const factory Backpressure.buffer({   required int size , }) = Backpressure_Buffer;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,41 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `internal_query_batch`, `new`, `query`, `resolve`, `settle`, `snapshot`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Slot`, `Snapshot`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            
+
+            /// One query of a batch.
+class BatchQuery  {
+                final String name;
+final Map<String, String> args;
+
+                const BatchQuery({required this.name ,required this.args ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^args.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BatchQuery &&
+                runtimeType == other.runtimeType
+                && name == other.name&& args == other.args;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,96 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `admit`, `begin_usage`, `finish`, `measures_bytes`, `new`, `new`, `record_bytes`, `roll`, `try_call`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BudgetGuard`, `Exceeded`, `FunctionUsage`, `PendingUsage`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// What happens to a call that would exceed a budget.
+enum BudgetEnforcement {
+                    /// The call proceeds and a warning is logged.
+warn,
+/// The call waits until the calls-per-minute budget allows it. Over the
+/// daily bandwidth budget it is rejected, as the wait could take hours.
+queue,
+/// The call fails with an error.
+reject,
+                    ;
+                    static Future<BudgetEnforcement>  default_()=>RustLib.instance.api.crateBudgetBudgetEnforcementDefault();
+
+
+                }
+
+/// Emitted whenever a call exceeds a budget.
+class BudgetExceededEvent  {
+                final String function;
+final BudgetLimit limit;
+final BudgetEnforcement enforcement;
+
+                const BudgetExceededEvent({required this.function ,required this.limit ,required this.enforcement ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => function.hashCode^limit.hashCode^enforcement.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BudgetExceededEvent &&
+                runtimeType == other.runtimeType
+                && function == other.function&& limit == other.limit&& enforcement == other.enforcement;
+        
+            }
+
+/// Which budget a call exceeded.
+enum BudgetLimit {
+                    callsPerMinute,
+bytesPerDay,
+                    ;
+                    
+                }
+
+/// Per-function limits on backend usage.
+class UsageBudget  {
+                /// Calls of one function within any 60 seconds. Unlimited when `None`.
+final int? maxCallsPerMinute;
+/// Bytes of JSON arguments and results of one function per day.
+/// Unlimited when `None`.
+final BigInt? maxBytesPerDay;
+final BudgetEnforcement enforcement;
+
+                const UsageBudget({this.maxCallsPerMinute ,this.maxBytesPerDay ,required this.enforcement ,});
+
+                static Future<UsageBudget>  default_()=>RustLib.instance.api.crateBudgetUsageBudgetDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxCallsPerMinute.hashCode^maxBytesPerDay.hashCode^enforcement.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UsageBudget &&
+                runtimeType == other.runtimeType
+                && maxCallsPerMinute == other.maxCallsPerMinute&& maxBytesPerDay == other.maxBytesPerDay&& enforcement == other.enforcement;
+        
+            }
+            
\ No newline at end of file
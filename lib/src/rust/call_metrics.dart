@@ -0,0 +1,119 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `begin_metrics`, `finish`, `quantile_ms`, `record_retry`, `record`, `report`, `report`, `track_connection`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CallMetrics`, `FunctionStats`, `PendingCall`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`
+
+
+            
+
+            /// Metrics of the calls of one function.
+class FunctionMetrics  {
+                final String name;
+final BigInt calls;
+/// Calls that failed in transport or returned an error.
+final BigInt errors;
+/// Mutations sent again after a failed attempt.
+final BigInt retries;
+final LatencyHistogram latency;
+/// Bytes of the JSON arguments of all calls.
+final BigInt argsBytes;
+/// Bytes of the JSON results of all calls.
+final BigInt resultBytes;
+final BigInt maxResultBytes;
+
+                const FunctionMetrics({required this.name ,required this.calls ,required this.errors ,required this.retries ,required this.latency ,required this.argsBytes ,required this.resultBytes ,required this.maxResultBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^calls.hashCode^errors.hashCode^retries.hashCode^latency.hashCode^argsBytes.hashCode^resultBytes.hashCode^maxResultBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FunctionMetrics &&
+                runtimeType == other.runtimeType
+                && name == other.name&& calls == other.calls&& errors == other.errors&& retries == other.retries&& latency == other.latency&& argsBytes == other.argsBytes&& resultBytes == other.resultBytes&& maxResultBytes == other.maxResultBytes;
+        
+            }
+
+/// Distribution of call latencies.
+class LatencyHistogram  {
+                /// Upper bounds in milliseconds of all buckets but the last.
+final Uint64List boundsMs;
+/// Calls per bucket; one more entry than `bounds_ms`, the last one
+/// counting calls slower than all bounds.
+final Uint64List counts;
+final double sumMs;
+final double maxMs;
+/// Median estimated from the buckets, as the bound of its bucket.
+final double p50Ms;
+/// 95th percentile estimated from the buckets, as the bound of its
+/// bucket.
+final double p95Ms;
+
+                const LatencyHistogram({required this.boundsMs ,required this.counts ,required this.sumMs ,required this.maxMs ,required this.p50Ms ,required this.p95Ms ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => boundsMs.hashCode^counts.hashCode^sumMs.hashCode^maxMs.hashCode^p50Ms.hashCode^p95Ms.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LatencyHistogram &&
+                runtimeType == other.runtimeType
+                && boundsMs == other.boundsMs&& counts == other.counts&& sumMs == other.sumMs&& maxMs == other.maxMs&& p50Ms == other.p50Ms&& p95Ms == other.p95Ms;
+        
+            }
+
+/// Point-in-time report of the call metrics.
+class MetricsReport  {
+                /// Unix time in milliseconds since which the metrics were recorded.
+final PlatformInt64 sinceMs;
+/// Functions called so far, by name.
+final List<FunctionMetrics> functions;
+/// Mutation retries of all functions.
+final BigInt retries;
+/// Times the WebSocket lost its connection.
+final BigInt reconnects;
+final int activeSubscriptions;
+
+                const MetricsReport({required this.sinceMs ,required this.functions ,required this.retries ,required this.reconnects ,required this.activeSubscriptions ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => sinceMs.hashCode^functions.hashCode^retries.hashCode^reconnects.hashCode^activeSubscriptions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MetricsReport &&
+                runtimeType == other.runtimeType
+                && sinceMs == other.sinceMs&& functions == other.functions&& retries == other.retries&& reconnects == other.reconnects&& activeSubscriptions == other.activeSubscriptions;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `run`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AbortOnDrop`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `drop`, `poll`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CancellationToken>>
+                abstract class CancellationToken implements RustOpaqueInterface {
+                    /// Cancels the calls made with this token.
+ void  cancel();
+
+
+static Future<CancellationToken>  default_()=>RustLib.instance.api.crateCancellationCancellationTokenDefault();
+
+
+/// Returns whether [`CancellationToken::cancel`] was called.
+ bool  isCancelled();
+
+
+/// Creates a token that is not cancelled.
+factory CancellationToken()=>RustLib.instance.api.crateCancellationCancellationTokenNew();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `spawn`, `split`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ChunkedSubscriber`, `Delivery`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `on_error`, `on_update`
+
+
+            
+
+            /// When and how results are split into chunks.
+class ChunkedResultOptions  {
+                /// Size in bytes above which a result is split.
+final BigInt thresholdBytes;
+/// Size in bytes of each chunk of a split result.
+final BigInt chunkBytes;
+
+                const ChunkedResultOptions({required this.thresholdBytes ,required this.chunkBytes ,});
+
+                static Future<ChunkedResultOptions>  default_()=>RustLib.instance.api.crateChunkedChunkedResultOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => thresholdBytes.hashCode^chunkBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ChunkedResultOptions &&
+                runtimeType == other.runtimeType
+                && thresholdBytes == other.thresholdBytes&& chunkBytes == other.chunkBytes;
+        
+            }
+
+/// A piece of a result serialized as JSON.
+class ResultChunk  {
+                /// Number of the result the chunk belongs to, counting from 0.
+final BigInt result;
+/// Position of the chunk within its result, counting from 0.
+final int index;
+/// Whether the chunk completes its result.
+final bool last;
+/// UTF-8 bytes of the JSON. Multi-byte characters may span two chunks.
+final Uint8List data;
+
+                const ResultChunk({required this.result ,required this.index ,required this.last ,required this.data ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => result.hashCode^index.hashCode^last.hashCode^data.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ResultChunk &&
+                runtimeType == other.runtimeType
+                && result == other.result&& index == other.index&& last == other.last&& data == other.data;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `decode_fields`, `decode_result`, `decodes`, `decoding_subscriber`, `encode_markers`, `encode`, `format_result`, `marker_type`, `wrap_fields`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DecodingSubscriber`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `on_done`, `on_error`, `on_update`
+
+
+            
+
+            /// Convex type a custom type is sent as.
+enum CodecWire {
+                    /// A number, e.g. milliseconds since the epoch for `DateTime`. Numeric
+/// strings are accepted as well.
+float64,
+/// A string, e.g. the decimal text of a `Decimal`. Numbers are accepted
+/// and converted to their text.
+string,
+                    ;
+                    
+                }
+
+/// How a custom Dart type crosses the FFI.
+class TypeCodec  {
+                /// Name used in markers, e.g. `DateTime`.
+final String typeName;
+final CodecWire wire;
+/// Object fields of results holding this type, e.g. `createdAt`.
+final List<String> fields;
+
+                const TypeCodec({required this.typeName ,required this.wire ,required this.fields ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => typeName.hashCode^wire.hashCode^fields.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TypeCodec &&
+                runtimeType == other.runtimeType
+                && typeName == other.typeName&& wire == other.wire&& fields == other.fields;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `parse_timestamp`, `snapshot_timestamp`, `wait_for_timestamp`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            
+
+            /// The result of [`MobileConvexClient::mutation_with_commit_token`].
+class CommittedMutation  {
+                /// The mutation's return value, serialized as JSON.
+final String value;
+/// A backend timestamp at or after the mutation's commit, to pass to
+/// [`MobileConvexClient::query_at_least`].
+final String commitToken;
+
+                const CommittedMutation({required this.value ,required this.commitToken ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => value.hashCode^commitToken.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CommittedMutation &&
+                runtimeType == other.runtimeType
+                && value == other.value&& commitToken == other.commitToken;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,126 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `backoff`, `build_with_retries`, `build`, `caller_waiting`, `diagnostics`, `disconnect_for`, `mark_disconnected`, `new`, `observe_build`, `report`, `set_state`, `start_failover_once`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ConnectionManager`, `SocketStates`, `WaitingCaller`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `drop`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            /// Retry policy for building the Convex client.
+class ConnectRetryOptions  {
+                /// Delay before the first retry; doubled after every failed attempt.
+final BigInt initialBackoffMs;
+/// Upper bound for the delay between attempts.
+final BigInt maxBackoffMs;
+/// Attempts per call, including the first one.
+final int maxAttempts;
+
+                const ConnectRetryOptions({required this.initialBackoffMs ,required this.maxBackoffMs ,required this.maxAttempts ,});
+
+                static Future<ConnectRetryOptions>  default_()=>RustLib.instance.api.crateConnectionConnectRetryOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => initialBackoffMs.hashCode^maxBackoffMs.hashCode^maxAttempts.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConnectRetryOptions &&
+                runtimeType == other.runtimeType
+                && initialBackoffMs == other.initialBackoffMs&& maxBackoffMs == other.maxBackoffMs&& maxAttempts == other.maxAttempts;
+        
+            }
+
+/// Why the WebSocket closed, as reported by
+/// [`WebSocketConnectionState::Closed`].
+enum ConnectionCloseReason {
+                    /// The open WebSocket dropped, e.g. because the network went away or the
+/// server closed it; the Convex client is reconnecting.
+connectionLost,
+/// Closed by [`MobileConvexClient::disconnect`].
+disconnected,
+/// Closed by [`MobileConvexClient::pause`].
+paused,
+/// Closed by [`MobileConvexClient::close`].
+clientClosed,
+                    ;
+                    
+                }
+
+/// State of the lazily built Convex client, as returned by
+/// [`MobileConvexClient::initialization_diagnostics`].
+class InitializationDiagnostics  {
+                /// Whether a client is built and not disconnected.
+final bool initialized;
+/// Unix time in milliseconds the current client was built at.
+final PlatformInt64? initializedAtMs;
+/// How long the last successful build took, retries included.
+final BigInt? lastDurationMs;
+/// Calls currently waiting for the client.
+final int waitingCallers;
+/// Why the last attempt to build the client failed, if it did.
+final String? lastError;
+
+                const InitializationDiagnostics({required this.initialized ,this.initializedAtMs ,this.lastDurationMs ,required this.waitingCallers ,this.lastError ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => initialized.hashCode^initializedAtMs.hashCode^lastDurationMs.hashCode^waitingCallers.hashCode^lastError.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is InitializationDiagnostics &&
+                runtimeType == other.runtimeType
+                && initialized == other.initialized&& initializedAtMs == other.initializedAtMs&& lastDurationMs == other.lastDurationMs&& waitingCallers == other.waitingCallers&& lastError == other.lastError;
+        
+            }
+
+/// Emitted once a build of the client takes longer than
+/// [`crate::options::ClientOptions::slow_initialization_ms`].
+class SlowInitializationEvent  {
+                /// Time spent building so far.
+final BigInt elapsedMs;
+/// Calls waiting for the build to finish.
+final int waitingCallers;
+
+                const SlowInitializationEvent({required this.elapsedMs ,required this.waitingCallers ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => elapsedMs.hashCode^waitingCallers.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SlowInitializationEvent &&
+                runtimeType == other.runtimeType
+                && elapsedMs == other.elapsedMs&& waitingCallers == other.waitingCallers;
+        
+            }
+            
\ No newline at end of file
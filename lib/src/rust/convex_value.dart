@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'convex_value.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `check_nesting`, `convex_args`, `decrypt_function_result`, `field_path`, `to_object`, `to_value`, `validate_field_name`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`, `from`, `from`
+
+
+            
+
+            @freezed
+                sealed class ConvexValue with _$ConvexValue  {
+                    const ConvexValue._();
+
+                     const factory ConvexValue.null_() = ConvexValue_Null;
+ const factory ConvexValue.bool(  bool field0,) = ConvexValue_Bool;
+ const factory ConvexValue.int64(  PlatformInt64 field0,) = ConvexValue_Int64;
+ const factory ConvexValue.float64(  double field0,) = ConvexValue_Float64;
+ const factory ConvexValue.string(  String field0,) = ConvexValue_String;
+ const factory ConvexValue.bytes(  Uint8List field0,) = ConvexValue_Bytes;
+ const factory ConvexValue.array(  List<ConvexValue> field0,) = ConvexValue_Array;
+ const factory ConvexValue.object(  Map<String, ConvexValue> field0,) = ConvexValue_Object;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
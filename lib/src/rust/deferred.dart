@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `call_started`, `defer_mutation`, `due`, `enqueue`, `flush_deferred`, `flush`, `new`, `queued`, `request_flush`, `run`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DeferredMutation`, `DeferredMutations`, `InFlightCall`, `Queue`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            /// Limits of the deferred mutation queue.
+class DeferredMutationOptions  {
+                /// Number of queued mutations that triggers sending the queue.
+final int maxBatchSize;
+
+                const DeferredMutationOptions({required this.maxBatchSize ,});
+
+                static Future<DeferredMutationOptions>  default_()=>RustLib.instance.api.crateDeferredDeferredMutationOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxBatchSize.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DeferredMutationOptions &&
+                runtimeType == other.runtimeType
+                && maxBatchSize == other.maxBatchSize;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,106 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'derived.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `combine`, `combine`, `count`, `join_by_id`, `pick`, `update`, `validate`, `watch_sources`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Combiner`, `DerivedState`, `SourceSubscriber`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `on_done`, `on_error`, `on_update`
+
+
+            
+
+            @freezed
+                sealed class CombineStrategy with _$CombineStrategy  {
+                    const CombineStrategy._();
+
+                     /// An array of the source results, in source order.
+const factory CombineStrategy.array() = CombineStrategy_Array;
+ /// An object holding each source result under the key at its index.
+const factory CombineStrategy.keyed({   required List<String> keys , }) = CombineStrategy_Keyed;
+ /// The object results merged into one object, fields of later sources
+/// replacing those of earlier ones. Other results are skipped.
+const factory CombineStrategy.merge() = CombineStrategy_Merge;
+
+                    
+
+                    
+                }
+
+/// A named field of a derived value.
+class DerivedField  {
+                final String name;
+final DerivedValue value;
+
+                const DerivedField({required this.name ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DerivedField &&
+                runtimeType == other.runtimeType
+                && name == other.name&& value == other.value;
+        
+            }
+
+/// A query feeding a derived value.
+class DerivedSource  {
+                final String name;
+final Map<String, String> args;
+
+                const DerivedSource({required this.name ,required this.args ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^args.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DerivedSource &&
+                runtimeType == other.runtimeType
+                && name == other.name&& args == other.args;
+        
+            }
+
+@freezed
+                sealed class DerivedValue with _$DerivedValue  {
+                    const DerivedValue._();
+
+                     /// The value at `path` in the source result, as dot-separated object
+/// keys and array indices (e.g. `user.tags.0`). An empty path picks the
+/// whole result; a missing one yields `null`.
+const factory DerivedValue.pick({   required int source ,  required String path , }) = DerivedValue_Pick;
+ /// The number of elements of an array result; `0` for `null` and `1`
+/// for any other value.
+const factory DerivedValue.count({   required int source , }) = DerivedValue_Count;
+ /// The documents of the array result of `source`, each with the
+/// document of `with_source` whose `_id` equals its `foreign_key` field
+/// added as `as_field` (`null` if there is none).
+const factory DerivedValue.joinById({   required int source ,  required int withSource ,  required String foreignKey ,  required String asField , }) = DerivedValue_JoinById;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `coded`, `convex_json`, `convex`, `internal`, `is_auth_error_data`, `is_auth_error_value`, `is_retryable`, `server_error_code`, `server`, `timeout`, `with_code`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CodedError`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// What kind of failure a [`ClientError`] is.
+enum ErrorCode {
+                    /// The deployment could not be reached or the connection was lost.
+network,
+/// The call did not complete within its timeout.
+timeout,
+/// The function rejected the auth token, as tagged by a `ConvexError`
+/// with `code` `"AUTH_EXPIRED"`.
+authExpired,
+/// The client's usage budget or the deployment rejected the call as
+/// too frequent.
+rateLimited,
+/// The deployment has no public function of the name.
+functionNotFound,
+/// The arguments were invalid, on the client or by the function's
+/// validator.
+validationFailed,
+/// The function threw a `ConvexError`, with data for the app.
+application,
+/// The function failed unexpectedly.
+server,
+/// The client failed or was used incorrectly, e.g. after `close`.
+internal,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'events.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `emit`, `next_auth_rejection`, `report_error`, `set_authenticated`, `skip_pending`, `subscribe`, `track_connection`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EventBus`, `EventSubscriber`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `on_done`, `on_error`, `on_update`
+
+
+            
+
+            @freezed
+                sealed class ClientEvent with _$ClientEvent  {
+                    const ClientEvent._();
+
+                     /// The WebSocket connected.
+const factory ClientEvent.connected() = ClientEvent_Connected;
+ /// The WebSocket lost its connection and is reconnecting.
+const factory ClientEvent.disconnected() = ClientEvent_Disconnected;
+ /// An auth token was applied or cleared.
+const factory ClientEvent.authChanged({   required bool authenticated , }) = ClientEvent_AuthChanged;
+ /// A subscription to `name` failed.
+const factory ClientEvent.subscriptionError({   required String name ,  required String message , }) = ClientEvent_SubscriptionError;
+ /// `name` failed with a `ConvexError` tagging its auth token as
+/// rejected, e.g. as revoked or expired (see [`crate::errors`]). An auth
+/// refresh session then fetches a new token right away.
+const factory ClientEvent.authRejected({   required String name ,  required String message , }) = ClientEvent_AuthRejected;
+ /// A mutation is sent again after its attempt number `attempt` failed.
+const factory ClientEvent.mutationRetried({   required String name ,  required int attempt ,  required String error , }) = ClientEvent_MutationRetried;
+ /// A result of `name` was answered from a cache.
+const factory ClientEvent.cacheHit({   required String name , }) = ClientEvent_CacheHit;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,81 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `active_client`, `active_url`, `new`, `next_index`, `probe`, `run`, `switch_to`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FailoverState`, `FailoverTask`, `Step`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// Emitted whenever the client switches to another deployment.
+class FailoverEvent  {
+                final String fromUrl;
+final String toUrl;
+final FailoverReason reason;
+
+                const FailoverEvent({required this.fromUrl ,required this.toUrl ,required this.reason ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fromUrl.hashCode^toUrl.hashCode^reason.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FailoverEvent &&
+                runtimeType == other.runtimeType
+                && fromUrl == other.fromUrl&& toUrl == other.toUrl&& reason == other.reason;
+        
+            }
+
+/// Fallback deployments and failover timing.
+class FailoverOptions  {
+                /// Fallback deployment URLs, in priority order.
+final List<String> fallbackUrls;
+/// How long the active deployment must be unreachable before switching.
+final BigInt failoverAfterMs;
+/// How often the primary is probed while a fallback is active.
+final BigInt failbackProbeIntervalMs;
+
+                const FailoverOptions({required this.fallbackUrls ,required this.failoverAfterMs ,required this.failbackProbeIntervalMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fallbackUrls.hashCode^failoverAfterMs.hashCode^failbackProbeIntervalMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FailoverOptions &&
+                runtimeType == other.runtimeType
+                && fallbackUrls == other.fallbackUrls&& failoverAfterMs == other.failoverAfterMs&& failbackProbeIntervalMs == other.failbackProbeIntervalMs;
+        
+            }
+
+/// Why the client switched deployments.
+enum FailoverReason {
+                    /// The active deployment was unreachable for too long.
+connectionLost,
+/// The primary deployment is reachable again.
+primaryRestored,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply`, `roll`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FaultInjector`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            /// Latency and failure rate injected into calls of a function.
+class FunctionFault  {
+                /// Delay before each call is made.
+final int latencyMs;
+/// Share of calls failing, from `0.0` (none) to `1.0` (all).
+final double failureRate;
+
+                const FunctionFault({required this.latencyMs ,required this.failureRate ,});
+
+                static Future<FunctionFault>  default_()=>RustLib.instance.api.crateFaultsFunctionFaultDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => latencyMs.hashCode^failureRate.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FunctionFault &&
+                runtimeType == other.runtimeType
+                && latencyMs == other.latencyMs&& failureRate == other.failureRate;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,91 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'file_storage.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `content_range`, `download_attempt`, `download_resuming`, `download_url`, `open`, `report_progress`, `resume_validator`, `storage_id`, `validator_path`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Failure`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// How files are uploaded.
+class FileStorageOptions  {
+                /// Mutation returning an upload URL, called without arguments.
+final String generateUploadUrlMutation;
+/// Attempts of a download, including the first one.
+final int downloadAttempts;
+/// Delay before resuming an interrupted download; doubled after every
+/// failed attempt.
+final BigInt downloadBackoffMs;
+/// Longest wait for the response or its next bytes before a download
+/// attempt counts as interrupted.
+final BigInt downloadReadTimeoutMs;
+
+                const FileStorageOptions({required this.generateUploadUrlMutation ,required this.downloadAttempts ,required this.downloadBackoffMs ,required this.downloadReadTimeoutMs ,});
+
+                static Future<FileStorageOptions>  default_()=>RustLib.instance.api.crateFileStorageFileStorageOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => generateUploadUrlMutation.hashCode^downloadAttempts.hashCode^downloadBackoffMs.hashCode^downloadReadTimeoutMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FileStorageOptions &&
+                runtimeType == other.runtimeType
+                && generateUploadUrlMutation == other.generateUploadUrlMutation&& downloadAttempts == other.downloadAttempts&& downloadBackoffMs == other.downloadBackoffMs&& downloadReadTimeoutMs == other.downloadReadTimeoutMs;
+        
+            }
+
+/// Progress of a file transfer.
+class TransferProgress  {
+                final BigInt transferredBytes;
+/// Size of the file, if known.
+final BigInt? totalBytes;
+
+                const TransferProgress({required this.transferredBytes ,this.totalBytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => transferredBytes.hashCode^totalBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TransferProgress &&
+                runtimeType == other.runtimeType
+                && transferredBytes == other.transferredBytes&& totalBytes == other.totalBytes;
+        
+            }
+
+@freezed
+                sealed class UploadSource with _$UploadSource  {
+                    const UploadSource._();
+
+                     /// The file at `path`, streamed from disk.
+const factory UploadSource.path({   required String path , }) = UploadSource_Path;
+ const factory UploadSource.bytes({   required Uint8List bytes , }) = UploadSource_Bytes;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -3,2245 +3,10799 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'audit.dart';
+import 'auth_changes.dart';
+import 'auth_refresh.dart';
+import 'backpressure.dart';
+import 'batch_query.dart';
+import 'budget.dart';
+import 'call_metrics.dart';
+import 'cancellation.dart';
+import 'chunked.dart';
+import 'codecs.dart';
+import 'commit_token.dart';
+import 'connection.dart';
+import 'convex_value.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'deferred.dart';
+import 'derived.dart';
+import 'errors.dart';
+import 'events.dart';
+import 'failover.dart';
+import 'faults.dart';
+import 'file_storage.dart';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'hints.dart';
+import 'http_actions.dart';
+import 'interceptors.dart';
+import 'jobs.dart';
 import 'lib.dart';
+import 'list_diff.dart';
+import 'logging.dart';
+import 'logout.dart';
+import 'memory.dart';
+import 'metrics.dart';
+import 'mutation_status.dart';
+import 'optimistic.dart';
+import 'options.dart';
+import 'outbox.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'pagination.dart';
+import 'panics.dart';
+import 'patches.dart';
+import 'persisted_results.dart';
+import 'placeholder.dart';
+import 'presence.dart';
+import 'pressure.dart';
+import 'preview.dart';
+import 'quality.dart';
+import 'query_cache.dart';
+import 'registry.dart';
+import 'request_ids.dart';
+import 'resubscribe.dart';
+import 'retry.dart';
+import 'sampling.dart';
+import 'schema_check.dart';
+import 'sequence.dart';
+import 'sharding.dart';
+import 'spans.dart';
+import 'subscription.dart';
+import 'subscription_stream.dart';
+import 'supervisor.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 1095084362;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'convex_flutter',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  void crateAuthHandleDispose({required AuthHandle that});
-
-  bool crateAuthHandleIsAuthenticated({required AuthHandle that});
-
-  Future<void> crateCallbackSubscriberDartFnOnError({
-    required CallbackSubscriberDartFn that,
-    required String message,
-    String? value,
-  });
-
-  Future<void> crateCallbackSubscriberDartFnOnUpdate({
-    required CallbackSubscriberDartFn that,
-    required String value,
-  });
-
-  Future<void> crateCallbackSubscriberOnError({
-    required CallbackSubscriber that,
-    required String message,
-    String? value,
-  });
-
-  Future<void> crateCallbackSubscriberOnUpdate({
-    required CallbackSubscriber that,
-    required String value,
-  });
-
-  Future<String> crateMobileConvexClientAction({
-    required MobileConvexClient that,
-    required String name,
-    required Map<String, String> args,
-  });
-
-  Future<String> crateMobileConvexClientMutation({
-    required MobileConvexClient that,
-    required String name,
-    required Map<String, String> args,
-  });
-
-  MobileConvexClient crateMobileConvexClientNew({
-    required String deploymentUrl,
-    required String clientId,
-  });
-
-  Future<void> crateMobileConvexClientOnWebsocketStateChange({
-    required MobileConvexClient that,
-    required FutureOr<void> Function(WebSocketConnectionState) onStateChange,
-  });
-
-  Future<String> crateMobileConvexClientQuery({
-    required MobileConvexClient that,
-    required String name,
-    required Map<String, String> args,
-  });
-
-  Future<void> crateMobileConvexClientSetAuth({
-    required MobileConvexClient that,
-    String? token,
-  });
-
-  Future<AuthHandle> crateMobileConvexClientSetAuthWithRefresh({
-    required MobileConvexClient that,
-    required FutureOr<String?> Function() fetchToken,
-    required FutureOr<void> Function(bool) onAuthChange,
-  });
-
-  Future<SubscriptionHandle> crateMobileConvexClientSubscribe({
-    required MobileConvexClient that,
-    required String name,
-    required Map<String, String> args,
-    required FutureOr<void> Function(String) onUpdate,
-    required FutureOr<void> Function(String, String?) onError,
-  });
-
-  void crateSubscriptionHandleCancel({required SubscriptionHandle that});
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_AuthHandle;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_AuthHandle;
-
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AuthHandlePtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_CallbackSubscriber;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_CallbackSubscriber;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_CallbackSubscriberPtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_CallbackSubscriberDartFn;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_CallbackSubscriberDartFn;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_CallbackSubscriberDartFnPtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_MobileConvexClient;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_MobileConvexClient;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_MobileConvexClientPtr;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_SubscriptionHandle;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_SubscriptionHandle;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_SubscriptionHandlePtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  void crateAuthHandleDispose({required AuthHandle that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateAuthHandleDisposeConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateAuthHandleDisposeConstMeta =>
-      const TaskConstMeta(debugName: "AuthHandle_dispose", argNames: ["that"]);
-
-  @override
-  bool crateAuthHandleIsAuthenticated({required AuthHandle that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-        },
-        codec: SseCodec(
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.11.1';
+
+                  @override
+                  int get rustContentHash => -1606394801;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'convex_flutter',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  void crateAuthHandleDispose({required AuthHandle that });
+
+bool crateAuthHandleIsActive({required AuthHandle that });
+
+bool crateAuthHandleIsAuthenticated({required AuthHandle that });
+
+Future<void> crateCallbackSubscriberDartFnOnDone({required CallbackSubscriberDartFn that });
+
+Future<void> crateCallbackSubscriberDartFnOnError({required CallbackSubscriberDartFn that , required String message , String? value });
+
+Future<void> crateCallbackSubscriberDartFnOnUpdate({required CallbackSubscriberDartFn that , required String value });
+
+Future<void> crateCallbackSubscriberOnDone({required CallbackSubscriber that });
+
+Future<void> crateCallbackSubscriberOnError({required CallbackSubscriber that , required String message , String? value });
+
+Future<void> crateCallbackSubscriberOnUpdate({required CallbackSubscriber that , required String value });
+
+void crateCancellationCancellationTokenCancel({required CancellationToken that });
+
+Future<CancellationToken> crateCancellationCancellationTokenDefault();
+
+bool crateCancellationCancellationTokenIsCancelled({required CancellationToken that });
+
+CancellationToken crateCancellationCancellationTokenNew();
+
+Future<String> crateMobileConvexClientAction({required MobileConvexClient that , required String name , required Map<String, String> args , BigInt? timeoutMs });
+
+Future<String> crateMobileConvexClientActionCancellable({required MobileConvexClient that , required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs });
+
+Future<ConvexValue> crateMobileConvexClientActionTyped({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args });
+
+Future<String> crateMobileConvexClientActionWithProgress({required MobileConvexClient that , required String name , required Map<String, String> args , required JobOptions options , required FutureOr<void> Function(JobProgress) onProgress });
+
+Future<String> crateMobileConvexClientActionWithValues({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs });
+
+String crateMobileConvexClientActiveDeploymentUrl({required MobileConvexClient that });
+
+List<SubscriptionInfo> crateMobileConvexClientActiveSubscriptions({required MobileConvexClient that });
+
+Future<BigInt> crateMobileConvexClientAddInterceptor({required MobileConvexClient that , required FutureOr<InterceptDecision> Function(InterceptedCall) onCall , required FutureOr<CallOutcome> Function(InterceptedCall, CallOutcome) onResult });
+
+Future<void> crateMobileConvexClientAttachDartKeepalive({required MobileConvexClient that , required DartKeepaliveOptions options , required FutureOr<void> Function() keepalive });
+
+Future<void> crateMobileConvexClientAwaitPendingWrites({required MobileConvexClient that , BigInt? timeoutMs });
+
+Future<String> crateMobileConvexClientCachedAction({required MobileConvexClient that , required String name , required Map<String, String> args , required BigInt ttlMs });
+
+Future<String> crateMobileConvexClientCachedQuery({required MobileConvexClient that , required String name , required Map<String, String> args });
+
+int crateMobileConvexClientCancelAllSubscriptions({required MobileConvexClient that });
+
+void crateMobileConvexClientClearActionCache({required MobileConvexClient that });
+
+Future<void> crateMobileConvexClientClearAuditLog({required MobileConvexClient that });
+
+void crateMobileConvexClientClearFieldEncryption({required MobileConvexClient that });
+
+void crateMobileConvexClientClearFunctionFault({required MobileConvexClient that , required String name });
+
+void crateMobileConvexClientClearFunctionFaults({required MobileConvexClient that });
+
+Future<void> crateMobileConvexClientClearUserData({required MobileConvexClient that });
+
+PlatformInt64 crateMobileConvexClientClockOffsetMs({required MobileConvexClient that });
+
+Future<void> crateMobileConvexClientClose({required MobileConvexClient that });
+
+void crateMobileConvexClientConfigureResultCache({required MobileConvexClient that , required ResultCacheOptions options });
+
+Future<void> crateMobileConvexClientConnect({required MobileConvexClient that });
+
+MobileConvexClient crateMobileConvexClientConnectPreview({required MobileConvexClient that , required String preview , required String clientId });
+
+ConnectionQuality crateMobileConvexClientConnectionQuality({required MobileConvexClient that });
+
+WebSocketConnectionState crateMobileConvexClientConnectionState({required MobileConvexClient that });
+
+DartLiveness crateMobileConvexClientDartLiveness({required MobileConvexClient that });
+
+void crateMobileConvexClientDeclareQueryDependencies({required MobileConvexClient that , required String queryName , required List<QueryDependency> dependencies });
+
+void crateMobileConvexClientDisableTraceExport({required MobileConvexClient that });
+
+Future<void> crateMobileConvexClientDisconnect({required MobileConvexClient that });
+
+Future<void> crateMobileConvexClientDownloadFile({required MobileConvexClient that , required String urlOrStorageId , required String destPath , required FutureOr<void> Function(TransferProgress) onProgress });
+
+int crateMobileConvexClientDuplicateClientCount({required MobileConvexClient that });
+
+void crateMobileConvexClientEnableTraceExport({required MobileConvexClient that , required TraceExportOptions options });
+
+Future<void> crateMobileConvexClientEvents({required MobileConvexClient that , required FutureOr<void> Function(ClientEvent) onEvent });
+
+Future<String> crateMobileConvexClientExportAuditLog({required MobileConvexClient that });
+
+MobileConvexClient crateMobileConvexClientFromConfig({required String configJson , required String flavor , required String clientId });
+
+String? crateMobileConvexClientGetCached({required MobileConvexClient that , required String name , required Map<String, String> args });
+
+Future<HttpActionResponse> crateMobileConvexClientHttpAction({required MobileConvexClient that , required String path , required String method , required Map<String, String> headers , Uint8List? body });
+
+InitializationDiagnostics crateMobileConvexClientInitializationDiagnostics({required MobileConvexClient that });
+
+Future<void> crateMobileConvexClientInvalidateCachedQuery({required MobileConvexClient that , required String queryName });
+
+bool crateMobileConvexClientIsAuthenticated({required MobileConvexClient that });
+
+bool crateMobileConvexClientIsIncognito({required MobileConvexClient that });
+
+PlatformInt64? crateMobileConvexClientLastConnectedAtMs({required MobileConvexClient that });
+
+String? crateMobileConvexClientLastConnectionError({required MobileConvexClient that });
+
+Future<LogoutEvent> crateMobileConvexClientLogout({required MobileConvexClient that , required bool clearUserData });
+
+ClientMetrics crateMobileConvexClientMetrics({required MobileConvexClient that });
+
+MetricsReport crateMobileConvexClientMetricsSnapshot({required MobileConvexClient that });
+
+Future<MutationSequenceResult> crateMobileConvexClientMutateSequence({required MobileConvexClient that , required List<MutationCall> calls , required bool stopOnError });
+
+Future<String> crateMobileConvexClientMutation({required MobileConvexClient that , required String name , required Map<String, String> args , BigInt? timeoutMs });
+
+Future<String> crateMobileConvexClientMutationCancellable({required MobileConvexClient that , required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs });
+
+Future<void> crateMobileConvexClientMutationDeferred({required MobileConvexClient that , required String name , required Map<String, String> args , required int maxDelayMs });
+
+Future<void> crateMobileConvexClientMutationDeferredTracked({required MobileConvexClient that , required String mutationId , required String name , required Map<String, String> args , required int maxDelayMs });
+
+Future<IdempotentMutationResult> crateMobileConvexClientMutationIdempotent({required MobileConvexClient that , required String name , required Map<String, String> args , String? idempotencyKey });
+
+Future<OfflineMutationResult> crateMobileConvexClientMutationOffline({required MobileConvexClient that , required String name , required Map<String, String> args });
+
+Future<String> crateMobileConvexClientMutationOptimistic({required MobileConvexClient that , required String name , required Map<String, String> args , required List<OptimisticUpdate> updates });
+
+Future<String> crateMobileConvexClientMutationTracked({required MobileConvexClient that , required String mutationId , required String name , required Map<String, String> args , required int maxAttempts });
+
+Future<ConvexValue> crateMobileConvexClientMutationTyped({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args });
+
+Future<CommittedMutation> crateMobileConvexClientMutationWithCommitToken({required MobileConvexClient that , required String name , required Map<String, String> args });
+
+Future<String> crateMobileConvexClientMutationWithValues({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs });
+
+MobileConvexClient crateMobileConvexClientNew({required String deploymentUrl , required String clientId });
+
+MobileConvexClient crateMobileConvexClientNewWithOptions({required String deploymentUrl , required String clientId , required ClientOptions options });
+
+Future<MemoryReleasedEvent> crateMobileConvexClientNotifyMemoryPressure({required MobileConvexClient that , required MemoryPressure pressure });
+
+Future<void> crateMobileConvexClientOnAuthChange({required MobileConvexClient that , required FutureOr<void> Function(AuthChange) onChange });
+
+Future<void> crateMobileConvexClientOnBackendChange({required MobileConvexClient that , required FutureOr<void> Function(BackendChangedEvent) onChange });
+
+Future<void> crateMobileConvexClientOnBudgetExceeded({required MobileConvexClient that , required FutureOr<void> Function(BudgetExceededEvent) onExceeded });
+
+Future<void> crateMobileConvexClientOnConnectionQualityChange({required MobileConvexClient that , required FutureOr<void> Function(ConnectionQuality) onChange });
+
+Future<void> crateMobileConvexClientOnFailover({required MobileConvexClient that , required FutureOr<void> Function(FailoverEvent) onFailover });
+
+Future<void> crateMobileConvexClientOnLogout({required MobileConvexClient that , required FutureOr<void> Function(LogoutEvent) onLogout });
+
+Future<void> crateMobileConvexClientOnMemoryReleased({required MobileConvexClient that , required FutureOr<void> Function(MemoryReleasedEvent) onReleased });
+
+Future<void> crateMobileConvexClientOnMutationLifecycle({required MobileConvexClient that , required FutureOr<void> Function(MutationLifecycleEvent) onEvent });
+
+Future<void> crateMobileConvexClientOnOutboxCompletion({required MobileConvexClient that , required FutureOr<void> Function(OutboxCompletion) onCompletion });
+
+Future<void> crateMobileConvexClientOnOutboxConflict({required MobileConvexClient that , required FutureOr<ConflictResolution> Function(OutboxConflict) resolve });
+
+Future<void> crateMobileConvexClientOnSlowInitialization({required MobileConvexClient that , required FutureOr<void> Function(SlowInitializationEvent) onSlow });
+
+Future<void> crateMobileConvexClientOnUiHint({required MobileConvexClient that , required FutureOr<void> Function(UiHint?) onHint });
+
+Future<void> crateMobileConvexClientOnWebsocketStateChange({required MobileConvexClient that , required FutureOr<void> Function(WebSocketConnectionState) onStateChange });
+
+Future<int> crateMobileConvexClientOutboxLen({required MobileConvexClient that });
+
+Future<void> crateMobileConvexClientPause({required MobileConvexClient that });
+
+Future<String> crateMobileConvexClientQuery({required MobileConvexClient that , required String name , required Map<String, String> args , BigInt? timeoutMs });
+
+Future<String> crateMobileConvexClientQueryAtLeast({required MobileConvexClient that , required String ts , required String name , required Map<String, String> args });
+
+Future<List<String>> crateMobileConvexClientQueryBatch({required MobileConvexClient that , required List<BatchQuery> queries , BigInt? timeoutMs });
+
+Future<String> crateMobileConvexClientQueryCancellable({required MobileConvexClient that , required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs });
+
+Future<String> crateMobileConvexClientQueryProjected({required MobileConvexClient that , required String name , required Map<String, String> args , required List<String> projection });
+
+Future<void> crateMobileConvexClientQueryStreamed({required MobileConvexClient that , required String name , required Map<String, String> args , required FutureOr<void> Function(ResultChunk) onChunk , BigInt? timeoutMs });
+
+Future<ConvexValue> crateMobileConvexClientQueryTyped({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args });
+
+Future<String> crateMobileConvexClientQueryWithValues({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs });
+
+Future<void> crateMobileConvexClientReconnect({required MobileConvexClient that });
+
+void crateMobileConvexClientRegisterShardMapping({required MobileConvexClient that , required String queryName , required ShardMapping mapping });
+
+bool crateMobileConvexClientRemoveInterceptor({required MobileConvexClient that , required BigInt id });
+
+void crateMobileConvexClientRemoveLogListener({required MobileConvexClient that });
+
+void crateMobileConvexClientRemovePanicReporter({required MobileConvexClient that });
+
+String? crateMobileConvexClientReplicaGet({required MobileConvexClient that , required String table , required String id });
+
+List<String> crateMobileConvexClientReplicaQuery({required MobileConvexClient that , required String table , required Map<String, String> filter });
+
+Future<SubscriptionHandle> crateMobileConvexClientReplicateQuery({required MobileConvexClient that , required String table , required String queryName , required Map<String, String> queryArgs });
+
+Future<void> crateMobileConvexClientResume({required MobileConvexClient that });
+
+Future<void> crateMobileConvexClientSetAuth({required MobileConvexClient that , String? token });
+
+Future<AuthHandle> crateMobileConvexClientSetAuthWithRefresh({required MobileConvexClient that , required FutureOr<String?> Function() fetchToken , required FutureOr<void> Function(bool) onAuthChange });
+
+Future<AuthHandle> crateMobileConvexClientSetAuthWithRefreshConfig({required MobileConvexClient that , required AuthRefreshConfig config , required FutureOr<String?> Function() fetchToken , required FutureOr<void> Function(bool) onAuthChange });
+
+void crateMobileConvexClientSetFieldEncryption({required MobileConvexClient that , required List<int> key , required List<String> fields });
+
+Future<void> crateMobileConvexClientSetFunctionFault({required MobileConvexClient that , required String name , required FunctionFault fault });
+
+void crateMobileConvexClientSetLogFile({required MobileConvexClient that , String? path , required LogLevel level });
+
+void crateMobileConvexClientSetLogLevel({required MobileConvexClient that , required LogLevel level });
+
+Future<void> crateMobileConvexClientSetLogListener({required MobileConvexClient that , required LogLevel level , required FutureOr<void> Function(LogRecord) onRecord });
+
+Future<void> crateMobileConvexClientSetPanicReporter({required MobileConvexClient that , required FutureOr<void> Function(PanicReport) onPanic });
+
+void crateMobileConvexClientSetStorageKey({required MobileConvexClient that , required List<int> key });
+
+void crateMobileConvexClientSetTelemetrySampling({required MobileConvexClient that , required TelemetrySampling sampling });
+
+void crateMobileConvexClientSetUiPressure({required MobileConvexClient that , required UiPressure pressure });
+
+ShardingMetrics crateMobileConvexClientShardingMetrics({required MobileConvexClient that });
+
+Future<PresenceHandle> crateMobileConvexClientStartPresence({required MobileConvexClient that , required String mutationName , required Map<String, String> args , required BigInt intervalMs });
+
+Future<String> crateMobileConvexClientStorageDirectory({required MobileConvexClient that });
+
+Future<SubscriptionHandle> crateMobileConvexClientSubscribe({required MobileConvexClient that , required String name , required Map<String, String> args , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError , required FutureOr<void> Function() onDone , SubscribeOptions? options });
+
+Future<SubscriptionHandle> crateMobileConvexClientSubscribeChunked({required MobileConvexClient that , required String name , required Map<String, String> args , required FutureOr<void> Function(ResultChunk) onChunk , required FutureOr<void> Function(String, String?) onError });
+
+Future<SubscriptionHandle> crateMobileConvexClientSubscribeCombined({required MobileConvexClient that , required List<DerivedSource> sources , required CombineStrategy strategy , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+Future<SubscriptionHandle> crateMobileConvexClientSubscribeKeyed({required MobileConvexClient that , required String key , required String name , required Map<String, String> args , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+Future<SubscriptionHandle> crateMobileConvexClientSubscribeList({required MobileConvexClient that , required String name , required Map<String, String> args , required String idField , required FutureOr<void> Function(ListUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+Future<String> crateMobileConvexClientSubscribeOnce({required MobileConvexClient that , required String name , required Map<String, String> args });
+
+Future<PaginatedQueryHandle> crateMobileConvexClientSubscribePaginated({required MobileConvexClient that , required String name , required Map<String, String> args , required int initialNumItems , required FutureOr<void> Function(PaginatedUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+Future<PaginatedQueryHandle> crateMobileConvexClientSubscribePaginatedFrom({required MobileConvexClient that , required String name , required Map<String, String> args , required List<String> pageBoundaries , required int numItems , required FutureOr<void> Function(PaginatedUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+Future<SubscriptionHandle> crateMobileConvexClientSubscribePresence({required MobileConvexClient that , required String name , required Map<String, String> args , required PresenceOptions options , required FutureOr<void> Function(List<PresenceStatus>) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+Stream<String> crateMobileConvexClientSubscribeStream({required MobileConvexClient that , required String name , required Map<String, String> args });
+
+Future<SubscriptionHandle> crateMobileConvexClientSubscribeTyped({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args , required FutureOr<void> Function(ConvexValue) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+Future<SubscriptionHandle> crateMobileConvexClientSubscribeWithEvents({required MobileConvexClient that , required String name , required Map<String, String> args , required FutureOr<void> Function(SubscriptionEvent) onEvent , SubscribeOptions? options });
+
+Future<SubscriptionHandle> crateMobileConvexClientSubscribeWithPatches({required MobileConvexClient that , required String name , required Map<String, String> args , required int snapshotInterval , required FutureOr<void> Function(PatchUpdate) onPatch , required FutureOr<void> Function(String, String?) onError });
+
+UiHint? crateMobileConvexClientUiHint({required MobileConvexClient that });
+
+UiPressure crateMobileConvexClientUiPressure({required MobileConvexClient that });
+
+void crateMobileConvexClientUnsubscribeKeyed({required MobileConvexClient that , required String key });
+
+Future<String> crateMobileConvexClientUploadFile({required MobileConvexClient that , required UploadSource source , required String mimeType , required FutureOr<void> Function(TransferProgress) onProgress });
+
+Future<SubscriptionHandle> crateMobileConvexClientWatch({required MobileConvexClient that , required String name , required Map<String, String> args , required PlaceholderPolicy placeholder , required FutureOr<void> Function(WatchEvent) onEvent });
+
+Future<SubscriptionHandle> crateMobileConvexClientWatchDerived({required MobileConvexClient that , required List<DerivedSource> sources , required List<DerivedField> fields , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+void cratePaginationPaginatedQueryHandleCancel({required PaginatedQueryHandle that });
+
+bool cratePaginationPaginatedQueryHandleLoadMore({required PaginatedQueryHandle that , required int numItems });
+
+PaginationStatus cratePaginationPaginatedQueryHandleStatus({required PaginatedQueryHandle that });
+
+bool cratePresencePresenceHandleIsPaused({required PresenceHandle that });
+
+void cratePresencePresenceHandlePause({required PresenceHandle that });
+
+void cratePresencePresenceHandleResume({required PresenceHandle that });
+
+void cratePresencePresenceHandleStop({required PresenceHandle that });
+
+bool crateSubscriptionHandleCancel({required SubscriptionHandle that });
+
+bool crateSubscriptionHandleIsActive({required SubscriptionHandle that });
+
+void crateSubscriptionHandleSetPriority({required SubscriptionHandle that , required SubscriptionPriority priority });
+
+SubscriptionState crateSubscriptionHandleState({required SubscriptionHandle that });
+
+Future<AuthRefreshConfig> crateAuthRefreshAuthRefreshConfigDefault();
+
+Future<BlankArgHandling> crateOptionsBlankArgHandlingDefault();
+
+Future<BudgetEnforcement> crateBudgetBudgetEnforcementDefault();
+
+Future<ChunkedResultOptions> crateChunkedChunkedResultOptionsDefault();
+
+Future<ClientOptions> crateOptionsClientOptionsDefault();
+
+Future<ConnectRetryOptions> crateConnectionConnectRetryOptionsDefault();
+
+Future<DartKeepaliveOptions> crateSupervisorDartKeepaliveOptionsDefault();
+
+Future<DeferredMutationOptions> crateDeferredDeferredMutationOptionsDefault();
+
+Future<FileStorageOptions> crateFileStorageFileStorageOptionsDefault();
+
+Future<FunctionFault> crateFaultsFunctionFaultDefault();
+
+Future<Int64Encoding> crateOptionsInt64EncodingDefault();
+
+Future<MutationRetryOptions> crateRetryMutationRetryOptionsDefault();
+
+Future<NullHandling> crateOptionsNullHandlingDefault();
+
+Future<PersistedResultOptions> cratePersistedResultsPersistedResultOptionsDefault();
+
+Future<PlaceholderPolicy> cratePlaceholderPlaceholderPolicyDefault();
+
+Future<PressureThrottle> cratePressurePressureThrottleDefault();
+
+Future<PreviewOptions> cratePreviewPreviewOptionsDefault();
+
+Future<RequestIdOptions> crateRequestIdsRequestIdOptionsDefault();
+
+Future<ResultCacheOptions> cratePlaceholderResultCacheOptionsDefault();
+
+Future<ShardingMetrics> crateShardingShardingMetricsDefault();
+
+Future<SubscribeOptions> crateSubscriptionSubscribeOptionsDefault();
+
+Future<SubscriptionPriority> crateResubscribeSubscriptionPriorityDefault();
+
+Future<TelemetrySampling> crateSamplingTelemetrySamplingDefault();
+
+Future<UiPressure> cratePressureUiPressureDefault();
+
+Future<UsageBudget> crateBudgetUsageBudgetDefault();
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_AuthHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_AuthHandle;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AuthHandlePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CallbackSubscriber;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CallbackSubscriber;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CallbackSubscriberPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CallbackSubscriberDartFn;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CallbackSubscriberDartFn;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CallbackSubscriberDartFnPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CancellationToken;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CancellationToken;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CancellationTokenPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MobileConvexClient;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MobileConvexClient;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MobileConvexClientPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PaginatedQueryHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PaginatedQueryHandle;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PaginatedQueryHandlePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PresenceHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PresenceHandle;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PresenceHandlePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SubscriptionHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SubscriptionHandle;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SubscriptionHandlePtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override void crateAuthHandleDispose({required AuthHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateAuthHandleDisposeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAuthHandleDisposeConstMeta => const TaskConstMeta(
+            debugName: "AuthHandle_dispose",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateAuthHandleIsActive({required AuthHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateAuthHandleIsActiveConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAuthHandleIsActiveConstMeta => const TaskConstMeta(
+            debugName: "AuthHandle_is_active",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateAuthHandleIsAuthenticated({required AuthHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateAuthHandleIsAuthenticatedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAuthHandleIsAuthenticatedConstMeta => const TaskConstMeta(
+            debugName: "AuthHandle_is_authenticated",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateCallbackSubscriberDartFnOnDone({required CallbackSubscriberDartFn that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCallbackSubscriberDartFnOnDoneConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCallbackSubscriberDartFnOnDoneConstMeta => const TaskConstMeta(
+            debugName: "CallbackSubscriberDartFn_on_done",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateCallbackSubscriberDartFnOnError({required CallbackSubscriberDartFn that , required String message , String? value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(that, serializer);
+sse_encode_String(message, serializer);
+sse_encode_opt_String(value, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCallbackSubscriberDartFnOnErrorConstMeta,
+            argValues: [that, message, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCallbackSubscriberDartFnOnErrorConstMeta => const TaskConstMeta(
+            debugName: "CallbackSubscriberDartFn_on_error",
+            argNames: ["that", "message", "value"],
+        );
+        
+
+@override Future<void> crateCallbackSubscriberDartFnOnUpdate({required CallbackSubscriberDartFn that , required String value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(that, serializer);
+sse_encode_String(value, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCallbackSubscriberDartFnOnUpdateConstMeta,
+            argValues: [that, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCallbackSubscriberDartFnOnUpdateConstMeta => const TaskConstMeta(
+            debugName: "CallbackSubscriberDartFn_on_update",
+            argNames: ["that", "value"],
+        );
+        
+
+@override Future<void> crateCallbackSubscriberOnDone({required CallbackSubscriber that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCallbackSubscriberOnDoneConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCallbackSubscriberOnDoneConstMeta => const TaskConstMeta(
+            debugName: "CallbackSubscriber_on_done",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateCallbackSubscriberOnError({required CallbackSubscriber that , required String message , String? value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(that, serializer);
+sse_encode_String(message, serializer);
+sse_encode_opt_String(value, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCallbackSubscriberOnErrorConstMeta,
+            argValues: [that, message, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCallbackSubscriberOnErrorConstMeta => const TaskConstMeta(
+            debugName: "CallbackSubscriber_on_error",
+            argNames: ["that", "message", "value"],
+        );
+        
+
+@override Future<void> crateCallbackSubscriberOnUpdate({required CallbackSubscriber that , required String value })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(that, serializer);
+sse_encode_String(value, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCallbackSubscriberOnUpdateConstMeta,
+            argValues: [that, value],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCallbackSubscriberOnUpdateConstMeta => const TaskConstMeta(
+            debugName: "CallbackSubscriber_on_update",
+            argNames: ["that", "value"],
+        );
+        
+
+@override void crateCancellationCancellationTokenCancel({required CancellationToken that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCancellationCancellationTokenCancelConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCancellationCancellationTokenCancelConstMeta => const TaskConstMeta(
+            debugName: "CancellationToken_cancel",
+            argNames: ["that"],
+        );
+        
+
+@override Future<CancellationToken> crateCancellationCancellationTokenDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCancellationCancellationTokenDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCancellationCancellationTokenDefaultConstMeta => const TaskConstMeta(
+            debugName: "CancellationToken_default",
+            argNames: [],
+        );
+        
+
+@override bool crateCancellationCancellationTokenIsCancelled({required CancellationToken that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateAuthHandleIsAuthenticatedConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateAuthHandleIsAuthenticatedConstMeta =>
-      const TaskConstMeta(
-        debugName: "AuthHandle_is_authenticated",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<void> crateCallbackSubscriberDartFnOnError({
-    required CallbackSubscriberDartFn that,
-    required String message,
-    String? value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-            that,
-            serializer,
-          );
-          sse_encode_String(message, serializer);
-          sse_encode_opt_String(value, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateCallbackSubscriberDartFnOnErrorConstMeta,
-        argValues: [that, message, value],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateCallbackSubscriberDartFnOnErrorConstMeta =>
-      const TaskConstMeta(
-        debugName: "CallbackSubscriberDartFn_on_error",
-        argNames: ["that", "message", "value"],
-      );
-
-  @override
-  Future<void> crateCallbackSubscriberDartFnOnUpdate({
-    required CallbackSubscriberDartFn that,
-    required String value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-            that,
-            serializer,
-          );
-          sse_encode_String(value, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateCallbackSubscriberDartFnOnUpdateConstMeta,
-        argValues: [that, value],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateCallbackSubscriberDartFnOnUpdateConstMeta =>
-      const TaskConstMeta(
-        debugName: "CallbackSubscriberDartFn_on_update",
-        argNames: ["that", "value"],
-      );
-
-  @override
-  Future<void> crateCallbackSubscriberOnError({
-    required CallbackSubscriber that,
-    required String message,
-    String? value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-            that,
-            serializer,
-          );
-          sse_encode_String(message, serializer);
-          sse_encode_opt_String(value, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateCallbackSubscriberOnErrorConstMeta,
-        argValues: [that, message, value],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateCallbackSubscriberOnErrorConstMeta =>
-      const TaskConstMeta(
-        debugName: "CallbackSubscriber_on_error",
-        argNames: ["that", "message", "value"],
-      );
-
-  @override
-  Future<void> crateCallbackSubscriberOnUpdate({
-    required CallbackSubscriber that,
-    required String value,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-            that,
-            serializer,
-          );
-          sse_encode_String(value, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateCallbackSubscriberOnUpdateConstMeta,
-        argValues: [that, value],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateCallbackSubscriberOnUpdateConstMeta =>
-      const TaskConstMeta(
-        debugName: "CallbackSubscriber_on_update",
-        argNames: ["that", "value"],
-      );
-
-  @override
-  Future<String> crateMobileConvexClientAction({
-    required MobileConvexClient that,
-    required String name,
-    required Map<String, String> args,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-            that,
-            serializer,
-          );
-          sse_encode_String(name, serializer);
-          sse_encode_Map_String_String_None(args, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateCancellationCancellationTokenIsCancelledConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCancellationCancellationTokenIsCancelledConstMeta => const TaskConstMeta(
+            debugName: "CancellationToken_is_cancelled",
+            argNames: ["that"],
+        );
+        
+
+@override CancellationToken crateCancellationCancellationTokenNew()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateCancellationCancellationTokenNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateCancellationCancellationTokenNewConstMeta => const TaskConstMeta(
+            debugName: "CancellationToken_new",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateMobileConvexClientAction({required MobileConvexClient that , required String name , required Map<String, String> args , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientActionConstMeta,
+            argValues: [that, name, args, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientActionConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_action",
+            argNames: ["that", "name", "args", "timeoutMs"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientActionCancellable({required MobileConvexClient that , required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(token, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_client_error,
-        ),
-        constMeta: kCrateMobileConvexClientActionConstMeta,
-        argValues: [that, name, args],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateMobileConvexClientActionConstMeta =>
-      const TaskConstMeta(
-        debugName: "MobileConvexClient_action",
-        argNames: ["that", "name", "args"],
-      );
-
-  @override
-  Future<String> crateMobileConvexClientMutation({
-    required MobileConvexClient that,
-    required String name,
-    required Map<String, String> args,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-            that,
-            serializer,
-          );
-          sse_encode_String(name, serializer);
-          sse_encode_Map_String_String_None(args, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 8,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateMobileConvexClientActionCancellableConstMeta,
+            argValues: [that, name, args, token, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientActionCancellableConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_action_cancellable",
+            argNames: ["that", "name", "args", "token", "timeoutMs"],
+        );
+        
+
+@override Future<ConvexValue> crateMobileConvexClientActionTyped({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_convex_value_None(args, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_convex_value,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientActionTypedConstMeta,
+            argValues: [that, name, args],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientActionTypedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_action_typed",
+            argNames: ["that", "name", "args"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientActionWithProgress({required MobileConvexClient that , required String name , required Map<String, String> args , required JobOptions options , required FutureOr<void> Function(JobProgress) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_box_autoadd_job_options(options, serializer);
+sse_encode_DartFn_Inputs_job_progress_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientActionWithProgressConstMeta,
+            argValues: [that, name, args, options, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientActionWithProgressConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_action_with_progress",
+            argNames: ["that", "name", "args", "options", "onProgress"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientActionWithValues({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_convex_value_None(args, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientActionWithValuesConstMeta,
+            argValues: [that, name, args, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientActionWithValuesConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_action_with_values",
+            argNames: ["that", "name", "args", "timeoutMs"],
+        );
+        
+
+@override String crateMobileConvexClientActiveDeploymentUrl({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientActiveDeploymentUrlConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientActiveDeploymentUrlConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_active_deployment_url",
+            argNames: ["that"],
+        );
+        
+
+@override List<SubscriptionInfo> crateMobileConvexClientActiveSubscriptions({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_subscription_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientActiveSubscriptionsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientActiveSubscriptionsConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_active_subscriptions",
+            argNames: ["that"],
+        );
+        
+
+@override Future<BigInt> crateMobileConvexClientAddInterceptor({required MobileConvexClient that , required FutureOr<InterceptDecision> Function(InterceptedCall) onCall , required FutureOr<CallOutcome> Function(InterceptedCall, CallOutcome) onResult })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_intercepted_call_Output_intercept_decision_AnyhowException(onCall, serializer);
+sse_encode_DartFn_Inputs_intercepted_call_call_outcome_Output_call_outcome_AnyhowException(onResult, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientAddInterceptorConstMeta,
+            argValues: [that, onCall, onResult],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientAddInterceptorConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_add_interceptor",
+            argNames: ["that", "onCall", "onResult"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientAttachDartKeepalive({required MobileConvexClient that , required DartKeepaliveOptions options , required FutureOr<void> Function() keepalive })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_box_autoadd_dart_keepalive_options(options, serializer);
+sse_encode_DartFn_Inputs__Output_unit_AnyhowException(keepalive, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientAttachDartKeepaliveConstMeta,
+            argValues: [that, options, keepalive],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientAttachDartKeepaliveConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_attach_dart_keepalive",
+            argNames: ["that", "options", "keepalive"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientAwaitPendingWrites({required MobileConvexClient that , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientAwaitPendingWritesConstMeta,
+            argValues: [that, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientAwaitPendingWritesConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_await_pending_writes",
+            argNames: ["that", "timeoutMs"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientCachedAction({required MobileConvexClient that , required String name , required Map<String, String> args , required BigInt ttlMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_u_64(ttlMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_client_error,
-        ),
-        constMeta: kCrateMobileConvexClientMutationConstMeta,
-        argValues: [that, name, args],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateMobileConvexClientMutationConstMeta =>
-      const TaskConstMeta(
-        debugName: "MobileConvexClient_mutation",
-        argNames: ["that", "name", "args"],
-      );
-
-  @override
-  MobileConvexClient crateMobileConvexClientNew({
-    required String deploymentUrl,
-    required String clientId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(deploymentUrl, serializer);
-          sse_encode_String(clientId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateMobileConvexClientNewConstMeta,
-        argValues: [deploymentUrl, clientId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateMobileConvexClientNewConstMeta => const TaskConstMeta(
-    debugName: "MobileConvexClient_new",
-    argNames: ["deploymentUrl", "clientId"],
-  );
-
-  @override
-  Future<void> crateMobileConvexClientOnWebsocketStateChange({
-    required MobileConvexClient that,
-    required FutureOr<void> Function(WebSocketConnectionState) onStateChange,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-            that,
-            serializer,
-          );
-          sse_encode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(
-            onStateChange,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 10,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_client_error,
-        ),
-        constMeta: kCrateMobileConvexClientOnWebsocketStateChangeConstMeta,
-        argValues: [that, onStateChange],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateMobileConvexClientOnWebsocketStateChangeConstMeta =>
-      const TaskConstMeta(
-        debugName: "MobileConvexClient_on_websocket_state_change",
-        argNames: ["that", "onStateChange"],
-      );
-
-  @override
-  Future<String> crateMobileConvexClientQuery({
-    required MobileConvexClient that,
-    required String name,
-    required Map<String, String> args,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-            that,
-            serializer,
-          );
-          sse_encode_String(name, serializer);
-          sse_encode_Map_String_String_None(args, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateMobileConvexClientCachedActionConstMeta,
+            argValues: [that, name, args, ttlMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientCachedActionConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_cached_action",
+            argNames: ["that", "name", "args", "ttlMs"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientCachedQuery({required MobileConvexClient that , required String name , required Map<String, String> args })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_client_error,
-        ),
-        constMeta: kCrateMobileConvexClientQueryConstMeta,
-        argValues: [that, name, args],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateMobileConvexClientQueryConstMeta =>
-      const TaskConstMeta(
-        debugName: "MobileConvexClient_query",
-        argNames: ["that", "name", "args"],
-      );
-
-  @override
-  Future<void> crateMobileConvexClientSetAuth({
-    required MobileConvexClient that,
-    String? token,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-            that,
-            serializer,
-          );
-          sse_encode_opt_String(token, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 12,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_client_error,
-        ),
-        constMeta: kCrateMobileConvexClientSetAuthConstMeta,
-        argValues: [that, token],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateMobileConvexClientSetAuthConstMeta =>
-      const TaskConstMeta(
-        debugName: "MobileConvexClient_set_auth",
-        argNames: ["that", "token"],
-      );
-
-  @override
-  Future<AuthHandle> crateMobileConvexClientSetAuthWithRefresh({
-    required MobileConvexClient that,
-    required FutureOr<String?> Function() fetchToken,
-    required FutureOr<void> Function(bool) onAuthChange,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-            that,
-            serializer,
-          );
-          sse_encode_DartFn_Inputs__Output_opt_String_AnyhowException(
-            fetchToken,
-            serializer,
-          );
-          sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-            onAuthChange,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle,
-          decodeErrorData: sse_decode_client_error,
-        ),
-        constMeta: kCrateMobileConvexClientSetAuthWithRefreshConstMeta,
-        argValues: [that, fetchToken, onAuthChange],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateMobileConvexClientSetAuthWithRefreshConstMeta =>
-      const TaskConstMeta(
-        debugName: "MobileConvexClient_set_auth_with_refresh",
-        argNames: ["that", "fetchToken", "onAuthChange"],
-      );
-
-  @override
-  Future<SubscriptionHandle> crateMobileConvexClientSubscribe({
-    required MobileConvexClient that,
-    required String name,
-    required Map<String, String> args,
-    required FutureOr<void> Function(String) onUpdate,
-    required FutureOr<void> Function(String, String?) onError,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-            that,
-            serializer,
-          );
-          sse_encode_String(name, serializer);
-          sse_encode_Map_String_String_None(args, serializer);
-          sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-            onUpdate,
-            serializer,
-          );
-          sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(
-            onError,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 14,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
-          decodeErrorData: sse_decode_client_error,
-        ),
-        constMeta: kCrateMobileConvexClientSubscribeConstMeta,
-        argValues: [that, name, args, onUpdate, onError],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateMobileConvexClientSubscribeConstMeta =>
-      const TaskConstMeta(
-        debugName: "MobileConvexClient_subscribe",
-        argNames: ["that", "name", "args", "onUpdate", "onError"],
-      );
-
-  @override
-  void crateSubscriptionHandleCancel({required SubscriptionHandle that}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateSubscriptionHandleCancelConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateSubscriptionHandleCancelConstMeta =>
-      const TaskConstMeta(
-        debugName: "SubscriptionHandle_cancel",
-        argNames: ["that"],
-      );
-
-  Future<void> Function(int, dynamic)
-  encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-    FutureOr<void> Function(String) raw,
-  ) {
-    return (callId, rawArg0) async {
-      final arg0 = dco_decode_String(rawArg0);
-
-      Box<void>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw(arg0));
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-        callId: callId,
-        ptr: output.ptr,
-        rustVecLen: output.rustVecLen,
-        dataLen: output.dataLen,
-      );
-    };
-  }
-
-  Future<void> Function(int, dynamic, dynamic)
-  encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(
-    FutureOr<void> Function(String, String?) raw,
-  ) {
-    return (callId, rawArg0, rawArg1) async {
-      final arg0 = dco_decode_String(rawArg0);
-      final arg1 = dco_decode_opt_String(rawArg1);
-
-      Box<void>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw(arg0, arg1));
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-        callId: callId,
-        ptr: output.ptr,
-        rustVecLen: output.rustVecLen,
-        dataLen: output.dataLen,
-      );
-    };
-  }
-
-  Future<void> Function(int)
-  encode_DartFn_Inputs__Output_opt_String_AnyhowException(
-    FutureOr<String?> Function() raw,
-  ) {
-    return (callId) async {
-      Box<String?>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw());
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_opt_String(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-        callId: callId,
-        ptr: output.ptr,
-        rustVecLen: output.rustVecLen,
-        dataLen: output.dataLen,
-      );
-    };
-  }
-
-  Future<void> Function(int, dynamic)
-  encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-    FutureOr<void> Function(bool) raw,
-  ) {
-    return (callId, rawArg0) async {
-      final arg0 = dco_decode_bool(rawArg0);
-
-      Box<void>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw(arg0));
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-        callId: callId,
-        ptr: output.ptr,
-        rustVecLen: output.rustVecLen,
-        dataLen: output.dataLen,
-      );
-    };
-  }
-
-  Future<void> Function(int, dynamic)
-  encode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(
-    FutureOr<void> Function(WebSocketConnectionState) raw,
-  ) {
-    return (callId, rawArg0) async {
-      final arg0 = dco_decode_web_socket_connection_state(rawArg0);
-
-      Box<void>? rawOutput;
-      Box<AnyhowException>? rawError;
-      try {
-        rawOutput = Box(await raw(arg0));
-      } catch (e, s) {
-        rawError = Box(AnyhowException("$e\n\n$s"));
-      }
-
-      final serializer = SseSerializer(generalizedFrbRustBinding);
-      assert((rawOutput != null) ^ (rawError != null));
-      if (rawOutput != null) {
-        serializer.buffer.putUint8(0);
-        sse_encode_unit(rawOutput.value, serializer);
-      } else {
-        serializer.buffer.putUint8(1);
-        sse_encode_AnyhowException(rawError!.value, serializer);
-      }
-      final output = serializer.intoRaw();
-
-      generalizedFrbRustBinding.dartFnDeliverOutput(
-        callId: callId,
-        ptr: output.ptr,
-        rustVecLen: output.rustVecLen,
-        dataLen: output.dataLen,
-      );
-    };
-  }
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_AuthHandle => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_AuthHandle => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_CallbackSubscriber => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_CallbackSubscriber => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_CallbackSubscriberDartFn => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_CallbackSubscriberDartFn => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_MobileConvexClient => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_MobileConvexClient => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient;
-
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_SubscriptionHandle => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle;
-
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_SubscriptionHandle => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  AuthHandle
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AuthHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CallbackSubscriber
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CallbackSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CallbackSubscriberDartFn
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CallbackSubscriberDartFnImpl.frbInternalDcoDecode(
-      raw as List<dynamic>,
-    );
-  }
-
-  @protected
-  MobileConvexClient
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MobileConvexClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SubscriptionHandle
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SubscriptionHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  AuthHandle
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AuthHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CallbackSubscriber
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CallbackSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CallbackSubscriberDartFn
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CallbackSubscriberDartFnImpl.frbInternalDcoDecode(
-      raw as List<dynamic>,
-    );
-  }
-
-  @protected
-  MobileConvexClient
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MobileConvexClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SubscriptionHandle
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SubscriptionHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  FutureOr<void> Function(String)
-  dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  FutureOr<void> Function(String, String?)
-  dco_decode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  FutureOr<String?> Function()
-  dco_decode_DartFn_Inputs__Output_opt_String_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  FutureOr<void> Function(bool)
-  dco_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  FutureOr<void> Function(WebSocketConnectionState)
-  dco_decode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError('');
-  }
-
-  @protected
-  Object dco_decode_DartOpaque(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return decodeDartOpaque(raw, generalizedFrbRustBinding);
-  }
-
-  @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_string(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  AuthHandle
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AuthHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CallbackSubscriber
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CallbackSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  CallbackSubscriberDartFn
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CallbackSubscriberDartFnImpl.frbInternalDcoDecode(
-      raw as List<dynamic>,
-    );
-  }
-
-  @protected
-  MobileConvexClient
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MobileConvexClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SubscriptionHandle
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SubscriptionHandleImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  QuerySubscriber dco_decode_TraitDef_QuerySubscriber(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  ClientError dco_decode_client_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return ClientError_InternalError(msg: dco_decode_String(raw[1]));
-      case 1:
-        return ClientError_ConvexError(data: dco_decode_String(raw[1]));
-      case 2:
-        return ClientError_ServerError(msg: dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_isize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  WebSocketConnectionState dco_decode_web_socket_connection_state(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return WebSocketConnectionState.values[raw as int];
-  }
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
-  }
-
-  @protected
-  AuthHandle
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AuthHandleImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CallbackSubscriber
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CallbackSubscriberImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CallbackSubscriberDartFn
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CallbackSubscriberDartFnImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  MobileConvexClient
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MobileConvexClientImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SubscriptionHandle
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SubscriptionHandleImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  AuthHandle
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AuthHandleImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CallbackSubscriber
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CallbackSubscriberImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CallbackSubscriberDartFn
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CallbackSubscriberDartFnImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  MobileConvexClient
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MobileConvexClientImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SubscriptionHandle
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SubscriptionHandleImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  Object sse_decode_DartOpaque(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_isize(deserializer);
-    return decodeDartOpaque(inner, generalizedFrbRustBinding);
-  }
-
-  @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  AuthHandle
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return AuthHandleImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CallbackSubscriber
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CallbackSubscriberImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  CallbackSubscriberDartFn
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return CallbackSubscriberDartFnImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  MobileConvexClient
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return MobileConvexClientImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SubscriptionHandle
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SubscriptionHandleImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  ClientError sse_decode_client_error(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_msg = sse_decode_String(deserializer);
-        return ClientError_InternalError(msg: var_msg);
-      case 1:
-        var var_data = sse_decode_String(deserializer);
-        return ClientError_ConvexError(data: var_data);
-      case 2:
-        var var_msg = sse_decode_String(deserializer);
-        return ClientError_ServerError(msg: var_msg);
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_isize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  WebSocketConnectionState sse_decode_web_socket_connection_state(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return WebSocketConnectionState.values[inner];
-  }
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    AuthHandle self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AuthHandleImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    CallbackSubscriber self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CallbackSubscriberImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    CallbackSubscriberDartFn self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CallbackSubscriberDartFnImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    MobileConvexClient self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MobileConvexClientImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SubscriptionHandle self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SubscriptionHandleImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    AuthHandle self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AuthHandleImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    CallbackSubscriber self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CallbackSubscriberImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    CallbackSubscriberDartFn self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CallbackSubscriberDartFnImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    MobileConvexClient self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MobileConvexClientImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SubscriptionHandle self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SubscriptionHandleImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-    FutureOr<void> Function(String) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-      encode_DartFn_Inputs_String_Output_unit_AnyhowException(self),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(
-    FutureOr<void> Function(String, String?) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-      encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(self),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_DartFn_Inputs__Output_opt_String_AnyhowException(
-    FutureOr<String?> Function() self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-      encode_DartFn_Inputs__Output_opt_String_AnyhowException(self),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-    FutureOr<void> Function(bool) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-      encode_DartFn_Inputs_bool_Output_unit_AnyhowException(self),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(
-    FutureOr<void> Function(WebSocketConnectionState) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_DartOpaque(
-      encode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(
-        self,
-      ),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_isize(
-      PlatformPointerUtil.ptrToPlatformInt64(
-        encodeDartOpaque(
-          self,
-          portManager.dartHandlerPort,
-          generalizedFrbRustBinding,
-        ),
-      ),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_string(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    AuthHandle self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as AuthHandleImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    CallbackSubscriber self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CallbackSubscriberImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    CallbackSubscriberDartFn self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as CallbackSubscriberDartFnImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    MobileConvexClient self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as MobileConvexClientImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SubscriptionHandle self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SubscriptionHandleImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_client_error(ClientError self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case ClientError_InternalError(msg: final msg):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(msg, serializer);
-      case ClientError_ConvexError(data: final data):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(data, serializer);
-      case ClientError_ServerError(msg: final msg):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(msg, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_String(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_web_socket_connection_state(
-    WebSocketConnectionState self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-}
-
-@sealed
-class AuthHandleImpl extends RustOpaque implements AuthHandle {
-  // Not to be used by end users
-  AuthHandleImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  AuthHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_AuthHandle,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_AuthHandle,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_AuthHandlePtr,
-  );
-
-  /// Disposes the auth session, stopping the token refresh loop and clearing authentication.
-  void dispose() => RustLib.instance.api.crateAuthHandleDispose(that: this);
-
-  /// Returns whether the user is currently authenticated.
-  bool isAuthenticated() =>
-      RustLib.instance.api.crateAuthHandleIsAuthenticated(that: this);
-}
-
-@sealed
-class CallbackSubscriberDartFnImpl extends RustOpaque
-    implements CallbackSubscriberDartFn {
-  // Not to be used by end users
-  CallbackSubscriberDartFnImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  CallbackSubscriberDartFnImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib
-        .instance
-        .api
-        .rust_arc_increment_strong_count_CallbackSubscriberDartFn,
-    rustArcDecrementStrongCount: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_CallbackSubscriberDartFn,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_CallbackSubscriberDartFnPtr,
-  );
-
-  Future<void> onError({required String message, String? value}) =>
-      RustLib.instance.api.crateCallbackSubscriberDartFnOnError(
-        that: this,
-        message: message,
-        value: value,
-      );
-
-  Future<void> onUpdate({required String value}) => RustLib.instance.api
-      .crateCallbackSubscriberDartFnOnUpdate(that: this, value: value);
-}
-
-@sealed
-class CallbackSubscriberImpl extends RustOpaque implements CallbackSubscriber {
-  // Not to be used by end users
-  CallbackSubscriberImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  CallbackSubscriberImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_CallbackSubscriber,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_CallbackSubscriber,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_CallbackSubscriberPtr,
-  );
-
-  Future<void> onError({required String message, String? value}) =>
-      RustLib.instance.api.crateCallbackSubscriberOnError(
-        that: this,
-        message: message,
-        value: value,
-      );
-
-  Future<void> onUpdate({required String value}) => RustLib.instance.api
-      .crateCallbackSubscriberOnUpdate(that: this, value: value);
-}
-
-@sealed
-class MobileConvexClientImpl extends RustOpaque implements MobileConvexClient {
-  // Not to be used by end users
-  MobileConvexClientImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  MobileConvexClientImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_MobileConvexClient,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_MobileConvexClient,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_MobileConvexClientPtr,
-  );
-
-  /// Executes an action on the Convex backend.
-  Future<String> action({
-    required String name,
-    required Map<String, String> args,
-  }) => RustLib.instance.api.crateMobileConvexClientAction(
-    that: this,
-    name: name,
-    args: args,
-  );
-
-  /// Executes a mutation on the Convex backend.
-  Future<String> mutation({
-    required String name,
-    required Map<String, String> args,
-  }) => RustLib.instance.api.crateMobileConvexClientMutation(
-    that: this,
-    name: name,
-    args: args,
-  );
-
-  /// Sets up WebSocket connection state change listener.
-  ///
-  /// Must be called BEFORE any queries/mutations to capture all state changes.
-  /// The callback will be invoked whenever the WebSocket transitions between
-  /// Connected and Connecting states.
-  ///
-  /// # Arguments
-  ///
-  /// * `on_state_change` - Async callback invoked when connection state changes
-  ///
-  /// # Example
-  ///
-  /// ```dart
-  /// await client.onWebsocketStateChange(
-  ///   onStateChange: (state) async {
-  ///     print('Connection state: ${state.name}');
-  ///   },
-  /// );
-  /// ```
-  Future<void> onWebsocketStateChange({
-    required FutureOr<void> Function(WebSocketConnectionState) onStateChange,
-  }) => RustLib.instance.api.crateMobileConvexClientOnWebsocketStateChange(
-    that: this,
-    onStateChange: onStateChange,
-  );
-
-  /// Executes a query on the Convex backend.
-  Future<String> query({
-    required String name,
-    required Map<String, String> args,
-  }) => RustLib.instance.api.crateMobileConvexClientQuery(
-    that: this,
-    name: name,
-    args: args,
-  );
-
-  /// Sets authentication token for the client.
-  Future<void> setAuth({String? token}) => RustLib.instance.api
-      .crateMobileConvexClientSetAuth(that: this, token: token);
-
-  /// Sets authentication with automatic token refresh.
-  ///
-  /// The `fetch_token` callback is called:
-  /// - Immediately to get the initial token
-  /// - Automatically when the token is about to expire (60 seconds before expiry)
-  ///
-  /// The `on_auth_change` callback is called whenever auth state changes.
-  ///
-  /// Returns an AuthHandle that can be used to dispose the auth session.
-  Future<AuthHandle> setAuthWithRefresh({
-    required FutureOr<String?> Function() fetchToken,
-    required FutureOr<void> Function(bool) onAuthChange,
-  }) => RustLib.instance.api.crateMobileConvexClientSetAuthWithRefresh(
-    that: this,
-    fetchToken: fetchToken,
-    onAuthChange: onAuthChange,
-  );
-
-  /// Subscribes to real-time updates from a Convex query.
-  Future<SubscriptionHandle> subscribe({
-    required String name,
-    required Map<String, String> args,
-    required FutureOr<void> Function(String) onUpdate,
-    required FutureOr<void> Function(String, String?) onError,
-  }) => RustLib.instance.api.crateMobileConvexClientSubscribe(
-    that: this,
-    name: name,
-    args: args,
-    onUpdate: onUpdate,
-    onError: onError,
-  );
-}
-
-@sealed
-class SubscriptionHandleImpl extends RustOpaque implements SubscriptionHandle {
-  // Not to be used by end users
-  SubscriptionHandleImpl.frbInternalDcoDecode(List<dynamic> wire)
-    : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  SubscriptionHandleImpl.frbInternalSseDecode(
-    BigInt ptr,
-    int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_SubscriptionHandle,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_SubscriptionHandle,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance
-        .api
-        .rust_arc_decrement_strong_count_SubscriptionHandlePtr,
-  );
-
-  /// Cancels the subscription by sending a cancellation signal.
-  void cancel() =>
-      RustLib.instance.api.crateSubscriptionHandleCancel(that: this);
-}
+        )
+        ,
+            constMeta: kCrateMobileConvexClientCachedQueryConstMeta,
+            argValues: [that, name, args],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientCachedQueryConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_cached_query",
+            argNames: ["that", "name", "args"],
+        );
+        
+
+@override int crateMobileConvexClientCancelAllSubscriptions({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientCancelAllSubscriptionsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientCancelAllSubscriptionsConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_cancel_all_subscriptions",
+            argNames: ["that"],
+        );
+        
+
+@override void crateMobileConvexClientClearActionCache({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientClearActionCacheConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientClearActionCacheConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_clear_action_cache",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientClearAuditLog({required MobileConvexClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientClearAuditLogConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientClearAuditLogConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_clear_audit_log",
+            argNames: ["that"],
+        );
+        
+
+@override void crateMobileConvexClientClearFieldEncryption({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientClearFieldEncryptionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientClearFieldEncryptionConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_clear_field_encryption",
+            argNames: ["that"],
+        );
+        
+
+@override void crateMobileConvexClientClearFunctionFault({required MobileConvexClient that , required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientClearFunctionFaultConstMeta,
+            argValues: [that, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientClearFunctionFaultConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_clear_function_fault",
+            argNames: ["that", "name"],
+        );
+        
+
+@override void crateMobileConvexClientClearFunctionFaults({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientClearFunctionFaultsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientClearFunctionFaultsConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_clear_function_faults",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientClearUserData({required MobileConvexClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientClearUserDataConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientClearUserDataConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_clear_user_data",
+            argNames: ["that"],
+        );
+        
+
+@override PlatformInt64 crateMobileConvexClientClockOffsetMs({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_i_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientClockOffsetMsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientClockOffsetMsConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_clock_offset_ms",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientClose({required MobileConvexClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientCloseConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientCloseConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_close",
+            argNames: ["that"],
+        );
+        
+
+@override void crateMobileConvexClientConfigureResultCache({required MobileConvexClient that , required ResultCacheOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_box_autoadd_result_cache_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientConfigureResultCacheConstMeta,
+            argValues: [that, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientConfigureResultCacheConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_configure_result_cache",
+            argNames: ["that", "options"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientConnect({required MobileConvexClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientConnectConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientConnectConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_connect",
+            argNames: ["that"],
+        );
+        
+
+@override MobileConvexClient crateMobileConvexClientConnectPreview({required MobileConvexClient that , required String preview , required String clientId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(preview, serializer);
+sse_encode_String(clientId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientConnectPreviewConstMeta,
+            argValues: [that, preview, clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientConnectPreviewConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_connect_preview",
+            argNames: ["that", "preview", "clientId"],
+        );
+        
+
+@override ConnectionQuality crateMobileConvexClientConnectionQuality({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connection_quality,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientConnectionQualityConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientConnectionQualityConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_connection_quality",
+            argNames: ["that"],
+        );
+        
+
+@override WebSocketConnectionState crateMobileConvexClientConnectionState({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_web_socket_connection_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientConnectionStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientConnectionStateConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_connection_state",
+            argNames: ["that"],
+        );
+        
+
+@override DartLiveness crateMobileConvexClientDartLiveness({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_dart_liveness,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientDartLivenessConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientDartLivenessConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_dart_liveness",
+            argNames: ["that"],
+        );
+        
+
+@override void crateMobileConvexClientDeclareQueryDependencies({required MobileConvexClient that , required String queryName , required List<QueryDependency> dependencies })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(queryName, serializer);
+sse_encode_list_query_dependency(dependencies, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientDeclareQueryDependenciesConstMeta,
+            argValues: [that, queryName, dependencies],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientDeclareQueryDependenciesConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_declare_query_dependencies",
+            argNames: ["that", "queryName", "dependencies"],
+        );
+        
+
+@override void crateMobileConvexClientDisableTraceExport({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientDisableTraceExportConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientDisableTraceExportConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_disable_trace_export",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientDisconnect({required MobileConvexClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientDisconnectConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientDisconnectConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_disconnect",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientDownloadFile({required MobileConvexClient that , required String urlOrStorageId , required String destPath , required FutureOr<void> Function(TransferProgress) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(urlOrStorageId, serializer);
+sse_encode_String(destPath, serializer);
+sse_encode_DartFn_Inputs_transfer_progress_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientDownloadFileConstMeta,
+            argValues: [that, urlOrStorageId, destPath, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientDownloadFileConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_download_file",
+            argNames: ["that", "urlOrStorageId", "destPath", "onProgress"],
+        );
+        
+
+@override int crateMobileConvexClientDuplicateClientCount({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientDuplicateClientCountConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientDuplicateClientCountConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_duplicate_client_count",
+            argNames: ["that"],
+        );
+        
+
+@override void crateMobileConvexClientEnableTraceExport({required MobileConvexClient that , required TraceExportOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_box_autoadd_trace_export_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientEnableTraceExportConstMeta,
+            argValues: [that, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientEnableTraceExportConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_enable_trace_export",
+            argNames: ["that", "options"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientEvents({required MobileConvexClient that , required FutureOr<void> Function(ClientEvent) onEvent })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_client_event_Output_unit_AnyhowException(onEvent, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientEventsConstMeta,
+            argValues: [that, onEvent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientEventsConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_events",
+            argNames: ["that", "onEvent"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientExportAuditLog({required MobileConvexClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientExportAuditLogConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientExportAuditLogConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_export_audit_log",
+            argNames: ["that"],
+        );
+        
+
+@override MobileConvexClient crateMobileConvexClientFromConfig({required String configJson , required String flavor , required String clientId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(configJson, serializer);
+sse_encode_String(flavor, serializer);
+sse_encode_String(clientId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientFromConfigConstMeta,
+            argValues: [configJson, flavor, clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientFromConfigConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_from_config",
+            argNames: ["configJson", "flavor", "clientId"],
+        );
+        
+
+@override String? crateMobileConvexClientGetCached({required MobileConvexClient that , required String name , required Map<String, String> args })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientGetCachedConstMeta,
+            argValues: [that, name, args],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientGetCachedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_get_cached",
+            argNames: ["that", "name", "args"],
+        );
+        
+
+@override Future<HttpActionResponse> crateMobileConvexClientHttpAction({required MobileConvexClient that , required String path , required String method , required Map<String, String> headers , Uint8List? body })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(path, serializer);
+sse_encode_String(method, serializer);
+sse_encode_Map_String_String_None(headers, serializer);
+sse_encode_opt_list_prim_u_8_strict(body, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_http_action_response,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientHttpActionConstMeta,
+            argValues: [that, path, method, headers, body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientHttpActionConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_http_action",
+            argNames: ["that", "path", "method", "headers", "body"],
+        );
+        
+
+@override InitializationDiagnostics crateMobileConvexClientInitializationDiagnostics({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_initialization_diagnostics,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientInitializationDiagnosticsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientInitializationDiagnosticsConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_initialization_diagnostics",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientInvalidateCachedQuery({required MobileConvexClient that , required String queryName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(queryName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientInvalidateCachedQueryConstMeta,
+            argValues: [that, queryName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientInvalidateCachedQueryConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_invalidate_cached_query",
+            argNames: ["that", "queryName"],
+        );
+        
+
+@override bool crateMobileConvexClientIsAuthenticated({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientIsAuthenticatedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientIsAuthenticatedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_is_authenticated",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateMobileConvexClientIsIncognito({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientIsIncognitoConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientIsIncognitoConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_is_incognito",
+            argNames: ["that"],
+        );
+        
+
+@override PlatformInt64? crateMobileConvexClientLastConnectedAtMs({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientLastConnectedAtMsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientLastConnectedAtMsConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_last_connected_at_ms",
+            argNames: ["that"],
+        );
+        
+
+@override String? crateMobileConvexClientLastConnectionError({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientLastConnectionErrorConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientLastConnectionErrorConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_last_connection_error",
+            argNames: ["that"],
+        );
+        
+
+@override Future<LogoutEvent> crateMobileConvexClientLogout({required MobileConvexClient that , required bool clearUserData })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_bool(clearUserData, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_logout_event,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientLogoutConstMeta,
+            argValues: [that, clearUserData],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientLogoutConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_logout",
+            argNames: ["that", "clearUserData"],
+        );
+        
+
+@override ClientMetrics crateMobileConvexClientMetrics({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_client_metrics,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMetricsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMetricsConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_metrics",
+            argNames: ["that"],
+        );
+        
+
+@override MetricsReport crateMobileConvexClientMetricsSnapshot({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_metrics_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMetricsSnapshotConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMetricsSnapshotConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_metrics_snapshot",
+            argNames: ["that"],
+        );
+        
+
+@override Future<MutationSequenceResult> crateMobileConvexClientMutateSequence({required MobileConvexClient that , required List<MutationCall> calls , required bool stopOnError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_list_mutation_call(calls, serializer);
+sse_encode_bool(stopOnError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_mutation_sequence_result,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutateSequenceConstMeta,
+            argValues: [that, calls, stopOnError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutateSequenceConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutate_sequence",
+            argNames: ["that", "calls", "stopOnError"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientMutation({required MobileConvexClient that , required String name , required Map<String, String> args , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutationConstMeta,
+            argValues: [that, name, args, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutationConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutation",
+            argNames: ["that", "name", "args", "timeoutMs"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientMutationCancellable({required MobileConvexClient that , required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(token, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutationCancellableConstMeta,
+            argValues: [that, name, args, token, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutationCancellableConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutation_cancellable",
+            argNames: ["that", "name", "args", "token", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientMutationDeferred({required MobileConvexClient that , required String name , required Map<String, String> args , required int maxDelayMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_u_32(maxDelayMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutationDeferredConstMeta,
+            argValues: [that, name, args, maxDelayMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutationDeferredConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutation_deferred",
+            argNames: ["that", "name", "args", "maxDelayMs"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientMutationDeferredTracked({required MobileConvexClient that , required String mutationId , required String name , required Map<String, String> args , required int maxDelayMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(mutationId, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_u_32(maxDelayMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutationDeferredTrackedConstMeta,
+            argValues: [that, mutationId, name, args, maxDelayMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutationDeferredTrackedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutation_deferred_tracked",
+            argNames: ["that", "mutationId", "name", "args", "maxDelayMs"],
+        );
+        
+
+@override Future<IdempotentMutationResult> crateMobileConvexClientMutationIdempotent({required MobileConvexClient that , required String name , required Map<String, String> args , String? idempotencyKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_opt_String(idempotencyKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_idempotent_mutation_result,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutationIdempotentConstMeta,
+            argValues: [that, name, args, idempotencyKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutationIdempotentConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutation_idempotent",
+            argNames: ["that", "name", "args", "idempotencyKey"],
+        );
+        
+
+@override Future<OfflineMutationResult> crateMobileConvexClientMutationOffline({required MobileConvexClient that , required String name , required Map<String, String> args })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_offline_mutation_result,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutationOfflineConstMeta,
+            argValues: [that, name, args],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutationOfflineConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutation_offline",
+            argNames: ["that", "name", "args"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientMutationOptimistic({required MobileConvexClient that , required String name , required Map<String, String> args , required List<OptimisticUpdate> updates })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_list_optimistic_update(updates, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutationOptimisticConstMeta,
+            argValues: [that, name, args, updates],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutationOptimisticConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutation_optimistic",
+            argNames: ["that", "name", "args", "updates"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientMutationTracked({required MobileConvexClient that , required String mutationId , required String name , required Map<String, String> args , required int maxAttempts })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(mutationId, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_u_32(maxAttempts, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutationTrackedConstMeta,
+            argValues: [that, mutationId, name, args, maxAttempts],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutationTrackedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutation_tracked",
+            argNames: ["that", "mutationId", "name", "args", "maxAttempts"],
+        );
+        
+
+@override Future<ConvexValue> crateMobileConvexClientMutationTyped({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_convex_value_None(args, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_convex_value,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutationTypedConstMeta,
+            argValues: [that, name, args],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutationTypedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutation_typed",
+            argNames: ["that", "name", "args"],
+        );
+        
+
+@override Future<CommittedMutation> crateMobileConvexClientMutationWithCommitToken({required MobileConvexClient that , required String name , required Map<String, String> args })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_committed_mutation,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutationWithCommitTokenConstMeta,
+            argValues: [that, name, args],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutationWithCommitTokenConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutation_with_commit_token",
+            argNames: ["that", "name", "args"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientMutationWithValues({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_convex_value_None(args, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientMutationWithValuesConstMeta,
+            argValues: [that, name, args, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientMutationWithValuesConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_mutation_with_values",
+            argNames: ["that", "name", "args", "timeoutMs"],
+        );
+        
+
+@override MobileConvexClient crateMobileConvexClientNew({required String deploymentUrl , required String clientId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(deploymentUrl, serializer);
+sse_encode_String(clientId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientNewConstMeta,
+            argValues: [deploymentUrl, clientId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientNewConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_new",
+            argNames: ["deploymentUrl", "clientId"],
+        );
+        
+
+@override MobileConvexClient crateMobileConvexClientNewWithOptions({required String deploymentUrl , required String clientId , required ClientOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(deploymentUrl, serializer);
+sse_encode_String(clientId, serializer);
+sse_encode_box_autoadd_client_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientNewWithOptionsConstMeta,
+            argValues: [deploymentUrl, clientId, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientNewWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_new_with_options",
+            argNames: ["deploymentUrl", "clientId", "options"],
+        );
+        
+
+@override Future<MemoryReleasedEvent> crateMobileConvexClientNotifyMemoryPressure({required MobileConvexClient that , required MemoryPressure pressure })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_memory_pressure(pressure, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_memory_released_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientNotifyMemoryPressureConstMeta,
+            argValues: [that, pressure],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientNotifyMemoryPressureConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_notify_memory_pressure",
+            argNames: ["that", "pressure"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnAuthChange({required MobileConvexClient that , required FutureOr<void> Function(AuthChange) onChange })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_auth_change_Output_unit_AnyhowException(onChange, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnAuthChangeConstMeta,
+            argValues: [that, onChange],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnAuthChangeConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_auth_change",
+            argNames: ["that", "onChange"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnBackendChange({required MobileConvexClient that , required FutureOr<void> Function(BackendChangedEvent) onChange })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_backend_changed_event_Output_unit_AnyhowException(onChange, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnBackendChangeConstMeta,
+            argValues: [that, onChange],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnBackendChangeConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_backend_change",
+            argNames: ["that", "onChange"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnBudgetExceeded({required MobileConvexClient that , required FutureOr<void> Function(BudgetExceededEvent) onExceeded })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_budget_exceeded_event_Output_unit_AnyhowException(onExceeded, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnBudgetExceededConstMeta,
+            argValues: [that, onExceeded],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnBudgetExceededConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_budget_exceeded",
+            argNames: ["that", "onExceeded"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnConnectionQualityChange({required MobileConvexClient that , required FutureOr<void> Function(ConnectionQuality) onChange })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_connection_quality_Output_unit_AnyhowException(onChange, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnConnectionQualityChangeConstMeta,
+            argValues: [that, onChange],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnConnectionQualityChangeConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_connection_quality_change",
+            argNames: ["that", "onChange"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnFailover({required MobileConvexClient that , required FutureOr<void> Function(FailoverEvent) onFailover })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_failover_event_Output_unit_AnyhowException(onFailover, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnFailoverConstMeta,
+            argValues: [that, onFailover],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnFailoverConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_failover",
+            argNames: ["that", "onFailover"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnLogout({required MobileConvexClient that , required FutureOr<void> Function(LogoutEvent) onLogout })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_logout_event_Output_unit_AnyhowException(onLogout, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnLogoutConstMeta,
+            argValues: [that, onLogout],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnLogoutConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_logout",
+            argNames: ["that", "onLogout"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnMemoryReleased({required MobileConvexClient that , required FutureOr<void> Function(MemoryReleasedEvent) onReleased })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_memory_released_event_Output_unit_AnyhowException(onReleased, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnMemoryReleasedConstMeta,
+            argValues: [that, onReleased],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnMemoryReleasedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_memory_released",
+            argNames: ["that", "onReleased"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnMutationLifecycle({required MobileConvexClient that , required FutureOr<void> Function(MutationLifecycleEvent) onEvent })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_mutation_lifecycle_event_Output_unit_AnyhowException(onEvent, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnMutationLifecycleConstMeta,
+            argValues: [that, onEvent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnMutationLifecycleConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_mutation_lifecycle",
+            argNames: ["that", "onEvent"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnOutboxCompletion({required MobileConvexClient that , required FutureOr<void> Function(OutboxCompletion) onCompletion })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_outbox_completion_Output_unit_AnyhowException(onCompletion, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnOutboxCompletionConstMeta,
+            argValues: [that, onCompletion],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnOutboxCompletionConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_outbox_completion",
+            argNames: ["that", "onCompletion"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnOutboxConflict({required MobileConvexClient that , required FutureOr<ConflictResolution> Function(OutboxConflict) resolve })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_outbox_conflict_Output_conflict_resolution_AnyhowException(resolve, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnOutboxConflictConstMeta,
+            argValues: [that, resolve],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnOutboxConflictConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_outbox_conflict",
+            argNames: ["that", "resolve"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnSlowInitialization({required MobileConvexClient that , required FutureOr<void> Function(SlowInitializationEvent) onSlow })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_slow_initialization_event_Output_unit_AnyhowException(onSlow, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnSlowInitializationConstMeta,
+            argValues: [that, onSlow],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnSlowInitializationConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_slow_initialization",
+            argNames: ["that", "onSlow"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnUiHint({required MobileConvexClient that , required FutureOr<void> Function(UiHint?) onHint })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_opt_box_autoadd_ui_hint_Output_unit_AnyhowException(onHint, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnUiHintConstMeta,
+            argValues: [that, onHint],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnUiHintConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_ui_hint",
+            argNames: ["that", "onHint"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientOnWebsocketStateChange({required MobileConvexClient that , required FutureOr<void> Function(WebSocketConnectionState) onStateChange })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(onStateChange, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOnWebsocketStateChangeConstMeta,
+            argValues: [that, onStateChange],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOnWebsocketStateChangeConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_on_websocket_state_change",
+            argNames: ["that", "onStateChange"],
+        );
+        
+
+@override Future<int> crateMobileConvexClientOutboxLen({required MobileConvexClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientOutboxLenConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientOutboxLenConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_outbox_len",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientPause({required MobileConvexClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientPauseConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientPauseConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_pause",
+            argNames: ["that"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientQuery({required MobileConvexClient that , required String name , required Map<String, String> args , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientQueryConstMeta,
+            argValues: [that, name, args, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientQueryConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_query",
+            argNames: ["that", "name", "args", "timeoutMs"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientQueryAtLeast({required MobileConvexClient that , required String ts , required String name , required Map<String, String> args })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(ts, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientQueryAtLeastConstMeta,
+            argValues: [that, ts, name, args],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientQueryAtLeastConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_query_at_least",
+            argNames: ["that", "ts", "name", "args"],
+        );
+        
+
+@override Future<List<String>> crateMobileConvexClientQueryBatch({required MobileConvexClient that , required List<BatchQuery> queries , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_list_batch_query(queries, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientQueryBatchConstMeta,
+            argValues: [that, queries, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientQueryBatchConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_query_batch",
+            argNames: ["that", "queries", "timeoutMs"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientQueryCancellable({required MobileConvexClient that , required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(token, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientQueryCancellableConstMeta,
+            argValues: [that, name, args, token, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientQueryCancellableConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_query_cancellable",
+            argNames: ["that", "name", "args", "token", "timeoutMs"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientQueryProjected({required MobileConvexClient that , required String name , required Map<String, String> args , required List<String> projection })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_list_String(projection, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientQueryProjectedConstMeta,
+            argValues: [that, name, args, projection],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientQueryProjectedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_query_projected",
+            argNames: ["that", "name", "args", "projection"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientQueryStreamed({required MobileConvexClient that , required String name , required Map<String, String> args , required FutureOr<void> Function(ResultChunk) onChunk , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_DartFn_Inputs_result_chunk_Output_unit_AnyhowException(onChunk, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientQueryStreamedConstMeta,
+            argValues: [that, name, args, onChunk, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientQueryStreamedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_query_streamed",
+            argNames: ["that", "name", "args", "onChunk", "timeoutMs"],
+        );
+        
+
+@override Future<ConvexValue> crateMobileConvexClientQueryTyped({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_convex_value_None(args, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_convex_value,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientQueryTypedConstMeta,
+            argValues: [that, name, args],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientQueryTypedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_query_typed",
+            argNames: ["that", "name", "args"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientQueryWithValues({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_convex_value_None(args, serializer);
+sse_encode_opt_box_autoadd_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientQueryWithValuesConstMeta,
+            argValues: [that, name, args, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientQueryWithValuesConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_query_with_values",
+            argNames: ["that", "name", "args", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientReconnect({required MobileConvexClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientReconnectConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientReconnectConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_reconnect",
+            argNames: ["that"],
+        );
+        
+
+@override void crateMobileConvexClientRegisterShardMapping({required MobileConvexClient that , required String queryName , required ShardMapping mapping })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(queryName, serializer);
+sse_encode_box_autoadd_shard_mapping(mapping, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientRegisterShardMappingConstMeta,
+            argValues: [that, queryName, mapping],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientRegisterShardMappingConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_register_shard_mapping",
+            argNames: ["that", "queryName", "mapping"],
+        );
+        
+
+@override bool crateMobileConvexClientRemoveInterceptor({required MobileConvexClient that , required BigInt id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_u_64(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientRemoveInterceptorConstMeta,
+            argValues: [that, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientRemoveInterceptorConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_remove_interceptor",
+            argNames: ["that", "id"],
+        );
+        
+
+@override void crateMobileConvexClientRemoveLogListener({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientRemoveLogListenerConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientRemoveLogListenerConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_remove_log_listener",
+            argNames: ["that"],
+        );
+        
+
+@override void crateMobileConvexClientRemovePanicReporter({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientRemovePanicReporterConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientRemovePanicReporterConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_remove_panic_reporter",
+            argNames: ["that"],
+        );
+        
+
+@override String? crateMobileConvexClientReplicaGet({required MobileConvexClient that , required String table , required String id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(table, serializer);
+sse_encode_String(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientReplicaGetConstMeta,
+            argValues: [that, table, id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientReplicaGetConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_replica_get",
+            argNames: ["that", "table", "id"],
+        );
+        
+
+@override List<String> crateMobileConvexClientReplicaQuery({required MobileConvexClient that , required String table , required Map<String, String> filter })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(table, serializer);
+sse_encode_Map_String_String_None(filter, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientReplicaQueryConstMeta,
+            argValues: [that, table, filter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientReplicaQueryConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_replica_query",
+            argNames: ["that", "table", "filter"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientReplicateQuery({required MobileConvexClient that , required String table , required String queryName , required Map<String, String> queryArgs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(table, serializer);
+sse_encode_String(queryName, serializer);
+sse_encode_Map_String_String_None(queryArgs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientReplicateQueryConstMeta,
+            argValues: [that, table, queryName, queryArgs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientReplicateQueryConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_replicate_query",
+            argNames: ["that", "table", "queryName", "queryArgs"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientResume({required MobileConvexClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientResumeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientResumeConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_resume",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientSetAuth({required MobileConvexClient that , String? token })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_opt_String(token, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetAuthConstMeta,
+            argValues: [that, token],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetAuthConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_auth",
+            argNames: ["that", "token"],
+        );
+        
+
+@override Future<AuthHandle> crateMobileConvexClientSetAuthWithRefresh({required MobileConvexClient that , required FutureOr<String?> Function() fetchToken , required FutureOr<void> Function(bool) onAuthChange })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs__Output_opt_String_AnyhowException(fetchToken, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onAuthChange, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetAuthWithRefreshConstMeta,
+            argValues: [that, fetchToken, onAuthChange],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetAuthWithRefreshConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_auth_with_refresh",
+            argNames: ["that", "fetchToken", "onAuthChange"],
+        );
+        
+
+@override Future<AuthHandle> crateMobileConvexClientSetAuthWithRefreshConfig({required MobileConvexClient that , required AuthRefreshConfig config , required FutureOr<String?> Function() fetchToken , required FutureOr<void> Function(bool) onAuthChange })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_box_autoadd_auth_refresh_config(config, serializer);
+sse_encode_DartFn_Inputs__Output_opt_String_AnyhowException(fetchToken, serializer);
+sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(onAuthChange, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetAuthWithRefreshConfigConstMeta,
+            argValues: [that, config, fetchToken, onAuthChange],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetAuthWithRefreshConfigConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_auth_with_refresh_config",
+            argNames: ["that", "config", "fetchToken", "onAuthChange"],
+        );
+        
+
+@override void crateMobileConvexClientSetFieldEncryption({required MobileConvexClient that , required List<int> key , required List<String> fields })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+sse_encode_list_String(fields, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetFieldEncryptionConstMeta,
+            argValues: [that, key, fields],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetFieldEncryptionConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_field_encryption",
+            argNames: ["that", "key", "fields"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientSetFunctionFault({required MobileConvexClient that , required String name , required FunctionFault fault })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_box_autoadd_function_fault(fault, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetFunctionFaultConstMeta,
+            argValues: [that, name, fault],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetFunctionFaultConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_function_fault",
+            argNames: ["that", "name", "fault"],
+        );
+        
+
+@override void crateMobileConvexClientSetLogFile({required MobileConvexClient that , String? path , required LogLevel level })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_opt_String(path, serializer);
+sse_encode_log_level(level, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetLogFileConstMeta,
+            argValues: [that, path, level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetLogFileConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_log_file",
+            argNames: ["that", "path", "level"],
+        );
+        
+
+@override void crateMobileConvexClientSetLogLevel({required MobileConvexClient that , required LogLevel level })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_log_level(level, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetLogLevelConstMeta,
+            argValues: [that, level],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetLogLevelConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_log_level",
+            argNames: ["that", "level"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientSetLogListener({required MobileConvexClient that , required LogLevel level , required FutureOr<void> Function(LogRecord) onRecord })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_log_level(level, serializer);
+sse_encode_DartFn_Inputs_log_record_Output_unit_AnyhowException(onRecord, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetLogListenerConstMeta,
+            argValues: [that, level, onRecord],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetLogListenerConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_log_listener",
+            argNames: ["that", "level", "onRecord"],
+        );
+        
+
+@override Future<void> crateMobileConvexClientSetPanicReporter({required MobileConvexClient that , required FutureOr<void> Function(PanicReport) onPanic })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_DartFn_Inputs_panic_report_Output_unit_AnyhowException(onPanic, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetPanicReporterConstMeta,
+            argValues: [that, onPanic],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetPanicReporterConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_panic_reporter",
+            argNames: ["that", "onPanic"],
+        );
+        
+
+@override void crateMobileConvexClientSetStorageKey({required MobileConvexClient that , required List<int> key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_list_prim_u_8_loose(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetStorageKeyConstMeta,
+            argValues: [that, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetStorageKeyConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_storage_key",
+            argNames: ["that", "key"],
+        );
+        
+
+@override void crateMobileConvexClientSetTelemetrySampling({required MobileConvexClient that , required TelemetrySampling sampling })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_box_autoadd_telemetry_sampling(sampling, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetTelemetrySamplingConstMeta,
+            argValues: [that, sampling],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetTelemetrySamplingConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_telemetry_sampling",
+            argNames: ["that", "sampling"],
+        );
+        
+
+@override void crateMobileConvexClientSetUiPressure({required MobileConvexClient that , required UiPressure pressure })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_ui_pressure(pressure, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSetUiPressureConstMeta,
+            argValues: [that, pressure],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSetUiPressureConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_set_ui_pressure",
+            argNames: ["that", "pressure"],
+        );
+        
+
+@override ShardingMetrics crateMobileConvexClientShardingMetrics({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_sharding_metrics,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientShardingMetricsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientShardingMetricsConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_sharding_metrics",
+            argNames: ["that"],
+        );
+        
+
+@override Future<PresenceHandle> crateMobileConvexClientStartPresence({required MobileConvexClient that , required String mutationName , required Map<String, String> args , required BigInt intervalMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(mutationName, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_u_64(intervalMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientStartPresenceConstMeta,
+            argValues: [that, mutationName, args, intervalMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientStartPresenceConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_start_presence",
+            argNames: ["that", "mutationName", "args", "intervalMs"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientStorageDirectory({required MobileConvexClient that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientStorageDirectoryConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientStorageDirectoryConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_storage_directory",
+            argNames: ["that"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientSubscribe({required MobileConvexClient that , required String name , required Map<String, String> args , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError , required FutureOr<void> Function() onDone , SubscribeOptions? options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onUpdate, serializer);
+sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(onError, serializer);
+sse_encode_DartFn_Inputs__Output_unit_AnyhowException(onDone, serializer);
+sse_encode_opt_box_autoadd_subscribe_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribeConstMeta,
+            argValues: [that, name, args, onUpdate, onError, onDone, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribeConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe",
+            argNames: ["that", "name", "args", "onUpdate", "onError", "onDone", "options"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientSubscribeChunked({required MobileConvexClient that , required String name , required Map<String, String> args , required FutureOr<void> Function(ResultChunk) onChunk , required FutureOr<void> Function(String, String?) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_DartFn_Inputs_result_chunk_Output_unit_AnyhowException(onChunk, serializer);
+sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribeChunkedConstMeta,
+            argValues: [that, name, args, onChunk, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribeChunkedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_chunked",
+            argNames: ["that", "name", "args", "onChunk", "onError"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientSubscribeCombined({required MobileConvexClient that , required List<DerivedSource> sources , required CombineStrategy strategy , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_list_derived_source(sources, serializer);
+sse_encode_box_autoadd_combine_strategy(strategy, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onUpdate, serializer);
+sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribeCombinedConstMeta,
+            argValues: [that, sources, strategy, onUpdate, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribeCombinedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_combined",
+            argNames: ["that", "sources", "strategy", "onUpdate", "onError"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientSubscribeKeyed({required MobileConvexClient that , required String key , required String name , required Map<String, String> args , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(key, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onUpdate, serializer);
+sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribeKeyedConstMeta,
+            argValues: [that, key, name, args, onUpdate, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribeKeyedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_keyed",
+            argNames: ["that", "key", "name", "args", "onUpdate", "onError"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientSubscribeList({required MobileConvexClient that , required String name , required Map<String, String> args , required String idField , required FutureOr<void> Function(ListUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_String(idField, serializer);
+sse_encode_DartFn_Inputs_list_update_Output_unit_AnyhowException(onUpdate, serializer);
+sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribeListConstMeta,
+            argValues: [that, name, args, idField, onUpdate, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribeListConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_list",
+            argNames: ["that", "name", "args", "idField", "onUpdate", "onError"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientSubscribeOnce({required MobileConvexClient that , required String name , required Map<String, String> args })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribeOnceConstMeta,
+            argValues: [that, name, args],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribeOnceConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_once",
+            argNames: ["that", "name", "args"],
+        );
+        
+
+@override Future<PaginatedQueryHandle> crateMobileConvexClientSubscribePaginated({required MobileConvexClient that , required String name , required Map<String, String> args , required int initialNumItems , required FutureOr<void> Function(PaginatedUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_u_32(initialNumItems, serializer);
+sse_encode_DartFn_Inputs_paginated_update_Output_unit_AnyhowException(onUpdate, serializer);
+sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribePaginatedConstMeta,
+            argValues: [that, name, args, initialNumItems, onUpdate, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribePaginatedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_paginated",
+            argNames: ["that", "name", "args", "initialNumItems", "onUpdate", "onError"],
+        );
+        
+
+@override Future<PaginatedQueryHandle> crateMobileConvexClientSubscribePaginatedFrom({required MobileConvexClient that , required String name , required Map<String, String> args , required List<String> pageBoundaries , required int numItems , required FutureOr<void> Function(PaginatedUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_list_String(pageBoundaries, serializer);
+sse_encode_u_32(numItems, serializer);
+sse_encode_DartFn_Inputs_paginated_update_Output_unit_AnyhowException(onUpdate, serializer);
+sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribePaginatedFromConstMeta,
+            argValues: [that, name, args, pageBoundaries, numItems, onUpdate, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribePaginatedFromConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_paginated_from",
+            argNames: ["that", "name", "args", "pageBoundaries", "numItems", "onUpdate", "onError"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientSubscribePresence({required MobileConvexClient that , required String name , required Map<String, String> args , required PresenceOptions options , required FutureOr<void> Function(List<PresenceStatus>) onUpdate , required FutureOr<void> Function(String, String?) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_box_autoadd_presence_options(options, serializer);
+sse_encode_DartFn_Inputs_list_presence_status_Output_unit_AnyhowException(onUpdate, serializer);
+sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribePresenceConstMeta,
+            argValues: [that, name, args, options, onUpdate, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribePresenceConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_presence",
+            argNames: ["that", "name", "args", "options", "onUpdate", "onError"],
+        );
+        
+
+@override Stream<String> crateMobileConvexClientSubscribeStream({required MobileConvexClient that , required String name , required Map<String, String> args })  { 
+            final sink = RustStreamSink<String>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_StreamSink_String_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribeStreamConstMeta,
+            argValues: [that, name, args, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribeStreamConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_stream",
+            argNames: ["that", "name", "args", "sink"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientSubscribeTyped({required MobileConvexClient that , required String name , required Map<String, ConvexValue> args , required FutureOr<void> Function(ConvexValue) onUpdate , required FutureOr<void> Function(String, String?) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_convex_value_None(args, serializer);
+sse_encode_DartFn_Inputs_convex_value_Output_unit_AnyhowException(onUpdate, serializer);
+sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribeTypedConstMeta,
+            argValues: [that, name, args, onUpdate, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribeTypedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_typed",
+            argNames: ["that", "name", "args", "onUpdate", "onError"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientSubscribeWithEvents({required MobileConvexClient that , required String name , required Map<String, String> args , required FutureOr<void> Function(SubscriptionEvent) onEvent , SubscribeOptions? options })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_DartFn_Inputs_subscription_event_Output_unit_AnyhowException(onEvent, serializer);
+sse_encode_opt_box_autoadd_subscribe_options(options, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribeWithEventsConstMeta,
+            argValues: [that, name, args, onEvent, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribeWithEventsConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_with_events",
+            argNames: ["that", "name", "args", "onEvent", "options"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientSubscribeWithPatches({required MobileConvexClient that , required String name , required Map<String, String> args , required int snapshotInterval , required FutureOr<void> Function(PatchUpdate) onPatch , required FutureOr<void> Function(String, String?) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_u_32(snapshotInterval, serializer);
+sse_encode_DartFn_Inputs_patch_update_Output_unit_AnyhowException(onPatch, serializer);
+sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientSubscribeWithPatchesConstMeta,
+            argValues: [that, name, args, snapshotInterval, onPatch, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientSubscribeWithPatchesConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_subscribe_with_patches",
+            argNames: ["that", "name", "args", "snapshotInterval", "onPatch", "onError"],
+        );
+        
+
+@override UiHint? crateMobileConvexClientUiHint({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_ui_hint,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientUiHintConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientUiHintConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_ui_hint",
+            argNames: ["that"],
+        );
+        
+
+@override UiPressure crateMobileConvexClientUiPressure({required MobileConvexClient that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_pressure,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientUiPressureConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientUiPressureConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_ui_pressure",
+            argNames: ["that"],
+        );
+        
+
+@override void crateMobileConvexClientUnsubscribeKeyed({required MobileConvexClient that , required String key })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(key, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientUnsubscribeKeyedConstMeta,
+            argValues: [that, key],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientUnsubscribeKeyedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_unsubscribe_keyed",
+            argNames: ["that", "key"],
+        );
+        
+
+@override Future<String> crateMobileConvexClientUploadFile({required MobileConvexClient that , required UploadSource source , required String mimeType , required FutureOr<void> Function(TransferProgress) onProgress })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_box_autoadd_upload_source(source, serializer);
+sse_encode_String(mimeType, serializer);
+sse_encode_DartFn_Inputs_transfer_progress_Output_unit_AnyhowException(onProgress, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientUploadFileConstMeta,
+            argValues: [that, source, mimeType, onProgress],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientUploadFileConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_upload_file",
+            argNames: ["that", "source", "mimeType", "onProgress"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientWatch({required MobileConvexClient that , required String name , required Map<String, String> args , required PlaceholderPolicy placeholder , required FutureOr<void> Function(WatchEvent) onEvent })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_String(name, serializer);
+sse_encode_Map_String_String_None(args, serializer);
+sse_encode_placeholder_policy(placeholder, serializer);
+sse_encode_DartFn_Inputs_watch_event_Output_unit_AnyhowException(onEvent, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientWatchConstMeta,
+            argValues: [that, name, args, placeholder, onEvent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientWatchConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_watch",
+            argNames: ["that", "name", "args", "placeholder", "onEvent"],
+        );
+        
+
+@override Future<SubscriptionHandle> crateMobileConvexClientWatchDerived({required MobileConvexClient that , required List<DerivedSource> sources , required List<DerivedField> fields , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(that, serializer);
+sse_encode_list_derived_source(sources, serializer);
+sse_encode_list_derived_field(fields, serializer);
+sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(onUpdate, serializer);
+sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(onError, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle,
+          decodeErrorData: sse_decode_client_error,
+        )
+        ,
+            constMeta: kCrateMobileConvexClientWatchDerivedConstMeta,
+            argValues: [that, sources, fields, onUpdate, onError],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateMobileConvexClientWatchDerivedConstMeta => const TaskConstMeta(
+            debugName: "MobileConvexClient_watch_derived",
+            argNames: ["that", "sources", "fields", "onUpdate", "onError"],
+        );
+        
+
+@override void cratePaginationPaginatedQueryHandleCancel({required PaginatedQueryHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePaginationPaginatedQueryHandleCancelConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePaginationPaginatedQueryHandleCancelConstMeta => const TaskConstMeta(
+            debugName: "PaginatedQueryHandle_cancel",
+            argNames: ["that"],
+        );
+        
+
+@override bool cratePaginationPaginatedQueryHandleLoadMore({required PaginatedQueryHandle that , required int numItems })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(that, serializer);
+sse_encode_u_32(numItems, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePaginationPaginatedQueryHandleLoadMoreConstMeta,
+            argValues: [that, numItems],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePaginationPaginatedQueryHandleLoadMoreConstMeta => const TaskConstMeta(
+            debugName: "PaginatedQueryHandle_load_more",
+            argNames: ["that", "numItems"],
+        );
+        
+
+@override PaginationStatus cratePaginationPaginatedQueryHandleStatus({required PaginatedQueryHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pagination_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePaginationPaginatedQueryHandleStatusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePaginationPaginatedQueryHandleStatusConstMeta => const TaskConstMeta(
+            debugName: "PaginatedQueryHandle_status",
+            argNames: ["that"],
+        );
+        
+
+@override bool cratePresencePresenceHandleIsPaused({required PresenceHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePresencePresenceHandleIsPausedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePresencePresenceHandleIsPausedConstMeta => const TaskConstMeta(
+            debugName: "PresenceHandle_is_paused",
+            argNames: ["that"],
+        );
+        
+
+@override void cratePresencePresenceHandlePause({required PresenceHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePresencePresenceHandlePauseConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePresencePresenceHandlePauseConstMeta => const TaskConstMeta(
+            debugName: "PresenceHandle_pause",
+            argNames: ["that"],
+        );
+        
+
+@override void cratePresencePresenceHandleResume({required PresenceHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePresencePresenceHandleResumeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePresencePresenceHandleResumeConstMeta => const TaskConstMeta(
+            debugName: "PresenceHandle_resume",
+            argNames: ["that"],
+        );
+        
+
+@override void cratePresencePresenceHandleStop({required PresenceHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePresencePresenceHandleStopConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePresencePresenceHandleStopConstMeta => const TaskConstMeta(
+            debugName: "PresenceHandle_stop",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateSubscriptionHandleCancel({required SubscriptionHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSubscriptionHandleCancelConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSubscriptionHandleCancelConstMeta => const TaskConstMeta(
+            debugName: "SubscriptionHandle_cancel",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateSubscriptionHandleIsActive({required SubscriptionHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSubscriptionHandleIsActiveConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSubscriptionHandleIsActiveConstMeta => const TaskConstMeta(
+            debugName: "SubscriptionHandle_is_active",
+            argNames: ["that"],
+        );
+        
+
+@override void crateSubscriptionHandleSetPriority({required SubscriptionHandle that , required SubscriptionPriority priority })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(that, serializer);
+sse_encode_subscription_priority(priority, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSubscriptionHandleSetPriorityConstMeta,
+            argValues: [that, priority],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSubscriptionHandleSetPriorityConstMeta => const TaskConstMeta(
+            debugName: "SubscriptionHandle_set_priority",
+            argNames: ["that", "priority"],
+        );
+        
+
+@override SubscriptionState crateSubscriptionHandleState({required SubscriptionHandle that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_subscription_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSubscriptionHandleStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSubscriptionHandleStateConstMeta => const TaskConstMeta(
+            debugName: "SubscriptionHandle_state",
+            argNames: ["that"],
+        );
+        
+
+@override Future<AuthRefreshConfig> crateAuthRefreshAuthRefreshConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_auth_refresh_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateAuthRefreshAuthRefreshConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateAuthRefreshAuthRefreshConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "auth_refresh_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<BlankArgHandling> crateOptionsBlankArgHandlingDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_blank_arg_handling,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateOptionsBlankArgHandlingDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateOptionsBlankArgHandlingDefaultConstMeta => const TaskConstMeta(
+            debugName: "blank_arg_handling_default",
+            argNames: [],
+        );
+        
+
+@override Future<BudgetEnforcement> crateBudgetBudgetEnforcementDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_budget_enforcement,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBudgetBudgetEnforcementDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBudgetBudgetEnforcementDefaultConstMeta => const TaskConstMeta(
+            debugName: "budget_enforcement_default",
+            argNames: [],
+        );
+        
+
+@override Future<ChunkedResultOptions> crateChunkedChunkedResultOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_chunked_result_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateChunkedChunkedResultOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateChunkedChunkedResultOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "chunked_result_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<ClientOptions> crateOptionsClientOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_client_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateOptionsClientOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateOptionsClientOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "client_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<ConnectRetryOptions> crateConnectionConnectRetryOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connect_retry_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateConnectionConnectRetryOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateConnectionConnectRetryOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "connect_retry_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<DartKeepaliveOptions> crateSupervisorDartKeepaliveOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_dart_keepalive_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSupervisorDartKeepaliveOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSupervisorDartKeepaliveOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "dart_keepalive_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<DeferredMutationOptions> crateDeferredDeferredMutationOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_deferred_mutation_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateDeferredDeferredMutationOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateDeferredDeferredMutationOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "deferred_mutation_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<FileStorageOptions> crateFileStorageFileStorageOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_file_storage_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateFileStorageFileStorageOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFileStorageFileStorageOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "file_storage_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<FunctionFault> crateFaultsFunctionFaultDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_function_fault,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateFaultsFunctionFaultDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateFaultsFunctionFaultDefaultConstMeta => const TaskConstMeta(
+            debugName: "function_fault_default",
+            argNames: [],
+        );
+        
+
+@override Future<Int64Encoding> crateOptionsInt64EncodingDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_int_64_encoding,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateOptionsInt64EncodingDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateOptionsInt64EncodingDefaultConstMeta => const TaskConstMeta(
+            debugName: "int_64_encoding_default",
+            argNames: [],
+        );
+        
+
+@override Future<MutationRetryOptions> crateRetryMutationRetryOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_mutation_retry_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateRetryMutationRetryOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRetryMutationRetryOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "mutation_retry_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<NullHandling> crateOptionsNullHandlingDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_null_handling,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateOptionsNullHandlingDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateOptionsNullHandlingDefaultConstMeta => const TaskConstMeta(
+            debugName: "null_handling_default",
+            argNames: [],
+        );
+        
+
+@override Future<PersistedResultOptions> cratePersistedResultsPersistedResultOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_persisted_result_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePersistedResultsPersistedResultOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePersistedResultsPersistedResultOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "persisted_result_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<PlaceholderPolicy> cratePlaceholderPlaceholderPolicyDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_placeholder_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePlaceholderPlaceholderPolicyDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePlaceholderPlaceholderPolicyDefaultConstMeta => const TaskConstMeta(
+            debugName: "placeholder_policy_default",
+            argNames: [],
+        );
+        
+
+@override Future<PressureThrottle> cratePressurePressureThrottleDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_pressure_throttle,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePressurePressureThrottleDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePressurePressureThrottleDefaultConstMeta => const TaskConstMeta(
+            debugName: "pressure_throttle_default",
+            argNames: [],
+        );
+        
+
+@override Future<PreviewOptions> cratePreviewPreviewOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_preview_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePreviewPreviewOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePreviewPreviewOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "preview_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<RequestIdOptions> crateRequestIdsRequestIdOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_request_id_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateRequestIdsRequestIdOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateRequestIdsRequestIdOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "request_id_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<ResultCacheOptions> cratePlaceholderResultCacheOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_result_cache_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePlaceholderResultCacheOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePlaceholderResultCacheOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "result_cache_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<ShardingMetrics> crateShardingShardingMetricsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_sharding_metrics,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateShardingShardingMetricsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateShardingShardingMetricsDefaultConstMeta => const TaskConstMeta(
+            debugName: "sharding_metrics_default",
+            argNames: [],
+        );
+        
+
+@override Future<SubscribeOptions> crateSubscriptionSubscribeOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_subscribe_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSubscriptionSubscribeOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSubscriptionSubscribeOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "subscribe_options_default",
+            argNames: [],
+        );
+        
+
+@override Future<SubscriptionPriority> crateResubscribeSubscriptionPriorityDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_subscription_priority,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateResubscribeSubscriptionPriorityDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateResubscribeSubscriptionPriorityDefaultConstMeta => const TaskConstMeta(
+            debugName: "subscription_priority_default",
+            argNames: [],
+        );
+        
+
+@override Future<TelemetrySampling> crateSamplingTelemetrySamplingDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_telemetry_sampling,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateSamplingTelemetrySamplingDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateSamplingTelemetrySamplingDefaultConstMeta => const TaskConstMeta(
+            debugName: "telemetry_sampling_default",
+            argNames: [],
+        );
+        
+
+@override Future<UiPressure> cratePressureUiPressureDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ui_pressure,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCratePressureUiPressureDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCratePressureUiPressureDefaultConstMeta => const TaskConstMeta(
+            debugName: "ui_pressure_default",
+            argNames: [],
+        );
+        
+
+@override Future<UsageBudget> crateBudgetUsageBudgetDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_usage_budget,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateBudgetUsageBudgetDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateBudgetUsageBudgetDefaultConstMeta => const TaskConstMeta(
+            debugName: "usage_budget_default",
+            argNames: [],
+        );
+        
+
+
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_String(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String?) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_String(rawArg0);
+final arg1 = dco_decode_opt_String(rawArg1);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, )
+                encode_DartFn_Inputs__Output_opt_String_AnyhowException(FutureOr<String?> Function() raw) {
+              return (callId, ) async {
+                
+
+                Box<String?>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw());
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_opt_String(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, )
+                encode_DartFn_Inputs__Output_unit_AnyhowException(FutureOr<void> Function() raw) {
+              return (callId, ) async {
+                
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw());
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_auth_change_Output_unit_AnyhowException(FutureOr<void> Function(AuthChange) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_auth_change(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_backend_changed_event_Output_unit_AnyhowException(FutureOr<void> Function(BackendChangedEvent) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_backend_changed_event(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_bool_Output_unit_AnyhowException(FutureOr<void> Function(bool) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_bool(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_budget_exceeded_event_Output_unit_AnyhowException(FutureOr<void> Function(BudgetExceededEvent) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_budget_exceeded_event(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_client_event_Output_unit_AnyhowException(FutureOr<void> Function(ClientEvent) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_client_event(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_connection_quality_Output_unit_AnyhowException(FutureOr<void> Function(ConnectionQuality) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_connection_quality(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_convex_value_Output_unit_AnyhowException(FutureOr<void> Function(ConvexValue) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_convex_value(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_failover_event_Output_unit_AnyhowException(FutureOr<void> Function(FailoverEvent) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_failover_event(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_intercepted_call_Output_intercept_decision_AnyhowException(FutureOr<InterceptDecision> Function(InterceptedCall) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_intercepted_call(rawArg0);
+
+
+                Box<InterceptDecision>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_intercept_decision(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic, dynamic)
+                encode_DartFn_Inputs_intercepted_call_call_outcome_Output_call_outcome_AnyhowException(FutureOr<CallOutcome> Function(InterceptedCall, CallOutcome) raw) {
+              return (callId, rawArg0, rawArg1) async {
+                final arg0 = dco_decode_intercepted_call(rawArg0);
+final arg1 = dco_decode_call_outcome(rawArg1);
+
+
+                Box<CallOutcome>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0, arg1));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_call_outcome(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_job_progress_Output_unit_AnyhowException(FutureOr<void> Function(JobProgress) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_job_progress(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_list_presence_status_Output_unit_AnyhowException(FutureOr<void> Function(List<PresenceStatus>) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_list_presence_status(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_list_update_Output_unit_AnyhowException(FutureOr<void> Function(ListUpdate) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_list_update(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_log_record_Output_unit_AnyhowException(FutureOr<void> Function(LogRecord) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_log_record(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_logout_event_Output_unit_AnyhowException(FutureOr<void> Function(LogoutEvent) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_logout_event(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_memory_released_event_Output_unit_AnyhowException(FutureOr<void> Function(MemoryReleasedEvent) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_memory_released_event(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_mutation_lifecycle_event_Output_unit_AnyhowException(FutureOr<void> Function(MutationLifecycleEvent) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_mutation_lifecycle_event(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_opt_box_autoadd_ui_hint_Output_unit_AnyhowException(FutureOr<void> Function(UiHint?) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_opt_box_autoadd_ui_hint(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_outbox_completion_Output_unit_AnyhowException(FutureOr<void> Function(OutboxCompletion) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_outbox_completion(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_outbox_conflict_Output_conflict_resolution_AnyhowException(FutureOr<ConflictResolution> Function(OutboxConflict) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_outbox_conflict(rawArg0);
+
+
+                Box<ConflictResolution>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_conflict_resolution(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_paginated_update_Output_unit_AnyhowException(FutureOr<void> Function(PaginatedUpdate) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_paginated_update(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_panic_report_Output_unit_AnyhowException(FutureOr<void> Function(PanicReport) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_panic_report(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_patch_update_Output_unit_AnyhowException(FutureOr<void> Function(PatchUpdate) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_patch_update(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_result_chunk_Output_unit_AnyhowException(FutureOr<void> Function(ResultChunk) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_result_chunk(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_slow_initialization_event_Output_unit_AnyhowException(FutureOr<void> Function(SlowInitializationEvent) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_slow_initialization_event(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_subscription_event_Output_unit_AnyhowException(FutureOr<void> Function(SubscriptionEvent) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_subscription_event(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_transfer_progress_Output_unit_AnyhowException(FutureOr<void> Function(TransferProgress) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_transfer_progress(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_watch_event_Output_unit_AnyhowException(FutureOr<void> Function(WatchEvent) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_watch_event(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            
+            Future<void> Function(int, dynamic)
+                encode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(FutureOr<void> Function(WebSocketConnectionState) raw) {
+              return (callId, rawArg0) async {
+                final arg0 = dco_decode_web_socket_connection_state(rawArg0);
+
+
+                Box<void>? rawOutput;
+                Box<AnyhowException>? rawError;
+                try {
+                    rawOutput = Box(await raw(arg0));
+                } catch (e, s) {
+                    rawError = Box(AnyhowException("$e\n\n$s"));
+                }
+
+                final serializer = SseSerializer(generalizedFrbRustBinding);
+                assert((rawOutput != null) ^ (rawError != null));
+                if (rawOutput != null) {
+                    serializer.buffer.putUint8(0);
+                    sse_encode_unit(rawOutput.value, serializer);
+                } else {
+                    serializer.buffer.putUint8(1);
+                    sse_encode_AnyhowException(rawError!.value, serializer);
+                }
+                final output = serializer.intoRaw();
+
+                generalizedFrbRustBinding.dartFnDeliverOutput(
+                  callId: callId, ptr: output.ptr, rustVecLen: output.rustVecLen, dataLen: output.dataLen);
+              };
+            }
+            RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_AuthHandle => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_AuthHandle => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CallbackSubscriber => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CallbackSubscriber => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CallbackSubscriberDartFn => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CallbackSubscriberDartFn => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CancellationToken => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CancellationToken => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_MobileConvexClient => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_MobileConvexClient => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PaginatedQueryHandle => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PaginatedQueryHandle => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PresenceHandle => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PresenceHandle => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SubscriptionHandle => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SubscriptionHandle => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected AuthHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AuthHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CallbackSubscriber dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CallbackSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CallbackSubscriberDartFn dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CallbackSubscriberDartFnImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CancellationToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MobileConvexClient dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MobileConvexClientImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PaginatedQueryHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PaginatedQueryHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PresenceHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PresenceHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SubscriptionHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SubscriptionHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected AuthHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AuthHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CallbackSubscriber dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CallbackSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CallbackSubscriberDartFn dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CallbackSubscriberDartFnImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CancellationToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MobileConvexClient dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MobileConvexClientImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PaginatedQueryHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PaginatedQueryHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PresenceHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PresenceHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SubscriptionHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SubscriptionHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected FutureOr<void> Function(String) dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(String, String?) dco_decode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<String?> Function() dco_decode_DartFn_Inputs__Output_opt_String_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function() dco_decode_DartFn_Inputs__Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(AuthChange) dco_decode_DartFn_Inputs_auth_change_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(BackendChangedEvent) dco_decode_DartFn_Inputs_backend_changed_event_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(bool) dco_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(BudgetExceededEvent) dco_decode_DartFn_Inputs_budget_exceeded_event_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(ClientEvent) dco_decode_DartFn_Inputs_client_event_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(ConnectionQuality) dco_decode_DartFn_Inputs_connection_quality_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(ConvexValue) dco_decode_DartFn_Inputs_convex_value_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(FailoverEvent) dco_decode_DartFn_Inputs_failover_event_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<InterceptDecision> Function(InterceptedCall) dco_decode_DartFn_Inputs_intercepted_call_Output_intercept_decision_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<CallOutcome> Function(InterceptedCall, CallOutcome) dco_decode_DartFn_Inputs_intercepted_call_call_outcome_Output_call_outcome_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(JobProgress) dco_decode_DartFn_Inputs_job_progress_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(List<PresenceStatus>) dco_decode_DartFn_Inputs_list_presence_status_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(ListUpdate) dco_decode_DartFn_Inputs_list_update_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(LogRecord) dco_decode_DartFn_Inputs_log_record_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(LogoutEvent) dco_decode_DartFn_Inputs_logout_event_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(MemoryReleasedEvent) dco_decode_DartFn_Inputs_memory_released_event_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(MutationLifecycleEvent) dco_decode_DartFn_Inputs_mutation_lifecycle_event_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(UiHint?) dco_decode_DartFn_Inputs_opt_box_autoadd_ui_hint_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(OutboxCompletion) dco_decode_DartFn_Inputs_outbox_completion_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<ConflictResolution> Function(OutboxConflict) dco_decode_DartFn_Inputs_outbox_conflict_Output_conflict_resolution_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(PaginatedUpdate) dco_decode_DartFn_Inputs_paginated_update_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(PanicReport) dco_decode_DartFn_Inputs_panic_report_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(PatchUpdate) dco_decode_DartFn_Inputs_patch_update_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(ResultChunk) dco_decode_DartFn_Inputs_result_chunk_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(SlowInitializationEvent) dco_decode_DartFn_Inputs_slow_initialization_event_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(SubscriptionEvent) dco_decode_DartFn_Inputs_subscription_event_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(TransferProgress) dco_decode_DartFn_Inputs_transfer_progress_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(WatchEvent) dco_decode_DartFn_Inputs_watch_event_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected FutureOr<void> Function(WebSocketConnectionState) dco_decode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(''); }
+
+@protected Object dco_decode_DartOpaque(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return decodeDartOpaque(raw, generalizedFrbRustBinding); }
+
+@protected Map<String, String> dco_decode_Map_String_String_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_string(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, ConvexValue> dco_decode_Map_String_convex_value_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_convex_value(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, double> dco_decode_Map_String_f_64_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_f_64(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected AuthHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AuthHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CallbackSubscriber dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CallbackSubscriberImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CallbackSubscriberDartFn dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CallbackSubscriberDartFnImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CancellationToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CancellationTokenImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected MobileConvexClient dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MobileConvexClientImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PaginatedQueryHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PaginatedQueryHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PresenceHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PresenceHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SubscriptionHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SubscriptionHandleImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected EventSink dco_decode_TraitDef_EventSink(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected QuerySubscriber dco_decode_TraitDef_QuerySubscriber(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected AuditLogOptions dco_decode_audit_log_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return AuditLogOptions(directory: dco_decode_String(arr[0]),
+maxFileBytes: dco_decode_u_64(arr[1]),
+maxFiles: dco_decode_u_32(arr[2]),); }
+
+@protected AuthChange dco_decode_auth_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return AuthChange(authenticated: dco_decode_bool(arr[0]),
+reason: dco_decode_auth_change_reason(arr[1]),); }
+
+@protected AuthChangeReason dco_decode_auth_change_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AuthChangeReason.values[raw as int]; }
+
+@protected AuthRefreshConfig dco_decode_auth_refresh_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return AuthRefreshConfig(bufferSecs: dco_decode_u_64(arr[0]),
+minIntervalSecs: dco_decode_u_64(arr[1]),
+defaultIntervalSecs: dco_decode_u_64(arr[2]),
+jitterPct: dco_decode_u_8(arr[3]),); }
+
+@protected BackendChangedEvent dco_decode_backend_changed_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BackendChangedEvent(previousFingerprint: dco_decode_String(arr[0]),
+currentFingerprint: dco_decode_String(arr[1]),
+cacheInvalidated: dco_decode_bool(arr[2]),); }
+
+@protected Backpressure dco_decode_backpressure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return Backpressure_LatestOnly();
+case 1: return Backpressure_Debounce(ms: dco_decode_u_32(raw[1]),);
+case 2: return Backpressure_Throttle(ms: dco_decode_u_32(raw[1]),);
+case 3: return Backpressure_Buffer(size: dco_decode_u_32(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected BatchQuery dco_decode_batch_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return BatchQuery(name: dco_decode_String(arr[0]),
+args: dco_decode_Map_String_String_None(arr[1]),); }
+
+@protected BlankArgHandling dco_decode_blank_arg_handling(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BlankArgHandling.values[raw as int]; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected AuditLogOptions dco_decode_box_autoadd_audit_log_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_audit_log_options(raw); }
+
+@protected AuthRefreshConfig dco_decode_box_autoadd_auth_refresh_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_auth_refresh_config(raw); }
+
+@protected Backpressure dco_decode_box_autoadd_backpressure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_backpressure(raw); }
+
+@protected ClientError dco_decode_box_autoadd_client_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_client_error(raw); }
+
+@protected ClientOptions dco_decode_box_autoadd_client_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_client_options(raw); }
+
+@protected CombineStrategy dco_decode_box_autoadd_combine_strategy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_combine_strategy(raw); }
+
+@protected ConvexValue dco_decode_box_autoadd_convex_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_convex_value(raw); }
+
+@protected DartKeepaliveOptions dco_decode_box_autoadd_dart_keepalive_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_dart_keepalive_options(raw); }
+
+@protected FailoverOptions dco_decode_box_autoadd_failover_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_failover_options(raw); }
+
+@protected FunctionFault dco_decode_box_autoadd_function_fault(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_function_fault(raw); }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected InterceptedCall dco_decode_box_autoadd_intercepted_call(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_intercepted_call(raw); }
+
+@protected JobOptions dco_decode_box_autoadd_job_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_job_options(raw); }
+
+@protected PersistedResultOptions dco_decode_box_autoadd_persisted_result_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_persisted_result_options(raw); }
+
+@protected PresenceOptions dco_decode_box_autoadd_presence_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_presence_options(raw); }
+
+@protected ResultCacheOptions dco_decode_box_autoadd_result_cache_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_result_cache_options(raw); }
+
+@protected SchemaCheckOptions dco_decode_box_autoadd_schema_check_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_schema_check_options(raw); }
+
+@protected ShardMapping dco_decode_box_autoadd_shard_mapping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_shard_mapping(raw); }
+
+@protected SubscribeOptions dco_decode_box_autoadd_subscribe_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_subscribe_options(raw); }
+
+@protected TelemetrySampling dco_decode_box_autoadd_telemetry_sampling(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_telemetry_sampling(raw); }
+
+@protected TraceExportOptions dco_decode_box_autoadd_trace_export_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_trace_export_options(raw); }
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected UiHint dco_decode_box_autoadd_ui_hint(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_ui_hint(raw); }
+
+@protected UploadSource dco_decode_box_autoadd_upload_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_upload_source(raw); }
+
+@protected UsageBudget dco_decode_box_autoadd_usage_budget(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_usage_budget(raw); }
+
+@protected BudgetEnforcement dco_decode_budget_enforcement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BudgetEnforcement.values[raw as int]; }
+
+@protected BudgetExceededEvent dco_decode_budget_exceeded_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return BudgetExceededEvent(function: dco_decode_String(arr[0]),
+limit: dco_decode_budget_limit(arr[1]),
+enforcement: dco_decode_budget_enforcement(arr[2]),); }
+
+@protected BudgetLimit dco_decode_budget_limit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return BudgetLimit.values[raw as int]; }
+
+@protected CallKind dco_decode_call_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CallKind.values[raw as int]; }
+
+@protected CallOutcome dco_decode_call_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return CallOutcome_Value(json: dco_decode_String(raw[1]),);
+case 1: return CallOutcome_ConvexError(message: dco_decode_String(raw[1]),data: dco_decode_String(raw[2]),);
+case 2: return CallOutcome_ServerError(message: dco_decode_String(raw[1]),);
+case 3: return CallOutcome_TransportError(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ChunkedResultOptions dco_decode_chunked_result_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ChunkedResultOptions(thresholdBytes: dco_decode_u_64(arr[0]),
+chunkBytes: dco_decode_u_64(arr[1]),); }
+
+@protected ClassSampleRate dco_decode_class_sample_rate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ClassSampleRate(class_: dco_decode_telemetry_class(arr[0]),
+rate: dco_decode_f_64(arr[1]),); }
+
+@protected ClientError dco_decode_client_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ClientError_InternalError(msg: dco_decode_String(raw[1]),code: dco_decode_error_code(raw[2]),retryable: dco_decode_bool(raw[3]),);
+case 1: return ClientError_ConvexError(message: dco_decode_String(raw[1]),data: dco_decode_box_autoadd_convex_value(raw[2]),code: dco_decode_error_code(raw[3]),retryable: dco_decode_bool(raw[4]),);
+case 2: return ClientError_ServerError(msg: dco_decode_String(raw[1]),code: dco_decode_error_code(raw[2]),retryable: dco_decode_bool(raw[3]),);
+case 3: return ClientError_Timeout(timeoutMs: dco_decode_u_64(raw[1]),code: dco_decode_error_code(raw[2]),retryable: dco_decode_bool(raw[3]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ClientEvent dco_decode_client_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ClientEvent_Connected();
+case 1: return ClientEvent_Disconnected();
+case 2: return ClientEvent_AuthChanged(authenticated: dco_decode_bool(raw[1]),);
+case 3: return ClientEvent_SubscriptionError(name: dco_decode_String(raw[1]),message: dco_decode_String(raw[2]),);
+case 4: return ClientEvent_AuthRejected(name: dco_decode_String(raw[1]),message: dco_decode_String(raw[2]),);
+case 5: return ClientEvent_MutationRetried(name: dco_decode_String(raw[1]),attempt: dco_decode_u_32(raw[2]),error: dco_decode_String(raw[3]),);
+case 6: return ClientEvent_CacheHit(name: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ClientMetrics dco_decode_client_metrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ClientMetrics(connection: dco_decode_connection_quality(arr[0]),
+runtime: dco_decode_runtime_metrics(arr[1]),); }
+
+@protected ClientOptions dco_decode_client_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 29) throw Exception('unexpected arr length: expect 29 but see ${arr.length}');
+                return ClientOptions(nullHandling: dco_decode_null_handling(arr[0]),
+maxConcurrentResubscribes: dco_decode_opt_box_autoadd_u_32(arr[1]),
+auditLog: dco_decode_opt_box_autoadd_audit_log_options(arr[2]),
+pressureThrottle: dco_decode_pressure_throttle(arr[3]),
+storageRoot: dco_decode_opt_String(arr[4]),
+debugMetricsPort: dco_decode_opt_box_autoadd_u_16(arr[5]),
+failover: dco_decode_opt_box_autoadd_failover_options(arr[6]),
+unauthenticated: dco_decode_bool(arr[7]),
+int64Encoding: dco_decode_int_64_encoding(arr[8]),
+blankArgs: dco_decode_blank_arg_handling(arr[9]),
+connectRetry: dco_decode_connect_retry_options(arr[10]),
+subscribeBatchWindowMs: dco_decode_opt_box_autoadd_u_64(arr[11]),
+schemaCheck: dco_decode_opt_box_autoadd_schema_check_options(arr[12]),
+incognito: dco_decode_bool(arr[13]),
+typeCodecs: dco_decode_list_type_codec(arr[14]),
+usageBudget: dco_decode_opt_box_autoadd_usage_budget(arr[15]),
+shareSubscriptions: dco_decode_bool(arr[16]),
+deferredMutations: dco_decode_deferred_mutation_options(arr[17]),
+slowInitializationMs: dco_decode_opt_box_autoadd_u_64(arr[18]),
+telemetrySampling: dco_decode_telemetry_sampling(arr[19]),
+previews: dco_decode_preview_options(arr[20]),
+mutationRetry: dco_decode_mutation_retry_options(arr[21]),
+resultCache: dco_decode_result_cache_options(arr[22]),
+resultCachePersistence: dco_decode_opt_box_autoadd_persisted_result_options(arr[23]),
+requireStorageKey: dco_decode_bool(arr[24]),
+httpActionsUrl: dco_decode_opt_String(arr[25]),
+fileStorage: dco_decode_file_storage_options(arr[26]),
+chunkedResults: dco_decode_chunked_result_options(arr[27]),
+requestIds: dco_decode_request_id_options(arr[28]),); }
+
+@protected CodecWire dco_decode_codec_wire(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CodecWire.values[raw as int]; }
+
+@protected CombineStrategy dco_decode_combine_strategy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return CombineStrategy_Array();
+case 1: return CombineStrategy_Keyed(keys: dco_decode_list_String(raw[1]),);
+case 2: return CombineStrategy_Merge();
+                default: throw Exception("unreachable");
+            } }
+
+@protected CommittedMutation dco_decode_committed_mutation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CommittedMutation(value: dco_decode_String(arr[0]),
+commitToken: dco_decode_String(arr[1]),); }
+
+@protected ConflictResolution dco_decode_conflict_resolution(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ConflictResolution_Retry();
+case 1: return ConflictResolution_Drop();
+case 2: return ConflictResolution_Transform(args: dco_decode_Map_String_String_None(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ConnectRetryOptions dco_decode_connect_retry_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ConnectRetryOptions(initialBackoffMs: dco_decode_u_64(arr[0]),
+maxBackoffMs: dco_decode_u_64(arr[1]),
+maxAttempts: dco_decode_u_32(arr[2]),); }
+
+@protected ConnectionCloseReason dco_decode_connection_close_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConnectionCloseReason.values[raw as int]; }
+
+@protected ConnectionQuality dco_decode_connection_quality(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ConnectionQuality(score: dco_decode_u_8(arr[0]),
+level: dco_decode_connection_quality_level(arr[1]),
+rttMs: dco_decode_f_64(arr[2]),
+failureRate: dco_decode_f_64(arr[3]),
+recentReconnects: dco_decode_u_32(arr[4]),); }
+
+@protected ConnectionQualityLevel dco_decode_connection_quality_level(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConnectionQualityLevel.values[raw as int]; }
+
+@protected ConvexValue dco_decode_convex_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ConvexValue_Null();
+case 1: return ConvexValue_Bool(dco_decode_bool(raw[1]),);
+case 2: return ConvexValue_Int64(dco_decode_i_64(raw[1]),);
+case 3: return ConvexValue_Float64(dco_decode_f_64(raw[1]),);
+case 4: return ConvexValue_String(dco_decode_String(raw[1]),);
+case 5: return ConvexValue_Bytes(dco_decode_list_prim_u_8_strict(raw[1]),);
+case 6: return ConvexValue_Array(dco_decode_list_convex_value(raw[1]),);
+case 7: return ConvexValue_Object(dco_decode_Map_String_convex_value_None(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected DartKeepaliveOptions dco_decode_dart_keepalive_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return DartKeepaliveOptions(intervalMs: dco_decode_u_64(arr[0]),
+timeoutMs: dco_decode_u_64(arr[1]),
+maxMissedPings: dco_decode_u_32(arr[2]),); }
+
+@protected DartLiveness dco_decode_dart_liveness(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DartLiveness(state: dco_decode_dart_liveness_state(arr[0]),
+lastResponseMs: dco_decode_opt_box_autoadd_i_64(arr[1]),
+missedPings: dco_decode_u_32(arr[2]),
+orphanedSubscriptions: dco_decode_u_32(arr[3]),); }
+
+@protected DartLivenessState dco_decode_dart_liveness_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DartLivenessState.values[raw as int]; }
+
+@protected DeferredMutationOptions dco_decode_deferred_mutation_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 1) throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+                return DeferredMutationOptions(maxBatchSize: dco_decode_u_32(arr[0]),); }
+
+@protected DerivedField dco_decode_derived_field(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DerivedField(name: dco_decode_String(arr[0]),
+value: dco_decode_derived_value(arr[1]),); }
+
+@protected DerivedSource dco_decode_derived_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DerivedSource(name: dco_decode_String(arr[0]),
+args: dco_decode_Map_String_String_None(arr[1]),); }
+
+@protected DerivedValue dco_decode_derived_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return DerivedValue_Pick(source: dco_decode_u_32(raw[1]),path: dco_decode_String(raw[2]),);
+case 1: return DerivedValue_Count(source: dco_decode_u_32(raw[1]),);
+case 2: return DerivedValue_JoinById(source: dco_decode_u_32(raw[1]),withSource: dco_decode_u_32(raw[2]),foreignKey: dco_decode_String(raw[3]),asField: dco_decode_String(raw[4]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ErrorCode dco_decode_error_code(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ErrorCode.values[raw as int]; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FailoverEvent dco_decode_failover_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FailoverEvent(fromUrl: dco_decode_String(arr[0]),
+toUrl: dco_decode_String(arr[1]),
+reason: dco_decode_failover_reason(arr[2]),); }
+
+@protected FailoverOptions dco_decode_failover_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FailoverOptions(fallbackUrls: dco_decode_list_String(arr[0]),
+failoverAfterMs: dco_decode_u_64(arr[1]),
+failbackProbeIntervalMs: dco_decode_u_64(arr[2]),); }
+
+@protected FailoverReason dco_decode_failover_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FailoverReason.values[raw as int]; }
+
+@protected FileStorageOptions dco_decode_file_storage_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return FileStorageOptions(generateUploadUrlMutation: dco_decode_String(arr[0]),
+downloadAttempts: dco_decode_u_32(arr[1]),
+downloadBackoffMs: dco_decode_u_64(arr[2]),
+downloadReadTimeoutMs: dco_decode_u_64(arr[3]),); }
+
+@protected FunctionFault dco_decode_function_fault(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return FunctionFault(latencyMs: dco_decode_u_32(arr[0]),
+failureRate: dco_decode_f_64(arr[1]),); }
+
+@protected FunctionMetrics dco_decode_function_metrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return FunctionMetrics(name: dco_decode_String(arr[0]),
+calls: dco_decode_u_64(arr[1]),
+errors: dco_decode_u_64(arr[2]),
+retries: dco_decode_u_64(arr[3]),
+latency: dco_decode_latency_histogram(arr[4]),
+argsBytes: dco_decode_u_64(arr[5]),
+resultBytes: dco_decode_u_64(arr[6]),
+maxResultBytes: dco_decode_u_64(arr[7]),); }
+
+@protected HttpActionResponse dco_decode_http_action_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return HttpActionResponse(status: dco_decode_u_16(arr[0]),
+headers: dco_decode_Map_String_String_None(arr[1]),
+body: dco_decode_list_prim_u_8_strict(arr[2]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected IdempotentMutationResult dco_decode_idempotent_mutation_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return IdempotentMutationResult(value: dco_decode_String(arr[0]),
+idempotencyKey: dco_decode_String(arr[1]),
+attempts: dco_decode_u_32(arr[2]),); }
+
+@protected InitializationDiagnostics dco_decode_initialization_diagnostics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return InitializationDiagnostics(initialized: dco_decode_bool(arr[0]),
+initializedAtMs: dco_decode_opt_box_autoadd_i_64(arr[1]),
+lastDurationMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+waitingCallers: dco_decode_u_32(arr[3]),
+lastError: dco_decode_opt_String(arr[4]),); }
+
+@protected Int64Encoding dco_decode_int_64_encoding(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Int64Encoding.values[raw as int]; }
+
+@protected InterceptDecision dco_decode_intercept_decision(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return InterceptDecision_Proceed(call: dco_decode_box_autoadd_intercepted_call(raw[1]),);
+case 1: return InterceptDecision_Veto(reason: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected InterceptedCall dco_decode_intercepted_call(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return InterceptedCall(kind: dco_decode_call_kind(arr[0]),
+name: dco_decode_String(arr[1]),
+args: dco_decode_String(arr[2]),
+metadata: dco_decode_Map_String_String_None(arr[3]),); }
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected JobOptions dco_decode_job_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return JobOptions(jobIdArg: dco_decode_String(arr[0]),
+statusQuery: dco_decode_String(arr[1]),); }
+
+@protected JobProgress dco_decode_job_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return JobProgress(jobId: dco_decode_String(arr[0]),
+status: dco_decode_String(arr[1]),); }
+
+@protected LatencyHistogram dco_decode_latency_histogram(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return LatencyHistogram(boundsMs: dco_decode_list_prim_u_64_strict(arr[0]),
+counts: dco_decode_list_prim_u_64_strict(arr[1]),
+sumMs: dco_decode_f_64(arr[2]),
+maxMs: dco_decode_f_64(arr[3]),
+p50Ms: dco_decode_f_64(arr[4]),
+p95Ms: dco_decode_f_64(arr[5]),); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<BatchQuery> dco_decode_list_batch_query(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_batch_query).toList(); }
+
+@protected ListChange dco_decode_list_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ListChange_Insert(index: dco_decode_u_32(raw[1]),item: dco_decode_String(raw[2]),);
+case 1: return ListChange_Remove(index: dco_decode_u_32(raw[1]),);
+case 2: return ListChange_Move(from: dco_decode_u_32(raw[1]),to: dco_decode_u_32(raw[2]),);
+case 3: return ListChange_Change(index: dco_decode_u_32(raw[1]),item: dco_decode_String(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected List<ClassSampleRate> dco_decode_list_class_sample_rate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_class_sample_rate).toList(); }
+
+@protected List<ConvexValue> dco_decode_list_convex_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_convex_value).toList(); }
+
+@protected List<DerivedField> dco_decode_list_derived_field(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_derived_field).toList(); }
+
+@protected List<DerivedSource> dco_decode_list_derived_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_derived_source).toList(); }
+
+@protected List<FunctionMetrics> dco_decode_list_function_metrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_function_metrics).toList(); }
+
+@protected List<ListChange> dco_decode_list_list_change(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_change).toList(); }
+
+@protected List<MutationCall> dco_decode_list_mutation_call(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_mutation_call).toList(); }
+
+@protected List<MutationStepStatus> dco_decode_list_mutation_step_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_mutation_step_status).toList(); }
+
+@protected List<OptimisticUpdate> dco_decode_list_optimistic_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_optimistic_update).toList(); }
+
+@protected List<PresenceStatus> dco_decode_list_presence_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_presence_status).toList(); }
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeUint64List(raw); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<QueryDependency> dco_decode_list_query_dependency(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_query_dependency).toList(); }
+
+@protected List<(String,ConvexValue)> dco_decode_list_record_string_convex_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_convex_value).toList(); }
+
+@protected List<(String,double)> dco_decode_list_record_string_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_f_64).toList(); }
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_string).toList(); }
+
+@protected List<SubscriptionInfo> dco_decode_list_subscription_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_subscription_info).toList(); }
+
+@protected List<TelemetryClass> dco_decode_list_telemetry_class(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_telemetry_class).toList(); }
+
+@protected List<TypeCodec> dco_decode_list_type_codec(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_type_codec).toList(); }
+
+@protected ListUpdate dco_decode_list_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ListUpdate_Reset(value: dco_decode_String(raw[1]),);
+case 1: return ListUpdate_Diff(changes: dco_decode_list_list_change(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected LogLevel dco_decode_log_level(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LogLevel.values[raw as int]; }
+
+@protected LogRecord dco_decode_log_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return LogRecord(level: dco_decode_log_level(arr[0]),
+target: dco_decode_String(arr[1]),
+message: dco_decode_String(arr[2]),
+timestampMs: dco_decode_i_64(arr[3]),); }
+
+@protected LogoutEvent dco_decode_logout_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return LogoutEvent(cancelledSubscriptions: dco_decode_u_32(arr[0]),
+clearedUserData: dco_decode_bool(arr[1]),); }
+
+@protected MemoryPressure dco_decode_memory_pressure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MemoryPressure.values[raw as int]; }
+
+@protected MemoryReleasedEvent dco_decode_memory_released_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return MemoryReleasedEvent(pressure: dco_decode_memory_pressure(arr[0]),
+queryCacheEntries: dco_decode_u_32(arr[1]),
+actionCacheEntries: dco_decode_u_32(arr[2]),
+placeholderValues: dco_decode_u_32(arr[3]),
+deferredMutationsSent: dco_decode_u_32(arr[4]),
+bytesReleased: dco_decode_u_64(arr[5]),); }
+
+@protected MetricsReport dco_decode_metrics_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return MetricsReport(sinceMs: dco_decode_i_64(arr[0]),
+functions: dco_decode_list_function_metrics(arr[1]),
+retries: dco_decode_u_64(arr[2]),
+reconnects: dco_decode_u_64(arr[3]),
+activeSubscriptions: dco_decode_u_32(arr[4]),); }
+
+@protected MutationCall dco_decode_mutation_call(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return MutationCall(name: dco_decode_String(arr[0]),
+args: dco_decode_Map_String_String_None(arr[1]),); }
+
+@protected MutationLifecycleEvent dco_decode_mutation_lifecycle_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MutationLifecycleEvent(mutationId: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+status: dco_decode_mutation_status(arr[2]),); }
+
+@protected MutationRetryOptions dco_decode_mutation_retry_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MutationRetryOptions(initialBackoffMs: dco_decode_u_64(arr[0]),
+maxBackoffMs: dco_decode_u_64(arr[1]),
+maxAttempts: dco_decode_u_32(arr[2]),
+idempotencyKeyArg: dco_decode_String(arr[3]),); }
+
+@protected MutationSequenceResult dco_decode_mutation_sequence_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return MutationSequenceResult(steps: dco_decode_list_mutation_step_status(arr[0]),
+succeeded: dco_decode_bool(arr[1]),); }
+
+@protected MutationStatus dco_decode_mutation_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return MutationStatus_Queued();
+case 1: return MutationStatus_Sending();
+case 2: return MutationStatus_Retrying(attempt: dco_decode_u_32(raw[1]),error: dco_decode_String(raw[2]),);
+case 3: return MutationStatus_Succeeded(completedAtMs: dco_decode_i_64(raw[1]),);
+case 4: return MutationStatus_Failed(error: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected MutationStepStatus dco_decode_mutation_step_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return MutationStepStatus_Succeeded(value: dco_decode_String(raw[1]),);
+case 1: return MutationStepStatus_Failed(message: dco_decode_String(raw[1]),data: dco_decode_opt_String(raw[2]),);
+case 2: return MutationStepStatus_Skipped();
+                default: throw Exception("unreachable");
+            } }
+
+@protected NullHandling dco_decode_null_handling(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NullHandling.values[raw as int]; }
+
+@protected OfflineMutationResult dco_decode_offline_mutation_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return OfflineMutationResult_Completed(value: dco_decode_String(raw[1]),);
+case 1: return OfflineMutationResult_Queued(outboxId: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected AuditLogOptions? dco_decode_opt_box_autoadd_audit_log_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_audit_log_options(raw); }
+
+@protected Backpressure? dco_decode_opt_box_autoadd_backpressure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_backpressure(raw); }
+
+@protected FailoverOptions? dco_decode_opt_box_autoadd_failover_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_failover_options(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected PersistedResultOptions? dco_decode_opt_box_autoadd_persisted_result_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_persisted_result_options(raw); }
+
+@protected SchemaCheckOptions? dco_decode_opt_box_autoadd_schema_check_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_schema_check_options(raw); }
+
+@protected SubscribeOptions? dco_decode_opt_box_autoadd_subscribe_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_subscribe_options(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_16(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected UiHint? dco_decode_opt_box_autoadd_ui_hint(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_ui_hint(raw); }
+
+@protected UsageBudget? dco_decode_opt_box_autoadd_usage_budget(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_usage_budget(raw); }
+
+@protected List<String>? dco_decode_opt_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_String(raw); }
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_list_prim_u_8_strict(raw); }
+
+@protected OptimisticUpdate dco_decode_optimistic_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return OptimisticUpdate(queryName: dco_decode_String(arr[0]),
+queryArgs: dco_decode_Map_String_String_None(arr[1]),
+patch: dco_decode_String(arr[2]),); }
+
+@protected OutboxCompletion dco_decode_outbox_completion(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return OutboxCompletion(outboxId: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+value: dco_decode_opt_String(arr[2]),
+error: dco_decode_opt_String(arr[3]),); }
+
+@protected OutboxConflict dco_decode_outbox_conflict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return OutboxConflict(outboxId: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+args: dco_decode_String(arr[2]),
+error: dco_decode_String(arr[3]),
+data: dco_decode_opt_String(arr[4]),); }
+
+@protected PaginatedUpdate dco_decode_paginated_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PaginatedUpdate(results: dco_decode_String(arr[0]),
+status: dco_decode_pagination_status(arr[1]),
+pageBoundaries: dco_decode_list_String(arr[2]),); }
+
+@protected PaginationStatus dco_decode_pagination_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PaginationStatus.values[raw as int]; }
+
+@protected PanicReport dco_decode_panic_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PanicReport(message: dco_decode_String(arr[0]),
+location: dco_decode_opt_String(arr[1]),
+thread: dco_decode_opt_String(arr[2]),
+backtrace: dco_decode_String(arr[3]),
+timestampMs: dco_decode_i_64(arr[4]),); }
+
+@protected PatchUpdate dco_decode_patch_update(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return PatchUpdate_Snapshot(value: dco_decode_String(raw[1]),);
+case 1: return PatchUpdate_Patch(operations: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected PersistedResultOptions dco_decode_persisted_result_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PersistedResultOptions(ttlMs: dco_decode_u_64(arr[0]),
+maxBytes: dco_decode_u_64(arr[1]),
+flushIntervalMs: dco_decode_u_64(arr[2]),); }
+
+@protected PlaceholderPolicy dco_decode_placeholder_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PlaceholderPolicy.values[raw as int]; }
+
+@protected PresenceOptions dco_decode_presence_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PresenceOptions(userField: dco_decode_String(arr[0]),
+lastSeenField: dco_decode_String(arr[1]),
+onlineThresholdMs: dco_decode_u_64(arr[2]),); }
+
+@protected PresenceStatus dco_decode_presence_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return PresenceStatus(userId: dco_decode_String(arr[0]),
+isOnline: dco_decode_bool(arr[1]),
+lastSeenMs: dco_decode_i_64(arr[2]),); }
+
+@protected PressureThrottle dco_decode_pressure_throttle(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PressureThrottle(moderateIntervalMs: dco_decode_u_64(arr[0]),
+highIntervalMs: dco_decode_u_64(arr[1]),); }
+
+@protected PreviewOptions dco_decode_preview_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PreviewOptions(urlTemplate: dco_decode_String(arr[0]),
+aliases: dco_decode_Map_String_String_None(arr[1]),); }
+
+@protected QueryDependency dco_decode_query_dependency(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return QueryDependency_Mutation(name: dco_decode_String(raw[1]),);
+case 1: return QueryDependency_Query(name: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected (String,ConvexValue) dco_decode_record_string_convex_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_convex_value(arr[1]),); }
+
+@protected (String,double) dco_decode_record_string_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_f_64(arr[1]),); }
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_String(arr[1]),); }
+
+@protected RequestIdOptions dco_decode_request_id_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RequestIdOptions(inErrors: dco_decode_bool(arr[0]),
+argName: dco_decode_opt_String(arr[1]),); }
+
+@protected ResultCacheOptions dco_decode_result_cache_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ResultCacheOptions(maxEntries: dco_decode_u_32(arr[0]),
+maxBytes: dco_decode_u_64(arr[1]),); }
+
+@protected ResultChunk dco_decode_result_chunk(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ResultChunk(result: dco_decode_u_64(arr[0]),
+index: dco_decode_u_32(arr[1]),
+last: dco_decode_bool(arr[2]),
+data: dco_decode_list_prim_u_8_strict(arr[3]),); }
+
+@protected RuntimeMetrics dco_decode_runtime_metrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return RuntimeMetrics(workers: dco_decode_u_32(arr[0]),
+aliveTasks: dco_decode_u_64(arr[1]),
+globalQueueDepth: dco_decode_u_64(arr[2]),
+workerUtilization: dco_decode_f_64(arr[3]),
+meanSchedulingLagMs: dco_decode_f_64(arr[4]),
+maxSchedulingLagMs: dco_decode_f_64(arr[5]),); }
+
+@protected SchemaCheckOptions dco_decode_schema_check_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SchemaCheckOptions(fingerprintQuery: dco_decode_String(arr[0]),
+invalidateCache: dco_decode_bool(arr[1]),); }
+
+@protected ShardMapping dco_decode_shard_mapping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ShardMapping(batchQuery: dco_decode_String(arr[0]),
+keyArg: dco_decode_String(arr[1]),
+batchArg: dco_decode_String(arr[2]),
+maxKeysPerShard: dco_decode_u_32(arr[3]),); }
+
+@protected ShardingMetrics dco_decode_sharding_metrics(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return ShardingMetrics(logicalSubscriptions: dco_decode_u_32(arr[0]),
+serverSubscriptions: dco_decode_u_32(arr[1]),); }
+
+@protected SlowInitializationEvent dco_decode_slow_initialization_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SlowInitializationEvent(elapsedMs: dco_decode_u_64(arr[0]),
+waitingCallers: dco_decode_u_32(arr[1]),); }
+
+@protected SubscribeOptions dco_decode_subscribe_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SubscribeOptions(priority: dco_decode_subscription_priority(arr[0]),
+valueArgs: dco_decode_Map_String_convex_value_None(arr[1]),
+distinct: dco_decode_bool(arr[2]),
+projection: dco_decode_opt_list_String(arr[3]),
+backpressure: dco_decode_opt_box_autoadd_backpressure(arr[4]),); }
+
+@protected SubscriptionCloseReason dco_decode_subscription_close_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SubscriptionCloseReason.values[raw as int]; }
+
+@protected SubscriptionEvent dco_decode_subscription_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SubscriptionEvent_Update(value: dco_decode_String(raw[1]),);
+case 1: return SubscriptionEvent_Error(message: dco_decode_String(raw[1]),data: dco_decode_opt_String(raw[2]),);
+case 2: return SubscriptionEvent_Resubscribed();
+case 3: return SubscriptionEvent_Paused();
+case 4: return SubscriptionEvent_Resumed();
+case 5: return SubscriptionEvent_Stale();
+case 6: return SubscriptionEvent_Closed(reason: dco_decode_subscription_close_reason(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SubscriptionInfo dco_decode_subscription_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SubscriptionInfo(id: dco_decode_u_64(arr[0]),
+name: dco_decode_String(arr[1]),
+argsHash: dco_decode_String(arr[2]),
+createdAtMs: dco_decode_i_64(arr[3]),
+updateCount: dco_decode_u_64(arr[4]),); }
+
+@protected SubscriptionPriority dco_decode_subscription_priority(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SubscriptionPriority.values[raw as int]; }
+
+@protected SubscriptionState dco_decode_subscription_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SubscriptionState.values[raw as int]; }
+
+@protected TelemetryClass dco_decode_telemetry_class(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TelemetryClass.values[raw as int]; }
+
+@protected TelemetrySampling dco_decode_telemetry_sampling(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TelemetrySampling(classRates: dco_decode_list_class_sample_rate(arr[0]),
+functionRates: dco_decode_Map_String_f_64_None(arr[1]),
+debugOnly: dco_decode_list_telemetry_class(arr[2]),); }
+
+@protected TraceExportOptions dco_decode_trace_export_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TraceExportOptions(endpoint: dco_decode_String(arr[0]),
+headers: dco_decode_Map_String_String_None(arr[1]),
+serviceName: dco_decode_String(arr[2]),); }
+
+@protected TransferProgress dco_decode_transfer_progress(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return TransferProgress(transferredBytes: dco_decode_u_64(arr[0]),
+totalBytes: dco_decode_opt_box_autoadd_u_64(arr[1]),); }
+
+@protected TypeCodec dco_decode_type_codec(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TypeCodec(typeName: dco_decode_String(arr[0]),
+wire: dco_decode_codec_wire(arr[1]),
+fields: dco_decode_list_String(arr[2]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected UiHint dco_decode_ui_hint(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return UiHint(code: dco_decode_ui_hint_code(arr[0]),
+params: dco_decode_Map_String_String_None(arr[1]),); }
+
+@protected UiHintCode dco_decode_ui_hint_code(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiHintCode.values[raw as int]; }
+
+@protected UiPressure dco_decode_ui_pressure(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UiPressure.values[raw as int]; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected UploadSource dco_decode_upload_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return UploadSource_Path(path: dco_decode_String(raw[1]),);
+case 1: return UploadSource_Bytes(bytes: dco_decode_list_prim_u_8_strict(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected UsageBudget dco_decode_usage_budget(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return UsageBudget(maxCallsPerMinute: dco_decode_opt_box_autoadd_u_32(arr[0]),
+maxBytesPerDay: dco_decode_opt_box_autoadd_u_64(arr[1]),
+enforcement: dco_decode_budget_enforcement(arr[2]),); }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected WatchEvent dco_decode_watch_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return WatchEvent_Loading();
+case 1: return WatchEvent_Cached(value: dco_decode_String(raw[1]),);
+case 2: return WatchEvent_Update(value: dco_decode_String(raw[1]),);
+case 3: return WatchEvent_Error(message: dco_decode_String(raw[1]),data: dco_decode_opt_String(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected WebSocketConnectionState dco_decode_web_socket_connection_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return WebSocketConnectionState_Connected();
+case 1: return WebSocketConnectionState_Connecting();
+case 2: return WebSocketConnectionState_Closed(reason: dco_decode_connection_close_reason(raw[1]),);
+case 3: return WebSocketConnectionState_Backoff(retryInMs: dco_decode_u_64(raw[1]),attempt: dco_decode_u_32(raw[2]),);
+case 4: return WebSocketConnectionState_Failed(reason: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected AuthHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AuthHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CallbackSubscriber sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CallbackSubscriberImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CallbackSubscriberDartFn sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CallbackSubscriberDartFnImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CancellationToken sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CancellationTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MobileConvexClient sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MobileConvexClientImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PaginatedQueryHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PaginatedQueryHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PresenceHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PresenceHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SubscriptionHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SubscriptionHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected AuthHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AuthHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CallbackSubscriber sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CallbackSubscriberImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CallbackSubscriberDartFn sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CallbackSubscriberDartFnImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CancellationToken sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CancellationTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MobileConvexClient sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MobileConvexClientImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PaginatedQueryHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PaginatedQueryHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PresenceHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PresenceHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SubscriptionHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SubscriptionHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_isize(deserializer);
+        return decodeDartOpaque(inner, generalizedFrbRustBinding); }
+
+@protected Map<String, String> sse_decode_Map_String_String_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_string(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, ConvexValue> sse_decode_Map_String_convex_value_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_convex_value(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, double> sse_decode_Map_String_f_64_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_f_64(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected AuthHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AuthHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CallbackSubscriber sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CallbackSubscriberImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CallbackSubscriberDartFn sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CallbackSubscriberDartFnImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CancellationToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CancellationTokenImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected MobileConvexClient sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return MobileConvexClientImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PaginatedQueryHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PaginatedQueryHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PresenceHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PresenceHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SubscriptionHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SubscriptionHandleImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AuditLogOptions sse_decode_audit_log_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_directory = sse_decode_String(deserializer);
+var var_maxFileBytes = sse_decode_u_64(deserializer);
+var var_maxFiles = sse_decode_u_32(deserializer);
+return AuditLogOptions(directory: var_directory, maxFileBytes: var_maxFileBytes, maxFiles: var_maxFiles); }
+
+@protected AuthChange sse_decode_auth_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_authenticated = sse_decode_bool(deserializer);
+var var_reason = sse_decode_auth_change_reason(deserializer);
+return AuthChange(authenticated: var_authenticated, reason: var_reason); }
+
+@protected AuthChangeReason sse_decode_auth_change_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AuthChangeReason.values[inner]; }
+
+@protected AuthRefreshConfig sse_decode_auth_refresh_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bufferSecs = sse_decode_u_64(deserializer);
+var var_minIntervalSecs = sse_decode_u_64(deserializer);
+var var_defaultIntervalSecs = sse_decode_u_64(deserializer);
+var var_jitterPct = sse_decode_u_8(deserializer);
+return AuthRefreshConfig(bufferSecs: var_bufferSecs, minIntervalSecs: var_minIntervalSecs, defaultIntervalSecs: var_defaultIntervalSecs, jitterPct: var_jitterPct); }
+
+@protected BackendChangedEvent sse_decode_backend_changed_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_previousFingerprint = sse_decode_String(deserializer);
+var var_currentFingerprint = sse_decode_String(deserializer);
+var var_cacheInvalidated = sse_decode_bool(deserializer);
+return BackendChangedEvent(previousFingerprint: var_previousFingerprint, currentFingerprint: var_currentFingerprint, cacheInvalidated: var_cacheInvalidated); }
+
+@protected Backpressure sse_decode_backpressure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return Backpressure_LatestOnly();case 1: var var_ms = sse_decode_u_32(deserializer);
+return Backpressure_Debounce(ms: var_ms);case 2: var var_ms = sse_decode_u_32(deserializer);
+return Backpressure_Throttle(ms: var_ms);case 3: var var_size = sse_decode_u_32(deserializer);
+return Backpressure_Buffer(size: var_size); default: throw UnimplementedError(''); }
+             }
+
+@protected BatchQuery sse_decode_batch_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_args = sse_decode_Map_String_String_None(deserializer);
+return BatchQuery(name: var_name, args: var_args); }
+
+@protected BlankArgHandling sse_decode_blank_arg_handling(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BlankArgHandling.values[inner]; }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected AuditLogOptions sse_decode_box_autoadd_audit_log_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_audit_log_options(deserializer)); }
+
+@protected AuthRefreshConfig sse_decode_box_autoadd_auth_refresh_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_auth_refresh_config(deserializer)); }
+
+@protected Backpressure sse_decode_box_autoadd_backpressure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_backpressure(deserializer)); }
+
+@protected ClientError sse_decode_box_autoadd_client_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_client_error(deserializer)); }
+
+@protected ClientOptions sse_decode_box_autoadd_client_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_client_options(deserializer)); }
+
+@protected CombineStrategy sse_decode_box_autoadd_combine_strategy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_combine_strategy(deserializer)); }
+
+@protected ConvexValue sse_decode_box_autoadd_convex_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_convex_value(deserializer)); }
+
+@protected DartKeepaliveOptions sse_decode_box_autoadd_dart_keepalive_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_dart_keepalive_options(deserializer)); }
+
+@protected FailoverOptions sse_decode_box_autoadd_failover_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_failover_options(deserializer)); }
+
+@protected FunctionFault sse_decode_box_autoadd_function_fault(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_function_fault(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected InterceptedCall sse_decode_box_autoadd_intercepted_call(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_intercepted_call(deserializer)); }
+
+@protected JobOptions sse_decode_box_autoadd_job_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_job_options(deserializer)); }
+
+@protected PersistedResultOptions sse_decode_box_autoadd_persisted_result_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_persisted_result_options(deserializer)); }
+
+@protected PresenceOptions sse_decode_box_autoadd_presence_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_presence_options(deserializer)); }
+
+@protected ResultCacheOptions sse_decode_box_autoadd_result_cache_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_result_cache_options(deserializer)); }
+
+@protected SchemaCheckOptions sse_decode_box_autoadd_schema_check_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_schema_check_options(deserializer)); }
+
+@protected ShardMapping sse_decode_box_autoadd_shard_mapping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_shard_mapping(deserializer)); }
+
+@protected SubscribeOptions sse_decode_box_autoadd_subscribe_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_subscribe_options(deserializer)); }
+
+@protected TelemetrySampling sse_decode_box_autoadd_telemetry_sampling(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_telemetry_sampling(deserializer)); }
+
+@protected TraceExportOptions sse_decode_box_autoadd_trace_export_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_trace_export_options(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_16(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected UiHint sse_decode_box_autoadd_ui_hint(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_ui_hint(deserializer)); }
+
+@protected UploadSource sse_decode_box_autoadd_upload_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_upload_source(deserializer)); }
+
+@protected UsageBudget sse_decode_box_autoadd_usage_budget(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_usage_budget(deserializer)); }
+
+@protected BudgetEnforcement sse_decode_budget_enforcement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BudgetEnforcement.values[inner]; }
+
+@protected BudgetExceededEvent sse_decode_budget_exceeded_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_function = sse_decode_String(deserializer);
+var var_limit = sse_decode_budget_limit(deserializer);
+var var_enforcement = sse_decode_budget_enforcement(deserializer);
+return BudgetExceededEvent(function: var_function, limit: var_limit, enforcement: var_enforcement); }
+
+@protected BudgetLimit sse_decode_budget_limit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return BudgetLimit.values[inner]; }
+
+@protected CallKind sse_decode_call_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CallKind.values[inner]; }
+
+@protected CallOutcome sse_decode_call_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_json = sse_decode_String(deserializer);
+return CallOutcome_Value(json: var_json);case 1: var var_message = sse_decode_String(deserializer);
+var var_data = sse_decode_String(deserializer);
+return CallOutcome_ConvexError(message: var_message, data: var_data);case 2: var var_message = sse_decode_String(deserializer);
+return CallOutcome_ServerError(message: var_message);case 3: var var_message = sse_decode_String(deserializer);
+return CallOutcome_TransportError(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected ChunkedResultOptions sse_decode_chunked_result_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_thresholdBytes = sse_decode_u_64(deserializer);
+var var_chunkBytes = sse_decode_u_64(deserializer);
+return ChunkedResultOptions(thresholdBytes: var_thresholdBytes, chunkBytes: var_chunkBytes); }
+
+@protected ClassSampleRate sse_decode_class_sample_rate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_class_ = sse_decode_telemetry_class(deserializer);
+var var_rate = sse_decode_f_64(deserializer);
+return ClassSampleRate(class_: var_class_, rate: var_rate); }
+
+@protected ClientError sse_decode_client_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_msg = sse_decode_String(deserializer);
+var var_code = sse_decode_error_code(deserializer);
+var var_retryable = sse_decode_bool(deserializer);
+return ClientError_InternalError(msg: var_msg, code: var_code, retryable: var_retryable);case 1: var var_message = sse_decode_String(deserializer);
+var var_data = sse_decode_box_autoadd_convex_value(deserializer);
+var var_code = sse_decode_error_code(deserializer);
+var var_retryable = sse_decode_bool(deserializer);
+return ClientError_ConvexError(message: var_message, data: var_data, code: var_code, retryable: var_retryable);case 2: var var_msg = sse_decode_String(deserializer);
+var var_code = sse_decode_error_code(deserializer);
+var var_retryable = sse_decode_bool(deserializer);
+return ClientError_ServerError(msg: var_msg, code: var_code, retryable: var_retryable);case 3: var var_timeoutMs = sse_decode_u_64(deserializer);
+var var_code = sse_decode_error_code(deserializer);
+var var_retryable = sse_decode_bool(deserializer);
+return ClientError_Timeout(timeoutMs: var_timeoutMs, code: var_code, retryable: var_retryable); default: throw UnimplementedError(''); }
+             }
+
+@protected ClientEvent sse_decode_client_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return ClientEvent_Connected();case 1: return ClientEvent_Disconnected();case 2: var var_authenticated = sse_decode_bool(deserializer);
+return ClientEvent_AuthChanged(authenticated: var_authenticated);case 3: var var_name = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+return ClientEvent_SubscriptionError(name: var_name, message: var_message);case 4: var var_name = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+return ClientEvent_AuthRejected(name: var_name, message: var_message);case 5: var var_name = sse_decode_String(deserializer);
+var var_attempt = sse_decode_u_32(deserializer);
+var var_error = sse_decode_String(deserializer);
+return ClientEvent_MutationRetried(name: var_name, attempt: var_attempt, error: var_error);case 6: var var_name = sse_decode_String(deserializer);
+return ClientEvent_CacheHit(name: var_name); default: throw UnimplementedError(''); }
+             }
+
+@protected ClientMetrics sse_decode_client_metrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_connection = sse_decode_connection_quality(deserializer);
+var var_runtime = sse_decode_runtime_metrics(deserializer);
+return ClientMetrics(connection: var_connection, runtime: var_runtime); }
+
+@protected ClientOptions sse_decode_client_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_nullHandling = sse_decode_null_handling(deserializer);
+var var_maxConcurrentResubscribes = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_auditLog = sse_decode_opt_box_autoadd_audit_log_options(deserializer);
+var var_pressureThrottle = sse_decode_pressure_throttle(deserializer);
+var var_storageRoot = sse_decode_opt_String(deserializer);
+var var_debugMetricsPort = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_failover = sse_decode_opt_box_autoadd_failover_options(deserializer);
+var var_unauthenticated = sse_decode_bool(deserializer);
+var var_int64Encoding = sse_decode_int_64_encoding(deserializer);
+var var_blankArgs = sse_decode_blank_arg_handling(deserializer);
+var var_connectRetry = sse_decode_connect_retry_options(deserializer);
+var var_subscribeBatchWindowMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_schemaCheck = sse_decode_opt_box_autoadd_schema_check_options(deserializer);
+var var_incognito = sse_decode_bool(deserializer);
+var var_typeCodecs = sse_decode_list_type_codec(deserializer);
+var var_usageBudget = sse_decode_opt_box_autoadd_usage_budget(deserializer);
+var var_shareSubscriptions = sse_decode_bool(deserializer);
+var var_deferredMutations = sse_decode_deferred_mutation_options(deserializer);
+var var_slowInitializationMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_telemetrySampling = sse_decode_telemetry_sampling(deserializer);
+var var_previews = sse_decode_preview_options(deserializer);
+var var_mutationRetry = sse_decode_mutation_retry_options(deserializer);
+var var_resultCache = sse_decode_result_cache_options(deserializer);
+var var_resultCachePersistence = sse_decode_opt_box_autoadd_persisted_result_options(deserializer);
+var var_requireStorageKey = sse_decode_bool(deserializer);
+var var_httpActionsUrl = sse_decode_opt_String(deserializer);
+var var_fileStorage = sse_decode_file_storage_options(deserializer);
+var var_chunkedResults = sse_decode_chunked_result_options(deserializer);
+var var_requestIds = sse_decode_request_id_options(deserializer);
+return ClientOptions(nullHandling: var_nullHandling, maxConcurrentResubscribes: var_maxConcurrentResubscribes, auditLog: var_auditLog, pressureThrottle: var_pressureThrottle, storageRoot: var_storageRoot, debugMetricsPort: var_debugMetricsPort, failover: var_failover, unauthenticated: var_unauthenticated, int64Encoding: var_int64Encoding, blankArgs: var_blankArgs, connectRetry: var_connectRetry, subscribeBatchWindowMs: var_subscribeBatchWindowMs, schemaCheck: var_schemaCheck, incognito: var_incognito, typeCodecs: var_typeCodecs, usageBudget: var_usageBudget, shareSubscriptions: var_shareSubscriptions, deferredMutations: var_deferredMutations, slowInitializationMs: var_slowInitializationMs, telemetrySampling: var_telemetrySampling, previews: var_previews, mutationRetry: var_mutationRetry, resultCache: var_resultCache, resultCachePersistence: var_resultCachePersistence, requireStorageKey: var_requireStorageKey, httpActionsUrl: var_httpActionsUrl, fileStorage: var_fileStorage, chunkedResults: var_chunkedResults, requestIds: var_requestIds); }
+
+@protected CodecWire sse_decode_codec_wire(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CodecWire.values[inner]; }
+
+@protected CombineStrategy sse_decode_combine_strategy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return CombineStrategy_Array();case 1: var var_keys = sse_decode_list_String(deserializer);
+return CombineStrategy_Keyed(keys: var_keys);case 2: return CombineStrategy_Merge(); default: throw UnimplementedError(''); }
+             }
+
+@protected CommittedMutation sse_decode_committed_mutation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_value = sse_decode_String(deserializer);
+var var_commitToken = sse_decode_String(deserializer);
+return CommittedMutation(value: var_value, commitToken: var_commitToken); }
+
+@protected ConflictResolution sse_decode_conflict_resolution(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return ConflictResolution_Retry();case 1: return ConflictResolution_Drop();case 2: var var_args = sse_decode_Map_String_String_None(deserializer);
+return ConflictResolution_Transform(args: var_args); default: throw UnimplementedError(''); }
+             }
+
+@protected ConnectRetryOptions sse_decode_connect_retry_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_initialBackoffMs = sse_decode_u_64(deserializer);
+var var_maxBackoffMs = sse_decode_u_64(deserializer);
+var var_maxAttempts = sse_decode_u_32(deserializer);
+return ConnectRetryOptions(initialBackoffMs: var_initialBackoffMs, maxBackoffMs: var_maxBackoffMs, maxAttempts: var_maxAttempts); }
+
+@protected ConnectionCloseReason sse_decode_connection_close_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConnectionCloseReason.values[inner]; }
+
+@protected ConnectionQuality sse_decode_connection_quality(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_score = sse_decode_u_8(deserializer);
+var var_level = sse_decode_connection_quality_level(deserializer);
+var var_rttMs = sse_decode_f_64(deserializer);
+var var_failureRate = sse_decode_f_64(deserializer);
+var var_recentReconnects = sse_decode_u_32(deserializer);
+return ConnectionQuality(score: var_score, level: var_level, rttMs: var_rttMs, failureRate: var_failureRate, recentReconnects: var_recentReconnects); }
+
+@protected ConnectionQualityLevel sse_decode_connection_quality_level(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConnectionQualityLevel.values[inner]; }
+
+@protected ConvexValue sse_decode_convex_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return ConvexValue_Null();case 1: var var_field0 = sse_decode_bool(deserializer);
+return ConvexValue_Bool(var_field0);case 2: var var_field0 = sse_decode_i_64(deserializer);
+return ConvexValue_Int64(var_field0);case 3: var var_field0 = sse_decode_f_64(deserializer);
+return ConvexValue_Float64(var_field0);case 4: var var_field0 = sse_decode_String(deserializer);
+return ConvexValue_String(var_field0);case 5: var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+return ConvexValue_Bytes(var_field0);case 6: var var_field0 = sse_decode_list_convex_value(deserializer);
+return ConvexValue_Array(var_field0);case 7: var var_field0 = sse_decode_Map_String_convex_value_None(deserializer);
+return ConvexValue_Object(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected DartKeepaliveOptions sse_decode_dart_keepalive_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_intervalMs = sse_decode_u_64(deserializer);
+var var_timeoutMs = sse_decode_u_64(deserializer);
+var var_maxMissedPings = sse_decode_u_32(deserializer);
+return DartKeepaliveOptions(intervalMs: var_intervalMs, timeoutMs: var_timeoutMs, maxMissedPings: var_maxMissedPings); }
+
+@protected DartLiveness sse_decode_dart_liveness(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_state = sse_decode_dart_liveness_state(deserializer);
+var var_lastResponseMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_missedPings = sse_decode_u_32(deserializer);
+var var_orphanedSubscriptions = sse_decode_u_32(deserializer);
+return DartLiveness(state: var_state, lastResponseMs: var_lastResponseMs, missedPings: var_missedPings, orphanedSubscriptions: var_orphanedSubscriptions); }
+
+@protected DartLivenessState sse_decode_dart_liveness_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DartLivenessState.values[inner]; }
+
+@protected DeferredMutationOptions sse_decode_deferred_mutation_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxBatchSize = sse_decode_u_32(deserializer);
+return DeferredMutationOptions(maxBatchSize: var_maxBatchSize); }
+
+@protected DerivedField sse_decode_derived_field(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_value = sse_decode_derived_value(deserializer);
+return DerivedField(name: var_name, value: var_value); }
+
+@protected DerivedSource sse_decode_derived_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_args = sse_decode_Map_String_String_None(deserializer);
+return DerivedSource(name: var_name, args: var_args); }
+
+@protected DerivedValue sse_decode_derived_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_source = sse_decode_u_32(deserializer);
+var var_path = sse_decode_String(deserializer);
+return DerivedValue_Pick(source: var_source, path: var_path);case 1: var var_source = sse_decode_u_32(deserializer);
+return DerivedValue_Count(source: var_source);case 2: var var_source = sse_decode_u_32(deserializer);
+var var_withSource = sse_decode_u_32(deserializer);
+var var_foreignKey = sse_decode_String(deserializer);
+var var_asField = sse_decode_String(deserializer);
+return DerivedValue_JoinById(source: var_source, withSource: var_withSource, foreignKey: var_foreignKey, asField: var_asField); default: throw UnimplementedError(''); }
+             }
+
+@protected ErrorCode sse_decode_error_code(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ErrorCode.values[inner]; }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FailoverEvent sse_decode_failover_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fromUrl = sse_decode_String(deserializer);
+var var_toUrl = sse_decode_String(deserializer);
+var var_reason = sse_decode_failover_reason(deserializer);
+return FailoverEvent(fromUrl: var_fromUrl, toUrl: var_toUrl, reason: var_reason); }
+
+@protected FailoverOptions sse_decode_failover_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fallbackUrls = sse_decode_list_String(deserializer);
+var var_failoverAfterMs = sse_decode_u_64(deserializer);
+var var_failbackProbeIntervalMs = sse_decode_u_64(deserializer);
+return FailoverOptions(fallbackUrls: var_fallbackUrls, failoverAfterMs: var_failoverAfterMs, failbackProbeIntervalMs: var_failbackProbeIntervalMs); }
+
+@protected FailoverReason sse_decode_failover_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FailoverReason.values[inner]; }
+
+@protected FileStorageOptions sse_decode_file_storage_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_generateUploadUrlMutation = sse_decode_String(deserializer);
+var var_downloadAttempts = sse_decode_u_32(deserializer);
+var var_downloadBackoffMs = sse_decode_u_64(deserializer);
+var var_downloadReadTimeoutMs = sse_decode_u_64(deserializer);
+return FileStorageOptions(generateUploadUrlMutation: var_generateUploadUrlMutation, downloadAttempts: var_downloadAttempts, downloadBackoffMs: var_downloadBackoffMs, downloadReadTimeoutMs: var_downloadReadTimeoutMs); }
+
+@protected FunctionFault sse_decode_function_fault(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_latencyMs = sse_decode_u_32(deserializer);
+var var_failureRate = sse_decode_f_64(deserializer);
+return FunctionFault(latencyMs: var_latencyMs, failureRate: var_failureRate); }
+
+@protected FunctionMetrics sse_decode_function_metrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_calls = sse_decode_u_64(deserializer);
+var var_errors = sse_decode_u_64(deserializer);
+var var_retries = sse_decode_u_64(deserializer);
+var var_latency = sse_decode_latency_histogram(deserializer);
+var var_argsBytes = sse_decode_u_64(deserializer);
+var var_resultBytes = sse_decode_u_64(deserializer);
+var var_maxResultBytes = sse_decode_u_64(deserializer);
+return FunctionMetrics(name: var_name, calls: var_calls, errors: var_errors, retries: var_retries, latency: var_latency, argsBytes: var_argsBytes, resultBytes: var_resultBytes, maxResultBytes: var_maxResultBytes); }
+
+@protected HttpActionResponse sse_decode_http_action_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_status = sse_decode_u_16(deserializer);
+var var_headers = sse_decode_Map_String_String_None(deserializer);
+var var_body = sse_decode_list_prim_u_8_strict(deserializer);
+return HttpActionResponse(status: var_status, headers: var_headers, body: var_body); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected IdempotentMutationResult sse_decode_idempotent_mutation_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_value = sse_decode_String(deserializer);
+var var_idempotencyKey = sse_decode_String(deserializer);
+var var_attempts = sse_decode_u_32(deserializer);
+return IdempotentMutationResult(value: var_value, idempotencyKey: var_idempotencyKey, attempts: var_attempts); }
+
+@protected InitializationDiagnostics sse_decode_initialization_diagnostics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_initialized = sse_decode_bool(deserializer);
+var var_initializedAtMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_lastDurationMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_waitingCallers = sse_decode_u_32(deserializer);
+var var_lastError = sse_decode_opt_String(deserializer);
+return InitializationDiagnostics(initialized: var_initialized, initializedAtMs: var_initializedAtMs, lastDurationMs: var_lastDurationMs, waitingCallers: var_waitingCallers, lastError: var_lastError); }
+
+@protected Int64Encoding sse_decode_int_64_encoding(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Int64Encoding.values[inner]; }
+
+@protected InterceptDecision sse_decode_intercept_decision(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_call = sse_decode_box_autoadd_intercepted_call(deserializer);
+return InterceptDecision_Proceed(call: var_call);case 1: var var_reason = sse_decode_String(deserializer);
+return InterceptDecision_Veto(reason: var_reason); default: throw UnimplementedError(''); }
+             }
+
+@protected InterceptedCall sse_decode_intercepted_call(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_call_kind(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_args = sse_decode_String(deserializer);
+var var_metadata = sse_decode_Map_String_String_None(deserializer);
+return InterceptedCall(kind: var_kind, name: var_name, args: var_args, metadata: var_metadata); }
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected JobOptions sse_decode_job_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_jobIdArg = sse_decode_String(deserializer);
+var var_statusQuery = sse_decode_String(deserializer);
+return JobOptions(jobIdArg: var_jobIdArg, statusQuery: var_statusQuery); }
+
+@protected JobProgress sse_decode_job_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_jobId = sse_decode_String(deserializer);
+var var_status = sse_decode_String(deserializer);
+return JobProgress(jobId: var_jobId, status: var_status); }
+
+@protected LatencyHistogram sse_decode_latency_histogram(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_boundsMs = sse_decode_list_prim_u_64_strict(deserializer);
+var var_counts = sse_decode_list_prim_u_64_strict(deserializer);
+var var_sumMs = sse_decode_f_64(deserializer);
+var var_maxMs = sse_decode_f_64(deserializer);
+var var_p50Ms = sse_decode_f_64(deserializer);
+var var_p95Ms = sse_decode_f_64(deserializer);
+return LatencyHistogram(boundsMs: var_boundsMs, counts: var_counts, sumMs: var_sumMs, maxMs: var_maxMs, p50Ms: var_p50Ms, p95Ms: var_p95Ms); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BatchQuery> sse_decode_list_batch_query(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BatchQuery>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_batch_query(deserializer)); }
+        return ans_;
+         }
+
+@protected ListChange sse_decode_list_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_index = sse_decode_u_32(deserializer);
+var var_item = sse_decode_String(deserializer);
+return ListChange_Insert(index: var_index, item: var_item);case 1: var var_index = sse_decode_u_32(deserializer);
+return ListChange_Remove(index: var_index);case 2: var var_from = sse_decode_u_32(deserializer);
+var var_to = sse_decode_u_32(deserializer);
+return ListChange_Move(from: var_from, to: var_to);case 3: var var_index = sse_decode_u_32(deserializer);
+var var_item = sse_decode_String(deserializer);
+return ListChange_Change(index: var_index, item: var_item); default: throw UnimplementedError(''); }
+             }
+
+@protected List<ClassSampleRate> sse_decode_list_class_sample_rate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ClassSampleRate>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_class_sample_rate(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ConvexValue> sse_decode_list_convex_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ConvexValue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_convex_value(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DerivedField> sse_decode_list_derived_field(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DerivedField>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_derived_field(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DerivedSource> sse_decode_list_derived_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DerivedSource>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_derived_source(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FunctionMetrics> sse_decode_list_function_metrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FunctionMetrics>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_function_metrics(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ListChange> sse_decode_list_list_change(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ListChange>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_change(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MutationCall> sse_decode_list_mutation_call(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MutationCall>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_mutation_call(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MutationStepStatus> sse_decode_list_mutation_step_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MutationStepStatus>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_mutation_step_status(deserializer)); }
+        return ans_;
+         }
+
+@protected List<OptimisticUpdate> sse_decode_list_optimistic_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <OptimisticUpdate>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_optimistic_update(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PresenceStatus> sse_decode_list_presence_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PresenceStatus>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_presence_status(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint64List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<QueryDependency> sse_decode_list_query_dependency(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <QueryDependency>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_query_dependency(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,ConvexValue)> sse_decode_list_record_string_convex_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,ConvexValue)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_convex_value(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,double)> sse_decode_list_record_string_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,double)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_f_64(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,String)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_string(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SubscriptionInfo> sse_decode_list_subscription_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SubscriptionInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_subscription_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TelemetryClass> sse_decode_list_telemetry_class(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TelemetryClass>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_telemetry_class(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TypeCodec> sse_decode_list_type_codec(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TypeCodec>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_type_codec(deserializer)); }
+        return ans_;
+         }
+
+@protected ListUpdate sse_decode_list_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_value = sse_decode_String(deserializer);
+return ListUpdate_Reset(value: var_value);case 1: var var_changes = sse_decode_list_list_change(deserializer);
+return ListUpdate_Diff(changes: var_changes); default: throw UnimplementedError(''); }
+             }
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LogLevel.values[inner]; }
+
+@protected LogRecord sse_decode_log_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_level = sse_decode_log_level(deserializer);
+var var_target = sse_decode_String(deserializer);
+var var_message = sse_decode_String(deserializer);
+var var_timestampMs = sse_decode_i_64(deserializer);
+return LogRecord(level: var_level, target: var_target, message: var_message, timestampMs: var_timestampMs); }
+
+@protected LogoutEvent sse_decode_logout_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_cancelledSubscriptions = sse_decode_u_32(deserializer);
+var var_clearedUserData = sse_decode_bool(deserializer);
+return LogoutEvent(cancelledSubscriptions: var_cancelledSubscriptions, clearedUserData: var_clearedUserData); }
+
+@protected MemoryPressure sse_decode_memory_pressure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MemoryPressure.values[inner]; }
+
+@protected MemoryReleasedEvent sse_decode_memory_released_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_pressure = sse_decode_memory_pressure(deserializer);
+var var_queryCacheEntries = sse_decode_u_32(deserializer);
+var var_actionCacheEntries = sse_decode_u_32(deserializer);
+var var_placeholderValues = sse_decode_u_32(deserializer);
+var var_deferredMutationsSent = sse_decode_u_32(deserializer);
+var var_bytesReleased = sse_decode_u_64(deserializer);
+return MemoryReleasedEvent(pressure: var_pressure, queryCacheEntries: var_queryCacheEntries, actionCacheEntries: var_actionCacheEntries, placeholderValues: var_placeholderValues, deferredMutationsSent: var_deferredMutationsSent, bytesReleased: var_bytesReleased); }
+
+@protected MetricsReport sse_decode_metrics_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_sinceMs = sse_decode_i_64(deserializer);
+var var_functions = sse_decode_list_function_metrics(deserializer);
+var var_retries = sse_decode_u_64(deserializer);
+var var_reconnects = sse_decode_u_64(deserializer);
+var var_activeSubscriptions = sse_decode_u_32(deserializer);
+return MetricsReport(sinceMs: var_sinceMs, functions: var_functions, retries: var_retries, reconnects: var_reconnects, activeSubscriptions: var_activeSubscriptions); }
+
+@protected MutationCall sse_decode_mutation_call(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_args = sse_decode_Map_String_String_None(deserializer);
+return MutationCall(name: var_name, args: var_args); }
+
+@protected MutationLifecycleEvent sse_decode_mutation_lifecycle_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_mutationId = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_status = sse_decode_mutation_status(deserializer);
+return MutationLifecycleEvent(mutationId: var_mutationId, name: var_name, status: var_status); }
+
+@protected MutationRetryOptions sse_decode_mutation_retry_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_initialBackoffMs = sse_decode_u_64(deserializer);
+var var_maxBackoffMs = sse_decode_u_64(deserializer);
+var var_maxAttempts = sse_decode_u_32(deserializer);
+var var_idempotencyKeyArg = sse_decode_String(deserializer);
+return MutationRetryOptions(initialBackoffMs: var_initialBackoffMs, maxBackoffMs: var_maxBackoffMs, maxAttempts: var_maxAttempts, idempotencyKeyArg: var_idempotencyKeyArg); }
+
+@protected MutationSequenceResult sse_decode_mutation_sequence_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_steps = sse_decode_list_mutation_step_status(deserializer);
+var var_succeeded = sse_decode_bool(deserializer);
+return MutationSequenceResult(steps: var_steps, succeeded: var_succeeded); }
+
+@protected MutationStatus sse_decode_mutation_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return MutationStatus_Queued();case 1: return MutationStatus_Sending();case 2: var var_attempt = sse_decode_u_32(deserializer);
+var var_error = sse_decode_String(deserializer);
+return MutationStatus_Retrying(attempt: var_attempt, error: var_error);case 3: var var_completedAtMs = sse_decode_i_64(deserializer);
+return MutationStatus_Succeeded(completedAtMs: var_completedAtMs);case 4: var var_error = sse_decode_String(deserializer);
+return MutationStatus_Failed(error: var_error); default: throw UnimplementedError(''); }
+             }
+
+@protected MutationStepStatus sse_decode_mutation_step_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_value = sse_decode_String(deserializer);
+return MutationStepStatus_Succeeded(value: var_value);case 1: var var_message = sse_decode_String(deserializer);
+var var_data = sse_decode_opt_String(deserializer);
+return MutationStepStatus_Failed(message: var_message, data: var_data);case 2: return MutationStepStatus_Skipped(); default: throw UnimplementedError(''); }
+             }
+
+@protected NullHandling sse_decode_null_handling(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return NullHandling.values[inner]; }
+
+@protected OfflineMutationResult sse_decode_offline_mutation_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_value = sse_decode_String(deserializer);
+return OfflineMutationResult_Completed(value: var_value);case 1: var var_outboxId = sse_decode_String(deserializer);
+return OfflineMutationResult_Queued(outboxId: var_outboxId); default: throw UnimplementedError(''); }
+             }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected AuditLogOptions? sse_decode_opt_box_autoadd_audit_log_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_audit_log_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Backpressure? sse_decode_opt_box_autoadd_backpressure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_backpressure(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FailoverOptions? sse_decode_opt_box_autoadd_failover_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_failover_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PersistedResultOptions? sse_decode_opt_box_autoadd_persisted_result_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_persisted_result_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SchemaCheckOptions? sse_decode_opt_box_autoadd_schema_check_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_schema_check_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SubscribeOptions? sse_decode_opt_box_autoadd_subscribe_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_subscribe_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_16(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UiHint? sse_decode_opt_box_autoadd_ui_hint(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_ui_hint(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected UsageBudget? sse_decode_opt_box_autoadd_usage_budget(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_usage_budget(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_list_prim_u_8_strict(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OptimisticUpdate sse_decode_optimistic_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_queryName = sse_decode_String(deserializer);
+var var_queryArgs = sse_decode_Map_String_String_None(deserializer);
+var var_patch = sse_decode_String(deserializer);
+return OptimisticUpdate(queryName: var_queryName, queryArgs: var_queryArgs, patch: var_patch); }
+
+@protected OutboxCompletion sse_decode_outbox_completion(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_outboxId = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_value = sse_decode_opt_String(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return OutboxCompletion(outboxId: var_outboxId, name: var_name, value: var_value, error: var_error); }
+
+@protected OutboxConflict sse_decode_outbox_conflict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_outboxId = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_args = sse_decode_String(deserializer);
+var var_error = sse_decode_String(deserializer);
+var var_data = sse_decode_opt_String(deserializer);
+return OutboxConflict(outboxId: var_outboxId, name: var_name, args: var_args, error: var_error, data: var_data); }
+
+@protected PaginatedUpdate sse_decode_paginated_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_results = sse_decode_String(deserializer);
+var var_status = sse_decode_pagination_status(deserializer);
+var var_pageBoundaries = sse_decode_list_String(deserializer);
+return PaginatedUpdate(results: var_results, status: var_status, pageBoundaries: var_pageBoundaries); }
+
+@protected PaginationStatus sse_decode_pagination_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PaginationStatus.values[inner]; }
+
+@protected PanicReport sse_decode_panic_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_message = sse_decode_String(deserializer);
+var var_location = sse_decode_opt_String(deserializer);
+var var_thread = sse_decode_opt_String(deserializer);
+var var_backtrace = sse_decode_String(deserializer);
+var var_timestampMs = sse_decode_i_64(deserializer);
+return PanicReport(message: var_message, location: var_location, thread: var_thread, backtrace: var_backtrace, timestampMs: var_timestampMs); }
+
+@protected PatchUpdate sse_decode_patch_update(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_value = sse_decode_String(deserializer);
+return PatchUpdate_Snapshot(value: var_value);case 1: var var_operations = sse_decode_String(deserializer);
+return PatchUpdate_Patch(operations: var_operations); default: throw UnimplementedError(''); }
+             }
+
+@protected PersistedResultOptions sse_decode_persisted_result_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ttlMs = sse_decode_u_64(deserializer);
+var var_maxBytes = sse_decode_u_64(deserializer);
+var var_flushIntervalMs = sse_decode_u_64(deserializer);
+return PersistedResultOptions(ttlMs: var_ttlMs, maxBytes: var_maxBytes, flushIntervalMs: var_flushIntervalMs); }
+
+@protected PlaceholderPolicy sse_decode_placeholder_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PlaceholderPolicy.values[inner]; }
+
+@protected PresenceOptions sse_decode_presence_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userField = sse_decode_String(deserializer);
+var var_lastSeenField = sse_decode_String(deserializer);
+var var_onlineThresholdMs = sse_decode_u_64(deserializer);
+return PresenceOptions(userField: var_userField, lastSeenField: var_lastSeenField, onlineThresholdMs: var_onlineThresholdMs); }
+
+@protected PresenceStatus sse_decode_presence_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_userId = sse_decode_String(deserializer);
+var var_isOnline = sse_decode_bool(deserializer);
+var var_lastSeenMs = sse_decode_i_64(deserializer);
+return PresenceStatus(userId: var_userId, isOnline: var_isOnline, lastSeenMs: var_lastSeenMs); }
+
+@protected PressureThrottle sse_decode_pressure_throttle(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_moderateIntervalMs = sse_decode_u_64(deserializer);
+var var_highIntervalMs = sse_decode_u_64(deserializer);
+return PressureThrottle(moderateIntervalMs: var_moderateIntervalMs, highIntervalMs: var_highIntervalMs); }
+
+@protected PreviewOptions sse_decode_preview_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_urlTemplate = sse_decode_String(deserializer);
+var var_aliases = sse_decode_Map_String_String_None(deserializer);
+return PreviewOptions(urlTemplate: var_urlTemplate, aliases: var_aliases); }
+
+@protected QueryDependency sse_decode_query_dependency(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_name = sse_decode_String(deserializer);
+return QueryDependency_Mutation(name: var_name);case 1: var var_name = sse_decode_String(deserializer);
+return QueryDependency_Query(name: var_name); default: throw UnimplementedError(''); }
+             }
+
+@protected (String,ConvexValue) sse_decode_record_string_convex_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_convex_value(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,double) sse_decode_record_string_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_f_64(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected RequestIdOptions sse_decode_request_id_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_inErrors = sse_decode_bool(deserializer);
+var var_argName = sse_decode_opt_String(deserializer);
+return RequestIdOptions(inErrors: var_inErrors, argName: var_argName); }
+
+@protected ResultCacheOptions sse_decode_result_cache_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxEntries = sse_decode_u_32(deserializer);
+var var_maxBytes = sse_decode_u_64(deserializer);
+return ResultCacheOptions(maxEntries: var_maxEntries, maxBytes: var_maxBytes); }
+
+@protected ResultChunk sse_decode_result_chunk(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_result = sse_decode_u_64(deserializer);
+var var_index = sse_decode_u_32(deserializer);
+var var_last = sse_decode_bool(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+return ResultChunk(result: var_result, index: var_index, last: var_last, data: var_data); }
+
+@protected RuntimeMetrics sse_decode_runtime_metrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_workers = sse_decode_u_32(deserializer);
+var var_aliveTasks = sse_decode_u_64(deserializer);
+var var_globalQueueDepth = sse_decode_u_64(deserializer);
+var var_workerUtilization = sse_decode_f_64(deserializer);
+var var_meanSchedulingLagMs = sse_decode_f_64(deserializer);
+var var_maxSchedulingLagMs = sse_decode_f_64(deserializer);
+return RuntimeMetrics(workers: var_workers, aliveTasks: var_aliveTasks, globalQueueDepth: var_globalQueueDepth, workerUtilization: var_workerUtilization, meanSchedulingLagMs: var_meanSchedulingLagMs, maxSchedulingLagMs: var_maxSchedulingLagMs); }
+
+@protected SchemaCheckOptions sse_decode_schema_check_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fingerprintQuery = sse_decode_String(deserializer);
+var var_invalidateCache = sse_decode_bool(deserializer);
+return SchemaCheckOptions(fingerprintQuery: var_fingerprintQuery, invalidateCache: var_invalidateCache); }
+
+@protected ShardMapping sse_decode_shard_mapping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_batchQuery = sse_decode_String(deserializer);
+var var_keyArg = sse_decode_String(deserializer);
+var var_batchArg = sse_decode_String(deserializer);
+var var_maxKeysPerShard = sse_decode_u_32(deserializer);
+return ShardMapping(batchQuery: var_batchQuery, keyArg: var_keyArg, batchArg: var_batchArg, maxKeysPerShard: var_maxKeysPerShard); }
+
+@protected ShardingMetrics sse_decode_sharding_metrics(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_logicalSubscriptions = sse_decode_u_32(deserializer);
+var var_serverSubscriptions = sse_decode_u_32(deserializer);
+return ShardingMetrics(logicalSubscriptions: var_logicalSubscriptions, serverSubscriptions: var_serverSubscriptions); }
+
+@protected SlowInitializationEvent sse_decode_slow_initialization_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_elapsedMs = sse_decode_u_64(deserializer);
+var var_waitingCallers = sse_decode_u_32(deserializer);
+return SlowInitializationEvent(elapsedMs: var_elapsedMs, waitingCallers: var_waitingCallers); }
+
+@protected SubscribeOptions sse_decode_subscribe_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_priority = sse_decode_subscription_priority(deserializer);
+var var_valueArgs = sse_decode_Map_String_convex_value_None(deserializer);
+var var_distinct = sse_decode_bool(deserializer);
+var var_projection = sse_decode_opt_list_String(deserializer);
+var var_backpressure = sse_decode_opt_box_autoadd_backpressure(deserializer);
+return SubscribeOptions(priority: var_priority, valueArgs: var_valueArgs, distinct: var_distinct, projection: var_projection, backpressure: var_backpressure); }
+
+@protected SubscriptionCloseReason sse_decode_subscription_close_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SubscriptionCloseReason.values[inner]; }
+
+@protected SubscriptionEvent sse_decode_subscription_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_value = sse_decode_String(deserializer);
+return SubscriptionEvent_Update(value: var_value);case 1: var var_message = sse_decode_String(deserializer);
+var var_data = sse_decode_opt_String(deserializer);
+return SubscriptionEvent_Error(message: var_message, data: var_data);case 2: return SubscriptionEvent_Resubscribed();case 3: return SubscriptionEvent_Paused();case 4: return SubscriptionEvent_Resumed();case 5: return SubscriptionEvent_Stale();case 6: var var_reason = sse_decode_subscription_close_reason(deserializer);
+return SubscriptionEvent_Closed(reason: var_reason); default: throw UnimplementedError(''); }
+             }
+
+@protected SubscriptionInfo sse_decode_subscription_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_u_64(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_argsHash = sse_decode_String(deserializer);
+var var_createdAtMs = sse_decode_i_64(deserializer);
+var var_updateCount = sse_decode_u_64(deserializer);
+return SubscriptionInfo(id: var_id, name: var_name, argsHash: var_argsHash, createdAtMs: var_createdAtMs, updateCount: var_updateCount); }
+
+@protected SubscriptionPriority sse_decode_subscription_priority(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SubscriptionPriority.values[inner]; }
+
+@protected SubscriptionState sse_decode_subscription_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SubscriptionState.values[inner]; }
+
+@protected TelemetryClass sse_decode_telemetry_class(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TelemetryClass.values[inner]; }
+
+@protected TelemetrySampling sse_decode_telemetry_sampling(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_classRates = sse_decode_list_class_sample_rate(deserializer);
+var var_functionRates = sse_decode_Map_String_f_64_None(deserializer);
+var var_debugOnly = sse_decode_list_telemetry_class(deserializer);
+return TelemetrySampling(classRates: var_classRates, functionRates: var_functionRates, debugOnly: var_debugOnly); }
+
+@protected TraceExportOptions sse_decode_trace_export_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_endpoint = sse_decode_String(deserializer);
+var var_headers = sse_decode_Map_String_String_None(deserializer);
+var var_serviceName = sse_decode_String(deserializer);
+return TraceExportOptions(endpoint: var_endpoint, headers: var_headers, serviceName: var_serviceName); }
+
+@protected TransferProgress sse_decode_transfer_progress(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_transferredBytes = sse_decode_u_64(deserializer);
+var var_totalBytes = sse_decode_opt_box_autoadd_u_64(deserializer);
+return TransferProgress(transferredBytes: var_transferredBytes, totalBytes: var_totalBytes); }
+
+@protected TypeCodec sse_decode_type_codec(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_typeName = sse_decode_String(deserializer);
+var var_wire = sse_decode_codec_wire(deserializer);
+var var_fields = sse_decode_list_String(deserializer);
+return TypeCodec(typeName: var_typeName, wire: var_wire, fields: var_fields); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected UiHint sse_decode_ui_hint(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_code = sse_decode_ui_hint_code(deserializer);
+var var_params = sse_decode_Map_String_String_None(deserializer);
+return UiHint(code: var_code, params: var_params); }
+
+@protected UiHintCode sse_decode_ui_hint_code(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiHintCode.values[inner]; }
+
+@protected UiPressure sse_decode_ui_pressure(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UiPressure.values[inner]; }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected UploadSource sse_decode_upload_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_path = sse_decode_String(deserializer);
+return UploadSource_Path(path: var_path);case 1: var var_bytes = sse_decode_list_prim_u_8_strict(deserializer);
+return UploadSource_Bytes(bytes: var_bytes); default: throw UnimplementedError(''); }
+             }
+
+@protected UsageBudget sse_decode_usage_budget(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxCallsPerMinute = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_maxBytesPerDay = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_enforcement = sse_decode_budget_enforcement(deserializer);
+return UsageBudget(maxCallsPerMinute: var_maxCallsPerMinute, maxBytesPerDay: var_maxBytesPerDay, enforcement: var_enforcement); }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected WatchEvent sse_decode_watch_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return WatchEvent_Loading();case 1: var var_value = sse_decode_String(deserializer);
+return WatchEvent_Cached(value: var_value);case 2: var var_value = sse_decode_String(deserializer);
+return WatchEvent_Update(value: var_value);case 3: var var_message = sse_decode_String(deserializer);
+var var_data = sse_decode_opt_String(deserializer);
+return WatchEvent_Error(message: var_message, data: var_data); default: throw UnimplementedError(''); }
+             }
+
+@protected WebSocketConnectionState sse_decode_web_socket_connection_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return WebSocketConnectionState_Connected();case 1: return WebSocketConnectionState_Connecting();case 2: var var_reason = sse_decode_connection_close_reason(deserializer);
+return WebSocketConnectionState_Closed(reason: var_reason);case 3: var var_retryInMs = sse_decode_u_64(deserializer);
+var var_attempt = sse_decode_u_32(deserializer);
+return WebSocketConnectionState_Backoff(retryInMs: var_retryInMs, attempt: var_attempt);case 4: var var_reason = sse_decode_String(deserializer);
+return WebSocketConnectionState_Failed(reason: var_reason); default: throw UnimplementedError(''); }
+             }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(AuthHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AuthHandleImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(CallbackSubscriber self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CallbackSubscriberImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(CallbackSubscriberDartFn self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CallbackSubscriberDartFnImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(CancellationToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CancellationTokenImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(MobileConvexClient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MobileConvexClientImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(PaginatedQueryHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PaginatedQueryHandleImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(PresenceHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PresenceHandleImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SubscriptionHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SubscriptionHandleImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(AuthHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AuthHandleImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(CallbackSubscriber self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CallbackSubscriberImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(CallbackSubscriberDartFn self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CallbackSubscriberDartFnImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(CancellationToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CancellationTokenImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(MobileConvexClient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MobileConvexClientImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(PaginatedQueryHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PaginatedQueryHandleImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(PresenceHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PresenceHandleImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SubscriptionHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SubscriptionHandleImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String?) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs__Output_opt_String_AnyhowException(FutureOr<String?> Function() self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs__Output_opt_String_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs__Output_unit_AnyhowException(FutureOr<void> Function() self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs__Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_auth_change_Output_unit_AnyhowException(FutureOr<void> Function(AuthChange) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_auth_change_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_backend_changed_event_Output_unit_AnyhowException(FutureOr<void> Function(BackendChangedEvent) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_backend_changed_event_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(FutureOr<void> Function(bool) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_bool_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_budget_exceeded_event_Output_unit_AnyhowException(FutureOr<void> Function(BudgetExceededEvent) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_budget_exceeded_event_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_client_event_Output_unit_AnyhowException(FutureOr<void> Function(ClientEvent) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_client_event_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_connection_quality_Output_unit_AnyhowException(FutureOr<void> Function(ConnectionQuality) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_connection_quality_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_convex_value_Output_unit_AnyhowException(FutureOr<void> Function(ConvexValue) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_convex_value_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_failover_event_Output_unit_AnyhowException(FutureOr<void> Function(FailoverEvent) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_failover_event_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_intercepted_call_Output_intercept_decision_AnyhowException(FutureOr<InterceptDecision> Function(InterceptedCall) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_intercepted_call_Output_intercept_decision_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_intercepted_call_call_outcome_Output_call_outcome_AnyhowException(FutureOr<CallOutcome> Function(InterceptedCall, CallOutcome) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_intercepted_call_call_outcome_Output_call_outcome_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_job_progress_Output_unit_AnyhowException(FutureOr<void> Function(JobProgress) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_job_progress_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_list_presence_status_Output_unit_AnyhowException(FutureOr<void> Function(List<PresenceStatus>) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_list_presence_status_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_list_update_Output_unit_AnyhowException(FutureOr<void> Function(ListUpdate) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_list_update_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_log_record_Output_unit_AnyhowException(FutureOr<void> Function(LogRecord) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_log_record_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_logout_event_Output_unit_AnyhowException(FutureOr<void> Function(LogoutEvent) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_logout_event_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_memory_released_event_Output_unit_AnyhowException(FutureOr<void> Function(MemoryReleasedEvent) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_memory_released_event_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_mutation_lifecycle_event_Output_unit_AnyhowException(FutureOr<void> Function(MutationLifecycleEvent) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_mutation_lifecycle_event_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_opt_box_autoadd_ui_hint_Output_unit_AnyhowException(FutureOr<void> Function(UiHint?) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_opt_box_autoadd_ui_hint_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_outbox_completion_Output_unit_AnyhowException(FutureOr<void> Function(OutboxCompletion) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_outbox_completion_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_outbox_conflict_Output_conflict_resolution_AnyhowException(FutureOr<ConflictResolution> Function(OutboxConflict) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_outbox_conflict_Output_conflict_resolution_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_paginated_update_Output_unit_AnyhowException(FutureOr<void> Function(PaginatedUpdate) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_paginated_update_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_panic_report_Output_unit_AnyhowException(FutureOr<void> Function(PanicReport) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_panic_report_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_patch_update_Output_unit_AnyhowException(FutureOr<void> Function(PatchUpdate) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_patch_update_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_result_chunk_Output_unit_AnyhowException(FutureOr<void> Function(ResultChunk) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_result_chunk_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_slow_initialization_event_Output_unit_AnyhowException(FutureOr<void> Function(SlowInitializationEvent) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_slow_initialization_event_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_subscription_event_Output_unit_AnyhowException(FutureOr<void> Function(SubscriptionEvent) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_subscription_event_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_transfer_progress_Output_unit_AnyhowException(FutureOr<void> Function(TransferProgress) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_transfer_progress_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_watch_event_Output_unit_AnyhowException(FutureOr<void> Function(WatchEvent) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_watch_event_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(FutureOr<void> Function(WebSocketConnectionState) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_DartOpaque(encode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(self), serializer); }
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_isize(PlatformPointerUtil.ptrToPlatformInt64(encodeDartOpaque(self, portManager.dartHandlerPort, generalizedFrbRustBinding)), serializer); }
+
+@protected void sse_encode_Map_String_String_None(Map<String, String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_string(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_String_convex_value_None(Map<String, ConvexValue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_convex_value(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_String_f_64_None(Map<String, double> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_f_64(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(AuthHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AuthHandleImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(CallbackSubscriber self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CallbackSubscriberImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(CallbackSubscriberDartFn self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CallbackSubscriberDartFnImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(CancellationToken self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CancellationTokenImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(MobileConvexClient self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as MobileConvexClientImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(PaginatedQueryHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PaginatedQueryHandleImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(PresenceHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PresenceHandleImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SubscriptionHandle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SubscriptionHandleImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_String,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_audit_log_options(AuditLogOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.directory, serializer);
+sse_encode_u_64(self.maxFileBytes, serializer);
+sse_encode_u_32(self.maxFiles, serializer);
+ }
+
+@protected void sse_encode_auth_change(AuthChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.authenticated, serializer);
+sse_encode_auth_change_reason(self.reason, serializer);
+ }
+
+@protected void sse_encode_auth_change_reason(AuthChangeReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_auth_refresh_config(AuthRefreshConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.bufferSecs, serializer);
+sse_encode_u_64(self.minIntervalSecs, serializer);
+sse_encode_u_64(self.defaultIntervalSecs, serializer);
+sse_encode_u_8(self.jitterPct, serializer);
+ }
+
+@protected void sse_encode_backend_changed_event(BackendChangedEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.previousFingerprint, serializer);
+sse_encode_String(self.currentFingerprint, serializer);
+sse_encode_bool(self.cacheInvalidated, serializer);
+ }
+
+@protected void sse_encode_backpressure(Backpressure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case Backpressure_LatestOnly(): sse_encode_i_32(0, serializer); case Backpressure_Debounce(ms: final ms): sse_encode_i_32(1, serializer); sse_encode_u_32(ms, serializer);
+case Backpressure_Throttle(ms: final ms): sse_encode_i_32(2, serializer); sse_encode_u_32(ms, serializer);
+case Backpressure_Buffer(size: final size): sse_encode_i_32(3, serializer); sse_encode_u_32(size, serializer);
+  } }
+
+@protected void sse_encode_batch_query(BatchQuery self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_Map_String_String_None(self.args, serializer);
+ }
+
+@protected void sse_encode_blank_arg_handling(BlankArgHandling self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_audit_log_options(AuditLogOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_audit_log_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_auth_refresh_config(AuthRefreshConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_auth_refresh_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_backpressure(Backpressure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_backpressure(self, serializer); }
+
+@protected void sse_encode_box_autoadd_client_error(ClientError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_client_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_client_options(ClientOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_client_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_combine_strategy(CombineStrategy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_combine_strategy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_convex_value(ConvexValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_convex_value(self, serializer); }
+
+@protected void sse_encode_box_autoadd_dart_keepalive_options(DartKeepaliveOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_dart_keepalive_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_failover_options(FailoverOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_failover_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_function_fault(FunctionFault self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_function_fault(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_intercepted_call(InterceptedCall self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_intercepted_call(self, serializer); }
+
+@protected void sse_encode_box_autoadd_job_options(JobOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_job_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_persisted_result_options(PersistedResultOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_persisted_result_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_presence_options(PresenceOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_presence_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_result_cache_options(ResultCacheOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_result_cache_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_schema_check_options(SchemaCheckOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_schema_check_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_shard_mapping(ShardMapping self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_shard_mapping(self, serializer); }
+
+@protected void sse_encode_box_autoadd_subscribe_options(SubscribeOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_subscribe_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_telemetry_sampling(TelemetrySampling self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_telemetry_sampling(self, serializer); }
+
+@protected void sse_encode_box_autoadd_trace_export_options(TraceExportOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_trace_export_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_ui_hint(UiHint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_hint(self, serializer); }
+
+@protected void sse_encode_box_autoadd_upload_source(UploadSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_upload_source(self, serializer); }
+
+@protected void sse_encode_box_autoadd_usage_budget(UsageBudget self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usage_budget(self, serializer); }
+
+@protected void sse_encode_budget_enforcement(BudgetEnforcement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_budget_exceeded_event(BudgetExceededEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.function, serializer);
+sse_encode_budget_limit(self.limit, serializer);
+sse_encode_budget_enforcement(self.enforcement, serializer);
+ }
+
+@protected void sse_encode_budget_limit(BudgetLimit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_call_kind(CallKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_call_outcome(CallOutcome self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case CallOutcome_Value(json: final json): sse_encode_i_32(0, serializer); sse_encode_String(json, serializer);
+case CallOutcome_ConvexError(message: final message,data: final data): sse_encode_i_32(1, serializer); sse_encode_String(message, serializer);
+sse_encode_String(data, serializer);
+case CallOutcome_ServerError(message: final message): sse_encode_i_32(2, serializer); sse_encode_String(message, serializer);
+case CallOutcome_TransportError(message: final message): sse_encode_i_32(3, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_chunked_result_options(ChunkedResultOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.thresholdBytes, serializer);
+sse_encode_u_64(self.chunkBytes, serializer);
+ }
+
+@protected void sse_encode_class_sample_rate(ClassSampleRate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_telemetry_class(self.class_, serializer);
+sse_encode_f_64(self.rate, serializer);
+ }
+
+@protected void sse_encode_client_error(ClientError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ClientError_InternalError(msg: final msg,code: final code,retryable: final retryable): sse_encode_i_32(0, serializer); sse_encode_String(msg, serializer);
+sse_encode_error_code(code, serializer);
+sse_encode_bool(retryable, serializer);
+case ClientError_ConvexError(message: final message,data: final data,code: final code,retryable: final retryable): sse_encode_i_32(1, serializer); sse_encode_String(message, serializer);
+sse_encode_box_autoadd_convex_value(data, serializer);
+sse_encode_error_code(code, serializer);
+sse_encode_bool(retryable, serializer);
+case ClientError_ServerError(msg: final msg,code: final code,retryable: final retryable): sse_encode_i_32(2, serializer); sse_encode_String(msg, serializer);
+sse_encode_error_code(code, serializer);
+sse_encode_bool(retryable, serializer);
+case ClientError_Timeout(timeoutMs: final timeoutMs,code: final code,retryable: final retryable): sse_encode_i_32(3, serializer); sse_encode_u_64(timeoutMs, serializer);
+sse_encode_error_code(code, serializer);
+sse_encode_bool(retryable, serializer);
+  } }
+
+@protected void sse_encode_client_event(ClientEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ClientEvent_Connected(): sse_encode_i_32(0, serializer); case ClientEvent_Disconnected(): sse_encode_i_32(1, serializer); case ClientEvent_AuthChanged(authenticated: final authenticated): sse_encode_i_32(2, serializer); sse_encode_bool(authenticated, serializer);
+case ClientEvent_SubscriptionError(name: final name,message: final message): sse_encode_i_32(3, serializer); sse_encode_String(name, serializer);
+sse_encode_String(message, serializer);
+case ClientEvent_AuthRejected(name: final name,message: final message): sse_encode_i_32(4, serializer); sse_encode_String(name, serializer);
+sse_encode_String(message, serializer);
+case ClientEvent_MutationRetried(name: final name,attempt: final attempt,error: final error): sse_encode_i_32(5, serializer); sse_encode_String(name, serializer);
+sse_encode_u_32(attempt, serializer);
+sse_encode_String(error, serializer);
+case ClientEvent_CacheHit(name: final name): sse_encode_i_32(6, serializer); sse_encode_String(name, serializer);
+  } }
+
+@protected void sse_encode_client_metrics(ClientMetrics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_connection_quality(self.connection, serializer);
+sse_encode_runtime_metrics(self.runtime, serializer);
+ }
+
+@protected void sse_encode_client_options(ClientOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_null_handling(self.nullHandling, serializer);
+sse_encode_opt_box_autoadd_u_32(self.maxConcurrentResubscribes, serializer);
+sse_encode_opt_box_autoadd_audit_log_options(self.auditLog, serializer);
+sse_encode_pressure_throttle(self.pressureThrottle, serializer);
+sse_encode_opt_String(self.storageRoot, serializer);
+sse_encode_opt_box_autoadd_u_16(self.debugMetricsPort, serializer);
+sse_encode_opt_box_autoadd_failover_options(self.failover, serializer);
+sse_encode_bool(self.unauthenticated, serializer);
+sse_encode_int_64_encoding(self.int64Encoding, serializer);
+sse_encode_blank_arg_handling(self.blankArgs, serializer);
+sse_encode_connect_retry_options(self.connectRetry, serializer);
+sse_encode_opt_box_autoadd_u_64(self.subscribeBatchWindowMs, serializer);
+sse_encode_opt_box_autoadd_schema_check_options(self.schemaCheck, serializer);
+sse_encode_bool(self.incognito, serializer);
+sse_encode_list_type_codec(self.typeCodecs, serializer);
+sse_encode_opt_box_autoadd_usage_budget(self.usageBudget, serializer);
+sse_encode_bool(self.shareSubscriptions, serializer);
+sse_encode_deferred_mutation_options(self.deferredMutations, serializer);
+sse_encode_opt_box_autoadd_u_64(self.slowInitializationMs, serializer);
+sse_encode_telemetry_sampling(self.telemetrySampling, serializer);
+sse_encode_preview_options(self.previews, serializer);
+sse_encode_mutation_retry_options(self.mutationRetry, serializer);
+sse_encode_result_cache_options(self.resultCache, serializer);
+sse_encode_opt_box_autoadd_persisted_result_options(self.resultCachePersistence, serializer);
+sse_encode_bool(self.requireStorageKey, serializer);
+sse_encode_opt_String(self.httpActionsUrl, serializer);
+sse_encode_file_storage_options(self.fileStorage, serializer);
+sse_encode_chunked_result_options(self.chunkedResults, serializer);
+sse_encode_request_id_options(self.requestIds, serializer);
+ }
+
+@protected void sse_encode_codec_wire(CodecWire self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_combine_strategy(CombineStrategy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case CombineStrategy_Array(): sse_encode_i_32(0, serializer); case CombineStrategy_Keyed(keys: final keys): sse_encode_i_32(1, serializer); sse_encode_list_String(keys, serializer);
+case CombineStrategy_Merge(): sse_encode_i_32(2, serializer);   } }
+
+@protected void sse_encode_committed_mutation(CommittedMutation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.value, serializer);
+sse_encode_String(self.commitToken, serializer);
+ }
+
+@protected void sse_encode_conflict_resolution(ConflictResolution self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ConflictResolution_Retry(): sse_encode_i_32(0, serializer); case ConflictResolution_Drop(): sse_encode_i_32(1, serializer); case ConflictResolution_Transform(args: final args): sse_encode_i_32(2, serializer); sse_encode_Map_String_String_None(args, serializer);
+  } }
+
+@protected void sse_encode_connect_retry_options(ConnectRetryOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.initialBackoffMs, serializer);
+sse_encode_u_64(self.maxBackoffMs, serializer);
+sse_encode_u_32(self.maxAttempts, serializer);
+ }
+
+@protected void sse_encode_connection_close_reason(ConnectionCloseReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_connection_quality(ConnectionQuality self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.score, serializer);
+sse_encode_connection_quality_level(self.level, serializer);
+sse_encode_f_64(self.rttMs, serializer);
+sse_encode_f_64(self.failureRate, serializer);
+sse_encode_u_32(self.recentReconnects, serializer);
+ }
+
+@protected void sse_encode_connection_quality_level(ConnectionQualityLevel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_convex_value(ConvexValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ConvexValue_Null(): sse_encode_i_32(0, serializer); case ConvexValue_Bool(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_bool(field0, serializer);
+case ConvexValue_Int64(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_i_64(field0, serializer);
+case ConvexValue_Float64(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_f_64(field0, serializer);
+case ConvexValue_String(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_String(field0, serializer);
+case ConvexValue_Bytes(field0: final field0): sse_encode_i_32(5, serializer); sse_encode_list_prim_u_8_strict(field0, serializer);
+case ConvexValue_Array(field0: final field0): sse_encode_i_32(6, serializer); sse_encode_list_convex_value(field0, serializer);
+case ConvexValue_Object(field0: final field0): sse_encode_i_32(7, serializer); sse_encode_Map_String_convex_value_None(field0, serializer);
+  } }
+
+@protected void sse_encode_dart_keepalive_options(DartKeepaliveOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.intervalMs, serializer);
+sse_encode_u_64(self.timeoutMs, serializer);
+sse_encode_u_32(self.maxMissedPings, serializer);
+ }
+
+@protected void sse_encode_dart_liveness(DartLiveness self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_dart_liveness_state(self.state, serializer);
+sse_encode_opt_box_autoadd_i_64(self.lastResponseMs, serializer);
+sse_encode_u_32(self.missedPings, serializer);
+sse_encode_u_32(self.orphanedSubscriptions, serializer);
+ }
+
+@protected void sse_encode_dart_liveness_state(DartLivenessState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_deferred_mutation_options(DeferredMutationOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxBatchSize, serializer);
+ }
+
+@protected void sse_encode_derived_field(DerivedField self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_derived_value(self.value, serializer);
+ }
+
+@protected void sse_encode_derived_source(DerivedSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_Map_String_String_None(self.args, serializer);
+ }
+
+@protected void sse_encode_derived_value(DerivedValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case DerivedValue_Pick(source: final source,path: final path): sse_encode_i_32(0, serializer); sse_encode_u_32(source, serializer);
+sse_encode_String(path, serializer);
+case DerivedValue_Count(source: final source): sse_encode_i_32(1, serializer); sse_encode_u_32(source, serializer);
+case DerivedValue_JoinById(source: final source,withSource: final withSource,foreignKey: final foreignKey,asField: final asField): sse_encode_i_32(2, serializer); sse_encode_u_32(source, serializer);
+sse_encode_u_32(withSource, serializer);
+sse_encode_String(foreignKey, serializer);
+sse_encode_String(asField, serializer);
+  } }
+
+@protected void sse_encode_error_code(ErrorCode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_failover_event(FailoverEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.fromUrl, serializer);
+sse_encode_String(self.toUrl, serializer);
+sse_encode_failover_reason(self.reason, serializer);
+ }
+
+@protected void sse_encode_failover_options(FailoverOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.fallbackUrls, serializer);
+sse_encode_u_64(self.failoverAfterMs, serializer);
+sse_encode_u_64(self.failbackProbeIntervalMs, serializer);
+ }
+
+@protected void sse_encode_failover_reason(FailoverReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_file_storage_options(FileStorageOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.generateUploadUrlMutation, serializer);
+sse_encode_u_32(self.downloadAttempts, serializer);
+sse_encode_u_64(self.downloadBackoffMs, serializer);
+sse_encode_u_64(self.downloadReadTimeoutMs, serializer);
+ }
+
+@protected void sse_encode_function_fault(FunctionFault self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.latencyMs, serializer);
+sse_encode_f_64(self.failureRate, serializer);
+ }
+
+@protected void sse_encode_function_metrics(FunctionMetrics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_64(self.calls, serializer);
+sse_encode_u_64(self.errors, serializer);
+sse_encode_u_64(self.retries, serializer);
+sse_encode_latency_histogram(self.latency, serializer);
+sse_encode_u_64(self.argsBytes, serializer);
+sse_encode_u_64(self.resultBytes, serializer);
+sse_encode_u_64(self.maxResultBytes, serializer);
+ }
+
+@protected void sse_encode_http_action_response(HttpActionResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self.status, serializer);
+sse_encode_Map_String_String_None(self.headers, serializer);
+sse_encode_list_prim_u_8_strict(self.body, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_idempotent_mutation_result(IdempotentMutationResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.value, serializer);
+sse_encode_String(self.idempotencyKey, serializer);
+sse_encode_u_32(self.attempts, serializer);
+ }
+
+@protected void sse_encode_initialization_diagnostics(InitializationDiagnostics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.initialized, serializer);
+sse_encode_opt_box_autoadd_i_64(self.initializedAtMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.lastDurationMs, serializer);
+sse_encode_u_32(self.waitingCallers, serializer);
+sse_encode_opt_String(self.lastError, serializer);
+ }
+
+@protected void sse_encode_int_64_encoding(Int64Encoding self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_intercept_decision(InterceptDecision self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case InterceptDecision_Proceed(call: final call): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_intercepted_call(call, serializer);
+case InterceptDecision_Veto(reason: final reason): sse_encode_i_32(1, serializer); sse_encode_String(reason, serializer);
+  } }
+
+@protected void sse_encode_intercepted_call(InterceptedCall self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_call_kind(self.kind, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.args, serializer);
+sse_encode_Map_String_String_None(self.metadata, serializer);
+ }
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_job_options(JobOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.jobIdArg, serializer);
+sse_encode_String(self.statusQuery, serializer);
+ }
+
+@protected void sse_encode_job_progress(JobProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.jobId, serializer);
+sse_encode_String(self.status, serializer);
+ }
+
+@protected void sse_encode_latency_histogram(LatencyHistogram self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_64_strict(self.boundsMs, serializer);
+sse_encode_list_prim_u_64_strict(self.counts, serializer);
+sse_encode_f_64(self.sumMs, serializer);
+sse_encode_f_64(self.maxMs, serializer);
+sse_encode_f_64(self.p50Ms, serializer);
+sse_encode_f_64(self.p95Ms, serializer);
+ }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_batch_query(List<BatchQuery> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_batch_query(item, serializer); } }
+
+@protected void sse_encode_list_change(ListChange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ListChange_Insert(index: final index,item: final item): sse_encode_i_32(0, serializer); sse_encode_u_32(index, serializer);
+sse_encode_String(item, serializer);
+case ListChange_Remove(index: final index): sse_encode_i_32(1, serializer); sse_encode_u_32(index, serializer);
+case ListChange_Move(from: final from,to: final to): sse_encode_i_32(2, serializer); sse_encode_u_32(from, serializer);
+sse_encode_u_32(to, serializer);
+case ListChange_Change(index: final index,item: final item): sse_encode_i_32(3, serializer); sse_encode_u_32(index, serializer);
+sse_encode_String(item, serializer);
+  } }
+
+@protected void sse_encode_list_class_sample_rate(List<ClassSampleRate> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_class_sample_rate(item, serializer); } }
+
+@protected void sse_encode_list_convex_value(List<ConvexValue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_convex_value(item, serializer); } }
+
+@protected void sse_encode_list_derived_field(List<DerivedField> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_derived_field(item, serializer); } }
+
+@protected void sse_encode_list_derived_source(List<DerivedSource> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_derived_source(item, serializer); } }
+
+@protected void sse_encode_list_function_metrics(List<FunctionMetrics> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_function_metrics(item, serializer); } }
+
+@protected void sse_encode_list_list_change(List<ListChange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_change(item, serializer); } }
+
+@protected void sse_encode_list_mutation_call(List<MutationCall> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_mutation_call(item, serializer); } }
+
+@protected void sse_encode_list_mutation_step_status(List<MutationStepStatus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_mutation_step_status(item, serializer); } }
+
+@protected void sse_encode_list_optimistic_update(List<OptimisticUpdate> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_optimistic_update(item, serializer); } }
+
+@protected void sse_encode_list_presence_status(List<PresenceStatus> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_presence_status(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint64List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_query_dependency(List<QueryDependency> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_query_dependency(item, serializer); } }
+
+@protected void sse_encode_list_record_string_convex_value(List<(String,ConvexValue)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_convex_value(item, serializer); } }
+
+@protected void sse_encode_list_record_string_f_64(List<(String,double)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_f_64(item, serializer); } }
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_string(item, serializer); } }
+
+@protected void sse_encode_list_subscription_info(List<SubscriptionInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_subscription_info(item, serializer); } }
+
+@protected void sse_encode_list_telemetry_class(List<TelemetryClass> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_telemetry_class(item, serializer); } }
+
+@protected void sse_encode_list_type_codec(List<TypeCodec> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_type_codec(item, serializer); } }
+
+@protected void sse_encode_list_update(ListUpdate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ListUpdate_Reset(value: final value): sse_encode_i_32(0, serializer); sse_encode_String(value, serializer);
+case ListUpdate_Diff(changes: final changes): sse_encode_i_32(1, serializer); sse_encode_list_list_change(changes, serializer);
+  } }
+
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_log_record(LogRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_log_level(self.level, serializer);
+sse_encode_String(self.target, serializer);
+sse_encode_String(self.message, serializer);
+sse_encode_i_64(self.timestampMs, serializer);
+ }
+
+@protected void sse_encode_logout_event(LogoutEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.cancelledSubscriptions, serializer);
+sse_encode_bool(self.clearedUserData, serializer);
+ }
+
+@protected void sse_encode_memory_pressure(MemoryPressure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_memory_released_event(MemoryReleasedEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_memory_pressure(self.pressure, serializer);
+sse_encode_u_32(self.queryCacheEntries, serializer);
+sse_encode_u_32(self.actionCacheEntries, serializer);
+sse_encode_u_32(self.placeholderValues, serializer);
+sse_encode_u_32(self.deferredMutationsSent, serializer);
+sse_encode_u_64(self.bytesReleased, serializer);
+ }
+
+@protected void sse_encode_metrics_report(MetricsReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self.sinceMs, serializer);
+sse_encode_list_function_metrics(self.functions, serializer);
+sse_encode_u_64(self.retries, serializer);
+sse_encode_u_64(self.reconnects, serializer);
+sse_encode_u_32(self.activeSubscriptions, serializer);
+ }
+
+@protected void sse_encode_mutation_call(MutationCall self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_Map_String_String_None(self.args, serializer);
+ }
+
+@protected void sse_encode_mutation_lifecycle_event(MutationLifecycleEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.mutationId, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_mutation_status(self.status, serializer);
+ }
+
+@protected void sse_encode_mutation_retry_options(MutationRetryOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.initialBackoffMs, serializer);
+sse_encode_u_64(self.maxBackoffMs, serializer);
+sse_encode_u_32(self.maxAttempts, serializer);
+sse_encode_String(self.idempotencyKeyArg, serializer);
+ }
+
+@protected void sse_encode_mutation_sequence_result(MutationSequenceResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_mutation_step_status(self.steps, serializer);
+sse_encode_bool(self.succeeded, serializer);
+ }
+
+@protected void sse_encode_mutation_status(MutationStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case MutationStatus_Queued(): sse_encode_i_32(0, serializer); case MutationStatus_Sending(): sse_encode_i_32(1, serializer); case MutationStatus_Retrying(attempt: final attempt,error: final error): sse_encode_i_32(2, serializer); sse_encode_u_32(attempt, serializer);
+sse_encode_String(error, serializer);
+case MutationStatus_Succeeded(completedAtMs: final completedAtMs): sse_encode_i_32(3, serializer); sse_encode_i_64(completedAtMs, serializer);
+case MutationStatus_Failed(error: final error): sse_encode_i_32(4, serializer); sse_encode_String(error, serializer);
+  } }
+
+@protected void sse_encode_mutation_step_status(MutationStepStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case MutationStepStatus_Succeeded(value: final value): sse_encode_i_32(0, serializer); sse_encode_String(value, serializer);
+case MutationStepStatus_Failed(message: final message,data: final data): sse_encode_i_32(1, serializer); sse_encode_String(message, serializer);
+sse_encode_opt_String(data, serializer);
+case MutationStepStatus_Skipped(): sse_encode_i_32(2, serializer);   } }
+
+@protected void sse_encode_null_handling(NullHandling self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_offline_mutation_result(OfflineMutationResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case OfflineMutationResult_Completed(value: final value): sse_encode_i_32(0, serializer); sse_encode_String(value, serializer);
+case OfflineMutationResult_Queued(outboxId: final outboxId): sse_encode_i_32(1, serializer); sse_encode_String(outboxId, serializer);
+  } }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_audit_log_options(AuditLogOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_audit_log_options(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_backpressure(Backpressure? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_backpressure(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_failover_options(FailoverOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_failover_options(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_persisted_result_options(PersistedResultOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_persisted_result_options(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_schema_check_options(SchemaCheckOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_schema_check_options(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_subscribe_options(SubscribeOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_subscribe_options(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_16(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_ui_hint(UiHint? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_ui_hint(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_usage_budget(UsageBudget? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_usage_budget(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_list_prim_u_8_strict(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_optimistic_update(OptimisticUpdate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.queryName, serializer);
+sse_encode_Map_String_String_None(self.queryArgs, serializer);
+sse_encode_String(self.patch, serializer);
+ }
+
+@protected void sse_encode_outbox_completion(OutboxCompletion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.outboxId, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.value, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_outbox_conflict(OutboxConflict self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.outboxId, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.args, serializer);
+sse_encode_String(self.error, serializer);
+sse_encode_opt_String(self.data, serializer);
+ }
+
+@protected void sse_encode_paginated_update(PaginatedUpdate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.results, serializer);
+sse_encode_pagination_status(self.status, serializer);
+sse_encode_list_String(self.pageBoundaries, serializer);
+ }
+
+@protected void sse_encode_pagination_status(PaginationStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_panic_report(PanicReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer);
+sse_encode_opt_String(self.location, serializer);
+sse_encode_opt_String(self.thread, serializer);
+sse_encode_String(self.backtrace, serializer);
+sse_encode_i_64(self.timestampMs, serializer);
+ }
+
+@protected void sse_encode_patch_update(PatchUpdate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case PatchUpdate_Snapshot(value: final value): sse_encode_i_32(0, serializer); sse_encode_String(value, serializer);
+case PatchUpdate_Patch(operations: final operations): sse_encode_i_32(1, serializer); sse_encode_String(operations, serializer);
+  } }
+
+@protected void sse_encode_persisted_result_options(PersistedResultOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.ttlMs, serializer);
+sse_encode_u_64(self.maxBytes, serializer);
+sse_encode_u_64(self.flushIntervalMs, serializer);
+ }
+
+@protected void sse_encode_placeholder_policy(PlaceholderPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_presence_options(PresenceOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.userField, serializer);
+sse_encode_String(self.lastSeenField, serializer);
+sse_encode_u_64(self.onlineThresholdMs, serializer);
+ }
+
+@protected void sse_encode_presence_status(PresenceStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.userId, serializer);
+sse_encode_bool(self.isOnline, serializer);
+sse_encode_i_64(self.lastSeenMs, serializer);
+ }
+
+@protected void sse_encode_pressure_throttle(PressureThrottle self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.moderateIntervalMs, serializer);
+sse_encode_u_64(self.highIntervalMs, serializer);
+ }
+
+@protected void sse_encode_preview_options(PreviewOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.urlTemplate, serializer);
+sse_encode_Map_String_String_None(self.aliases, serializer);
+ }
+
+@protected void sse_encode_query_dependency(QueryDependency self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case QueryDependency_Mutation(name: final name): sse_encode_i_32(0, serializer); sse_encode_String(name, serializer);
+case QueryDependency_Query(name: final name): sse_encode_i_32(1, serializer); sse_encode_String(name, serializer);
+  } }
+
+@protected void sse_encode_record_string_convex_value((String,ConvexValue) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_convex_value(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_f_64((String,double) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_f_64(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_request_id_options(RequestIdOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.inErrors, serializer);
+sse_encode_opt_String(self.argName, serializer);
+ }
+
+@protected void sse_encode_result_cache_options(ResultCacheOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxEntries, serializer);
+sse_encode_u_64(self.maxBytes, serializer);
+ }
+
+@protected void sse_encode_result_chunk(ResultChunk self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.result, serializer);
+sse_encode_u_32(self.index, serializer);
+sse_encode_bool(self.last, serializer);
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+ }
+
+@protected void sse_encode_runtime_metrics(RuntimeMetrics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.workers, serializer);
+sse_encode_u_64(self.aliveTasks, serializer);
+sse_encode_u_64(self.globalQueueDepth, serializer);
+sse_encode_f_64(self.workerUtilization, serializer);
+sse_encode_f_64(self.meanSchedulingLagMs, serializer);
+sse_encode_f_64(self.maxSchedulingLagMs, serializer);
+ }
+
+@protected void sse_encode_schema_check_options(SchemaCheckOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.fingerprintQuery, serializer);
+sse_encode_bool(self.invalidateCache, serializer);
+ }
+
+@protected void sse_encode_shard_mapping(ShardMapping self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.batchQuery, serializer);
+sse_encode_String(self.keyArg, serializer);
+sse_encode_String(self.batchArg, serializer);
+sse_encode_u_32(self.maxKeysPerShard, serializer);
+ }
+
+@protected void sse_encode_sharding_metrics(ShardingMetrics self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.logicalSubscriptions, serializer);
+sse_encode_u_32(self.serverSubscriptions, serializer);
+ }
+
+@protected void sse_encode_slow_initialization_event(SlowInitializationEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.elapsedMs, serializer);
+sse_encode_u_32(self.waitingCallers, serializer);
+ }
+
+@protected void sse_encode_subscribe_options(SubscribeOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_subscription_priority(self.priority, serializer);
+sse_encode_Map_String_convex_value_None(self.valueArgs, serializer);
+sse_encode_bool(self.distinct, serializer);
+sse_encode_opt_list_String(self.projection, serializer);
+sse_encode_opt_box_autoadd_backpressure(self.backpressure, serializer);
+ }
+
+@protected void sse_encode_subscription_close_reason(SubscriptionCloseReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_subscription_event(SubscriptionEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SubscriptionEvent_Update(value: final value): sse_encode_i_32(0, serializer); sse_encode_String(value, serializer);
+case SubscriptionEvent_Error(message: final message,data: final data): sse_encode_i_32(1, serializer); sse_encode_String(message, serializer);
+sse_encode_opt_String(data, serializer);
+case SubscriptionEvent_Resubscribed(): sse_encode_i_32(2, serializer); case SubscriptionEvent_Paused(): sse_encode_i_32(3, serializer); case SubscriptionEvent_Resumed(): sse_encode_i_32(4, serializer); case SubscriptionEvent_Stale(): sse_encode_i_32(5, serializer); case SubscriptionEvent_Closed(reason: final reason): sse_encode_i_32(6, serializer); sse_encode_subscription_close_reason(reason, serializer);
+  } }
+
+@protected void sse_encode_subscription_info(SubscriptionInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.argsHash, serializer);
+sse_encode_i_64(self.createdAtMs, serializer);
+sse_encode_u_64(self.updateCount, serializer);
+ }
+
+@protected void sse_encode_subscription_priority(SubscriptionPriority self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_subscription_state(SubscriptionState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_telemetry_class(TelemetryClass self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_telemetry_sampling(TelemetrySampling self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_class_sample_rate(self.classRates, serializer);
+sse_encode_Map_String_f_64_None(self.functionRates, serializer);
+sse_encode_list_telemetry_class(self.debugOnly, serializer);
+ }
+
+@protected void sse_encode_trace_export_options(TraceExportOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.endpoint, serializer);
+sse_encode_Map_String_String_None(self.headers, serializer);
+sse_encode_String(self.serviceName, serializer);
+ }
+
+@protected void sse_encode_transfer_progress(TransferProgress self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.transferredBytes, serializer);
+sse_encode_opt_box_autoadd_u_64(self.totalBytes, serializer);
+ }
+
+@protected void sse_encode_type_codec(TypeCodec self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.typeName, serializer);
+sse_encode_codec_wire(self.wire, serializer);
+sse_encode_list_String(self.fields, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_ui_hint(UiHint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_ui_hint_code(self.code, serializer);
+sse_encode_Map_String_String_None(self.params, serializer);
+ }
+
+@protected void sse_encode_ui_hint_code(UiHintCode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ui_pressure(UiPressure self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_upload_source(UploadSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case UploadSource_Path(path: final path): sse_encode_i_32(0, serializer); sse_encode_String(path, serializer);
+case UploadSource_Bytes(bytes: final bytes): sse_encode_i_32(1, serializer); sse_encode_list_prim_u_8_strict(bytes, serializer);
+  } }
+
+@protected void sse_encode_usage_budget(UsageBudget self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_32(self.maxCallsPerMinute, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxBytesPerDay, serializer);
+sse_encode_budget_enforcement(self.enforcement, serializer);
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_watch_event(WatchEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case WatchEvent_Loading(): sse_encode_i_32(0, serializer); case WatchEvent_Cached(value: final value): sse_encode_i_32(1, serializer); sse_encode_String(value, serializer);
+case WatchEvent_Update(value: final value): sse_encode_i_32(2, serializer); sse_encode_String(value, serializer);
+case WatchEvent_Error(message: final message,data: final data): sse_encode_i_32(3, serializer); sse_encode_String(message, serializer);
+sse_encode_opt_String(data, serializer);
+  } }
+
+@protected void sse_encode_web_socket_connection_state(WebSocketConnectionState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case WebSocketConnectionState_Connected(): sse_encode_i_32(0, serializer); case WebSocketConnectionState_Connecting(): sse_encode_i_32(1, serializer); case WebSocketConnectionState_Closed(reason: final reason): sse_encode_i_32(2, serializer); sse_encode_connection_close_reason(reason, serializer);
+case WebSocketConnectionState_Backoff(retryInMs: final retryInMs,attempt: final attempt): sse_encode_i_32(3, serializer); sse_encode_u_64(retryInMs, serializer);
+sse_encode_u_32(attempt, serializer);
+case WebSocketConnectionState_Failed(reason: final reason): sse_encode_i_32(4, serializer); sse_encode_String(reason, serializer);
+  } }
+                }
+                
+
+            @sealed class AuthHandleImpl extends RustOpaque implements AuthHandle {
+                // Not to be used by end users
+                AuthHandleImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                AuthHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_AuthHandle,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_AuthHandle,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_AuthHandlePtr,
+                );
+
+                /// Disposes the auth session, stopping the token refresh loop and clearing authentication.
+ void  dispose()=>RustLib.instance.api.crateAuthHandleDispose(that: this, );
+
+
+/// Returns whether the token refresh loop is still running.
+ bool  isActive()=>RustLib.instance.api.crateAuthHandleIsActive(that: this, );
+
+
+/// Returns whether the user is currently authenticated.
+ bool  isAuthenticated()=>RustLib.instance.api.crateAuthHandleIsAuthenticated(that: this, );
+
+
+            }
+            @sealed class CallbackSubscriberDartFnImpl extends RustOpaque implements CallbackSubscriberDartFn {
+                // Not to be used by end users
+                CallbackSubscriberDartFnImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CallbackSubscriberDartFnImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CallbackSubscriberDartFn,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CallbackSubscriberDartFn,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CallbackSubscriberDartFnPtr,
+                );
+
+                 Future<void>  onDone()=>RustLib.instance.api.crateCallbackSubscriberDartFnOnDone(that: this, );
+
+
+ Future<void>  onError({required String message , String? value })=>RustLib.instance.api.crateCallbackSubscriberDartFnOnError(that: this, message: message, value: value);
+
+
+ Future<void>  onUpdate({required String value })=>RustLib.instance.api.crateCallbackSubscriberDartFnOnUpdate(that: this, value: value);
+
+
+            }
+            @sealed class CallbackSubscriberImpl extends RustOpaque implements CallbackSubscriber {
+                // Not to be used by end users
+                CallbackSubscriberImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CallbackSubscriberImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CallbackSubscriber,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CallbackSubscriber,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CallbackSubscriberPtr,
+                );
+
+                 Future<void>  onDone()=>RustLib.instance.api.crateCallbackSubscriberOnDone(that: this, );
+
+
+ Future<void>  onError({required String message , String? value })=>RustLib.instance.api.crateCallbackSubscriberOnError(that: this, message: message, value: value);
+
+
+ Future<void>  onUpdate({required String value })=>RustLib.instance.api.crateCallbackSubscriberOnUpdate(that: this, value: value);
+
+
+            }
+            @sealed class CancellationTokenImpl extends RustOpaque implements CancellationToken {
+                // Not to be used by end users
+                CancellationTokenImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CancellationTokenImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CancellationToken,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CancellationToken,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CancellationTokenPtr,
+                );
+
+                /// Cancels the calls made with this token.
+ void  cancel()=>RustLib.instance.api.crateCancellationCancellationTokenCancel(that: this, );
+
+
+/// Returns whether [`CancellationToken::cancel`] was called.
+ bool  isCancelled()=>RustLib.instance.api.crateCancellationCancellationTokenIsCancelled(that: this, );
+
+
+            }
+            @sealed class MobileConvexClientImpl extends RustOpaque implements MobileConvexClient {
+                // Not to be used by end users
+                MobileConvexClientImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                MobileConvexClientImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_MobileConvexClient,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_MobileConvexClient,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_MobileConvexClientPtr,
+                );
+
+                /// Executes an action on the Convex backend. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  action({required String name , required Map<String, String> args , BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientAction(that: this, name: name, args: args, timeoutMs: timeoutMs);
+
+
+/// Like [`MobileConvexClient::action`], but abandoned once `token` is
+/// cancelled.
+ Future<String>  actionCancellable({required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientActionCancellable(that: this, name: name, args: args, token: token, timeoutMs: timeoutMs);
+
+
+/// Executes an action with structured arguments and result.
+ Future<ConvexValue>  actionTyped({required String name , required Map<String, ConvexValue> args })=>RustLib.instance.api.crateMobileConvexClientActionTyped(that: this, name: name, args: args);
+
+
+/// Runs an action while reporting the status document of its job through
+/// `on_progress`, and returns the action's result.
+///
+/// The status watch is removed as soon as the action completes or fails.
+ Future<String>  actionWithProgress({required String name , required Map<String, String> args , required JobOptions options , required FutureOr<void> Function(JobProgress) onProgress })=>RustLib.instance.api.crateMobileConvexClientActionWithProgress(that: this, name: name, args: args, options: options, onProgress: onProgress);
+
+
+/// Executes an action with structured arguments. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  actionWithValues({required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientActionWithValues(that: this, name: name, args: args, timeoutMs: timeoutMs);
+
+
+/// Returns the URL of the deployment currently in use.
+ String  activeDeploymentUrl()=>RustLib.instance.api.crateMobileConvexClientActiveDeploymentUrl(that: this, );
+
+
+/// Returns the subscriptions that are neither cancelled nor ended,
+/// oldest first.
+ List<SubscriptionInfo>  activeSubscriptions()=>RustLib.instance.api.crateMobileConvexClientActiveSubscriptions(that: this, );
+
+
+/// Adds an interceptor of queries, mutations and actions, as described
+/// in the [module docs](crate::interceptors). `on_call` decides about
+/// each call and `on_result` returns its result, possibly replaced.
+/// Returns an ID for [`MobileConvexClient::remove_interceptor`].
+ Future<BigInt>  addInterceptor({required FutureOr<InterceptDecision> Function(InterceptedCall) onCall , required FutureOr<CallOutcome> Function(InterceptedCall, CallOutcome) onResult })=>RustLib.instance.api.crateMobileConvexClientAddInterceptor(that: this, onCall: onCall, onResult: onResult);
+
+
+/// Supervises the calling isolate through `keepalive`, which should
+/// return right away, as described in the
+/// [module docs](crate::supervisor). Replaces the callback of a
+/// previously attached isolate and resumes the subscriptions paused
+/// because it stopped responding.
+ Future<void>  attachDartKeepalive({required DartKeepaliveOptions options , required FutureOr<void> Function() keepalive })=>RustLib.instance.api.crateMobileConvexClientAttachDartKeepalive(that: this, options: options, keepalive: keepalive);
+
+
+/// Resolves once all mutations sent so far are reflected in the active
+/// subscriptions, as described in the [module docs](crate::write_barrier).
+/// Fails with [`ClientError::Timeout`] after `timeout_ms` milliseconds,
+/// if given.
+ Future<void>  awaitPendingWrites({BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientAwaitPendingWrites(that: this, timeoutMs: timeoutMs);
+
+
+/// Executes an action, reusing its result from an identical call made
+/// within the last `ttl_ms` milliseconds.
+ Future<String>  cachedAction({required String name , required Map<String, String> args , required BigInt ttlMs })=>RustLib.instance.api.crateMobileConvexClientCachedAction(that: this, name: name, args: args, ttlMs: ttlMs);
+
+
+/// Executes a query, answering from the cache when possible.
+ Future<String>  cachedQuery({required String name , required Map<String, String> args })=>RustLib.instance.api.crateMobileConvexClientCachedQuery(that: this, name: name, args: args);
+
+
+/// Cancels every live subscription, returning how many there were.
+ int  cancelAllSubscriptions()=>RustLib.instance.api.crateMobileConvexClientCancelAllSubscriptions(that: this, );
+
+
+/// Forgets all memoized action results.
+ void  clearActionCache()=>RustLib.instance.api.crateMobileConvexClientClearActionCache(that: this, );
+
+
+/// Deletes all retained audit log entries.
+ Future<void>  clearAuditLog()=>RustLib.instance.api.crateMobileConvexClientClearAuditLog(that: this, );
+
+
+/// Stops encrypting and decrypting fields.
+ void  clearFieldEncryption()=>RustLib.instance.api.crateMobileConvexClientClearFieldEncryption(that: this, );
+
+
+/// Stops injecting faults into calls of `name`.
+ void  clearFunctionFault({required String name })=>RustLib.instance.api.crateMobileConvexClientClearFunctionFault(that: this, name: name);
+
+
+/// Stops injecting faults into calls of any function.
+ void  clearFunctionFaults()=>RustLib.instance.api.crateMobileConvexClientClearFunctionFaults(that: this, );
+
+
+/// Deletes all on-disk data of the current identity on this deployment.
+/// Data of other identities and deployments is left untouched.
+ Future<void>  clearUserData()=>RustLib.instance.api.crateMobileConvexClientClearUserData(that: this, );
+
+
+/// Returns the estimated server clock minus the local clock, in milliseconds.
+ PlatformInt64  clockOffsetMs()=>RustLib.instance.api.crateMobileConvexClientClockOffsetMs(that: this, );
+
+
+/// Closes the client: cancels all subscriptions and background tasks,
+/// including the auth refresh loop, closes the WebSocket and shuts the
+/// runtime down. Every later call fails with an error. Closing twice is a
+/// no-op.
+ Future<void>  close()=>RustLib.instance.api.crateMobileConvexClientClose(that: this, );
+
+
+/// Changes how many subscription results are cached, evicting those
+/// exceeding the new limits.
+ void  configureResultCache({required ResultCacheOptions options })=>RustLib.instance.api.crateMobileConvexClientConfigureResultCache(that: this, options: options);
+
+
+/// Opens the WebSocket now rather than on the first call, e.g. to warm
+/// the connection up during a splash screen, and re-establishes the
+/// subscriptions stopped by [`MobileConvexClient::disconnect`] or
+/// [`MobileConvexClient::pause`].
+ Future<void>  connect()=>RustLib.instance.api.crateMobileConvexClientConnect(that: this, );
+
+
+/// Creates a client for the preview deployment named by `preview`, as
+/// described in the [module docs](crate::preview). It uses this
+/// client's options without failover; closing either client leaves the
+/// other one open.
+ MobileConvexClient  connectPreview({required String preview , required String clientId })=>RustLib.instance.api.crateMobileConvexClientConnectPreview(that: this, preview: preview, clientId: clientId);
+
+
+/// Returns the current connection-quality estimate.
+ ConnectionQuality  connectionQuality()=>RustLib.instance.api.crateMobileConvexClientConnectionQuality(that: this, );
+
+
+/// Returns the current WebSocket connection state.
+ WebSocketConnectionState  connectionState()=>RustLib.instance.api.crateMobileConvexClientConnectionState(that: this, );
+
+
+/// Returns whether the attached Dart isolate responds to keepalive
+/// pings.
+ DartLiveness  dartLiveness()=>RustLib.instance.api.crateMobileConvexClientDartLiveness(that: this, );
+
+
+/// Declares what the cached results of query `query_name` depend on.
+/// Declarations accumulate across calls.
+ void  declareQueryDependencies({required String queryName , required List<QueryDependency> dependencies })=>RustLib.instance.api.crateMobileConvexClientDeclareQueryDependencies(that: this, queryName: queryName, dependencies: dependencies);
+
+
+/// Stops exporting spans once the queued ones were sent.
+ void  disableTraceExport()=>RustLib.instance.api.crateMobileConvexClientDisableTraceExport(that: this, );
+
+
+/// Closes the WebSocket while keeping the client usable, e.g. while the
+/// app is idle.
+///
+/// Subscriptions are stopped as by [`MobileConvexClient::pause`], so
+/// nothing keeps the connection alive; their handles stay valid and
+/// [`MobileConvexClient::connect`] re-establishes them. Queries and
+/// other calls made meanwhile open a new WebSocket on their own, while
+/// subscriptions wait for `connect`.
+ Future<void>  disconnect()=>RustLib.instance.api.crateMobileConvexClientDisconnect(that: this, );
+
+
+/// Downloads `url_or_storage_id`, a URL such as one returned by
+/// `ctx.storage.getUrl()` or a storage ID, to `dest_path`, as described
+/// in the [module docs](crate::file_storage). `on_progress` is called
+/// with the bytes written so far, including those of a resumed partial
+/// file.
+ Future<void>  downloadFile({required String urlOrStorageId , required String destPath , required FutureOr<void> Function(TransferProgress) onProgress })=>RustLib.instance.api.crateMobileConvexClientDownloadFile(that: this, urlOrStorageId: urlOrStorageId, destPath: destPath, onProgress: onProgress);
+
+
+/// Returns how many other open clients in this process use the same
+/// deployment. Anything above zero usually means clients are created
+/// where a shared one was intended.
+ int  duplicateClientCount()=>RustLib.instance.api.crateMobileConvexClientDuplicateClientCount(that: this, );
+
+
+/// Exports the spans of all calls from now on, as described in the
+/// [module docs](crate::spans). Replaces the previous export, if any.
+ void  enableTraceExport({required TraceExportOptions options })=>RustLib.instance.api.crateMobileConvexClientEnableTraceExport(that: this, options: options);
+
+
+/// Registers a callback invoked with every [`ClientEvent`] from now on,
+/// as described in the [module docs](crate::events).
+ Future<void>  events({required FutureOr<void> Function(ClientEvent) onEvent })=>RustLib.instance.api.crateMobileConvexClientEvents(that: this, onEvent: onEvent);
+
+
+/// Returns the retained audit log entries as JSON lines, oldest first.
+ Future<String>  exportAuditLog()=>RustLib.instance.api.crateMobileConvexClientExportAuditLog(that: this, );
+
+
+/// Returns the last result of a subscription to `name` with `args`,
+/// serialized as JSON, if one is cached.
+ String?  getCached({required String name , required Map<String, String> args })=>RustLib.instance.api.crateMobileConvexClientGetCached(that: this, name: name, args: args);
+
+
+/// Calls the HTTP action at `path`, e.g. `/webhooks/stripe`, with
+/// `method`, `headers` and `body`, as described in the
+/// [module docs](crate::http_actions). The current auth token is sent
+/// as a bearer token unless `headers` has an `Authorization` header.
+/// Responses of any status are returned; only failing to get one is an
+/// error.
+ Future<HttpActionResponse>  httpAction({required String path , required String method , required Map<String, String> headers , Uint8List? body })=>RustLib.instance.api.crateMobileConvexClientHttpAction(that: this, path: path, method: method, headers: headers, body: body);
+
+
+/// Returns whether and when the Convex client was built, and how many
+/// calls are waiting for it.
+ InitializationDiagnostics  initializationDiagnostics()=>RustLib.instance.api.crateMobileConvexClientInitializationDiagnostics(that: this, );
+
+
+/// Invalidates and refetches the cached results of `query_name` and of
+/// all queries depending on it.
+ Future<void>  invalidateCachedQuery({required String queryName })=>RustLib.instance.api.crateMobileConvexClientInvalidateCachedQuery(that: this, queryName: queryName);
+
+
+/// Returns whether an auth token is currently set. Always `false` for
+/// clients created with [`ClientOptions::unauthenticated`].
+ bool  isAuthenticated()=>RustLib.instance.api.crateMobileConvexClientIsAuthenticated(that: this, );
+
+
+/// Returns whether the client keeps all state in memory.
+ bool  isIncognito()=>RustLib.instance.api.crateMobileConvexClientIsIncognito(that: this, );
+
+
+/// Returns when the WebSocket last became connected, as Unix time in
+/// milliseconds, or `None` if it never was.
+ PlatformInt64?  lastConnectedAtMs()=>RustLib.instance.api.crateMobileConvexClientLastConnectedAtMs(that: this, );
+
+
+/// Returns why the last attempt to connect failed, or `None` if the last
+/// attempt succeeded or none was made yet.
+ String?  lastConnectionError()=>RustLib.instance.api.crateMobileConvexClientLastConnectionError(that: this, );
+
+
+/// Signs the user out: stops the auth refresh loop, clears auth on the
+/// connection, cancels auth-scoped subscriptions and purges the query
+/// and action caches. With `clear_user_data`, the identity's on-disk
+/// data is deleted too, as by [`MobileConvexClient::clear_user_data`].
+///
+/// Listeners registered with [`MobileConvexClient::on_logout`] are
+/// notified once everything is done.
+ Future<LogoutEvent>  logout({required bool clearUserData })=>RustLib.instance.api.crateMobileConvexClientLogout(that: this, clearUserData: clearUserData);
+
+
+/// Returns a snapshot of the connection and runtime health metrics.
+ ClientMetrics  metrics()=>RustLib.instance.api.crateMobileConvexClientMetrics(that: this, );
+
+
+/// Returns the call metrics recorded so far, as described in the
+/// [module docs](crate::call_metrics).
+ MetricsReport  metricsSnapshot()=>RustLib.instance.api.crateMobileConvexClientMetricsSnapshot(that: this, );
+
+
+/// Executes `calls` strictly in order. No other mutation of this client
+/// runs between the first and the last step. After a failed step, the
+/// remaining ones are skipped if `stop_on_error` is set.
+///
+/// Fails without running any step if the arguments of a call are invalid.
+ Future<MutationSequenceResult>  mutateSequence({required List<MutationCall> calls , required bool stopOnError })=>RustLib.instance.api.crateMobileConvexClientMutateSequence(that: this, calls: calls, stopOnError: stopOnError);
+
+
+/// Executes a mutation on the Convex backend. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  mutation({required String name , required Map<String, String> args , BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientMutation(that: this, name: name, args: args, timeoutMs: timeoutMs);
+
+
+/// Like [`MobileConvexClient::mutation`], but abandoned once `token` is
+/// cancelled.
+ Future<String>  mutationCancellable({required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientMutationCancellable(that: this, name: name, args: args, token: token, timeoutMs: timeoutMs);
+
+
+/// Queues a low-priority Convex mutation, sent at the latest after
+/// `max_delay_ms` milliseconds. Returns once queued; the result of the
+/// mutation is not reported.
+ Future<void>  mutationDeferred({required String name , required Map<String, String> args , required int maxDelayMs })=>RustLib.instance.api.crateMobileConvexClientMutationDeferred(that: this, name: name, args: args, maxDelayMs: maxDelayMs);
+
+
+/// Like [`MobileConvexClient::mutation_deferred`], reporting the
+/// mutation's lifecycle under `mutation_id`.
+ Future<void>  mutationDeferredTracked({required String mutationId , required String name , required Map<String, String> args , required int maxDelayMs })=>RustLib.instance.api.crateMobileConvexClientMutationDeferredTracked(that: this, mutationId: mutationId, name: name, args: args, maxDelayMs: maxDelayMs);
+
+
+/// Executes a mutation with an idempotency key, retrying it as
+/// described in the [module docs](crate::retry). A new key is generated
+/// unless `idempotency_key` is given, e.g. to retry across app
+/// restarts.
+ Future<IdempotentMutationResult>  mutationIdempotent({required String name , required Map<String, String> args , String? idempotencyKey })=>RustLib.instance.api.crateMobileConvexClientMutationIdempotent(that: this, name: name, args: args, idempotencyKey: idempotencyKey);
+
+
+/// Executes a mutation now if connected, or queues it in the outbox
+/// otherwise, as described in the [module docs](crate::outbox).
+ Future<OfflineMutationResult>  mutationOffline({required String name , required Map<String, String> args })=>RustLib.instance.api.crateMobileConvexClientMutationOffline(that: this, name: name, args: args);
+
+
+/// Executes a mutation like [`MobileConvexClient::mutation`], showing
+/// `updates` in the affected subscriptions until it completes, as
+/// described in the [module docs](crate::optimistic).
+ Future<String>  mutationOptimistic({required String name , required Map<String, String> args , required List<OptimisticUpdate> updates })=>RustLib.instance.api.crateMobileConvexClientMutationOptimistic(that: this, name: name, args: args, updates: updates);
+
+
+/// Executes a mutation like [`MobileConvexClient::mutation`], reporting
+/// its lifecycle under `mutation_id`. Attempts failing for a reason
+/// other than the function's own error are retried with the backoff of
+/// [`crate::options::ClientOptions::mutation_retry`], up to
+/// `max_attempts` attempts in total.
+ Future<String>  mutationTracked({required String mutationId , required String name , required Map<String, String> args , required int maxAttempts })=>RustLib.instance.api.crateMobileConvexClientMutationTracked(that: this, mutationId: mutationId, name: name, args: args, maxAttempts: maxAttempts);
+
+
+/// Executes a mutation with structured arguments and result.
+ Future<ConvexValue>  mutationTyped({required String name , required Map<String, ConvexValue> args })=>RustLib.instance.api.crateMobileConvexClientMutationTyped(that: this, name: name, args: args);
+
+
+/// Executes a mutation like [`MobileConvexClient::mutation`] and returns
+/// its result together with a commit token.
+ Future<CommittedMutation>  mutationWithCommitToken({required String name , required Map<String, String> args })=>RustLib.instance.api.crateMobileConvexClientMutationWithCommitToken(that: this, name: name, args: args);
+
+
+/// Executes a mutation with structured arguments. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  mutationWithValues({required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientMutationWithValues(that: this, name: name, args: args, timeoutMs: timeoutMs);
+
+
+/// Releases memory the client can do without, according to
+/// `pressure`, and returns what was released.
+ Future<MemoryReleasedEvent>  notifyMemoryPressure({required MemoryPressure pressure })=>RustLib.instance.api.crateMobileConvexClientNotifyMemoryPressure(that: this, pressure: pressure);
+
+
+/// Registers a callback invoked with every [`AuthChange`] from now on,
+/// as described in the [module docs](crate::auth_changes).
+ Future<void>  onAuthChange({required FutureOr<void> Function(AuthChange) onChange })=>RustLib.instance.api.crateMobileConvexClientOnAuthChange(that: this, onChange: onChange);
+
+
+/// Registers a callback invoked whenever the backend fingerprint
+/// changes. Requires [`crate::options::ClientOptions::schema_check`].
+ Future<void>  onBackendChange({required FutureOr<void> Function(BackendChangedEvent) onChange })=>RustLib.instance.api.crateMobileConvexClientOnBackendChange(that: this, onChange: onChange);
+
+
+/// Registers a callback invoked whenever a call exceeds the usage
+/// budget. Requires [`crate::options::ClientOptions::usage_budget`].
+ Future<void>  onBudgetExceeded({required FutureOr<void> Function(BudgetExceededEvent) onExceeded })=>RustLib.instance.api.crateMobileConvexClientOnBudgetExceeded(that: this, onExceeded: onExceeded);
+
+
+/// Registers a callback invoked with the current connection quality and
+/// then whenever the score changes significantly or the level changes.
+ Future<void>  onConnectionQualityChange({required FutureOr<void> Function(ConnectionQuality) onChange })=>RustLib.instance.api.crateMobileConvexClientOnConnectionQualityChange(that: this, onChange: onChange);
+
+
+/// Registers a callback invoked whenever the client switches deployments.
+ Future<void>  onFailover({required FutureOr<void> Function(FailoverEvent) onFailover })=>RustLib.instance.api.crateMobileConvexClientOnFailover(that: this, onFailover: onFailover);
+
+
+/// Registers a callback invoked whenever a logout has completed.
+ Future<void>  onLogout({required FutureOr<void> Function(LogoutEvent) onLogout })=>RustLib.instance.api.crateMobileConvexClientOnLogout(that: this, onLogout: onLogout);
+
+
+/// Registers a callback invoked whenever memory was released in
+/// response to [`MobileConvexClient::notify_memory_pressure`].
+ Future<void>  onMemoryReleased({required FutureOr<void> Function(MemoryReleasedEvent) onReleased })=>RustLib.instance.api.crateMobileConvexClientOnMemoryReleased(that: this, onReleased: onReleased);
+
+
+/// Registers a callback invoked with every status change of tracked
+/// mutations. A callback still busy with an earlier event may miss some.
+ Future<void>  onMutationLifecycle({required FutureOr<void> Function(MutationLifecycleEvent) onEvent })=>RustLib.instance.api.crateMobileConvexClientOnMutationLifecycle(that: this, onEvent: onEvent);
+
+
+/// Registers a callback invoked with the outcome of every mutation
+/// replayed from the outbox, and starts replaying mutations queued by an
+/// earlier run.
+ Future<void>  onOutboxCompletion({required FutureOr<void> Function(OutboxCompletion) onCompletion })=>RustLib.instance.api.crateMobileConvexClientOnOutboxCompletion(that: this, onCompletion: onCompletion);
+
+
+/// Registers the resolver deciding what to do with replayed mutations
+/// the function rejects, replacing any registered before, as described
+/// in the [module docs](crate::outbox). Replaying waits for it.
+ Future<void>  onOutboxConflict({required FutureOr<ConflictResolution> Function(OutboxConflict) resolve })=>RustLib.instance.api.crateMobileConvexClientOnOutboxConflict(that: this, resolve: resolve);
+
+
+/// Registers a callback invoked whenever building the Convex client
+/// takes longer than [`crate::options::ClientOptions::slow_initialization_ms`].
+ Future<void>  onSlowInitialization({required FutureOr<void> Function(SlowInitializationEvent) onSlow })=>RustLib.instance.api.crateMobileConvexClientOnSlowInitialization(that: this, onSlow: onSlow);
+
+
+/// Registers a callback invoked with the current hint and whenever it
+/// changes.
+ Future<void>  onUiHint({required FutureOr<void> Function(UiHint?) onHint })=>RustLib.instance.api.crateMobileConvexClientOnUiHint(that: this, onHint: onHint);
+
+
+/// Adds a WebSocket connection state change listener.
+///
+/// Listeners can be added at any time and any number of them can be
+/// registered. Each one is invoked with the current state right away and
+/// then whenever the WebSocket transitions between Connected and
+/// Connecting states. A listener that is still busy with its previous
+/// state receives only the latest one.
+///
+/// # Arguments
+///
+/// * `on_state_change` - Async callback invoked when connection state changes
+///
+/// # Example
+///
+/// ```dart
+/// await client.onWebsocketStateChange(
+///   onStateChange: (state) async {
+///     print('Connection state: ${state.name}');
+///   },
+/// );
+/// ```
+ Future<void>  onWebsocketStateChange({required FutureOr<void> Function(WebSocketConnectionState) onStateChange })=>RustLib.instance.api.crateMobileConvexClientOnWebsocketStateChange(that: this, onStateChange: onStateChange);
+
+
+/// Returns the number of mutations waiting in the outbox.
+ Future<int>  outboxLen()=>RustLib.instance.api.crateMobileConvexClientOutboxLen(that: this, );
+
+
+/// Stops all subscriptions and closes the WebSocket, e.g. when the app
+/// goes to the background. Subscription handles stay valid and their
+/// subscriptions are re-established by [`MobileConvexClient::resume`].
+///
+/// Presence heartbeats started by [`MobileConvexClient::start_presence`]
+/// are paused through their own handle. Changed subscription results
+/// are persisted, if enabled (see [`crate::persisted_results`]).
+ Future<void>  pause()=>RustLib.instance.api.crateMobileConvexClientPause(that: this, );
+
+
+/// Executes a query on the Convex backend. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  query({required String name , required Map<String, String> args , BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientQuery(that: this, name: name, args: args, timeoutMs: timeoutMs);
+
+
+/// Executes a query once the deployment's snapshot is at or beyond `ts`,
+/// a commit token returned by
+/// [`MobileConvexClient::mutation_with_commit_token`] on any client of the
+/// same deployment. Queries are evaluated at the latest snapshot, so the
+/// result includes that mutation's writes.
+ Future<String>  queryAtLeast({required String ts , required String name , required Map<String, String> args })=>RustLib.instance.api.crateMobileConvexClientQueryAtLeast(that: this, ts: ts, name: name, args: args);
+
+
+/// Runs `queries` and returns their results in order, all read at the
+/// same logical timestamp. Fails if any of the queries fails, or with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<List<String>>  queryBatch({required List<BatchQuery> queries , BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientQueryBatch(that: this, queries: queries, timeoutMs: timeoutMs);
+
+
+/// Like [`MobileConvexClient::query`], but abandoned once `token` is
+/// cancelled.
+ Future<String>  queryCancellable({required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientQueryCancellable(that: this, name: name, args: args, token: token, timeoutMs: timeoutMs);
+
+
+/// Like [`MobileConvexClient::query`], but returns only the fields
+/// selected by the JSON pointers in `projection`.
+ Future<String>  queryProjected({required String name , required Map<String, String> args , required List<String> projection })=>RustLib.instance.api.crateMobileConvexClientQueryProjected(that: this, name: name, args: args, projection: projection);
+
+
+/// Executes a query and delivers its result to `on_chunk` in chunks, as
+/// described in the [module docs](crate::chunked). Resolves once the
+/// last chunk was handled. Fails with [`ClientError::Timeout`] if the
+/// result has not arrived after `timeout_ms` milliseconds, if set.
+ Future<void>  queryStreamed({required String name , required Map<String, String> args , required FutureOr<void> Function(ResultChunk) onChunk , BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientQueryStreamed(that: this, name: name, args: args, onChunk: onChunk, timeoutMs: timeoutMs);
+
+
+/// Executes a query with structured arguments and result.
+ Future<ConvexValue>  queryTyped({required String name , required Map<String, ConvexValue> args })=>RustLib.instance.api.crateMobileConvexClientQueryTyped(that: this, name: name, args: args);
+
+
+/// Executes a query with structured arguments. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  queryWithValues({required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs })=>RustLib.instance.api.crateMobileConvexClientQueryWithValues(that: this, name: name, args: args, timeoutMs: timeoutMs);
+
+
+/// Replaces the Convex client with a freshly built one for the active
+/// deployment, re-applying the current auth token.
+///
+/// Use this to recover after connecting failed, without recreating the
+/// whole client. Running subscriptions are moved to the new client,
+/// highest priority first, and keep their handles.
+ Future<void>  reconnect()=>RustLib.instance.api.crateMobileConvexClientReconnect(that: this, );
+
+
+/// Serves future subscriptions to `query_name` from `mapping.batch_query`,
+/// grouping many watches into few server subscriptions.
+ void  registerShardMapping({required String queryName , required ShardMapping mapping })=>RustLib.instance.api.crateMobileConvexClientRegisterShardMapping(that: this, queryName: queryName, mapping: mapping);
+
+
+/// Removes the interceptor with `id`, returning whether it was added.
+/// Calls already passing it are unaffected.
+ bool  removeInterceptor({required BigInt id })=>RustLib.instance.api.crateMobileConvexClientRemoveInterceptor(that: this, id: id);
+
+
+/// Stops forwarding records to the listener set with
+/// [`MobileConvexClient::set_log_listener`].
+ void  removeLogListener()=>RustLib.instance.api.crateMobileConvexClientRemoveLogListener(that: this, );
+
+
+/// Stops handing panics to the reporter set with
+/// [`MobileConvexClient::set_panic_reporter`].
+ void  removePanicReporter()=>RustLib.instance.api.crateMobileConvexClientRemovePanicReporter(that: this, );
+
+
+/// Returns the document with `id` from the replica table `table`,
+/// serialized as JSON.
+ String?  replicaGet({required String table , required String id })=>RustLib.instance.api.crateMobileConvexClientReplicaGet(that: this, table: table, id: id);
+
+
+/// Returns the documents of the replica table `table`, serialized as
+/// JSON and ordered by `_id`, whose fields equal those of `filter`. The
+/// filter values are JSON, e.g. `{"done": "false"}`.
+ List<String>  replicaQuery({required String table , required Map<String, String> filter })=>RustLib.instance.api.crateMobileConvexClientReplicaQuery(that: this, table: table, filter: filter);
+
+
+/// Subscribes to `query_name` with `query_args` and mirrors the returned
+/// documents into the replica table `table`, as described in the
+/// [module docs](crate::replication). Table names consist of ASCII
+/// letters, digits and underscores. Cancelling the subscription stops
+/// mirroring; the table keeps its rows.
+ Future<SubscriptionHandle>  replicateQuery({required String table , required String queryName , required Map<String, String> queryArgs })=>RustLib.instance.api.crateMobileConvexClientReplicateQuery(that: this, table: table, queryName: queryName, queryArgs: queryArgs);
+
+
+/// Reconnects and re-establishes the subscriptions stopped by
+/// [`MobileConvexClient::pause`]. If connecting fails, the client stays
+/// paused and `resume` can be retried.
+ Future<void>  resume()=>RustLib.instance.api.crateMobileConvexClientResume(that: this, );
+
+
+/// Sets authentication token for the client.
+ Future<void>  setAuth({String? token })=>RustLib.instance.api.crateMobileConvexClientSetAuth(that: this, token: token);
+
+
+/// Sets authentication with automatic token refresh.
+///
+/// The `fetch_token` callback is called:
+/// - Immediately to get the initial token
+/// - Automatically when the token is about to expire (60 seconds before expiry,
+///   see [`MobileConvexClient::set_auth_with_refresh_config`])
+/// - Immediately when the backend rejects the token, see
+///   [`events::ClientEvent::AuthRejected`]; if it keeps rejecting new tokens,
+///   after a delay doubling from 1 second up to a minute
+/// - Immediately when the WebSocket reconnects after the token expired;
+///   an unexpired token is applied again instead, see
+///   [`auth_changes`]
+///
+/// The `on_auth_change` callback is called whenever auth state changes.
+///
+/// Returns an AuthHandle that can be used to dispose the auth session.
+/// Disposing stops waiting on a pending `fetch_token` call; a token it
+/// returns afterwards is never applied. A later `set_auth`,
+/// `set_auth_with_refresh` or `logout` ends the session too, without its
+/// refresh loop clearing the newer auth.
+ Future<AuthHandle>  setAuthWithRefresh({required FutureOr<String?> Function() fetchToken , required FutureOr<void> Function(bool) onAuthChange })=>RustLib.instance.api.crateMobileConvexClientSetAuthWithRefresh(that: this, fetchToken: fetchToken, onAuthChange: onAuthChange);
+
+
+/// Like [`MobileConvexClient::set_auth_with_refresh`], but refreshes the
+/// token on the schedule of `config`, as described in the
+/// [module docs](crate::auth_refresh).
+ Future<AuthHandle>  setAuthWithRefreshConfig({required AuthRefreshConfig config , required FutureOr<String?> Function() fetchToken , required FutureOr<void> Function(bool) onAuthChange })=>RustLib.instance.api.crateMobileConvexClientSetAuthWithRefreshConfig(that: this, config: config, fetchToken: fetchToken, onAuthChange: onAuthChange);
+
+
+/// Encrypts the fields at `fields` with the 32-byte AES-256 `key` from
+/// now on, as described in the [module docs](crate::encryption).
+/// Replaces fields and key registered before.
+ void  setFieldEncryption({required List<int> key , required List<String> fields })=>RustLib.instance.api.crateMobileConvexClientSetFieldEncryption(that: this, key: key, fields: fields);
+
+
+/// Injects `fault` into all later calls of the function `name`,
+/// replacing any fault set for it before.
+ Future<void>  setFunctionFault({required String name , required FunctionFault fault })=>RustLib.instance.api.crateMobileConvexClientSetFunctionFault(that: this, name: name, fault: fault);
+
+
+/// Appends records at `level` or more severe to the file at `path`, as
+/// described in the [module docs](crate::logging), or stops writing
+/// the file if `path` is `None`. Once the file exceeds 4 MiB it is
+/// moved to `<path>.old` and started over. Applies to the whole
+/// process. Fails while an incognito client exists.
+ void  setLogFile({String? path , required LogLevel level })=>RustLib.instance.api.crateMobileConvexClientSetLogFile(that: this, path: path, level: level);
+
+
+/// Writes records at `level` or more severe to the platform log, e.g.
+/// logcat, from now on, as described in the
+/// [module docs](crate::logging). Applies to the whole process.
+ void  setLogLevel({required LogLevel level })=>RustLib.instance.api.crateMobileConvexClientSetLogLevel(that: this, level: level);
+
+
+/// Calls `on_record` with every record at `level` or more severe from
+/// now on, as described in the [module docs](crate::logging).
+ Future<void>  setLogListener({required LogLevel level , required FutureOr<void> Function(LogRecord) onRecord })=>RustLib.instance.api.crateMobileConvexClientSetLogListener(that: this, level: level, onRecord: onRecord);
+
+
+/// Calls `on_panic` with every panic in the process from now on, as
+/// described in the [module docs](crate::panics). A later call
+/// replaces the reporter.
+ Future<void>  setPanicReporter({required FutureOr<void> Function(PanicReport) onPanic })=>RustLib.instance.api.crateMobileConvexClientSetPanicReporter(that: this, onPanic: onPanic);
+
+
+/// Sets the 32-byte key encrypting the client's files, as described in
+/// the [module docs](crate::storage_encryption), and replays mutations
+/// queued in an outbox that could not be read without it.
+ void  setStorageKey({required List<int> key })=>RustLib.instance.api.crateMobileConvexClientSetStorageKey(that: this, key: key);
+
+
+/// Replaces the telemetry sampling configuration. Applies to events
+/// recorded from now on.
+ void  setTelemetrySampling({required TelemetrySampling sampling })=>RustLib.instance.api.crateMobileConvexClientSetTelemetrySampling(that: this, sampling: sampling);
+
+
+/// Reports the current UI load. Elevated pressure throttles subscription
+/// updates as configured by [`crate::options::ClientOptions::pressure_throttle`].
+ void  setUiPressure({required UiPressure pressure })=>RustLib.instance.api.crateMobileConvexClientSetUiPressure(that: this, pressure: pressure);
+
+
+/// Returns how many sharded subscriptions are served by how many server
+/// subscriptions.
+ ShardingMetrics  shardingMetrics()=>RustLib.instance.api.crateMobileConvexClientShardingMetrics(that: this, );
+
+
+/// Starts sending `mutation_name` with `args` every `interval_ms`.
+///
+/// Heartbeats are skipped while the WebSocket is not connected or the
+/// returned handle is paused, and one is sent immediately on reconnect
+/// or resume. If the mutation returns a number it is used as the server
+/// time to estimate clock skew for [`MobileConvexClient::subscribe_presence`].
+/// Heartbeats are sent like any other mutation, through the interceptors
+/// and call metrics, and stop once the client is dropped.
+ Future<PresenceHandle>  startPresence({required String mutationName , required Map<String, String> args , required BigInt intervalMs })=>RustLib.instance.api.crateMobileConvexClientStartPresence(that: this, mutationName: mutationName, args: args, intervalMs: intervalMs);
+
+
+/// Returns the storage directory of the current deployment and identity,
+/// creating it if needed. Apps can keep their own per-user files there.
+ Future<String>  storageDirectory()=>RustLib.instance.api.crateMobileConvexClientStorageDirectory(that: this, );
+
+
+/// Subscribes to real-time updates from a Convex query, as configured by
+/// `options`. `on_done` is called once the subscription stream ended and
+/// no more updates will ever arrive, as opposed to waiting for the next
+/// update. Cancelling does not call `on_done`.
+ Future<SubscriptionHandle>  subscribe({required String name , required Map<String, String> args , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError , required FutureOr<void> Function() onDone , SubscribeOptions? options })=>RustLib.instance.api.crateMobileConvexClientSubscribe(that: this, name: name, args: args, onUpdate: onUpdate, onError: onError, onDone: onDone, options: options);
+
+
+/// Like [`MobileConvexClient::subscribe`], but delivers every result to
+/// `on_chunk` in chunks, as described in the
+/// [module docs](crate::chunked).
+ Future<SubscriptionHandle>  subscribeChunked({required String name , required Map<String, String> args , required FutureOr<void> Function(ResultChunk) onChunk , required FutureOr<void> Function(String, String?) onError })=>RustLib.instance.api.crateMobileConvexClientSubscribeChunked(that: this, name: name, args: args, onChunk: onChunk, onError: onError);
+
+
+/// Subscribes to all `sources` and delivers their results joined by
+/// `strategy` whenever any of them changes, once every source has a
+/// result. Errors of any source are passed to `on_error`. Cancelling the
+/// returned handle cancels all source subscriptions.
+ Future<SubscriptionHandle>  subscribeCombined({required List<DerivedSource> sources , required CombineStrategy strategy , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError })=>RustLib.instance.api.crateMobileConvexClientSubscribeCombined(that: this, sources: sources, strategy: strategy, onUpdate: onUpdate, onError: onError);
+
+
+/// Subscribes to a query under `key`, replacing the subscription
+/// previously made under the same key.
+///
+/// Updates of replaced subscriptions are never delivered, even when they
+/// were already in flight. If subscribing fails, the replaced
+/// subscription is cancelled all the same.
+ Future<SubscriptionHandle>  subscribeKeyed({required String key , required String name , required Map<String, String> args , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError })=>RustLib.instance.api.crateMobileConvexClientSubscribeKeyed(that: this, key: key, name: name, args: args, onUpdate: onUpdate, onError: onError);
+
+
+/// Subscribes to a Convex query returning an array, delivering each
+/// update as a [`ListUpdate`] against the previous one. Items are
+/// matched by their `id_field`, e.g. `"_id"`. Callbacks are awaited in
+/// order, so diffs apply in sequence.
+ Future<SubscriptionHandle>  subscribeList({required String name , required Map<String, String> args , required String idField , required FutureOr<void> Function(ListUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError })=>RustLib.instance.api.crateMobileConvexClientSubscribeList(that: this, name: name, args: args, idField: idField, onUpdate: onUpdate, onError: onError);
+
+
+/// Subscribes to a Convex query, resolves with its first result and
+/// cancels the subscription. Unlike [`MobileConvexClient::query`], the
+/// result is read through the subscription set and is therefore
+/// consistent with the client's other subscriptions. Query errors are
+/// returned like those of `query`.
+ Future<String>  subscribeOnce({required String name , required Map<String, String> args })=>RustLib.instance.api.crateMobileConvexClientSubscribeOnce(that: this, name: name, args: args);
+
+
+/// Subscribes to a paginated query, starting with a page of
+/// `initial_num_items` items, as described in the
+/// [module docs](crate::pagination). `args` must not contain
+/// `paginationOpts`; it is added per page. Updates carry the items of
+/// all loaded pages and are awaited in order.
+ Future<PaginatedQueryHandle>  subscribePaginated({required String name , required Map<String, String> args , required int initialNumItems , required FutureOr<void> Function(PaginatedUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError })=>RustLib.instance.api.crateMobileConvexClientSubscribePaginated(that: this, name: name, args: args, initialNumItems: initialNumItems, onUpdate: onUpdate, onError: onError);
+
+
+/// Like [`MobileConvexClient::subscribe_paginated`], but starts with the
+/// pages delimited by `page_boundaries` from an earlier
+/// [`PaginatedUpdate`], followed by a page of `num_items` items.
+ Future<PaginatedQueryHandle>  subscribePaginatedFrom({required String name , required Map<String, String> args , required List<String> pageBoundaries , required int numItems , required FutureOr<void> Function(PaginatedUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError })=>RustLib.instance.api.crateMobileConvexClientSubscribePaginatedFrom(that: this, name: name, args: args, pageBoundaries: pageBoundaries, numItems: numItems, onUpdate: onUpdate, onError: onError);
+
+
+/// Subscribes to a query returning heartbeat documents and delivers the
+/// derived online/offline status of each user.
+///
+/// Statuses are re-evaluated as time passes, so users drop to offline
+/// without a server update. The server clock is estimated using the skew
+/// measured by [`MobileConvexClient::start_presence`].
+ Future<SubscriptionHandle>  subscribePresence({required String name , required Map<String, String> args , required PresenceOptions options , required FutureOr<void> Function(List<PresenceStatus>) onUpdate , required FutureOr<void> Function(String, String?) onError })=>RustLib.instance.api.crateMobileConvexClientSubscribePresence(that: this, name: name, args: args, options: options, onUpdate: onUpdate, onError: onError);
+
+
+/// Subscribes to a Convex query, adding every result, serialized as
+/// JSON, to `sink`, as described in the
+/// [module docs](crate::subscription_stream). Updates are not dropped
+/// or coalesced for a slow listener. Cancelling the Dart stream
+/// subscription does not cancel the query; the returned handle does.
+ Stream<String>  subscribeStream({required String name , required Map<String, String> args })=>RustLib.instance.api.crateMobileConvexClientSubscribeStream(that: this, name: name, args: args);
+
+
+/// Subscribes to a query with structured arguments, delivering results as
+/// [`ConvexValue`]s.
+///
+/// Results go through the same pipeline as those of
+/// [`MobileConvexClient::subscribe`], so the subscription is throttled,
+/// shared, sharded, paused and re-established the same way. With
+/// [`crate::options::Int64Encoding::String`], `Int64`s in results
+/// arrive as strings; the other encodings keep them exact.
+ Future<SubscriptionHandle>  subscribeTyped({required String name , required Map<String, ConvexValue> args , required FutureOr<void> Function(ConvexValue) onUpdate , required FutureOr<void> Function(String, String?) onError })=>RustLib.instance.api.crateMobileConvexClientSubscribeTyped(that: this, name: name, args: args, onUpdate: onUpdate, onError: onError);
+
+
+/// Subscribes to a Convex query and delivers every lifecycle signal as a
+/// single [`SubscriptionEvent`] through `on_event`.
+///
+/// Events are delivered in order: each callback is awaited before the
+/// next event is produced. The last event is always
+/// [`SubscriptionEvent::Closed`]. The subscription is made like those of
+/// [`MobileConvexClient::subscribe`], so it is also paused and resumed,
+/// and takes the same `options` except for a backpressure policy.
+ Future<SubscriptionHandle>  subscribeWithEvents({required String name , required Map<String, String> args , required FutureOr<void> Function(SubscriptionEvent) onEvent , SubscribeOptions? options })=>RustLib.instance.api.crateMobileConvexClientSubscribeWithEvents(that: this, name: name, args: args, onEvent: onEvent, options: options);
+
+
+/// Subscribes to a Convex query, delivering each update as a
+/// [`PatchUpdate`] against the previous one. A full snapshot is also
+/// sent every `snapshot_interval` updates, or only when needed if it is
+/// `0`. Callbacks are awaited in order, so patches apply in sequence.
+ Future<SubscriptionHandle>  subscribeWithPatches({required String name , required Map<String, String> args , required int snapshotInterval , required FutureOr<void> Function(PatchUpdate) onPatch , required FutureOr<void> Function(String, String?) onError })=>RustLib.instance.api.crateMobileConvexClientSubscribeWithPatches(that: this, name: name, args: args, snapshotInterval: snapshotInterval, onPatch: onPatch, onError: onError);
+
+
+/// Returns the hint to show for the client's current state, if any.
+ UiHint?  uiHint()=>RustLib.instance.api.crateMobileConvexClientUiHint(that: this, );
+
+
+/// Returns the UI load last reported with [`MobileConvexClient::set_ui_pressure`].
+ UiPressure  uiPressure()=>RustLib.instance.api.crateMobileConvexClientUiPressure(that: this, );
+
+
+/// Cancels the subscription made under `key`, if any.
+ void  unsubscribeKeyed({required String key })=>RustLib.instance.api.crateMobileConvexClientUnsubscribeKeyed(that: this, key: key);
+
+
+/// Uploads `source` with the content type `mime_type` and returns its
+/// storage ID, as described in the [module docs](crate::file_storage).
+/// `on_progress` is called with the bytes sent so far; updates arriving
+/// while it runs are coalesced to the latest.
+ Future<String>  uploadFile({required UploadSource source , required String mimeType , required FutureOr<void> Function(TransferProgress) onProgress })=>RustLib.instance.api.crateMobileConvexClientUploadFile(that: this, source: source, mimeType: mimeType, onProgress: onProgress);
+
+
+/// Subscribes to a Convex query, first emitting the placeholder chosen
+/// by `placeholder`. The placeholder is always delivered before any
+/// server value.
+ Future<SubscriptionHandle>  watch({required String name , required Map<String, String> args , required PlaceholderPolicy placeholder , required FutureOr<void> Function(WatchEvent) onEvent })=>RustLib.instance.api.crateMobileConvexClientWatch(that: this, name: name, args: args, placeholder: placeholder, onEvent: onEvent);
+
+
+/// Subscribes to all `sources` and delivers the value described by
+/// `fields` as a JSON object whenever it changes. Errors of any source
+/// are passed to `on_error`. Cancelling the returned handle cancels all
+/// source subscriptions.
+ Future<SubscriptionHandle>  watchDerived({required List<DerivedSource> sources , required List<DerivedField> fields , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError })=>RustLib.instance.api.crateMobileConvexClientWatchDerived(that: this, sources: sources, fields: fields, onUpdate: onUpdate, onError: onError);
+
+
+            }
+            @sealed class PaginatedQueryHandleImpl extends RustOpaque implements PaginatedQueryHandle {
+                // Not to be used by end users
+                PaginatedQueryHandleImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                PaginatedQueryHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PaginatedQueryHandle,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PaginatedQueryHandle,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PaginatedQueryHandlePtr,
+                );
+
+                /// Cancels all page subscriptions.
+ void  cancel()=>RustLib.instance.api.cratePaginationPaginatedQueryHandleCancel(that: this, );
+
+
+/// Requests another page of `num_items` items. Returns `false` if no
+/// more items exist, a page is still loading or the subscription was
+/// cancelled.
+ bool  loadMore({required int numItems })=>RustLib.instance.api.cratePaginationPaginatedQueryHandleLoadMore(that: this, numItems: numItems);
+
+
+/// Returns the current loading state.
+ PaginationStatus  status()=>RustLib.instance.api.cratePaginationPaginatedQueryHandleStatus(that: this, );
+
+
+            }
+            @sealed class PresenceHandleImpl extends RustOpaque implements PresenceHandle {
+                // Not to be used by end users
+                PresenceHandleImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                PresenceHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PresenceHandle,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PresenceHandle,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PresenceHandlePtr,
+                );
+
+                /// Returns whether heartbeats are currently paused by the caller.
+ bool  isPaused()=>RustLib.instance.api.cratePresencePresenceHandleIsPaused(that: this, );
+
+
+/// Pauses heartbeats, e.g. when the app moves to the background.
+ void  pause()=>RustLib.instance.api.cratePresencePresenceHandlePause(that: this, );
+
+
+/// Resumes heartbeats and sends one immediately.
+ void  resume()=>RustLib.instance.api.cratePresencePresenceHandleResume(that: this, );
+
+
+/// Stops the heartbeat loop.
+ void  stop()=>RustLib.instance.api.cratePresencePresenceHandleStop(that: this, );
+
+
+            }
+            @sealed class SubscriptionHandleImpl extends RustOpaque implements SubscriptionHandle {
+                // Not to be used by end users
+                SubscriptionHandleImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SubscriptionHandleImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SubscriptionHandle,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SubscriptionHandle,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SubscriptionHandlePtr,
+                );
+
+                /// Cancels the subscription by sending a cancellation signal. Returns
+/// `false` if it had already been cancelled or ended; calling it again
+/// is harmless.
+ bool  cancel()=>RustLib.instance.api.crateSubscriptionHandleCancel(that: this, );
+
+
+/// Returns whether the subscription is neither cancelled nor ended.
+ bool  isActive()=>RustLib.instance.api.crateSubscriptionHandleIsActive(that: this, );
+
+
+/// Changes the order in which this subscription is re-established after
+/// a reconnect, e.g. when its screen becomes visible.
+ void  setPriority({required SubscriptionPriority priority })=>RustLib.instance.api.crateSubscriptionHandleSetPriority(that: this, priority: priority);
+
+
+/// Returns whether the subscription is active, has ended or was
+/// cancelled.
+ SubscriptionState  state()=>RustLib.instance.api.crateSubscriptionHandleState(that: this, );
+
+
+            }
\ No newline at end of file
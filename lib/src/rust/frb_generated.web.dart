@@ -3,696 +3,1605 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'audit.dart';
+import 'auth_changes.dart';
+import 'auth_refresh.dart';
+import 'backpressure.dart';
+import 'batch_query.dart';
+import 'budget.dart';
+import 'call_metrics.dart';
+import 'cancellation.dart';
+import 'chunked.dart';
+import 'codecs.dart';
+import 'commit_token.dart';
+import 'connection.dart';
+import 'convex_value.dart';
 import 'dart:async';
 import 'dart:convert';
+import 'deferred.dart';
+import 'derived.dart';
+import 'errors.dart';
+import 'events.dart';
+import 'failover.dart';
+import 'faults.dart';
+import 'file_storage.dart';
 import 'frb_generated.dart';
+import 'hints.dart';
+import 'http_actions.dart';
+import 'interceptors.dart';
+import 'jobs.dart';
 import 'lib.dart';
+import 'list_diff.dart';
+import 'logging.dart';
+import 'logout.dart';
+import 'memory.dart';
+import 'metrics.dart';
+import 'mutation_status.dart';
+import 'optimistic.dart';
+import 'options.dart';
+import 'outbox.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
+import 'pagination.dart';
+import 'panics.dart';
+import 'patches.dart';
+import 'persisted_results.dart';
+import 'placeholder.dart';
+import 'presence.dart';
+import 'pressure.dart';
+import 'preview.dart';
+import 'quality.dart';
+import 'query_cache.dart';
+import 'registry.dart';
+import 'request_ids.dart';
+import 'resubscribe.dart';
+import 'retry.dart';
+import 'sampling.dart';
+import 'schema_check.dart';
+import 'sequence.dart';
+import 'sharding.dart';
+import 'spans.dart';
+import 'subscription.dart';
+import 'subscription_stream.dart';
+import 'supervisor.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_AuthHandlePtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_CallbackSubscriberPtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_CallbackSubscriberDartFnPtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_MobileConvexClientPtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient;
-
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_SubscriptionHandlePtr => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle;
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw);
-
-  @protected
-  AuthHandle
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    dynamic raw,
-  );
-
-  @protected
-  CallbackSubscriber
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    dynamic raw,
-  );
-
-  @protected
-  CallbackSubscriberDartFn
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    dynamic raw,
-  );
-
-  @protected
-  MobileConvexClient
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    dynamic raw,
-  );
-
-  @protected
-  SubscriptionHandle
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    dynamic raw,
-  );
-
-  @protected
-  AuthHandle
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    dynamic raw,
-  );
-
-  @protected
-  CallbackSubscriber
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    dynamic raw,
-  );
-
-  @protected
-  CallbackSubscriberDartFn
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    dynamic raw,
-  );
-
-  @protected
-  MobileConvexClient
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    dynamic raw,
-  );
-
-  @protected
-  SubscriptionHandle
-  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    dynamic raw,
-  );
-
-  @protected
-  FutureOr<void> Function(String)
-  dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw);
-
-  @protected
-  FutureOr<void> Function(String, String?)
-  dco_decode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(
-    dynamic raw,
-  );
-
-  @protected
-  FutureOr<String?> Function()
-  dco_decode_DartFn_Inputs__Output_opt_String_AnyhowException(dynamic raw);
-
-  @protected
-  FutureOr<void> Function(bool)
-  dco_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(dynamic raw);
-
-  @protected
-  FutureOr<void> Function(WebSocketConnectionState)
-  dco_decode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(
-    dynamic raw,
-  );
-
-  @protected
-  Object dco_decode_DartOpaque(dynamic raw);
-
-  @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
-
-  @protected
-  AuthHandle
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    dynamic raw,
-  );
-
-  @protected
-  CallbackSubscriber
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    dynamic raw,
-  );
-
-  @protected
-  CallbackSubscriberDartFn
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    dynamic raw,
-  );
-
-  @protected
-  MobileConvexClient
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    dynamic raw,
-  );
-
-  @protected
-  SubscriptionHandle
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    dynamic raw,
-  );
-
-  @protected
-  String dco_decode_String(dynamic raw);
-
-  @protected
-  QuerySubscriber dco_decode_TraitDef_QuerySubscriber(dynamic raw);
-
-  @protected
-  bool dco_decode_bool(dynamic raw);
-
-  @protected
-  ClientError dco_decode_client_error(dynamic raw);
-
-  @protected
-  int dco_decode_i_32(dynamic raw);
-
-  @protected
-  PlatformInt64 dco_decode_isize(dynamic raw);
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
-
-  @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw);
-
-  @protected
-  (String, String) dco_decode_record_string_string(dynamic raw);
-
-  @protected
-  int dco_decode_u_8(dynamic raw);
-
-  @protected
-  void dco_decode_unit(dynamic raw);
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
-
-  @protected
-  WebSocketConnectionState dco_decode_web_socket_connection_state(dynamic raw);
-
-  @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
-
-  @protected
-  AuthHandle
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CallbackSubscriber
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CallbackSubscriberDartFn
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MobileConvexClient
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  SubscriptionHandle
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  AuthHandle
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CallbackSubscriber
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CallbackSubscriberDartFn
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MobileConvexClient
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  SubscriptionHandle
-  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  Object sse_decode_DartOpaque(SseDeserializer deserializer);
-
-  @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  AuthHandle
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CallbackSubscriber
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  CallbackSubscriberDartFn
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  MobileConvexClient
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  SubscriptionHandle
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
-
-  @protected
-  ClientError sse_decode_client_error(SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
-
-  @protected
-  PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
-
-  @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
-
-  @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
-
-  @protected
-  WebSocketConnectionState sse_decode_web_socket_connection_state(
-    SseDeserializer deserializer,
-  );
-
-  @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    AuthHandle self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    CallbackSubscriber self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    CallbackSubscriberDartFn self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    MobileConvexClient self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SubscriptionHandle self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    AuthHandle self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    CallbackSubscriber self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    CallbackSubscriberDartFn self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    MobileConvexClient self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SubscriptionHandle self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
-    FutureOr<void> Function(String) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(
-    FutureOr<void> Function(String, String?) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_DartFn_Inputs__Output_opt_String_AnyhowException(
-    FutureOr<String?> Function() self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(
-    FutureOr<void> Function(bool) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(
-    FutureOr<void> Function(WebSocketConnectionState) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    AuthHandle self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    CallbackSubscriber self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    CallbackSubscriberDartFn self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    MobileConvexClient self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    SubscriptionHandle self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_client_error(ClientError self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
-    SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_web_socket_connection_state(
-    WebSocketConnectionState self,
-    SseSerializer serializer,
-  );
-}
 
-// Section: wire_class
 
-class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-        ptr,
-      );
-
-  void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-        ptr,
-      );
-
-  void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    int ptr,
-  ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-        ptr,
-      );
-}
-
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
-
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(
-    int ptr,
-  );
-
-  external void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    int ptr,
-  );
-
-  external void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(
-    int ptr,
-  );
-}
+
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AuthHandlePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CallbackSubscriberPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CallbackSubscriberDartFnPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CancellationTokenPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_MobileConvexClientPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PaginatedQueryHandlePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PresenceHandlePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SubscriptionHandlePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
+
+@protected AuthHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(dynamic raw);
+
+@protected CallbackSubscriber dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(dynamic raw);
+
+@protected CallbackSubscriberDartFn dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(dynamic raw);
+
+@protected CancellationToken dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(dynamic raw);
+
+@protected MobileConvexClient dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(dynamic raw);
+
+@protected PaginatedQueryHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(dynamic raw);
+
+@protected PresenceHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(dynamic raw);
+
+@protected SubscriptionHandle dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(dynamic raw);
+
+@protected AuthHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(dynamic raw);
+
+@protected CallbackSubscriber dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(dynamic raw);
+
+@protected CallbackSubscriberDartFn dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(dynamic raw);
+
+@protected CancellationToken dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(dynamic raw);
+
+@protected MobileConvexClient dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(dynamic raw);
+
+@protected PaginatedQueryHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(dynamic raw);
+
+@protected PresenceHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(dynamic raw);
+
+@protected SubscriptionHandle dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(dynamic raw);
+
+@protected FutureOr<void> Function(String) dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(String, String?) dco_decode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<String?> Function() dco_decode_DartFn_Inputs__Output_opt_String_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function() dco_decode_DartFn_Inputs__Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(AuthChange) dco_decode_DartFn_Inputs_auth_change_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(BackendChangedEvent) dco_decode_DartFn_Inputs_backend_changed_event_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(bool) dco_decode_DartFn_Inputs_bool_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(BudgetExceededEvent) dco_decode_DartFn_Inputs_budget_exceeded_event_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(ClientEvent) dco_decode_DartFn_Inputs_client_event_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(ConnectionQuality) dco_decode_DartFn_Inputs_connection_quality_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(ConvexValue) dco_decode_DartFn_Inputs_convex_value_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(FailoverEvent) dco_decode_DartFn_Inputs_failover_event_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<InterceptDecision> Function(InterceptedCall) dco_decode_DartFn_Inputs_intercepted_call_Output_intercept_decision_AnyhowException(dynamic raw);
+
+@protected FutureOr<CallOutcome> Function(InterceptedCall, CallOutcome) dco_decode_DartFn_Inputs_intercepted_call_call_outcome_Output_call_outcome_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(JobProgress) dco_decode_DartFn_Inputs_job_progress_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(List<PresenceStatus>) dco_decode_DartFn_Inputs_list_presence_status_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(ListUpdate) dco_decode_DartFn_Inputs_list_update_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(LogRecord) dco_decode_DartFn_Inputs_log_record_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(LogoutEvent) dco_decode_DartFn_Inputs_logout_event_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(MemoryReleasedEvent) dco_decode_DartFn_Inputs_memory_released_event_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(MutationLifecycleEvent) dco_decode_DartFn_Inputs_mutation_lifecycle_event_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(UiHint?) dco_decode_DartFn_Inputs_opt_box_autoadd_ui_hint_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(OutboxCompletion) dco_decode_DartFn_Inputs_outbox_completion_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<ConflictResolution> Function(OutboxConflict) dco_decode_DartFn_Inputs_outbox_conflict_Output_conflict_resolution_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(PaginatedUpdate) dco_decode_DartFn_Inputs_paginated_update_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(PanicReport) dco_decode_DartFn_Inputs_panic_report_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(PatchUpdate) dco_decode_DartFn_Inputs_patch_update_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(ResultChunk) dco_decode_DartFn_Inputs_result_chunk_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(SlowInitializationEvent) dco_decode_DartFn_Inputs_slow_initialization_event_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(SubscriptionEvent) dco_decode_DartFn_Inputs_subscription_event_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(TransferProgress) dco_decode_DartFn_Inputs_transfer_progress_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(WatchEvent) dco_decode_DartFn_Inputs_watch_event_Output_unit_AnyhowException(dynamic raw);
+
+@protected FutureOr<void> Function(WebSocketConnectionState) dco_decode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(dynamic raw);
+
+@protected Object dco_decode_DartOpaque(dynamic raw);
+
+@protected Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
+
+@protected Map<String, ConvexValue> dco_decode_Map_String_convex_value_None(dynamic raw);
+
+@protected Map<String, double> dco_decode_Map_String_f_64_None(dynamic raw);
+
+@protected AuthHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(dynamic raw);
+
+@protected CallbackSubscriber dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(dynamic raw);
+
+@protected CallbackSubscriberDartFn dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(dynamic raw);
+
+@protected CancellationToken dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(dynamic raw);
+
+@protected MobileConvexClient dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(dynamic raw);
+
+@protected PaginatedQueryHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(dynamic raw);
+
+@protected PresenceHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(dynamic raw);
+
+@protected SubscriptionHandle dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(dynamic raw);
+
+@protected RustStreamSink<String> dco_decode_StreamSink_String_Sse(dynamic raw);
+
+@protected String dco_decode_String(dynamic raw);
+
+@protected EventSink dco_decode_TraitDef_EventSink(dynamic raw);
+
+@protected QuerySubscriber dco_decode_TraitDef_QuerySubscriber(dynamic raw);
+
+@protected AuditLogOptions dco_decode_audit_log_options(dynamic raw);
+
+@protected AuthChange dco_decode_auth_change(dynamic raw);
+
+@protected AuthChangeReason dco_decode_auth_change_reason(dynamic raw);
+
+@protected AuthRefreshConfig dco_decode_auth_refresh_config(dynamic raw);
+
+@protected BackendChangedEvent dco_decode_backend_changed_event(dynamic raw);
+
+@protected Backpressure dco_decode_backpressure(dynamic raw);
+
+@protected BatchQuery dco_decode_batch_query(dynamic raw);
+
+@protected BlankArgHandling dco_decode_blank_arg_handling(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected AuditLogOptions dco_decode_box_autoadd_audit_log_options(dynamic raw);
+
+@protected AuthRefreshConfig dco_decode_box_autoadd_auth_refresh_config(dynamic raw);
+
+@protected Backpressure dco_decode_box_autoadd_backpressure(dynamic raw);
+
+@protected ClientError dco_decode_box_autoadd_client_error(dynamic raw);
+
+@protected ClientOptions dco_decode_box_autoadd_client_options(dynamic raw);
+
+@protected CombineStrategy dco_decode_box_autoadd_combine_strategy(dynamic raw);
+
+@protected ConvexValue dco_decode_box_autoadd_convex_value(dynamic raw);
+
+@protected DartKeepaliveOptions dco_decode_box_autoadd_dart_keepalive_options(dynamic raw);
+
+@protected FailoverOptions dco_decode_box_autoadd_failover_options(dynamic raw);
+
+@protected FunctionFault dco_decode_box_autoadd_function_fault(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected InterceptedCall dco_decode_box_autoadd_intercepted_call(dynamic raw);
+
+@protected JobOptions dco_decode_box_autoadd_job_options(dynamic raw);
+
+@protected PersistedResultOptions dco_decode_box_autoadd_persisted_result_options(dynamic raw);
+
+@protected PresenceOptions dco_decode_box_autoadd_presence_options(dynamic raw);
+
+@protected ResultCacheOptions dco_decode_box_autoadd_result_cache_options(dynamic raw);
+
+@protected SchemaCheckOptions dco_decode_box_autoadd_schema_check_options(dynamic raw);
+
+@protected ShardMapping dco_decode_box_autoadd_shard_mapping(dynamic raw);
+
+@protected SubscribeOptions dco_decode_box_autoadd_subscribe_options(dynamic raw);
+
+@protected TelemetrySampling dco_decode_box_autoadd_telemetry_sampling(dynamic raw);
+
+@protected TraceExportOptions dco_decode_box_autoadd_trace_export_options(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected UiHint dco_decode_box_autoadd_ui_hint(dynamic raw);
+
+@protected UploadSource dco_decode_box_autoadd_upload_source(dynamic raw);
+
+@protected UsageBudget dco_decode_box_autoadd_usage_budget(dynamic raw);
+
+@protected BudgetEnforcement dco_decode_budget_enforcement(dynamic raw);
+
+@protected BudgetExceededEvent dco_decode_budget_exceeded_event(dynamic raw);
+
+@protected BudgetLimit dco_decode_budget_limit(dynamic raw);
+
+@protected CallKind dco_decode_call_kind(dynamic raw);
+
+@protected CallOutcome dco_decode_call_outcome(dynamic raw);
+
+@protected ChunkedResultOptions dco_decode_chunked_result_options(dynamic raw);
+
+@protected ClassSampleRate dco_decode_class_sample_rate(dynamic raw);
+
+@protected ClientError dco_decode_client_error(dynamic raw);
+
+@protected ClientEvent dco_decode_client_event(dynamic raw);
+
+@protected ClientMetrics dco_decode_client_metrics(dynamic raw);
+
+@protected ClientOptions dco_decode_client_options(dynamic raw);
+
+@protected CodecWire dco_decode_codec_wire(dynamic raw);
+
+@protected CombineStrategy dco_decode_combine_strategy(dynamic raw);
+
+@protected CommittedMutation dco_decode_committed_mutation(dynamic raw);
+
+@protected ConflictResolution dco_decode_conflict_resolution(dynamic raw);
+
+@protected ConnectRetryOptions dco_decode_connect_retry_options(dynamic raw);
+
+@protected ConnectionCloseReason dco_decode_connection_close_reason(dynamic raw);
+
+@protected ConnectionQuality dco_decode_connection_quality(dynamic raw);
+
+@protected ConnectionQualityLevel dco_decode_connection_quality_level(dynamic raw);
+
+@protected ConvexValue dco_decode_convex_value(dynamic raw);
+
+@protected DartKeepaliveOptions dco_decode_dart_keepalive_options(dynamic raw);
+
+@protected DartLiveness dco_decode_dart_liveness(dynamic raw);
+
+@protected DartLivenessState dco_decode_dart_liveness_state(dynamic raw);
+
+@protected DeferredMutationOptions dco_decode_deferred_mutation_options(dynamic raw);
+
+@protected DerivedField dco_decode_derived_field(dynamic raw);
+
+@protected DerivedSource dco_decode_derived_source(dynamic raw);
+
+@protected DerivedValue dco_decode_derived_value(dynamic raw);
+
+@protected ErrorCode dco_decode_error_code(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected FailoverEvent dco_decode_failover_event(dynamic raw);
+
+@protected FailoverOptions dco_decode_failover_options(dynamic raw);
+
+@protected FailoverReason dco_decode_failover_reason(dynamic raw);
+
+@protected FileStorageOptions dco_decode_file_storage_options(dynamic raw);
+
+@protected FunctionFault dco_decode_function_fault(dynamic raw);
+
+@protected FunctionMetrics dco_decode_function_metrics(dynamic raw);
+
+@protected HttpActionResponse dco_decode_http_action_response(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected IdempotentMutationResult dco_decode_idempotent_mutation_result(dynamic raw);
+
+@protected InitializationDiagnostics dco_decode_initialization_diagnostics(dynamic raw);
+
+@protected Int64Encoding dco_decode_int_64_encoding(dynamic raw);
+
+@protected InterceptDecision dco_decode_intercept_decision(dynamic raw);
+
+@protected InterceptedCall dco_decode_intercepted_call(dynamic raw);
+
+@protected PlatformInt64 dco_decode_isize(dynamic raw);
+
+@protected JobOptions dco_decode_job_options(dynamic raw);
+
+@protected JobProgress dco_decode_job_progress(dynamic raw);
+
+@protected LatencyHistogram dco_decode_latency_histogram(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<BatchQuery> dco_decode_list_batch_query(dynamic raw);
+
+@protected ListChange dco_decode_list_change(dynamic raw);
+
+@protected List<ClassSampleRate> dco_decode_list_class_sample_rate(dynamic raw);
+
+@protected List<ConvexValue> dco_decode_list_convex_value(dynamic raw);
+
+@protected List<DerivedField> dco_decode_list_derived_field(dynamic raw);
+
+@protected List<DerivedSource> dco_decode_list_derived_source(dynamic raw);
+
+@protected List<FunctionMetrics> dco_decode_list_function_metrics(dynamic raw);
+
+@protected List<ListChange> dco_decode_list_list_change(dynamic raw);
+
+@protected List<MutationCall> dco_decode_list_mutation_call(dynamic raw);
+
+@protected List<MutationStepStatus> dco_decode_list_mutation_step_status(dynamic raw);
+
+@protected List<OptimisticUpdate> dco_decode_list_optimistic_update(dynamic raw);
+
+@protected List<PresenceStatus> dco_decode_list_presence_status(dynamic raw);
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<QueryDependency> dco_decode_list_query_dependency(dynamic raw);
+
+@protected List<(String,ConvexValue)> dco_decode_list_record_string_convex_value(dynamic raw);
+
+@protected List<(String,double)> dco_decode_list_record_string_f_64(dynamic raw);
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw);
+
+@protected List<SubscriptionInfo> dco_decode_list_subscription_info(dynamic raw);
+
+@protected List<TelemetryClass> dco_decode_list_telemetry_class(dynamic raw);
+
+@protected List<TypeCodec> dco_decode_list_type_codec(dynamic raw);
+
+@protected ListUpdate dco_decode_list_update(dynamic raw);
+
+@protected LogLevel dco_decode_log_level(dynamic raw);
+
+@protected LogRecord dco_decode_log_record(dynamic raw);
+
+@protected LogoutEvent dco_decode_logout_event(dynamic raw);
+
+@protected MemoryPressure dco_decode_memory_pressure(dynamic raw);
+
+@protected MemoryReleasedEvent dco_decode_memory_released_event(dynamic raw);
+
+@protected MetricsReport dco_decode_metrics_report(dynamic raw);
+
+@protected MutationCall dco_decode_mutation_call(dynamic raw);
+
+@protected MutationLifecycleEvent dco_decode_mutation_lifecycle_event(dynamic raw);
+
+@protected MutationRetryOptions dco_decode_mutation_retry_options(dynamic raw);
+
+@protected MutationSequenceResult dco_decode_mutation_sequence_result(dynamic raw);
+
+@protected MutationStatus dco_decode_mutation_status(dynamic raw);
+
+@protected MutationStepStatus dco_decode_mutation_step_status(dynamic raw);
+
+@protected NullHandling dco_decode_null_handling(dynamic raw);
+
+@protected OfflineMutationResult dco_decode_offline_mutation_result(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected AuditLogOptions? dco_decode_opt_box_autoadd_audit_log_options(dynamic raw);
+
+@protected Backpressure? dco_decode_opt_box_autoadd_backpressure(dynamic raw);
+
+@protected FailoverOptions? dco_decode_opt_box_autoadd_failover_options(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected PersistedResultOptions? dco_decode_opt_box_autoadd_persisted_result_options(dynamic raw);
+
+@protected SchemaCheckOptions? dco_decode_opt_box_autoadd_schema_check_options(dynamic raw);
+
+@protected SubscribeOptions? dco_decode_opt_box_autoadd_subscribe_options(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected UiHint? dco_decode_opt_box_autoadd_ui_hint(dynamic raw);
+
+@protected UsageBudget? dco_decode_opt_box_autoadd_usage_budget(dynamic raw);
+
+@protected List<String>? dco_decode_opt_list_String(dynamic raw);
+
+@protected Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+@protected OptimisticUpdate dco_decode_optimistic_update(dynamic raw);
+
+@protected OutboxCompletion dco_decode_outbox_completion(dynamic raw);
+
+@protected OutboxConflict dco_decode_outbox_conflict(dynamic raw);
+
+@protected PaginatedUpdate dco_decode_paginated_update(dynamic raw);
+
+@protected PaginationStatus dco_decode_pagination_status(dynamic raw);
+
+@protected PanicReport dco_decode_panic_report(dynamic raw);
+
+@protected PatchUpdate dco_decode_patch_update(dynamic raw);
+
+@protected PersistedResultOptions dco_decode_persisted_result_options(dynamic raw);
+
+@protected PlaceholderPolicy dco_decode_placeholder_policy(dynamic raw);
+
+@protected PresenceOptions dco_decode_presence_options(dynamic raw);
+
+@protected PresenceStatus dco_decode_presence_status(dynamic raw);
+
+@protected PressureThrottle dco_decode_pressure_throttle(dynamic raw);
+
+@protected PreviewOptions dco_decode_preview_options(dynamic raw);
+
+@protected QueryDependency dco_decode_query_dependency(dynamic raw);
+
+@protected (String,ConvexValue) dco_decode_record_string_convex_value(dynamic raw);
+
+@protected (String,double) dco_decode_record_string_f_64(dynamic raw);
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw);
+
+@protected RequestIdOptions dco_decode_request_id_options(dynamic raw);
+
+@protected ResultCacheOptions dco_decode_result_cache_options(dynamic raw);
+
+@protected ResultChunk dco_decode_result_chunk(dynamic raw);
+
+@protected RuntimeMetrics dco_decode_runtime_metrics(dynamic raw);
+
+@protected SchemaCheckOptions dco_decode_schema_check_options(dynamic raw);
+
+@protected ShardMapping dco_decode_shard_mapping(dynamic raw);
+
+@protected ShardingMetrics dco_decode_sharding_metrics(dynamic raw);
+
+@protected SlowInitializationEvent dco_decode_slow_initialization_event(dynamic raw);
+
+@protected SubscribeOptions dco_decode_subscribe_options(dynamic raw);
+
+@protected SubscriptionCloseReason dco_decode_subscription_close_reason(dynamic raw);
+
+@protected SubscriptionEvent dco_decode_subscription_event(dynamic raw);
+
+@protected SubscriptionInfo dco_decode_subscription_info(dynamic raw);
+
+@protected SubscriptionPriority dco_decode_subscription_priority(dynamic raw);
+
+@protected SubscriptionState dco_decode_subscription_state(dynamic raw);
+
+@protected TelemetryClass dco_decode_telemetry_class(dynamic raw);
+
+@protected TelemetrySampling dco_decode_telemetry_sampling(dynamic raw);
+
+@protected TraceExportOptions dco_decode_trace_export_options(dynamic raw);
+
+@protected TransferProgress dco_decode_transfer_progress(dynamic raw);
+
+@protected TypeCodec dco_decode_type_codec(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected UiHint dco_decode_ui_hint(dynamic raw);
+
+@protected UiHintCode dco_decode_ui_hint_code(dynamic raw);
+
+@protected UiPressure dco_decode_ui_pressure(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected UploadSource dco_decode_upload_source(dynamic raw);
+
+@protected UsageBudget dco_decode_usage_budget(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected WatchEvent dco_decode_watch_event(dynamic raw);
+
+@protected WebSocketConnectionState dco_decode_web_socket_connection_state(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected AuthHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(SseDeserializer deserializer);
+
+@protected CallbackSubscriber sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(SseDeserializer deserializer);
+
+@protected CallbackSubscriberDartFn sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(SseDeserializer deserializer);
+
+@protected CancellationToken sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(SseDeserializer deserializer);
+
+@protected MobileConvexClient sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(SseDeserializer deserializer);
+
+@protected PaginatedQueryHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(SseDeserializer deserializer);
+
+@protected PresenceHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(SseDeserializer deserializer);
+
+@protected SubscriptionHandle sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SseDeserializer deserializer);
+
+@protected AuthHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(SseDeserializer deserializer);
+
+@protected CallbackSubscriber sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(SseDeserializer deserializer);
+
+@protected CallbackSubscriberDartFn sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(SseDeserializer deserializer);
+
+@protected CancellationToken sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(SseDeserializer deserializer);
+
+@protected MobileConvexClient sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(SseDeserializer deserializer);
+
+@protected PaginatedQueryHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(SseDeserializer deserializer);
+
+@protected PresenceHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(SseDeserializer deserializer);
+
+@protected SubscriptionHandle sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SseDeserializer deserializer);
+
+@protected Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
+@protected Map<String, String> sse_decode_Map_String_String_None(SseDeserializer deserializer);
+
+@protected Map<String, ConvexValue> sse_decode_Map_String_convex_value_None(SseDeserializer deserializer);
+
+@protected Map<String, double> sse_decode_Map_String_f_64_None(SseDeserializer deserializer);
+
+@protected AuthHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(SseDeserializer deserializer);
+
+@protected CallbackSubscriber sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(SseDeserializer deserializer);
+
+@protected CallbackSubscriberDartFn sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(SseDeserializer deserializer);
+
+@protected CancellationToken sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(SseDeserializer deserializer);
+
+@protected MobileConvexClient sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(SseDeserializer deserializer);
+
+@protected PaginatedQueryHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(SseDeserializer deserializer);
+
+@protected PresenceHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(SseDeserializer deserializer);
+
+@protected SubscriptionHandle sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SseDeserializer deserializer);
+
+@protected RustStreamSink<String> sse_decode_StreamSink_String_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AuditLogOptions sse_decode_audit_log_options(SseDeserializer deserializer);
+
+@protected AuthChange sse_decode_auth_change(SseDeserializer deserializer);
+
+@protected AuthChangeReason sse_decode_auth_change_reason(SseDeserializer deserializer);
+
+@protected AuthRefreshConfig sse_decode_auth_refresh_config(SseDeserializer deserializer);
+
+@protected BackendChangedEvent sse_decode_backend_changed_event(SseDeserializer deserializer);
+
+@protected Backpressure sse_decode_backpressure(SseDeserializer deserializer);
+
+@protected BatchQuery sse_decode_batch_query(SseDeserializer deserializer);
+
+@protected BlankArgHandling sse_decode_blank_arg_handling(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected AuditLogOptions sse_decode_box_autoadd_audit_log_options(SseDeserializer deserializer);
+
+@protected AuthRefreshConfig sse_decode_box_autoadd_auth_refresh_config(SseDeserializer deserializer);
+
+@protected Backpressure sse_decode_box_autoadd_backpressure(SseDeserializer deserializer);
+
+@protected ClientError sse_decode_box_autoadd_client_error(SseDeserializer deserializer);
+
+@protected ClientOptions sse_decode_box_autoadd_client_options(SseDeserializer deserializer);
+
+@protected CombineStrategy sse_decode_box_autoadd_combine_strategy(SseDeserializer deserializer);
+
+@protected ConvexValue sse_decode_box_autoadd_convex_value(SseDeserializer deserializer);
+
+@protected DartKeepaliveOptions sse_decode_box_autoadd_dart_keepalive_options(SseDeserializer deserializer);
+
+@protected FailoverOptions sse_decode_box_autoadd_failover_options(SseDeserializer deserializer);
+
+@protected FunctionFault sse_decode_box_autoadd_function_fault(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected InterceptedCall sse_decode_box_autoadd_intercepted_call(SseDeserializer deserializer);
+
+@protected JobOptions sse_decode_box_autoadd_job_options(SseDeserializer deserializer);
+
+@protected PersistedResultOptions sse_decode_box_autoadd_persisted_result_options(SseDeserializer deserializer);
+
+@protected PresenceOptions sse_decode_box_autoadd_presence_options(SseDeserializer deserializer);
+
+@protected ResultCacheOptions sse_decode_box_autoadd_result_cache_options(SseDeserializer deserializer);
+
+@protected SchemaCheckOptions sse_decode_box_autoadd_schema_check_options(SseDeserializer deserializer);
+
+@protected ShardMapping sse_decode_box_autoadd_shard_mapping(SseDeserializer deserializer);
+
+@protected SubscribeOptions sse_decode_box_autoadd_subscribe_options(SseDeserializer deserializer);
+
+@protected TelemetrySampling sse_decode_box_autoadd_telemetry_sampling(SseDeserializer deserializer);
+
+@protected TraceExportOptions sse_decode_box_autoadd_trace_export_options(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected UiHint sse_decode_box_autoadd_ui_hint(SseDeserializer deserializer);
+
+@protected UploadSource sse_decode_box_autoadd_upload_source(SseDeserializer deserializer);
+
+@protected UsageBudget sse_decode_box_autoadd_usage_budget(SseDeserializer deserializer);
+
+@protected BudgetEnforcement sse_decode_budget_enforcement(SseDeserializer deserializer);
+
+@protected BudgetExceededEvent sse_decode_budget_exceeded_event(SseDeserializer deserializer);
+
+@protected BudgetLimit sse_decode_budget_limit(SseDeserializer deserializer);
+
+@protected CallKind sse_decode_call_kind(SseDeserializer deserializer);
+
+@protected CallOutcome sse_decode_call_outcome(SseDeserializer deserializer);
+
+@protected ChunkedResultOptions sse_decode_chunked_result_options(SseDeserializer deserializer);
+
+@protected ClassSampleRate sse_decode_class_sample_rate(SseDeserializer deserializer);
+
+@protected ClientError sse_decode_client_error(SseDeserializer deserializer);
+
+@protected ClientEvent sse_decode_client_event(SseDeserializer deserializer);
+
+@protected ClientMetrics sse_decode_client_metrics(SseDeserializer deserializer);
+
+@protected ClientOptions sse_decode_client_options(SseDeserializer deserializer);
+
+@protected CodecWire sse_decode_codec_wire(SseDeserializer deserializer);
+
+@protected CombineStrategy sse_decode_combine_strategy(SseDeserializer deserializer);
+
+@protected CommittedMutation sse_decode_committed_mutation(SseDeserializer deserializer);
+
+@protected ConflictResolution sse_decode_conflict_resolution(SseDeserializer deserializer);
+
+@protected ConnectRetryOptions sse_decode_connect_retry_options(SseDeserializer deserializer);
+
+@protected ConnectionCloseReason sse_decode_connection_close_reason(SseDeserializer deserializer);
+
+@protected ConnectionQuality sse_decode_connection_quality(SseDeserializer deserializer);
+
+@protected ConnectionQualityLevel sse_decode_connection_quality_level(SseDeserializer deserializer);
+
+@protected ConvexValue sse_decode_convex_value(SseDeserializer deserializer);
+
+@protected DartKeepaliveOptions sse_decode_dart_keepalive_options(SseDeserializer deserializer);
+
+@protected DartLiveness sse_decode_dart_liveness(SseDeserializer deserializer);
+
+@protected DartLivenessState sse_decode_dart_liveness_state(SseDeserializer deserializer);
+
+@protected DeferredMutationOptions sse_decode_deferred_mutation_options(SseDeserializer deserializer);
+
+@protected DerivedField sse_decode_derived_field(SseDeserializer deserializer);
+
+@protected DerivedSource sse_decode_derived_source(SseDeserializer deserializer);
+
+@protected DerivedValue sse_decode_derived_value(SseDeserializer deserializer);
+
+@protected ErrorCode sse_decode_error_code(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FailoverEvent sse_decode_failover_event(SseDeserializer deserializer);
+
+@protected FailoverOptions sse_decode_failover_options(SseDeserializer deserializer);
+
+@protected FailoverReason sse_decode_failover_reason(SseDeserializer deserializer);
+
+@protected FileStorageOptions sse_decode_file_storage_options(SseDeserializer deserializer);
+
+@protected FunctionFault sse_decode_function_fault(SseDeserializer deserializer);
+
+@protected FunctionMetrics sse_decode_function_metrics(SseDeserializer deserializer);
+
+@protected HttpActionResponse sse_decode_http_action_response(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected IdempotentMutationResult sse_decode_idempotent_mutation_result(SseDeserializer deserializer);
+
+@protected InitializationDiagnostics sse_decode_initialization_diagnostics(SseDeserializer deserializer);
+
+@protected Int64Encoding sse_decode_int_64_encoding(SseDeserializer deserializer);
+
+@protected InterceptDecision sse_decode_intercept_decision(SseDeserializer deserializer);
+
+@protected InterceptedCall sse_decode_intercepted_call(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
+@protected JobOptions sse_decode_job_options(SseDeserializer deserializer);
+
+@protected JobProgress sse_decode_job_progress(SseDeserializer deserializer);
+
+@protected LatencyHistogram sse_decode_latency_histogram(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<BatchQuery> sse_decode_list_batch_query(SseDeserializer deserializer);
+
+@protected ListChange sse_decode_list_change(SseDeserializer deserializer);
+
+@protected List<ClassSampleRate> sse_decode_list_class_sample_rate(SseDeserializer deserializer);
+
+@protected List<ConvexValue> sse_decode_list_convex_value(SseDeserializer deserializer);
+
+@protected List<DerivedField> sse_decode_list_derived_field(SseDeserializer deserializer);
+
+@protected List<DerivedSource> sse_decode_list_derived_source(SseDeserializer deserializer);
+
+@protected List<FunctionMetrics> sse_decode_list_function_metrics(SseDeserializer deserializer);
+
+@protected List<ListChange> sse_decode_list_list_change(SseDeserializer deserializer);
+
+@protected List<MutationCall> sse_decode_list_mutation_call(SseDeserializer deserializer);
+
+@protected List<MutationStepStatus> sse_decode_list_mutation_step_status(SseDeserializer deserializer);
+
+@protected List<OptimisticUpdate> sse_decode_list_optimistic_update(SseDeserializer deserializer);
+
+@protected List<PresenceStatus> sse_decode_list_presence_status(SseDeserializer deserializer);
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<QueryDependency> sse_decode_list_query_dependency(SseDeserializer deserializer);
+
+@protected List<(String,ConvexValue)> sse_decode_list_record_string_convex_value(SseDeserializer deserializer);
+
+@protected List<(String,double)> sse_decode_list_record_string_f_64(SseDeserializer deserializer);
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer);
+
+@protected List<SubscriptionInfo> sse_decode_list_subscription_info(SseDeserializer deserializer);
+
+@protected List<TelemetryClass> sse_decode_list_telemetry_class(SseDeserializer deserializer);
+
+@protected List<TypeCodec> sse_decode_list_type_codec(SseDeserializer deserializer);
+
+@protected ListUpdate sse_decode_list_update(SseDeserializer deserializer);
+
+@protected LogLevel sse_decode_log_level(SseDeserializer deserializer);
+
+@protected LogRecord sse_decode_log_record(SseDeserializer deserializer);
+
+@protected LogoutEvent sse_decode_logout_event(SseDeserializer deserializer);
+
+@protected MemoryPressure sse_decode_memory_pressure(SseDeserializer deserializer);
+
+@protected MemoryReleasedEvent sse_decode_memory_released_event(SseDeserializer deserializer);
+
+@protected MetricsReport sse_decode_metrics_report(SseDeserializer deserializer);
+
+@protected MutationCall sse_decode_mutation_call(SseDeserializer deserializer);
+
+@protected MutationLifecycleEvent sse_decode_mutation_lifecycle_event(SseDeserializer deserializer);
+
+@protected MutationRetryOptions sse_decode_mutation_retry_options(SseDeserializer deserializer);
+
+@protected MutationSequenceResult sse_decode_mutation_sequence_result(SseDeserializer deserializer);
+
+@protected MutationStatus sse_decode_mutation_status(SseDeserializer deserializer);
+
+@protected MutationStepStatus sse_decode_mutation_step_status(SseDeserializer deserializer);
+
+@protected NullHandling sse_decode_null_handling(SseDeserializer deserializer);
+
+@protected OfflineMutationResult sse_decode_offline_mutation_result(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected AuditLogOptions? sse_decode_opt_box_autoadd_audit_log_options(SseDeserializer deserializer);
+
+@protected Backpressure? sse_decode_opt_box_autoadd_backpressure(SseDeserializer deserializer);
+
+@protected FailoverOptions? sse_decode_opt_box_autoadd_failover_options(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected PersistedResultOptions? sse_decode_opt_box_autoadd_persisted_result_options(SseDeserializer deserializer);
+
+@protected SchemaCheckOptions? sse_decode_opt_box_autoadd_schema_check_options(SseDeserializer deserializer);
+
+@protected SubscribeOptions? sse_decode_opt_box_autoadd_subscribe_options(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected UiHint? sse_decode_opt_box_autoadd_ui_hint(SseDeserializer deserializer);
+
+@protected UsageBudget? sse_decode_opt_box_autoadd_usage_budget(SseDeserializer deserializer);
+
+@protected List<String>? sse_decode_opt_list_String(SseDeserializer deserializer);
+
+@protected Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected OptimisticUpdate sse_decode_optimistic_update(SseDeserializer deserializer);
+
+@protected OutboxCompletion sse_decode_outbox_completion(SseDeserializer deserializer);
+
+@protected OutboxConflict sse_decode_outbox_conflict(SseDeserializer deserializer);
+
+@protected PaginatedUpdate sse_decode_paginated_update(SseDeserializer deserializer);
+
+@protected PaginationStatus sse_decode_pagination_status(SseDeserializer deserializer);
+
+@protected PanicReport sse_decode_panic_report(SseDeserializer deserializer);
+
+@protected PatchUpdate sse_decode_patch_update(SseDeserializer deserializer);
+
+@protected PersistedResultOptions sse_decode_persisted_result_options(SseDeserializer deserializer);
+
+@protected PlaceholderPolicy sse_decode_placeholder_policy(SseDeserializer deserializer);
+
+@protected PresenceOptions sse_decode_presence_options(SseDeserializer deserializer);
+
+@protected PresenceStatus sse_decode_presence_status(SseDeserializer deserializer);
+
+@protected PressureThrottle sse_decode_pressure_throttle(SseDeserializer deserializer);
+
+@protected PreviewOptions sse_decode_preview_options(SseDeserializer deserializer);
+
+@protected QueryDependency sse_decode_query_dependency(SseDeserializer deserializer);
+
+@protected (String,ConvexValue) sse_decode_record_string_convex_value(SseDeserializer deserializer);
+
+@protected (String,double) sse_decode_record_string_f_64(SseDeserializer deserializer);
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer);
+
+@protected RequestIdOptions sse_decode_request_id_options(SseDeserializer deserializer);
+
+@protected ResultCacheOptions sse_decode_result_cache_options(SseDeserializer deserializer);
+
+@protected ResultChunk sse_decode_result_chunk(SseDeserializer deserializer);
+
+@protected RuntimeMetrics sse_decode_runtime_metrics(SseDeserializer deserializer);
+
+@protected SchemaCheckOptions sse_decode_schema_check_options(SseDeserializer deserializer);
+
+@protected ShardMapping sse_decode_shard_mapping(SseDeserializer deserializer);
+
+@protected ShardingMetrics sse_decode_sharding_metrics(SseDeserializer deserializer);
+
+@protected SlowInitializationEvent sse_decode_slow_initialization_event(SseDeserializer deserializer);
+
+@protected SubscribeOptions sse_decode_subscribe_options(SseDeserializer deserializer);
+
+@protected SubscriptionCloseReason sse_decode_subscription_close_reason(SseDeserializer deserializer);
+
+@protected SubscriptionEvent sse_decode_subscription_event(SseDeserializer deserializer);
+
+@protected SubscriptionInfo sse_decode_subscription_info(SseDeserializer deserializer);
+
+@protected SubscriptionPriority sse_decode_subscription_priority(SseDeserializer deserializer);
+
+@protected SubscriptionState sse_decode_subscription_state(SseDeserializer deserializer);
+
+@protected TelemetryClass sse_decode_telemetry_class(SseDeserializer deserializer);
+
+@protected TelemetrySampling sse_decode_telemetry_sampling(SseDeserializer deserializer);
+
+@protected TraceExportOptions sse_decode_trace_export_options(SseDeserializer deserializer);
+
+@protected TransferProgress sse_decode_transfer_progress(SseDeserializer deserializer);
+
+@protected TypeCodec sse_decode_type_codec(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected UiHint sse_decode_ui_hint(SseDeserializer deserializer);
+
+@protected UiHintCode sse_decode_ui_hint_code(SseDeserializer deserializer);
+
+@protected UiPressure sse_decode_ui_pressure(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected UploadSource sse_decode_upload_source(SseDeserializer deserializer);
+
+@protected UsageBudget sse_decode_usage_budget(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected WatchEvent sse_decode_watch_event(SseDeserializer deserializer);
+
+@protected WebSocketConnectionState sse_decode_web_socket_connection_state(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(AuthHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(CallbackSubscriber self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(CallbackSubscriberDartFn self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(CancellationToken self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(MobileConvexClient self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(PaginatedQueryHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(PresenceHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SubscriptionHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(AuthHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(CallbackSubscriber self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(CallbackSubscriberDartFn self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(CancellationToken self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(MobileConvexClient self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(PaginatedQueryHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(PresenceHandle self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SubscriptionHandle self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(FutureOr<void> Function(String) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_String_opt_String_Output_unit_AnyhowException(FutureOr<void> Function(String, String?) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs__Output_opt_String_AnyhowException(FutureOr<String?> Function() self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs__Output_unit_AnyhowException(FutureOr<void> Function() self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_auth_change_Output_unit_AnyhowException(FutureOr<void> Function(AuthChange) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_backend_changed_event_Output_unit_AnyhowException(FutureOr<void> Function(BackendChangedEvent) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_bool_Output_unit_AnyhowException(FutureOr<void> Function(bool) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_budget_exceeded_event_Output_unit_AnyhowException(FutureOr<void> Function(BudgetExceededEvent) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_client_event_Output_unit_AnyhowException(FutureOr<void> Function(ClientEvent) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_connection_quality_Output_unit_AnyhowException(FutureOr<void> Function(ConnectionQuality) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_convex_value_Output_unit_AnyhowException(FutureOr<void> Function(ConvexValue) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_failover_event_Output_unit_AnyhowException(FutureOr<void> Function(FailoverEvent) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_intercepted_call_Output_intercept_decision_AnyhowException(FutureOr<InterceptDecision> Function(InterceptedCall) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_intercepted_call_call_outcome_Output_call_outcome_AnyhowException(FutureOr<CallOutcome> Function(InterceptedCall, CallOutcome) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_job_progress_Output_unit_AnyhowException(FutureOr<void> Function(JobProgress) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_list_presence_status_Output_unit_AnyhowException(FutureOr<void> Function(List<PresenceStatus>) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_list_update_Output_unit_AnyhowException(FutureOr<void> Function(ListUpdate) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_log_record_Output_unit_AnyhowException(FutureOr<void> Function(LogRecord) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_logout_event_Output_unit_AnyhowException(FutureOr<void> Function(LogoutEvent) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_memory_released_event_Output_unit_AnyhowException(FutureOr<void> Function(MemoryReleasedEvent) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_mutation_lifecycle_event_Output_unit_AnyhowException(FutureOr<void> Function(MutationLifecycleEvent) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_opt_box_autoadd_ui_hint_Output_unit_AnyhowException(FutureOr<void> Function(UiHint?) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_outbox_completion_Output_unit_AnyhowException(FutureOr<void> Function(OutboxCompletion) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_outbox_conflict_Output_conflict_resolution_AnyhowException(FutureOr<ConflictResolution> Function(OutboxConflict) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_paginated_update_Output_unit_AnyhowException(FutureOr<void> Function(PaginatedUpdate) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_panic_report_Output_unit_AnyhowException(FutureOr<void> Function(PanicReport) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_patch_update_Output_unit_AnyhowException(FutureOr<void> Function(PatchUpdate) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_result_chunk_Output_unit_AnyhowException(FutureOr<void> Function(ResultChunk) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_slow_initialization_event_Output_unit_AnyhowException(FutureOr<void> Function(SlowInitializationEvent) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_subscription_event_Output_unit_AnyhowException(FutureOr<void> Function(SubscriptionEvent) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_transfer_progress_Output_unit_AnyhowException(FutureOr<void> Function(TransferProgress) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_watch_event_Output_unit_AnyhowException(FutureOr<void> Function(WatchEvent) self, SseSerializer serializer);
+
+@protected void sse_encode_DartFn_Inputs_web_socket_connection_state_Output_unit_AnyhowException(FutureOr<void> Function(WebSocketConnectionState) self, SseSerializer serializer);
+
+@protected void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_String_None(Map<String, String> self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_convex_value_None(Map<String, ConvexValue> self, SseSerializer serializer);
+
+@protected void sse_encode_Map_String_f_64_None(Map<String, double> self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(AuthHandle self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(CallbackSubscriber self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(CallbackSubscriberDartFn self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(CancellationToken self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(MobileConvexClient self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(PaginatedQueryHandle self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(PresenceHandle self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(SubscriptionHandle self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_String_Sse(RustStreamSink<String> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_audit_log_options(AuditLogOptions self, SseSerializer serializer);
+
+@protected void sse_encode_auth_change(AuthChange self, SseSerializer serializer);
+
+@protected void sse_encode_auth_change_reason(AuthChangeReason self, SseSerializer serializer);
+
+@protected void sse_encode_auth_refresh_config(AuthRefreshConfig self, SseSerializer serializer);
+
+@protected void sse_encode_backend_changed_event(BackendChangedEvent self, SseSerializer serializer);
+
+@protected void sse_encode_backpressure(Backpressure self, SseSerializer serializer);
+
+@protected void sse_encode_batch_query(BatchQuery self, SseSerializer serializer);
+
+@protected void sse_encode_blank_arg_handling(BlankArgHandling self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_audit_log_options(AuditLogOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_auth_refresh_config(AuthRefreshConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_backpressure(Backpressure self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_client_error(ClientError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_client_options(ClientOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_combine_strategy(CombineStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_convex_value(ConvexValue self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_dart_keepalive_options(DartKeepaliveOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_failover_options(FailoverOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_function_fault(FunctionFault self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_intercepted_call(InterceptedCall self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_job_options(JobOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_persisted_result_options(PersistedResultOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_presence_options(PresenceOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_result_cache_options(ResultCacheOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_schema_check_options(SchemaCheckOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_shard_mapping(ShardMapping self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_subscribe_options(SubscribeOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_telemetry_sampling(TelemetrySampling self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_trace_export_options(TraceExportOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_ui_hint(UiHint self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_upload_source(UploadSource self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_usage_budget(UsageBudget self, SseSerializer serializer);
+
+@protected void sse_encode_budget_enforcement(BudgetEnforcement self, SseSerializer serializer);
+
+@protected void sse_encode_budget_exceeded_event(BudgetExceededEvent self, SseSerializer serializer);
+
+@protected void sse_encode_budget_limit(BudgetLimit self, SseSerializer serializer);
+
+@protected void sse_encode_call_kind(CallKind self, SseSerializer serializer);
+
+@protected void sse_encode_call_outcome(CallOutcome self, SseSerializer serializer);
+
+@protected void sse_encode_chunked_result_options(ChunkedResultOptions self, SseSerializer serializer);
+
+@protected void sse_encode_class_sample_rate(ClassSampleRate self, SseSerializer serializer);
+
+@protected void sse_encode_client_error(ClientError self, SseSerializer serializer);
+
+@protected void sse_encode_client_event(ClientEvent self, SseSerializer serializer);
+
+@protected void sse_encode_client_metrics(ClientMetrics self, SseSerializer serializer);
+
+@protected void sse_encode_client_options(ClientOptions self, SseSerializer serializer);
+
+@protected void sse_encode_codec_wire(CodecWire self, SseSerializer serializer);
+
+@protected void sse_encode_combine_strategy(CombineStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_committed_mutation(CommittedMutation self, SseSerializer serializer);
+
+@protected void sse_encode_conflict_resolution(ConflictResolution self, SseSerializer serializer);
+
+@protected void sse_encode_connect_retry_options(ConnectRetryOptions self, SseSerializer serializer);
+
+@protected void sse_encode_connection_close_reason(ConnectionCloseReason self, SseSerializer serializer);
+
+@protected void sse_encode_connection_quality(ConnectionQuality self, SseSerializer serializer);
+
+@protected void sse_encode_connection_quality_level(ConnectionQualityLevel self, SseSerializer serializer);
+
+@protected void sse_encode_convex_value(ConvexValue self, SseSerializer serializer);
+
+@protected void sse_encode_dart_keepalive_options(DartKeepaliveOptions self, SseSerializer serializer);
+
+@protected void sse_encode_dart_liveness(DartLiveness self, SseSerializer serializer);
+
+@protected void sse_encode_dart_liveness_state(DartLivenessState self, SseSerializer serializer);
+
+@protected void sse_encode_deferred_mutation_options(DeferredMutationOptions self, SseSerializer serializer);
+
+@protected void sse_encode_derived_field(DerivedField self, SseSerializer serializer);
+
+@protected void sse_encode_derived_source(DerivedSource self, SseSerializer serializer);
+
+@protected void sse_encode_derived_value(DerivedValue self, SseSerializer serializer);
+
+@protected void sse_encode_error_code(ErrorCode self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_failover_event(FailoverEvent self, SseSerializer serializer);
+
+@protected void sse_encode_failover_options(FailoverOptions self, SseSerializer serializer);
+
+@protected void sse_encode_failover_reason(FailoverReason self, SseSerializer serializer);
+
+@protected void sse_encode_file_storage_options(FileStorageOptions self, SseSerializer serializer);
+
+@protected void sse_encode_function_fault(FunctionFault self, SseSerializer serializer);
+
+@protected void sse_encode_function_metrics(FunctionMetrics self, SseSerializer serializer);
+
+@protected void sse_encode_http_action_response(HttpActionResponse self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_idempotent_mutation_result(IdempotentMutationResult self, SseSerializer serializer);
+
+@protected void sse_encode_initialization_diagnostics(InitializationDiagnostics self, SseSerializer serializer);
+
+@protected void sse_encode_int_64_encoding(Int64Encoding self, SseSerializer serializer);
+
+@protected void sse_encode_intercept_decision(InterceptDecision self, SseSerializer serializer);
+
+@protected void sse_encode_intercepted_call(InterceptedCall self, SseSerializer serializer);
+
+@protected void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_job_options(JobOptions self, SseSerializer serializer);
+
+@protected void sse_encode_job_progress(JobProgress self, SseSerializer serializer);
+
+@protected void sse_encode_latency_histogram(LatencyHistogram self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_batch_query(List<BatchQuery> self, SseSerializer serializer);
+
+@protected void sse_encode_list_change(ListChange self, SseSerializer serializer);
+
+@protected void sse_encode_list_class_sample_rate(List<ClassSampleRate> self, SseSerializer serializer);
+
+@protected void sse_encode_list_convex_value(List<ConvexValue> self, SseSerializer serializer);
+
+@protected void sse_encode_list_derived_field(List<DerivedField> self, SseSerializer serializer);
+
+@protected void sse_encode_list_derived_source(List<DerivedSource> self, SseSerializer serializer);
+
+@protected void sse_encode_list_function_metrics(List<FunctionMetrics> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_change(List<ListChange> self, SseSerializer serializer);
+
+@protected void sse_encode_list_mutation_call(List<MutationCall> self, SseSerializer serializer);
+
+@protected void sse_encode_list_mutation_step_status(List<MutationStepStatus> self, SseSerializer serializer);
+
+@protected void sse_encode_list_optimistic_update(List<OptimisticUpdate> self, SseSerializer serializer);
+
+@protected void sse_encode_list_presence_status(List<PresenceStatus> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_query_dependency(List<QueryDependency> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_convex_value(List<(String,ConvexValue)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_f_64(List<(String,double)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer);
+
+@protected void sse_encode_list_subscription_info(List<SubscriptionInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_telemetry_class(List<TelemetryClass> self, SseSerializer serializer);
+
+@protected void sse_encode_list_type_codec(List<TypeCodec> self, SseSerializer serializer);
+
+@protected void sse_encode_list_update(ListUpdate self, SseSerializer serializer);
+
+@protected void sse_encode_log_level(LogLevel self, SseSerializer serializer);
+
+@protected void sse_encode_log_record(LogRecord self, SseSerializer serializer);
+
+@protected void sse_encode_logout_event(LogoutEvent self, SseSerializer serializer);
+
+@protected void sse_encode_memory_pressure(MemoryPressure self, SseSerializer serializer);
+
+@protected void sse_encode_memory_released_event(MemoryReleasedEvent self, SseSerializer serializer);
+
+@protected void sse_encode_metrics_report(MetricsReport self, SseSerializer serializer);
+
+@protected void sse_encode_mutation_call(MutationCall self, SseSerializer serializer);
+
+@protected void sse_encode_mutation_lifecycle_event(MutationLifecycleEvent self, SseSerializer serializer);
+
+@protected void sse_encode_mutation_retry_options(MutationRetryOptions self, SseSerializer serializer);
+
+@protected void sse_encode_mutation_sequence_result(MutationSequenceResult self, SseSerializer serializer);
+
+@protected void sse_encode_mutation_status(MutationStatus self, SseSerializer serializer);
+
+@protected void sse_encode_mutation_step_status(MutationStepStatus self, SseSerializer serializer);
+
+@protected void sse_encode_null_handling(NullHandling self, SseSerializer serializer);
+
+@protected void sse_encode_offline_mutation_result(OfflineMutationResult self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_audit_log_options(AuditLogOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_backpressure(Backpressure? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_failover_options(FailoverOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_persisted_result_options(PersistedResultOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_schema_check_options(SchemaCheckOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_subscribe_options(SubscribeOptions? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_ui_hint(UiHint? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_usage_budget(UsageBudget? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_String(List<String>? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_list_prim_u_8_strict(Uint8List? self, SseSerializer serializer);
+
+@protected void sse_encode_optimistic_update(OptimisticUpdate self, SseSerializer serializer);
+
+@protected void sse_encode_outbox_completion(OutboxCompletion self, SseSerializer serializer);
+
+@protected void sse_encode_outbox_conflict(OutboxConflict self, SseSerializer serializer);
+
+@protected void sse_encode_paginated_update(PaginatedUpdate self, SseSerializer serializer);
+
+@protected void sse_encode_pagination_status(PaginationStatus self, SseSerializer serializer);
+
+@protected void sse_encode_panic_report(PanicReport self, SseSerializer serializer);
+
+@protected void sse_encode_patch_update(PatchUpdate self, SseSerializer serializer);
+
+@protected void sse_encode_persisted_result_options(PersistedResultOptions self, SseSerializer serializer);
+
+@protected void sse_encode_placeholder_policy(PlaceholderPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_presence_options(PresenceOptions self, SseSerializer serializer);
+
+@protected void sse_encode_presence_status(PresenceStatus self, SseSerializer serializer);
+
+@protected void sse_encode_pressure_throttle(PressureThrottle self, SseSerializer serializer);
+
+@protected void sse_encode_preview_options(PreviewOptions self, SseSerializer serializer);
+
+@protected void sse_encode_query_dependency(QueryDependency self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_convex_value((String,ConvexValue) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_f_64((String,double) self, SseSerializer serializer);
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer);
+
+@protected void sse_encode_request_id_options(RequestIdOptions self, SseSerializer serializer);
+
+@protected void sse_encode_result_cache_options(ResultCacheOptions self, SseSerializer serializer);
+
+@protected void sse_encode_result_chunk(ResultChunk self, SseSerializer serializer);
+
+@protected void sse_encode_runtime_metrics(RuntimeMetrics self, SseSerializer serializer);
+
+@protected void sse_encode_schema_check_options(SchemaCheckOptions self, SseSerializer serializer);
+
+@protected void sse_encode_shard_mapping(ShardMapping self, SseSerializer serializer);
+
+@protected void sse_encode_sharding_metrics(ShardingMetrics self, SseSerializer serializer);
+
+@protected void sse_encode_slow_initialization_event(SlowInitializationEvent self, SseSerializer serializer);
+
+@protected void sse_encode_subscribe_options(SubscribeOptions self, SseSerializer serializer);
+
+@protected void sse_encode_subscription_close_reason(SubscriptionCloseReason self, SseSerializer serializer);
+
+@protected void sse_encode_subscription_event(SubscriptionEvent self, SseSerializer serializer);
+
+@protected void sse_encode_subscription_info(SubscriptionInfo self, SseSerializer serializer);
+
+@protected void sse_encode_subscription_priority(SubscriptionPriority self, SseSerializer serializer);
+
+@protected void sse_encode_subscription_state(SubscriptionState self, SseSerializer serializer);
+
+@protected void sse_encode_telemetry_class(TelemetryClass self, SseSerializer serializer);
+
+@protected void sse_encode_telemetry_sampling(TelemetrySampling self, SseSerializer serializer);
+
+@protected void sse_encode_trace_export_options(TraceExportOptions self, SseSerializer serializer);
+
+@protected void sse_encode_transfer_progress(TransferProgress self, SseSerializer serializer);
+
+@protected void sse_encode_type_codec(TypeCodec self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_ui_hint(UiHint self, SseSerializer serializer);
+
+@protected void sse_encode_ui_hint_code(UiHintCode self, SseSerializer serializer);
+
+@protected void sse_encode_ui_pressure(UiPressure self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_upload_source(UploadSource self, SseSerializer serializer);
+
+@protected void sse_encode_usage_budget(UsageBudget self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_watch_event(WatchEvent self, SseSerializer serializer);
+
+@protected void sse_encode_web_socket_connection_state(WebSocketConnectionState self, SseSerializer serializer);
+                }
+                
+
+
+// Section: wire_class
+
+class RustLibWire implements BaseWire {
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(ptr);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
+
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAuthHandle(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriber(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCallbackSubscriberDartFn(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCancellationToken(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerMobileConvexClient(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPaginatedQueryHandle(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPresenceHandle(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSubscriptionHandle(int ptr);
+        }
+        
\ No newline at end of file
@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `hint`, `mutation_started`, `new`, `record_state`, `set_signing_in`, `track_connection`, `update`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `HintInputs`, `PendingMutation`, `UiHints`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `drop`, `eq`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            /// A status hint: a code plus parameters for the localized message.
+class UiHint  {
+                final UiHintCode code;
+final Map<String, String> params;
+
+                const UiHint({required this.code ,required this.params ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => code.hashCode^params.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UiHint &&
+                runtimeType == other.runtimeType
+                && code == other.code&& params == other.params;
+        
+            }
+
+/// What a [`UiHint`] is about; stable across releases for use as a
+/// localization key.
+enum UiHintCode {
+                    /// Establishing the first connection.
+connecting,
+/// The connection was lost. Parameter `offlineSinceMs`: Unix time in
+/// milliseconds when it was lost.
+reconnecting,
+/// Waiting for the first auth token.
+signingIn,
+/// Mutations are waiting for the server. Parameter `count`: how many.
+syncingChanges,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `merge_headers`, `site_url`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            
+
+            /// The response of an HTTP action.
+class HttpActionResponse  {
+                final int status;
+/// Response headers by lowercase name; repeated headers are joined with
+/// `, `.
+final Map<String, String> headers;
+final Uint8List body;
+
+                const HttpActionResponse({required this.status ,required this.headers ,required this.body ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => status.hashCode^headers.hashCode^body.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HttpActionResponse &&
+                runtimeType == other.runtimeType
+                && status == other.status&& headers == other.headers&& body == other.body;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,91 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'interceptors.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `add`, `into_result`, `of`, `parse_args`, `parse_json`, `remove`, `run`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DartInterceptor`, `Interceptors`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `add_rust_interceptor`, `intercept_call`, `intercept_result`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `intercept_call`, `intercept_result`
+
+
+            
+
+            /// The kind of an intercepted call.
+enum CallKind {
+                    query,
+mutation,
+action,
+                    ;
+                    
+                }
+
+@freezed
+                sealed class CallOutcome with _$CallOutcome  {
+                    const CallOutcome._();
+
+                     /// The function returned `json`, in Convex's JSON encoding.
+const factory CallOutcome.value({   required String json , }) = CallOutcome_Value;
+ /// The function threw a `ConvexError` carrying `data`.
+const factory CallOutcome.convexError({   required String message ,  required String data , }) = CallOutcome_ConvexError;
+ /// The function failed unexpectedly.
+const factory CallOutcome.serverError({   required String message , }) = CallOutcome_ServerError;
+ /// The call did not reach the backend or its result was lost.
+const factory CallOutcome.transportError({   required String message , }) = CallOutcome_TransportError;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class InterceptDecision with _$InterceptDecision  {
+                    const InterceptDecision._();
+
+                     /// Sends `call`, possibly changed, on to the next interceptor.
+const factory InterceptDecision.proceed({   required InterceptedCall call , }) = InterceptDecision_Proceed;
+ /// Fails the call with `reason` without sending it.
+const factory InterceptDecision.veto({   required String reason , }) = InterceptDecision_Veto;
+
+                    
+
+                    
+                }
+
+/// A call about to be sent.
+class InterceptedCall  {
+                /// Informational; changing it has no effect.
+final CallKind kind;
+/// Name of the function, e.g. `messages:send`.
+final String name;
+/// Arguments as a JSON object, in Convex's JSON encoding.
+final String args;
+/// Attached by interceptors for the result interceptors.
+final Map<String, String> metadata;
+
+                const InterceptedCall({required this.kind ,required this.name ,required this.args ,required this.metadata ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^name.hashCode^args.hashCode^metadata.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is InterceptedCall &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& name == other.name&& args == other.args&& metadata == other.metadata;
+        
+            }
+            
\ No newline at end of file
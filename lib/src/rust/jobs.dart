@@ -0,0 +1,68 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `with_job_id`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// How an action and its status query receive the job id.
+class JobOptions  {
+                /// Argument holding the job id, passed to the action and the status query.
+final String jobIdArg;
+/// Query returning the status document of a job.
+final String statusQuery;
+
+                const JobOptions({required this.jobIdArg ,required this.statusQuery ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => jobIdArg.hashCode^statusQuery.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JobOptions &&
+                runtimeType == other.runtimeType
+                && jobIdArg == other.jobIdArg&& statusQuery == other.statusQuery;
+        
+            }
+
+/// A status update of a running job.
+class JobProgress  {
+                final String jobId;
+/// JSON-serialized status document.
+final String status;
+
+                const JobProgress({required this.jobId ,required this.status ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => jobId.hashCode^status.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is JobProgress &&
+                runtimeType == other.runtimeType
+                && jobId == other.jobId&& status == other.status;
+        
+            }
+            
\ No newline at end of file
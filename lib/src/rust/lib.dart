@@ -3,160 +3,991 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import 'audit.dart';
+import 'auth_changes.dart';
+import 'auth_refresh.dart';
+import 'backpressure.dart';
+import 'batch_query.dart';
+import 'budget.dart';
+import 'call_metrics.dart';
+import 'cancellation.dart';
+import 'chunked.dart';
+import 'codecs.dart';
+import 'commit_token.dart';
+import 'connection.dart';
+import 'convex_value.dart';
+import 'deferred.dart';
+import 'derived.dart';
+import 'errors.dart';
+import 'events.dart';
+import 'failover.dart';
+import 'faults.dart';
+import 'file_storage.dart';
 import 'frb_generated.dart';
+import 'hints.dart';
+import 'http_actions.dart';
+import 'interceptors.dart';
+import 'jobs.dart';
+import 'list_diff.dart';
+import 'logging.dart';
+import 'logout.dart';
+import 'memory.dart';
+import 'metrics.dart';
+import 'mutation_status.dart';
+import 'optimistic.dart';
+import 'options.dart';
+import 'outbox.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'pagination.dart';
+import 'panics.dart';
+import 'patches.dart';
+import 'persisted_results.dart';
+import 'placeholder.dart';
+import 'presence.dart';
+import 'pressure.dart';
+import 'preview.dart';
+import 'quality.dart';
+import 'query_cache.dart';
+import 'registry.dart';
+import 'request_ids.dart';
+import 'resubscribe.dart';
+import 'retry.dart';
+import 'sampling.dart';
+import 'schema_check.dart';
+import 'sequence.dart';
+import 'sharding.dart';
+import 'spans.dart';
+import 'subscription.dart';
+import 'supervisor.dart';
 part 'lib.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `connected_client`, `decode_jwt_expiry`, `handle_direct_function_result`, `internal_action`, `internal_mutation`, `internal_set_auth`, `internal_subscribe`, `new`, `new`, `parse_json_args`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `JwtClaims`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`, `fmt`, `from`, `from`
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AuthHandle>>
-abstract class AuthHandle implements RustOpaqueInterface {
-  /// Disposes the auth session, stopping the token refresh loop and clearing authentication.
-  @override
-  void dispose();
-
-  /// Returns whether the user is currently authenticated.
-  bool isAuthenticated();
-}
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackSubscriber>>
-abstract class CallbackSubscriber
-    implements RustOpaqueInterface, QuerySubscriber {
-  @override
-  Future<void> onError({required String message, String? value});
-
-  @override
-  Future<void> onUpdate({required String value});
-}
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackSubscriberDartFn>>
-abstract class CallbackSubscriberDartFn
-    implements RustOpaqueInterface, QuerySubscriber {
-  @override
-  Future<void> onError({required String message, String? value});
-
-  @override
-  Future<void> onUpdate({required String value});
-}
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MobileConvexClient>>
-abstract class MobileConvexClient implements RustOpaqueInterface {
-  /// Executes an action on the Convex backend.
-  Future<String> action({
-    required String name,
-    required Map<String, String> args,
-  });
-
-  /// Executes a mutation on the Convex backend.
-  Future<String> mutation({
-    required String name,
-    required Map<String, String> args,
-  });
-
-  /// Creates a new MobileConvexClient instance with the given deployment URL and client ID.
-  factory MobileConvexClient({
-    required String deploymentUrl,
-    required String clientId,
-  }) => RustLib.instance.api.crateMobileConvexClientNew(
-    deploymentUrl: deploymentUrl,
-    clientId: clientId,
-  );
-
-  /// Sets up WebSocket connection state change listener.
-  ///
-  /// Must be called BEFORE any queries/mutations to capture all state changes.
-  /// The callback will be invoked whenever the WebSocket transitions between
-  /// Connected and Connecting states.
-  ///
-  /// # Arguments
-  ///
-  /// * `on_state_change` - Async callback invoked when connection state changes
-  ///
-  /// # Example
-  ///
-  /// ```dart
-  /// await client.onWebsocketStateChange(
-  ///   onStateChange: (state) async {
-  ///     print('Connection state: ${state.name}');
-  ///   },
-  /// );
-  /// ```
-  Future<void> onWebsocketStateChange({
-    required FutureOr<void> Function(WebSocketConnectionState) onStateChange,
-  });
-
-  /// Executes a query on the Convex backend.
-  Future<String> query({
-    required String name,
-    required Map<String, String> args,
-  });
-
-  /// Sets authentication token for the client.
-  Future<void> setAuth({String? token});
-
-  /// Sets authentication with automatic token refresh.
-  ///
-  /// The `fetch_token` callback is called:
-  /// - Immediately to get the initial token
-  /// - Automatically when the token is about to expire (60 seconds before expiry)
-  ///
-  /// The `on_auth_change` callback is called whenever auth state changes.
-  ///
-  /// Returns an AuthHandle that can be used to dispose the auth session.
-  Future<AuthHandle> setAuthWithRefresh({
-    required FutureOr<String?> Function() fetchToken,
-    required FutureOr<void> Function(bool) onAuthChange,
-  });
-
-  /// Subscribes to real-time updates from a Convex query.
-  Future<SubscriptionHandle> subscribe({
-    required String name,
-    required Map<String, String> args,
-    required FutureOr<void> Function(String) onUpdate,
-    required FutureOr<void> Function(String, String?) onError,
-  });
-}
-
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SubscriptionHandle>>
-abstract class SubscriptionHandle implements RustOpaqueInterface {
-  /// Cancels the subscription by sending a cancellation signal.
-  void cancel();
-}
-
-abstract class QuerySubscriber {
-  Future<void> onError({required String message, String? value});
-
-  Future<void> onUpdate({required String value});
-}
+            // These functions are ignored because they are not marked as `pub`: `begin_audit`, `build`, `connected_client`, `convert_args`, `deliver_to_subscriber`, `diagnose_auth`, `downgrade`, `enabled_audit_log`, `ensure_auth_enabled`, `ensure_open`, `establish_subscription`, `establish_upstream_subscription`, `fetch_unless_cancelled`, `internal_action`, `internal_mutation`, `internal_query_via`, `internal_query`, `internal_set_auth`, `internal_subscribe`, `new`, `new`, `parse_args`, `record_call`, `rejection_backoff`, `resolve`, `retire`, `send_action`, `send_mutation`, `send_query`, `share`, `start_failover`, `stop`, `subscribe_unguarded`, `subscription_args`, `unordered_mutation`, `upgrade`, `with_priority`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ClientFactory`, `ClientInner`, `FirstResultSubscriber`, `WeakClient`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `deref`, `drop`, `drop`, `drop`, `eq`, `fmt`, `fmt`, `fmt`, `from`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `on_done`, `on_done`, `on_done`, `on_done`, `on_done`, `on_done`, `on_done`, `on_error`, `on_update`
 
-@freezed
-sealed class ClientError with _$ClientError implements FrbException {
-  const ClientError._();
 
-  /// An internal error within the mobile Convex client.
-  const factory ClientError.internalError({required String msg}) =
-      ClientError_InternalError;
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AuthHandle>>
+                abstract class AuthHandle implements RustOpaqueInterface {
+                    /// Disposes the auth session, stopping the token refresh loop and clearing authentication.
+ void  dispose();
+
+
+/// Returns whether the token refresh loop is still running.
+ bool  isActive();
+
+
+/// Returns whether the user is currently authenticated.
+ bool  isAuthenticated();
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackSubscriber>>
+                abstract class CallbackSubscriber implements RustOpaqueInterface, QuerySubscriber {
+                     Future<void>  onDone();
+
+
+ Future<void>  onError({required String message , String? value });
+
+
+ Future<void>  onUpdate({required String value });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CallbackSubscriberDartFn>>
+                abstract class CallbackSubscriberDartFn implements RustOpaqueInterface, QuerySubscriber {
+                     Future<void>  onDone();
+
 
-  /// An application-specific error from a remote Convex backend function.
-  const factory ClientError.convexError({required String data}) =
-      ClientError_ConvexError;
+ Future<void>  onError({required String message , String? value });
 
-  /// An unexpected server-side error from a remote Convex function.
-  const factory ClientError.serverError({required String msg}) =
-      ClientError_ServerError;
-}
 
-/// WebSocket connection state exposed to Flutter/Dart.
+ Future<void>  onUpdate({required String value });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<MobileConvexClient>>
+                abstract class MobileConvexClient implements RustOpaqueInterface {
+                    /// Executes an action on the Convex backend. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  action({required String name , required Map<String, String> args , BigInt? timeoutMs });
+
+
+/// Like [`MobileConvexClient::action`], but abandoned once `token` is
+/// cancelled.
+ Future<String>  actionCancellable({required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs });
+
+
+/// Executes an action with structured arguments and result.
+ Future<ConvexValue>  actionTyped({required String name , required Map<String, ConvexValue> args });
+
+
+/// Runs an action while reporting the status document of its job through
+/// `on_progress`, and returns the action's result.
 ///
-/// This enum represents the current state of the WebSocket connection
-/// to the Convex backend, allowing real-time connection monitoring.
-enum WebSocketConnectionState {
-  /// The WebSocket is open and connected to the Convex backend.
-  connected,
+/// The status watch is removed as soon as the action completes or fails.
+ Future<String>  actionWithProgress({required String name , required Map<String, String> args , required JobOptions options , required FutureOr<void> Function(JobProgress) onProgress });
+
+
+/// Executes an action with structured arguments. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  actionWithValues({required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs });
+
+
+/// Returns the URL of the deployment currently in use.
+ String  activeDeploymentUrl();
+
+
+/// Returns the subscriptions that are neither cancelled nor ended,
+/// oldest first.
+ List<SubscriptionInfo>  activeSubscriptions();
+
+
+/// Adds an interceptor of queries, mutations and actions, as described
+/// in the [module docs](crate::interceptors). `on_call` decides about
+/// each call and `on_result` returns its result, possibly replaced.
+/// Returns an ID for [`MobileConvexClient::remove_interceptor`].
+ Future<BigInt>  addInterceptor({required FutureOr<InterceptDecision> Function(InterceptedCall) onCall , required FutureOr<CallOutcome> Function(InterceptedCall, CallOutcome) onResult });
+
+
+/// Supervises the calling isolate through `keepalive`, which should
+/// return right away, as described in the
+/// [module docs](crate::supervisor). Replaces the callback of a
+/// previously attached isolate and resumes the subscriptions paused
+/// because it stopped responding.
+ Future<void>  attachDartKeepalive({required DartKeepaliveOptions options , required FutureOr<void> Function() keepalive });
+
+
+/// Resolves once all mutations sent so far are reflected in the active
+/// subscriptions, as described in the [module docs](crate::write_barrier).
+/// Fails with [`ClientError::Timeout`] after `timeout_ms` milliseconds,
+/// if given.
+ Future<void>  awaitPendingWrites({BigInt? timeoutMs });
+
+
+/// Executes an action, reusing its result from an identical call made
+/// within the last `ttl_ms` milliseconds.
+ Future<String>  cachedAction({required String name , required Map<String, String> args , required BigInt ttlMs });
+
+
+/// Executes a query, answering from the cache when possible.
+ Future<String>  cachedQuery({required String name , required Map<String, String> args });
+
+
+/// Cancels every live subscription, returning how many there were.
+ int  cancelAllSubscriptions();
+
+
+/// Forgets all memoized action results.
+ void  clearActionCache();
+
+
+/// Deletes all retained audit log entries.
+ Future<void>  clearAuditLog();
+
+
+/// Stops encrypting and decrypting fields.
+ void  clearFieldEncryption();
+
+
+/// Stops injecting faults into calls of `name`.
+ void  clearFunctionFault({required String name });
+
+
+/// Stops injecting faults into calls of any function.
+ void  clearFunctionFaults();
+
+
+/// Deletes all on-disk data of the current identity on this deployment.
+/// Data of other identities and deployments is left untouched.
+ Future<void>  clearUserData();
+
+
+/// Returns the estimated server clock minus the local clock, in milliseconds.
+ PlatformInt64  clockOffsetMs();
+
+
+/// Closes the client: cancels all subscriptions and background tasks,
+/// including the auth refresh loop, closes the WebSocket and shuts the
+/// runtime down. Every later call fails with an error. Closing twice is a
+/// no-op.
+ Future<void>  close();
+
+
+/// Changes how many subscription results are cached, evicting those
+/// exceeding the new limits.
+ void  configureResultCache({required ResultCacheOptions options });
+
+
+/// Opens the WebSocket now rather than on the first call, e.g. to warm
+/// the connection up during a splash screen, and re-establishes the
+/// subscriptions stopped by [`MobileConvexClient::disconnect`] or
+/// [`MobileConvexClient::pause`].
+ Future<void>  connect();
+
+
+/// Creates a client for the preview deployment named by `preview`, as
+/// described in the [module docs](crate::preview). It uses this
+/// client's options without failover; closing either client leaves the
+/// other one open.
+ MobileConvexClient  connectPreview({required String preview , required String clientId });
+
+
+/// Returns the current connection-quality estimate.
+ ConnectionQuality  connectionQuality();
+
+
+/// Returns the current WebSocket connection state.
+ WebSocketConnectionState  connectionState();
+
+
+/// Returns whether the attached Dart isolate responds to keepalive
+/// pings.
+ DartLiveness  dartLiveness();
+
+
+/// Declares what the cached results of query `query_name` depend on.
+/// Declarations accumulate across calls.
+ void  declareQueryDependencies({required String queryName , required List<QueryDependency> dependencies });
+
+
+/// Stops exporting spans once the queued ones were sent.
+ void  disableTraceExport();
+
+
+/// Closes the WebSocket while keeping the client usable, e.g. while the
+/// app is idle.
+///
+/// Subscriptions are stopped as by [`MobileConvexClient::pause`], so
+/// nothing keeps the connection alive; their handles stay valid and
+/// [`MobileConvexClient::connect`] re-establishes them. Queries and
+/// other calls made meanwhile open a new WebSocket on their own, while
+/// subscriptions wait for `connect`.
+ Future<void>  disconnect();
+
+
+/// Downloads `url_or_storage_id`, a URL such as one returned by
+/// `ctx.storage.getUrl()` or a storage ID, to `dest_path`, as described
+/// in the [module docs](crate::file_storage). `on_progress` is called
+/// with the bytes written so far, including those of a resumed partial
+/// file.
+ Future<void>  downloadFile({required String urlOrStorageId , required String destPath , required FutureOr<void> Function(TransferProgress) onProgress });
+
+
+/// Returns how many other open clients in this process use the same
+/// deployment. Anything above zero usually means clients are created
+/// where a shared one was intended.
+ int  duplicateClientCount();
+
+
+/// Exports the spans of all calls from now on, as described in the
+/// [module docs](crate::spans). Replaces the previous export, if any.
+ void  enableTraceExport({required TraceExportOptions options });
+
+
+/// Registers a callback invoked with every [`ClientEvent`] from now on,
+/// as described in the [module docs](crate::events).
+ Future<void>  events({required FutureOr<void> Function(ClientEvent) onEvent });
+
+
+/// Returns the retained audit log entries as JSON lines, oldest first.
+ Future<String>  exportAuditLog();
+
+
+/// Creates a client for one flavor of a JSON client configuration, as
+/// described in the [module docs](crate::config).
+static MobileConvexClient  fromConfig({required String configJson , required String flavor , required String clientId })=>RustLib.instance.api.crateMobileConvexClientFromConfig(configJson: configJson, flavor: flavor, clientId: clientId);
+
+
+/// Returns the last result of a subscription to `name` with `args`,
+/// serialized as JSON, if one is cached.
+ String?  getCached({required String name , required Map<String, String> args });
+
+
+/// Calls the HTTP action at `path`, e.g. `/webhooks/stripe`, with
+/// `method`, `headers` and `body`, as described in the
+/// [module docs](crate::http_actions). The current auth token is sent
+/// as a bearer token unless `headers` has an `Authorization` header.
+/// Responses of any status are returned; only failing to get one is an
+/// error.
+ Future<HttpActionResponse>  httpAction({required String path , required String method , required Map<String, String> headers , Uint8List? body });
+
+
+/// Returns whether and when the Convex client was built, and how many
+/// calls are waiting for it.
+ InitializationDiagnostics  initializationDiagnostics();
+
+
+/// Invalidates and refetches the cached results of `query_name` and of
+/// all queries depending on it.
+ Future<void>  invalidateCachedQuery({required String queryName });
+
+
+/// Returns whether an auth token is currently set. Always `false` for
+/// clients created with [`ClientOptions::unauthenticated`].
+ bool  isAuthenticated();
+
+
+/// Returns whether the client keeps all state in memory.
+ bool  isIncognito();
+
+
+/// Returns when the WebSocket last became connected, as Unix time in
+/// milliseconds, or `None` if it never was.
+ PlatformInt64?  lastConnectedAtMs();
+
+
+/// Returns why the last attempt to connect failed, or `None` if the last
+/// attempt succeeded or none was made yet.
+ String?  lastConnectionError();
+
+
+/// Signs the user out: stops the auth refresh loop, clears auth on the
+/// connection, cancels auth-scoped subscriptions and purges the query
+/// and action caches. With `clear_user_data`, the identity's on-disk
+/// data is deleted too, as by [`MobileConvexClient::clear_user_data`].
+///
+/// Listeners registered with [`MobileConvexClient::on_logout`] are
+/// notified once everything is done.
+ Future<LogoutEvent>  logout({required bool clearUserData });
+
+
+/// Returns a snapshot of the connection and runtime health metrics.
+ ClientMetrics  metrics();
+
+
+/// Returns the call metrics recorded so far, as described in the
+/// [module docs](crate::call_metrics).
+ MetricsReport  metricsSnapshot();
+
+
+/// Executes `calls` strictly in order. No other mutation of this client
+/// runs between the first and the last step. After a failed step, the
+/// remaining ones are skipped if `stop_on_error` is set.
+///
+/// Fails without running any step if the arguments of a call are invalid.
+ Future<MutationSequenceResult>  mutateSequence({required List<MutationCall> calls , required bool stopOnError });
+
+
+/// Executes a mutation on the Convex backend. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  mutation({required String name , required Map<String, String> args , BigInt? timeoutMs });
+
+
+/// Like [`MobileConvexClient::mutation`], but abandoned once `token` is
+/// cancelled.
+ Future<String>  mutationCancellable({required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs });
+
+
+/// Queues a low-priority Convex mutation, sent at the latest after
+/// `max_delay_ms` milliseconds. Returns once queued; the result of the
+/// mutation is not reported.
+ Future<void>  mutationDeferred({required String name , required Map<String, String> args , required int maxDelayMs });
+
+
+/// Like [`MobileConvexClient::mutation_deferred`], reporting the
+/// mutation's lifecycle under `mutation_id`.
+ Future<void>  mutationDeferredTracked({required String mutationId , required String name , required Map<String, String> args , required int maxDelayMs });
+
+
+/// Executes a mutation with an idempotency key, retrying it as
+/// described in the [module docs](crate::retry). A new key is generated
+/// unless `idempotency_key` is given, e.g. to retry across app
+/// restarts.
+ Future<IdempotentMutationResult>  mutationIdempotent({required String name , required Map<String, String> args , String? idempotencyKey });
+
+
+/// Executes a mutation now if connected, or queues it in the outbox
+/// otherwise, as described in the [module docs](crate::outbox).
+ Future<OfflineMutationResult>  mutationOffline({required String name , required Map<String, String> args });
+
+
+/// Executes a mutation like [`MobileConvexClient::mutation`], showing
+/// `updates` in the affected subscriptions until it completes, as
+/// described in the [module docs](crate::optimistic).
+ Future<String>  mutationOptimistic({required String name , required Map<String, String> args , required List<OptimisticUpdate> updates });
+
+
+/// Executes a mutation like [`MobileConvexClient::mutation`], reporting
+/// its lifecycle under `mutation_id`. Attempts failing for a reason
+/// other than the function's own error are retried with the backoff of
+/// [`crate::options::ClientOptions::mutation_retry`], up to
+/// `max_attempts` attempts in total.
+ Future<String>  mutationTracked({required String mutationId , required String name , required Map<String, String> args , required int maxAttempts });
+
+
+/// Executes a mutation with structured arguments and result.
+ Future<ConvexValue>  mutationTyped({required String name , required Map<String, ConvexValue> args });
+
+
+/// Executes a mutation like [`MobileConvexClient::mutation`] and returns
+/// its result together with a commit token.
+ Future<CommittedMutation>  mutationWithCommitToken({required String name , required Map<String, String> args });
+
+
+/// Executes a mutation with structured arguments. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  mutationWithValues({required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs });
+
+
+/// Creates a new MobileConvexClient instance with the given deployment URL and client ID.
+factory MobileConvexClient({required String deploymentUrl , required String clientId })=>RustLib.instance.api.crateMobileConvexClientNew(deploymentUrl: deploymentUrl, clientId: clientId);
+
+
+/// Creates a new MobileConvexClient instance with explicit client options.
+static MobileConvexClient  newWithOptions({required String deploymentUrl , required String clientId , required ClientOptions options })=>RustLib.instance.api.crateMobileConvexClientNewWithOptions(deploymentUrl: deploymentUrl, clientId: clientId, options: options);
+
+
+/// Releases memory the client can do without, according to
+/// `pressure`, and returns what was released.
+ Future<MemoryReleasedEvent>  notifyMemoryPressure({required MemoryPressure pressure });
+
+
+/// Registers a callback invoked with every [`AuthChange`] from now on,
+/// as described in the [module docs](crate::auth_changes).
+ Future<void>  onAuthChange({required FutureOr<void> Function(AuthChange) onChange });
+
+
+/// Registers a callback invoked whenever the backend fingerprint
+/// changes. Requires [`crate::options::ClientOptions::schema_check`].
+ Future<void>  onBackendChange({required FutureOr<void> Function(BackendChangedEvent) onChange });
+
+
+/// Registers a callback invoked whenever a call exceeds the usage
+/// budget. Requires [`crate::options::ClientOptions::usage_budget`].
+ Future<void>  onBudgetExceeded({required FutureOr<void> Function(BudgetExceededEvent) onExceeded });
+
+
+/// Registers a callback invoked with the current connection quality and
+/// then whenever the score changes significantly or the level changes.
+ Future<void>  onConnectionQualityChange({required FutureOr<void> Function(ConnectionQuality) onChange });
+
+
+/// Registers a callback invoked whenever the client switches deployments.
+ Future<void>  onFailover({required FutureOr<void> Function(FailoverEvent) onFailover });
+
+
+/// Registers a callback invoked whenever a logout has completed.
+ Future<void>  onLogout({required FutureOr<void> Function(LogoutEvent) onLogout });
+
+
+/// Registers a callback invoked whenever memory was released in
+/// response to [`MobileConvexClient::notify_memory_pressure`].
+ Future<void>  onMemoryReleased({required FutureOr<void> Function(MemoryReleasedEvent) onReleased });
+
+
+/// Registers a callback invoked with every status change of tracked
+/// mutations. A callback still busy with an earlier event may miss some.
+ Future<void>  onMutationLifecycle({required FutureOr<void> Function(MutationLifecycleEvent) onEvent });
+
+
+/// Registers a callback invoked with the outcome of every mutation
+/// replayed from the outbox, and starts replaying mutations queued by an
+/// earlier run.
+ Future<void>  onOutboxCompletion({required FutureOr<void> Function(OutboxCompletion) onCompletion });
+
+
+/// Registers the resolver deciding what to do with replayed mutations
+/// the function rejects, replacing any registered before, as described
+/// in the [module docs](crate::outbox). Replaying waits for it.
+ Future<void>  onOutboxConflict({required FutureOr<ConflictResolution> Function(OutboxConflict) resolve });
+
+
+/// Registers a callback invoked whenever building the Convex client
+/// takes longer than [`crate::options::ClientOptions::slow_initialization_ms`].
+ Future<void>  onSlowInitialization({required FutureOr<void> Function(SlowInitializationEvent) onSlow });
+
+
+/// Registers a callback invoked with the current hint and whenever it
+/// changes.
+ Future<void>  onUiHint({required FutureOr<void> Function(UiHint?) onHint });
+
+
+/// Adds a WebSocket connection state change listener.
+///
+/// Listeners can be added at any time and any number of them can be
+/// registered. Each one is invoked with the current state right away and
+/// then whenever the WebSocket transitions between Connected and
+/// Connecting states. A listener that is still busy with its previous
+/// state receives only the latest one.
+///
+/// # Arguments
+///
+/// * `on_state_change` - Async callback invoked when connection state changes
+///
+/// # Example
+///
+/// ```dart
+/// await client.onWebsocketStateChange(
+///   onStateChange: (state) async {
+///     print('Connection state: ${state.name}');
+///   },
+/// );
+/// ```
+ Future<void>  onWebsocketStateChange({required FutureOr<void> Function(WebSocketConnectionState) onStateChange });
+
+
+/// Returns the number of mutations waiting in the outbox.
+ Future<int>  outboxLen();
+
+
+/// Stops all subscriptions and closes the WebSocket, e.g. when the app
+/// goes to the background. Subscription handles stay valid and their
+/// subscriptions are re-established by [`MobileConvexClient::resume`].
+///
+/// Presence heartbeats started by [`MobileConvexClient::start_presence`]
+/// are paused through their own handle. Changed subscription results
+/// are persisted, if enabled (see [`crate::persisted_results`]).
+ Future<void>  pause();
+
+
+/// Executes a query on the Convex backend. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  query({required String name , required Map<String, String> args , BigInt? timeoutMs });
+
+
+/// Executes a query once the deployment's snapshot is at or beyond `ts`,
+/// a commit token returned by
+/// [`MobileConvexClient::mutation_with_commit_token`] on any client of the
+/// same deployment. Queries are evaluated at the latest snapshot, so the
+/// result includes that mutation's writes.
+ Future<String>  queryAtLeast({required String ts , required String name , required Map<String, String> args });
+
+
+/// Runs `queries` and returns their results in order, all read at the
+/// same logical timestamp. Fails if any of the queries fails, or with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<List<String>>  queryBatch({required List<BatchQuery> queries , BigInt? timeoutMs });
+
+
+/// Like [`MobileConvexClient::query`], but abandoned once `token` is
+/// cancelled.
+ Future<String>  queryCancellable({required String name , required Map<String, String> args , required CancellationToken token , BigInt? timeoutMs });
+
+
+/// Like [`MobileConvexClient::query`], but returns only the fields
+/// selected by the JSON pointers in `projection`.
+ Future<String>  queryProjected({required String name , required Map<String, String> args , required List<String> projection });
+
+
+/// Executes a query and delivers its result to `on_chunk` in chunks, as
+/// described in the [module docs](crate::chunked). Resolves once the
+/// last chunk was handled. Fails with [`ClientError::Timeout`] if the
+/// result has not arrived after `timeout_ms` milliseconds, if set.
+ Future<void>  queryStreamed({required String name , required Map<String, String> args , required FutureOr<void> Function(ResultChunk) onChunk , BigInt? timeoutMs });
+
+
+/// Executes a query with structured arguments and result.
+ Future<ConvexValue>  queryTyped({required String name , required Map<String, ConvexValue> args });
+
+
+/// Executes a query with structured arguments. Fails with
+/// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+ Future<String>  queryWithValues({required String name , required Map<String, ConvexValue> args , BigInt? timeoutMs });
+
+
+/// Replaces the Convex client with a freshly built one for the active
+/// deployment, re-applying the current auth token.
+///
+/// Use this to recover after connecting failed, without recreating the
+/// whole client. Running subscriptions are moved to the new client,
+/// highest priority first, and keep their handles.
+ Future<void>  reconnect();
+
+
+/// Serves future subscriptions to `query_name` from `mapping.batch_query`,
+/// grouping many watches into few server subscriptions.
+ void  registerShardMapping({required String queryName , required ShardMapping mapping });
+
+
+/// Removes the interceptor with `id`, returning whether it was added.
+/// Calls already passing it are unaffected.
+ bool  removeInterceptor({required BigInt id });
+
+
+/// Stops forwarding records to the listener set with
+/// [`MobileConvexClient::set_log_listener`].
+ void  removeLogListener();
+
+
+/// Stops handing panics to the reporter set with
+/// [`MobileConvexClient::set_panic_reporter`].
+ void  removePanicReporter();
+
+
+/// Returns the document with `id` from the replica table `table`,
+/// serialized as JSON.
+ String?  replicaGet({required String table , required String id });
+
+
+/// Returns the documents of the replica table `table`, serialized as
+/// JSON and ordered by `_id`, whose fields equal those of `filter`. The
+/// filter values are JSON, e.g. `{"done": "false"}`.
+ List<String>  replicaQuery({required String table , required Map<String, String> filter });
+
+
+/// Subscribes to `query_name` with `query_args` and mirrors the returned
+/// documents into the replica table `table`, as described in the
+/// [module docs](crate::replication). Table names consist of ASCII
+/// letters, digits and underscores. Cancelling the subscription stops
+/// mirroring; the table keeps its rows.
+ Future<SubscriptionHandle>  replicateQuery({required String table , required String queryName , required Map<String, String> queryArgs });
+
+
+/// Reconnects and re-establishes the subscriptions stopped by
+/// [`MobileConvexClient::pause`]. If connecting fails, the client stays
+/// paused and `resume` can be retried.
+ Future<void>  resume();
+
+
+/// Sets authentication token for the client.
+ Future<void>  setAuth({String? token });
+
+
+/// Sets authentication with automatic token refresh.
+///
+/// The `fetch_token` callback is called:
+/// - Immediately to get the initial token
+/// - Automatically when the token is about to expire (60 seconds before expiry,
+///   see [`MobileConvexClient::set_auth_with_refresh_config`])
+/// - Immediately when the backend rejects the token, see
+///   [`events::ClientEvent::AuthRejected`]; if it keeps rejecting new tokens,
+///   after a delay doubling from 1 second up to a minute
+/// - Immediately when the WebSocket reconnects after the token expired;
+///   an unexpired token is applied again instead, see
+///   [`auth_changes`]
+///
+/// The `on_auth_change` callback is called whenever auth state changes.
+///
+/// Returns an AuthHandle that can be used to dispose the auth session.
+/// Disposing stops waiting on a pending `fetch_token` call; a token it
+/// returns afterwards is never applied. A later `set_auth`,
+/// `set_auth_with_refresh` or `logout` ends the session too, without its
+/// refresh loop clearing the newer auth.
+ Future<AuthHandle>  setAuthWithRefresh({required FutureOr<String?> Function() fetchToken , required FutureOr<void> Function(bool) onAuthChange });
+
+
+/// Like [`MobileConvexClient::set_auth_with_refresh`], but refreshes the
+/// token on the schedule of `config`, as described in the
+/// [module docs](crate::auth_refresh).
+ Future<AuthHandle>  setAuthWithRefreshConfig({required AuthRefreshConfig config , required FutureOr<String?> Function() fetchToken , required FutureOr<void> Function(bool) onAuthChange });
+
+
+/// Encrypts the fields at `fields` with the 32-byte AES-256 `key` from
+/// now on, as described in the [module docs](crate::encryption).
+/// Replaces fields and key registered before.
+ void  setFieldEncryption({required List<int> key , required List<String> fields });
+
+
+/// Injects `fault` into all later calls of the function `name`,
+/// replacing any fault set for it before.
+ Future<void>  setFunctionFault({required String name , required FunctionFault fault });
+
+
+/// Appends records at `level` or more severe to the file at `path`, as
+/// described in the [module docs](crate::logging), or stops writing
+/// the file if `path` is `None`. Once the file exceeds 4 MiB it is
+/// moved to `<path>.old` and started over. Applies to the whole
+/// process. Fails while an incognito client exists.
+ void  setLogFile({String? path , required LogLevel level });
+
+
+/// Writes records at `level` or more severe to the platform log, e.g.
+/// logcat, from now on, as described in the
+/// [module docs](crate::logging). Applies to the whole process.
+ void  setLogLevel({required LogLevel level });
+
+
+/// Calls `on_record` with every record at `level` or more severe from
+/// now on, as described in the [module docs](crate::logging).
+ Future<void>  setLogListener({required LogLevel level , required FutureOr<void> Function(LogRecord) onRecord });
+
+
+/// Calls `on_panic` with every panic in the process from now on, as
+/// described in the [module docs](crate::panics). A later call
+/// replaces the reporter.
+ Future<void>  setPanicReporter({required FutureOr<void> Function(PanicReport) onPanic });
+
+
+/// Sets the 32-byte key encrypting the client's files, as described in
+/// the [module docs](crate::storage_encryption), and replays mutations
+/// queued in an outbox that could not be read without it.
+ void  setStorageKey({required List<int> key });
+
+
+/// Replaces the telemetry sampling configuration. Applies to events
+/// recorded from now on.
+ void  setTelemetrySampling({required TelemetrySampling sampling });
+
+
+/// Reports the current UI load. Elevated pressure throttles subscription
+/// updates as configured by [`crate::options::ClientOptions::pressure_throttle`].
+ void  setUiPressure({required UiPressure pressure });
+
+
+/// Returns how many sharded subscriptions are served by how many server
+/// subscriptions.
+ ShardingMetrics  shardingMetrics();
+
+
+/// Starts sending `mutation_name` with `args` every `interval_ms`.
+///
+/// Heartbeats are skipped while the WebSocket is not connected or the
+/// returned handle is paused, and one is sent immediately on reconnect
+/// or resume. If the mutation returns a number it is used as the server
+/// time to estimate clock skew for [`MobileConvexClient::subscribe_presence`].
+/// Heartbeats are sent like any other mutation, through the interceptors
+/// and call metrics, and stop once the client is dropped.
+ Future<PresenceHandle>  startPresence({required String mutationName , required Map<String, String> args , required BigInt intervalMs });
+
+
+/// Returns the storage directory of the current deployment and identity,
+/// creating it if needed. Apps can keep their own per-user files there.
+ Future<String>  storageDirectory();
+
+
+/// Subscribes to real-time updates from a Convex query, as configured by
+/// `options`. `on_done` is called once the subscription stream ended and
+/// no more updates will ever arrive, as opposed to waiting for the next
+/// update. Cancelling does not call `on_done`.
+ Future<SubscriptionHandle>  subscribe({required String name , required Map<String, String> args , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError , required FutureOr<void> Function() onDone , SubscribeOptions? options });
+
+
+/// Like [`MobileConvexClient::subscribe`], but delivers every result to
+/// `on_chunk` in chunks, as described in the
+/// [module docs](crate::chunked).
+ Future<SubscriptionHandle>  subscribeChunked({required String name , required Map<String, String> args , required FutureOr<void> Function(ResultChunk) onChunk , required FutureOr<void> Function(String, String?) onError });
+
+
+/// Subscribes to all `sources` and delivers their results joined by
+/// `strategy` whenever any of them changes, once every source has a
+/// result. Errors of any source are passed to `on_error`. Cancelling the
+/// returned handle cancels all source subscriptions.
+ Future<SubscriptionHandle>  subscribeCombined({required List<DerivedSource> sources , required CombineStrategy strategy , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+
+/// Subscribes to a query under `key`, replacing the subscription
+/// previously made under the same key.
+///
+/// Updates of replaced subscriptions are never delivered, even when they
+/// were already in flight. If subscribing fails, the replaced
+/// subscription is cancelled all the same.
+ Future<SubscriptionHandle>  subscribeKeyed({required String key , required String name , required Map<String, String> args , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+
+/// Subscribes to a Convex query returning an array, delivering each
+/// update as a [`ListUpdate`] against the previous one. Items are
+/// matched by their `id_field`, e.g. `"_id"`. Callbacks are awaited in
+/// order, so diffs apply in sequence.
+ Future<SubscriptionHandle>  subscribeList({required String name , required Map<String, String> args , required String idField , required FutureOr<void> Function(ListUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+
+/// Subscribes to a Convex query, resolves with its first result and
+/// cancels the subscription. Unlike [`MobileConvexClient::query`], the
+/// result is read through the subscription set and is therefore
+/// consistent with the client's other subscriptions. Query errors are
+/// returned like those of `query`.
+ Future<String>  subscribeOnce({required String name , required Map<String, String> args });
+
+
+/// Subscribes to a paginated query, starting with a page of
+/// `initial_num_items` items, as described in the
+/// [module docs](crate::pagination). `args` must not contain
+/// `paginationOpts`; it is added per page. Updates carry the items of
+/// all loaded pages and are awaited in order.
+ Future<PaginatedQueryHandle>  subscribePaginated({required String name , required Map<String, String> args , required int initialNumItems , required FutureOr<void> Function(PaginatedUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+
+/// Like [`MobileConvexClient::subscribe_paginated`], but starts with the
+/// pages delimited by `page_boundaries` from an earlier
+/// [`PaginatedUpdate`], followed by a page of `num_items` items.
+ Future<PaginatedQueryHandle>  subscribePaginatedFrom({required String name , required Map<String, String> args , required List<String> pageBoundaries , required int numItems , required FutureOr<void> Function(PaginatedUpdate) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+
+/// Subscribes to a query returning heartbeat documents and delivers the
+/// derived online/offline status of each user.
+///
+/// Statuses are re-evaluated as time passes, so users drop to offline
+/// without a server update. The server clock is estimated using the skew
+/// measured by [`MobileConvexClient::start_presence`].
+ Future<SubscriptionHandle>  subscribePresence({required String name , required Map<String, String> args , required PresenceOptions options , required FutureOr<void> Function(List<PresenceStatus>) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+
+/// Subscribes to a Convex query, adding every result, serialized as
+/// JSON, to `sink`, as described in the
+/// [module docs](crate::subscription_stream). Updates are not dropped
+/// or coalesced for a slow listener. Cancelling the Dart stream
+/// subscription does not cancel the query; the returned handle does.
+ Stream<String>  subscribeStream({required String name , required Map<String, String> args });
+
+
+/// Subscribes to a query with structured arguments, delivering results as
+/// [`ConvexValue`]s.
+///
+/// Results go through the same pipeline as those of
+/// [`MobileConvexClient::subscribe`], so the subscription is throttled,
+/// shared, sharded, paused and re-established the same way. With
+/// [`crate::options::Int64Encoding::String`], `Int64`s in results
+/// arrive as strings; the other encodings keep them exact.
+ Future<SubscriptionHandle>  subscribeTyped({required String name , required Map<String, ConvexValue> args , required FutureOr<void> Function(ConvexValue) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+
+/// Subscribes to a Convex query and delivers every lifecycle signal as a
+/// single [`SubscriptionEvent`] through `on_event`.
+///
+/// Events are delivered in order: each callback is awaited before the
+/// next event is produced. The last event is always
+/// [`SubscriptionEvent::Closed`]. The subscription is made like those of
+/// [`MobileConvexClient::subscribe`], so it is also paused and resumed,
+/// and takes the same `options` except for a backpressure policy.
+ Future<SubscriptionHandle>  subscribeWithEvents({required String name , required Map<String, String> args , required FutureOr<void> Function(SubscriptionEvent) onEvent , SubscribeOptions? options });
+
+
+/// Subscribes to a Convex query, delivering each update as a
+/// [`PatchUpdate`] against the previous one. A full snapshot is also
+/// sent every `snapshot_interval` updates, or only when needed if it is
+/// `0`. Callbacks are awaited in order, so patches apply in sequence.
+ Future<SubscriptionHandle>  subscribeWithPatches({required String name , required Map<String, String> args , required int snapshotInterval , required FutureOr<void> Function(PatchUpdate) onPatch , required FutureOr<void> Function(String, String?) onError });
+
+
+/// Returns the hint to show for the client's current state, if any.
+ UiHint?  uiHint();
+
+
+/// Returns the UI load last reported with [`MobileConvexClient::set_ui_pressure`].
+ UiPressure  uiPressure();
+
+
+/// Cancels the subscription made under `key`, if any.
+ void  unsubscribeKeyed({required String key });
+
+
+/// Uploads `source` with the content type `mime_type` and returns its
+/// storage ID, as described in the [module docs](crate::file_storage).
+/// `on_progress` is called with the bytes sent so far; updates arriving
+/// while it runs are coalesced to the latest.
+ Future<String>  uploadFile({required UploadSource source , required String mimeType , required FutureOr<void> Function(TransferProgress) onProgress });
+
+
+/// Subscribes to a Convex query, first emitting the placeholder chosen
+/// by `placeholder`. The placeholder is always delivered before any
+/// server value.
+ Future<SubscriptionHandle>  watch({required String name , required Map<String, String> args , required PlaceholderPolicy placeholder , required FutureOr<void> Function(WatchEvent) onEvent });
+
+
+/// Subscribes to all `sources` and delivers the value described by
+/// `fields` as a JSON object whenever it changes. Errors of any source
+/// are passed to `on_error`. Cancelling the returned handle cancels all
+/// source subscriptions.
+ Future<SubscriptionHandle>  watchDerived({required List<DerivedSource> sources , required List<DerivedField> fields , required FutureOr<void> Function(String) onUpdate , required FutureOr<void> Function(String, String?) onError });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SubscriptionHandle>>
+                abstract class SubscriptionHandle implements RustOpaqueInterface {
+                    /// Cancels the subscription by sending a cancellation signal. Returns
+/// `false` if it had already been cancelled or ended; calling it again
+/// is harmless.
+ bool  cancel();
+
+
+/// Returns whether the subscription is neither cancelled nor ended.
+ bool  isActive();
+
+
+/// Changes the order in which this subscription is re-established after
+/// a reconnect, e.g. when its screen becomes visible.
+ void  setPriority({required SubscriptionPriority priority });
+
+
+/// Returns whether the subscription is active, has ended or was
+/// cancelled.
+ SubscriptionState  state();
+
+
+
+                    
+                }
+                
+
+
+                abstract class QuerySubscriber {
+                     Future<void>  onDone();
+
+
+ Future<void>  onError({required String message , String? value });
+
+
+ Future<void>  onUpdate({required String value });
+
+
+                }
+                
+
+@freezed
+                sealed class ClientError with _$ClientError implements FrbException {
+                    const ClientError._();
+
+                     /// An internal error within the mobile Convex client, or a failure it
+/// detected on the way to the backend, such as a lost connection.
+const factory ClientError.internalError({   required String msg ,  required ErrorCode code ,  required bool retryable , }) = ClientError_InternalError;
+ /// An application-specific error from a remote Convex backend function,
+/// with the data it was thrown with.
+const factory ClientError.convexError({   required String message ,  required ConvexValue data ,  required ErrorCode code ,  required bool retryable , }) = ClientError_ConvexError;
+ /// An unexpected server-side error from a remote Convex function.
+const factory ClientError.serverError({   required String msg ,  required ErrorCode code ,  required bool retryable , }) = ClientError_ServerError;
+ /// The call did not complete within its timeout.
+const factory ClientError.timeout({   required BigInt timeoutMs ,  required ErrorCode code ,  required bool retryable , }) = ClientError_Timeout;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class WebSocketConnectionState with _$WebSocketConnectionState  {
+                    const WebSocketConnectionState._();
+
+                     /// The WebSocket is open and connected to the Convex backend.
+const factory WebSocketConnectionState.connected() = WebSocketConnectionState_Connected;
+ /// The WebSocket of a newly built client is being opened.
+const factory WebSocketConnectionState.connecting() = WebSocketConnectionState_Connecting;
+ /// The WebSocket closed. After [`ConnectionCloseReason::ConnectionLost`]
+/// the Convex client reconnects by itself; otherwise the next call, or
+/// [`MobileConvexClient::connect`], opens a new one.
+const factory WebSocketConnectionState.closed({   required ConnectionCloseReason reason , }) = WebSocketConnectionState_Closed;
+ /// Building the client failed; the next attempt starts in `retry_in_ms`.
+const factory WebSocketConnectionState.backoff({   required BigInt retryInMs ,  required int attempt , }) = WebSocketConnectionState_Backoff;
+ /// Building the client failed on every attempt. Calls made later start
+/// over, as does [`MobileConvexClient::reconnect`].
+const factory WebSocketConnectionState.failed({   required String reason , }) = WebSocketConnectionState_Failed;
+
+                    
 
-  /// The WebSocket is closed and is connecting or reconnecting.
-  connecting,
-}
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'list_diff.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `diff_lists`, `item_key`, `next`, `unique_keys`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ListEvent`, `ListState`, `ListSubscriber`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `on_error`, `on_update`
+
+
+            
+
+            @freezed
+                sealed class ListChange with _$ListChange  {
+                    const ListChange._();
+
+                     /// `item`, serialized as JSON, was inserted at `index`.
+const factory ListChange.insert({   required int index ,  required String item , }) = ListChange_Insert;
+ /// The item at `index` was removed.
+const factory ListChange.remove({   required int index , }) = ListChange_Remove;
+ /// The item at `from` was moved to `to`.
+const factory ListChange.move({   required int from ,  required int to , }) = ListChange_Move;
+ /// The item at `index` kept its id but changed to `item`.
+const factory ListChange.change({   required int index ,  required String item , }) = ListChange_Change;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class ListUpdate with _$ListUpdate  {
+                    const ListUpdate._();
+
+                     /// The whole result, serialized as JSON; replaces the list held by the
+/// app. Sent first, after errors and for results that cannot be diffed.
+const factory ListUpdate.reset({   required String value , }) = ListUpdate_Reset;
+ /// Operations turning the previous list into the new one, in order.
+const factory ListUpdate.diff({   required List<ListChange> changes , }) = ListUpdate_Diff;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
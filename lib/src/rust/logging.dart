@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `incognito_clients_alive`, `install`, `max_level`, `new`, `open`, `platform_level`, `platform_logger`, `update_max_level`, `write`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Dispatcher`, `IncognitoLogGuard`, `Listener`, `LogFile`, `StderrLogger`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `cmp`, `drop`, `enabled`, `enabled`, `eq`, `eq`, `flush`, `flush`, `fmt`, `fmt`, `from`, `from`, `log`, `log`, `partial_cmp`
+
+
+            
+
+            /// Severity of a log record, or the most verbose severity let through.
+enum LogLevel {
+                    /// Nothing is logged; never the level of a record.
+off,
+error,
+warn,
+info,
+debug,
+trace,
+                    ;
+                    
+                }
+
+/// A record logged by the client.
+class LogRecord  {
+                final LogLevel level;
+/// Module that logged the record, e.g. `convex_flutter::outbox`.
+final String target;
+final String message;
+final PlatformInt64 timestampMs;
+
+                const LogRecord({required this.level ,required this.target ,required this.message ,required this.timestampMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => level.hashCode^target.hashCode^message.hashCode^timestampMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LogRecord &&
+                runtimeType == other.runtimeType
+                && level == other.level&& target == other.target&& message == other.message&& timestampMs == other.timestampMs;
+        
+            }
+            
\ No newline at end of file
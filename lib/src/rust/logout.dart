@@ -0,0 +1,43 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cancel_subscriptions`, `new`, `stop_refresh`, `track_refresh`, `track_subscription`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AuthSession`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            
+
+            /// Emitted once a logout has completed.
+class LogoutEvent  {
+                /// Number of auth-scoped subscriptions that were cancelled.
+final int cancelledSubscriptions;
+/// Whether the on-disk data of the signed-out identity was deleted.
+final bool clearedUserData;
+
+                const LogoutEvent({required this.cancelledSubscriptions ,required this.clearedUserData ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => cancelledSubscriptions.hashCode^clearedUserData.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LogoutEvent &&
+                runtimeType == other.runtimeType
+                && cancelledSubscriptions == other.cancelledSubscriptions&& clearedUserData == other.clearedUserData;
+        
+            }
+            
\ No newline at end of file
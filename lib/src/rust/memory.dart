@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `memory_events`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// Severity of the memory pressure reported by the OS.
+enum MemoryPressure {
+                    /// Memory is getting low, e.g. `TRIM_MEMORY_RUNNING_LOW`.
+moderate,
+/// The app is about to be killed, e.g. `TRIM_MEMORY_RUNNING_CRITICAL`
+/// or an iOS memory warning.
+critical,
+                    ;
+                    
+                }
+
+/// What the client released in response to memory pressure.
+class MemoryReleasedEvent  {
+                final MemoryPressure pressure;
+/// Cached one-shot query results dropped.
+final int queryCacheEntries;
+/// Memoized action results dropped.
+final int actionCacheEntries;
+/// Values remembered for watch placeholders dropped.
+final int placeholderValues;
+/// Deferred mutations sent early.
+final int deferredMutationsSent;
+/// Approximate size of the dropped results.
+final BigInt bytesReleased;
+
+                const MemoryReleasedEvent({required this.pressure ,required this.queryCacheEntries ,required this.actionCacheEntries ,required this.placeholderValues ,required this.deferredMutationsSent ,required this.bytesReleased ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => pressure.hashCode^queryCacheEntries.hashCode^actionCacheEntries.hashCode^placeholderValues.hashCode^deferredMutationsSent.hashCode^bytesReleased.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MemoryReleasedEvent &&
+                runtimeType == other.runtimeType
+                && pressure == other.pressure&& queryCacheEntries == other.queryCacheEntries&& actionCacheEntries == other.actionCacheEntries&& placeholderValues == other.placeholderValues&& deferredMutationsSent == other.deferredMutationsSent&& bytesReleased == other.bytesReleased;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,79 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'quality.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `current`, `max_ms`, `mean_ms`, `new`, `push`, `spawn_probe`, `start_probe`, `total_busy`, `utilization`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LagWindow`, `ProbeState`, `RuntimeMonitor`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `drop`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`
+
+
+            
+
+            /// Point-in-time snapshot of all client metrics.
+class ClientMetrics  {
+                final ConnectionQuality connection;
+final RuntimeMetrics runtime;
+
+                const ClientMetrics({required this.connection ,required this.runtime ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => connection.hashCode^runtime.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ClientMetrics &&
+                runtimeType == other.runtimeType
+                && connection == other.connection&& runtime == other.runtime;
+        
+            }
+
+/// Health of the native async runtime.
+class RuntimeMetrics  {
+                /// Number of worker threads.
+final int workers;
+/// Tasks currently alive (spawned and not yet completed).
+final BigInt aliveTasks;
+/// Tasks waiting in the global scheduling queue.
+final BigInt globalQueueDepth;
+/// Fraction of time the workers were busy during the last sample, 0 to 1.
+/// Values near 1 mean the runtime is saturated or a worker is blocked.
+final double workerUtilization;
+/// Average delay before a woken task ran, over the last minute.
+final double meanSchedulingLagMs;
+/// Longest delay before a woken task ran, over the last minute.
+final double maxSchedulingLagMs;
+
+                const RuntimeMetrics({required this.workers ,required this.aliveTasks ,required this.globalQueueDepth ,required this.workerUtilization ,required this.meanSchedulingLagMs ,required this.maxSchedulingLagMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => workers.hashCode^aliveTasks.hashCode^globalQueueDepth.hashCode^workerUtilization.hashCode^meanSchedulingLagMs.hashCode^maxSchedulingLagMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RuntimeMetrics &&
+                runtimeType == other.runtimeType
+                && workers == other.workers&& aliveTasks == other.aliveTasks&& globalQueueDepth == other.globalQueueDepth&& workerUtilization == other.workerUtilization&& meanSchedulingLagMs == other.meanSchedulingLagMs&& maxSchedulingLagMs == other.maxSchedulingLagMs;
+        
+            }
+            
\ No newline at end of file
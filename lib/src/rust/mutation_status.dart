@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'mutation_status.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `final_status`, `is_final`, `mutation_events`, `report`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// A status change of a tracked mutation.
+class MutationLifecycleEvent  {
+                /// The id the mutation was tracked with.
+final String mutationId;
+/// Name of the mutation function.
+final String name;
+final MutationStatus status;
+
+                const MutationLifecycleEvent({required this.mutationId ,required this.name ,required this.status ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => mutationId.hashCode^name.hashCode^status.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MutationLifecycleEvent &&
+                runtimeType == other.runtimeType
+                && mutationId == other.mutationId&& name == other.name&& status == other.status;
+        
+            }
+
+@freezed
+                sealed class MutationStatus with _$MutationStatus  {
+                    const MutationStatus._();
+
+                     /// Waiting in the deferred mutation queue.
+const factory MutationStatus.queued() = MutationStatus_Queued;
+ /// Sent to the backend, awaiting its result.
+const factory MutationStatus.sending() = MutationStatus_Sending;
+ /// Attempt `attempt` failed and the mutation will be sent again.
+const factory MutationStatus.retrying({   required int attempt ,  required String error , }) = MutationStatus_Retrying;
+ /// Committed by the backend; `completed_at_ms` is the client time the
+/// result arrived, in milliseconds since the epoch.
+const factory MutationStatus.succeeded({   required PlatformInt64 completedAtMs , }) = MutationStatus_Succeeded;
+ /// Failed for good.
+const factory MutationStatus.failed({   required String error , }) = MutationStatus_Failed;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_layers`, `push`, `query_key`, `refresh`, `refresh`, `register`, `remove`, `subscriber`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LayerGuard`, `Layer`, `OptimisticStore`, `OptimisticSubscriber`, `TrackedSubscriber`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `drop`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `on_done`, `on_error`, `on_update`
+
+
+            
+
+            /// An optimistic change to the results of a query while a mutation is in
+/// flight.
+class OptimisticUpdate  {
+                /// Name of the query function.
+final String queryName;
+/// Arguments of the affected subscriptions, as for
+/// [`MobileConvexClient::subscribe`].
+final Map<String, String> queryArgs;
+/// An [RFC 6902](https://www.rfc-editor.org/rfc/rfc6902) JSON Patch,
+/// serialized as JSON, applied to the query's result.
+final String patch;
+
+                const OptimisticUpdate({required this.queryName ,required this.queryArgs ,required this.patch ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => queryName.hashCode^queryArgs.hashCode^patch.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OptimisticUpdate &&
+                runtimeType == other.runtimeType
+                && queryName == other.queryName&& queryArgs == other.queryArgs&& patch == other.patch;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,203 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'audit.dart';
+import 'budget.dart';
+import 'chunked.dart';
+import 'codecs.dart';
+import 'connection.dart';
+import 'deferred.dart';
+import 'failover.dart';
+import 'file_storage.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'persisted_results.dart';
+import 'placeholder.dart';
+import 'pressure.dart';
+import 'preview.dart';
+import 'request_ids.dart';
+import 'retry.dart';
+import 'sampling.dart';
+import 'schema_check.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `enforce_incognito`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// How arguments whose JSON text is empty or only whitespace are treated.
+///
+/// Such text is not valid JSON; it typically comes from an unset text field.
+enum BlankArgHandling {
+                    /// The call fails with an error naming the argument.
+error,
+/// The argument is sent as `null`, subject to [`NullHandling`].
+null_,
+/// The argument is left out, as if it were `undefined`.
+skip,
+                    ;
+                    static Future<BlankArgHandling>  default_()=>RustLib.instance.api.crateOptionsBlankArgHandlingDefault();
+
+
+                }
+
+/// Options for constructing a [`crate::MobileConvexClient`].
+class ClientOptions  {
+                /// How `null` values in arguments are serialized.
+final NullHandling nullHandling;
+/// Maximum number of subscriptions re-established concurrently after a
+/// reconnect, ordered by [`crate::resubscribe::SubscriptionPriority`].
+/// `None` lets the client resend all subscriptions at once.
+final int? maxConcurrentResubscribes;
+/// Enables the on-device audit log of queries, subscriptions, mutations
+/// and actions. Disabled when `None`.
+final AuditLogOptions? auditLog;
+/// How strongly subscription updates are throttled under UI pressure.
+final PressureThrottle pressureThrottle;
+/// Directory below which on-disk state is partitioned by deployment and
+/// auth identity. No state is written to disk when `None`.
+final String? storageRoot;
+/// Serves the client metrics in Prometheus text format on
+/// `127.0.0.1:<port>`. Only honoured in debug builds.
+final int? debugMetricsPort;
+/// Fallback deployments to switch to when the primary is unreachable.
+/// Failover is disabled when `None`.
+final FailoverOptions? failover;
+/// Declares that the app intentionally runs without authentication.
+/// Auth tokens and token refresh are rejected, `is_authenticated` is
+/// always `false`, and no warnings about missing auth are logged.
+final bool unauthenticated;
+/// How `Int64` values appear in JSON results and subscription updates.
+final Int64Encoding int64Encoding;
+/// How arguments with empty or whitespace-only JSON text are treated.
+final BlankArgHandling blankArgs;
+/// How building the Convex client is retried when it fails.
+final ConnectRetryOptions connectRetry;
+/// Window in milliseconds within which new subscriptions are collected
+/// and established together. `None` establishes each one immediately.
+final BigInt? subscribeBatchWindowMs;
+/// Checks on connect whether the backend changed since the last run.
+/// Disabled when `None`.
+final SchemaCheckOptions? schemaCheck;
+/// Keeps all client state in memory, e.g. for privacy-sensitive flows
+/// and shared-device kiosk sessions. [`Self::storage_root`] and
+/// [`Self::audit_log`] are ignored and nothing is written to disk, nor
+/// to the log file of [`crate::MobileConvexClient::set_log_file`] while
+/// the client exists.
+final bool incognito;
+/// Conversions of custom Dart types in arguments and results, as
+/// described in [`crate::codecs`].
+final List<TypeCodec> typeCodecs;
+/// Per-function limits on calls and bandwidth. Unlimited when `None`.
+final UsageBudget? usageBudget;
+/// Shares one server subscription among subscriptions of the same
+/// function with equal arguments. A later subscriber receives the latest
+/// result right away; the shared subscription keeps the priority of the
+/// first one and is cancelled with the last one.
+final bool shareSubscriptions;
+/// Limits of the queue of [`crate::MobileConvexClient::mutation_deferred`].
+final DeferredMutationOptions deferredMutations;
+/// Time in milliseconds after which a build of the Convex client still
+/// in progress is reported through
+/// [`crate::MobileConvexClient::on_slow_initialization`]. Not reported
+/// when `None`.
+final BigInt? slowInitializationMs;
+/// Which telemetry events are recorded; all of them by default. Can be
+/// changed later with
+/// [`crate::MobileConvexClient::set_telemetry_sampling`].
+final TelemetrySampling telemetrySampling;
+/// How [`crate::MobileConvexClient::connect_preview`] finds preview
+/// deployments.
+final PreviewOptions previews;
+/// Backoff and idempotency key of retried mutations, see
+/// [`crate::retry`].
+final MutationRetryOptions mutationRetry;
+/// Limits of the last subscription results kept for
+/// [`crate::MobileConvexClient::get_cached`] and placeholders.
+final ResultCacheOptions resultCache;
+/// Persists the last subscription results for the next app start, see
+/// [`crate::persisted_results`]. Requires [`Self::storage_root`].
+final PersistedResultOptions? resultCachePersistence;
+/// Writes no files until [`crate::MobileConvexClient::set_storage_key`]
+/// was called, so nothing is stored in plaintext.
+final bool requireStorageKey;
+/// Site URL serving the deployment's HTTP actions, see
+/// [`crate::http_actions`]. Derived from `*.convex.cloud` deployment URLs
+/// when `None`.
+final String? httpActionsUrl;
+/// How [`crate::MobileConvexClient::upload_file`] gets upload URLs.
+final FileStorageOptions fileStorage;
+/// When [`crate::MobileConvexClient::query_streamed`] and
+/// [`crate::MobileConvexClient::subscribe_chunked`] split results.
+final ChunkedResultOptions chunkedResults;
+/// How the request IDs of [`crate::request_ids`] are reported.
+final RequestIdOptions requestIds;
+
+                const ClientOptions({required this.nullHandling ,this.maxConcurrentResubscribes ,this.auditLog ,required this.pressureThrottle ,this.storageRoot ,this.debugMetricsPort ,this.failover ,required this.unauthenticated ,required this.int64Encoding ,required this.blankArgs ,required this.connectRetry ,this.subscribeBatchWindowMs ,this.schemaCheck ,required this.incognito ,required this.typeCodecs ,this.usageBudget ,required this.shareSubscriptions ,required this.deferredMutations ,this.slowInitializationMs ,required this.telemetrySampling ,required this.previews ,required this.mutationRetry ,required this.resultCache ,this.resultCachePersistence ,required this.requireStorageKey ,this.httpActionsUrl ,required this.fileStorage ,required this.chunkedResults ,required this.requestIds ,});
+
+                static Future<ClientOptions>  default_()=>RustLib.instance.api.crateOptionsClientOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => nullHandling.hashCode^maxConcurrentResubscribes.hashCode^auditLog.hashCode^pressureThrottle.hashCode^storageRoot.hashCode^debugMetricsPort.hashCode^failover.hashCode^unauthenticated.hashCode^int64Encoding.hashCode^blankArgs.hashCode^connectRetry.hashCode^subscribeBatchWindowMs.hashCode^schemaCheck.hashCode^incognito.hashCode^typeCodecs.hashCode^usageBudget.hashCode^shareSubscriptions.hashCode^deferredMutations.hashCode^slowInitializationMs.hashCode^telemetrySampling.hashCode^previews.hashCode^mutationRetry.hashCode^resultCache.hashCode^resultCachePersistence.hashCode^requireStorageKey.hashCode^httpActionsUrl.hashCode^fileStorage.hashCode^chunkedResults.hashCode^requestIds.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ClientOptions &&
+                runtimeType == other.runtimeType
+                && nullHandling == other.nullHandling&& maxConcurrentResubscribes == other.maxConcurrentResubscribes&& auditLog == other.auditLog&& pressureThrottle == other.pressureThrottle&& storageRoot == other.storageRoot&& debugMetricsPort == other.debugMetricsPort&& failover == other.failover&& unauthenticated == other.unauthenticated&& int64Encoding == other.int64Encoding&& blankArgs == other.blankArgs&& connectRetry == other.connectRetry&& subscribeBatchWindowMs == other.subscribeBatchWindowMs&& schemaCheck == other.schemaCheck&& incognito == other.incognito&& typeCodecs == other.typeCodecs&& usageBudget == other.usageBudget&& shareSubscriptions == other.shareSubscriptions&& deferredMutations == other.deferredMutations&& slowInitializationMs == other.slowInitializationMs&& telemetrySampling == other.telemetrySampling&& previews == other.previews&& mutationRetry == other.mutationRetry&& resultCache == other.resultCache&& resultCachePersistence == other.resultCachePersistence&& requireStorageKey == other.requireStorageKey&& httpActionsUrl == other.httpActionsUrl&& fileStorage == other.fileStorage&& chunkedResults == other.chunkedResults&& requestIds == other.requestIds;
+        
+            }
+
+/// How `Int64` values are written into JSON results.
+///
+/// JSON numbers lose precision beyond 2^53 in many decoders, so Convex's
+/// tagged form is the default. Structured [`crate::convex_value::ConvexValue`]
+/// results carry exact 64-bit integers regardless of this setting, except
+/// for updates of [`crate::MobileConvexClient::subscribe_typed`] with
+/// [`Int64Encoding::String`].
+enum Int64Encoding {
+                    /// `{"$integer": "<base64 little-endian>"}`, as in Convex's JSON format.
+tagged,
+/// A plain JSON integer, decoded as a Dart `int` on native platforms.
+number,
+/// A decimal string, suitable for `BigInt.parse`.
+string,
+                    ;
+                    static Future<Int64Encoding>  default_()=>RustLib.instance.api.crateOptionsInt64EncodingDefault();
+
+
+                }
+
+/// How `null` values in function arguments are sent to Convex.
+///
+/// Convex distinguishes an absent field from a field set to `null`. Dart has
+/// no `undefined`, so apps that build partial updates from nullable fields can
+/// opt into treating `null` object fields as absent. Independently of this
+/// setting, the JSON marker `{"$undefined": true}` always stands for
+/// `undefined`: it is omitted from objects and becomes `null` inside arrays,
+/// matching `JSON.stringify`.
+///
+/// Results are never rewritten: absent fields stay absent and explicit
+/// `null`s stay `null` in the serialized JSON.
+enum NullHandling {
+                    /// `null` is sent as an explicit Convex `null`.
+preserve,
+/// Object fields (including top-level arguments) set to `null` are omitted.
+omitNullFields,
+                    ;
+                    static Future<NullHandling>  default_()=>RustLib.instance.api.crateOptionsNullHandlingDefault();
+
+
+                }
+            
\ No newline at end of file
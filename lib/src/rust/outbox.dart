@@ -0,0 +1,112 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'outbox.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `decode`, `encode`, `first`, `insert`, `len`, `migrate`, `new`, `open`, `path`, `push`, `remove`, `replace_args`, `replay`, `resolve`, `send_queued`, `start_outbox_replay`, `wake_replay`, `with_store_blocking`, `with_store`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `OutboxEntry`, `Outbox`, `Resolved`, `Store`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            @freezed
+                sealed class ConflictResolution with _$ConflictResolution  {
+                    const ConflictResolution._();
+
+                     /// Keeps it first in the outbox, to be sent again on the next replay,
+/// i.e. when the WebSocket reconnects or another mutation is queued.
+const factory ConflictResolution.retry() = ConflictResolution_Retry;
+ /// Removes it, reporting its error as its completion.
+const factory ConflictResolution.drop() = ConflictResolution_Drop;
+ /// Sends it again right away with `args`, given as for
+/// [`MobileConvexClient::mutation`].
+const factory ConflictResolution.transform({   required Map<String, String> args , }) = ConflictResolution_Transform;
+
+                    
+
+                    
+                }
+
+@freezed
+                sealed class OfflineMutationResult with _$OfflineMutationResult  {
+                    const OfflineMutationResult._();
+
+                     /// Sent and answered; `value` is the mutation's return value.
+const factory OfflineMutationResult.completed({   required String value , }) = OfflineMutationResult_Completed;
+ /// Queued in the outbox; its completion is reported under `outbox_id`.
+const factory OfflineMutationResult.queued({   required String outboxId , }) = OfflineMutationResult_Queued;
+
+                    
+
+                    
+                }
+
+/// The outcome of a mutation replayed from the outbox.
+class OutboxCompletion  {
+                final String outboxId;
+/// Name of the mutation function.
+final String name;
+/// The mutation's return value, serialized as JSON, if it succeeded.
+final String? value;
+/// Why the mutation failed, if it did.
+final String? error;
+
+                const OutboxCompletion({required this.outboxId ,required this.name ,this.value ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => outboxId.hashCode^name.hashCode^value.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OutboxCompletion &&
+                runtimeType == other.runtimeType
+                && outboxId == other.outboxId&& name == other.name&& value == other.value&& error == other.error;
+        
+            }
+
+/// A replayed mutation rejected by the function, as passed to the resolver
+/// registered with [`MobileConvexClient::on_outbox_conflict`].
+class OutboxConflict  {
+                final String outboxId;
+/// Name of the mutation function.
+final String name;
+/// The queued arguments, serialized as a JSON object.
+final String args;
+/// Why the function rejected the mutation.
+final String error;
+/// The serialized ConvexError payload, if the function threw one.
+final String? data;
+
+                const OutboxConflict({required this.outboxId ,required this.name ,required this.args ,required this.error ,this.data ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => outboxId.hashCode^name.hashCode^args.hashCode^error.hashCode^data.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OutboxConflict &&
+                runtimeType == other.runtimeType
+                && outboxId == other.outboxId&& name == other.name&& args == other.args&& error == other.error&& data == other.data;
+        
+            }
+            
\ No newline at end of file
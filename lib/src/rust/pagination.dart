@@ -0,0 +1,81 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `add_page`, `boundaries`, `load_more`, `new`, `on_page_result`, `on_result`, `parse`, `restore`, `resubscribe`, `results_json`, `results`, `run`, `start_paginated`, `status`, `subscribe_page`, `subscribe`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PageCommand`, `PageResult`, `Page`, `PaginationEvent`, `PaginationTask`, `Pagination`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PaginatedQueryHandle>>
+                abstract class PaginatedQueryHandle implements RustOpaqueInterface {
+                    /// Cancels all page subscriptions.
+ void  cancel();
+
+
+/// Requests another page of `num_items` items. Returns `false` if no
+/// more items exist, a page is still loading or the subscription was
+/// cancelled.
+ bool  loadMore({required int numItems });
+
+
+/// Returns the current loading state.
+ PaginationStatus  status();
+
+
+
+                    
+                }
+                
+
+/// The merged results of a paginated subscription.
+class PaginatedUpdate  {
+                /// The items of all loaded pages, in order, as a JSON array.
+final String results;
+final PaginationStatus status;
+/// The end cursors of all pages but the last, to pass to
+/// [`MobileConvexClient::subscribe_paginated_from`].
+final List<String> pageBoundaries;
+
+                const PaginatedUpdate({required this.results ,required this.status ,required this.pageBoundaries ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => results.hashCode^status.hashCode^pageBoundaries.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PaginatedUpdate &&
+                runtimeType == other.runtimeType
+                && results == other.results&& status == other.status&& pageBoundaries == other.pageBoundaries;
+        
+            }
+
+/// Loading state of a paginated subscription.
+enum PaginationStatus {
+                    /// The first page has not been loaded yet.
+loadingFirstPage,
+/// All requested pages are loaded and more items exist.
+canLoadMore,
+/// A page requested with `load_more` is still loading.
+loadingMore,
+/// All items are loaded.
+exhausted,
+                    ;
+                    
+                }
+            
\ No newline at end of file
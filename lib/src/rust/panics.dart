@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `catch_panics_sync`, `catch_panics`, `install`, `of`, `panic_error`, `panic_message`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+
+
+            
+
+            /// A panic in the client.
+class PanicReport  {
+                final String message;
+/// Source location of the panic, e.g. `src/args.rs:42:17`.
+final String? location;
+/// Name of the panicking thread, if it has one.
+final String? thread;
+final String backtrace;
+final PlatformInt64 timestampMs;
+
+                const PanicReport({required this.message ,this.location ,this.thread ,required this.backtrace ,required this.timestampMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => message.hashCode^location.hashCode^thread.hashCode^backtrace.hashCode^timestampMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PanicReport &&
+                runtimeType == other.runtimeType
+                && message == other.message&& location == other.location&& thread == other.thread&& backtrace == other.backtrace&& timestampMs == other.timestampMs;
+        
+            }
+            
\ No newline at end of file
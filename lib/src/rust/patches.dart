@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'patches.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `next`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PatchEvent`, `PatchState`, `PatchSubscriber`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `on_error`, `on_update`
+
+
+            
+
+            @freezed
+                sealed class PatchUpdate with _$PatchUpdate  {
+                    const PatchUpdate._();
+
+                     /// The full value, serialized as JSON; replaces what the app holds.
+const factory PatchUpdate.snapshot({   required String value , }) = PatchUpdate_Snapshot;
+ /// A JSON Patch, serialized as JSON, to apply to the value held by the
+/// app.
+const factory PatchUpdate.patch({   required String operations , }) = PatchUpdate_Patch;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
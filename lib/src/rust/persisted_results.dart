@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `file`, `forget_loaded`, `is_fresh`, `load`, `new`, `save`, `size`, `spawn_flush_loop`, `take_unloaded`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PersistedResult`, `ResultPersistence`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// How subscription results are persisted.
+class PersistedResultOptions  {
+                /// Age in milliseconds after which a persisted result is dropped.
+final BigInt ttlMs;
+/// Approximate size in bytes of the results file.
+final BigInt maxBytes;
+/// Time between writes of changed results.
+final BigInt flushIntervalMs;
+
+                const PersistedResultOptions({required this.ttlMs ,required this.maxBytes ,required this.flushIntervalMs ,});
+
+                static Future<PersistedResultOptions>  default_()=>RustLib.instance.api.cratePersistedResultsPersistedResultOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => ttlMs.hashCode^maxBytes.hashCode^flushIntervalMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PersistedResultOptions &&
+                runtimeType == other.runtimeType
+                && ttlMs == other.ttlMs&& maxBytes == other.maxBytes&& flushIntervalMs == other.flushIntervalMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,81 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'placeholder.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `cache_key`, `caching_subscriber`, `clear`, `configure`, `emit`, `entry_size`, `evict`, `flush`, `get`, `insert`, `new`, `placeholder`, `release`, `restore`, `store`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CachedValue`, `CachingSubscriber`, `Entries`, `LastValues`, `WatchSubscriber`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `on_done`, `on_error`, `on_error`, `on_update`, `on_update`
+
+
+            
+
+            /// What [`MobileConvexClient::watch`] emits before the first server value.
+enum PlaceholderPolicy {
+                    /// The last known value as [`WatchEvent::Cached`], or
+/// [`WatchEvent::Loading`] if there is none.
+cached,
+/// [`WatchEvent::Loading`], even if a value is cached.
+loading,
+/// Nothing; the first event is the first server value or error.
+wait,
+                    ;
+                    static Future<PlaceholderPolicy>  default_()=>RustLib.instance.api.cratePlaceholderPlaceholderPolicyDefault();
+
+
+                }
+
+/// Limits of the last subscription results kept by the client. The least
+/// recently updated results are evicted first.
+class ResultCacheOptions  {
+                /// Number of results kept; `0` disables the cache.
+final int maxEntries;
+/// Approximate total size in bytes of the results kept.
+final BigInt maxBytes;
+
+                const ResultCacheOptions({required this.maxEntries ,required this.maxBytes ,});
+
+                static Future<ResultCacheOptions>  default_()=>RustLib.instance.api.cratePlaceholderResultCacheOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxEntries.hashCode^maxBytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ResultCacheOptions &&
+                runtimeType == other.runtimeType
+                && maxEntries == other.maxEntries&& maxBytes == other.maxBytes;
+        
+            }
+
+@freezed
+                sealed class WatchEvent with _$WatchEvent  {
+                    const WatchEvent._();
+
+                     /// No value is available yet.
+const factory WatchEvent.loading() = WatchEvent_Loading;
+ /// A cached value, shown until the server's value arrives.
+const factory WatchEvent.cached({   required String value , }) = WatchEvent_Cached;
+ /// A value from the server, serialized as JSON.
+const factory WatchEvent.update({   required String value , }) = WatchEvent_Update;
+ /// The query failed; `data` holds the serialized ConvexError payload, if any.
+const factory WatchEvent.error({   required String message ,  String? data , }) = WatchEvent_Error;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
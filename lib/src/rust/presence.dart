@@ -0,0 +1,95 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `evaluate_presence`, `extract_heartbeats`, `now_millis`, `value_as_millis`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PresenceHandle>>
+                abstract class PresenceHandle implements RustOpaqueInterface {
+                    /// Returns whether heartbeats are currently paused by the caller.
+ bool  isPaused();
+
+
+/// Pauses heartbeats, e.g. when the app moves to the background.
+ void  pause();
+
+
+/// Resumes heartbeats and sends one immediately.
+ void  resume();
+
+
+/// Stops the heartbeat loop.
+ void  stop();
+
+
+
+                    
+                }
+                
+
+/// Options controlling how heartbeat documents are interpreted.
+class PresenceOptions  {
+                /// Field holding the user identifier in each heartbeat document.
+final String userField;
+/// Field holding the last heartbeat time (server milliseconds since epoch).
+final String lastSeenField;
+/// A user is online if their last heartbeat is at most this old.
+final BigInt onlineThresholdMs;
+
+                const PresenceOptions({required this.userField ,required this.lastSeenField ,required this.onlineThresholdMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => userField.hashCode^lastSeenField.hashCode^onlineThresholdMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PresenceOptions &&
+                runtimeType == other.runtimeType
+                && userField == other.userField&& lastSeenField == other.lastSeenField&& onlineThresholdMs == other.onlineThresholdMs;
+        
+            }
+
+/// Online/offline status of a single user derived from their last heartbeat.
+class PresenceStatus  {
+                final String userId;
+final bool isOnline;
+/// Last heartbeat time in server milliseconds since epoch.
+final PlatformInt64 lastSeenMs;
+
+                const PresenceStatus({required this.userId ,required this.isOnline ,required this.lastSeenMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => userId.hashCode^isOnline.hashCode^lastSeenMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PresenceStatus &&
+                runtimeType == other.runtimeType
+                && userId == other.userId&& isOnline == other.isOnline&& lastSeenMs == other.lastSeenMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,58 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `coalesce_updates`, `deliver`, `interval`, `throttle_subscriber`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ThrottledSubscriber`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `on_done`, `on_error`, `on_update`
+
+
+            
+
+            /// Minimum time between two delivered updates of a subscription per pressure level.
+class PressureThrottle  {
+                final BigInt moderateIntervalMs;
+final BigInt highIntervalMs;
+
+                const PressureThrottle({required this.moderateIntervalMs ,required this.highIntervalMs ,});
+
+                static Future<PressureThrottle>  default_()=>RustLib.instance.api.cratePressurePressureThrottleDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => moderateIntervalMs.hashCode^highIntervalMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PressureThrottle &&
+                runtimeType == other.runtimeType
+                && moderateIntervalMs == other.moderateIntervalMs&& highIntervalMs == other.highIntervalMs;
+        
+            }
+
+/// UI load reported by the app.
+enum UiPressure {
+                    /// Frames are rendered on time; updates are delivered at full rate.
+normal,
+/// Some frames are dropped.
+moderate,
+/// Most frames are dropped.
+high,
+                    ;
+                    static Future<UiPressure>  default_()=>RustLib.instance.api.cratePressureUiPressureDefault();
+
+
+                }
+            
\ No newline at end of file
@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `is_deployment_name`, `pull_request_alias`, `resolve_preview`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            
+
+            /// How preview deployments are found.
+class PreviewOptions  {
+                /// URL of a deployment with `{name}` standing for the deployment name.
+final String urlTemplate;
+/// Deployment names or URLs by alias. PR identifiers are looked up as
+/// `pr-<number>`.
+final Map<String, String> aliases;
+
+                const PreviewOptions({required this.urlTemplate ,required this.aliases ,});
+
+                static Future<PreviewOptions>  default_()=>RustLib.instance.api.cratePreviewPreviewOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => urlTemplate.hashCode^aliases.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PreviewOptions &&
+                runtimeType == other.runtimeType
+                && urlTemplate == other.urlTemplate&& aliases == other.aliases;
+        
+            }
+            
\ No newline at end of file
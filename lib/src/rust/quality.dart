@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `current`, `new`, `publish`, `record_call`, `record_state`, `snapshot`, `subscribe`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `QualityState`, `QualityTracker`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// Snapshot of the rolling connection-quality estimate.
+class ConnectionQuality  {
+                /// 0 (unusable) to 100 (perfect).
+final int score;
+final ConnectionQualityLevel level;
+/// Moving average of call round-trip times in milliseconds.
+final double rttMs;
+/// Moving average of the fraction of calls that failed in transport.
+final double failureRate;
+/// Reconnects observed during the last five minutes.
+final int recentReconnects;
+
+                const ConnectionQuality({required this.score ,required this.level ,required this.rttMs ,required this.failureRate ,required this.recentReconnects ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => score.hashCode^level.hashCode^rttMs.hashCode^failureRate.hashCode^recentReconnects.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ConnectionQuality &&
+                runtimeType == other.runtimeType
+                && score == other.score&& level == other.level&& rttMs == other.rttMs&& failureRate == other.failureRate&& recentReconnects == other.recentReconnects;
+        
+            }
+
+/// Coarse classification of [`ConnectionQuality::score`].
+enum ConnectionQualityLevel {
+                    excellent,
+good,
+fair,
+poor,
+/// The WebSocket is not connected.
+offline,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,32 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'query_cache.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `cache_key`, `clear`, `declare`, `get`, `invalidate_after_mutation`, `invalidate`, `is_invalidated`, `refetch`, `release`, `store`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CacheEntry`, `CacheState`, `QueryCache`, `Refetch`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`, `hash`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`
+
+
+            
+
+            @freezed
+                sealed class QueryDependency with _$QueryDependency  {
+                    const QueryDependency._();
+
+                     /// Invalidate after a successful call of this mutation.
+const factory QueryDependency.mutation({   required String name , }) = QueryDependency_Mutation;
+ /// Invalidate whenever this query is invalidated.
+const factory QueryDependency.query({   required String name , }) = QueryDependency_Query;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
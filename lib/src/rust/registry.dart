@@ -0,0 +1,50 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `active`, `args_hash`, `cancel_all`, `new`, `register`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CountingSubscriber`, `RegisteredSubscription`, `SubscriptionRegistry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `on_done`, `on_error`, `on_update`
+
+
+            
+
+            /// A live subscription, as listed by
+/// [`MobileConvexClient::active_subscriptions`].
+class SubscriptionInfo  {
+                /// Registration order, unique per client.
+final BigInt id;
+final String name;
+/// Short hash of the arguments, equal for equal arguments.
+final String argsHash;
+/// Unix time in milliseconds of subscribing.
+final PlatformInt64 createdAtMs;
+/// Updates delivered so far.
+final BigInt updateCount;
+
+                const SubscriptionInfo({required this.id ,required this.name ,required this.argsHash ,required this.createdAtMs ,required this.updateCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^argsHash.hashCode^createdAtMs.hashCode^updateCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SubscriptionInfo &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& argsHash == other.argsHash&& createdAtMs == other.createdAtMs&& updateCount == other.updateCount;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `annotate`, `new_request_id`, `send_with_request_id`, `with_request_id`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            
+
+            /// Where request IDs are reported.
+class RequestIdOptions  {
+                /// Whether error messages end with the request ID.
+final bool inErrors;
+/// Argument receiving the request ID, e.g. `requestId`. Not sent when
+/// `None`.
+final String? argName;
+
+                const RequestIdOptions({required this.inErrors ,this.argName ,});
+
+                static Future<RequestIdOptions>  default_()=>RustLib.instance.api.crateRequestIdsRequestIdOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => inErrors.hashCode^argName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RequestIdOptions &&
+                runtimeType == other.runtimeType
+                && inErrors == other.inErrors&& argName == other.argName;
+        
+            }
+            
\ No newline at end of file
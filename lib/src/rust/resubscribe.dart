@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `acquire`, `from_u8`, `new`, `release`, `run`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ManagedSubscription`, `ResubscribePermit`, `ResubscribeScheduler`, `SchedulerState`, `Waiter`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `cmp`, `cmp`, `drop`, `eq`, `eq`, `fmt`, `partial_cmp`, `partial_cmp`
+
+
+            
+
+            /// Order in which subscriptions are re-established after a reconnect.
+enum SubscriptionPriority {
+                    low,
+normal,
+/// Data shown on the visible screen.
+high,
+                    ;
+                    static Future<SubscriptionPriority>  default_()=>RustLib.instance.api.crateResubscribeSubscriptionPriorityDefault();
+
+
+                }
+            
\ No newline at end of file
@@ -0,0 +1,78 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `backoff`, `send_with_retries`, `with_idempotency_key`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// The result of [`MobileConvexClient::mutation_idempotent`].
+class IdempotentMutationResult  {
+                /// The mutation's return value, serialized as JSON.
+final String value;
+/// The key the mutation was sent with.
+final String idempotencyKey;
+/// Attempts it took, including the successful one.
+final int attempts;
+
+                const IdempotentMutationResult({required this.value ,required this.idempotencyKey ,required this.attempts ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => value.hashCode^idempotencyKey.hashCode^attempts.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is IdempotentMutationResult &&
+                runtimeType == other.runtimeType
+                && value == other.value&& idempotencyKey == other.idempotencyKey&& attempts == other.attempts;
+        
+            }
+
+/// Retry policy of [`MobileConvexClient::mutation_idempotent`] and
+/// [`MobileConvexClient::mutation_tracked`].
+class MutationRetryOptions  {
+                /// Delay before the first retry; doubled after every failed attempt.
+final BigInt initialBackoffMs;
+/// Upper bound for the delay between attempts.
+final BigInt maxBackoffMs;
+/// Attempts of an idempotent mutation, including the first one.
+final int maxAttempts;
+/// Argument receiving the idempotency key.
+final String idempotencyKeyArg;
+
+                const MutationRetryOptions({required this.initialBackoffMs ,required this.maxBackoffMs ,required this.maxAttempts ,required this.idempotencyKeyArg ,});
+
+                static Future<MutationRetryOptions>  default_()=>RustLib.instance.api.crateRetryMutationRetryOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => initialBackoffMs.hashCode^maxBackoffMs.hashCode^maxAttempts.hashCode^idempotencyKeyArg.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MutationRetryOptions &&
+                runtimeType == other.runtimeType
+                && initialBackoffMs == other.initialBackoffMs&& maxBackoffMs == other.maxBackoffMs&& maxAttempts == other.maxAttempts&& idempotencyKeyArg == other.idempotencyKeyArg;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,89 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`, `next_random`, `sample`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `TelemetrySampler`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `hash`
+
+
+            
+
+            /// The share of events of a class that are recorded.
+class ClassSampleRate  {
+                final TelemetryClass class_;
+/// From 0 (none) to 1 (all).
+final double rate;
+
+                const ClassSampleRate({required this.class_ ,required this.rate ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => class_.hashCode^rate.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ClassSampleRate &&
+                runtimeType == other.runtimeType
+                && class_ == other.class_&& rate == other.rate;
+        
+            }
+
+/// A kind of telemetry event.
+enum TelemetryClass {
+                    /// Entries of the audit log.
+audit,
+/// Call latency and failure samples feeding
+/// [`crate::quality::ConnectionQuality`] and the
+/// [`crate::call_metrics::MetricsReport`].
+callLatency,
+/// Runtime probe samples feeding [`crate::metrics::RuntimeMetrics`].
+runtimeProbe,
+                    ;
+                    
+                }
+
+/// Which telemetry events are recorded.
+class TelemetrySampling  {
+                /// Share of events recorded per class. Classes not listed are recorded
+/// in full.
+final List<ClassSampleRate> classRates;
+/// Share of events about a function that are recorded, by function
+/// name. Overrides the rate of the event's class.
+final Map<String, double> functionRates;
+/// Classes recorded in debug builds only.
+final List<TelemetryClass> debugOnly;
+
+                const TelemetrySampling({required this.classRates ,required this.functionRates ,required this.debugOnly ,});
+
+                static Future<TelemetrySampling>  default_()=>RustLib.instance.api.crateSamplingTelemetrySamplingDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => classRates.hashCode^functionRates.hashCode^debugOnly.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TelemetrySampling &&
+                runtimeType == other.runtimeType
+                && classRates == other.classRates&& functionRates == other.functionRates&& debugOnly == other.debugOnly;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,70 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`, `read_fingerprint`, `record`, `run`, `start_schema_check`, `with_storage`, `write_fingerprint`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SchemaCheck`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            
+
+            /// Emitted when the backend fingerprint differs from the one seen last.
+class BackendChangedEvent  {
+                final String previousFingerprint;
+final String currentFingerprint;
+/// Whether cached results were invalidated.
+final bool cacheInvalidated;
+
+                const BackendChangedEvent({required this.previousFingerprint ,required this.currentFingerprint ,required this.cacheInvalidated ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => previousFingerprint.hashCode^currentFingerprint.hashCode^cacheInvalidated.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BackendChangedEvent &&
+                runtimeType == other.runtimeType
+                && previousFingerprint == other.previousFingerprint&& currentFingerprint == other.currentFingerprint&& cacheInvalidated == other.cacheInvalidated;
+        
+            }
+
+/// How the backend is checked for changes.
+class SchemaCheckOptions  {
+                /// Query without arguments whose result identifies the backend version.
+final String fingerprintQuery;
+/// Whether cached query and action results are dropped on a change.
+final bool invalidateCache;
+
+                const SchemaCheckOptions({required this.fingerprintQuery ,required this.invalidateCache ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fingerprintQuery.hashCode^invalidateCache.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SchemaCheckOptions &&
+                runtimeType == other.runtimeType
+                && fingerprintQuery == other.fingerprintQuery&& invalidateCache == other.invalidateCache;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,85 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'sequence.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `failed_step`, `run_steps`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// One mutation of a sequence.
+class MutationCall  {
+                final String name;
+final Map<String, String> args;
+
+                const MutationCall({required this.name ,required this.args ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^args.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MutationCall &&
+                runtimeType == other.runtimeType
+                && name == other.name&& args == other.args;
+        
+            }
+
+/// Outcome of a whole mutation sequence.
+class MutationSequenceResult  {
+                /// One status per call, in order.
+final List<MutationStepStatus> steps;
+/// Whether every step succeeded.
+final bool succeeded;
+
+                const MutationSequenceResult({required this.steps ,required this.succeeded ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => steps.hashCode^succeeded.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MutationSequenceResult &&
+                runtimeType == other.runtimeType
+                && steps == other.steps&& succeeded == other.succeeded;
+        
+            }
+
+@freezed
+                sealed class MutationStepStatus with _$MutationStepStatus  {
+                    const MutationStepStatus._();
+
+                     /// The mutation returned `value`, serialized as JSON.
+const factory MutationStepStatus.succeeded({   required String value , }) = MutationStepStatus_Succeeded;
+ /// The mutation failed; `data` holds the serialized ConvexError payload,
+/// if any.
+const factory MutationStepStatus.failed({   required String message ,  String? data , }) = MutationStepStatus_Failed;
+ /// Not run because an earlier step failed.
+const factory MutationStepStatus.skipped() = MutationStepStatus_Skipped;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,78 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `dispatch`, `join`, `keys`, `leave`, `metrics`, `new`, `next_id`, `run_shard`, `subscribe_sharded`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Joined`, `NewShard`, `ShardRegistry`, `Shard`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            /// Maps a per-document query onto a backend batch query.
+class ShardMapping  {
+                /// Batch query serving many keys at once.
+final String batchQuery;
+/// Argument of the per-document query holding the document key.
+final String keyArg;
+/// Argument of the batch query receiving the array of keys.
+final String batchArg;
+/// Maximum number of keys per server subscription.
+final int maxKeysPerShard;
+
+                const ShardMapping({required this.batchQuery ,required this.keyArg ,required this.batchArg ,required this.maxKeysPerShard ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => batchQuery.hashCode^keyArg.hashCode^batchArg.hashCode^maxKeysPerShard.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ShardMapping &&
+                runtimeType == other.runtimeType
+                && batchQuery == other.batchQuery&& keyArg == other.keyArg&& batchArg == other.batchArg&& maxKeysPerShard == other.maxKeysPerShard;
+        
+            }
+
+/// Subscription counts with and without sharding.
+class ShardingMetrics  {
+                /// Sharded subscriptions requested by the app, i.e. the server
+/// subscriptions that would exist without sharding.
+final int logicalSubscriptions;
+/// Server subscriptions actually serving them.
+final int serverSubscriptions;
+
+                const ShardingMetrics({required this.logicalSubscriptions ,required this.serverSubscriptions ,});
+
+                static Future<ShardingMetrics>  default_()=>RustLib.instance.api.crateShardingShardingMetricsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => logicalSubscriptions.hashCode^serverSubscriptions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ShardingMetrics &&
+                runtimeType == other.runtimeType
+                && logicalSubscriptions == other.logicalSubscriptions&& serverSubscriptions == other.serverSubscriptions;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,46 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `begin_span`, `export_request`, `finish`, `name`, `run_export`, `start`, `status_name`, `to_otlp`, `traceparent`, `unix_nanos`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CallSpan`, `FinishedSpan`, `SpanOperation`, `TraceExporter`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// Where and how spans are exported.
+class TraceExportOptions  {
+                /// URL the spans are posted to, e.g.
+/// `https://collector.example.com/v1/traces`.
+final String endpoint;
+/// Headers sent with every export, e.g. an API key.
+final Map<String, String> headers;
+/// `service.name` of the exported spans, e.g. the app's name.
+final String serviceName;
+
+                const TraceExportOptions({required this.endpoint ,required this.headers ,required this.serviceName ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => endpoint.hashCode^headers.hashCode^serviceName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TraceExportOptions &&
+                runtimeType == other.runtimeType
+                && endpoint == other.endpoint&& headers == other.headers&& serviceName == other.serviceName;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,113 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'backpressure.dart';
+import 'convex_value.dart';
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'resubscribe.dart';
+part 'subscription.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `close`, `new`, `on_connection_change`, `on_delivery`, `on_pause_change`, `on_result`, `validate`, `with_int64_encoding`, `wrap`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EventForwarder`, `SubscriptionStateMachine`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `on_done`, `on_error`, `on_update`
+
+
+            
+
+            /// Options of [`crate::MobileConvexClient::subscribe`] and
+/// [`crate::MobileConvexClient::subscribe_with_events`]. They can be
+/// combined freely; the defaults deliver every result as it arrives.
+class SubscribeOptions  {
+                /// Order in which the subscription is re-established after a reconnect.
+/// Only takes effect when
+/// [`crate::options::ClientOptions::max_concurrent_resubscribes`] is set.
+final SubscriptionPriority priority;
+/// Arguments with structured values, added to the JSON arguments.
+final Map<String, ConvexValue> valueArgs;
+/// Skips updates identical to the one delivered before, as described in
+/// the [distinct docs](crate::distinct).
+final bool distinct;
+/// JSON pointers selecting the fields to deliver, as described in the
+/// [projection docs](crate::projection). Updates leaving them unchanged
+/// are skipped.
+final List<String>? projection;
+/// How updates arriving faster than `on_update` completes are
+/// delivered. Not supported by `subscribe_with_events`, whose callback
+/// is always awaited before the next event.
+final Backpressure? backpressure;
+
+                const SubscribeOptions({required this.priority ,required this.valueArgs ,required this.distinct ,this.projection ,this.backpressure ,});
+
+                static Future<SubscribeOptions>  default_()=>RustLib.instance.api.crateSubscriptionSubscribeOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => priority.hashCode^valueArgs.hashCode^distinct.hashCode^projection.hashCode^backpressure.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SubscribeOptions &&
+                runtimeType == other.runtimeType
+                && priority == other.priority&& valueArgs == other.valueArgs&& distinct == other.distinct&& projection == other.projection&& backpressure == other.backpressure;
+        
+            }
+
+/// Why a subscription stopped producing events.
+enum SubscriptionCloseReason {
+                    /// The subscription was cancelled through its handle.
+cancelled,
+/// The underlying subscription stream ended; no more updates will arrive.
+streamEnded,
+                    ;
+                    
+                }
+
+@freezed
+                sealed class SubscriptionEvent with _$SubscriptionEvent  {
+                    const SubscriptionEvent._();
+
+                     /// A new result value, serialized as JSON.
+const factory SubscriptionEvent.update({   required String value , }) = SubscriptionEvent_Update;
+ /// The query failed; `data` holds the serialized ConvexError payload, if any.
+const factory SubscriptionEvent.error({   required String message ,  String? data , }) = SubscriptionEvent_Error;
+ /// The connection was restored and the subscription is live again.
+const factory SubscriptionEvent.resubscribed() = SubscriptionEvent_Resubscribed;
+ /// Delivery is suspended by [`crate::MobileConvexClient::pause`]; no
+/// updates until resumed.
+const factory SubscriptionEvent.paused() = SubscriptionEvent_Paused;
+ /// [`crate::MobileConvexClient::resume`] re-establishes the subscription
+/// after a pause.
+const factory SubscriptionEvent.resumed() = SubscriptionEvent_Resumed;
+ /// The connection was lost; the last delivered value may be outdated.
+const factory SubscriptionEvent.stale() = SubscriptionEvent_Stale;
+ /// The subscription has ended. This is always the last event.
+const factory SubscriptionEvent.closed({   required SubscriptionCloseReason reason , }) = SubscriptionEvent_Closed;
+
+                    
+
+                    
+                }
+
+/// Current state of a subscription, as reported by its handle.
+enum SubscriptionState {
+                    /// Updates are still being delivered.
+active,
+/// The subscription stream ended; no more updates will arrive.
+ended,
+/// The subscription was cancelled.
+cancelled,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'convex_value.dart';
+import 'errors.dart';
+import 'frb_generated.dart';
+import 'lib.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SinkSubscriber`
+// These functions are ignored (category: IgnoreBecauseNotAllowedOwner): `send_error`, `send_value`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `on_error`, `on_update`
+
+
+            
+
+            
+                abstract class EventSink {
+                     Future<void>  sendError({required ClientError error });
+
+
+ Future<void>  sendValue({required String value });
+
+
+                }
+                
+            
\ No newline at end of file
@@ -0,0 +1,94 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `answered`, `attach`, `is_current`, `missed`, `orphan`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `DartSupervisor`, `SupervisorState`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            /// How the Dart keepalive callback is pinged.
+class DartKeepaliveOptions  {
+                /// Time between pings.
+final BigInt intervalMs;
+/// Time a ping may take before it counts as missed.
+final BigInt timeoutMs;
+/// Missed pings in a row after which the isolate is considered gone.
+final int maxMissedPings;
+
+                const DartKeepaliveOptions({required this.intervalMs ,required this.timeoutMs ,required this.maxMissedPings ,});
+
+                static Future<DartKeepaliveOptions>  default_()=>RustLib.instance.api.crateSupervisorDartKeepaliveOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => intervalMs.hashCode^timeoutMs.hashCode^maxMissedPings.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DartKeepaliveOptions &&
+                runtimeType == other.runtimeType
+                && intervalMs == other.intervalMs&& timeoutMs == other.timeoutMs&& maxMissedPings == other.maxMissedPings;
+        
+            }
+
+/// Liveness of the Dart isolate, as returned by
+/// [`MobileConvexClient::dart_liveness`].
+class DartLiveness  {
+                final DartLivenessState state;
+/// Unix time in milliseconds of the last answered ping, if any.
+final PlatformInt64? lastResponseMs;
+/// Unanswered pings in a row.
+final int missedPings;
+/// Subscriptions paused when the isolate was considered gone.
+final int orphanedSubscriptions;
+
+                const DartLiveness({required this.state ,this.lastResponseMs ,required this.missedPings ,required this.orphanedSubscriptions ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => state.hashCode^lastResponseMs.hashCode^missedPings.hashCode^orphanedSubscriptions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DartLiveness &&
+                runtimeType == other.runtimeType
+                && state == other.state&& lastResponseMs == other.lastResponseMs&& missedPings == other.missedPings&& orphanedSubscriptions == other.orphanedSubscriptions;
+        
+            }
+
+/// Whether the attached Dart isolate responds.
+enum DartLivenessState {
+                    /// No keepalive callback was attached.
+unsupervised,
+/// The last ping was answered.
+alive,
+/// Recent pings went unanswered.
+unresponsive,
+/// The isolate is considered gone and subscriptions are paused until
+/// another isolate attaches.
+orphaned,
+                    ;
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,74 @@
+#![no_main]
+
+use std::collections::HashMap;
+
+use arbitrary::Arbitrary;
+use convex_flutter::{convex_value::ConvexValue, fuzzing, options::NullHandling};
+use libfuzzer_sys::fuzz_target;
+
+/// An arbitrary [`ConvexValue`] tree.
+#[derive(Debug, Arbitrary)]
+enum Tree {
+    Null,
+    Bool(bool),
+    Int64(i64),
+    Float64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Array(Vec<Tree>),
+    Object(HashMap<String, Tree>),
+}
+
+impl From<Tree> for ConvexValue {
+    fn from(tree: Tree) -> Self {
+        match tree {
+            Tree::Null => ConvexValue::Null,
+            Tree::Bool(b) => ConvexValue::Bool(b),
+            Tree::Int64(i) => ConvexValue::Int64(i),
+            Tree::Float64(f) => ConvexValue::Float64(f),
+            Tree::String(s) => ConvexValue::String(s),
+            Tree::Bytes(b) => ConvexValue::Bytes(b),
+            Tree::Array(items) => ConvexValue::Array(items.into_iter().map(Into::into).collect()),
+            Tree::Object(fields) => ConvexValue::Object(
+                fields
+                    .into_iter()
+                    .map(|(name, value)| (name, value.into()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    args: HashMap<String, Tree>,
+    // Wraps every argument in this many single-element arrays or objects,
+    // which reaches the nesting limit without huge inputs.
+    nesting: u8,
+    wrap_in_objects: bool,
+    omit_null_fields: bool,
+}
+
+fuzz_target!(|input: Input| {
+    let null_handling = if input.omit_null_fields {
+        NullHandling::OmitNullFields
+    } else {
+        NullHandling::Preserve
+    };
+    let args = input
+        .args
+        .into_iter()
+        .map(|(name, tree)| {
+            let mut value = ConvexValue::from(tree);
+            for _ in 0..input.nesting {
+                value = if input.wrap_in_objects {
+                    ConvexValue::Object(HashMap::from([("a".to_owned(), value)]))
+                } else {
+                    ConvexValue::Array(vec![value])
+                };
+            }
+            (name, value)
+        })
+        .collect();
+    fuzzing::convex_args(args, null_handling);
+});
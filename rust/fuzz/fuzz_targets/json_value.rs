@@ -0,0 +1,8 @@
+#![no_main]
+
+use convex_flutter::fuzzing;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|json: &str| {
+    fuzzing::json_value_round_trip(json);
+});
@@ -0,0 +1,8 @@
+#![no_main]
+
+use convex_flutter::fuzzing;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|token: &str| {
+    let _ = fuzzing::decode_jwt_expiry(token);
+});
@@ -0,0 +1,22 @@
+#![no_main]
+
+use std::collections::HashMap;
+
+use arbitrary::Arbitrary;
+use convex_flutter::{fuzzing, options::NullHandling};
+use libfuzzer_sys::fuzz_target;
+
+#[derive(Debug, Arbitrary)]
+struct Input {
+    args: HashMap<String, String>,
+    omit_null_fields: bool,
+}
+
+fuzz_target!(|input: Input| {
+    let null_handling = if input.omit_null_fields {
+        NullHandling::OmitNullFields
+    } else {
+        NullHandling::Preserve
+    };
+    let _ = fuzzing::parse_json_args(input.args, null_handling);
+});
@@ -0,0 +1,153 @@
+//! Client-side memoization of action results.
+//!
+//! Actions are not reactive, so their results cannot be kept fresh by the
+//! server. For actions proxying slow or billed third-party APIs (e.g.
+//! geocoding), [`MobileConvexClient::cached_action`] remembers successful
+//! results per function name and arguments for a caller-specified TTL.
+//! Errors are never cached.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::{Duration, Instant},
+};
+
+use convex::{FunctionResult, Value};
+use flutter_rust_bridge::frb;
+use parking_lot::Mutex;
+
+use crate::{
+    events::ClientEvent,
+    value::{value_to_json_string, value_to_json_string_as},
+    ClientError, MobileConvexClient,
+};
+
+/// Cache key: function name and the canonical JSON of the arguments.
+type CacheKey = (String, String);
+
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct ActionCache {
+    entries: Mutex<HashMap<CacheKey, (Instant, String)>>,
+}
+
+fn cache_key(name: &str, args: &BTreeMap<String, Value>) -> CacheKey {
+    (
+        name.to_owned(),
+        value_to_json_string(Value::Object(args.clone())),
+    )
+}
+
+impl ActionCache {
+    fn get(&self, name: &str, args: &BTreeMap<String, Value>, now: Instant) -> Option<String> {
+        let entries = self.entries.lock();
+        let (expires_at, value) = entries.get(&cache_key(name, args))?;
+        (now < *expires_at).then(|| value.clone())
+    }
+
+    fn store(
+        &self,
+        name: &str,
+        args: &BTreeMap<String, Value>,
+        value: String,
+        expires_at: Instant,
+        now: Instant,
+    ) {
+        let mut entries = self.entries.lock();
+        entries.retain(|_, (expires_at, _)| now < *expires_at);
+        entries.insert(cache_key(name, args), (expires_at, value));
+    }
+
+    pub(crate) fn clear(&self) {
+        self.entries.lock().clear();
+    }
+
+    /// Drops all results and their storage, returning how many there were
+    /// and their approximate size in bytes.
+    pub(crate) fn release(&self) -> (usize, usize) {
+        let entries = std::mem::take(&mut *self.entries.lock());
+        let bytes = entries
+            .iter()
+            .map(|((name, args), (_, value))| name.len() + args.len() + value.len())
+            .sum();
+        (entries.len(), bytes)
+    }
+}
+
+impl MobileConvexClient {
+    /// Executes an action, reusing its result from an identical call made
+    /// within the last `ttl_ms` milliseconds.
+    #[frb]
+    pub async fn cached_action(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        ttl_ms: u64,
+    ) -> Result<String, ClientError> {
+        let args = self.parse_args(args)?;
+        if let Some(value) = self.action_cache.get(&name, &args, Instant::now()) {
+            self.events.emit(ClientEvent::CacheHit { name });
+            return Ok(self.decode_result(value));
+        }
+        let result = self.internal_action(name.clone(), args.clone()).await?;
+        if let FunctionResult::Value(value) = &result {
+            let now = Instant::now();
+            let value = value_to_json_string_as(value.clone(), self.options.int64_encoding);
+            let expires_at = now + Duration::from_millis(ttl_ms);
+            self.action_cache
+                .store(&name, &args, value, expires_at, now);
+        }
+        self.format_result(result)
+    }
+
+    /// Forgets all memoized action results.
+    #[frb(sync)]
+    pub fn clear_action_cache(&self) {
+        self.action_cache.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::btreemap;
+
+    use super::*;
+
+    #[test]
+    fn results_expire_after_their_ttl() {
+        let cache = ActionCache::default();
+        let args = btreemap! {"address".to_string() => Value::String("Main St".into())};
+        let now = Instant::now();
+        let ttl = Duration::from_secs(60);
+        cache.store("geo:lookup", &args, "1".into(), now + ttl, now);
+
+        assert_eq!(cache.get("geo:lookup", &args, now), Some("1".into()));
+        assert_eq!(cache.get("geo:lookup", &BTreeMap::new(), now), None);
+        assert_eq!(cache.get("geo:other", &args, now), None);
+        assert_eq!(cache.get("geo:lookup", &args, now + ttl), None);
+    }
+
+    #[test]
+    fn expired_entries_are_evicted_on_store() {
+        let cache = ActionCache::default();
+        let now = Instant::now();
+        let later = now + Duration::from_secs(10);
+        cache.store(
+            "a",
+            &BTreeMap::new(),
+            "1".into(),
+            now + Duration::from_secs(1),
+            now,
+        );
+        cache.store(
+            "b",
+            &BTreeMap::new(),
+            "2".into(),
+            later + Duration::from_secs(1),
+            later,
+        );
+        assert_eq!(cache.entries.lock().len(), 1);
+
+        cache.clear();
+        assert_eq!(cache.get("b", &BTreeMap::new(), later), None);
+    }
+}
@@ -0,0 +1,249 @@
+//! Parsing of the JSON-encoded argument maps received over the FFI.
+
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::{bail, Context};
+use convex::Value;
+
+use crate::{
+    codecs::{encode_markers, TypeCodec},
+    options::{BlankArgHandling, NullHandling},
+    value::json_to_value,
+};
+
+/// Key of the JSON marker object `{"$undefined": true}` standing for `undefined`.
+const UNDEFINED_MARKER: &str = "$undefined";
+
+/// Parses HashMap arguments into Convex Value format, reporting the first
+/// argument that is not valid JSON or not a valid Convex value.
+///
+/// Arguments whose JSON text is empty or only whitespace are handled as
+/// `blank_handling` says, and custom type markers are encoded by `codecs`.
+pub(crate) fn parse_json_args(
+    raw_args: HashMap<String, String>,
+    null_handling: NullHandling,
+    blank_handling: BlankArgHandling,
+    codecs: &[TypeCodec],
+) -> anyhow::Result<BTreeMap<String, Value>> {
+    let mut args = BTreeMap::new();
+    for (key, raw) in raw_args {
+        if raw.trim().is_empty() {
+            match blank_handling {
+                BlankArgHandling::Error => {
+                    bail!("Argument `{key}` is empty; pass valid JSON such as `null`")
+                }
+                BlankArgHandling::Skip => continue,
+                BlankArgHandling::Null => {
+                    if null_handling == NullHandling::Preserve {
+                        args.insert(key, Value::Null);
+                    }
+                    continue;
+                }
+            }
+        }
+        let json = serde_json::from_str::<serde_json::Value>(&raw)
+            .with_context(|| format!("Invalid JSON data for argument `{key}`"))?;
+        let json = encode_markers(json, codecs)
+            .with_context(|| format!("Invalid custom type in argument `{key}`"))?;
+        if omits_field(&json, null_handling) {
+            continue;
+        }
+        let value = json_to_value(normalize_undefined(json, null_handling))
+            .with_context(|| format!("Invalid Convex data for argument `{key}`"))?;
+        args.insert(key, value);
+    }
+    Ok(args)
+}
+
+fn is_undefined_marker(value: &serde_json::Value) -> bool {
+    matches!(
+        value,
+        serde_json::Value::Object(map) if map.len() == 1 && map.contains_key(UNDEFINED_MARKER)
+    )
+}
+
+/// Whether an object field with this value should be left out entirely.
+fn omits_field(value: &serde_json::Value, null_handling: NullHandling) -> bool {
+    is_undefined_marker(value) || (null_handling == NullHandling::OmitNullFields && value.is_null())
+}
+
+/// Removes `undefined` markers (and, if configured, null fields) recursively.
+/// Inside arrays `undefined` becomes `null`, as with `JSON.stringify`.
+fn normalize_undefined(value: serde_json::Value, null_handling: NullHandling) -> serde_json::Value {
+    match value {
+        serde_json::Value::Array(items) => items
+            .into_iter()
+            .map(|item| {
+                if is_undefined_marker(&item) {
+                    serde_json::Value::Null
+                } else {
+                    normalize_undefined(item, null_handling)
+                }
+            })
+            .collect(),
+        serde_json::Value::Object(map) => map
+            .into_iter()
+            .filter(|(_, v)| !omits_field(v, null_handling))
+            .map(|(k, v)| (k, normalize_undefined(v, null_handling)))
+            .collect(),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::{btreemap, hashmap};
+
+    use super::*;
+
+    fn parse(raw: HashMap<String, String>, null_handling: NullHandling) -> BTreeMap<String, Value> {
+        parse_json_args(raw, null_handling, BlankArgHandling::Error, &[]).unwrap()
+    }
+
+    #[test]
+    fn parses_scalars() {
+        let args = parse(
+            hashmap! {
+                "s".into() => r#""text""#.into(),
+                "n".into() => "1.5".into(),
+                "b".into() => "true".into(),
+                "z".into() => "null".into(),
+            },
+            NullHandling::Preserve,
+        );
+        assert_eq!(
+            args,
+            btreemap! {
+                "s".into() => Value::String("text".into()),
+                "n".into() => Value::Float64(1.5),
+                "b".into() => Value::Boolean(true),
+                "z".into() => Value::Null,
+            }
+        );
+    }
+
+    #[test]
+    fn parses_nested_and_tagged_values() {
+        let args = parse(
+            hashmap! {
+                "paginationOpts".into() =>
+                    r#"{"numItems":{"$integer":"CgAAAAAAAAA="},"cursor":null}"#.into(),
+            },
+            NullHandling::Preserve,
+        );
+        assert_eq!(
+            args["paginationOpts"],
+            Value::Object(btreemap! {
+                "numItems".into() => Value::Int64(10),
+                "cursor".into() => Value::Null,
+            })
+        );
+    }
+
+    #[test]
+    fn undefined_marker_is_omitted_from_objects() {
+        let args = parse(
+            hashmap! {
+                "gone".into() => r#"{"$undefined":true}"#.into(),
+                "patch".into() => r#"{"a":{"$undefined":true},"b":null}"#.into(),
+            },
+            NullHandling::Preserve,
+        );
+        assert!(!args.contains_key("gone"));
+        assert_eq!(
+            args["patch"],
+            Value::Object(btreemap! { "b".into() => Value::Null })
+        );
+    }
+
+    #[test]
+    fn undefined_marker_becomes_null_in_arrays() {
+        let args = parse(
+            hashmap! { "list".into() => r#"[1,{"$undefined":true}]"#.into() },
+            NullHandling::Preserve,
+        );
+        assert_eq!(
+            args["list"],
+            Value::Array(vec![Value::Float64(1.0), Value::Null])
+        );
+    }
+
+    #[test]
+    fn omit_null_fields_drops_nulls_but_not_array_elements() {
+        let args = parse(
+            hashmap! {
+                "top".into() => "null".into(),
+                "patch".into() => r#"{"a":null,"b":[null],"c":1}"#.into(),
+            },
+            NullHandling::OmitNullFields,
+        );
+        assert!(!args.contains_key("top"));
+        assert_eq!(
+            args["patch"],
+            Value::Object(btreemap! {
+                "b".into() => Value::Array(vec![Value::Null]),
+                "c".into() => Value::Float64(1.0),
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_json_reports_argument_name() {
+        let err = parse_json_args(
+            hashmap! { "bad".into() => "{oops".into() },
+            NullHandling::Preserve,
+            BlankArgHandling::Error,
+            &[],
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("`bad`"));
+    }
+
+    #[test]
+    fn invalid_convex_value_is_an_error() {
+        assert!(parse_json_args(
+            hashmap! { "bad".into() => r#"{"$set":[]}"#.into() },
+            NullHandling::Preserve,
+            BlankArgHandling::Error,
+            &[],
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn blank_arguments_are_handled_as_configured() {
+        let raw = hashmap! { "blank".into() => " \n".into(), "n".into() => "1".into() };
+        let err = parse_json_args(
+            raw.clone(),
+            NullHandling::Preserve,
+            BlankArgHandling::Error,
+            &[],
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("`blank` is empty"), "{err}");
+        assert_eq!(
+            parse_json_args(
+                raw.clone(),
+                NullHandling::Preserve,
+                BlankArgHandling::Null,
+                &[]
+            )
+            .unwrap(),
+            btreemap! { "blank".into() => Value::Null, "n".into() => Value::Float64(1.0) }
+        );
+        assert_eq!(
+            parse_json_args(
+                raw.clone(),
+                NullHandling::OmitNullFields,
+                BlankArgHandling::Null,
+                &[]
+            )
+            .unwrap(),
+            btreemap! { "n".into() => Value::Float64(1.0) }
+        );
+        assert_eq!(
+            parse_json_args(raw, NullHandling::Preserve, BlankArgHandling::Skip, &[]).unwrap(),
+            btreemap! { "n".into() => Value::Float64(1.0) }
+        );
+    }
+}
@@ -0,0 +1,319 @@
+//! Opt-in, on-device audit log of the data operations performed by the client.
+//!
+//! Each operation is appended as one JSON line to `audit.log` in the
+//! configured directory. Arguments are never stored, only a SHA-256 of their
+//! canonical JSON encoding. When the file would exceed the size limit it is
+//! rotated to `audit.log.1`, `audit.log.2`, ... and the oldest file is deleted.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use convex::{FunctionResult, Value};
+use flutter_rust_bridge::frb;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::presence::now_millis;
+
+const LOG_FILE_NAME: &str = "audit.log";
+
+/// Where and how much audit data is kept.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct AuditLogOptions {
+    /// Directory holding the log files; created if missing.
+    pub directory: String,
+    /// Size at which the current file is rotated.
+    pub max_file_bytes: u64,
+    /// Number of files kept, including the current one.
+    pub max_files: u32,
+}
+
+/// Kind of audited operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[frb]
+pub enum AuditOperation {
+    Query,
+    Subscribe,
+    Mutation,
+    Action,
+}
+
+/// Outcome of an audited operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+#[frb]
+pub enum AuditStatus {
+    Success,
+    /// The function threw a ConvexError.
+    ConvexError,
+    /// The function failed with a server error.
+    ServerError,
+    /// The request did not reach the backend.
+    TransportError,
+}
+
+impl AuditStatus {
+    pub(crate) fn of(result: &anyhow::Result<FunctionResult>) -> Self {
+        match result {
+            Ok(FunctionResult::Value(_)) => AuditStatus::Success,
+            Ok(FunctionResult::ConvexError(_)) => AuditStatus::ConvexError,
+            Ok(FunctionResult::ErrorMessage(_)) => AuditStatus::ServerError,
+            Err(_) => AuditStatus::TransportError,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AuditEntry<'a> {
+    timestamp_ms: i64,
+    operation: AuditOperation,
+    function: &'a str,
+    args_sha256: &'a str,
+    status: AuditStatus,
+    user_id: Option<&'a str>,
+}
+
+/// Appends audit entries to rotating files.
+pub(crate) struct AuditLog {
+    directory: PathBuf,
+    max_file_bytes: u64,
+    max_files: u32,
+    // Serializes appends, rotation and export.
+    lock: Mutex<()>,
+}
+
+/// An operation whose outcome is not known yet.
+pub(crate) struct PendingAudit {
+    log: Arc<AuditLog>,
+    operation: AuditOperation,
+    function: String,
+    args_sha256: String,
+    user_id: Option<String>,
+}
+
+impl PendingAudit {
+    /// Writes the entry. Failures are logged and never fail the operation.
+    pub(crate) fn finish(self, status: AuditStatus) {
+        let entry = AuditEntry {
+            timestamp_ms: now_millis(),
+            operation: self.operation,
+            function: &self.function,
+            args_sha256: &self.args_sha256,
+            status,
+            user_id: self.user_id.as_deref(),
+        };
+        if let Err(e) = self.log.append(&entry) {
+            log::warn!("Failed to write audit log entry: {e}");
+        }
+    }
+}
+
+impl AuditLog {
+    pub(crate) fn new(options: AuditLogOptions) -> Arc<Self> {
+        Arc::new(AuditLog {
+            directory: PathBuf::from(options.directory),
+            max_file_bytes: options.max_file_bytes.max(1),
+            max_files: options.max_files.max(1),
+            lock: Mutex::new(()),
+        })
+    }
+
+    /// Starts an entry for an operation about to be sent.
+    pub(crate) fn begin(
+        self: &Arc<Self>,
+        operation: AuditOperation,
+        function: &str,
+        args: &BTreeMap<String, Value>,
+        user_id: Option<String>,
+    ) -> PendingAudit {
+        PendingAudit {
+            log: self.clone(),
+            operation,
+            function: function.to_owned(),
+            args_sha256: hash_args(args),
+            user_id,
+        }
+    }
+
+    fn file_path(&self, index: u32) -> PathBuf {
+        if index == 0 {
+            self.directory.join(LOG_FILE_NAME)
+        } else {
+            self.directory.join(format!("{LOG_FILE_NAME}.{index}"))
+        }
+    }
+
+    fn append(&self, entry: &AuditEntry) -> io::Result<()> {
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+        let _guard = self.lock.lock();
+        fs::create_dir_all(&self.directory)?;
+        let current = self.file_path(0);
+        let size = fs::metadata(&current).map(|m| m.len()).unwrap_or(0);
+        if size > 0 && size + line.len() as u64 > self.max_file_bytes {
+            self.rotate()?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&current)?
+            .write_all(&line)
+    }
+
+    fn rotate(&self) -> io::Result<()> {
+        let oldest = self.file_path(self.max_files - 1);
+        if self.max_files == 1 {
+            return fs::remove_file(oldest);
+        }
+        if oldest.exists() {
+            fs::remove_file(&oldest)?;
+        }
+        for index in (0..self.max_files - 1).rev() {
+            let from = self.file_path(index);
+            if from.exists() {
+                fs::rename(from, self.file_path(index + 1))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns all retained entries as JSON lines, oldest first.
+    pub(crate) fn export(&self) -> io::Result<String> {
+        let _guard = self.lock.lock();
+        let mut exported = String::new();
+        for index in (0..self.max_files).rev() {
+            match fs::read_to_string(self.file_path(index)) {
+                Ok(contents) => exported.push_str(&contents),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(exported)
+    }
+
+    /// Deletes all retained entries.
+    pub(crate) fn clear(&self) -> io::Result<()> {
+        let _guard = self.lock.lock();
+        for index in 0..self.max_files {
+            match fs::remove_file(self.file_path(index)) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// SHA-256 of the canonical (key-sorted) JSON encoding of the arguments.
+fn hash_args(args: &BTreeMap<String, Value>) -> String {
+    let json = serde_json::Value::from(Value::Object(args.clone())).to_string();
+    hex::encode(Sha256::digest(json.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::btreemap;
+
+    use super::*;
+
+    fn log_in(dir: &tempfile::TempDir, max_file_bytes: u64, max_files: u32) -> Arc<AuditLog> {
+        AuditLog::new(AuditLogOptions {
+            directory: dir.path().join("audit").to_string_lossy().into_owned(),
+            max_file_bytes,
+            max_files,
+        })
+    }
+
+    fn record(log: &Arc<AuditLog>, function: &str) {
+        log.begin(
+            AuditOperation::Mutation,
+            function,
+            &btreemap! {"id".to_string() => Value::Int64(1)},
+            Some("user|1".into()),
+        )
+        .finish(AuditStatus::Success);
+    }
+
+    fn functions(exported: &str) -> Vec<String> {
+        exported
+            .lines()
+            .map(|line| {
+                let entry: serde_json::Value = serde_json::from_str(line).unwrap();
+                entry["function"].as_str().unwrap().to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn entries_hold_metadata_but_not_arguments() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = log_in(&dir, 1 << 20, 3);
+        log.begin(
+            AuditOperation::Query,
+            "messages:list",
+            &btreemap! {"secret".to_string() => Value::String("hunter2".into())},
+            None,
+        )
+        .finish(AuditStatus::ServerError);
+
+        let exported = log.export().unwrap();
+        assert!(!exported.contains("hunter2"));
+        let entry: serde_json::Value = serde_json::from_str(exported.trim()).unwrap();
+        assert_eq!(entry["operation"], "query");
+        assert_eq!(entry["function"], "messages:list");
+        assert_eq!(entry["status"], "server_error");
+        assert_eq!(entry["userId"], serde_json::Value::Null);
+        assert_eq!(entry["argsSha256"].as_str().unwrap().len(), 64);
+        assert!(entry["timestampMs"].as_i64().unwrap() > 0);
+    }
+
+    #[test]
+    fn equal_arguments_hash_equally() {
+        let a = btreemap! {"a".to_string() => Value::Int64(1), "b".to_string() => Value::Null};
+        let b = btreemap! {"b".to_string() => Value::Null, "a".to_string() => Value::Int64(1)};
+        assert_eq!(hash_args(&a), hash_args(&b));
+        assert_ne!(hash_args(&a), hash_args(&BTreeMap::new()));
+    }
+
+    #[test]
+    fn rotation_keeps_the_newest_files() {
+        let dir = tempfile::tempdir().unwrap();
+        // Small enough that every entry goes to its own file.
+        let log = log_in(&dir, 10, 3);
+        for i in 0..5 {
+            record(&log, &format!("fn{i}"));
+        }
+        assert_eq!(functions(&log.export().unwrap()), ["fn2", "fn3", "fn4"]);
+    }
+
+    #[test]
+    fn single_file_is_truncated_on_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = log_in(&dir, 10, 1);
+        record(&log, "first");
+        record(&log, "second");
+        assert_eq!(functions(&log.export().unwrap()), ["second"]);
+    }
+
+    #[test]
+    fn clear_removes_all_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = log_in(&dir, 10, 3);
+        assert_eq!(log.export().unwrap(), "");
+        record(&log, "a");
+        record(&log, "b");
+        log.clear().unwrap();
+        assert_eq!(log.export().unwrap(), "");
+        log.clear().unwrap();
+    }
+}
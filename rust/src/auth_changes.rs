@@ -0,0 +1,145 @@
+//! Auth across reconnects, and the reasons auth changes.
+//!
+//! The Convex client re-sends its token when the WebSocket reconnects, but
+//! a token that expired while the connection was down, e.g. while the app
+//! was suspended, is rejected and subscriptions fall back to
+//! unauthenticated results. An auth refresh session started with
+//! [`MobileConvexClient::set_auth_with_refresh`] therefore checks its token
+//! after every reconnect: it fetches a new one right away if the token
+//! expired, and applies it again otherwise, so the new connection is
+//! verifiably authenticated.
+//!
+//! [`MobileConvexClient::on_auth_change`] reports every change of the auth
+//! state as an [`AuthChange`] with its [`AuthChangeReason`], unlike the
+//! `on_auth_change` callback of `set_auth_with_refresh`, which only tells
+//! whether the session is authenticated.
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use tokio::sync::{broadcast, watch};
+
+use crate::{jwt::decode_jwt_expiry, ClientError, MobileConvexClient, WebSocketConnectionState};
+
+/// Why the auth state changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum AuthChangeReason {
+    /// A token was applied by `set_auth` or an auth refresh session.
+    SignedIn,
+    /// The WebSocket reconnected and the session's token was applied again.
+    ConnectedReauth,
+    /// The session's token expired, e.g. while the WebSocket was down; a
+    /// new one is being fetched.
+    TokenExpired,
+    /// The token fetcher returned no token, which cleared auth.
+    TokenRefreshFailed,
+    /// Auth was cleared by `set_auth`, `logout` or by disposing the auth
+    /// handle.
+    SignedOut,
+}
+
+/// A change of the auth state.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct AuthChange {
+    /// Whether the client is authenticated after the change.
+    pub authenticated: bool,
+    pub reason: AuthChangeReason,
+}
+
+/// Broadcasts [`AuthChange`]s to the registered listeners.
+#[frb(ignore)]
+pub(crate) struct AuthChanges {
+    sender: broadcast::Sender<AuthChange>,
+}
+
+impl Default for AuthChanges {
+    fn default() -> Self {
+        AuthChanges {
+            sender: broadcast::channel(16).0,
+        }
+    }
+}
+
+impl AuthChanges {
+    pub(crate) fn emit(&self, authenticated: bool, reason: AuthChangeReason) {
+        // Fails only while nobody listens.
+        let _ = self.sender.send(AuthChange {
+            authenticated,
+            reason,
+        });
+    }
+}
+
+/// Returns whether `token` expires at or before `now_secs`. Tokens without
+/// a readable expiry never do.
+pub(crate) fn is_expired(token: &str, now_secs: u64) -> bool {
+    decode_jwt_expiry(token).is_some_and(|exp| exp <= now_secs)
+}
+
+/// Waits until the WebSocket, once connected, lost its connection and
+/// connected again.
+pub(crate) async fn next_reconnect(state_rx: &mut watch::Receiver<WebSocketConnectionState>) {
+    for connected in [true, false, true] {
+        let gone = state_rx
+            .wait_for(|state| (*state == WebSocketConnectionState::Connected) == connected)
+            .await
+            .is_err();
+        if gone {
+            // The client is gone and never reconnects.
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+impl MobileConvexClient {
+    /// Registers a callback invoked with every [`AuthChange`] from now on,
+    /// as described in the [module docs](crate::auth_changes).
+    #[frb]
+    pub async fn on_auth_change(
+        &self,
+        on_change: impl Fn(AuthChange) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let mut changes = self.auth_changes.sender.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                match changes.recv().await {
+                    Ok(change) => on_change(change).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::FutureExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reconnects_are_told_from_the_first_connect() {
+        let state = watch::Sender::new(WebSocketConnectionState::Connecting);
+        let mut state_rx = state.subscribe();
+        let reconnect = tokio::spawn(async move { next_reconnect(&mut state_rx).await });
+        for next in [
+            WebSocketConnectionState::Connected,
+            WebSocketConnectionState::Connecting,
+        ] {
+            state.send_replace(next);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(!reconnect.is_finished());
+        state.send_replace(WebSocketConnectionState::Connected);
+        reconnect.await.unwrap();
+
+        // A client that is gone never reconnects.
+        let mut state_rx = state.subscribe();
+        drop(state);
+        assert!(next_reconnect(&mut state_rx).now_or_never().is_none());
+    }
+}
@@ -0,0 +1,125 @@
+//! Scheduling of auth token refreshes.
+//!
+//! An auth refresh session fetches a new token some time before the current
+//! one expires. The fixed 60 second buffer and 5 minute fallback suited
+//! hour-long tokens but not short-lived ones, and devices signed in at the
+//! same moment all refreshed at the same moment.
+//! [`crate::MobileConvexClient::set_auth_with_refresh_config`] takes an
+//! [`AuthRefreshConfig`] instead, whose `jitter_pct` moves every refresh
+//! earlier by a random share of its interval.
+
+use std::time::Duration;
+
+use flutter_rust_bridge::frb;
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::jwt::decode_jwt_expiry;
+
+/// When an auth refresh session fetches a new token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct AuthRefreshConfig {
+    /// Seconds before the token's expiry at which it is refreshed.
+    pub buffer_secs: u64,
+    /// Shortest wait between two refreshes, e.g. when a new token is due
+    /// right away, so a fetcher returning expired tokens is not called in
+    /// a tight loop.
+    pub min_interval_secs: u64,
+    /// Wait before refreshing tokens whose expiry cannot be read.
+    pub default_interval_secs: u64,
+    /// Largest share of the interval, in percent, by which a refresh is
+    /// moved earlier at random. 0 refreshes exactly on schedule.
+    pub jitter_pct: u8,
+}
+
+impl Default for AuthRefreshConfig {
+    fn default() -> Self {
+        AuthRefreshConfig {
+            buffer_secs: 60,
+            min_interval_secs: 5,
+            default_interval_secs: 300,
+            jitter_pct: 0,
+        }
+    }
+}
+
+impl AuthRefreshConfig {
+    /// Returns how long to wait before refreshing `token` at `now_secs`.
+    pub(crate) fn refresh_delay(&self, token: &str, now_secs: u64) -> Duration {
+        self.delay(decode_jwt_expiry(token), now_secs, random_fraction())
+    }
+
+    /// Returns the wait for a token expiring at `expiry`, with `random` in
+    /// `[0, 1)` choosing the jitter.
+    fn delay(&self, expiry: Option<u64>, now_secs: u64, random: f64) -> Duration {
+        let interval = match expiry {
+            Some(exp) => {
+                let refresh_at = exp.saturating_sub(self.buffer_secs);
+                if refresh_at > now_secs {
+                    refresh_at - now_secs
+                } else {
+                    // Token already expired or about to, refresh soon
+                    self.min_interval_secs
+                }
+            }
+            None => self.default_interval_secs,
+        };
+        let interval = Duration::from_secs(interval);
+        let jitter = interval.mul_f64(f64::from(self.jitter_pct.min(100)) / 100.0 * random);
+        let floor = interval.min(Duration::from_secs(self.min_interval_secs));
+        (interval - jitter).max(floor)
+    }
+}
+
+/// Returns a random number in `[0, 1)`, or 0 without randomness, i.e.
+/// without jitter.
+fn random_fraction() -> f64 {
+    let mut bytes = [0; 8];
+    if SystemRandom::new().fill(&mut bytes).is_err() {
+        return 0.0;
+    }
+    // The top 53 bits fill an f64 mantissa exactly.
+    (u64::from_le_bytes(bytes) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refreshes_are_scheduled_before_expiry_with_jitter() {
+        let config = AuthRefreshConfig::default();
+        let now = 1_700_000_000;
+        assert_eq!(
+            config.delay(Some(now + 3600), now, 0.9),
+            Duration::from_secs(3540)
+        );
+        assert_eq!(
+            config.delay(Some(now + 30), now, 0.0),
+            Duration::from_secs(5)
+        );
+        assert_eq!(config.delay(None, now, 0.0), Duration::from_secs(300));
+
+        // A 2 minute token refreshed 30 seconds early, up to 20% sooner.
+        let config = AuthRefreshConfig {
+            buffer_secs: 30,
+            jitter_pct: 20,
+            ..Default::default()
+        };
+        assert_eq!(
+            config.delay(Some(now + 120), now, 0.0),
+            Duration::from_secs(90)
+        );
+        assert_eq!(
+            config.delay(Some(now + 120), now, 0.5),
+            Duration::from_secs(81)
+        );
+        assert_eq!(
+            config.delay(Some(now + 10), now, 0.99),
+            Duration::from_secs(5)
+        );
+        let delay = config.refresh_delay("not a jwt", now);
+        assert!((240..=300).contains(&delay.as_secs()), "{delay:?}");
+        assert!((0.0..1.0).contains(&random_fraction()));
+    }
+}
@@ -0,0 +1,30 @@
+//! Exponential backoff shared by the retry loops.
+
+use std::time::Duration;
+
+/// Delay after the failed attempt number `attempt` (starting at 1):
+/// `initial_ms`, doubling with every attempt up to `max_ms`.
+pub(crate) fn exponential_backoff(initial_ms: u64, max_ms: u64, attempt: u32) -> Duration {
+    let factor = 1u64
+        .checked_shl(attempt.saturating_sub(1))
+        .unwrap_or(u64::MAX);
+    Duration::from_millis(initial_ms.saturating_mul(factor).min(max_ms))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays_double_without_overflowing() {
+        let delays: Vec<_> = (0..=4)
+            .map(|attempt| exponential_backoff(100, 1_000, attempt).as_millis())
+            .collect();
+        assert_eq!(delays, [100, 100, 200, 400, 800]);
+        assert_eq!(exponential_backoff(100, 1_000, 200).as_millis(), 1_000);
+        assert_eq!(
+            exponential_backoff(u64::MAX, u64::MAX, 70),
+            Duration::from_millis(u64::MAX)
+        );
+    }
+}
@@ -0,0 +1,287 @@
+//! Per-subscription backpressure.
+//!
+//! A query updating many times per second spawns a Dart future and crosses
+//! the FFI for every update, more often than the UI can render.
+//! [`MobileConvexClient::subscribe`] takes a [`Backpressure`] policy in
+//! [`crate::subscription::SubscribeOptions::backpressure`], applied in Rust
+//! before the callbacks are called. Unlike the client-wide throttling under
+//! UI pressure, callbacks are awaited one at a time. Errors are never
+//! dropped and keep their order relative to updates.
+
+use std::{sync::Arc, time::Duration};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use tokio::{sync::mpsc, time::Instant};
+
+use crate::{
+    pressure::coalesce_updates, subscription::SubscriptionCloseReason, ClientError,
+    MobileConvexClient, QuerySubscriber, SubscriptionEvent,
+};
+
+/// How updates arriving faster than `on_update` completes are delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum Backpressure {
+    /// Only the latest update that arrived while the previous callback ran.
+    LatestOnly,
+    /// The latest update, once none arrived for `ms` milliseconds.
+    Debounce { ms: u32 },
+    /// The latest update, at most once per `ms` milliseconds.
+    Throttle { ms: u32 },
+    /// Every update, in order, keeping at most `size` waiting; the oldest
+    /// waiting updates are dropped first.
+    Buffer { size: u32 },
+}
+
+type OnUpdate = dyn Fn(String) -> DartFnFuture<()> + Send + Sync;
+type OnError = dyn Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync;
+type OnDone = dyn Fn() -> DartFnFuture<()> + Send + Sync;
+
+/// Hands events to the delivery task applying the policy.
+#[frb(ignore)]
+struct BackpressureSubscriber {
+    events: mpsc::UnboundedSender<SubscriptionEvent>,
+}
+
+impl QuerySubscriber for BackpressureSubscriber {
+    fn on_update(&self, value: String) {
+        let _ = self.events.send(SubscriptionEvent::Update { value });
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        let _ = self.events.send(SubscriptionEvent::Error {
+            message,
+            data: value,
+        });
+    }
+
+    fn on_done(&self) {
+        let _ = self.events.send(SubscriptionEvent::Closed {
+            reason: SubscriptionCloseReason::StreamEnded,
+        });
+    }
+}
+
+/// Keeps the last `size` updates, and all errors.
+fn keep_latest_updates(events: Vec<SubscriptionEvent>, size: usize) -> Vec<SubscriptionEvent> {
+    let updates = events
+        .iter()
+        .filter(|event| matches!(event, SubscriptionEvent::Update { .. }))
+        .count();
+    let mut dropped = updates.saturating_sub(size);
+    events
+        .into_iter()
+        .filter(|event| {
+            if dropped > 0 && matches!(event, SubscriptionEvent::Update { .. }) {
+                dropped -= 1;
+                return false;
+            }
+            true
+        })
+        .collect()
+}
+
+async fn deliver(
+    mut receiver: mpsc::UnboundedReceiver<SubscriptionEvent>,
+    on_update: Box<OnUpdate>,
+    on_error: Box<OnError>,
+    on_done: Box<OnDone>,
+    policy: Backpressure,
+) {
+    let mut last_update: Option<Instant> = None;
+    while let Some(first) = receiver.recv().await {
+        let mut pending = vec![first];
+        if matches!(pending[0], SubscriptionEvent::Update { .. }) {
+            match policy {
+                Backpressure::Debounce { ms } => {
+                    let quiet = Duration::from_millis(ms.into());
+                    // Wait for a quiet period, stopping early at an error.
+                    while let Ok(Some(event)) = tokio::time::timeout(quiet, receiver.recv()).await {
+                        let is_update = matches!(event, SubscriptionEvent::Update { .. });
+                        pending.push(event);
+                        if !is_update {
+                            break;
+                        }
+                    }
+                }
+                Backpressure::Throttle { ms } => {
+                    if let Some(last) = last_update {
+                        let interval = Duration::from_millis(ms.into());
+                        tokio::time::sleep_until(last + interval).await;
+                    }
+                }
+                Backpressure::LatestOnly | Backpressure::Buffer { .. } => {}
+            }
+        }
+
+        while let Ok(event) = receiver.try_recv() {
+            pending.push(event);
+        }
+        let pending = match policy {
+            Backpressure::Buffer { size } => keep_latest_updates(pending, size as usize),
+            _ => coalesce_updates(pending),
+        };
+        for event in pending {
+            match event {
+                SubscriptionEvent::Update { value } => {
+                    on_update(value).await;
+                    last_update = Some(Instant::now());
+                }
+                SubscriptionEvent::Error { message, data } => on_error(message, data).await,
+                SubscriptionEvent::Closed { .. } => on_done().await,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Fails unless `backpressure` can deliver updates.
+pub(crate) fn validate_backpressure(backpressure: Backpressure) -> Result<(), ClientError> {
+    if backpressure == (Backpressure::Buffer { size: 0 }) {
+        return Err(ClientError::internal("Buffer size must be at least 1"));
+    }
+    Ok(())
+}
+
+impl MobileConvexClient {
+    /// Returns a subscriber calling the callbacks as `backpressure` allows.
+    /// Each callback is awaited before the next one is called.
+    pub(crate) fn backpressure_subscriber(
+        &self,
+        backpressure: Backpressure,
+        on_update: Box<OnUpdate>,
+        on_error: Box<OnError>,
+        on_done: Box<OnDone>,
+    ) -> Arc<dyn QuerySubscriber> {
+        let (events, receiver) = mpsc::unbounded_channel();
+        // The delivery task ends once the subscriber is dropped.
+        self.rt.spawn(deliver(
+            receiver,
+            on_update,
+            on_error,
+            on_done,
+            backpressure,
+        ));
+        Arc::new(BackpressureSubscriber { events })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parking_lot::Mutex;
+
+    use super::*;
+
+    fn update(value: &str) -> SubscriptionEvent {
+        SubscriptionEvent::Update {
+            value: value.into(),
+        }
+    }
+
+    /// Starts a delivery task whose update callback takes `render` to
+    /// complete, recording what it was called with.
+    fn start(
+        policy: Backpressure,
+        render: Duration,
+    ) -> (
+        mpsc::UnboundedSender<SubscriptionEvent>,
+        Arc<Mutex<Vec<String>>>,
+    ) {
+        let delivered = Arc::new(Mutex::new(Vec::new()));
+        let (events, receiver) = mpsc::unbounded_channel();
+        let updates = delivered.clone();
+        let errors = delivered.clone();
+        let done = delivered.clone();
+        tokio::spawn(deliver(
+            receiver,
+            Box::new(move |value| {
+                updates.lock().push(value);
+                Box::pin(tokio::time::sleep(render))
+            }),
+            Box::new(move |message, _| {
+                errors.lock().push(format!("error: {message}"));
+                Box::pin(async {})
+            }),
+            Box::new(move || {
+                done.lock().push("done".into());
+                Box::pin(async {})
+            }),
+            policy,
+        ));
+        (events, delivered)
+    }
+
+    async fn settle() {
+        tokio::time::sleep(Duration::from_millis(30)).await;
+    }
+
+    #[test]
+    fn buffers_drop_the_oldest_updates_but_no_errors() {
+        let error = SubscriptionEvent::Error {
+            message: "boom".into(),
+            data: None,
+        };
+        let events = vec![update("1"), error.clone(), update("2"), update("3")];
+        assert_eq!(
+            keep_latest_updates(events, 2),
+            vec![error, update("2"), update("3")]
+        );
+    }
+
+    #[tokio::test]
+    async fn latest_only_skips_updates_arriving_during_a_callback() {
+        let (events, delivered) = start(Backpressure::LatestOnly, Duration::from_millis(50));
+        events.send(update("1")).unwrap();
+        settle().await;
+        events.send(update("2")).unwrap();
+        events.send(update("3")).unwrap();
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(*delivered.lock(), ["1", "3"]);
+    }
+
+    #[tokio::test]
+    async fn debounce_waits_for_a_quiet_period() {
+        let (events, delivered) = start(Backpressure::Debounce { ms: 50 }, Duration::ZERO);
+        for value in ["1", "2", "3"] {
+            events.send(update(value)).unwrap();
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(delivered.lock().is_empty());
+        tokio::time::sleep(Duration::from_millis(80)).await;
+        assert_eq!(*delivered.lock(), ["3"]);
+    }
+
+    #[tokio::test]
+    async fn throttle_limits_the_rate_and_keeps_errors() {
+        let (events, delivered) = start(Backpressure::Throttle { ms: 100 }, Duration::ZERO);
+        events.send(update("1")).unwrap();
+        settle().await;
+        events.send(update("2")).unwrap();
+        events.send(update("3")).unwrap();
+        events
+            .send(SubscriptionEvent::Error {
+                message: "boom".into(),
+                data: None,
+            })
+            .unwrap();
+        settle().await;
+        assert_eq!(*delivered.lock(), ["1"]);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(*delivered.lock(), ["1", "3", "error: boom"]);
+    }
+
+    #[tokio::test]
+    async fn the_end_of_the_stream_follows_the_pending_updates() {
+        let (events, delivered) = start(Backpressure::LatestOnly, Duration::from_millis(50));
+        events.send(update("1")).unwrap();
+        settle().await;
+        events.send(update("2")).unwrap();
+        events
+            .send(SubscriptionEvent::Closed {
+                reason: SubscriptionCloseReason::StreamEnded,
+            })
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(120)).await;
+        assert_eq!(*delivered.lock(), ["1", "2", "done"]);
+    }
+}
@@ -0,0 +1,309 @@
+//! Running several queries against one snapshot.
+//!
+//! A screen rendered from separate [`MobileConvexClient::query`] calls may
+//! combine results from different timestamps, e.g. a message referring to a
+//! user that the users query does not return yet.
+//! [`MobileConvexClient::query_batch`] sends every query through the same
+//! path as [`MobileConvexClient::query`], with its interceptors, budget,
+//! audit, spans and request IDs, but subscribes them all and returns the
+//! results of the first transition that covers all of them, so they reflect
+//! the same logical timestamp.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Instant,
+};
+
+use anyhow::anyhow;
+use convex::{
+    ConvexClient, FunctionResult, QueryResults, QuerySetSubscription, QuerySubscription,
+    SubscriberId, Value,
+};
+use flutter_rust_bridge::frb;
+use futures::{future::join_all, FutureExt, StreamExt};
+use log::debug;
+use parking_lot::Mutex;
+use tokio::sync::{watch, Notify};
+
+use crate::{timeout::with_timeout, ClientError, MobileConvexClient};
+
+/// One query of a batch.
+#[derive(Debug, Clone)]
+#[frb]
+pub struct BatchQuery {
+    pub name: String,
+    pub args: HashMap<String, String>,
+}
+
+/// Where a query of a batch is on its way to the client.
+#[frb(ignore)]
+enum Slot {
+    /// The query has not reached the client yet.
+    Pending,
+    /// The query is subscribed and waits for the snapshot.
+    Subscribed(QuerySubscription),
+    /// The query completed without joining the snapshot, e.g. when an
+    /// interceptor answered it or its budget rejected it.
+    Settled,
+}
+
+/// Results of a batch by position, or why there are none.
+type Results = Option<Result<Vec<Option<FunctionResult>>, String>>;
+
+/// Collects the queries of a batch as they reach the client and resolves
+/// them all from one transition.
+#[frb(ignore)]
+struct Snapshot {
+    client: ConvexClient,
+    slots: Mutex<Vec<Slot>>,
+    arrived: Notify,
+    results: watch::Sender<Results>,
+}
+
+/// Returns the result of every subscriber in `ids` if `results` has them all.
+fn snapshot(results: &QueryResults, ids: &[SubscriberId]) -> Option<Vec<FunctionResult>> {
+    ids.iter().map(|id| results.get(id).cloned()).collect()
+}
+
+impl Snapshot {
+    fn new(client: ConvexClient, size: usize) -> Self {
+        Self {
+            client,
+            slots: Mutex::new((0..size).map(|_| Slot::Pending).collect()),
+            arrived: Notify::new(),
+            results: watch::channel(None).0,
+        }
+    }
+
+    /// Marks the query at `index` as not joining the snapshot, unless it
+    /// did already.
+    fn settle(&self, index: usize) {
+        let mut slots = self.slots.lock();
+        if matches!(slots[index], Slot::Pending) {
+            slots[index] = Slot::Settled;
+            self.arrived.notify_one();
+        }
+    }
+
+    /// Subscribes the query at `index` and returns its result in the
+    /// snapshot. The batch's own client is used, as its transitions are
+    /// the ones watched.
+    async fn query(
+        &self,
+        index: usize,
+        name: String,
+        args: BTreeMap<String, Value>,
+    ) -> anyhow::Result<FunctionResult> {
+        let mut results = self.results.subscribe();
+        match self.client.clone().subscribe(&name, args).await {
+            Ok(subscription) => {
+                self.slots.lock()[index] = Slot::Subscribed(subscription);
+                self.arrived.notify_one();
+            }
+            Err(error) => {
+                self.settle(index);
+                return Err(error);
+            }
+        }
+        let results = results.wait_for(Option::is_some).await?;
+        match results.as_ref() {
+            Some(Ok(results)) => results[index]
+                .clone()
+                .ok_or_else(|| anyhow!("The batch has no result for {name}")),
+            Some(Err(message)) => Err(anyhow!("{message}")),
+            None => unreachable!("waited for the results"),
+        }
+    }
+
+    /// Waits until every query is subscribed or settled, then publishes the
+    /// results of the first transition covering all subscribed queries.
+    async fn resolve(&self, mut transitions: QuerySetSubscription) {
+        loop {
+            if !self
+                .slots
+                .lock()
+                .iter()
+                .any(|slot| matches!(slot, Slot::Pending))
+            {
+                break;
+            }
+            self.arrived.notified().await;
+        }
+        let mut subscriptions: Vec<(usize, QuerySubscription)> = self
+            .slots
+            .lock()
+            .iter_mut()
+            .enumerate()
+            .filter_map(
+                |(index, slot)| match std::mem::replace(slot, Slot::Settled) {
+                    Slot::Subscribed(subscription) => Some((index, subscription)),
+                    _ => None,
+                },
+            )
+            .collect();
+        let ids: Vec<SubscriberId> = subscriptions.iter().map(|(_, s)| *s.id()).collect();
+
+        let mut results = None;
+        let mut transitioned = false;
+        while let Some(Some(transition)) = transitions.next().now_or_never() {
+            transitioned = true;
+            results = snapshot(&transition, &ids).or(results);
+        }
+        if !transitioned {
+            // Without a transition, the values the subscriptions started
+            // with all come from the current snapshot.
+            results = subscriptions
+                .iter_mut()
+                .map(|(_, subscription)| subscription.next().now_or_never().flatten())
+                .collect();
+        }
+        while results.is_none() {
+            let Some(transition) = transitions.next().await else {
+                let closed = "The connection closed during the batch".to_owned();
+                self.results.send_replace(Some(Err(closed)));
+                return;
+            };
+            results = snapshot(&transition, &ids);
+        }
+
+        let mut by_index = vec![None; self.slots.lock().len()];
+        for ((index, _), result) in subscriptions.iter().zip(results.unwrap_or_default()) {
+            by_index[*index] = Some(result);
+        }
+        self.results.send_replace(Some(Ok(by_index)));
+    }
+}
+
+impl MobileConvexClient {
+    /// Runs `queries` and returns their results in order, all read at the
+    /// same logical timestamp. Fails if any of the queries fails, or with
+    /// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+    #[frb]
+    pub async fn query_batch(
+        &self,
+        queries: Vec<BatchQuery>,
+        timeout_ms: Option<u64>,
+    ) -> Result<Vec<String>, ClientError> {
+        with_timeout(timeout_ms, self.internal_query_batch(queries)).await
+    }
+
+    async fn internal_query_batch(
+        &self,
+        queries: Vec<BatchQuery>,
+    ) -> Result<Vec<String>, ClientError> {
+        if queries.is_empty() {
+            return Ok(Vec::new());
+        }
+        let queries = queries
+            .into_iter()
+            .map(|query| Ok((query.name, self.parse_args(query.args)?)))
+            .collect::<Result<Vec<_>, ClientError>>()?;
+        let client = self.connected_client().await?;
+        let started = Instant::now();
+
+        // Transitions are watched from before the first subscription, so
+        // any that happens while subscribing is seen by `resolve`.
+        let transitions = client.watch_all();
+        let batch = Snapshot::new(client, queries.len());
+        let count = queries.len();
+        let calls = queries
+            .into_iter()
+            .enumerate()
+            .map(|(index, (name, args))| {
+                let batch = &batch;
+                async move {
+                    let result = self
+                        .internal_query_via(name, args, |_, name, args| {
+                            batch.query(index, name, args)
+                        })
+                        .await;
+                    batch.settle(index);
+                    result
+                }
+            });
+        let (results, ()) = futures::join!(join_all(calls), batch.resolve(transitions));
+        debug!("Batch of {count} queries took {:?}", started.elapsed());
+        results
+            .into_iter()
+            .map(|result| self.format_result(result?))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_batches_and_bad_arguments_need_no_connection() {
+        let client = MobileConvexClient::new("https://example.convex.cloud".into(), "test".into());
+        let results = client.rt.block_on(client.query_batch(Vec::new(), None));
+        assert_eq!(results.unwrap(), Vec::<String>::new());
+
+        let query = BatchQuery {
+            name: "messages:list".into(),
+            args: HashMap::from([("channel".to_owned(), "{not json".to_owned())]),
+        };
+        let results = client.rt.block_on(client.query_batch(vec![query], None));
+        assert!(results.is_err());
+    }
+
+    #[cfg(feature = "stub-server")]
+    #[test]
+    fn batched_queries_pass_the_interceptors() {
+        use std::sync::Arc;
+
+        use futures::future::{self, BoxFuture};
+
+        use crate::{
+            interceptors::{CallInterceptor, InterceptDecision, InterceptedCall},
+            stub_server::{start, StubScript},
+        };
+
+        /// Renames `users:old` to `users:list` and vetoes `secrets:get`.
+        struct Rename;
+
+        impl CallInterceptor for Rename {
+            fn intercept_call(
+                &self,
+                mut call: InterceptedCall,
+            ) -> BoxFuture<'static, InterceptDecision> {
+                if call.name == "users:old" {
+                    call.name = "users:list".into();
+                }
+                Box::pin(future::ready(if call.name == "secrets:get" {
+                    InterceptDecision::Veto {
+                        reason: "secrets stay on the device".into(),
+                    }
+                } else {
+                    InterceptDecision::Proceed { call }
+                }))
+            }
+        }
+
+        let script = StubScript::from_json(
+            r#"{"functions": [
+                {"name": "messages:list", "result": []},
+                {"name": "users:list", "result": [{"name": "ada"}]}
+            ]}"#,
+        )
+        .unwrap();
+        let probe = MobileConvexClient::new("https://example.convex.cloud".into(), "test".into());
+        let url = probe.rt.block_on(start(script)).unwrap();
+        let client = MobileConvexClient::new(url, "test".into());
+        client.add_rust_interceptor(Arc::new(Rename));
+        let query = |name: &str| BatchQuery {
+            name: name.into(),
+            args: HashMap::new(),
+        };
+
+        let batch = vec![query("messages:list"), query("users:old")];
+        let results = client.rt.block_on(client.query_batch(batch, Some(5_000)));
+        assert_eq!(results.unwrap(), [r#"[]"#, r#"[{"name":"ada"}]"#]);
+
+        // A vetoed query fails the batch instead of holding up the others.
+        let batch = vec![query("messages:list"), query("secrets:get")];
+        let results = client.rt.block_on(client.query_batch(batch, Some(5_000)));
+        assert!(matches!(results, Err(ClientError::InternalError { .. })));
+    }
+}
@@ -0,0 +1,114 @@
+//! Batched establishment of subscriptions.
+//!
+//! Screens often register many subscriptions in the same frame. With
+//! [`crate::options::ClientOptions::subscribe_batch_window_ms`] set, the
+//! subscriptions requested within that window are queued and established
+//! together by a single task once the window closes, so the connection sees
+//! them back to back instead of interleaved with other traffic.
+//!
+//! The Convex client still sends one query set modification per
+//! subscription; batching bounds the number of task wakes and lets the
+//! modifications go out in one burst.
+
+use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+use anyhow::Context;
+use convex::{ConvexClient, QuerySubscription, Value};
+use futures::{channel::oneshot, future};
+use log::debug;
+use parking_lot::Mutex;
+
+use crate::MobileConvexClient;
+
+/// A subscription waiting for its batch to be flushed.
+struct PendingSubscribe {
+    client: ConvexClient,
+    name: String,
+    args: BTreeMap<String, Value>,
+    reply: oneshot::Sender<anyhow::Result<QuerySubscription>>,
+}
+
+/// Queues subscriptions for the current batching window.
+pub(crate) struct SubscribeBatcher {
+    window: Duration,
+    pending: Mutex<Vec<PendingSubscribe>>,
+}
+
+impl SubscribeBatcher {
+    pub(crate) fn new(window_ms: u64) -> Arc<Self> {
+        Arc::new(SubscribeBatcher {
+            window: Duration::from_millis(window_ms),
+            pending: Mutex::new(Vec::new()),
+        })
+    }
+
+    /// Queues a subscription; returns whether it opened a new batch.
+    fn enqueue(&self, pending: PendingSubscribe) -> bool {
+        let mut queue = self.pending.lock();
+        queue.push(pending);
+        queue.len() == 1
+    }
+
+    async fn flush(self: Arc<Self>) {
+        tokio::time::sleep(self.window).await;
+        let batch = std::mem::take(&mut *self.pending.lock());
+        debug!("Establishing {} batched subscriptions", batch.len());
+        future::join_all(batch.into_iter().map(|mut pending| async move {
+            let result = pending.client.subscribe(&pending.name, pending.args).await;
+            let _ = pending.reply.send(result);
+        }))
+        .await;
+    }
+}
+
+impl MobileConvexClient {
+    /// Subscribes through the batcher if batching is enabled.
+    pub(crate) async fn batched_subscribe(
+        &self,
+        client: &mut ConvexClient,
+        name: &str,
+        args: BTreeMap<String, Value>,
+    ) -> anyhow::Result<QuerySubscription> {
+        let Some(batcher) = &self.subscribe_batcher else {
+            return client.subscribe(name, args).await;
+        };
+        let (reply, result) = oneshot::channel();
+        let pending = PendingSubscribe {
+            client: client.clone(),
+            name: name.to_owned(),
+            args,
+            reply,
+        };
+        if batcher.enqueue(pending) {
+            self.rt.spawn(batcher.clone().flush());
+        }
+        result.await.context("Subscription batch was dropped")?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_subscription_of_a_window_opens_the_batch() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let client = rt
+            .block_on(convex::ConvexClientBuilder::new("https://example.convex.cloud").build())
+            .unwrap();
+        let batcher = SubscribeBatcher::new(10);
+        let pending = |name: &str| {
+            let (reply, _) = oneshot::channel();
+            PendingSubscribe {
+                client: client.clone(),
+                name: name.to_owned(),
+                args: BTreeMap::new(),
+                reply,
+            }
+        };
+        assert!(batcher.enqueue(pending("a")));
+        assert!(!batcher.enqueue(pending("b")));
+        assert_eq!(std::mem::take(&mut *batcher.pending.lock()).len(), 2);
+        assert!(batcher.enqueue(pending("c")));
+    }
+}
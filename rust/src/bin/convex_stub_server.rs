@@ -0,0 +1,28 @@
+//! Serves a stub script for integration tests; see
+//! `convex_flutter::stub_server` for the script format.
+//!
+//! Usage: `convex_stub_server <script.json> [port]`. Once listening, the
+//! deployment URL to connect to is printed on its own line.
+
+use anyhow::Context;
+use convex_flutter::stub_server::{serve, StubScript};
+use tokio::net::TcpListener;
+
+const USAGE: &str = "Usage: convex_stub_server <script.json> [port]";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let path = args.next().context(USAGE)?;
+    let port = match args.next() {
+        Some(port) => port.parse().context(USAGE)?,
+        None => 0,
+    };
+    let script = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {path}"))
+        .and_then(|json| StubScript::from_json(&json))?;
+    let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+    println!("http://{}", listener.local_addr()?);
+    serve(listener, script).await;
+    Ok(())
+}
@@ -0,0 +1,352 @@
+//! Client-side budgets for backend usage.
+//!
+//! A UI bug in a widely installed release, such as a query re-run on every
+//! frame, can exhaust the deployment's usage limits long before a fix ships.
+//! A [`UsageBudget`] caps, per function, the number of calls per minute and
+//! the bytes sent and received per day, where a day starts with the first
+//! call of the function. Subscribing counts as a call; subscription updates
+//! are not counted.
+//!
+//! When a call would exceed a budget, [`BudgetEnforcement`] decides whether
+//! it is only logged, delayed until the minute window allows it, or
+//! rejected. Listeners registered with
+//! [`MobileConvexClient::on_budget_exceeded`] are told either way.
+
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use convex::{FunctionResult, Value};
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::warn;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use tokio::sync::broadcast;
+
+use crate::{
+    errors::{with_code, ErrorCode},
+    value::value_to_json_string,
+    ClientError, MobileConvexClient,
+};
+
+const MINUTE: Duration = Duration::from_secs(60);
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// What happens to a call that would exceed a budget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[frb]
+pub enum BudgetEnforcement {
+    /// The call proceeds and a warning is logged.
+    #[default]
+    Warn,
+    /// The call waits until the calls-per-minute budget allows it. Over the
+    /// daily bandwidth budget it is rejected, as the wait could take hours.
+    Queue,
+    /// The call fails with an error.
+    Reject,
+}
+
+/// Per-function limits on backend usage.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct UsageBudget {
+    /// Calls of one function within any 60 seconds. Unlimited when `None`.
+    pub max_calls_per_minute: Option<u32>,
+    /// Bytes of JSON arguments and results of one function per day.
+    /// Unlimited when `None`.
+    pub max_bytes_per_day: Option<u64>,
+    pub enforcement: BudgetEnforcement,
+}
+
+/// Which budget a call exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum BudgetLimit {
+    CallsPerMinute,
+    BytesPerDay,
+}
+
+/// Emitted whenever a call exceeds a budget.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct BudgetExceededEvent {
+    pub function: String,
+    pub limit: BudgetLimit,
+    pub enforcement: BudgetEnforcement,
+}
+
+/// Recent usage of one function.
+struct FunctionUsage {
+    calls: VecDeque<Instant>,
+    day_started: Instant,
+    bytes_today: u64,
+}
+
+impl FunctionUsage {
+    fn new(now: Instant) -> Self {
+        FunctionUsage {
+            calls: VecDeque::new(),
+            day_started: now,
+            bytes_today: 0,
+        }
+    }
+
+    fn roll(&mut self, now: Instant) {
+        while self
+            .calls
+            .front()
+            .is_some_and(|&call| now.duration_since(call) >= MINUTE)
+        {
+            self.calls.pop_front();
+        }
+        if now.duration_since(self.day_started) >= DAY {
+            self.day_started = now;
+            self.bytes_today = 0;
+        }
+    }
+}
+
+/// A budget a call would exceed, and when the call could be made instead.
+#[derive(Debug, PartialEq, Eq)]
+struct Exceeded {
+    limit: BudgetLimit,
+    retry_in: Duration,
+}
+
+/// Enforces a [`UsageBudget`] for all functions.
+pub(crate) struct BudgetGuard {
+    budget: UsageBudget,
+    usage: Mutex<HashMap<String, FunctionUsage>>,
+    events: broadcast::Sender<BudgetExceededEvent>,
+}
+
+impl BudgetGuard {
+    pub(crate) fn new(budget: UsageBudget) -> Arc<Self> {
+        Arc::new(BudgetGuard {
+            budget,
+            usage: Mutex::new(HashMap::new()),
+            events: broadcast::channel(16).0,
+        })
+    }
+
+    /// Records a call of `function` made at `now`, unless it would exceed
+    /// the budget and `force` is not set.
+    fn try_call(&self, function: &str, now: Instant, force: bool) -> Result<(), Exceeded> {
+        let mut usage = self.usage.lock();
+        let usage = usage
+            .entry(function.to_owned())
+            .or_insert_with(|| FunctionUsage::new(now));
+        usage.roll(now);
+        let exceeded = if self
+            .budget
+            .max_bytes_per_day
+            .is_some_and(|max| usage.bytes_today >= max)
+        {
+            Some(Exceeded {
+                limit: BudgetLimit::BytesPerDay,
+                retry_in: DAY - now.duration_since(usage.day_started),
+            })
+        } else if self
+            .budget
+            .max_calls_per_minute
+            .is_some_and(|max| usage.calls.len() >= max as usize)
+        {
+            let oldest = usage.calls.front().copied().unwrap_or(now);
+            Some(Exceeded {
+                limit: BudgetLimit::CallsPerMinute,
+                retry_in: MINUTE.saturating_sub(now.duration_since(oldest)),
+            })
+        } else {
+            None
+        };
+        match exceeded {
+            Some(exceeded) if !force => Err(exceeded),
+            _ => {
+                usage.calls.push_back(now);
+                Ok(())
+            }
+        }
+    }
+
+    fn record_bytes(&self, function: &str, bytes: u64) {
+        if let Some(usage) = self.usage.lock().get_mut(function) {
+            usage.bytes_today = usage.bytes_today.saturating_add(bytes);
+        }
+    }
+
+    /// Waits until a call of `function` fits the budget, as configured.
+    pub(crate) async fn admit(&self, function: &str) -> anyhow::Result<()> {
+        let mut reported = false;
+        loop {
+            let Err(exceeded) = self.try_call(function, Instant::now(), false) else {
+                return Ok(());
+            };
+            let enforcement = self.budget.enforcement;
+            if !reported {
+                reported = true;
+                let _ = self.events.send(BudgetExceededEvent {
+                    function: function.to_owned(),
+                    limit: exceeded.limit,
+                    enforcement,
+                });
+            }
+            match (enforcement, exceeded.limit) {
+                (BudgetEnforcement::Warn, limit) => {
+                    warn!("Usage budget {limit:?} exceeded by {function}");
+                    let _ = self.try_call(function, Instant::now(), true);
+                    return Ok(());
+                }
+                (BudgetEnforcement::Queue, BudgetLimit::CallsPerMinute) => {
+                    tokio::time::sleep(exceeded.retry_in).await;
+                }
+                (_, limit) => {
+                    let error = anyhow::anyhow!(
+                        "Usage budget {limit:?} exceeded by {function}; retry in {}s",
+                        exceeded.retry_in.as_secs()
+                    );
+                    return Err(with_code(error, ErrorCode::RateLimited));
+                }
+            }
+        }
+    }
+
+    fn measures_bytes(&self) -> bool {
+        self.budget.max_bytes_per_day.is_some()
+    }
+}
+
+/// A call admitted by a [`BudgetGuard`], whose result size is not known yet.
+pub(crate) struct PendingUsage {
+    guard: Arc<BudgetGuard>,
+    function: String,
+    args_bytes: u64,
+}
+
+impl PendingUsage {
+    /// Counts the bytes of the arguments and of the result.
+    pub(crate) fn finish(self, result: &anyhow::Result<FunctionResult>) {
+        if !self.guard.measures_bytes() {
+            return;
+        }
+        let result_bytes = match result {
+            Ok(FunctionResult::Value(value)) => value_to_json_string(value.clone()).len() as u64,
+            _ => 0,
+        };
+        self.guard
+            .record_bytes(&self.function, self.args_bytes + result_bytes);
+    }
+}
+
+impl MobileConvexClient {
+    /// Admits a call of `name` against the usage budget, if configured.
+    pub(crate) async fn begin_usage(
+        &self,
+        name: &str,
+        args: &BTreeMap<String, Value>,
+    ) -> anyhow::Result<Option<PendingUsage>> {
+        let Some(guard) = &self.budget_guard else {
+            return Ok(None);
+        };
+        guard.admit(name).await?;
+        let args_bytes = if guard.measures_bytes() {
+            value_to_json_string(Value::Object(args.clone())).len() as u64
+        } else {
+            0
+        };
+        Ok(Some(PendingUsage {
+            guard: guard.clone(),
+            function: name.to_owned(),
+            args_bytes,
+        }))
+    }
+
+    /// Registers a callback invoked whenever a call exceeds the usage
+    /// budget. Requires [`crate::options::ClientOptions::usage_budget`].
+    #[frb]
+    pub async fn on_budget_exceeded(
+        &self,
+        on_exceeded: impl Fn(BudgetExceededEvent) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let guard = self
+            .budget_guard
+            .as_ref()
+            .ok_or_else(|| ClientError::internal("No usage budget configured"))?;
+        let mut events = guard.events.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => on_exceeded(event).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard(max_calls: Option<u32>, max_bytes: Option<u64>) -> Arc<BudgetGuard> {
+        BudgetGuard::new(UsageBudget {
+            max_calls_per_minute: max_calls,
+            max_bytes_per_day: max_bytes,
+            enforcement: BudgetEnforcement::Reject,
+        })
+    }
+
+    #[test]
+    fn calls_are_limited_per_function_and_minute() {
+        let guard = guard(Some(2), None);
+        let start = Instant::now();
+        assert!(guard.try_call("a", start, false).is_ok());
+        assert!(guard
+            .try_call("a", start + Duration::from_secs(10), false)
+            .is_ok());
+        assert_eq!(
+            guard.try_call("a", start + Duration::from_secs(20), false),
+            Err(Exceeded {
+                limit: BudgetLimit::CallsPerMinute,
+                retry_in: Duration::from_secs(40),
+            })
+        );
+        assert!(guard.try_call("b", start, false).is_ok());
+        assert!(guard.try_call("a", start + MINUTE, false).is_ok());
+    }
+
+    #[test]
+    fn bandwidth_is_limited_per_day() {
+        let guard = guard(None, Some(100));
+        let start = Instant::now();
+        assert!(guard.try_call("a", start, false).is_ok());
+        guard.record_bytes("a", 100);
+        let exceeded = guard.try_call("a", start + MINUTE, false).unwrap_err();
+        assert_eq!(exceeded.limit, BudgetLimit::BytesPerDay);
+        assert!(guard.try_call("a", start + DAY, false).is_ok());
+    }
+
+    #[tokio::test]
+    async fn enforcement_decides_over_calls() {
+        let rejecting = guard(Some(1), None);
+        rejecting.admit("a").await.unwrap();
+        let mut events = rejecting.events.subscribe();
+        assert!(rejecting.admit("a").await.is_err());
+        assert_eq!(
+            events.try_recv().unwrap().limit,
+            BudgetLimit::CallsPerMinute
+        );
+
+        let warning = BudgetGuard::new(UsageBudget {
+            max_calls_per_minute: Some(1),
+            ..Default::default()
+        });
+        warning.admit("a").await.unwrap();
+        warning.admit("a").await.unwrap();
+    }
+}
@@ -0,0 +1,347 @@
+//! Per-function call metrics.
+//!
+//! [`MobileConvexClient::metrics`] covers the connection and the runtime,
+//! which leaves a slow function indistinguishable from a slow network. The
+//! client also records, per function, a latency histogram, the number of
+//! calls, errors and retries, and the bytes of arguments and results, plus
+//! the reconnects of the client. [`MobileConvexClient::metrics_snapshot`]
+//! returns them, along with the number of active subscriptions, as a
+//! [`MetricsReport`]. Queries, mutations and actions are recorded as
+//! sampled for [`TelemetryClass::CallLatency`].
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use convex::{FunctionResult, Value};
+use flutter_rust_bridge::frb;
+use parking_lot::Mutex;
+use tokio::sync::watch;
+
+use crate::{
+    presence::now_millis, sampling::TelemetryClass, value::value_to_json_string,
+    MobileConvexClient, WebSocketConnectionState,
+};
+
+/// Upper bounds in milliseconds of the latency histogram buckets. A last
+/// bucket counts the slower calls.
+const LATENCY_BOUNDS_MS: [u64; 10] = [10, 25, 50, 100, 250, 500, 1_000, 2_500, 5_000, 10_000];
+
+/// Distribution of call latencies.
+#[derive(Debug, Clone, PartialEq)]
+#[frb]
+pub struct LatencyHistogram {
+    /// Upper bounds in milliseconds of all buckets but the last.
+    pub bounds_ms: Vec<u64>,
+    /// Calls per bucket; one more entry than `bounds_ms`, the last one
+    /// counting calls slower than all bounds.
+    pub counts: Vec<u64>,
+    pub sum_ms: f64,
+    pub max_ms: f64,
+    /// Median estimated from the buckets, as the bound of its bucket.
+    pub p50_ms: f64,
+    /// 95th percentile estimated from the buckets, as the bound of its
+    /// bucket.
+    pub p95_ms: f64,
+}
+
+/// Metrics of the calls of one function.
+#[derive(Debug, Clone, PartialEq)]
+#[frb]
+pub struct FunctionMetrics {
+    pub name: String,
+    pub calls: u64,
+    /// Calls that failed in transport or returned an error.
+    pub errors: u64,
+    /// Mutations sent again after a failed attempt.
+    pub retries: u64,
+    pub latency: LatencyHistogram,
+    /// Bytes of the JSON arguments of all calls.
+    pub args_bytes: u64,
+    /// Bytes of the JSON results of all calls.
+    pub result_bytes: u64,
+    pub max_result_bytes: u64,
+}
+
+/// Point-in-time report of the call metrics.
+#[derive(Debug, Clone, PartialEq)]
+#[frb]
+pub struct MetricsReport {
+    /// Unix time in milliseconds since which the metrics were recorded.
+    pub since_ms: i64,
+    /// Functions called so far, by name.
+    pub functions: Vec<FunctionMetrics>,
+    /// Mutation retries of all functions.
+    pub retries: u64,
+    /// Times the WebSocket lost its connection.
+    pub reconnects: u64,
+    pub active_subscriptions: u32,
+}
+
+#[derive(Debug)]
+#[frb(ignore)]
+struct FunctionStats {
+    calls: u64,
+    errors: u64,
+    retries: u64,
+    latency_counts: Vec<u64>, // One per bound of `LATENCY_BOUNDS_MS`, then the slower calls
+    sum_ms: f64,
+    max_ms: f64,
+    args_bytes: u64,
+    result_bytes: u64,
+    max_result_bytes: u64,
+}
+
+impl Default for FunctionStats {
+    fn default() -> Self {
+        FunctionStats {
+            calls: 0,
+            errors: 0,
+            retries: 0,
+            latency_counts: vec![0; LATENCY_BOUNDS_MS.len() + 1],
+            sum_ms: 0.0,
+            max_ms: 0.0,
+            args_bytes: 0,
+            result_bytes: 0,
+            max_result_bytes: 0,
+        }
+    }
+}
+
+impl FunctionStats {
+    fn report(&self, name: &str) -> FunctionMetrics {
+        FunctionMetrics {
+            name: name.to_owned(),
+            calls: self.calls,
+            errors: self.errors,
+            retries: self.retries,
+            latency: LatencyHistogram {
+                bounds_ms: LATENCY_BOUNDS_MS.to_vec(),
+                counts: self.latency_counts.clone(),
+                sum_ms: self.sum_ms,
+                max_ms: self.max_ms,
+                p50_ms: quantile_ms(&self.latency_counts, 0.5, self.max_ms),
+                p95_ms: quantile_ms(&self.latency_counts, 0.95, self.max_ms),
+            },
+            args_bytes: self.args_bytes,
+            result_bytes: self.result_bytes,
+            max_result_bytes: self.max_result_bytes,
+        }
+    }
+}
+
+/// Estimates the `quantile` of the latencies counted in `counts` as the
+/// bound of the bucket it falls into, at most `max_ms`. 0 without calls.
+fn quantile_ms(counts: &[u64], quantile: f64, max_ms: f64) -> f64 {
+    let total: u64 = counts.iter().sum();
+    let rank = ((total as f64 * quantile).ceil() as u64).max(1);
+    let mut seen = 0;
+    for (bucket, count) in counts.iter().enumerate() {
+        seen += count;
+        if seen >= rank {
+            return LATENCY_BOUNDS_MS
+                .get(bucket)
+                .map_or(max_ms, |bound| (*bound as f64).min(max_ms));
+        }
+    }
+    0.0
+}
+
+/// Collects the call metrics of a client.
+#[frb(ignore)]
+pub(crate) struct CallMetrics {
+    since_ms: i64,
+    functions: Mutex<HashMap<String, FunctionStats>>,
+    reconnects: AtomicU64,
+}
+
+impl Default for CallMetrics {
+    fn default() -> Self {
+        CallMetrics {
+            since_ms: now_millis(),
+            functions: Mutex::default(),
+            reconnects: AtomicU64::new(0),
+        }
+    }
+}
+
+impl CallMetrics {
+    fn record(
+        &self,
+        function: &str,
+        elapsed: Duration,
+        ok: bool,
+        args_bytes: u64,
+        result_bytes: u64,
+    ) {
+        let elapsed_ms = elapsed.as_secs_f64() * 1000.0;
+        let bucket = LATENCY_BOUNDS_MS
+            .iter()
+            .position(|bound| elapsed_ms <= *bound as f64)
+            .unwrap_or(LATENCY_BOUNDS_MS.len());
+        let mut functions = self.functions.lock();
+        let stats = functions.entry(function.to_owned()).or_default();
+        stats.calls += 1;
+        if !ok {
+            stats.errors += 1;
+        }
+        stats.latency_counts[bucket] += 1;
+        stats.sum_ms += elapsed_ms;
+        stats.max_ms = stats.max_ms.max(elapsed_ms);
+        stats.args_bytes += args_bytes;
+        stats.result_bytes += result_bytes;
+        stats.max_result_bytes = stats.max_result_bytes.max(result_bytes);
+    }
+
+    /// Counts a retry of the mutation `function`.
+    pub(crate) fn record_retry(&self, function: &str) {
+        let mut functions = self.functions.lock();
+        functions.entry(function.to_owned()).or_default().retries += 1;
+    }
+
+    /// Counts the connection losses among the changes of `state_rx`.
+    pub(crate) async fn track_connection(
+        self: Arc<Self>,
+        mut state_rx: watch::Receiver<WebSocketConnectionState>,
+    ) {
+        let mut connected = false;
+        loop {
+            let state = state_rx.borrow_and_update().clone();
+            let now_connected = state == WebSocketConnectionState::Connected;
+            if connected && !now_connected {
+                self.reconnects.fetch_add(1, Ordering::Relaxed);
+            }
+            connected = now_connected;
+            if state_rx.changed().await.is_err() {
+                break;
+            }
+        }
+    }
+
+    fn report(&self, active_subscriptions: u32) -> MetricsReport {
+        let mut functions: Vec<_> = self
+            .functions
+            .lock()
+            .iter()
+            .map(|(name, stats)| stats.report(name))
+            .collect();
+        functions.sort_by(|a, b| a.name.cmp(&b.name));
+        MetricsReport {
+            since_ms: self.since_ms,
+            retries: functions.iter().map(|function| function.retries).sum(),
+            functions,
+            reconnects: self.reconnects.load(Ordering::Relaxed),
+            active_subscriptions,
+        }
+    }
+}
+
+/// A sampled call whose result is not known yet.
+pub(crate) struct PendingCall {
+    metrics: Arc<CallMetrics>,
+    function: String,
+    args_bytes: u64,
+    started: Instant,
+}
+
+impl PendingCall {
+    /// Records the call with its `result`.
+    pub(crate) fn finish(self, result: &anyhow::Result<FunctionResult>) {
+        let result_bytes = match result {
+            Ok(FunctionResult::Value(value)) => value_to_json_string(value.clone()).len() as u64,
+            _ => 0,
+        };
+        let ok = matches!(result, Ok(FunctionResult::Value(_)));
+        self.metrics.record(
+            &self.function,
+            self.started.elapsed(),
+            ok,
+            self.args_bytes,
+            result_bytes,
+        );
+    }
+}
+
+impl MobileConvexClient {
+    /// Starts recording a call of `name`, if sampled.
+    pub(crate) fn begin_metrics(
+        &self,
+        name: &str,
+        args: &BTreeMap<String, Value>,
+    ) -> Option<PendingCall> {
+        if !self
+            .telemetry_sampler
+            .sample(TelemetryClass::CallLatency, Some(name))
+        {
+            return None;
+        }
+        Some(PendingCall {
+            metrics: self.call_metrics.clone(),
+            function: name.to_owned(),
+            args_bytes: value_to_json_string(Value::Object(args.clone())).len() as u64,
+            started: Instant::now(),
+        })
+    }
+
+    /// Returns the call metrics recorded so far, as described in the
+    /// [module docs](crate::call_metrics).
+    #[frb(sync)]
+    pub fn metrics_snapshot(&self) -> MetricsReport {
+        let active_subscriptions = self.active_subscriptions().len() as u32;
+        self.call_metrics.report(active_subscriptions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calls_are_bucketed_per_function() {
+        let metrics = CallMetrics::default();
+        for elapsed_ms in [5, 8, 40, 90, 30_000] {
+            let elapsed = Duration::from_millis(elapsed_ms);
+            metrics.record("messages:list", elapsed, true, 10, 100);
+        }
+        metrics.record("messages:send", Duration::from_millis(200), false, 20, 0);
+        metrics.record_retry("messages:send");
+
+        let report = metrics.report(3);
+        assert_eq!(report.retries, 1);
+        assert_eq!(report.active_subscriptions, 3);
+        let [list, send] = &report.functions[..] else {
+            panic!("unexpected functions: {:?}", report.functions);
+        };
+        assert_eq!(list.name, "messages:list");
+        assert_eq!((list.calls, list.errors, list.args_bytes), (5, 0, 50));
+        assert_eq!(list.latency.counts, [2, 0, 1, 1, 0, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(list.latency.p50_ms, 50.0);
+        assert_eq!(list.latency.p95_ms, 30_000.0);
+        assert_eq!((send.calls, send.errors, send.retries), (1, 1, 1));
+        assert_eq!(send.max_result_bytes, 0);
+    }
+
+    #[tokio::test]
+    async fn connection_losses_are_counted() {
+        let metrics = Arc::new(CallMetrics::default());
+        let state = watch::Sender::new(WebSocketConnectionState::Connecting);
+        let tracker = tokio::spawn(metrics.clone().track_connection(state.subscribe()));
+        for next in [
+            WebSocketConnectionState::Connected,
+            WebSocketConnectionState::Connecting,
+            WebSocketConnectionState::Connected,
+        ] {
+            tokio::task::yield_now().await;
+            state.send_replace(next);
+        }
+        tokio::task::yield_now().await;
+        drop(state);
+        tracker.await.unwrap();
+        assert_eq!(metrics.report(0).reconnects, 1);
+    }
+}
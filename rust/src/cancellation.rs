@@ -0,0 +1,186 @@
+//! Cancelling calls in flight.
+//!
+//! A page that starts a slow action and is navigated away from has no use
+//! for its result. [`MobileConvexClient::query_cancellable`],
+//! [`MobileConvexClient::mutation_cancellable`] and
+//! [`MobileConvexClient::action_cancellable`] take a [`CancellationToken`],
+//! e.g. one per page disposed with it. Cancelling the token drops every call
+//! made with it, aborting the runtime task that sends it, freeing what the
+//! call holds and failing it right away instead of resolving into a disposed
+//! widget. The backend is not told:
+//! a mutation or action already sent may still run to completion.
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use anyhow::anyhow;
+use flutter_rust_bridge::frb;
+use futures::{pin_mut, select_biased, FutureExt};
+use tokio::{
+    sync::watch,
+    task::{JoinError, JoinHandle},
+};
+
+use crate::{ClientError, MobileConvexClient};
+
+/// Opaque type for Dart, cancelling the calls made with it. A token stays
+/// cancelled; calls made with a cancelled token fail immediately.
+#[frb(opaque)]
+pub struct CancellationToken {
+    cancelled: watch::Sender<bool>,
+}
+
+impl CancellationToken {
+    /// Creates a token that is not cancelled.
+    #[frb(sync)]
+    pub fn new() -> CancellationToken {
+        CancellationToken {
+            cancelled: watch::Sender::new(false),
+        }
+    }
+
+    /// Cancels the calls made with this token.
+    #[frb(sync)]
+    pub fn cancel(&self) {
+        self.cancelled.send_replace(true);
+    }
+
+    /// Returns whether [`CancellationToken::cancel`] was called.
+    #[frb(sync)]
+    pub fn is_cancelled(&self) -> bool {
+        *self.cancelled.borrow()
+    }
+
+    /// Runs `call` until it completes or the token is cancelled.
+    async fn run<T>(
+        &self,
+        call: impl Future<Output = Result<T, ClientError>>,
+    ) -> Result<T, ClientError> {
+        let mut cancelled = self.cancelled.subscribe();
+        let cancelled = cancelled.wait_for(|cancelled| *cancelled).fuse();
+        let call = call.fuse();
+        pin_mut!(cancelled, call);
+        select_biased! {
+            _ = cancelled => Err(anyhow!("The call was cancelled").into()),
+            result = call => result,
+        }
+    }
+}
+
+/// A spawned task that is aborted once dropped, so that dropping a call in
+/// flight also stops the task sending it.
+pub(crate) struct AbortOnDrop<T>(pub(crate) JoinHandle<T>);
+
+impl<T> Future for AbortOnDrop<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+impl<T> Drop for AbortOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MobileConvexClient {
+    /// Like [`MobileConvexClient::query`], but abandoned once `token` is
+    /// cancelled.
+    #[frb]
+    pub async fn query_cancellable(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        token: &CancellationToken,
+        timeout_ms: Option<u64>,
+    ) -> Result<String, ClientError> {
+        token.run(self.query(name, args, timeout_ms)).await
+    }
+
+    /// Like [`MobileConvexClient::mutation`], but abandoned once `token` is
+    /// cancelled.
+    #[frb]
+    pub async fn mutation_cancellable(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        token: &CancellationToken,
+        timeout_ms: Option<u64>,
+    ) -> Result<String, ClientError> {
+        token.run(self.mutation(name, args, timeout_ms)).await
+    }
+
+    /// Like [`MobileConvexClient::action`], but abandoned once `token` is
+    /// cancelled.
+    #[frb]
+    pub async fn action_cancellable(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        token: &CancellationToken,
+        timeout_ms: Option<u64>,
+    ) -> Result<String, ClientError> {
+        token.run(self.action(name, args, timeout_ms)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use futures::channel::oneshot;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn cancelling_drops_calls_in_flight() {
+        let token = CancellationToken::new();
+        let pending = token.run(async {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+            Ok(())
+        });
+        let cancel = async {
+            tokio::task::yield_now().await;
+            token.cancel();
+        };
+        let (result, ()) = tokio::join!(pending, cancel);
+        assert!(result.is_err());
+        assert!(token.is_cancelled());
+
+        // Calls made with a cancelled token do not start.
+        assert!(token.run(async { Ok(()) }).await.is_err());
+        let fresh = CancellationToken::default();
+        assert_eq!(fresh.run(async { Ok(7) }).await.unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn cancelling_aborts_the_spawned_task() {
+        let token = CancellationToken::new();
+        let (alive_tx, alive_rx) = oneshot::channel::<()>();
+        let task = AbortOnDrop(tokio::spawn(async move {
+            let _alive = alive_tx;
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        }));
+        let pending = token.run(async { Ok(task.await.map_err(anyhow::Error::from)?) });
+        let cancel = async {
+            tokio::task::yield_now().await;
+            token.cancel();
+        };
+        let (result, ()) = tokio::join!(pending, cancel);
+        assert!(result.is_err());
+        // The task was dropped, closing the channel it held.
+        assert!(alive_rx.await.is_err());
+    }
+}
@@ -0,0 +1,210 @@
+//! Large results delivered in chunks.
+//!
+//! A result of several megabytes handed to Dart as one `String` is copied
+//! and decoded in a single step on the UI isolate, dropping frames.
+//! [`MobileConvexClient::query_streamed`] and
+//! [`MobileConvexClient::subscribe_chunked`] deliver results larger than
+//! [`ChunkedResultOptions::threshold_bytes`] as a series of
+//! [`ResultChunk`]s of UTF-8 bytes instead, for Dart to decode
+//! incrementally, e.g. with `utf8.decoder.startChunkedConversion`. Smaller
+//! results arrive as a single chunk.
+
+use std::{collections::HashMap, sync::Arc};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use serde::Deserialize;
+use tokio::sync::mpsc;
+
+use crate::{
+    resubscribe::SubscriptionPriority, timeout::with_timeout, ClientError, MobileConvexClient,
+    QuerySubscriber, SubscriptionHandle,
+};
+
+/// When and how results are split into chunks.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct ChunkedResultOptions {
+    /// Size in bytes above which a result is split.
+    pub threshold_bytes: u64,
+    /// Size in bytes of each chunk of a split result.
+    pub chunk_bytes: u64,
+}
+
+impl Default for ChunkedResultOptions {
+    fn default() -> Self {
+        ChunkedResultOptions {
+            threshold_bytes: 2 << 20,
+            chunk_bytes: 256 << 10,
+        }
+    }
+}
+
+/// A piece of a result serialized as JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct ResultChunk {
+    /// Number of the result the chunk belongs to, counting from 0.
+    pub result: u64,
+    /// Position of the chunk within its result, counting from 0.
+    pub index: u32,
+    /// Whether the chunk completes its result.
+    pub last: bool,
+    /// UTF-8 bytes of the JSON. Multi-byte characters may span two chunks.
+    pub data: Vec<u8>,
+}
+
+/// Splits `value`, the `result`th result, into chunks.
+fn split(value: String, result: u64, options: &ChunkedResultOptions) -> Vec<ResultChunk> {
+    let bytes = value.into_bytes();
+    if bytes.len() as u64 <= options.threshold_bytes {
+        return vec![ResultChunk {
+            result,
+            index: 0,
+            last: true,
+            data: bytes,
+        }];
+    }
+    let chunks: Vec<_> = bytes.chunks(options.chunk_bytes.max(1) as usize).collect();
+    let count = chunks.len();
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(index, data)| ResultChunk {
+            result,
+            index: index as u32,
+            last: index + 1 == count,
+            data: data.to_vec(),
+        })
+        .collect()
+}
+
+type OnChunk = dyn Fn(ResultChunk) -> DartFnFuture<()> + Send + Sync;
+type OnError = dyn Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync;
+
+/// What a [`ChunkedSubscriber`] hands to Dart.
+#[frb(ignore)]
+enum Delivery {
+    Update(String),
+    Error(String, Option<String>),
+}
+
+/// Queues the updates of a subscription for a task delivering them in
+/// chunks, so the chunks of consecutive results never interleave.
+#[frb(ignore)]
+struct ChunkedSubscriber {
+    deliveries: mpsc::UnboundedSender<Delivery>,
+}
+
+impl ChunkedSubscriber {
+    fn spawn(
+        rt: &tokio::runtime::Handle,
+        options: ChunkedResultOptions,
+        on_chunk: Box<OnChunk>,
+        on_error: Box<OnError>,
+    ) -> Self {
+        let (deliveries, mut queued) = mpsc::unbounded_channel();
+        rt.spawn(async move {
+            let mut result = 0;
+            while let Some(delivery) = queued.recv().await {
+                match delivery {
+                    Delivery::Update(value) => {
+                        for chunk in split(value, result, &options) {
+                            on_chunk(chunk).await;
+                        }
+                        result += 1;
+                    }
+                    Delivery::Error(message, value) => on_error(message, value).await,
+                }
+            }
+        });
+        ChunkedSubscriber { deliveries }
+    }
+}
+
+impl QuerySubscriber for ChunkedSubscriber {
+    fn on_update(&self, value: String) {
+        let _ = self.deliveries.send(Delivery::Update(value));
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        let _ = self.deliveries.send(Delivery::Error(message, value));
+    }
+}
+
+impl MobileConvexClient {
+    /// Executes a query and delivers its result to `on_chunk` in chunks, as
+    /// described in the [module docs](crate::chunked). Resolves once the
+    /// last chunk was handled. Fails with [`ClientError::Timeout`] if the
+    /// result has not arrived after `timeout_ms` milliseconds, if set.
+    #[frb]
+    pub async fn query_streamed(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        on_chunk: impl Fn(ResultChunk) -> DartFnFuture<()> + Send + Sync + 'static,
+        timeout_ms: Option<u64>,
+    ) -> Result<(), ClientError> {
+        let value = with_timeout(timeout_ms, async {
+            let args = self.parse_args(args)?;
+            self.format_result(self.internal_query(name, args).await?)
+        })
+        .await?;
+        for chunk in split(value, 0, &self.options.chunked_results) {
+            on_chunk(chunk).await;
+        }
+        Ok(())
+    }
+
+    /// Like [`MobileConvexClient::subscribe`], but delivers every result to
+    /// `on_chunk` in chunks, as described in the
+    /// [module docs](crate::chunked).
+    #[frb]
+    pub async fn subscribe_chunked(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        on_chunk: impl Fn(ResultChunk) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        let args = self.parse_args(args)?;
+        let subscriber = Arc::new(ChunkedSubscriber::spawn(
+            &self.rt,
+            self.options.chunked_results.clone(),
+            Box::new(on_chunk),
+            Box::new(on_error),
+        ));
+        self.internal_subscribe(name, args, subscriber, SubscriptionPriority::Normal)
+            .await
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_results_above_the_threshold_are_split() {
+        let options = ChunkedResultOptions {
+            threshold_bytes: 8,
+            chunk_bytes: 4,
+        };
+        let small = split("[1,2,3]".to_owned(), 0, &options);
+        assert_eq!(small.len(), 1);
+        assert!(small[0].last);
+
+        let large = split("\"añbcdefghi\"".to_owned(), 1, &options);
+        assert_eq!(
+            large.iter().map(|chunk| chunk.index).collect::<Vec<_>>(),
+            [0, 1, 2, 3]
+        );
+        assert!(large.iter().all(|chunk| chunk.result == 1));
+        assert_eq!(
+            large.iter().map(|chunk| chunk.last).collect::<Vec<_>>(),
+            [false, false, false, true]
+        );
+        let joined: Vec<u8> = large.into_iter().flat_map(|chunk| chunk.data).collect();
+        assert_eq!(String::from_utf8(joined).unwrap(), "\"añbcdefghi\"");
+    }
+}
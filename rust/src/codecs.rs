@@ -0,0 +1,265 @@
+//! Conversion of custom Dart types at the FFI boundary.
+//!
+//! Domain types such as `DateTime` or `Decimal` have no Convex counterpart,
+//! so every call site used to convert them by hand. With
+//! [`crate::options::ClientOptions::type_codecs`], Dart passes them as
+//! markers of the form `{"$type": "DateTime", "value": 1700000000000}`
+//! anywhere in arguments, and each [`TypeCodec`] checks and converts the
+//! value to its wire type. Convex rejects field names starting with `$`, so
+//! markers cannot clash with real data.
+//!
+//! Results cannot carry the type, so codecs name the object fields holding
+//! their type instead. Such fields are wrapped into markers in the JSON of
+//! query, mutation and action results (cached or not) and of subscription
+//! updates, leaving Dart a single place to turn markers into domain objects.
+//! Structured [`crate::convex_value::ConvexValue`] arguments and results are
+//! not converted.
+
+use std::sync::Arc;
+
+use anyhow::{bail, Context};
+use convex::FunctionResult;
+use flutter_rust_bridge::frb;
+use serde::Deserialize;
+use serde_json::{Map, Number, Value as JsonValue};
+
+use crate::{
+    encryption::FieldEncryption, result::handle_direct_function_result, ClientError,
+    MobileConvexClient, QuerySubscriber,
+};
+
+/// Key naming the type of a marker object.
+const TYPE_MARKER: &str = "$type";
+/// Key holding the encoded value of a marker object.
+const VALUE_KEY: &str = "value";
+
+/// Convex type a custom type is sent as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[frb]
+pub enum CodecWire {
+    /// A number, e.g. milliseconds since the epoch for `DateTime`. Numeric
+    /// strings are accepted as well.
+    Float64,
+    /// A string, e.g. the decimal text of a `Decimal`. Numbers are accepted
+    /// and converted to their text.
+    String,
+}
+
+/// How a custom Dart type crosses the FFI.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct TypeCodec {
+    /// Name used in markers, e.g. `DateTime`.
+    pub type_name: String,
+    pub wire: CodecWire,
+    /// Object fields of results holding this type, e.g. `createdAt`.
+    #[serde(default)]
+    pub fields: Vec<String>,
+}
+
+impl TypeCodec {
+    fn encode(&self, value: JsonValue) -> anyhow::Result<JsonValue> {
+        let encoded = match (self.wire, value) {
+            (CodecWire::Float64, JsonValue::Number(n)) => JsonValue::Number(n),
+            (CodecWire::Float64, JsonValue::String(s)) => s
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .and_then(Number::from_f64)
+                .map(JsonValue::Number)
+                .with_context(|| format!("`{s}` is not a number"))?,
+            (CodecWire::String, JsonValue::String(s)) => JsonValue::String(s),
+            (CodecWire::String, JsonValue::Number(n)) => JsonValue::String(n.to_string()),
+            (wire, other) => bail!("{other} cannot be sent as {wire:?}"),
+        };
+        Ok(encoded)
+    }
+
+    fn decodes(&self, name: &str, value: &JsonValue) -> bool {
+        let matches_wire = match self.wire {
+            CodecWire::Float64 => value.is_number(),
+            CodecWire::String => value.is_string(),
+        };
+        matches_wire && self.fields.iter().any(|field| field == name)
+    }
+}
+
+fn marker_type(map: &Map<String, JsonValue>) -> Option<&str> {
+    if map.len() != 2 || !map.contains_key(VALUE_KEY) {
+        return None;
+    }
+    map.get(TYPE_MARKER)?.as_str()
+}
+
+/// Replaces the markers in `json` by their encoded values.
+pub(crate) fn encode_markers(json: JsonValue, codecs: &[TypeCodec]) -> anyhow::Result<JsonValue> {
+    match json {
+        JsonValue::Object(mut map) => {
+            if let Some(type_name) = marker_type(&map).map(str::to_owned) {
+                let codec = codecs
+                    .iter()
+                    .find(|codec| codec.type_name == type_name)
+                    .with_context(|| format!("No codec registered for type `{type_name}`"))?;
+                let value = map.remove(VALUE_KEY).unwrap_or_default();
+                return codec
+                    .encode(value)
+                    .with_context(|| format!("Invalid `{type_name}` value"));
+            }
+            map.into_iter()
+                .map(|(key, value)| Ok((key, encode_markers(value, codecs)?)))
+                .collect::<anyhow::Result<Map<_, _>>>()
+                .map(JsonValue::Object)
+        }
+        JsonValue::Array(items) => items
+            .into_iter()
+            .map(|item| encode_markers(item, codecs))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .map(JsonValue::Array),
+        other => Ok(other),
+    }
+}
+
+fn wrap_fields(json: JsonValue, codecs: &[TypeCodec]) -> JsonValue {
+    match json {
+        JsonValue::Object(map) => map
+            .into_iter()
+            .map(|(name, value)| {
+                let value = match codecs.iter().find(|codec| codec.decodes(&name, &value)) {
+                    Some(codec) => JsonValue::Object(Map::from_iter([
+                        (TYPE_MARKER.to_owned(), codec.type_name.clone().into()),
+                        (VALUE_KEY.to_owned(), value),
+                    ])),
+                    None => wrap_fields(value, codecs),
+                };
+                (name, value)
+            })
+            .collect(),
+        JsonValue::Array(items) => items
+            .into_iter()
+            .map(|item| wrap_fields(item, codecs))
+            .collect(),
+        other => other,
+    }
+}
+
+/// Wraps the result fields declared by `codecs` into markers. Already
+/// wrapped fields are left alone.
+pub(crate) fn decode_fields(json: String, codecs: &[TypeCodec]) -> String {
+    if codecs.iter().all(|codec| codec.fields.is_empty()) {
+        return json;
+    }
+    match serde_json::from_str(&json) {
+        Ok(parsed) => wrap_fields(parsed, codecs).to_string(),
+        Err(_) => json,
+    }
+}
+
+/// Wraps codec fields and decrypts encrypted fields in the updates passed
+/// to `inner`.
+#[frb(ignore)]
+struct DecodingSubscriber {
+    inner: Arc<dyn QuerySubscriber>,
+    codecs: Arc<[TypeCodec]>,
+    encryption: FieldEncryption,
+}
+
+impl QuerySubscriber for DecodingSubscriber {
+    fn on_update(&self, value: String) {
+        let value = decode_fields(value, &self.codecs);
+        self.inner.on_update(self.encryption.decrypt_result(value));
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.inner.on_error(message, value);
+    }
+
+    fn on_done(&self) {
+        self.inner.on_done();
+    }
+}
+
+impl MobileConvexClient {
+    /// Serializes a function result, wrapping codec fields.
+    pub(crate) fn format_result(&self, result: FunctionResult) -> Result<String, ClientError> {
+        handle_direct_function_result(result, self.options.int64_encoding)
+            .map(|json| self.decode_result(json))
+    }
+
+    /// Wraps codec fields in serialized result JSON.
+    pub(crate) fn decode_result(&self, json: String) -> String {
+        let json = decode_fields(json, &self.options.type_codecs);
+        self.field_encryption.decrypt_result(json)
+    }
+
+    /// Returns `subscriber`, wrapping codec fields and decrypting encrypted
+    /// fields in its updates.
+    pub(crate) fn decoding_subscriber(
+        &self,
+        subscriber: Arc<dyn QuerySubscriber>,
+    ) -> Arc<dyn QuerySubscriber> {
+        Arc::new(DecodingSubscriber {
+            inner: subscriber,
+            codecs: self.options.type_codecs.as_slice().into(),
+            encryption: self.field_encryption.clone(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn codecs() -> Vec<TypeCodec> {
+        vec![
+            TypeCodec {
+                type_name: "DateTime".into(),
+                wire: CodecWire::Float64,
+                fields: vec!["createdAt".into()],
+            },
+            TypeCodec {
+                type_name: "Decimal".into(),
+                wire: CodecWire::String,
+                fields: vec!["price".into()],
+            },
+        ]
+    }
+
+    #[test]
+    fn markers_are_encoded_as_their_wire_type() {
+        let args = json!({
+            "since": {"$type": "DateTime", "value": "1700000000000"},
+            "items": [{"price": {"$type": "Decimal", "value": 9.5}}],
+        });
+        assert_eq!(
+            encode_markers(args, &codecs()).unwrap(),
+            json!({"since": 1700000000000.0, "items": [{"price": "9.5"}]})
+        );
+
+        let unknown = json!({"$type": "Money", "value": 1});
+        let err = encode_markers(unknown, &codecs()).unwrap_err();
+        assert!(err.to_string().contains("Money"), "{err}");
+        let invalid = json!({"$type": "DateTime", "value": true});
+        assert!(encode_markers(invalid, &codecs()).is_err());
+    }
+
+    #[test]
+    fn declared_result_fields_are_wrapped_once() {
+        let result = json!([{"createdAt": 1.0, "price": "2.50", "name": "a"}]).to_string();
+        let decoded = decode_fields(result, &codecs());
+        let expected = json!([{
+            "createdAt": {"$type": "DateTime", "value": 1.0},
+            "price": {"$type": "Decimal", "value": "2.50"},
+            "name": "a",
+        }]);
+        assert_eq!(
+            serde_json::from_str::<JsonValue>(&decoded).unwrap(),
+            expected
+        );
+        let again = decode_fields(decoded.clone(), &codecs());
+        assert_eq!(again, decoded);
+    }
+}
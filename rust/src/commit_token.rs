@@ -0,0 +1,137 @@
+//! Read-your-writes across clients.
+//!
+//! A mutation's writes are visible to every query evaluated at a backend
+//! timestamp at or after its commit. The Convex Rust client does not expose
+//! those timestamps, so [`MobileConvexClient::mutation_with_commit_token`]
+//! asks the deployment for its latest snapshot timestamp once the mutation
+//! committed, which is at least the commit timestamp. Another client, e.g.
+//! in a share extension, passes that token to
+//! [`MobileConvexClient::query_at_least`], which waits until the deployment
+//! reached it before querying.
+
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, bail};
+use base64::Engine;
+use flutter_rust_bridge::frb;
+use serde_json::Value as JsonValue;
+
+use crate::{http::post_json, ClientError, MobileConvexClient};
+
+/// How long [`MobileConvexClient::query_at_least`] waits for the deployment.
+const COMMIT_WAIT_TIMEOUT: Duration = Duration::from_secs(30);
+/// First and longest delay between snapshot timestamp checks.
+const MIN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+const MAX_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The result of [`MobileConvexClient::mutation_with_commit_token`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct CommittedMutation {
+    /// The mutation's return value, serialized as JSON.
+    pub value: String,
+    /// A backend timestamp at or after the mutation's commit, to pass to
+    /// [`MobileConvexClient::query_at_least`].
+    pub commit_token: String,
+}
+
+/// Reads a timestamp, sent either as a number, a decimal string or an
+/// encoded Convex `Int64`.
+fn parse_timestamp(value: &JsonValue) -> Option<u64> {
+    match value {
+        JsonValue::Number(number) => number.as_u64(),
+        JsonValue::String(digits) => digits.parse().ok(),
+        JsonValue::Object(object) => {
+            let encoded = object.get("$integer")?.as_str()?;
+            let bytes = base64::engine::general_purpose::STANDARD
+                .decode(encoded)
+                .ok()?;
+            Some(u64::from_le_bytes(bytes.try_into().ok()?))
+        }
+        _ => None,
+    }
+}
+
+impl MobileConvexClient {
+    /// Returns the timestamp of the deployment's latest snapshot.
+    async fn snapshot_timestamp(&self) -> anyhow::Result<u64> {
+        self.ensure_open()?;
+        let url = self.failover.active_url().to_owned();
+        let token = self.auth_token.lock().clone();
+        let response = post_json(&url, "/api/query_ts", token.as_deref(), "{}").await?;
+        response
+            .get("ts")
+            .and_then(parse_timestamp)
+            .ok_or_else(|| anyhow!("unexpected snapshot timestamp response: {response}"))
+    }
+
+    /// Waits until the deployment's snapshot reached `target`.
+    async fn wait_for_timestamp(&self, target: u64) -> anyhow::Result<()> {
+        let started = Instant::now();
+        let mut interval = MIN_POLL_INTERVAL;
+        while self.snapshot_timestamp().await? < target {
+            if started.elapsed() >= COMMIT_WAIT_TIMEOUT {
+                bail!("the deployment did not reach commit token {target} in time");
+            }
+            tokio::time::sleep(interval).await;
+            interval = (interval * 2).min(MAX_POLL_INTERVAL);
+        }
+        Ok(())
+    }
+
+    /// Executes a mutation like [`MobileConvexClient::mutation`] and returns
+    /// its result together with a commit token.
+    #[frb]
+    pub async fn mutation_with_commit_token(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+    ) -> Result<CommittedMutation, ClientError> {
+        let value = self.mutation(name, args, None).await?;
+        let ts = self.snapshot_timestamp().await?;
+        Ok(CommittedMutation {
+            value,
+            commit_token: ts.to_string(),
+        })
+    }
+
+    /// Executes a query once the deployment's snapshot is at or beyond `ts`,
+    /// a commit token returned by
+    /// [`MobileConvexClient::mutation_with_commit_token`] on any client of the
+    /// same deployment. Queries are evaluated at the latest snapshot, so the
+    /// result includes that mutation's writes.
+    #[frb]
+    pub async fn query_at_least(
+        &self,
+        ts: String,
+        name: String,
+        args: HashMap<String, String>,
+    ) -> Result<String, ClientError> {
+        let target = ts
+            .parse()
+            .map_err(|_| anyhow!("invalid commit token `{ts}`"))?;
+        self.wait_for_timestamp(target).await?;
+        self.query(name, args, None).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn timestamps_are_read_in_every_encoding() {
+        let ts = 1_700_000_000_123_456_789u64;
+        let encoded = base64::engine::general_purpose::STANDARD.encode(ts.to_le_bytes());
+        assert_eq!(parse_timestamp(&json!(ts)), Some(ts));
+        assert_eq!(parse_timestamp(&json!(ts.to_string())), Some(ts));
+        assert_eq!(parse_timestamp(&json!({ "$integer": encoded })), Some(ts));
+        assert_eq!(parse_timestamp(&json!({ "$integer": "AQ==" })), None);
+        assert_eq!(parse_timestamp(&json!(null)), None);
+    }
+}
@@ -0,0 +1,171 @@
+//! Client configuration loaded from JSON, with one entry per app flavor.
+//!
+//! White-label apps bundle a single configuration asset and pick the flavor
+//! at startup instead of building [`ClientOptions`] by hand in Dart:
+//!
+//! ```json
+//! {
+//!   "defaults": { "int64Encoding": "string" },
+//!   "flavors": {
+//!     "acme": {
+//!       "deploymentUrl": "https://acme.convex.cloud",
+//!       "options": { "connectRetry": { "maxAttempts": 8 } }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! A flavor's `options` are merged over `defaults`, with nested objects
+//! merged field by field. Option names are the camelCase forms of the
+//! [`ClientOptions`] fields; unknown names and invalid URLs are rejected so
+//! typos surface at startup rather than as silently ignored settings.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, Context};
+use flutter_rust_bridge::frb;
+use serde::Deserialize;
+use serde_json::{Map, Value as JsonValue};
+
+use crate::{options::ClientOptions, ClientError, MobileConvexClient};
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct ClientConfig {
+    #[serde(default)]
+    defaults: Map<String, JsonValue>,
+    flavors: BTreeMap<String, FlavorConfig>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct FlavorConfig {
+    deployment_url: String,
+    #[serde(default)]
+    options: Map<String, JsonValue>,
+}
+
+/// Merges `overrides` into `base`, recursing into nested objects.
+fn merge(base: &mut Map<String, JsonValue>, overrides: Map<String, JsonValue>) {
+    for (key, value) in overrides {
+        match (base.get_mut(&key), value) {
+            (Some(JsonValue::Object(base)), JsonValue::Object(value)) => merge(base, value),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+fn validate_url(url: &str, what: &str) -> anyhow::Result<()> {
+    if !(url.starts_with("https://") || url.starts_with("http://")) {
+        bail!("{what} `{url}` must start with https:// or http://");
+    }
+    Ok(())
+}
+
+/// Resolves the deployment URL and options of `flavor`.
+pub(crate) fn load_flavor(
+    config_json: &str,
+    flavor: &str,
+) -> anyhow::Result<(String, ClientOptions)> {
+    let config: ClientConfig =
+        serde_json::from_str(config_json).context("Invalid client configuration")?;
+    let ClientConfig {
+        mut defaults,
+        mut flavors,
+    } = config;
+    let Some(FlavorConfig {
+        deployment_url,
+        options,
+    }) = flavors.remove(flavor)
+    else {
+        let known: Vec<_> = flavors.keys().map(String::as_str).collect();
+        bail!(
+            "Unknown flavor `{flavor}`; configured: {}",
+            known.join(", ")
+        );
+    };
+    merge(&mut defaults, options);
+    let options: ClientOptions = serde_json::from_value(JsonValue::Object(defaults))
+        .with_context(|| format!("Invalid options for flavor `{flavor}`"))?;
+
+    validate_url(&deployment_url, "Deployment URL")?;
+    if let Some(failover) = &options.failover {
+        for url in &failover.fallback_urls {
+            validate_url(url, "Fallback URL")?;
+        }
+    }
+    Ok((deployment_url, options))
+}
+
+impl MobileConvexClient {
+    /// Creates a client for one flavor of a JSON client configuration, as
+    /// described in the [module docs](crate::config).
+    #[frb(sync)]
+    pub fn from_config(
+        config_json: String,
+        flavor: String,
+        client_id: String,
+    ) -> Result<MobileConvexClient, ClientError> {
+        // Keep the causes: they name the offending option.
+        let (deployment_url, options) = load_flavor(&config_json, &flavor)
+            .map_err(|e| ClientError::internal(format!("{e:#}")))?;
+        Ok(Self::new_with_options(deployment_url, client_id, options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::Int64Encoding;
+
+    const CONFIG: &str = r#"{
+        "defaults": {
+            "int64Encoding": "string",
+            "connectRetry": { "maxAttempts": 3, "initialBackoffMs": 100 }
+        },
+        "flavors": {
+            "acme": {
+                "deploymentUrl": "https://acme.convex.cloud",
+                "options": {
+                    "connectRetry": { "maxAttempts": 8 },
+                    "failover": {
+                        "fallbackUrls": ["https://acme-backup.convex.cloud"],
+                        "failoverAfterMs": 5000,
+                        "failbackProbeIntervalMs": 60000
+                    }
+                }
+            },
+            "plain": { "deploymentUrl": "https://plain.convex.cloud" }
+        }
+    }"#;
+
+    #[test]
+    fn flavor_options_are_merged_over_defaults() {
+        let (url, options) = load_flavor(CONFIG, "acme").unwrap();
+        assert_eq!(url, "https://acme.convex.cloud");
+        assert_eq!(options.int64_encoding, Int64Encoding::String);
+        assert_eq!(options.connect_retry.max_attempts, 8);
+        assert_eq!(options.connect_retry.initial_backoff_ms, 100);
+        assert_eq!(options.failover.unwrap().failover_after_ms, 5000);
+
+        let (_, options) = load_flavor(CONFIG, "plain").unwrap();
+        assert_eq!(options.connect_retry.max_attempts, 3);
+        assert!(options.failover.is_none());
+    }
+
+    #[test]
+    fn invalid_configurations_are_rejected() {
+        let err = load_flavor(CONFIG, "missing").unwrap_err();
+        assert!(err.to_string().contains("configured: acme, plain"), "{err}");
+
+        let typo = r#"{"flavors": {"a": {"deploymentUrl": "https://a.convex.cloud",
+            "options": {"int64Encodng": "string"}}}}"#;
+        let err = load_flavor(typo, "a").unwrap_err();
+        assert!(format!("{err:#}").contains("int64Encodng"), "{err:#}");
+
+        let bad_url = r#"{"flavors": {"a": {"deploymentUrl": "acme.convex.cloud"}}}"#;
+        assert!(load_flavor(bad_url, "a").is_err());
+    }
+}
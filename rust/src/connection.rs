@@ -0,0 +1,587 @@
+//! Building the Convex client, with retries.
+//!
+//! The client is built lazily by the first call that needs it. A failed build
+//! (for example, no network at startup) is retried with exponential backoff
+//! before the error reaches the caller; concurrent callers wait for the same
+//! attempt instead of each starting their own. The reason of the last failure
+//! is kept for [`MobileConvexClient::last_connection_error`], and
+//! [`MobileConvexClient::reconnect`] replaces the client without recreating
+//! the [`MobileConvexClient`].
+//!
+//! [`WebSocketConnectionState`] also reports the states only this layer
+//! knows about: waiting to retry a failed build, giving up, and closing the
+//! WebSocket on purpose. The Convex client does not report why an open
+//! WebSocket closed, so a dropped connection shows up as
+//! [`ConnectionCloseReason::ConnectionLost`] while it reconnects.
+//!
+//! Since every call waits for the same build, a hanging one stalls the whole
+//! app. [`MobileConvexClient::initialization_diagnostics`] tells whether and
+//! when the client was built and how many calls are waiting for it, and
+//! [`MobileConvexClient::on_slow_initialization`] reports builds taking
+//! longer than [`crate::options::ClientOptions::slow_initialization_ms`].
+
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicBool, AtomicI64, AtomicU32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use convex::{ConvexClient, WebSocketState};
+use flutter_rust_bridge::{frb, DartFnFuture};
+use futures::{pin_mut, select_biased, FutureExt};
+use log::{debug, warn};
+use parking_lot::Mutex;
+use serde::Deserialize;
+use tokio::{
+    sync::{broadcast, watch},
+    time::Instant,
+};
+
+use crate::{
+    backoff::exponential_backoff,
+    errors::{with_code, ErrorCode},
+    presence::now_millis,
+    ClientError, ClientFactory, MobileConvexClient, WebSocketConnectionState,
+};
+
+/// Why the WebSocket closed, as reported by
+/// [`WebSocketConnectionState::Closed`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum ConnectionCloseReason {
+    /// The open WebSocket dropped, e.g. because the network went away or the
+    /// server closed it; the Convex client is reconnecting.
+    ConnectionLost,
+    /// Closed by [`MobileConvexClient::disconnect`].
+    Disconnected,
+    /// Closed by [`MobileConvexClient::pause`].
+    Paused,
+    /// Closed by [`MobileConvexClient::close`].
+    ClientClosed,
+}
+
+/// Turns the states reported by one Convex client into
+/// [`WebSocketConnectionState`]s. The Convex client reports reconnecting
+/// after an open WebSocket closed the same way as opening the first one.
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct SocketStates {
+    was_connected: bool,
+}
+
+impl SocketStates {
+    pub(crate) fn report(&mut self, state: WebSocketState) -> WebSocketConnectionState {
+        match state {
+            WebSocketState::Connected => {
+                self.was_connected = true;
+                WebSocketConnectionState::Connected
+            }
+            WebSocketState::Connecting if self.was_connected => WebSocketConnectionState::Closed {
+                reason: ConnectionCloseReason::ConnectionLost,
+            },
+            WebSocketState::Connecting => WebSocketConnectionState::Connecting,
+        }
+    }
+}
+
+/// Retry policy for building the Convex client.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct ConnectRetryOptions {
+    /// Delay before the first retry; doubled after every failed attempt.
+    pub initial_backoff_ms: u64,
+    /// Upper bound for the delay between attempts.
+    pub max_backoff_ms: u64,
+    /// Attempts per call, including the first one.
+    pub max_attempts: u32,
+}
+
+impl Default for ConnectRetryOptions {
+    fn default() -> Self {
+        ConnectRetryOptions {
+            initial_backoff_ms: 500,
+            max_backoff_ms: 30_000,
+            max_attempts: 5,
+        }
+    }
+}
+
+impl ConnectRetryOptions {
+    /// Delay after the failed attempt number `attempt` (starting at 1).
+    fn backoff(&self, attempt: u32) -> Duration {
+        exponential_backoff(self.initial_backoff_ms, self.max_backoff_ms, attempt)
+    }
+}
+
+/// State of the lazily built Convex client, as returned by
+/// [`MobileConvexClient::initialization_diagnostics`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct InitializationDiagnostics {
+    /// Whether a client is built and not disconnected.
+    pub initialized: bool,
+    /// Unix time in milliseconds the current client was built at.
+    pub initialized_at_ms: Option<i64>,
+    /// How long the last successful build took, retries included.
+    pub last_duration_ms: Option<u64>,
+    /// Calls currently waiting for the client.
+    pub waiting_callers: u32,
+    /// Why the last attempt to build the client failed, if it did.
+    pub last_error: Option<String>,
+}
+
+/// Emitted once a build of the client takes longer than
+/// [`crate::options::ClientOptions::slow_initialization_ms`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct SlowInitializationEvent {
+    /// Time spent building so far.
+    pub elapsed_ms: u64,
+    /// Calls waiting for the build to finish.
+    pub waiting_callers: u32,
+}
+
+/// Tracks client builds for the lifetime of a [`MobileConvexClient`].
+pub(crate) struct ConnectionManager {
+    last_error: Mutex<Option<String>>,
+    failover_started: AtomicBool,
+    // Shared with the client factory, which reports the WebSocket states
+    state: Arc<watch::Sender<WebSocketConnectionState>>,
+    // Unix time in milliseconds of the last build, 0 if none
+    initialized_at_ms: AtomicI64,
+    last_duration_ms: Mutex<Option<u64>>,
+    waiting_callers: AtomicU32,
+    slow_after: Option<Duration>,
+    slow_events: broadcast::Sender<SlowInitializationEvent>,
+}
+
+/// A call waiting for the client, counted until dropped.
+pub(crate) struct WaitingCaller<'a>(&'a ConnectionManager);
+
+impl Drop for WaitingCaller<'_> {
+    fn drop(&mut self) {
+        self.0.waiting_callers.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+impl ConnectionManager {
+    /// Creates a manager reporting builds slower than
+    /// `slow_initialization_ms`, if set, and failed builds to `state`.
+    pub(crate) fn new(
+        slow_initialization_ms: Option<u64>,
+        state: Arc<watch::Sender<WebSocketConnectionState>>,
+    ) -> Self {
+        ConnectionManager {
+            last_error: Mutex::new(None),
+            failover_started: AtomicBool::new(false),
+            state,
+            initialized_at_ms: AtomicI64::new(0),
+            last_duration_ms: Mutex::new(None),
+            waiting_callers: AtomicU32::new(0),
+            slow_after: slow_initialization_ms.map(Duration::from_millis),
+            slow_events: broadcast::channel(4).0,
+        }
+    }
+
+    pub(crate) fn caller_waiting(&self) -> WaitingCaller<'_> {
+        self.waiting_callers.fetch_add(1, Ordering::SeqCst);
+        WaitingCaller(self)
+    }
+
+    fn diagnostics(&self) -> InitializationDiagnostics {
+        let initialized_at_ms =
+            Some(self.initialized_at_ms.load(Ordering::SeqCst)).filter(|at| *at != 0);
+        InitializationDiagnostics {
+            initialized: initialized_at_ms.is_some(),
+            initialized_at_ms,
+            last_duration_ms: *self.last_duration_ms.lock(),
+            waiting_callers: self.waiting_callers.load(Ordering::SeqCst),
+            last_error: self.last_error.lock().clone(),
+        }
+    }
+
+    /// Awaits `build`, reporting it once it takes longer than the threshold
+    /// and recording when it succeeded.
+    async fn observe_build<T>(
+        &self,
+        build: impl Future<Output = anyhow::Result<T>>,
+    ) -> anyhow::Result<T> {
+        let started = Instant::now();
+        let build = build.fuse();
+        pin_mut!(build);
+        let result = match self.slow_after {
+            Some(threshold) => {
+                let timer = tokio::time::sleep(threshold).fuse();
+                pin_mut!(timer);
+                select_biased! {
+                    result = build => result,
+                    _ = timer => {
+                        let event = SlowInitializationEvent {
+                            elapsed_ms: started.elapsed().as_millis() as u64,
+                            waiting_callers: self.waiting_callers.load(Ordering::SeqCst),
+                        };
+                        warn!("Building the Convex client is slow: {event:?}");
+                        let _ = self.slow_events.send(event);
+                        build.await
+                    }
+                }
+            }
+            None => build.await,
+        };
+        if result.is_ok() {
+            self.initialized_at_ms.store(now_millis(), Ordering::SeqCst);
+            *self.last_duration_ms.lock() = Some(started.elapsed().as_millis() as u64);
+        }
+        result
+    }
+
+    fn set_state(&self, state: WebSocketConnectionState) {
+        self.state.send_replace(state);
+    }
+
+    /// Records that the client was dropped on purpose.
+    pub(crate) fn mark_disconnected(&self) {
+        self.initialized_at_ms.store(0, Ordering::SeqCst);
+    }
+
+    /// Builds a client for `url`, retrying failed attempts per `retry`.
+    pub(crate) async fn build(
+        &self,
+        factory: &ClientFactory,
+        url: &str,
+        retry: &ConnectRetryOptions,
+    ) -> anyhow::Result<ConvexClient> {
+        self.observe_build(self.build_with_retries(factory, url, retry))
+            .await
+    }
+
+    async fn build_with_retries(
+        &self,
+        factory: &ClientFactory,
+        url: &str,
+        retry: &ConnectRetryOptions,
+    ) -> anyhow::Result<ConvexClient> {
+        let max_attempts = retry.max_attempts.max(1);
+        let mut attempt = 1;
+        loop {
+            match factory.build(url).await {
+                Ok(client) => {
+                    self.last_error.lock().take();
+                    return Ok(client);
+                }
+                Err(e) => {
+                    let reason = format!("{e:#}");
+                    *self.last_error.lock() = Some(reason.clone());
+                    if attempt >= max_attempts {
+                        self.set_state(WebSocketConnectionState::Failed { reason });
+                        let e = e.context(format!(
+                            "Failed to connect to {url} after {attempt} attempts"
+                        ));
+                        return Err(with_code(e, ErrorCode::Network));
+                    }
+                    let delay = retry.backoff(attempt);
+                    warn!("Connecting to {url} failed ({e}), retrying in {delay:?}");
+                    self.set_state(WebSocketConnectionState::Backoff {
+                        retry_in_ms: delay.as_millis() as u64,
+                        attempt,
+                    });
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    /// Returns `true` the first time it is called.
+    pub(crate) fn start_failover_once(&self) -> bool {
+        !self.failover_started.swap(true, Ordering::SeqCst)
+    }
+}
+
+impl MobileConvexClient {
+    /// Opens the WebSocket now rather than on the first call, e.g. to warm
+    /// the connection up during a splash screen, and re-establishes the
+    /// subscriptions stopped by [`MobileConvexClient::disconnect`] or
+    /// [`MobileConvexClient::pause`].
+    #[frb]
+    pub async fn connect(&self) -> Result<(), ClientError> {
+        if self.lifecycle.is_paused() {
+            return self.resume().await;
+        }
+        self.connected_client().await?;
+        Ok(())
+    }
+
+    /// Closes the WebSocket while keeping the client usable, e.g. while the
+    /// app is idle.
+    ///
+    /// Subscriptions are stopped as by [`MobileConvexClient::pause`], so
+    /// nothing keeps the connection alive; their handles stay valid and
+    /// [`MobileConvexClient::connect`] re-establishes them. Queries and
+    /// other calls made meanwhile open a new WebSocket on their own, while
+    /// subscriptions wait for `connect`.
+    #[frb]
+    pub async fn disconnect(&self) -> Result<(), ClientError> {
+        self.ensure_open()?;
+        self.lifecycle.pause();
+        self.disconnect_for(ConnectionCloseReason::Disconnected)
+            .await
+    }
+
+    /// Drops the client, reporting the WebSocket as closed for `reason`.
+    pub(crate) async fn disconnect_for(
+        &self,
+        reason: ConnectionCloseReason,
+    ) -> Result<(), ClientError> {
+        self.ensure_open()?;
+        self.flush_deferred().await;
+        let client = self.client.lock().await.take();
+        if client.is_some() {
+            debug!("Disconnecting");
+            self.client_factory.retire(reason);
+            self.connection.mark_disconnected();
+        }
+        Ok(())
+    }
+
+    /// Returns why the last attempt to connect failed, or `None` if the last
+    /// attempt succeeded or none was made yet.
+    #[frb(sync)]
+    pub fn last_connection_error(&self) -> Option<String> {
+        self.connection.last_error.lock().clone()
+    }
+
+    /// Returns whether and when the Convex client was built, and how many
+    /// calls are waiting for it.
+    #[frb(sync)]
+    pub fn initialization_diagnostics(&self) -> InitializationDiagnostics {
+        self.connection.diagnostics()
+    }
+
+    /// Registers a callback invoked whenever building the Convex client
+    /// takes longer than [`crate::options::ClientOptions::slow_initialization_ms`].
+    #[frb]
+    pub async fn on_slow_initialization(
+        &self,
+        on_slow: impl Fn(SlowInitializationEvent) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let mut events = self.connection.slow_events.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => on_slow(event).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Replaces the Convex client with a freshly built one for the active
+    /// deployment, re-applying the current auth token.
+    ///
+    /// Use this to recover after connecting failed, without recreating the
+    /// whole client. Running subscriptions are moved to the new client,
+    /// highest priority first, and keep their handles.
+    #[frb]
+    pub async fn reconnect(&self) -> Result<(), ClientError> {
+        self.ensure_open()?;
+        let mut slot = self.client.lock().await;
+        let url = self.failover.active_url().to_owned();
+        debug!("Reconnecting to {url}");
+        let mut client = self
+            .connection
+            .build(&self.client_factory, &url, &self.options.connect_retry)
+            .await?;
+        let token = self.auth_token.lock().clone();
+        if token.is_some() {
+            client.set_auth(token).await;
+        }
+        self.start_schema_check(&client);
+        *slot = Some(client);
+        drop(slot);
+        self.start_failover();
+        self.move_subscriptions().await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::oneshot;
+
+    use super::*;
+    use crate::{options::ClientOptions, QuerySubscriber, SubscriptionHandle};
+
+    #[test]
+    fn backoff_doubles_up_to_the_limit() {
+        let retry = ConnectRetryOptions {
+            initial_backoff_ms: 100,
+            max_backoff_ms: 1_000,
+            max_attempts: 10,
+        };
+        let delays: Vec<_> = (1..=6).map(|attempt| retry.backoff(attempt)).collect();
+        assert_eq!(
+            delays,
+            [100, 200, 400, 800, 1_000, 1_000].map(Duration::from_millis)
+        );
+        assert_eq!(retry.backoff(200), Duration::from_millis(1_000));
+    }
+
+    #[test]
+    fn failed_builds_are_retried_and_reported() {
+        let options = ClientOptions {
+            connect_retry: ConnectRetryOptions {
+                initial_backoff_ms: 1,
+                max_backoff_ms: 1,
+                max_attempts: 3,
+            },
+            ..Default::default()
+        };
+        let client =
+            MobileConvexClient::new_with_options("not a url".into(), "test".into(), options);
+        assert_eq!(client.last_connection_error(), None);
+
+        let err = client.rt.block_on(client.reconnect()).unwrap_err();
+        match err {
+            ClientError::InternalError {
+                code: ErrorCode::Network,
+                retryable: true,
+                msg,
+            } => assert!(msg.contains("3 attempts"), "{msg}"),
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert!(client.last_connection_error().is_some());
+        assert!(matches!(
+            client.connection_state(),
+            WebSocketConnectionState::Failed { .. }
+        ));
+    }
+
+    #[tokio::test]
+    async fn slow_builds_are_reported_and_recorded() {
+        let state = Arc::new(watch::Sender::new(WebSocketConnectionState::Connecting));
+        let manager = ConnectionManager::new(Some(10), state);
+        let mut events = manager.slow_events.subscribe();
+        let _waiting = manager.caller_waiting();
+        assert!(!manager.diagnostics().initialized);
+
+        let build = async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            anyhow::Ok(())
+        };
+        manager.observe_build(build).await.unwrap();
+        let event = events.try_recv().unwrap();
+        assert!(event.elapsed_ms >= 10);
+        assert_eq!(event.waiting_callers, 1);
+
+        let diagnostics = manager.diagnostics();
+        assert!(diagnostics.initialized);
+        assert!(diagnostics.last_duration_ms.unwrap() >= 50);
+        assert_eq!(diagnostics.waiting_callers, 1);
+        manager
+            .observe_build(async { anyhow::Ok(()) })
+            .await
+            .unwrap();
+        assert!(events.try_recv().is_err());
+
+        manager.mark_disconnected();
+        assert_eq!(manager.diagnostics().initialized_at_ms, None);
+    }
+
+    #[test]
+    fn dropped_websockets_are_reported_as_closed() {
+        let mut states = SocketStates::default();
+        let reported: Vec<_> = [
+            WebSocketState::Connecting,
+            WebSocketState::Connecting,
+            WebSocketState::Connected,
+            WebSocketState::Connecting,
+            WebSocketState::Connecting,
+            WebSocketState::Connected,
+        ]
+        .into_iter()
+        .map(|state| states.report(state))
+        .collect();
+        let lost = WebSocketConnectionState::Closed {
+            reason: ConnectionCloseReason::ConnectionLost,
+        };
+        assert_eq!(
+            reported,
+            [
+                WebSocketConnectionState::Connecting,
+                WebSocketConnectionState::Connecting,
+                WebSocketConnectionState::Connected,
+                lost.clone(),
+                lost,
+                WebSocketConnectionState::Connected,
+            ]
+        );
+    }
+
+    #[test]
+    fn disconnecting_reports_the_reason() {
+        let client = MobileConvexClient::new_with_options(
+            "https://example.convex.cloud".into(),
+            "test".into(),
+            ClientOptions::default(),
+        );
+        client.rt.block_on(client.connect()).unwrap();
+        client
+            .rt
+            .block_on(client.disconnect_for(ConnectionCloseReason::Paused))
+            .unwrap();
+        assert_eq!(
+            client.connection_state(),
+            WebSocketConnectionState::Closed {
+                reason: ConnectionCloseReason::Paused
+            }
+        );
+        assert!(!client.initialization_diagnostics().initialized);
+    }
+
+    #[test]
+    fn disconnecting_parks_subscriptions_until_connect() {
+        struct Ignore;
+
+        impl QuerySubscriber for Ignore {
+            fn on_update(&self, _value: String) {}
+
+            fn on_error(&self, _message: String, _value: Option<String>) {}
+        }
+
+        let client = MobileConvexClient::new("https://example.convex.cloud".into(), "test".into());
+        client.rt.block_on(client.connect()).unwrap();
+        let (cancel_tx, mut cancel_rx) = oneshot::channel();
+        let handle = SubscriptionHandle::new(cancel_tx);
+        let subscriber = Arc::new(Ignore);
+        client.lifecycle.track(
+            "messages:list".into(),
+            Default::default(),
+            subscriber,
+            &handle,
+        );
+
+        client.rt.block_on(client.disconnect()).unwrap();
+        assert_eq!(cancel_rx.try_recv(), Ok(Some(())));
+        assert!(handle.is_active());
+        assert!(client.lifecycle.is_paused());
+        assert_eq!(
+            client.connection_state(),
+            WebSocketConnectionState::Closed {
+                reason: ConnectionCloseReason::Disconnected
+            }
+        );
+
+        // Cancelled while disconnected, so there is nothing to re-establish.
+        handle.stop();
+        client.rt.block_on(client.connect()).unwrap();
+        assert!(!client.lifecycle.is_paused());
+        assert!(client.initialization_diagnostics().initialized);
+    }
+}
@@ -0,0 +1,410 @@
+//! Structured function arguments passed over the FFI without a JSON round-trip.
+//!
+//! [`ConvexValue`] mirrors Convex's value types, so nested arguments such as
+//! `paginationOpts` can be built natively in Dart. Invalid arguments, such as
+//! object fields starting with `$`, are reported as
+//! [`crate::errors::ErrorCode::ValidationFailed`] naming the offending path,
+//! and so are arrays and objects nested deeper than [`MAX_NESTING`] levels,
+//! before their conversion could exhaust the stack.
+//!
+//! The `*_typed` methods also return results as [`ConvexValue`] trees, which
+//! keeps `Int64` and `Bytes` intact and avoids parsing JSON again in Dart.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use anyhow::bail;
+use convex::{FunctionResult, Value};
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::debug;
+use tokio::sync::mpsc;
+
+use crate::{
+    options::NullHandling,
+    resubscribe::SubscriptionPriority,
+    result::handle_typed_function_result,
+    subscription::{EventForwarder, SubscriptionEvent},
+    value::json_to_value_as,
+    ClientError, MobileConvexClient, SubscriptionHandle,
+};
+
+/// A Convex value.
+#[derive(Debug, Clone, PartialEq)]
+#[frb]
+pub enum ConvexValue {
+    Null,
+    Bool(bool),
+    Int64(i64),
+    Float64(f64),
+    String(String),
+    Bytes(Vec<u8>),
+    Array(Vec<ConvexValue>),
+    Object(HashMap<String, ConvexValue>),
+}
+
+impl From<Value> for ConvexValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => ConvexValue::Null,
+            Value::Boolean(b) => ConvexValue::Bool(b),
+            Value::Int64(i) => ConvexValue::Int64(i),
+            Value::Float64(f) => ConvexValue::Float64(f),
+            Value::String(s) => ConvexValue::String(s),
+            Value::Bytes(b) => ConvexValue::Bytes(b),
+            Value::Array(items) => ConvexValue::Array(items.into_iter().map(Into::into).collect()),
+            Value::Object(fields) => ConvexValue::Object(
+                fields
+                    .into_iter()
+                    .map(|(name, value)| (name, value.into()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<ConvexValue> for Value {
+    fn from(value: ConvexValue) -> Self {
+        match value {
+            ConvexValue::Null => Value::Null,
+            ConvexValue::Bool(b) => Value::Boolean(b),
+            ConvexValue::Int64(i) => Value::Int64(i),
+            ConvexValue::Float64(f) => Value::Float64(f),
+            ConvexValue::String(s) => Value::String(s),
+            ConvexValue::Bytes(b) => Value::Bytes(b),
+            ConvexValue::Array(items) => Value::Array(items.into_iter().map(Into::into).collect()),
+            ConvexValue::Object(fields) => Value::Object(
+                fields
+                    .into_iter()
+                    .map(|(name, value)| (name, value.into()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// Deepest nesting of arrays and objects accepted in arguments, counting the
+/// arguments object itself as the first level.
+pub(crate) const MAX_NESTING: usize = 64;
+
+/// Checks that `name` is a valid Convex field name.
+fn validate_field_name(name: &str, path: &str) -> anyhow::Result<()> {
+    if name.is_empty() {
+        bail!("Empty field name at `{path}`");
+    }
+    if name.starts_with('$') {
+        bail!("Field name `{name}` at `{path}` must not start with `$`");
+    }
+    if !name.chars().all(|c| c.is_ascii() && !c.is_ascii_control()) {
+        bail!("Field name `{name}` at `{path}` must only contain printable ASCII characters");
+    }
+    Ok(())
+}
+
+fn field_path(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{parent}.{name}")
+    }
+}
+
+fn check_nesting(depth: usize, path: &str) -> anyhow::Result<()> {
+    if depth > MAX_NESTING {
+        bail!("Value at `{path}` is nested deeper than {MAX_NESTING} levels");
+    }
+    Ok(())
+}
+
+fn to_value(
+    value: ConvexValue,
+    path: &str,
+    null_handling: NullHandling,
+    depth: usize,
+) -> anyhow::Result<Value> {
+    Ok(match value {
+        ConvexValue::Null => Value::Null,
+        ConvexValue::Bool(b) => Value::Boolean(b),
+        ConvexValue::Int64(i) => Value::Int64(i),
+        ConvexValue::Float64(f) => Value::Float64(f),
+        ConvexValue::String(s) => Value::String(s),
+        ConvexValue::Bytes(b) => Value::Bytes(b),
+        ConvexValue::Array(items) => {
+            check_nesting(depth, path)?;
+            Value::Array(
+                items
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, item)| {
+                        to_value(item, &format!("{path}[{i}]"), null_handling, depth + 1)
+                    })
+                    .collect::<anyhow::Result<_>>()?,
+            )
+        }
+        ConvexValue::Object(fields) => {
+            Value::Object(to_object(fields, path, null_handling, depth)?)
+        }
+    })
+}
+
+fn to_object(
+    fields: HashMap<String, ConvexValue>,
+    path: &str,
+    null_handling: NullHandling,
+    depth: usize,
+) -> anyhow::Result<BTreeMap<String, Value>> {
+    check_nesting(depth, path)?;
+    let mut object = BTreeMap::new();
+    for (name, value) in fields {
+        let path = field_path(path, &name);
+        validate_field_name(&name, &path)?;
+        if null_handling == NullHandling::OmitNullFields && value == ConvexValue::Null {
+            continue;
+        }
+        object.insert(name, to_value(value, &path, null_handling, depth + 1)?);
+    }
+    Ok(object)
+}
+
+/// Converts structured FFI arguments into Convex arguments.
+pub(crate) fn convex_args(
+    args: HashMap<String, ConvexValue>,
+    null_handling: NullHandling,
+) -> anyhow::Result<BTreeMap<String, Value>> {
+    to_object(args, "", null_handling, 1)
+}
+
+impl MobileConvexClient {
+    /// Decrypts the encrypted fields of a successful `result`.
+    fn decrypt_function_result(&self, result: FunctionResult) -> FunctionResult {
+        match result {
+            FunctionResult::Value(value) => {
+                FunctionResult::Value(self.field_encryption.decrypt_value(value))
+            }
+            other => other,
+        }
+    }
+
+    /// Executes a query with structured arguments and result.
+    #[frb]
+    pub async fn query_typed(
+        &self,
+        name: String,
+        args: HashMap<String, ConvexValue>,
+    ) -> Result<ConvexValue, ClientError> {
+        let args = self.convert_args(args)?;
+        let result = self.internal_query(name, args).await?;
+        handle_typed_function_result(self.decrypt_function_result(result))
+    }
+
+    /// Executes a mutation with structured arguments and result.
+    #[frb]
+    pub async fn mutation_typed(
+        &self,
+        name: String,
+        args: HashMap<String, ConvexValue>,
+    ) -> Result<ConvexValue, ClientError> {
+        let args = self.convert_args(args)?;
+        let result = self.internal_mutation(name.clone(), args).await?;
+        if matches!(result, FunctionResult::Value(_)) {
+            self.invalidate_after_mutation(&name).await;
+        }
+        handle_typed_function_result(self.decrypt_function_result(result))
+    }
+
+    /// Executes an action with structured arguments and result.
+    #[frb]
+    pub async fn action_typed(
+        &self,
+        name: String,
+        args: HashMap<String, ConvexValue>,
+    ) -> Result<ConvexValue, ClientError> {
+        let args = self.convert_args(args)?;
+        let result = self.internal_action(name, args).await?;
+        handle_typed_function_result(self.decrypt_function_result(result))
+    }
+
+    /// Subscribes to a query with structured arguments, delivering results as
+    /// [`ConvexValue`]s.
+    ///
+    /// Results go through the same pipeline as those of
+    /// [`MobileConvexClient::subscribe`], so the subscription is throttled,
+    /// shared, sharded, paused and re-established the same way. With
+    /// [`crate::options::Int64Encoding::String`], `Int64`s in results
+    /// arrive as strings; the other encodings keep them exact.
+    #[frb]
+    pub async fn subscribe_typed(
+        &self,
+        name: String,
+        args: HashMap<String, ConvexValue>,
+        on_update: impl Fn(ConvexValue) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        let args = self.convert_args(args)?;
+        let (deliveries, mut delivered) = mpsc::unbounded_channel();
+        let handle = self
+            .internal_subscribe(
+                name.clone(),
+                args,
+                Arc::new(EventForwarder(deliveries)),
+                SubscriptionPriority::Normal,
+            )
+            .await?;
+        let int64_encoding = self.options.int64_encoding;
+        self.rt.spawn(async move {
+            // Ends once the subscription is cancelled and drops the forwarder.
+            while let Some(event) = delivered.recv().await {
+                match event {
+                    SubscriptionEvent::Update { value } => {
+                        let value = serde_json::from_str(&value)
+                            .map_err(anyhow::Error::from)
+                            .and_then(|json| json_to_value_as(json, int64_encoding));
+                        match value {
+                            Ok(value) => on_update(value.into()).await,
+                            Err(e) => {
+                                on_error(format!("Invalid result of {name}: {e}"), None).await
+                            }
+                        }
+                    }
+                    SubscriptionEvent::Error { message, data } => on_error(message, data).await,
+                    SubscriptionEvent::Closed { .. } => {
+                        log::warn!("Subscription stream ended for {name}");
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+            debug!("Subscription canceled");
+        });
+        Ok(handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::{btreemap, hashmap};
+
+    use super::*;
+
+    #[test]
+    fn nested_arguments_convert_natively() {
+        let args = convex_args(
+            hashmap! {
+                "paginationOpts".into() => ConvexValue::Object(hashmap! {
+                    "numItems".into() => ConvexValue::Int64(10),
+                    "cursor".into() => ConvexValue::Null,
+                }),
+                "tags".into() => ConvexValue::Array(vec![
+                    ConvexValue::String("a".into()),
+                    ConvexValue::Bytes(vec![1, 2]),
+                    ConvexValue::Bool(true),
+                    ConvexValue::Float64(0.5),
+                ]),
+            },
+            NullHandling::Preserve,
+        )
+        .unwrap();
+        assert_eq!(
+            args,
+            btreemap! {
+                "paginationOpts".into() => Value::Object(btreemap! {
+                    "numItems".into() => Value::Int64(10),
+                    "cursor".into() => Value::Null,
+                }),
+                "tags".into() => Value::Array(vec![
+                    Value::String("a".into()),
+                    Value::Bytes(vec![1, 2]),
+                    Value::Boolean(true),
+                    Value::Float64(0.5),
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn convex_values_convert_back() {
+        let value = Value::Object(btreemap! {
+            "id".into() => Value::Int64(i64::MAX),
+            "blob".into() => Value::Bytes(vec![0, 255]),
+            "list".into() => Value::Array(vec![Value::Null, Value::Boolean(false)]),
+        });
+        assert_eq!(
+            ConvexValue::from(value),
+            ConvexValue::Object(hashmap! {
+                "id".into() => ConvexValue::Int64(i64::MAX),
+                "blob".into() => ConvexValue::Bytes(vec![0, 255]),
+                "list".into() => ConvexValue::Array(vec![ConvexValue::Null, ConvexValue::Bool(false)]),
+            })
+        );
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nested = |levels: usize| {
+            let mut value = ConvexValue::Null;
+            for i in 0..levels {
+                value = if i % 2 == 0 {
+                    ConvexValue::Array(vec![value])
+                } else {
+                    ConvexValue::Object(hashmap! { "a".into() => value })
+                };
+            }
+            hashmap! { "a".into() => value }
+        };
+        assert!(convex_args(nested(MAX_NESTING - 1), NullHandling::Preserve).is_ok());
+        let error = convex_args(nested(MAX_NESTING), NullHandling::Preserve).unwrap_err();
+        assert!(
+            error.to_string().contains("nested deeper than 64 levels"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn null_fields_can_be_omitted() {
+        let args = convex_args(
+            hashmap! {
+                "name".into() => ConvexValue::Null,
+                "patch".into() => ConvexValue::Object(hashmap! {
+                    "a".into() => ConvexValue::Null,
+                    "b".into() => ConvexValue::Array(vec![ConvexValue::Null]),
+                }),
+            },
+            NullHandling::OmitNullFields,
+        )
+        .unwrap();
+        assert_eq!(
+            args,
+            btreemap! {
+                "patch".into() => Value::Object(btreemap! {
+                    "b".into() => Value::Array(vec![Value::Null]),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_field_names_report_their_path() {
+        let err = convex_args(
+            hashmap! {
+                "filter".into() => ConvexValue::Array(vec![ConvexValue::Object(hashmap! {
+                    "$gt".into() => ConvexValue::Int64(1),
+                })]),
+            },
+            NullHandling::Preserve,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("`filter[0].$gt`"), "{err}");
+
+        assert!(convex_args(
+            hashmap! { "".into() => ConvexValue::Null },
+            NullHandling::Preserve
+        )
+        .is_err());
+        assert!(convex_args(
+            hashmap! { "naïve".into() => ConvexValue::Null },
+            NullHandling::Preserve
+        )
+        .is_err());
+    }
+}
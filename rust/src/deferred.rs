@@ -0,0 +1,316 @@
+//! Deferred low-priority mutations.
+//!
+//! Analytics events and read receipts need not reach the backend right away,
+//! but sending each one wakes the radio and costs a backend call.
+//! [`MobileConvexClient::mutation_deferred`] queues such writes and sends the
+//! queue in order when the first of these happens:
+//!
+//! - a queued mutation reaches its maximum delay,
+//! - the queue reaches [`DeferredMutationOptions::max_batch_size`],
+//! - the last query, mutation or action in flight completes, while the
+//!   connection is awake anyway,
+//! - the client disconnects or is closed.
+//!
+//! Deferred mutations are fire-and-forget: failures are logged and not
+//! retried. [`MobileConvexClient::mutation_deferred_tracked`] additionally
+//! reports each step to [`MobileConvexClient::on_mutation_lifecycle`].
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use convex::{ConvexClient, Value};
+use flutter_rust_bridge::frb;
+use futures::{pin_mut, select_biased, FutureExt};
+use log::{debug, warn};
+use parking_lot::Mutex;
+use serde::Deserialize;
+use tokio::{sync::Notify, time::Instant};
+
+use crate::{
+    mutation_status::{final_status, report, MutationEvents, MutationStatus},
+    options::Int64Encoding,
+    result::handle_direct_function_result,
+    ClientError, MobileConvexClient,
+};
+
+/// Limits of the deferred mutation queue.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct DeferredMutationOptions {
+    /// Number of queued mutations that triggers sending the queue.
+    pub max_batch_size: u32,
+}
+
+impl Default for DeferredMutationOptions {
+    fn default() -> Self {
+        DeferredMutationOptions { max_batch_size: 20 }
+    }
+}
+
+#[frb(ignore)]
+struct DeferredMutation {
+    id: Option<String>, // Lifecycle id, if tracked
+    name: String,
+    args: BTreeMap<String, Value>,
+    deadline: Instant,
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+struct Queue {
+    mutations: Vec<DeferredMutation>,
+    flush_requested: bool,
+}
+
+/// The queue of deferred mutations and the calls it waits for.
+pub(crate) struct DeferredMutations {
+    max_batch_size: usize,
+    queue: Mutex<Queue>,
+    wake: Notify,
+    in_flight: AtomicUsize,
+    // Keeps batches in order when sent concurrently.
+    sending: tokio::sync::Mutex<()>,
+    events: MutationEvents,
+}
+
+/// A query, mutation or action in flight; the last one to complete sends
+/// the queue.
+pub(crate) struct InFlightCall(Arc<DeferredMutations>);
+
+impl Drop for InFlightCall {
+    fn drop(&mut self) {
+        if self.0.in_flight.fetch_sub(1, Ordering::SeqCst) == 1 {
+            self.0.request_flush();
+        }
+    }
+}
+
+impl DeferredMutations {
+    pub(crate) fn new(options: &DeferredMutationOptions, events: MutationEvents) -> Arc<Self> {
+        Arc::new(DeferredMutations {
+            max_batch_size: options.max_batch_size.max(1) as usize,
+            queue: Mutex::default(),
+            wake: Notify::new(),
+            in_flight: AtomicUsize::new(0),
+            sending: tokio::sync::Mutex::new(()),
+            events,
+        })
+    }
+
+    /// Number of mutations waiting to be sent.
+    pub(crate) fn queued(&self) -> usize {
+        self.queue.lock().mutations.len()
+    }
+
+    pub(crate) fn call_started(self: &Arc<Self>) -> InFlightCall {
+        self.in_flight.fetch_add(1, Ordering::SeqCst);
+        InFlightCall(self.clone())
+    }
+
+    /// Queues a mutation; returns whether it opened a new batch.
+    fn enqueue(&self, mutation: DeferredMutation) -> bool {
+        if let Some(id) = &mutation.id {
+            report(&self.events, id, &mutation.name, MutationStatus::Queued);
+        }
+        let opened = {
+            let mut queue = self.queue.lock();
+            queue.mutations.push(mutation);
+            if queue.mutations.len() >= self.max_batch_size {
+                queue.flush_requested = true;
+            }
+            queue.mutations.len() == 1
+        };
+        self.wake.notify_one();
+        opened
+    }
+
+    fn request_flush(&self) {
+        let mut queue = self.queue.lock();
+        if !queue.mutations.is_empty() {
+            queue.flush_requested = true;
+            self.wake.notify_one();
+        }
+    }
+
+    /// Returns when the queue is due to be sent, or `None` if it is empty.
+    fn due(&self) -> Option<Instant> {
+        let queue = self.queue.lock();
+        if queue.flush_requested {
+            return Some(Instant::now());
+        }
+        queue
+            .mutations
+            .iter()
+            .map(|mutation| mutation.deadline)
+            .min()
+    }
+
+    /// Waits until the queue is due, then sends it through `client`.
+    async fn run(self: Arc<Self>, client: ConvexClient) {
+        loop {
+            let Some(due) = self.due() else {
+                return;
+            };
+            if due <= Instant::now() {
+                break;
+            }
+            let sleep_fut = tokio::time::sleep_until(due).fuse();
+            let wake_fut = self.wake.notified().fuse();
+            pin_mut!(sleep_fut, wake_fut);
+            select_biased! {
+                _ = sleep_fut => break,
+                _ = wake_fut => {},
+            }
+        }
+        self.flush(client).await;
+    }
+
+    /// Sends all queued mutations in order.
+    pub(crate) async fn flush(&self, mut client: ConvexClient) {
+        let _sending = self.sending.lock().await;
+        let batch = {
+            let mut queue = self.queue.lock();
+            queue.flush_requested = false;
+            std::mem::take(&mut queue.mutations)
+        };
+        // Lets a waiting task see the queue is empty and end.
+        self.wake.notify_waiters();
+        if batch.is_empty() {
+            return;
+        }
+        debug!("Sending {} deferred mutations", batch.len());
+        for DeferredMutation { id, name, args, .. } in batch {
+            let report = |status| {
+                if let Some(id) = &id {
+                    report(&self.events, id, &name, status);
+                }
+            };
+            report(MutationStatus::Sending);
+            let result = match client.mutation(&name, args).await {
+                Ok(result) => handle_direct_function_result(result, Int64Encoding::default()),
+                Err(e) => Err(e.into()),
+            };
+            if let Err(e) = &result {
+                warn!("Deferred mutation {name} failed: {e}");
+            }
+            report(final_status(&result));
+        }
+    }
+}
+
+impl MobileConvexClient {
+    /// Queues a low-priority Convex mutation, sent at the latest after
+    /// `max_delay_ms` milliseconds. Returns once queued; the result of the
+    /// mutation is not reported.
+    #[frb]
+    pub async fn mutation_deferred(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        max_delay_ms: u32,
+    ) -> Result<(), ClientError> {
+        self.defer_mutation(None, name, args, max_delay_ms).await
+    }
+
+    /// Like [`MobileConvexClient::mutation_deferred`], reporting the
+    /// mutation's lifecycle under `mutation_id`.
+    #[frb]
+    pub async fn mutation_deferred_tracked(
+        &self,
+        mutation_id: String,
+        name: String,
+        args: HashMap<String, String>,
+        max_delay_ms: u32,
+    ) -> Result<(), ClientError> {
+        self.defer_mutation(Some(mutation_id), name, args, max_delay_ms)
+            .await
+    }
+
+    async fn defer_mutation(
+        &self,
+        id: Option<String>,
+        name: String,
+        args: HashMap<String, String>,
+        max_delay_ms: u32,
+    ) -> Result<(), ClientError> {
+        let args = self.parse_args(args)?;
+        let client = self.connected_client().await?;
+        let mutation = DeferredMutation {
+            id,
+            name,
+            args,
+            deadline: Instant::now() + Duration::from_millis(max_delay_ms.into()),
+        };
+        if self.deferred.enqueue(mutation) {
+            self.rt.spawn(self.deferred.clone().run(client));
+        }
+        Ok(())
+    }
+
+    /// Sends the deferred mutations through the current client, if any.
+    pub(crate) async fn flush_deferred(&self) {
+        let client = self.client.lock().await.clone();
+        if let Some(client) = client {
+            self.deferred.flush(client).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutation_status::mutation_events;
+
+    fn deferred(max_batch_size: u32) -> Arc<DeferredMutations> {
+        let options = DeferredMutationOptions { max_batch_size };
+        DeferredMutations::new(&options, mutation_events())
+    }
+
+    fn mutation(delay_ms: u64) -> DeferredMutation {
+        DeferredMutation {
+            id: None,
+            name: "events:track".into(),
+            args: BTreeMap::new(),
+            deadline: Instant::now() + Duration::from_millis(delay_ms),
+        }
+    }
+
+    #[tokio::test]
+    async fn the_earliest_deadline_is_due() {
+        let deferred = deferred(20);
+        assert_eq!(deferred.due(), None);
+        assert!(deferred.enqueue(mutation(5_000)));
+        assert!(!deferred.enqueue(mutation(1_000)));
+        let due = deferred.due().unwrap();
+        assert!(due <= Instant::now() + Duration::from_millis(1_000));
+        assert!(due > Instant::now() + Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn full_batches_are_due_immediately() {
+        let deferred = deferred(2);
+        deferred.enqueue(mutation(60_000));
+        assert!(deferred.due().unwrap() > Instant::now());
+        deferred.enqueue(mutation(60_000));
+        assert!(deferred.due().unwrap() <= Instant::now());
+    }
+
+    #[tokio::test]
+    async fn the_last_call_in_flight_requests_a_flush() {
+        let deferred = deferred(20);
+        deferred.enqueue(mutation(60_000));
+        let first = deferred.call_started();
+        let second = deferred.call_started();
+        drop(first);
+        assert!(!deferred.queue.lock().flush_requested);
+        drop(second);
+        assert!(deferred.queue.lock().flush_requested);
+    }
+}
@@ -0,0 +1,466 @@
+//! Watching a value derived from several queries.
+//!
+//! Composite views built from several subscriptions rebuild once per
+//! subscription update. [`MobileConvexClient::watch_derived`] subscribes to
+//! all sources and combines their results in Rust with a small declarative
+//! combiner, delivering one JSON object per change instead. Nothing is
+//! delivered until every source has produced a result, and a combined value
+//! equal to the previous one is not delivered again.
+//! [`MobileConvexClient::subscribe_combined`] does the same with a
+//! [`CombineStrategy`] joining the whole results.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use futures::{channel::oneshot, future::try_join_all};
+use log::debug;
+use parking_lot::Mutex;
+use serde_json::{Map, Value as JsonValue};
+
+use crate::{
+    ordered::OrderedCalls, resubscribe::SubscriptionPriority, CallbackSubscriberDartFn,
+    ClientError, MobileConvexClient, QuerySubscriber, SubscriptionHandle,
+};
+
+/// A query feeding a derived value.
+#[derive(Debug, Clone)]
+#[frb]
+pub struct DerivedSource {
+    pub name: String,
+    pub args: HashMap<String, String>,
+}
+
+/// How one field of a derived value is computed. Sources are referred to
+/// by their index in the list passed to [`MobileConvexClient::watch_derived`].
+#[derive(Debug, Clone)]
+#[frb]
+pub enum DerivedValue {
+    /// The value at `path` in the source result, as dot-separated object
+    /// keys and array indices (e.g. `user.tags.0`). An empty path picks the
+    /// whole result; a missing one yields `null`.
+    Pick { source: u32, path: String },
+    /// The number of elements of an array result; `0` for `null` and `1`
+    /// for any other value.
+    Count { source: u32 },
+    /// The documents of the array result of `source`, each with the
+    /// document of `with_source` whose `_id` equals its `foreign_key` field
+    /// added as `as_field` (`null` if there is none).
+    JoinById {
+        source: u32,
+        with_source: u32,
+        foreign_key: String,
+        as_field: String,
+    },
+}
+
+/// A named field of a derived value.
+#[derive(Debug, Clone)]
+#[frb]
+pub struct DerivedField {
+    pub name: String,
+    pub value: DerivedValue,
+}
+
+/// How [`MobileConvexClient::subscribe_combined`] joins source results.
+#[derive(Debug, Clone)]
+#[frb]
+pub enum CombineStrategy {
+    /// An array of the source results, in source order.
+    Array,
+    /// An object holding each source result under the key at its index.
+    Keyed { keys: Vec<String> },
+    /// The object results merged into one object, fields of later sources
+    /// replacing those of earlier ones. Other results are skipped.
+    Merge,
+}
+
+/// Computes a combined value from the results of all sources.
+enum Combiner {
+    Fields(Vec<DerivedField>),
+    Strategy(CombineStrategy),
+}
+
+impl Combiner {
+    fn combine(&self, results: &[JsonValue]) -> JsonValue {
+        match self {
+            Combiner::Fields(fields) => combine(fields, results),
+            Combiner::Strategy(CombineStrategy::Array) => JsonValue::Array(results.to_vec()),
+            Combiner::Strategy(CombineStrategy::Keyed { keys }) => {
+                JsonValue::Object(keys.iter().cloned().zip(results.iter().cloned()).collect())
+            }
+            Combiner::Strategy(CombineStrategy::Merge) => JsonValue::Object(
+                results
+                    .iter()
+                    .filter_map(JsonValue::as_object)
+                    .flat_map(|fields| fields.clone())
+                    .collect(),
+            ),
+        }
+    }
+}
+
+fn pick(value: &JsonValue, path: &str) -> JsonValue {
+    if path.is_empty() {
+        return value.clone();
+    }
+    let pointer: String = path
+        .split('.')
+        .map(|segment| format!("/{segment}"))
+        .collect();
+    value.pointer(&pointer).cloned().unwrap_or(JsonValue::Null)
+}
+
+fn count(value: &JsonValue) -> usize {
+    match value {
+        JsonValue::Array(items) => items.len(),
+        JsonValue::Null => 0,
+        _ => 1,
+    }
+}
+
+fn join_by_id(
+    documents: &JsonValue,
+    others: &JsonValue,
+    foreign_key: &str,
+    as_field: &str,
+) -> JsonValue {
+    let by_id: HashMap<&str, &JsonValue> = others
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|other| Some((other.get("_id")?.as_str()?, other)))
+        .collect();
+    let joined = documents
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|document| {
+            let mut document = document.clone();
+            let other = document
+                .get(foreign_key)
+                .and_then(JsonValue::as_str)
+                .and_then(|id| by_id.get(id))
+                .map(|&other| other.clone())
+                .unwrap_or(JsonValue::Null);
+            if let JsonValue::Object(fields) = &mut document {
+                fields.insert(as_field.to_owned(), other);
+            }
+            document
+        })
+        .collect();
+    JsonValue::Array(joined)
+}
+
+/// Computes the derived value from the results of all sources.
+fn combine(fields: &[DerivedField], results: &[JsonValue]) -> JsonValue {
+    let result = |source: u32| &results[source as usize];
+    let combined: Map<String, JsonValue> = fields
+        .iter()
+        .map(|field| {
+            let value = match &field.value {
+                DerivedValue::Pick { source, path } => pick(result(*source), path),
+                DerivedValue::Count { source } => count(result(*source)).into(),
+                DerivedValue::JoinById {
+                    source,
+                    with_source,
+                    foreign_key,
+                    as_field,
+                } => join_by_id(result(*source), result(*with_source), foreign_key, as_field),
+            };
+            (field.name.clone(), value)
+        })
+        .collect();
+    JsonValue::Object(combined)
+}
+
+fn validate(fields: &[DerivedField], source_count: usize) -> Result<(), ClientError> {
+    for field in fields {
+        let sources = match &field.value {
+            DerivedValue::Pick { source, .. } | DerivedValue::Count { source } => vec![*source],
+            DerivedValue::JoinById {
+                source,
+                with_source,
+                ..
+            } => vec![*source, *with_source],
+        };
+        if let Some(source) = sources.iter().find(|&&s| s as usize >= source_count) {
+            return Err(ClientError::internal(format!(
+                "Field `{}` refers to source {source}, but there are {source_count}",
+                field.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Latest source results and the last delivered value.
+struct DerivedState {
+    combiner: Combiner,
+    results: Mutex<Vec<Option<JsonValue>>>,
+    delivered: Mutex<Option<JsonValue>>,
+    ended: AtomicUsize, // Number of sources whose stream ended
+    subscriber: Arc<dyn QuerySubscriber>,
+}
+
+impl DerivedState {
+    /// Records a source result, returning the combined value if it changed.
+    fn update(&self, source: usize, value: JsonValue) -> Option<JsonValue> {
+        let combined = {
+            let mut results = self.results.lock();
+            results[source] = Some(value);
+            let results: Option<Vec<_>> = results.iter().cloned().collect();
+            self.combiner.combine(&results?)
+        };
+        let mut delivered = self.delivered.lock();
+        if delivered.as_ref() == Some(&combined) {
+            debug!("Derived value unchanged");
+            return None;
+        }
+        *delivered = Some(combined.clone());
+        Some(combined)
+    }
+}
+
+/// Feeds the results of one source into a [`DerivedState`].
+#[frb(ignore)]
+struct SourceSubscriber {
+    state: Arc<DerivedState>,
+    source: usize,
+}
+
+impl QuerySubscriber for SourceSubscriber {
+    fn on_update(&self, value: String) {
+        let value = match serde_json::from_str(&value) {
+            Ok(value) => value,
+            Err(e) => {
+                self.state.subscriber.on_error(e.to_string(), None);
+                return;
+            }
+        };
+        if let Some(combined) = self.state.update(self.source, value) {
+            self.state.subscriber.on_update(combined.to_string());
+        }
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.state.subscriber.on_error(message, value);
+    }
+
+    fn on_done(&self) {
+        // The derived value only stops changing once every source ended.
+        let ended = self.state.ended.fetch_add(1, Ordering::SeqCst) + 1;
+        if ended == self.state.results.lock().len() {
+            self.state.subscriber.on_done();
+        }
+    }
+}
+
+impl MobileConvexClient {
+    /// Subscribes to all `sources` and delivers the value described by
+    /// `fields` as a JSON object whenever it changes. Errors of any source
+    /// are passed to `on_error`. Cancelling the returned handle cancels all
+    /// source subscriptions.
+    #[frb]
+    pub async fn watch_derived(
+        &self,
+        sources: Vec<DerivedSource>,
+        fields: Vec<DerivedField>,
+        on_update: impl Fn(String) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        validate(&fields, sources.len())?;
+        self.watch_sources(sources, Combiner::Fields(fields), on_update, on_error)
+            .await
+    }
+
+    /// Subscribes to all `sources` and delivers their results joined by
+    /// `strategy` whenever any of them changes, once every source has a
+    /// result. Errors of any source are passed to `on_error`. Cancelling the
+    /// returned handle cancels all source subscriptions.
+    #[frb]
+    pub async fn subscribe_combined(
+        &self,
+        sources: Vec<DerivedSource>,
+        strategy: CombineStrategy,
+        on_update: impl Fn(String) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        if let CombineStrategy::Keyed { keys } = &strategy {
+            if keys.len() != sources.len() {
+                return Err(ClientError::internal(format!(
+                    "Got {} keys for {} sources",
+                    keys.len(),
+                    sources.len()
+                )));
+            }
+        }
+        self.watch_sources(sources, Combiner::Strategy(strategy), on_update, on_error)
+            .await
+    }
+
+    /// Subscribes to all `sources`, delivering values of `combiner`.
+    async fn watch_sources(
+        &self,
+        sources: Vec<DerivedSource>,
+        combiner: Combiner,
+        on_update: impl Fn(String) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        let state = Arc::new(DerivedState {
+            combiner,
+            results: Mutex::new(vec![None; sources.len()]),
+            delivered: Mutex::new(None),
+            ended: AtomicUsize::new(0),
+            subscriber: Arc::new(CallbackSubscriberDartFn {
+                on_update: Box::new(on_update),
+                on_error: Box::new(on_error),
+                on_done: None,
+                calls: OrderedCalls::default(),
+            }),
+        });
+        let mut subscriptions = Vec::with_capacity(sources.len());
+        for (source, DerivedSource { name, args }) in sources.into_iter().enumerate() {
+            let subscriber = Arc::new(SourceSubscriber {
+                state: state.clone(),
+                source,
+            });
+            subscriptions.push(self.internal_subscribe(
+                name,
+                self.parse_args(args)?,
+                subscriber,
+                SubscriptionPriority::Normal,
+            ));
+        }
+        let handles = try_join_all(subscriptions).await?;
+        let (cancel_sender, cancel_receiver) = oneshot::channel::<()>();
+        self.rt.spawn(async move {
+            let _ = cancel_receiver.await;
+            // Dropping the handles cancels the source subscriptions.
+            drop(handles);
+            debug!("Derived watch canceled");
+        });
+        Ok(SubscriptionHandle::new(cancel_sender))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    struct Ignore;
+
+    impl QuerySubscriber for Ignore {
+        fn on_update(&self, _value: String) {}
+
+        fn on_error(&self, _message: String, _value: Option<String>) {}
+    }
+
+    fn field(name: &str, value: DerivedValue) -> DerivedField {
+        DerivedField {
+            name: name.into(),
+            value,
+        }
+    }
+
+    #[test]
+    fn fields_are_picked_counted_and_joined() {
+        let fields = [
+            field(
+                "title",
+                DerivedValue::Pick {
+                    source: 0,
+                    path: "settings.title".into(),
+                },
+            ),
+            field(
+                "firstTag",
+                DerivedValue::Pick {
+                    source: 0,
+                    path: "tags.0".into(),
+                },
+            ),
+            field("unread", DerivedValue::Count { source: 1 }),
+            field(
+                "messages",
+                DerivedValue::JoinById {
+                    source: 1,
+                    with_source: 2,
+                    foreign_key: "author".into(),
+                    as_field: "authorDoc".into(),
+                },
+            ),
+        ];
+        let results = [
+            json!({"settings": {"title": "Inbox"}, "tags": ["a"]}),
+            json!([{"_id": "m1", "author": "u1"}, {"_id": "m2", "author": "u9"}]),
+            json!([{"_id": "u1", "name": "Ada"}]),
+        ];
+        assert_eq!(
+            combine(&fields, &results),
+            json!({
+                "title": "Inbox",
+                "firstTag": "a",
+                "unread": 2,
+                "messages": [
+                    {"_id": "m1", "author": "u1", "authorDoc": {"_id": "u1", "name": "Ada"}},
+                    {"_id": "m2", "author": "u9", "authorDoc": null},
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn values_are_delivered_once_all_sources_report_and_on_change() {
+        let state = DerivedState {
+            combiner: Combiner::Fields(vec![
+                field("a", DerivedValue::Count { source: 0 }),
+                field(
+                    "b",
+                    DerivedValue::Pick {
+                        source: 1,
+                        path: String::new(),
+                    },
+                ),
+            ]),
+            results: Mutex::new(vec![None, None]),
+            delivered: Mutex::new(None),
+            ended: AtomicUsize::new(0),
+            subscriber: Arc::new(Ignore),
+        };
+        assert_eq!(state.update(0, json!([1, 2])), None);
+        assert_eq!(state.update(1, json!("x")), Some(json!({"a": 2, "b": "x"})));
+        assert_eq!(state.update(0, json!([3, 4])), None);
+        assert_eq!(state.update(0, json!([3])), Some(json!({"a": 1, "b": "x"})));
+    }
+
+    #[test]
+    fn strategies_join_whole_results() {
+        let results = [json!({"a": 1, "b": 1}), json!([2]), json!({"b": 3})];
+        let combine = |strategy| Combiner::Strategy(strategy).combine(&results);
+        assert_eq!(
+            combine(CombineStrategy::Array),
+            json!([{"a": 1, "b": 1}, [2], {"b": 3}])
+        );
+        let keys = vec!["user".into(), "tags".into(), "prefs".into()];
+        assert_eq!(
+            combine(CombineStrategy::Keyed { keys }),
+            json!({"user": {"a": 1, "b": 1}, "tags": [2], "prefs": {"b": 3}})
+        );
+        assert_eq!(combine(CombineStrategy::Merge), json!({"a": 1, "b": 3}));
+    }
+
+    #[test]
+    fn out_of_range_sources_are_rejected() {
+        let fields = [field("n", DerivedValue::Count { source: 1 })];
+        assert!(validate(&fields, 1).is_err());
+        assert!(validate(&fields, 2).is_ok());
+    }
+}
@@ -0,0 +1,86 @@
+//! Suppression of repeated identical results.
+//!
+//! Convex occasionally re-delivers an identical result, e.g. after a
+//! reconnect, and every delivery crosses the FFI and rebuilds widgets.
+//! With [`crate::subscription::SubscribeOptions::distinct`], each
+//! serialized result is hashed and updates equal to the previous one are
+//! dropped. An error in between resets this, so a value arriving after an
+//! error is always delivered.
+
+use std::sync::Arc;
+
+use flutter_rust_bridge::frb;
+use parking_lot::Mutex;
+use sha2::{Digest, Sha256};
+
+use crate::QuerySubscriber;
+
+/// Passes on updates that differ from the previous one.
+#[frb(ignore)]
+pub(crate) struct DistinctSubscriber {
+    inner: Arc<dyn QuerySubscriber>,
+    last_hash: Mutex<Option<[u8; 32]>>,
+}
+
+impl DistinctSubscriber {
+    pub(crate) fn new(inner: Arc<dyn QuerySubscriber>) -> Self {
+        DistinctSubscriber {
+            inner,
+            last_hash: Mutex::new(None),
+        }
+    }
+}
+
+impl QuerySubscriber for DistinctSubscriber {
+    fn on_update(&self, value: String) {
+        let hash: [u8; 32] = Sha256::digest(value.as_bytes()).into();
+        if self.last_hash.lock().replace(hash) == Some(hash) {
+            return;
+        }
+        self.inner.on_update(value);
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.last_hash.lock().take();
+        self.inner.on_error(message, value);
+    }
+
+    fn on_done(&self) {
+        self.inner.on_done();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl QuerySubscriber for Recorder {
+        fn on_update(&self, value: String) {
+            self.events.lock().push(value);
+        }
+
+        fn on_error(&self, message: String, _value: Option<String>) {
+            self.events.lock().push(format!("error: {message}"));
+        }
+    }
+
+    #[test]
+    fn repeated_results_are_skipped_unless_an_error_came_between() {
+        let recorder = Arc::new(Recorder::default());
+        let distinct = DistinctSubscriber::new(recorder.clone());
+        for value in ["[1]", "[1]", "[1,2]", "[1]"] {
+            distinct.on_update(value.into());
+        }
+        distinct.on_error("boom".into(), None);
+        distinct.on_update("[1]".into());
+        assert_eq!(
+            *recorder.events.lock(),
+            ["[1]", "[1,2]", "[1]", "error: boom", "[1]"]
+        );
+    }
+}
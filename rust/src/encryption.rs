@@ -0,0 +1,319 @@
+//! End-to-end encryption of selected fields.
+//!
+//! End-to-end encrypted chats must not hand message bodies to the backend,
+//! nor keep them in plaintext in the client's caches.
+//! [`MobileConvexClient::set_field_encryption`] registers field paths and a
+//! key held by the app. Those fields are encrypted with AES-256-GCM when
+//! arguments are parsed, before they reach a cache or the connection, and
+//! are stored by the backend as strings of the form `e2ee:v1:<base64>`.
+//! Results and subscription updates carry them back encrypted, and they are
+//! decrypted only when handed to Dart; the query and action caches keep the
+//! ciphertext and watches do not remember values for placeholders while
+//! encryption is set.
+//!
+//! Paths are dot-separated object keys, e.g. `message.body`. Arrays on the
+//! way are entered element by element, so `body` also selects the bodies in
+//! a list of messages. Encryption uses a fresh nonce per value, so encrypted
+//! arguments never match cached or shared ones. Values that do not decrypt
+//! with the current key are delivered as they are. Structured
+//! [`crate::convex_value::ConvexValue`] arguments and results, paginated
+//! subscriptions and presence are converted the same way. Subscriptions
+//! with encrypted arguments are never sharded, since their ciphertexts
+//! neither group nor match the keys of a batch query.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use anyhow::{anyhow, bail, Context};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use convex::Value;
+use flutter_rust_bridge::frb;
+use log::debug;
+use parking_lot::RwLock;
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    rand::{SecureRandom, SystemRandom},
+};
+use serde_json::Value as JsonValue;
+
+use crate::{
+    value::{json_to_value, value_to_json_string},
+    ClientError, MobileConvexClient,
+};
+
+/// Prefix of encrypted field values.
+const CIPHERTEXT_PREFIX: &str = "e2ee:v1:";
+
+/// A key and the fields it encrypts.
+struct FieldCipher {
+    key: LessSafeKey,
+    paths: Vec<Vec<String>>,
+    random: SystemRandom,
+}
+
+impl FieldCipher {
+    fn new(key: &[u8], fields: Vec<String>) -> anyhow::Result<Self> {
+        let key = UnboundKey::new(&AES_256_GCM, key)
+            .map_err(|_| anyhow!("Field encryption needs a 32-byte key"))?;
+        let paths = fields
+            .into_iter()
+            .map(|field| {
+                let path: Vec<String> = field.split('.').map(str::to_owned).collect();
+                if path.iter().any(String::is_empty) {
+                    bail!("`{field}` is not a dot-separated field path");
+                }
+                Ok(path)
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(FieldCipher {
+            key: LessSafeKey::new(key),
+            paths,
+            random: SystemRandom::new(),
+        })
+    }
+
+    fn seal(&self, plaintext: &str) -> anyhow::Result<String> {
+        let mut nonce = [0; NONCE_LEN];
+        self.random
+            .fill(&mut nonce)
+            .map_err(|_| anyhow!("No randomness available for a nonce"))?;
+        let mut sealed = plaintext.as_bytes().to_vec();
+        self.key
+            .seal_in_place_append_tag(
+                Nonce::assume_unique_for_key(nonce),
+                Aad::empty(),
+                &mut sealed,
+            )
+            .map_err(|_| anyhow!("Encryption failed"))?;
+        sealed.splice(0..0, nonce);
+        Ok(format!("{CIPHERTEXT_PREFIX}{}", STANDARD.encode(sealed)))
+    }
+
+    fn open(&self, sealed: &str) -> Option<JsonValue> {
+        let sealed = STANDARD
+            .decode(sealed.strip_prefix(CIPHERTEXT_PREFIX)?)
+            .ok()?;
+        if sealed.len() < NONCE_LEN {
+            return None;
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).ok()?;
+        let mut ciphertext = ciphertext.to_vec();
+        let plaintext = self
+            .key
+            .open_in_place(nonce, Aad::empty(), &mut ciphertext)
+            .ok()?;
+        serde_json::from_slice(plaintext).ok()
+    }
+
+    fn encrypt_at(&self, value: &mut Value, path: &[String]) -> anyhow::Result<()> {
+        match (value, path) {
+            (Value::String(s), []) if s.starts_with(CIPHERTEXT_PREFIX) => {}
+            (value, []) => {
+                let plaintext = JsonValue::from(value.clone()).to_string();
+                *value = Value::String(self.seal(&plaintext)?);
+            }
+            (Value::Object(map), [key, rest @ ..]) => {
+                if let Some(field) = map.get_mut(key) {
+                    self.encrypt_at(field, rest)?;
+                }
+            }
+            (Value::Array(items), path) => {
+                for item in items {
+                    self.encrypt_at(item, path)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn decrypt_at(&self, value: &mut JsonValue, path: &[String]) {
+        match (value, path) {
+            (value @ JsonValue::String(_), []) => {
+                if let Some(plaintext) = value.as_str().and_then(|s| self.open(s)) {
+                    *value = plaintext;
+                } else if value
+                    .as_str()
+                    .is_some_and(|s| s.starts_with(CIPHERTEXT_PREFIX))
+                {
+                    debug!("An encrypted field did not decrypt with the current key");
+                }
+            }
+            (JsonValue::Object(map), [key, rest @ ..]) => {
+                if let Some(field) = map.get_mut(key) {
+                    self.decrypt_at(field, rest);
+                }
+            }
+            (JsonValue::Array(items), path) => {
+                for item in items {
+                    self.decrypt_at(item, path);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The field encryption of a client, shared with its subscribers.
+#[derive(Clone, Default)]
+#[frb(ignore)]
+pub(crate) struct FieldEncryption(Arc<RwLock<Option<Arc<FieldCipher>>>>);
+
+impl FieldEncryption {
+    fn cipher(&self) -> Option<Arc<FieldCipher>> {
+        self.0.read().clone()
+    }
+
+    pub(crate) fn is_set(&self) -> bool {
+        self.0.read().is_some()
+    }
+
+    /// Returns whether `args` has a field that is encrypted.
+    pub(crate) fn encrypts_any(&self, args: &BTreeMap<String, Value>) -> bool {
+        self.cipher()
+            .is_some_and(|cipher| cipher.paths.iter().any(|path| args.contains_key(&path[0])))
+    }
+
+    /// Encrypts the registered fields of `args`.
+    pub(crate) fn encrypt_args(
+        &self,
+        mut args: BTreeMap<String, Value>,
+    ) -> anyhow::Result<BTreeMap<String, Value>> {
+        let Some(cipher) = self.cipher() else {
+            return Ok(args);
+        };
+        for path in &cipher.paths {
+            if let Some(value) = args.get_mut(&path[0]) {
+                cipher
+                    .encrypt_at(value, &path[1..])
+                    .with_context(|| format!("Could not encrypt `{}`", path.join(".")))?;
+            }
+        }
+        Ok(args)
+    }
+
+    /// Decrypts the registered fields of the result JSON `json`.
+    pub(crate) fn decrypt_result(&self, json: String) -> String {
+        let Some(cipher) = self.cipher() else {
+            return json;
+        };
+        match serde_json::from_str(&json) {
+            Ok(mut parsed) => {
+                for path in &cipher.paths {
+                    cipher.decrypt_at(&mut parsed, path);
+                }
+                parsed.to_string()
+            }
+            Err(_) => json,
+        }
+    }
+
+    /// Decrypts the registered fields of the result `value`.
+    pub(crate) fn decrypt_value(&self, value: Value) -> Value {
+        if !self.is_set() {
+            return value;
+        }
+        let json = self.decrypt_result(value_to_json_string(value.clone()));
+        serde_json::from_str(&json)
+            .ok()
+            .and_then(|json| json_to_value(json).ok())
+            .unwrap_or(value)
+    }
+}
+
+impl MobileConvexClient {
+    /// Encrypts the fields at `fields` with the 32-byte AES-256 `key` from
+    /// now on, as described in the [module docs](crate::encryption).
+    /// Replaces fields and key registered before.
+    #[frb(sync)]
+    pub fn set_field_encryption(
+        &self,
+        key: Vec<u8>,
+        fields: Vec<String>,
+    ) -> Result<(), ClientError> {
+        let cipher = FieldCipher::new(&key, fields)?;
+        *self.field_encryption.0.write() = Some(Arc::new(cipher));
+        Ok(())
+    }
+
+    /// Stops encrypting and decrypting fields.
+    #[frb(sync)]
+    pub fn clear_field_encryption(&self) {
+        self.field_encryption.0.write().take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::btreemap;
+    use serde_json::json;
+
+    use super::*;
+
+    fn with_key(key: u8) -> FieldEncryption {
+        let cipher = FieldCipher::new(&[key; 32], vec!["body".into(), "meta.title".into()]);
+        FieldEncryption(Arc::new(RwLock::new(Some(Arc::new(cipher.unwrap())))))
+    }
+
+    #[test]
+    fn registered_fields_round_trip_encrypted() {
+        let encryption = with_key(7);
+        let args = btreemap! {
+            "body".to_owned() => Value::from("hello"),
+            "meta".to_owned() => Value::Object(btreemap! {
+                "title".to_owned() => Value::from(3.5),
+                "tag".to_owned() => Value::from("plain"),
+            }),
+        };
+        let encrypted = encryption.encrypt_args(args).unwrap();
+        let JsonValue::String(body) = JsonValue::from(encrypted["body"].clone()) else {
+            panic!("body was not encrypted");
+        };
+        assert!(body.starts_with(CIPHERTEXT_PREFIX), "{body}");
+        let meta = JsonValue::from(encrypted["meta"].clone());
+        assert_eq!(meta["tag"], "plain");
+
+        // Results returning a list of the stored documents decrypt the same
+        // fields in every element.
+        let stored = json!({"body": body, "meta": meta});
+        let result = json!([stored, {"body": "legacy"}]).to_string();
+        assert_eq!(
+            serde_json::from_str::<JsonValue>(&encryption.decrypt_result(result.clone())).unwrap(),
+            json!([
+                {"body": "hello", "meta": {"title": 3.5, "tag": "plain"}},
+                {"body": "legacy"},
+            ])
+        );
+        let undecryptable = with_key(8).decrypt_result(result.clone());
+        assert_eq!(
+            serde_json::from_str::<JsonValue>(&undecryptable).unwrap(),
+            serde_json::from_str::<JsonValue>(&result).unwrap()
+        );
+    }
+
+    #[test]
+    fn structured_results_decrypt_in_place() {
+        let encryption = with_key(7);
+        let args = btreemap! {
+            "body".to_owned() => Value::from("hello"),
+            "count".to_owned() => Value::Int64(2),
+        };
+        assert!(encryption.encrypts_any(&args));
+        assert!(!encryption.encrypts_any(&btreemap! { "id".to_owned() => Value::from("a") }));
+        let encrypted = encryption.encrypt_args(args.clone()).unwrap();
+        assert_ne!(encrypted, args);
+        assert_eq!(
+            encryption.decrypt_value(Value::Object(encrypted)),
+            Value::Object(args)
+        );
+        assert!(!FieldEncryption::default().encrypts_any(&btreemap! {
+            "body".to_owned() => Value::from("hello"),
+        }));
+    }
+
+    #[test]
+    fn keys_and_paths_are_checked() {
+        assert!(FieldCipher::new(&[0; 16], vec!["body".into()]).is_err());
+        assert!(FieldCipher::new(&[0; 32], vec!["meta..title".into()]).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+//! Error codes and retryability of [`ClientError`]s.
+//!
+//! Dart apps used to match error messages against regular expressions to
+//! decide whether to retry. Every [`ClientError`] is classified once, when
+//! it is created, and carries its [`ErrorCode`] as `code` and whether
+//! sending the same call again may succeed as `retryable`. A `ConvexError`
+//! also carries the data it was thrown with as a
+//! [`crate::convex_value::ConvexValue`]. Failures the client detects itself,
+//! such as a lost connection or a rejected budget, arrive as
+//! [`ClientError::InternalError`] with their code. The messages of server
+//! errors are classified by the texts Convex uses.
+//!
+//! Convex rejects invalid auth tokens on the connection rather than by
+//! failing functions, so functions checking auth themselves tag the failure
+//! by throwing a `ConvexError` whose data has `code` `"AUTH_EXPIRED"`,
+//! e.g. `throw new ConvexError({ code: "AUTH_EXPIRED" })`. Such errors are
+//! classified as [`ErrorCode::AuthExpired`] and make an auth refresh session
+//! fetch a new token (see [`crate::events::ClientEvent::AuthRejected`]).
+
+use convex::Value;
+use flutter_rust_bridge::frb;
+use serde_json::Value as JsonValue;
+
+use crate::{value::json_to_value, ClientError};
+
+/// `code` in the data of a `ConvexError` marking a rejected auth token.
+pub(crate) const AUTH_EXPIRED_CODE: &str = "AUTH_EXPIRED";
+
+/// What kind of failure a [`ClientError`] is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum ErrorCode {
+    /// The deployment could not be reached or the connection was lost.
+    Network,
+    /// The call did not complete within its timeout.
+    Timeout,
+    /// The function rejected the auth token, as tagged by a `ConvexError`
+    /// with `code` `"AUTH_EXPIRED"`.
+    AuthExpired,
+    /// The client's usage budget or the deployment rejected the call as
+    /// too frequent.
+    RateLimited,
+    /// The deployment has no public function of the name.
+    FunctionNotFound,
+    /// The arguments were invalid, on the client or by the function's
+    /// validator.
+    ValidationFailed,
+    /// The function threw a `ConvexError`, with data for the app.
+    Application,
+    /// The function failed unexpectedly.
+    Server,
+    /// The client failed or was used incorrectly, e.g. after `close`.
+    Internal,
+}
+
+impl ErrorCode {
+    fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            ErrorCode::Network | ErrorCode::Timeout | ErrorCode::RateLimited
+        )
+    }
+}
+
+/// Context marking an error with its [`ErrorCode`]; displayed as the
+/// message of the error it marks.
+#[derive(Debug, thiserror::Error)]
+#[error("{message}")]
+pub(crate) struct CodedError {
+    pub(crate) code: ErrorCode,
+    message: String,
+}
+
+/// Marks `error` with `code`, keeping its message.
+pub(crate) fn with_code(error: anyhow::Error, code: ErrorCode) -> anyhow::Error {
+    let message = error.to_string();
+    error.context(CodedError { code, message })
+}
+
+/// Returns whether the data of a `ConvexError`, serialized as JSON, tags it
+/// as a rejected auth token.
+pub(crate) fn is_auth_error_data(data: &str) -> bool {
+    serde_json::from_str::<JsonValue>(data)
+        .is_ok_and(|data| data.get("code").and_then(JsonValue::as_str) == Some(AUTH_EXPIRED_CODE))
+}
+
+/// Classifies the message of a server error.
+pub(crate) fn server_error_code(message: &str) -> ErrorCode {
+    let lowercase = message.to_ascii_lowercase();
+    if message.contains("Could not find public function") {
+        ErrorCode::FunctionNotFound
+    } else if message.contains("ArgumentValidationError") || message.contains("Validator error") {
+        ErrorCode::ValidationFailed
+    } else if lowercase.contains("rate limit") || lowercase.contains("too many requests") {
+        ErrorCode::RateLimited
+    } else {
+        ErrorCode::Server
+    }
+}
+
+/// Returns whether the data of a `ConvexError` tags it as a rejected auth
+/// token.
+fn is_auth_error_value(data: &Value) -> bool {
+    matches!(data, Value::Object(fields)
+        if matches!(fields.get("code"), Some(Value::String(code)) if code == AUTH_EXPIRED_CODE))
+}
+
+impl ClientError {
+    /// A failure within the client, or one it detected itself, of `code`.
+    pub(crate) fn coded(code: ErrorCode, msg: impl Into<String>) -> Self {
+        ClientError::InternalError {
+            msg: msg.into(),
+            code,
+            retryable: code.is_retryable(),
+        }
+    }
+
+    /// An error within the client or a misuse of it.
+    pub(crate) fn internal(msg: impl Into<String>) -> Self {
+        Self::coded(ErrorCode::Internal, msg)
+    }
+
+    /// A `ConvexError` thrown by a function with `data`.
+    pub(crate) fn convex(message: String, data: Value) -> Self {
+        let code = if is_auth_error_value(&data) {
+            ErrorCode::AuthExpired
+        } else {
+            ErrorCode::Application
+        };
+        ClientError::ConvexError {
+            message,
+            data: data.into(),
+            code,
+            retryable: code.is_retryable(),
+        }
+    }
+
+    /// A `ConvexError` whose data arrived serialized as JSON, as subscribers
+    /// receive it.
+    pub(crate) fn convex_json(message: String, data: &str) -> Self {
+        let data = serde_json::from_str(data)
+            .ok()
+            .and_then(|json| json_to_value(json).ok())
+            .unwrap_or_else(|| Value::String(data.to_owned()));
+        Self::convex(message, data)
+    }
+
+    /// An unexpected failure of a function, classified by its message.
+    pub(crate) fn server(msg: String) -> Self {
+        let code = server_error_code(&msg);
+        ClientError::ServerError {
+            msg,
+            code,
+            retryable: code.is_retryable(),
+        }
+    }
+
+    /// A call that did not complete within `timeout_ms` milliseconds.
+    pub(crate) fn timeout(timeout_ms: u64) -> Self {
+        ClientError::Timeout {
+            timeout_ms,
+            code: ErrorCode::Timeout,
+            retryable: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+    use crate::convex_value::ConvexValue;
+
+    fn code_of(error: &ClientError) -> (ErrorCode, bool) {
+        match error {
+            ClientError::InternalError {
+                code, retryable, ..
+            }
+            | ClientError::ConvexError {
+                code, retryable, ..
+            }
+            | ClientError::ServerError {
+                code, retryable, ..
+            }
+            | ClientError::Timeout {
+                code, retryable, ..
+            } => (*code, *retryable),
+        }
+    }
+
+    #[test]
+    fn errors_are_classified() {
+        let lost = with_code(anyhow!("connection reset"), ErrorCode::Network);
+        let lost = ClientError::from(lost.context("sending messages:send"));
+        assert!(matches!(&lost, ClientError::InternalError {
+            code: ErrorCode::Network,
+            retryable: true,
+            msg,
+        } if msg == "sending messages:send"));
+        assert_eq!(
+            code_of(&ClientError::from(anyhow!("bug"))),
+            (ErrorCode::Internal, false)
+        );
+
+        let server = |msg: &str| code_of(&ClientError::server(msg.into())).0;
+        assert_eq!(
+            server("Could not find public function for 'messages:lst'"),
+            ErrorCode::FunctionNotFound
+        );
+        assert_eq!(
+            server("ArgumentValidationError: Object is missing the required field `body`"),
+            ErrorCode::ValidationFailed
+        );
+        assert_eq!(server("Uncaught Error: Unauthenticated"), ErrorCode::Server);
+        assert_eq!(
+            server("Uncaught Error: Token for the payment provider expired"),
+            ErrorCode::Server
+        );
+        assert_eq!(
+            server("Uncaught TypeError: x is undefined"),
+            ErrorCode::Server
+        );
+        assert_eq!(
+            code_of(&ClientError::timeout(10)),
+            (ErrorCode::Timeout, true)
+        );
+
+        let convex =
+            ClientError::convex_json("Uncaught ConvexError".into(), r#"{"code":"OUT_OF_STOCK"}"#);
+        assert_eq!(code_of(&convex), (ErrorCode::Application, false));
+        assert!(matches!(
+            convex,
+            ClientError::ConvexError {
+                data: ConvexValue::Object(_),
+                ..
+            }
+        ));
+        let auth = ClientError::convex_json(
+            "Uncaught ConvexError".into(),
+            r#"{"code":"AUTH_EXPIRED","message":"Sign in again"}"#,
+        );
+        assert_eq!(code_of(&auth), (ErrorCode::AuthExpired, false));
+    }
+}
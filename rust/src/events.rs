@@ -0,0 +1,237 @@
+//! A single stream of client events.
+//!
+//! Observability and analytics layers used to register one callback per
+//! subsystem. [`MobileConvexClient::events`] delivers the notable events
+//! of the whole client as one tagged [`ClientEvent`] stream instead. The
+//! dedicated callbacks, e.g. [`MobileConvexClient::on_websocket_state_change`],
+//! remain for apps that need their details.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use tokio::sync::{broadcast, watch};
+
+use crate::{
+    errors::is_auth_error_data, ClientError, MobileConvexClient, QuerySubscriber,
+    WebSocketConnectionState,
+};
+
+/// An event of the client's lifecycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum ClientEvent {
+    /// The WebSocket connected.
+    Connected,
+    /// The WebSocket lost its connection and is reconnecting.
+    Disconnected,
+    /// An auth token was applied or cleared.
+    AuthChanged { authenticated: bool },
+    /// A subscription to `name` failed.
+    SubscriptionError { name: String, message: String },
+    /// `name` failed with a `ConvexError` tagging its auth token as
+    /// rejected, e.g. as revoked or expired (see [`crate::errors`]). An auth
+    /// refresh session then fetches a new token right away.
+    AuthRejected { name: String, message: String },
+    /// A mutation is sent again after its attempt number `attempt` failed.
+    MutationRetried {
+        name: String,
+        attempt: u32,
+        error: String,
+    },
+    /// A result of `name` was answered from a cache.
+    CacheHit { name: String },
+}
+
+/// Broadcasts [`ClientEvent`]s to the registered listeners.
+#[frb(ignore)]
+pub(crate) struct EventBus {
+    sender: broadcast::Sender<ClientEvent>,
+    authenticated: AtomicBool, // Last state reported by `AuthChanged`
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        EventBus {
+            sender: broadcast::channel(64).0,
+            authenticated: AtomicBool::new(false),
+        }
+    }
+}
+
+impl EventBus {
+    pub(crate) fn emit(&self, event: ClientEvent) {
+        // Fails only while nobody listens.
+        let _ = self.sender.send(event);
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<ClientEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Emits [`ClientEvent::AuthRejected`] if `name` failed with `message`
+    /// and a `ConvexError` with `data` tagging its auth token as rejected
+    /// (see [`crate::errors`]). Failures while unauthenticated are left to
+    /// the app.
+    pub(crate) fn report_error(&self, name: &str, message: &str, data: Option<&str>) {
+        if self.authenticated.load(Ordering::SeqCst) && data.is_some_and(is_auth_error_data) {
+            self.emit(ClientEvent::AuthRejected {
+                name: name.to_owned(),
+                message: message.to_owned(),
+            });
+        }
+    }
+
+    /// Emits [`ClientEvent::AuthChanged`] if `authenticated` differs from
+    /// the last reported state.
+    pub(crate) fn set_authenticated(&self, authenticated: bool) {
+        if self.authenticated.swap(authenticated, Ordering::SeqCst) != authenticated {
+            self.emit(ClientEvent::AuthChanged { authenticated });
+        }
+    }
+
+    /// Emits connection events for the changes of `state_rx`.
+    pub(crate) async fn track_connection(
+        self: Arc<Self>,
+        mut state_rx: watch::Receiver<WebSocketConnectionState>,
+    ) {
+        let mut connected = false;
+        loop {
+            let now_connected =
+                *state_rx.borrow_and_update() == WebSocketConnectionState::Connected;
+            if now_connected != connected {
+                connected = now_connected;
+                self.emit(if connected {
+                    ClientEvent::Connected
+                } else {
+                    ClientEvent::Disconnected
+                });
+            }
+            if state_rx.changed().await.is_err() {
+                break;
+            }
+        }
+    }
+}
+
+/// Waits for the next [`ClientEvent::AuthRejected`] among `events`, or
+/// forever once the bus is gone.
+pub(crate) async fn next_auth_rejection(events: &mut broadcast::Receiver<ClientEvent>) {
+    loop {
+        match events.recv().await {
+            Ok(ClientEvent::AuthRejected { .. }) => return,
+            Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(broadcast::error::RecvError::Closed) => std::future::pending().await,
+        }
+    }
+}
+
+/// Discards the events already waiting in `events`.
+pub(crate) fn skip_pending(events: &mut broadcast::Receiver<ClientEvent>) {
+    while !matches!(
+        events.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty | broadcast::error::TryRecvError::Closed)
+    ) {}
+}
+
+/// Reports the errors of a subscription on the event bus.
+#[frb(ignore)]
+pub(crate) struct EventSubscriber {
+    pub(crate) inner: Arc<dyn QuerySubscriber>,
+    pub(crate) name: String,
+    pub(crate) events: Arc<EventBus>,
+}
+
+impl QuerySubscriber for EventSubscriber {
+    fn on_update(&self, value: String) {
+        self.inner.on_update(value);
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.events.emit(ClientEvent::SubscriptionError {
+            name: self.name.clone(),
+            message: message.clone(),
+        });
+        self.events
+            .report_error(&self.name, &message, value.as_deref());
+        self.inner.on_error(message, value);
+    }
+
+    fn on_done(&self) {
+        self.inner.on_done();
+    }
+}
+
+impl MobileConvexClient {
+    /// Registers a callback invoked with every [`ClientEvent`] from now on,
+    /// as described in the [module docs](crate::events).
+    #[frb]
+    pub async fn events(
+        &self,
+        on_event: impl Fn(ClientEvent) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let mut events = self.events.sender.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => on_event(event).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn connection_and_auth_changes_are_reported_once() {
+        let bus = Arc::new(EventBus::default());
+        let mut events = bus.sender.subscribe();
+        let state = watch::Sender::new(WebSocketConnectionState::Connecting);
+        let tracker = tokio::spawn(bus.clone().track_connection(state.subscribe()));
+        tokio::task::yield_now().await;
+        state.send_replace(WebSocketConnectionState::Connected);
+        tokio::task::yield_now().await;
+        state.send_replace(WebSocketConnectionState::Connecting);
+        tokio::task::yield_now().await;
+        drop(state);
+        tracker.await.unwrap();
+
+        let rejected = Some(r#"{"code":"AUTH_EXPIRED"}"#);
+        bus.report_error("messages:list", "Uncaught ConvexError", rejected);
+        bus.set_authenticated(true);
+        bus.set_authenticated(true);
+        bus.report_error("messages:list", "Uncaught ConvexError", rejected);
+        bus.report_error("messages:list", "Uncaught Error: Unauthenticated", None);
+        bus.report_error("messages:list", "Uncaught ConvexError", Some(r#""stale""#));
+        bus.set_authenticated(false);
+        let mut received = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            received.push(event);
+        }
+        assert_eq!(
+            received,
+            [
+                ClientEvent::Connected,
+                ClientEvent::Disconnected,
+                ClientEvent::AuthChanged {
+                    authenticated: true
+                },
+                ClientEvent::AuthRejected {
+                    name: "messages:list".into(),
+                    message: "Uncaught ConvexError".into(),
+                },
+                ClientEvent::AuthChanged {
+                    authenticated: false
+                },
+            ]
+        );
+    }
+}
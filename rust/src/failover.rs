@@ -0,0 +1,291 @@
+//! Failover between a prioritized list of deployments.
+//!
+//! The first URL is the primary deployment passed to the client constructor;
+//! [`FailoverOptions::fallback_urls`] follow in priority order. When the
+//! active deployment stays unreachable for `failover_after_ms`, the client
+//! switches to the next URL (wrapping around) and emits a [`FailoverEvent`].
+//! While a fallback is active, the primary is probed every
+//! `failback_probe_interval_ms` and the client fails back once it answers.
+//!
+//! Queries, mutations and actions issued after a switch go to the new
+//! deployment and the current auth token is re-applied. Existing
+//! subscriptions are re-established on the new deployment, highest priority
+//! first and limited by
+//! [`crate::options::ClientOptions::max_concurrent_resubscribes`] if set,
+//! and keep their handles; paused ones follow on resume.
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use convex::{ConvexClient, ConvexClientBuilder, WebSocketState as ConvexWebSocketState};
+use flutter_rust_bridge::{frb, DartFnFuture};
+use futures::{future, pin_mut, select_biased, FutureExt};
+use log::warn;
+use parking_lot::Mutex;
+use serde::Deserialize;
+use tokio::sync::{broadcast, mpsc, watch};
+
+use crate::{
+    ClientError, ClientFactory, ClientSlot, MobileConvexClient, WeakClient,
+    WebSocketConnectionState,
+};
+
+/// How long a probe waits for the primary deployment to accept a connection.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Fallback deployments and failover timing.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct FailoverOptions {
+    /// Fallback deployment URLs, in priority order.
+    pub fallback_urls: Vec<String>,
+    /// How long the active deployment must be unreachable before switching.
+    pub failover_after_ms: u64,
+    /// How often the primary is probed while a fallback is active.
+    pub failback_probe_interval_ms: u64,
+}
+
+/// Why the client switched deployments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum FailoverReason {
+    /// The active deployment was unreachable for too long.
+    ConnectionLost,
+    /// The primary deployment is reachable again.
+    PrimaryRestored,
+}
+
+/// Emitted whenever the client switches to another deployment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct FailoverEvent {
+    pub from_url: String,
+    pub to_url: String,
+    pub reason: FailoverReason,
+}
+
+/// Deployment list and the index of the active deployment.
+pub(crate) struct FailoverState {
+    urls: Vec<String>,
+    active: AtomicUsize,
+    events: broadcast::Sender<FailoverEvent>,
+}
+
+impl FailoverState {
+    pub(crate) fn new(primary_url: &str, options: Option<&FailoverOptions>) -> Arc<Self> {
+        let mut urls = vec![primary_url.to_owned()];
+        if let Some(options) = options {
+            urls.extend(options.fallback_urls.iter().cloned());
+        }
+        Arc::new(FailoverState {
+            urls,
+            active: AtomicUsize::new(0),
+            events: broadcast::channel(16).0,
+        })
+    }
+
+    pub(crate) fn active_url(&self) -> &str {
+        &self.urls[self.active.load(Ordering::SeqCst)]
+    }
+
+    /// Index of the deployment to try after `active`.
+    fn next_index(&self, active: usize) -> usize {
+        (active + 1) % self.urls.len()
+    }
+}
+
+/// Everything the failover task needs to replace the active client.
+pub(crate) struct FailoverTask {
+    pub(crate) state: Arc<FailoverState>,
+    pub(crate) options: FailoverOptions,
+    pub(crate) factory: ClientFactory,
+    pub(crate) slot: ClientSlot,
+    pub(crate) auth_token: Arc<Mutex<Option<String>>>,
+    pub(crate) state_rx: watch::Receiver<WebSocketConnectionState>,
+    pub(crate) client: WeakClient, // Re-establishes subscriptions after a switch
+}
+
+enum Step {
+    StateChanged,
+    Failover,
+    ProbePrimary,
+    Stop,
+}
+
+impl FailoverTask {
+    pub(crate) async fn run(mut self) {
+        if self.state.urls.len() < 2 {
+            return;
+        }
+        let failover_after = Duration::from_millis(self.options.failover_after_ms.max(1));
+        let probe_interval = Duration::from_millis(self.options.failback_probe_interval_ms.max(1));
+        loop {
+            let connected =
+                *self.state_rx.borrow_and_update() == WebSocketConnectionState::Connected;
+            let active = self.state.active.load(Ordering::SeqCst);
+            let step = {
+                let outage_fut = async {
+                    if connected {
+                        future::pending::<()>().await;
+                    }
+                    tokio::time::sleep(failover_after).await;
+                }
+                .fuse();
+                let probe_fut = async {
+                    if active == 0 {
+                        future::pending::<()>().await;
+                    }
+                    tokio::time::sleep(probe_interval).await;
+                }
+                .fuse();
+                let changed_fut = self.state_rx.changed().fuse();
+                pin_mut!(outage_fut, probe_fut, changed_fut);
+                select_biased! {
+                    changed = changed_fut => {
+                        if changed.is_err() { Step::Stop } else { Step::StateChanged }
+                    }
+                    _ = outage_fut => Step::Failover,
+                    _ = probe_fut => Step::ProbePrimary,
+                }
+            };
+            match step {
+                Step::StateChanged => {}
+                Step::Stop => break,
+                Step::Failover => {
+                    let target = self.state.next_index(active);
+                    self.switch_to(target, FailoverReason::ConnectionLost).await;
+                }
+                Step::ProbePrimary => {
+                    if probe(&self.state.urls[0], &self.factory.client_id).await {
+                        self.switch_to(0, FailoverReason::PrimaryRestored).await;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn switch_to(&self, target: usize, reason: FailoverReason) {
+        let to_url = self.state.urls[target].clone();
+        let mut client = match self.factory.build(&to_url).await {
+            Ok(client) => client,
+            Err(e) => {
+                warn!("Failover to {to_url} failed: {e}");
+                return;
+            }
+        };
+        let token = self.auth_token.lock().clone();
+        if token.is_some() {
+            client.set_auth(token).await;
+        }
+        *self.slot.lock().await = Some(client);
+        let from = self.state.active.swap(target, Ordering::SeqCst);
+        let event = FailoverEvent {
+            from_url: self.state.urls[from].clone(),
+            to_url,
+            reason,
+        };
+        warn!("Switched deployment: {event:?}");
+        let _ = self.state.events.send(event);
+        if let Some(client) = self.client.upgrade() {
+            client.move_subscriptions().await;
+        }
+    }
+}
+
+/// Returns the client of the active deployment, or `fallback` if none is set.
+pub(crate) async fn active_client(slot: &ClientSlot, fallback: &ConvexClient) -> ConvexClient {
+    slot.lock()
+        .await
+        .clone()
+        .unwrap_or_else(|| fallback.clone())
+}
+
+/// Returns whether `url` accepts a WebSocket connection within [`PROBE_TIMEOUT`].
+async fn probe(url: &str, client_id: &str) -> bool {
+    let (state_tx, mut state_rx) = mpsc::channel(4);
+    let built = ConvexClientBuilder::new(url)
+        .with_client_id(client_id)
+        .with_on_state_change(state_tx)
+        .build()
+        .await;
+    let Ok(_client) = built else {
+        return false;
+    };
+    tokio::time::timeout(PROBE_TIMEOUT, async {
+        while let Some(state) = state_rx.recv().await {
+            if matches!(state, ConvexWebSocketState::Connected) {
+                return true;
+            }
+        }
+        false
+    })
+    .await
+    .unwrap_or(false)
+}
+
+impl MobileConvexClient {
+    /// Returns the URL of the deployment currently in use.
+    #[frb(sync)]
+    pub fn active_deployment_url(&self) -> String {
+        self.failover.active_url().to_owned()
+    }
+
+    /// Registers a callback invoked whenever the client switches deployments.
+    #[frb]
+    pub async fn on_failover(
+        &self,
+        on_failover: impl Fn(FailoverEvent) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let mut events = self.failover.events.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => on_failover(event).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(fallback_urls: &[&str]) -> FailoverOptions {
+        FailoverOptions {
+            fallback_urls: fallback_urls.iter().map(|url| url.to_string()).collect(),
+            failover_after_ms: 1000,
+            failback_probe_interval_ms: 1000,
+        }
+    }
+
+    #[test]
+    fn primary_comes_first_and_failover_wraps_around() {
+        let state = FailoverState::new("https://a", Some(&options(&["https://b", "https://c"])));
+        assert_eq!(state.active_url(), "https://a");
+        assert_eq!(state.next_index(0), 1);
+        assert_eq!(state.next_index(1), 2);
+        assert_eq!(state.next_index(2), 0);
+    }
+
+    #[test]
+    fn without_options_only_the_primary_is_known() {
+        let state = FailoverState::new("https://a", None);
+        assert_eq!(state.urls, ["https://a"]);
+        assert_eq!(state.next_index(0), 0);
+    }
+
+    #[tokio::test]
+    async fn probing_an_invalid_url_fails_fast() {
+        assert!(!probe("not a url", "test").await);
+    }
+}
@@ -0,0 +1,130 @@
+//! Artificial latency and failures per function, for UX testing.
+//!
+//! Loading, skeleton and error states are hard to see against a fast and
+//! healthy backend. [`MobileConvexClient::set_function_fault`] makes calls
+//! of one function wait and fail at a given rate, so a single screen can be
+//! exercised from Dart at runtime without touching the backend. Faults
+//! apply to queries, mutations, actions and new subscriptions; injected
+//! failures are reported like server errors and never reach the backend.
+
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    hash::BuildHasher,
+    time::Duration,
+};
+
+use flutter_rust_bridge::frb;
+use log::debug;
+use parking_lot::Mutex;
+
+use crate::{ClientError, MobileConvexClient};
+
+/// Latency and failure rate injected into calls of a function.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[frb]
+pub struct FunctionFault {
+    /// Delay before each call is made.
+    pub latency_ms: u32,
+    /// Share of calls failing, from `0.0` (none) to `1.0` (all).
+    pub failure_rate: f64,
+}
+
+/// Returns a uniformly distributed number in `[0, 1)`.
+fn roll() -> f64 {
+    // Each `RandomState` is seeded differently.
+    (RandomState::new().hash_one(0u8) >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Faults configured per function name.
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct FaultInjector {
+    faults: Mutex<HashMap<String, FunctionFault>>,
+}
+
+impl FaultInjector {
+    /// Delays a call of `name` as configured, returning the message of an
+    /// injected failure, if any.
+    pub(crate) async fn apply(&self, name: &str) -> Result<(), String> {
+        let Some(fault) = self.faults.lock().get(name).cloned() else {
+            return Ok(());
+        };
+        if fault.latency_ms > 0 {
+            debug!("Delaying {name} by {}ms", fault.latency_ms);
+            tokio::time::sleep(Duration::from_millis(fault.latency_ms.into())).await;
+        }
+        if roll() < fault.failure_rate {
+            return Err(format!("Injected failure of {name}"));
+        }
+        Ok(())
+    }
+}
+
+impl MobileConvexClient {
+    /// Injects `fault` into all later calls of the function `name`,
+    /// replacing any fault set for it before.
+    #[frb]
+    pub async fn set_function_fault(
+        &self,
+        name: String,
+        fault: FunctionFault,
+    ) -> Result<(), ClientError> {
+        if !(0.0..=1.0).contains(&fault.failure_rate) {
+            return Err(ClientError::internal(format!(
+                "Failure rate must be between 0 and 1, got {}",
+                fault.failure_rate
+            )));
+        }
+        self.faults.faults.lock().insert(name, fault);
+        Ok(())
+    }
+
+    /// Stops injecting faults into calls of `name`.
+    #[frb(sync)]
+    pub fn clear_function_fault(&self, name: String) {
+        self.faults.faults.lock().remove(&name);
+    }
+
+    /// Stops injecting faults into calls of any function.
+    #[frb(sync)]
+    pub fn clear_function_faults(&self) {
+        self.faults.faults.lock().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn injector(name: &str, fault: FunctionFault) -> FaultInjector {
+        let injector = FaultInjector::default();
+        injector.faults.lock().insert(name.into(), fault);
+        injector
+    }
+
+    #[test]
+    fn rolls_are_in_the_unit_interval() {
+        assert!((0..1000).map(|_| roll()).all(|r| (0.0..1.0).contains(&r)));
+    }
+
+    #[tokio::test]
+    async fn faults_apply_only_to_their_function() {
+        let failing = injector(
+            "messages:list",
+            FunctionFault {
+                latency_ms: 20,
+                failure_rate: 1.0,
+            },
+        );
+        let started = tokio::time::Instant::now();
+        assert_eq!(
+            failing.apply("messages:list").await,
+            Err("Injected failure of messages:list".into())
+        );
+        assert!(started.elapsed() >= Duration::from_millis(20));
+        assert_eq!(failing.apply("messages:send").await, Ok(()));
+
+        let healthy = injector("messages:list", FunctionFault::default());
+        assert_eq!(healthy.apply("messages:list").await, Ok(()));
+    }
+}
@@ -0,0 +1,506 @@
+//! Uploads to Convex file storage.
+//!
+//! Uploading a file takes three steps: a mutation returns a short-lived
+//! upload URL, the file is POSTed to it, and the response carries the
+//! storage ID to save in a document. [`MobileConvexClient::upload_file`]
+//! does all three from Rust, streaming files from disk instead of copying
+//! them into Dart memory and reporting the progress along the way. The
+//! mutation is [`FileStorageOptions::generate_upload_url_mutation`], which
+//! must return the URL of `ctx.storage.generateUploadUrl()`.
+//!
+//! [`MobileConvexClient::download_file`] streams a file to disk, so large
+//! media never passes through Dart memory. It is written to `<dest>.part`
+//! first and renamed once complete. When the connection drops or no data
+//! arrives for [`FileStorageOptions::download_read_timeout_ms`], the download
+//! resumes where it stopped with a `Range` request, up to
+//! [`FileStorageOptions::download_attempts`] times; a partial file left by
+//! an earlier run is resumed as well. The file's `ETag`, or its
+//! `Last-Modified` date, is kept in `<dest>.part.validator` and sent as
+//! `If-Range`, so a file that changed in between is downloaded again from
+//! the start instead of being spliced. Partial files without a validator
+//! are never resumed.
+
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+    pin::Pin,
+    time::Duration,
+};
+
+use anyhow::{anyhow, bail, Context};
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::warn;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use tokio::{fs, io::AsyncRead, sync::watch, task::JoinHandle};
+
+use crate::{
+    backoff::exponential_backoff,
+    convex_value::ConvexValue,
+    http::{get_streaming, header, request_streaming},
+    result::handle_typed_function_result,
+    ClientError, MobileConvexClient,
+};
+
+/// How files are uploaded.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct FileStorageOptions {
+    /// Mutation returning an upload URL, called without arguments.
+    pub generate_upload_url_mutation: String,
+    /// Attempts of a download, including the first one.
+    pub download_attempts: u32,
+    /// Delay before resuming an interrupted download; doubled after every
+    /// failed attempt.
+    pub download_backoff_ms: u64,
+    /// Longest wait for the response or its next bytes before a download
+    /// attempt counts as interrupted.
+    pub download_read_timeout_ms: u64,
+}
+
+impl Default for FileStorageOptions {
+    fn default() -> Self {
+        FileStorageOptions {
+            generate_upload_url_mutation: "files:generateUploadUrl".to_owned(),
+            download_attempts: 5,
+            download_backoff_ms: 500,
+            download_read_timeout_ms: 30_000,
+        }
+    }
+}
+
+/// What [`MobileConvexClient::upload_file`] uploads.
+#[derive(Debug, Clone)]
+#[frb]
+pub enum UploadSource {
+    /// The file at `path`, streamed from disk.
+    Path {
+        path: String,
+    },
+    Bytes {
+        bytes: Vec<u8>,
+    },
+}
+
+/// Progress of a file transfer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub struct TransferProgress {
+    pub transferred_bytes: u64,
+    /// Size of the file, if known.
+    pub total_bytes: Option<u64>,
+}
+
+/// Suffix of the file a download is written to until it is complete.
+const PARTIAL_SUFFIX: &str = ".part";
+/// Suffix, after the partial file's name, of the file keeping the
+/// validator a partial download is resumed with.
+const VALIDATOR_SUFFIX: &str = ".validator";
+
+type OnProgress = dyn Fn(TransferProgress) -> DartFnFuture<()> + Send + Sync;
+
+type Body = Pin<Box<dyn AsyncRead + Send>>;
+
+/// Opens `source` for streaming and returns it with its length.
+async fn open(source: UploadSource) -> anyhow::Result<(Body, u64)> {
+    match source {
+        UploadSource::Path { path } => {
+            let file = tokio::fs::File::open(&path)
+                .await
+                .with_context(|| format!("Cannot open `{path}`"))?;
+            let length = file.metadata().await?.len();
+            Ok((Box::pin(file), length))
+        }
+        UploadSource::Bytes { bytes } => {
+            let length = bytes.len() as u64;
+            Ok((Box::pin(std::io::Cursor::new(bytes)), length))
+        }
+    }
+}
+
+/// Returns the storage ID of an upload response.
+fn storage_id(status: u16, body: &[u8]) -> anyhow::Result<String> {
+    let text = String::from_utf8_lossy(body);
+    if !(200..300).contains(&status) {
+        bail!("The upload failed with status {status}: {text}");
+    }
+    let response: JsonValue = serde_json::from_slice(body)
+        .with_context(|| format!("Unexpected upload response: {text}"))?;
+    match response.get("storageId").and_then(JsonValue::as_str) {
+        Some(id) => Ok(id.to_owned()),
+        None => bail!("The upload response has no storage ID: {text}"),
+    }
+}
+
+/// Returns the URL of a download: `target` itself if it is a URL, or the
+/// deployment's storage endpoint for a storage ID.
+fn download_url(deployment_url: &str, target: &str) -> String {
+    if target.starts_with("https://") || target.starts_with("http://") {
+        target.to_owned()
+    } else {
+        let deployment = deployment_url.trim_end_matches('/');
+        format!("{deployment}/api/storage/{target}")
+    }
+}
+
+/// Returns the first byte and total size of a `Content-Range` header like
+/// `bytes 100-199/200`.
+fn content_range(value: &str) -> Option<(u64, Option<u64>)> {
+    let (range, total) = value.strip_prefix("bytes ")?.split_once('/')?;
+    let start = range.split_once('-')?.0.parse().ok()?;
+    Some((start, total.parse().ok()))
+}
+
+/// Returns the path of the validator kept next to `partial`.
+fn validator_path(partial: &Path) -> PathBuf {
+    let mut path = partial.as_os_str().to_owned();
+    path.push(VALIDATOR_SUFFIX);
+    PathBuf::from(path)
+}
+
+/// Returns the value identifying the version of a response's file in an
+/// `If-Range` header: its strong `ETag`, or else its `Last-Modified` date.
+fn resume_validator(headers: &[(String, String)]) -> Option<&str> {
+    header(headers, "etag")
+        .filter(|etag| !etag.starts_with("W/"))
+        .or_else(|| header(headers, "last-modified"))
+}
+
+/// Why a download attempt failed.
+enum Failure {
+    /// The server refused the download; retrying does not help.
+    Rejected(anyhow::Error),
+    /// The connection failed; the download can be resumed.
+    Interrupted(anyhow::Error),
+}
+
+/// Downloads `url` into `partial`, resuming after the bytes already in it
+/// if the file did not change since. Waits at most `read_timeout` for the
+/// response and for each of its reads.
+async fn download_attempt(
+    url: &str,
+    partial: &Path,
+    read_timeout: Duration,
+    progress: &watch::Sender<TransferProgress>,
+) -> Result<(), Failure> {
+    let offset = match fs::metadata(partial).await {
+        Ok(metadata) => metadata.len(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+        Err(e) => return Err(Failure::Rejected(e.into())),
+    };
+    let validator_path = validator_path(partial);
+    let validator = match offset {
+        0 => None,
+        _ => fs::read_to_string(&validator_path).await.ok(),
+    };
+    let mut headers = Vec::new();
+    if let Some(validator) = validator {
+        headers.push(("Range".to_owned(), format!("bytes={offset}-")));
+        headers.push(("If-Range".to_owned(), validator));
+    }
+    let response = tokio::time::timeout(read_timeout, get_streaming(url, &headers))
+        .await
+        .map_err(|_| Failure::Interrupted(anyhow!("No response within {read_timeout:?}")))?
+        .map_err(Failure::Interrupted)?;
+    let length = header(&response.headers, "content-length").and_then(|l| l.parse().ok());
+    let (start, total) = match response.status {
+        206 => header(&response.headers, "content-range")
+            .and_then(content_range)
+            .filter(|(start, _)| *start == offset)
+            .ok_or_else(|| Failure::Rejected(anyhow!("Unexpected range in the response")))?,
+        200 => (0, length),
+        // The partial file is not a prefix of the file anymore.
+        416 => {
+            let _ = fs::remove_file(partial).await;
+            return Err(Failure::Interrupted(anyhow!(
+                "The partial download is invalid"
+            )));
+        }
+        status => {
+            let body = response.body.read_all().await.unwrap_or_default();
+            let error = anyhow!(
+                "The download failed with status {status}: {}",
+                String::from_utf8_lossy(&body)
+            );
+            return Err(if status >= 500 {
+                Failure::Interrupted(error)
+            } else {
+                Failure::Rejected(error)
+            });
+        }
+    };
+    let total = total.or(length.map(|length: u64| start + length));
+    match resume_validator(&response.headers) {
+        Some(validator) => fs::write(&validator_path, validator)
+            .await
+            .map_err(|e| Failure::Rejected(e.into()))?,
+        None if start == 0 => {
+            let _ = fs::remove_file(&validator_path).await;
+        }
+        None => {}
+    }
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(start > 0)
+        .write(true)
+        .truncate(start == 0)
+        .open(partial)
+        .await
+        .map_err(|e| Failure::Rejected(e.into()))?;
+    progress.send_replace(TransferProgress {
+        transferred_bytes: start,
+        total_bytes: total,
+    });
+    response
+        .body
+        .copy_to(&mut file, read_timeout, |received| {
+            progress.send_modify(|progress| progress.transferred_bytes = start + received);
+        })
+        .await
+        .map_err(Failure::Interrupted)?;
+    let metadata = file.metadata().await;
+    let written = metadata.map_err(|e| Failure::Rejected(e.into()))?.len();
+    match total {
+        Some(total) if written != total => Err(Failure::Interrupted(anyhow!(
+            "Downloaded {written} of {total} bytes"
+        ))),
+        _ => Ok(()),
+    }
+}
+
+impl MobileConvexClient {
+    /// Calls `on_progress` with the updates sent to the returned channel, in
+    /// order and coalesced to the latest while a call runs, until the
+    /// channel is dropped.
+    fn report_progress(
+        &self,
+        on_progress: Box<OnProgress>,
+        initial: TransferProgress,
+    ) -> (watch::Sender<TransferProgress>, JoinHandle<()>) {
+        let (progress, mut updates) = watch::channel(initial);
+        let reporter = self.rt.spawn(async move {
+            loop {
+                let update = *updates.borrow_and_update();
+                on_progress(update).await;
+                if updates.changed().await.is_err() {
+                    break;
+                }
+            }
+        });
+        (progress, reporter)
+    }
+
+    /// Downloads `url` into `partial`, resuming interrupted attempts.
+    async fn download_resuming(
+        &self,
+        url: &str,
+        partial: &Path,
+        progress: &watch::Sender<TransferProgress>,
+    ) -> anyhow::Result<()> {
+        let options = &self.options.file_storage;
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let read_timeout = Duration::from_millis(options.download_read_timeout_ms);
+            match download_attempt(url, partial, read_timeout, progress).await {
+                Ok(()) => return Ok(()),
+                Err(Failure::Rejected(e)) => return Err(e),
+                Err(Failure::Interrupted(e)) if attempt >= options.download_attempts => {
+                    return Err(e)
+                }
+                Err(Failure::Interrupted(e)) => {
+                    warn!("Resuming the download of {url} after: {e}");
+                    let delay = exponential_backoff(options.download_backoff_ms, u64::MAX, attempt);
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    /// Uploads `source` with the content type `mime_type` and returns its
+    /// storage ID, as described in the [module docs](crate::file_storage).
+    /// `on_progress` is called with the bytes sent so far; updates arriving
+    /// while it runs are coalesced to the latest.
+    #[frb]
+    pub async fn upload_file(
+        &self,
+        source: UploadSource,
+        mime_type: String,
+        on_progress: impl Fn(TransferProgress) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<String, ClientError> {
+        self.ensure_open()?;
+        let (body, length) = open(source).await?;
+        let mutation = self
+            .options
+            .file_storage
+            .generate_upload_url_mutation
+            .clone();
+        let result = self
+            .internal_mutation(mutation.clone(), BTreeMap::new())
+            .await?;
+        let ConvexValue::String(url) = handle_typed_function_result(result)? else {
+            return Err(anyhow!("`{mutation}` did not return an upload URL").into());
+        };
+
+        let initial = TransferProgress {
+            transferred_bytes: 0,
+            total_bytes: Some(length),
+        };
+        let (progress, reporter) = self.report_progress(Box::new(on_progress), initial);
+        let headers = [("Content-Type".to_owned(), mime_type)];
+        let response = request_streaming(&url, "POST", "", &headers, body, length, |sent| {
+            progress.send_modify(|progress| progress.transferred_bytes = sent);
+        })
+        .await;
+        drop(progress);
+        // The last update is delivered before the upload resolves.
+        let _ = reporter.await;
+        let response = response?;
+        Ok(storage_id(response.status, &response.body)?)
+    }
+
+    /// Downloads `url_or_storage_id`, a URL such as one returned by
+    /// `ctx.storage.getUrl()` or a storage ID, to `dest_path`, as described
+    /// in the [module docs](crate::file_storage). `on_progress` is called
+    /// with the bytes written so far, including those of a resumed partial
+    /// file.
+    #[frb]
+    pub async fn download_file(
+        &self,
+        url_or_storage_id: String,
+        dest_path: String,
+        on_progress: impl Fn(TransferProgress) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        self.ensure_open()?;
+        let url = download_url(self.failover.active_url(), &url_or_storage_id);
+        let partial = format!("{dest_path}{PARTIAL_SUFFIX}");
+        let initial = TransferProgress {
+            transferred_bytes: 0,
+            total_bytes: None,
+        };
+        let (progress, reporter) = self.report_progress(Box::new(on_progress), initial);
+        let result = self
+            .download_resuming(&url, Path::new(&partial), &progress)
+            .await;
+        drop(progress);
+        let _ = reporter.await;
+        result?;
+        fs::rename(&partial, &dest_path)
+            .await
+            .with_context(|| format!("Cannot move the download to `{dest_path}`"))?;
+        let _ = fs::remove_file(validator_path(Path::new(&partial))).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn files_are_opened_with_their_length_and_responses_parsed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("photo.jpg");
+        std::fs::write(&path, [1; 70_000]).unwrap();
+        let (_, length) = open(UploadSource::Path {
+            path: path.to_string_lossy().into_owned(),
+        })
+        .await
+        .unwrap();
+        assert_eq!(length, 70_000);
+        let missing = dir.path().join("missing").to_string_lossy().into_owned();
+        assert!(open(UploadSource::Path { path: missing }).await.is_err());
+
+        let id = storage_id(200, br#"{"storageId":"kg2abc"}"#).unwrap();
+        assert_eq!(id, "kg2abc");
+        assert!(storage_id(200, b"{}").is_err());
+        assert!(storage_id(413, b"Payload too large").is_err());
+    }
+
+    #[tokio::test]
+    async fn interrupted_downloads_resume_with_a_range_request() {
+        use tokio::{
+            io::{AsyncReadExt, AsyncWriteExt},
+            net::TcpListener,
+        };
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = download_url(
+            &format!("http://{}", listener.local_addr().unwrap()),
+            "kg2abc",
+        );
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            let mut streams = Vec::new();
+            let responses: [&[u8]; 4] = [
+                b"HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 10\r\n\r\n0123",
+                b"HTTP/1.1 206 Partial Content\r\nContent-Range: bytes 4-9/10\r\n\
+                  ETag: \"v1\"\r\nContent-Length: 6\r\n\r\n456789",
+                // The file changed, so `If-Range` does not match.
+                b"HTTP/1.1 200 OK\r\nETag: \"v2\"\r\nContent-Length: 5\r\n\r\nwxyz0",
+                // Stalls after two bytes.
+                b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\n01",
+            ];
+            for response in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = vec![0u8; 4096];
+                let read = stream.read(&mut request).await.unwrap();
+                requests.push(String::from_utf8_lossy(&request[..read]).into_owned());
+                stream.write_all(response).await.unwrap();
+                if response.ends_with(b"01") {
+                    streams.push(stream);
+                }
+            }
+            (requests, streams)
+        });
+
+        let dir = tempfile::tempdir().unwrap();
+        let partial = dir.path().join("video.mp4.part");
+        let (progress, _updates) = watch::channel(TransferProgress {
+            transferred_bytes: 0,
+            total_bytes: None,
+        });
+        let timeout = Duration::from_secs(5);
+        let interrupted = download_attempt(&url, &partial, timeout, &progress).await;
+        assert!(matches!(interrupted, Err(Failure::Interrupted(_))));
+        assert!(download_attempt(&url, &partial, timeout, &progress)
+            .await
+            .is_ok());
+        assert_eq!(std::fs::read(&partial).unwrap(), b"0123456789");
+        assert_eq!(
+            *progress.borrow(),
+            TransferProgress {
+                transferred_bytes: 10,
+                total_bytes: Some(10),
+            }
+        );
+
+        // A partial file of an older version starts over.
+        std::fs::write(&partial, b"0123").unwrap();
+        assert!(download_attempt(&url, &partial, timeout, &progress)
+            .await
+            .is_ok());
+        assert_eq!(std::fs::read(&partial).unwrap(), b"wxyz0");
+        let validator = std::fs::read_to_string(validator_path(&partial)).unwrap();
+        assert_eq!(validator, "\"v2\"");
+
+        // Partial files without a validator are not resumed, and stalled
+        // reads time out.
+        std::fs::remove_file(validator_path(&partial)).unwrap();
+        let timeout = Duration::from_millis(200);
+        let stalled = download_attempt(&url, &partial, timeout, &progress).await;
+        assert!(matches!(stalled, Err(Failure::Interrupted(_))));
+
+        let (requests, _streams) = server.await.unwrap();
+        let requests: Vec<String> = requests.iter().map(|r| r.to_ascii_lowercase()).collect();
+        assert!(requests[0].starts_with("get /api/storage/kg2abc http/1.1\r\n"));
+        assert!(!requests[0].contains("range"));
+        assert!(requests[1].contains("range: bytes=4-\r\n"));
+        assert!(requests[1].contains("if-range: \"v1\"\r\n"));
+        assert!(requests[2].contains("if-range: \"v1\"\r\n"));
+        assert!(!requests[3].contains("range"));
+        assert_eq!(content_range("bytes 4-9/*"), Some((4, None)));
+    }
+}
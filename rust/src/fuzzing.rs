@@ -0,0 +1,80 @@
+//! Entry points for the cargo-fuzz targets in `fuzz/`.
+//!
+//! Only compiled with `--cfg fuzzing` (set by `cargo fuzz`), so the internal
+//! parsers can be driven from the separate fuzz crate without making them
+//! part of the public or bridged API.
+
+use std::collections::HashMap;
+
+use flutter_rust_bridge::frb;
+
+use convex::Value;
+
+use crate::{
+    convex_value::{ConvexValue, MAX_NESTING},
+    options::{BlankArgHandling, NullHandling},
+};
+
+/// Parses an FFI argument map, returning whether it was accepted.
+#[frb(ignore)]
+pub fn parse_json_args(raw_args: HashMap<String, String>, null_handling: NullHandling) -> bool {
+    crate::args::parse_json_args(raw_args, null_handling, BlankArgHandling::Error, &[]).is_ok()
+}
+
+/// Converts structured FFI arguments and checks that accepted ones only
+/// contain valid field names and stay within the nesting limit.
+#[frb(ignore)]
+pub fn convex_args(args: HashMap<String, ConvexValue>, null_handling: NullHandling) {
+    let Ok(args) = crate::convex_value::convex_args(args, null_handling) else {
+        return;
+    };
+    check_converted(&Value::Object(args), 1);
+}
+
+fn check_converted(value: &Value, depth: usize) {
+    match value {
+        Value::Array(items) => {
+            assert!(depth <= MAX_NESTING, "accepted {depth} levels of nesting");
+            for item in items {
+                check_converted(item, depth + 1);
+            }
+        }
+        Value::Object(fields) => {
+            assert!(depth <= MAX_NESTING, "accepted {depth} levels of nesting");
+            for (name, value) in fields {
+                assert!(
+                    !name.is_empty()
+                        && !name.starts_with('$')
+                        && name.bytes().all(|b| (0x20..0x7f).contains(&b)),
+                    "accepted field name {name:?}"
+                );
+                check_converted(value, depth + 1);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Parses JSON text into a Convex value and checks that serializing it again
+/// yields the same value.
+#[frb(ignore)]
+pub fn json_value_round_trip(json: &str) {
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(json) else {
+        return;
+    };
+    let Ok(value) = crate::value::json_to_value(json) else {
+        return;
+    };
+    let encoded = crate::value::value_to_json_string(value.clone());
+    let decoded = serde_json::from_str(&encoded)
+        .map_err(anyhow::Error::from)
+        .and_then(crate::value::json_to_value)
+        .expect("serialized Convex value must parse again");
+    assert_eq!(decoded, value, "round trip changed {encoded}");
+}
+
+/// Extracts the `exp` claim from an arbitrary token.
+#[frb(ignore)]
+pub fn decode_jwt_expiry(token: &str) -> Option<u64> {
+    crate::jwt::decode_jwt_expiry(token)
+}
@@ -0,0 +1,220 @@
+//! User-facing status hints derived from the client's internal state.
+//!
+//! Instead of every app mapping connection, auth and mutation state to
+//! status-bar text, the client publishes a single [`UiHint`]: a stable code
+//! for localization plus named parameters to interpolate. `None` means there
+//! is nothing worth showing.
+//!
+//! Hints are prioritized: connectivity first, then signing in, then pending
+//! mutations. The Convex client does not expose its reconnect backoff, so
+//! [`UiHintCode::Reconnecting`] reports when the connection was lost rather
+//! than when the next attempt happens.
+
+use std::{collections::HashMap, sync::Arc};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use parking_lot::Mutex;
+use tokio::sync::watch;
+
+use crate::{presence::now_millis, ClientError, MobileConvexClient, WebSocketConnectionState};
+
+/// What a [`UiHint`] is about; stable across releases for use as a
+/// localization key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum UiHintCode {
+    /// Establishing the first connection.
+    Connecting,
+    /// The connection was lost. Parameter `offlineSinceMs`: Unix time in
+    /// milliseconds when it was lost.
+    Reconnecting,
+    /// Waiting for the first auth token.
+    SigningIn,
+    /// Mutations are waiting for the server. Parameter `count`: how many.
+    SyncingChanges,
+}
+
+/// A status hint: a code plus parameters for the localized message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct UiHint {
+    pub code: UiHintCode,
+    pub params: HashMap<String, String>,
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+struct HintInputs {
+    connected_once: bool,
+    offline_since_ms: Option<i64>,
+    signing_in: bool,
+    pending_mutations: u32,
+}
+
+impl HintInputs {
+    fn hint(&self) -> Option<UiHint> {
+        let (code, params) = if let Some(since) = self.offline_since_ms {
+            let params = HashMap::from([("offlineSinceMs".to_owned(), since.to_string())]);
+            (UiHintCode::Reconnecting, params)
+        } else if !self.connected_once {
+            (UiHintCode::Connecting, HashMap::new())
+        } else if self.signing_in {
+            (UiHintCode::SigningIn, HashMap::new())
+        } else if self.pending_mutations > 0 {
+            let params = HashMap::from([("count".to_owned(), self.pending_mutations.to_string())]);
+            (UiHintCode::SyncingChanges, params)
+        } else {
+            return None;
+        };
+        Some(UiHint { code, params })
+    }
+}
+
+/// Tracks the inputs of the current hint and publishes changes.
+pub(crate) struct UiHints {
+    inputs: Mutex<HintInputs>,
+    current: watch::Sender<Option<UiHint>>,
+}
+
+/// Counts a mutation as pending until dropped.
+pub(crate) struct PendingMutation(Arc<UiHints>);
+
+impl Drop for PendingMutation {
+    fn drop(&mut self) {
+        self.0.update(|inputs| inputs.pending_mutations -= 1);
+    }
+}
+
+impl UiHints {
+    pub(crate) fn new() -> Self {
+        let inputs = HintInputs::default();
+        let current = watch::Sender::new(inputs.hint());
+        UiHints {
+            inputs: Mutex::new(inputs),
+            current,
+        }
+    }
+
+    fn update(&self, change: impl FnOnce(&mut HintInputs)) {
+        let hint = {
+            let mut inputs = self.inputs.lock();
+            change(&mut inputs);
+            inputs.hint()
+        };
+        self.current.send_if_modified(|current| {
+            let changed = *current != hint;
+            *current = hint;
+            changed
+        });
+    }
+
+    /// Follows the connection state until the client is dropped.
+    pub(crate) async fn track_connection(
+        self: Arc<Self>,
+        mut state_rx: watch::Receiver<WebSocketConnectionState>,
+    ) {
+        loop {
+            let state = state_rx.borrow_and_update().clone();
+            self.record_state(&state);
+            if state_rx.changed().await.is_err() {
+                break;
+            }
+        }
+    }
+
+    fn record_state(&self, state: &WebSocketConnectionState) {
+        let connected = *state == WebSocketConnectionState::Connected;
+        self.update(|inputs| {
+            if connected {
+                inputs.connected_once = true;
+                inputs.offline_since_ms = None;
+            } else if inputs.connected_once && inputs.offline_since_ms.is_none() {
+                inputs.offline_since_ms = Some(now_millis());
+            }
+        });
+    }
+
+    pub(crate) fn set_signing_in(&self, signing_in: bool) {
+        self.update(|inputs| inputs.signing_in = signing_in);
+    }
+
+    pub(crate) fn mutation_started(self: &Arc<Self>) -> PendingMutation {
+        self.update(|inputs| inputs.pending_mutations += 1);
+        PendingMutation(self.clone())
+    }
+}
+
+impl MobileConvexClient {
+    /// Returns the hint to show for the client's current state, if any.
+    #[frb(sync)]
+    pub fn ui_hint(&self) -> Option<UiHint> {
+        self.ui_hints.current.borrow().clone()
+    }
+
+    /// Registers a callback invoked with the current hint and whenever it
+    /// changes.
+    #[frb]
+    pub async fn on_ui_hint(
+        &self,
+        on_hint: impl Fn(Option<UiHint>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let mut hints = self.ui_hints.current.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                let hint = hints.borrow_and_update().clone();
+                on_hint(hint).await;
+                if hints.changed().await.is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code(hints: &UiHints) -> Option<UiHintCode> {
+        hints.current.borrow().as_ref().map(|hint| hint.code)
+    }
+
+    #[test]
+    fn connectivity_takes_priority() {
+        let hints = Arc::new(UiHints::new());
+        assert_eq!(code(&hints), Some(UiHintCode::Connecting));
+        hints.set_signing_in(true);
+        assert_eq!(code(&hints), Some(UiHintCode::Connecting));
+
+        hints.record_state(&WebSocketConnectionState::Connected);
+        assert_eq!(code(&hints), Some(UiHintCode::SigningIn));
+        hints.record_state(&WebSocketConnectionState::Connecting);
+        assert_eq!(code(&hints), Some(UiHintCode::Reconnecting));
+        let hint = hints.current.borrow().clone().unwrap();
+        assert!(hint.params["offlineSinceMs"].parse::<i64>().unwrap() > 0);
+
+        hints.record_state(&WebSocketConnectionState::Connected);
+        hints.set_signing_in(false);
+        assert_eq!(code(&hints), None);
+    }
+
+    #[test]
+    fn pending_mutations_are_counted_until_dropped() {
+        let hints = Arc::new(UiHints::new());
+        hints.record_state(&WebSocketConnectionState::Connected);
+        let first = hints.mutation_started();
+        let second = hints.mutation_started();
+        let hint = hints.current.borrow().clone().unwrap();
+        assert_eq!(hint.code, UiHintCode::SyncingChanges);
+        assert_eq!(hint.params["count"], "2");
+
+        drop(first);
+        assert_eq!(
+            hints.current.borrow().as_ref().unwrap().params["count"],
+            "1"
+        );
+        drop(second);
+        assert_eq!(code(&hints), None);
+    }
+}
@@ -0,0 +1,363 @@
+//! HTTP client for the deployment's HTTP API and HTTP actions.
+//!
+//! The Convex Rust client only speaks the sync protocol over its WebSocket;
+//! the few endpoints it does not cover are called here with one shared
+//! `reqwest` client using rustls. Connecting times out after
+//! [`CONNECT_TIMEOUT`] and every read of a response after [`READ_TIMEOUT`],
+//! so a stalled server fails the call instead of hanging it, and up to
+//! [`MAX_REDIRECTS`] redirects are followed.
+
+use std::{sync::OnceLock, time::Duration};
+
+use anyhow::{anyhow, bail, Context};
+use futures::{channel::mpsc, SinkExt};
+use reqwest::{
+    header::{HeaderMap, CONTENT_LENGTH},
+    redirect::Policy,
+    Client, Method, Response,
+};
+use serde_json::Value as JsonValue;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Longest wait for a connection to be established.
+pub(crate) const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// Longest wait for the next bytes of a response.
+pub(crate) const READ_TIMEOUT: Duration = Duration::from_secs(30);
+/// Most redirects followed for one request.
+pub(crate) const MAX_REDIRECTS: usize = 10;
+
+/// A response with its status, headers in order and body.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct HttpResponse {
+    pub(crate) status: u16,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: Vec<u8>,
+}
+
+/// The client shared by all requests, keeping connections alive between
+/// them.
+fn client() -> anyhow::Result<&'static Client> {
+    static CLIENT: OnceLock<Client> = OnceLock::new();
+    if let Some(client) = CLIENT.get() {
+        return Ok(client);
+    }
+    let client = Client::builder()
+        .use_rustls_tls()
+        .connect_timeout(CONNECT_TIMEOUT)
+        .read_timeout(READ_TIMEOUT)
+        .redirect(Policy::limited(MAX_REDIRECTS))
+        .build()
+        .context("building the HTTP client")?;
+    Ok(CLIENT.get_or_init(|| client))
+}
+
+/// Sends a POST with a JSON `body` to `path` of `deployment_url` and returns
+/// the JSON response. Non-2xx responses are errors carrying the body.
+pub(crate) async fn post_json(
+    deployment_url: &str,
+    path: &str,
+    auth_token: Option<&str>,
+    body: &str,
+) -> anyhow::Result<JsonValue> {
+    let mut headers = vec![("Content-Type".to_owned(), "application/json".to_owned())];
+    if let Some(token) = auth_token {
+        headers.push(("Authorization".to_owned(), format!("Bearer {token}")));
+    }
+    let response = request(deployment_url, "POST", path, &headers, body.as_bytes()).await?;
+    let status = response.status;
+    if !(200..300).contains(&status) {
+        bail!(
+            "{path} failed with status {status}: {}",
+            String::from_utf8_lossy(&response.body)
+        );
+    }
+    serde_json::from_slice(&response.body)
+        .with_context(|| format!("parsing the response of {path}"))
+}
+
+/// Sends a `method` request with `headers` and `body` to `path` of
+/// `base_url`, returning the response whatever its status.
+pub(crate) async fn request(
+    base_url: &str,
+    method: &str,
+    path: &str,
+    headers: &[(String, String)],
+    body: &[u8],
+) -> anyhow::Result<HttpResponse> {
+    let response = builder(base_url, method, path, headers)?
+        .body(body.to_vec())
+        .send()
+        .await?;
+    read_response(response).await
+}
+
+/// Like [`request`], but streams the `length` bytes of `body`, calling
+/// `on_sent` with the number of bytes sent so far after each chunk.
+pub(crate) async fn request_streaming(
+    base_url: &str,
+    method: &str,
+    path: &str,
+    headers: &[(String, String)],
+    mut body: impl AsyncRead + Unpin,
+    length: u64,
+    mut on_sent: impl FnMut(u64),
+) -> anyhow::Result<HttpResponse> {
+    // The body is read here rather than by reqwest, so `body` and
+    // `on_sent` need not be `'static`. A single slot keeps the reported
+    // progress close to what the connection accepted.
+    let (mut chunks, stream) = mpsc::channel::<anyhow::Result<Vec<u8>>>(1);
+    let send = builder(base_url, method, path, headers)?
+        .header(CONTENT_LENGTH, length)
+        .body(reqwest::Body::wrap_stream(stream))
+        .send();
+    let pump = async move {
+        let mut chunk = vec![0u8; 64 * 1024];
+        let mut sent = 0;
+        while sent < length {
+            let read = body.read(&mut chunk).await?;
+            if read == 0 {
+                bail!("the body ended after {sent} of {length} bytes");
+            }
+            let read = read.min((length - sent) as usize);
+            if chunks.send(Ok(chunk[..read].to_vec())).await.is_err() {
+                // The request failed and reports why.
+                return Ok(());
+            }
+            sent += read as u64;
+            on_sent(sent);
+        }
+        Ok(())
+    };
+    let (response, pumped) = futures::join!(send, pump);
+    pumped?;
+    read_response(response?).await
+}
+
+/// A response whose body is still to be read from the connection.
+pub(crate) struct StreamedResponse {
+    pub(crate) status: u16,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: ResponseBody,
+}
+
+/// The body of a [`StreamedResponse`].
+pub(crate) struct ResponseBody(Response);
+
+/// Sends a GET for `url` with `headers` and returns the response once its
+/// head arrived, whatever its status.
+pub(crate) async fn get_streaming(
+    url: &str,
+    headers: &[(String, String)],
+) -> anyhow::Result<StreamedResponse> {
+    let response = builder(url, "GET", "", headers)?.send().await?;
+    Ok(StreamedResponse {
+        status: response.status().as_u16(),
+        headers: header_list(response.headers()),
+        body: ResponseBody(response),
+    })
+}
+
+impl ResponseBody {
+    /// Writes the body to `sink`, calling `on_received` with the number of
+    /// bytes written so far. Fails once no bytes arrive for `read_timeout`.
+    pub(crate) async fn copy_to(
+        mut self,
+        sink: &mut (impl AsyncWrite + Unpin),
+        read_timeout: Duration,
+        mut on_received: impl FnMut(u64),
+    ) -> anyhow::Result<()> {
+        let mut received = 0;
+        loop {
+            let chunk = tokio::time::timeout(read_timeout, self.0.chunk())
+                .await
+                .map_err(|_| anyhow!("no data received for {read_timeout:?}"))??;
+            let Some(chunk) = chunk else {
+                break;
+            };
+            sink.write_all(&chunk).await?;
+            received += chunk.len() as u64;
+            on_received(received);
+        }
+        sink.flush().await?;
+        Ok(())
+    }
+
+    /// Reads the whole body, e.g. of an error response.
+    pub(crate) async fn read_all(self) -> anyhow::Result<Vec<u8>> {
+        Ok(self.0.bytes().await?.to_vec())
+    }
+}
+
+/// Returns the value of the first header named `wanted`, ignoring case.
+pub(crate) fn header<'a>(headers: &'a [(String, String)], wanted: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(wanted))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Starts a `method` request for `path` of `base_url` with `headers`.
+fn builder(
+    base_url: &str,
+    method: &str,
+    path: &str,
+    headers: &[(String, String)],
+) -> anyhow::Result<reqwest::RequestBuilder> {
+    if !base_url.starts_with("https://") && !base_url.starts_with("http://") {
+        bail!("unsupported deployment URL `{base_url}`");
+    }
+    let url = format!("{}{path}", base_url.trim_end_matches('/'));
+    let method = Method::from_bytes(method.as_bytes())
+        .with_context(|| format!("invalid method `{method}`"))?;
+    let mut builder = client()?.request(method, &url);
+    for (name, value) in headers {
+        builder = builder.header(name, value);
+    }
+    Ok(builder)
+}
+
+async fn read_response(response: Response) -> anyhow::Result<HttpResponse> {
+    let status = response.status().as_u16();
+    let headers = header_list(response.headers());
+    let body = response.bytes().await?.to_vec();
+    Ok(HttpResponse {
+        status,
+        headers,
+        body,
+    })
+}
+
+fn header_list(headers: &HeaderMap) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = String::from_utf8_lossy(value.as_bytes()).into_owned();
+            (name.as_str().to_owned(), value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::net::{TcpListener, TcpStream};
+
+    use super::*;
+
+    /// Reads a request whose body ends with `end`.
+    async fn read_request(stream: &mut TcpStream, end: &str) -> String {
+        let mut request = Vec::new();
+        let mut buffer = [0u8; 4096];
+        while !request.ends_with(end.as_bytes()) {
+            let read = stream.read(&mut buffer).await.unwrap();
+            assert!(read > 0, "the request ended early");
+            request.extend_from_slice(&buffer[..read]);
+        }
+        String::from_utf8_lossy(&request).into_owned()
+    }
+
+    #[tokio::test]
+    async fn posts_json_and_decodes_chunked_responses() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let request = read_request(&mut stream, "\r\n\r\n{}").await;
+            stream
+                .write_all(
+                    b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n\
+                      5\r\n{\"ts\"\r\n5\r\n: 42}\r\n0\r\n\r\n",
+                )
+                .await
+                .unwrap();
+            request
+        });
+
+        let response = post_json(&format!("http://{address}/"), "/api/x", Some("jwt"), "{}")
+            .await
+            .unwrap();
+        assert_eq!(response["ts"], 42);
+        let request = server.await.unwrap().to_ascii_lowercase();
+        assert!(request.starts_with("post /api/x http/1.1\r\n"));
+        assert!(request.contains("authorization: bearer jwt\r\n"));
+    }
+
+    #[tokio::test]
+    async fn redirects_are_followed_on_ipv6_hosts() {
+        let Ok(listener) = TcpListener::bind("[::1]:0").await else {
+            // No IPv6 loopback in this environment.
+            return;
+        };
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            let responses: [&[u8]; 2] = [
+                b"HTTP/1.1 302 Found\r\nLocation: /b\r\nConnection: close\r\n\
+                  Content-Length: 0\r\n\r\n",
+                b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nok",
+            ];
+            for response in responses {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                requests.push(read_request(&mut stream, "\r\n\r\n").await);
+                stream.write_all(response).await.unwrap();
+            }
+            requests
+        });
+
+        let response = request(&format!("http://{address}"), "GET", "/a", &[], b"")
+            .await
+            .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, b"ok");
+        let requests = server.await.unwrap();
+        assert!(requests[0].starts_with("GET /a HTTP/1.1\r\n"));
+        assert!(requests[1].starts_with("GET /b HTTP/1.1\r\n"));
+    }
+
+    #[tokio::test]
+    async fn bodies_stream_with_their_length() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let request = read_request(&mut stream, "abcdef").await;
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+                .await
+                .unwrap();
+            request
+        });
+
+        let mut sent = 0;
+        let body = &b"abcdefgh"[..];
+        let response = request_streaming(
+            &format!("http://{address}"),
+            "POST",
+            "/upload",
+            &[],
+            body,
+            6,
+            |bytes| sent = bytes,
+        )
+        .await
+        .unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(sent, 6);
+        let received = server.await.unwrap().to_ascii_lowercase();
+        assert!(received.contains("content-length: 6\r\n"));
+
+        assert!(request_streaming(
+            &format!("http://{address}"),
+            "POST",
+            "/upload",
+            &[],
+            &b"ab"[..],
+            6,
+            |_| {},
+        )
+        .await
+        .is_err());
+        assert!(request("ftp://example.com", "GET", "", &[], b"")
+            .await
+            .is_err());
+    }
+}
@@ -0,0 +1,133 @@
+//! Calls to the deployment's HTTP actions.
+//!
+//! HTTP actions are served from the deployment's site URL,
+//! `https://<deployment>.convex.site`, rather than through the WebSocket,
+//! so apps used to need a separate HTTP client and their own copy of the
+//! auth token. [`MobileConvexClient::http_action`] sends the request from
+//! Rust with the client's current token. The site URL is derived from a
+//! `*.convex.cloud` deployment URL; other deployments, e.g. self-hosted or
+//! local ones, set [`crate::options::ClientOptions::http_actions_url`].
+
+use std::collections::HashMap;
+
+use anyhow::bail;
+use flutter_rust_bridge::frb;
+
+use crate::{
+    audit::AuditStatus, http::request, spans::SpanOperation, ClientError, MobileConvexClient,
+};
+
+/// The response of an HTTP action.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct HttpActionResponse {
+    pub status: u16,
+    /// Response headers by lowercase name; repeated headers are joined with
+    /// `, `.
+    pub headers: HashMap<String, String>,
+    pub body: Vec<u8>,
+}
+
+/// Returns the site URL serving the HTTP actions of `deployment_url`.
+fn site_url(deployment_url: &str, configured: Option<&str>) -> anyhow::Result<String> {
+    if let Some(url) = configured {
+        return Ok(url.trim_end_matches('/').to_owned());
+    }
+    let url = deployment_url.trim_end_matches('/');
+    match url.strip_suffix(".convex.cloud") {
+        Some(deployment) => Ok(format!("{deployment}.convex.site")),
+        None => bail!("Set `httpActionsUrl` to call HTTP actions of `{deployment_url}`"),
+    }
+}
+
+/// Joins repeated headers and lowercases their names.
+fn merge_headers(headers: Vec<(String, String)>) -> HashMap<String, String> {
+    let mut merged: HashMap<String, String> = HashMap::new();
+    for (name, value) in headers {
+        merged
+            .entry(name.to_ascii_lowercase())
+            .and_modify(|joined| {
+                joined.push_str(", ");
+                joined.push_str(&value);
+            })
+            .or_insert(value);
+    }
+    merged
+}
+
+impl MobileConvexClient {
+    /// Calls the HTTP action at `path`, e.g. `/webhooks/stripe`, with
+    /// `method`, `headers` and `body`, as described in the
+    /// [module docs](crate::http_actions). The current auth token is sent
+    /// as a bearer token unless `headers` has an `Authorization` header.
+    /// Responses of any status are returned; only failing to get one is an
+    /// error.
+    #[frb]
+    pub async fn http_action(
+        &self,
+        path: String,
+        method: String,
+        headers: HashMap<String, String>,
+        body: Option<Vec<u8>>,
+    ) -> Result<HttpActionResponse, ClientError> {
+        self.ensure_open()?;
+        if !path.starts_with('/') {
+            return Err(anyhow::anyhow!("HTTP action paths start with `/`, got `{path}`").into());
+        }
+        let base_url = site_url(
+            self.failover.active_url(),
+            self.options.http_actions_url.as_deref(),
+        )?;
+        let mut headers: Vec<_> = headers.into_iter().collect();
+        let has_authorization = headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case("authorization"));
+        let token = self.auth_token.lock().clone();
+        if let (Some(token), false) = (token, has_authorization) {
+            headers.push(("Authorization".to_owned(), format!("Bearer {token}")));
+        }
+        let span = self.begin_span(SpanOperation::HttpAction, &path, None);
+        let has_traceparent = headers
+            .iter()
+            .any(|(name, _)| name.eq_ignore_ascii_case("traceparent"));
+        if let (Some(traceparent), false) = (span.traceparent(), has_traceparent) {
+            headers.push(("traceparent".to_owned(), traceparent));
+        }
+        let method = method.to_ascii_uppercase();
+        let body = body.unwrap_or_default();
+        let response = request(&base_url, &method, &path, &headers, &body).await;
+        span.finish(match &response {
+            Ok(response) if response.status >= 500 => AuditStatus::ServerError,
+            Ok(_) => AuditStatus::Success,
+            Err(_) => AuditStatus::TransportError,
+        });
+        let response = response?;
+        Ok(HttpActionResponse {
+            status: response.status,
+            headers: merge_headers(response.headers),
+            body: response.body,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn site_urls_and_headers() {
+        let site = site_url("https://happy-otter-123.convex.cloud/", None).unwrap();
+        assert_eq!(site, "https://happy-otter-123.convex.site");
+        assert!(site_url("http://127.0.0.1:3210", None).is_err());
+        let local = site_url("http://127.0.0.1:3210", Some("http://127.0.0.1:3211/")).unwrap();
+        assert_eq!(local, "http://127.0.0.1:3211");
+
+        let headers = merge_headers(vec![
+            ("Set-Cookie".into(), "a=1".into()),
+            ("Content-Type".into(), "text/plain".into()),
+            ("set-cookie".into(), "b=2".into()),
+        ]);
+        assert_eq!(headers["set-cookie"], "a=1, b=2");
+        assert_eq!(headers["content-type"], "text/plain");
+    }
+}
@@ -0,0 +1,134 @@
+//! Detection of several clients for the same deployment.
+//!
+//! Each [`MobileConvexClient`] opens its own WebSocket and keeps its own
+//! subscriptions, so apps that accidentally construct a client per screen
+//! see double the expected bandwidth. Live clients are tracked per process;
+//! creating one for a deployment that already has a client logs a warning
+//! naming where the others were created, and
+//! [`MobileConvexClient::duplicate_client_count`] reports how many there are.
+
+use std::{
+    backtrace::{Backtrace, BacktraceStatus},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use flutter_rust_bridge::frb;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::{presence::now_millis, MobileConvexClient};
+
+struct LiveClient {
+    id: u64,
+    deployment_url: String,
+    context: String,
+}
+
+static LIVE_CLIENTS: Mutex<Vec<LiveClient>> = Mutex::new(Vec::new());
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Describes where a client was created. The backtrace is only captured
+/// when `RUST_BACKTRACE` is set.
+fn creation_context(client_id: &str) -> String {
+    let thread = std::thread::current();
+    let mut context = format!(
+        "client id {client_id:?}, created at {} on thread {:?}",
+        now_millis(),
+        thread.name().unwrap_or("unnamed")
+    );
+    let backtrace = Backtrace::capture();
+    if backtrace.status() == BacktraceStatus::Captured {
+        context.push_str(&format!("\n{backtrace}"));
+    }
+    context
+}
+
+/// Registers a client while alive.
+pub(crate) struct ClientInstance {
+    id: u64,
+    deployment_url: String,
+}
+
+impl ClientInstance {
+    /// Registers a client of `deployment_url`, warning if others exist.
+    pub(crate) fn register(deployment_url: &str, client_id: &str) -> Self {
+        let id = NEXT_ID.fetch_add(1, Ordering::SeqCst);
+        let context = creation_context(client_id);
+        let mut live = LIVE_CLIENTS.lock();
+        let others: Vec<&str> = live
+            .iter()
+            .filter(|client| client.deployment_url == deployment_url)
+            .map(|client| client.context.as_str())
+            .collect();
+        if !others.is_empty() {
+            warn!(
+                "{} other clients for {deployment_url} are alive; each opens its own \
+                 connection. This one: {context}. Others: {}",
+                others.len(),
+                others.join("; ")
+            );
+        }
+        live.push(LiveClient {
+            id,
+            deployment_url: deployment_url.to_owned(),
+            context,
+        });
+        ClientInstance {
+            id,
+            deployment_url: deployment_url.to_owned(),
+        }
+    }
+
+    /// Returns the number of other live clients for the same deployment.
+    fn duplicates(&self) -> u32 {
+        LIVE_CLIENTS
+            .lock()
+            .iter()
+            .filter(|client| client.deployment_url == self.deployment_url && client.id != self.id)
+            .count() as u32
+    }
+
+    /// Stops counting this client, e.g. once closed.
+    pub(crate) fn unregister(&self) {
+        LIVE_CLIENTS.lock().retain(|client| client.id != self.id);
+    }
+}
+
+impl Drop for ClientInstance {
+    fn drop(&mut self) {
+        self.unregister();
+    }
+}
+
+impl MobileConvexClient {
+    /// Returns how many other open clients in this process use the same
+    /// deployment. Anything above zero usually means clients are created
+    /// where a shared one was intended.
+    #[frb(sync)]
+    pub fn duplicate_client_count(&self) -> u32 {
+        self.instance.duplicates()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clients_of_the_same_deployment_are_counted_until_closed() {
+        let url = "https://duplicates.convex.cloud";
+        let first = ClientInstance::register(url, "first");
+        let other = ClientInstance::register("https://other.convex.cloud", "other");
+        assert_eq!(first.duplicates(), 0);
+
+        let second = ClientInstance::register(url, "second");
+        assert_eq!(first.duplicates(), 1);
+        assert_eq!(second.duplicates(), 1);
+
+        second.unregister();
+        assert_eq!(first.duplicates(), 0);
+        drop(second);
+        drop(first);
+        assert_eq!(other.duplicates(), 0);
+    }
+}
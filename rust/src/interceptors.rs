@@ -0,0 +1,361 @@
+//! Interception of outgoing calls and their results.
+//!
+//! Analytics, scrubbing of arguments or function rewrites behind feature
+//! flags used to require forking the crate. Interceptors added with
+//! [`MobileConvexClient::add_interceptor`] from Dart, or with
+//! [`MobileConvexClient::add_rust_interceptor`] from Rust plugins, see every
+//! query, mutation and action as an [`InterceptedCall`] before it is sent.
+//! They can rewrite its function name and arguments, attach metadata or
+//! veto it. They then see its result as a [`CallOutcome`] and can replace
+//! it, e.g. to transform a value or log an error.
+//!
+//! Calls pass the interceptors in the order they were added and results
+//! pass them in reverse order. Metadata is handed on to the result
+//! interceptors and never sent. Arguments are seen after field encryption,
+//! and subscriptions are not intercepted.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use anyhow::{anyhow, bail};
+use convex::{ConvexError, FunctionResult, Value};
+use flutter_rust_bridge::{frb, DartFnFuture};
+use futures::future::{self, BoxFuture};
+use parking_lot::RwLock;
+
+use crate::{
+    value::{json_to_value, value_to_json_string},
+    ClientError, MobileConvexClient,
+};
+
+/// The kind of an intercepted call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum CallKind {
+    Query,
+    Mutation,
+    Action,
+}
+
+/// A call about to be sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct InterceptedCall {
+    /// Informational; changing it has no effect.
+    pub kind: CallKind,
+    /// Name of the function, e.g. `messages:send`.
+    pub name: String,
+    /// Arguments as a JSON object, in Convex's JSON encoding.
+    pub args: String,
+    /// Attached by interceptors for the result interceptors.
+    pub metadata: HashMap<String, String>,
+}
+
+/// What an interceptor decided about a call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum InterceptDecision {
+    /// Sends `call`, possibly changed, on to the next interceptor.
+    Proceed { call: InterceptedCall },
+    /// Fails the call with `reason` without sending it.
+    Veto { reason: String },
+}
+
+/// The result of a call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum CallOutcome {
+    /// The function returned `json`, in Convex's JSON encoding.
+    Value { json: String },
+    /// The function threw a `ConvexError` carrying `data`.
+    ConvexError { message: String, data: String },
+    /// The function failed unexpectedly.
+    ServerError { message: String },
+    /// The call did not reach the backend or its result was lost.
+    TransportError { message: String },
+}
+
+impl CallOutcome {
+    fn of(result: anyhow::Result<FunctionResult>) -> Self {
+        match result {
+            Ok(FunctionResult::Value(value)) => CallOutcome::Value {
+                json: value_to_json_string(value),
+            },
+            Ok(FunctionResult::ConvexError(error)) => CallOutcome::ConvexError {
+                message: error.message,
+                data: value_to_json_string(error.data),
+            },
+            Ok(FunctionResult::ErrorMessage(message)) => CallOutcome::ServerError { message },
+            Err(error) => CallOutcome::TransportError {
+                message: format!("{error:#}"),
+            },
+        }
+    }
+
+    fn into_result(self) -> anyhow::Result<FunctionResult> {
+        Ok(match self {
+            CallOutcome::Value { json } => FunctionResult::Value(parse_json(&json)?),
+            CallOutcome::ConvexError { message, data } => {
+                FunctionResult::ConvexError(ConvexError {
+                    message,
+                    data: parse_json(&data)?,
+                })
+            }
+            CallOutcome::ServerError { message } => FunctionResult::ErrorMessage(message),
+            CallOutcome::TransportError { message } => return Err(anyhow!(message)),
+        })
+    }
+}
+
+fn parse_json(json: &str) -> anyhow::Result<Value> {
+    json_to_value(serde_json::from_str(json)?)
+}
+
+fn parse_args(json: &str) -> anyhow::Result<BTreeMap<String, Value>> {
+    match parse_json(json)? {
+        Value::Object(args) => Ok(args),
+        _ => bail!("Intercepted arguments must be a JSON object, got {json}"),
+    }
+}
+
+/// Intercepts calls and their results, as described in the
+/// [module docs](crate::interceptors). Both methods pass everything on
+/// unchanged by default.
+#[frb(ignore)]
+pub trait CallInterceptor: Send + Sync {
+    #[frb(ignore)]
+    fn intercept_call(&self, call: InterceptedCall) -> BoxFuture<'static, InterceptDecision> {
+        Box::pin(future::ready(InterceptDecision::Proceed { call }))
+    }
+
+    #[frb(ignore)]
+    fn intercept_result(
+        &self,
+        _call: InterceptedCall,
+        outcome: CallOutcome,
+    ) -> BoxFuture<'static, CallOutcome> {
+        Box::pin(future::ready(outcome))
+    }
+}
+
+type OnCall = dyn Fn(InterceptedCall) -> DartFnFuture<InterceptDecision> + Send + Sync;
+type OnResult = dyn Fn(InterceptedCall, CallOutcome) -> DartFnFuture<CallOutcome> + Send + Sync;
+
+/// An interceptor implemented by Dart callbacks.
+#[frb(ignore)]
+struct DartInterceptor {
+    on_call: Box<OnCall>,
+    on_result: Box<OnResult>,
+}
+
+impl CallInterceptor for DartInterceptor {
+    fn intercept_call(&self, call: InterceptedCall) -> BoxFuture<'static, InterceptDecision> {
+        (self.on_call)(call)
+    }
+
+    fn intercept_result(
+        &self,
+        call: InterceptedCall,
+        outcome: CallOutcome,
+    ) -> BoxFuture<'static, CallOutcome> {
+        (self.on_result)(call, outcome)
+    }
+}
+
+/// The interceptors of a client, in the order they were added.
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct Interceptors {
+    next_id: AtomicU64,
+    added: RwLock<Vec<(u64, Arc<dyn CallInterceptor>)>>,
+}
+
+impl Interceptors {
+    fn add(&self, interceptor: Arc<dyn CallInterceptor>) -> u64 {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        self.added.write().push((id, interceptor));
+        id
+    }
+
+    fn remove(&self, id: u64) -> bool {
+        let mut added = self.added.write();
+        let before = added.len();
+        added.retain(|(added_id, _)| *added_id != id);
+        added.len() != before
+    }
+
+    /// Passes the call of `name` with `args` through the interceptors,
+    /// sends it with `send` unless vetoed and passes the result back
+    /// through them.
+    pub(crate) async fn run<F, Fut>(
+        &self,
+        kind: CallKind,
+        name: String,
+        args: BTreeMap<String, Value>,
+        send: F,
+    ) -> anyhow::Result<FunctionResult>
+    where
+        F: FnOnce(String, BTreeMap<String, Value>) -> Fut,
+        Fut: Future<Output = anyhow::Result<FunctionResult>>,
+    {
+        let interceptors: Vec<_> = self
+            .added
+            .read()
+            .iter()
+            .map(|(_, interceptor)| interceptor.clone())
+            .collect();
+        if interceptors.is_empty() {
+            return send(name, args).await;
+        }
+        let mut call = InterceptedCall {
+            kind,
+            name,
+            args: value_to_json_string(Value::Object(args)),
+            metadata: HashMap::new(),
+        };
+        for interceptor in &interceptors {
+            match interceptor.intercept_call(call.clone()).await {
+                InterceptDecision::Proceed { call: next } => {
+                    call = InterceptedCall { kind, ..next }
+                }
+                InterceptDecision::Veto { reason } => {
+                    bail!("An interceptor vetoed the call of {}: {reason}", call.name)
+                }
+            }
+        }
+        let result = match parse_args(&call.args) {
+            Ok(args) => send(call.name.clone(), args).await,
+            Err(error) => Err(error),
+        };
+        let mut outcome = CallOutcome::of(result);
+        for interceptor in interceptors.iter().rev() {
+            outcome = interceptor.intercept_result(call.clone(), outcome).await;
+        }
+        outcome.into_result()
+    }
+}
+
+impl MobileConvexClient {
+    /// Adds an interceptor of queries, mutations and actions, as described
+    /// in the [module docs](crate::interceptors). `on_call` decides about
+    /// each call and `on_result` returns its result, possibly replaced.
+    /// Returns an ID for [`MobileConvexClient::remove_interceptor`].
+    #[frb]
+    pub async fn add_interceptor(
+        &self,
+        on_call: impl Fn(InterceptedCall) -> DartFnFuture<InterceptDecision> + Send + Sync + 'static,
+        on_result: impl Fn(InterceptedCall, CallOutcome) -> DartFnFuture<CallOutcome>
+            + Send
+            + Sync
+            + 'static,
+    ) -> Result<u64, ClientError> {
+        Ok(self.interceptors.add(Arc::new(DartInterceptor {
+            on_call: Box::new(on_call),
+            on_result: Box::new(on_result),
+        })))
+    }
+
+    /// Like [`MobileConvexClient::add_interceptor`], for interceptors
+    /// implemented in Rust.
+    #[frb(ignore)]
+    pub fn add_rust_interceptor(&self, interceptor: Arc<dyn CallInterceptor>) -> u64 {
+        self.interceptors.add(interceptor)
+    }
+
+    /// Removes the interceptor with `id`, returning whether it was added.
+    /// Calls already passing it are unaffected.
+    #[frb(sync)]
+    pub fn remove_interceptor(&self, id: u64) -> bool {
+        self.interceptors.remove(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parking_lot::Mutex;
+
+    use super::*;
+
+    /// Rewrites `flags:old` to `flags:new`, tags calls and uppercases
+    /// string results.
+    struct Rewrite;
+
+    impl CallInterceptor for Rewrite {
+        fn intercept_call(
+            &self,
+            mut call: InterceptedCall,
+        ) -> BoxFuture<'static, InterceptDecision> {
+            if call.name == "flags:old" {
+                call.name = "flags:new".into();
+            }
+            call.metadata.insert("tag".into(), "1".into());
+            Box::pin(future::ready(InterceptDecision::Proceed { call }))
+        }
+
+        fn intercept_result(
+            &self,
+            call: InterceptedCall,
+            outcome: CallOutcome,
+        ) -> BoxFuture<'static, CallOutcome> {
+            let outcome = match outcome {
+                CallOutcome::Value { json } if call.metadata["tag"] == "1" => CallOutcome::Value {
+                    json: json.to_uppercase(),
+                },
+                other => other,
+            };
+            Box::pin(future::ready(outcome))
+        }
+    }
+
+    struct VetoSecrets;
+
+    impl CallInterceptor for VetoSecrets {
+        fn intercept_call(&self, call: InterceptedCall) -> BoxFuture<'static, InterceptDecision> {
+            Box::pin(future::ready(if call.args.contains("secret") {
+                InterceptDecision::Veto {
+                    reason: "secrets stay on the device".into(),
+                }
+            } else {
+                InterceptDecision::Proceed { call }
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn calls_are_rewritten_vetoed_and_results_transformed() {
+        let interceptors = Interceptors::default();
+        interceptors.add(Arc::new(Rewrite));
+        let veto = interceptors.add(Arc::new(VetoSecrets));
+        let sent = Mutex::new(Vec::new());
+        let send = |name: String, args: BTreeMap<String, Value>| {
+            sent.lock().push((name, args));
+            future::ready(Ok(FunctionResult::Value(Value::String("on".into()))))
+        };
+        let args = BTreeMap::from([("user".to_owned(), Value::String("ada".into()))]);
+
+        let result = interceptors
+            .run(CallKind::Query, "flags:old".into(), args.clone(), send)
+            .await;
+        assert!(matches!(result, Ok(FunctionResult::Value(Value::String(value))) if value == "ON"));
+        assert_eq!(sent.lock()[0], ("flags:new".to_owned(), args));
+
+        let secret = BTreeMap::from([("token".to_owned(), Value::String("secret".into()))]);
+        let vetoed = interceptors
+            .run(CallKind::Mutation, "auth:store".into(), secret, send)
+            .await;
+        assert!(vetoed
+            .unwrap_err()
+            .to_string()
+            .contains("secrets stay on the device"));
+        assert_eq!(sent.lock().len(), 1);
+
+        assert!(interceptors.remove(veto));
+        assert!(!interceptors.remove(veto));
+    }
+}
@@ -0,0 +1,141 @@
+//! Actions reporting progress through a status document.
+//!
+//! Long-running actions commonly report progress by writing a status
+//! document keyed by a job id, which the client watches with a query. The
+//! helper here packages that pattern: the client generates the job id, passes
+//! it to both the action and the status query, forwards status updates while
+//! the action runs and drops the watch once the action has finished.
+//!
+//! The status query should return `null` until the status document exists;
+//! `null` results are not reported as progress.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use anyhow::bail;
+use convex::Value;
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::debug;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use crate::{
+    resubscribe::SubscriptionPriority,
+    subscription::{EventForwarder, SubscriptionEvent},
+    ClientError, MobileConvexClient,
+};
+
+/// How an action and its status query receive the job id.
+#[derive(Debug, Clone)]
+#[frb]
+pub struct JobOptions {
+    /// Argument holding the job id, passed to the action and the status query.
+    pub job_id_arg: String,
+    /// Query returning the status document of a job.
+    pub status_query: String,
+}
+
+/// A status update of a running job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct JobProgress {
+    pub job_id: String,
+    /// JSON-serialized status document.
+    pub status: String,
+}
+
+/// Adds the job id to the action arguments.
+fn with_job_id(
+    mut args: BTreeMap<String, Value>,
+    job_id_arg: &str,
+    job_id: &str,
+) -> anyhow::Result<BTreeMap<String, Value>> {
+    if args.contains_key(job_id_arg) {
+        bail!("Argument `{job_id_arg}` is reserved for the job id");
+    }
+    args.insert(job_id_arg.to_owned(), Value::String(job_id.to_owned()));
+    Ok(args)
+}
+
+impl MobileConvexClient {
+    /// Runs an action while reporting the status document of its job through
+    /// `on_progress`, and returns the action's result.
+    ///
+    /// The status watch is removed as soon as the action completes or fails.
+    #[frb]
+    pub async fn action_with_progress(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        options: JobOptions,
+        on_progress: impl Fn(JobProgress) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<String, ClientError> {
+        let job_id = Uuid::new_v4().to_string();
+        let args = with_job_id(self.parse_args(args)?, &options.job_id_arg, &job_id)?;
+        let status_args =
+            BTreeMap::from([(options.job_id_arg.clone(), Value::String(job_id.clone()))]);
+
+        let (statuses, mut received) = mpsc::unbounded_channel();
+        // Like other subscriptions, the watch is paused with the client.
+        // Dropping the handle when this call returns ends it.
+        let _watch = self
+            .internal_subscribe(
+                options.status_query,
+                status_args,
+                Arc::new(EventForwarder(statuses)),
+                SubscriptionPriority::Normal,
+            )
+            .await?;
+        let watched_job_id = job_id.clone();
+        self.rt.spawn(async move {
+            // Ends once the watch is dropped.
+            while let Some(event) = received.recv().await {
+                match event {
+                    SubscriptionEvent::Update { value } if value == "null" => {}
+                    SubscriptionEvent::Update { value } => {
+                        let progress = JobProgress {
+                            job_id: watched_job_id.clone(),
+                            status: value,
+                        };
+                        on_progress(progress).await;
+                    }
+                    SubscriptionEvent::Closed { .. } => break,
+                    other => debug!("Status of job {watched_job_id} failed: {other:?}"),
+                }
+            }
+            debug!("Stopped watching job {watched_job_id}");
+        });
+
+        debug!("Running action {name} as job {job_id}");
+        let result = self.internal_action(name, args).await?;
+        self.format_result(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::btreemap;
+
+    use super::*;
+
+    #[test]
+    fn job_id_is_added_to_the_arguments() {
+        let args = btreemap! {"file".to_string() => Value::String("a.csv".into())};
+        assert_eq!(
+            with_job_id(args, "jobId", "123").unwrap(),
+            btreemap! {
+                "file".to_string() => Value::String("a.csv".into()),
+                "jobId".to_string() => Value::String("123".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn job_id_argument_must_not_be_taken() {
+        let args = btreemap! {"jobId".to_string() => Value::Null};
+        let err = with_job_id(args, "jobId", "123").unwrap_err();
+        assert!(err.to_string().contains("`jobId`"));
+    }
+}
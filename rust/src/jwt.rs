@@ -0,0 +1,99 @@
+//! JWT helpers used by the token refresh loop.
+
+use base64::Engine;
+use serde::Deserialize;
+
+/// JWT claims structure for extracting expiration time.
+#[derive(Deserialize)]
+struct JwtClaims {
+    exp: u64,
+}
+
+/// JWT claims structure for extracting the subject (user identity).
+#[derive(Deserialize)]
+struct JwtSubject {
+    sub: String,
+}
+
+/// Decodes the payload (second part) of a JWT token using URL-safe base64.
+fn decode_jwt_payload(token: &str) -> Option<Vec<u8>> {
+    // JWT format: header.payload.signature
+    let parts: Vec<&str> = token.split('.').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(parts[1])
+        .ok()
+}
+
+/// Decodes a JWT token and extracts the expiration timestamp.
+/// Returns None if the token is malformed or doesn't contain an exp claim.
+pub(crate) fn decode_jwt_expiry(token: &str) -> Option<u64> {
+    let payload = decode_jwt_payload(token)?;
+    let claims: JwtClaims = serde_json::from_slice(&payload).ok()?;
+    Some(claims.exp)
+}
+
+/// Decodes a JWT token and extracts the `sub` claim.
+/// Returns None if the token is malformed or doesn't contain a string subject.
+pub(crate) fn decode_jwt_subject(token: &str) -> Option<String> {
+    let payload = decode_jwt_payload(token)?;
+    let claims: JwtSubject = serde_json::from_slice(&payload).ok()?;
+    Some(claims.sub)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token_with_payload(payload: &str) -> String {
+        let encoded = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(payload);
+        format!("eyJhbGciOiJIUzI1NiJ9.{encoded}.signature")
+    }
+
+    #[test]
+    fn decodes_exp_claim() {
+        let token = token_with_payload(r#"{"sub":"user","exp":1700000000}"#);
+        assert_eq!(decode_jwt_expiry(&token), Some(1700000000));
+    }
+
+    #[test]
+    fn missing_exp_claim_is_none() {
+        let token = token_with_payload(r#"{"sub":"user"}"#);
+        assert_eq!(decode_jwt_expiry(&token), None);
+    }
+
+    #[test]
+    fn non_numeric_exp_is_none() {
+        let token = token_with_payload(r#"{"exp":"tomorrow"}"#);
+        assert_eq!(decode_jwt_expiry(&token), None);
+    }
+
+    #[test]
+    fn wrong_segment_count_is_none() {
+        assert_eq!(decode_jwt_expiry("only.two"), None);
+        assert_eq!(decode_jwt_expiry("a.b.c.d"), None);
+        assert_eq!(decode_jwt_expiry(""), None);
+    }
+
+    #[test]
+    fn invalid_base64_is_none() {
+        assert_eq!(decode_jwt_expiry("header.!!!not-base64!!!.sig"), None);
+    }
+
+    #[test]
+    fn padded_payload_is_rejected() {
+        let encoded = base64::engine::general_purpose::URL_SAFE.encode(r#"{"exp":10}"#);
+        assert!(encoded.ends_with('='));
+        assert_eq!(decode_jwt_expiry(&format!("h.{encoded}.s")), None);
+    }
+
+    #[test]
+    fn decodes_subject_claim() {
+        let token = token_with_payload(r#"{"sub":"user|123","exp":1}"#);
+        assert_eq!(decode_jwt_subject(&token), Some("user|123".to_string()));
+        let token = token_with_payload(r#"{"exp":1}"#);
+        assert_eq!(decode_jwt_subject(&token), None);
+    }
+}
@@ -0,0 +1,279 @@
+//! Subscriptions replaced by key.
+//!
+//! Screens whose filters change re-subscribe with new arguments. Subscribing
+//! again with the same key through [`MobileConvexClient::subscribe_keyed`]
+//! cancels the previous subscription, but updates of the old subscription
+//! may already be in flight. Every subscription of a key is therefore tagged
+//! with a generation, and deliveries are dropped in Rust unless their
+//! generation is still the key's current one, so the UI never briefly shows
+//! data for the old arguments.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::debug;
+use parking_lot::Mutex;
+
+use crate::{
+    ordered::OrderedCalls, resubscribe::SubscriptionPriority, CallbackSubscriberDartFn,
+    ClientError, MobileConvexClient, QuerySubscriber, SubscriptionHandle,
+};
+
+/// The current generation of a key and the subscription serving it.
+#[derive(Default)]
+#[frb(ignore)]
+struct KeyedEntry {
+    generation: Arc<AtomicU64>,
+    handle: Option<SubscriptionHandle>,
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct KeyedSubscriptions {
+    entries: Mutex<HashMap<String, KeyedEntry>>,
+}
+
+impl KeyedSubscriptions {
+    /// Starts a new generation of `key`, superseding all earlier ones.
+    fn next_generation(&self, key: &str) -> (Arc<AtomicU64>, u64) {
+        let mut entries = self.entries.lock();
+        let entry = entries.entry(key.to_owned()).or_default();
+        let generation = entry.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        (entry.generation.clone(), generation)
+    }
+
+    /// Records `handle` as serving `key` if `generation` is still current,
+    /// cancelling the subscription it replaces. A superseded handle is
+    /// cancelled instead.
+    fn install(&self, key: &str, generation: u64, handle: &SubscriptionHandle) {
+        let replaced = {
+            let mut entries = self.entries.lock();
+            let entry = entries.entry(key.to_owned()).or_default();
+            if entry.generation.load(Ordering::SeqCst) != generation {
+                Some(handle.share())
+            } else {
+                entry.handle.replace(handle.share())
+            }
+        };
+        if let Some(replaced) = replaced {
+            replaced.stop();
+        }
+    }
+
+    /// Cancels the subscription `generation` was meant to replace, after
+    /// subscribing failed. Its deliveries are already dropped, so it would
+    /// otherwise keep running unseen.
+    fn abandon(&self, key: &str, generation: u64) {
+        let replaced = {
+            let mut entries = self.entries.lock();
+            match entries.get_mut(key) {
+                Some(entry) if entry.generation.load(Ordering::SeqCst) == generation => {
+                    entry.handle.take()
+                }
+                _ => None,
+            }
+        };
+        if let Some(replaced) = replaced {
+            replaced.stop();
+        }
+    }
+
+    /// Cancels the subscription of `key` and drops its pending deliveries.
+    fn remove(&self, key: &str) {
+        let entry = self.entries.lock().remove(key);
+        if let Some(entry) = entry {
+            entry.generation.fetch_add(1, Ordering::SeqCst);
+            if let Some(handle) = entry.handle {
+                handle.stop();
+            }
+        }
+    }
+}
+
+/// Forwards deliveries only while its generation is current.
+#[frb(ignore)]
+struct GenerationSubscriber {
+    inner: Arc<dyn QuerySubscriber>,
+    current: Arc<AtomicU64>,
+    generation: u64,
+}
+
+impl GenerationSubscriber {
+    fn is_current(&self) -> bool {
+        let current = self.current.load(Ordering::SeqCst) == self.generation;
+        if !current {
+            debug!(
+                "Dropping delivery of superseded generation {}",
+                self.generation
+            );
+        }
+        current
+    }
+}
+
+impl QuerySubscriber for GenerationSubscriber {
+    fn on_update(&self, value: String) {
+        if self.is_current() {
+            self.inner.on_update(value);
+        }
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        if self.is_current() {
+            self.inner.on_error(message, value);
+        }
+    }
+
+    fn on_done(&self) {
+        if self.is_current() {
+            self.inner.on_done();
+        }
+    }
+}
+
+impl MobileConvexClient {
+    /// Subscribes to a query under `key`, replacing the subscription
+    /// previously made under the same key.
+    ///
+    /// Updates of replaced subscriptions are never delivered, even when they
+    /// were already in flight. If subscribing fails, the replaced
+    /// subscription is cancelled all the same.
+    #[frb]
+    pub async fn subscribe_keyed(
+        &self,
+        key: String,
+        name: String,
+        args: HashMap<String, String>,
+        on_update: impl Fn(String) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        let args = self.parse_args(args)?;
+        let (current, generation) = self.keyed.next_generation(&key);
+        let subscriber = Arc::new(GenerationSubscriber {
+            inner: Arc::new(CallbackSubscriberDartFn {
+                on_update: Box::new(on_update),
+                on_error: Box::new(on_error),
+                on_done: None,
+                calls: OrderedCalls::default(),
+            }),
+            current,
+            generation,
+        });
+        let handle = match self
+            .internal_subscribe(name, args, subscriber, SubscriptionPriority::Normal)
+            .await
+        {
+            Ok(handle) => handle,
+            Err(e) => {
+                self.keyed.abandon(&key, generation);
+                return Err(e.into());
+            }
+        };
+        self.keyed.install(&key, generation, &handle);
+        Ok(handle)
+    }
+
+    /// Cancels the subscription made under `key`, if any.
+    #[frb(sync)]
+    pub fn unsubscribe_keyed(&self, key: String) {
+        self.keyed.remove(&key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::oneshot;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        updates: Mutex<Vec<String>>,
+    }
+
+    impl QuerySubscriber for Recorder {
+        fn on_update(&self, value: String) {
+            self.updates.lock().push(value);
+        }
+
+        fn on_error(&self, message: String, _value: Option<String>) {
+            self.updates.lock().push(format!("error: {message}"));
+        }
+    }
+
+    fn subscriber(
+        keyed: &KeyedSubscriptions,
+        recorder: &Arc<Recorder>,
+    ) -> (GenerationSubscriber, u64) {
+        let (current, generation) = keyed.next_generation("list");
+        let subscriber = GenerationSubscriber {
+            inner: recorder.clone(),
+            current,
+            generation,
+        };
+        (subscriber, generation)
+    }
+
+    #[test]
+    fn superseded_generations_are_dropped() {
+        let keyed = KeyedSubscriptions::default();
+        let recorder = Arc::new(Recorder::default());
+        let (old, _) = subscriber(&keyed, &recorder);
+        old.on_update("old filter".into());
+        let (new, _) = subscriber(&keyed, &recorder);
+        old.on_update("late".into());
+        old.on_error("late".into(), None);
+        new.on_update("new filter".into());
+        assert_eq!(*recorder.updates.lock(), ["old filter", "new filter"]);
+
+        keyed.remove("list");
+        new.on_update("after removal".into());
+        assert_eq!(recorder.updates.lock().len(), 2);
+    }
+
+    #[test]
+    fn installing_cancels_the_replaced_subscription() {
+        let keyed = KeyedSubscriptions::default();
+        let recorder = Arc::new(Recorder::default());
+        let (first_tx, mut first_rx) = oneshot::channel();
+        let (second_tx, mut second_rx) = oneshot::channel();
+        let (_, first) = subscriber(&keyed, &recorder);
+        let (_, second) = subscriber(&keyed, &recorder);
+
+        // The newer subscription finishes first; the older one must not win.
+        let (first_handle, second_handle) = (
+            SubscriptionHandle::new(first_tx),
+            SubscriptionHandle::new(second_tx),
+        );
+        keyed.install("list", second, &second_handle);
+        keyed.install("list", first, &first_handle);
+        assert_eq!(first_rx.try_recv(), Ok(Some(())));
+        assert_eq!(second_rx.try_recv(), Ok(None));
+
+        keyed.remove("list");
+        assert_eq!(second_rx.try_recv(), Ok(Some(())));
+    }
+
+    #[test]
+    fn a_failed_replacement_cancels_the_replaced_subscription() {
+        let keyed = KeyedSubscriptions::default();
+        let recorder = Arc::new(Recorder::default());
+        let (cancel_tx, mut cancel_rx) = oneshot::channel();
+        let (_, first) = subscriber(&keyed, &recorder);
+        let handle = SubscriptionHandle::new(cancel_tx);
+        keyed.install("list", first, &handle);
+
+        let (_, failed) = subscriber(&keyed, &recorder);
+        let (_, newer) = subscriber(&keyed, &recorder);
+        keyed.abandon("list", failed);
+        assert_eq!(cancel_rx.try_recv(), Ok(None));
+        keyed.abandon("list", newer);
+        assert_eq!(cancel_rx.try_recv(), Ok(Some(())));
+    }
+}
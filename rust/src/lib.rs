@@ -1,16 +1,100 @@
+pub mod action_cache;
+mod args;
+pub mod audit;
+pub mod auth_changes;
+pub mod auth_refresh;
+mod backoff;
+pub mod backpressure;
+pub mod batch_query;
+mod batching;
+pub mod budget;
+pub mod call_metrics;
+pub mod cancellation;
+pub mod chunked;
+pub mod codecs;
+pub mod commit_token;
+pub mod config;
+pub mod connection;
+pub mod convex_value;
+pub mod deferred;
+pub mod derived;
+pub mod distinct;
+pub mod encryption;
+pub mod errors;
+pub mod events;
+pub mod failover;
+pub mod faults;
+pub mod file_storage;
 mod frb_generated;
+#[cfg(fuzzing)]
+#[doc(hidden)]
+pub mod fuzzing;
+pub mod hints;
+mod http;
+pub mod http_actions;
+pub mod instances;
+pub mod interceptors;
+pub mod jobs;
+mod jwt;
+pub mod keyed;
+pub mod lifecycle;
+pub mod logging;
+pub mod list_diff;
+pub mod logout;
+pub mod memory;
+pub mod metrics;
+mod multiplex;
+pub mod mutation_status;
+pub mod optimistic;
+pub mod options;
+mod ordered;
+pub mod outbox;
+pub mod pagination;
+pub mod panics;
+pub mod patches;
+pub mod persisted_results;
+pub mod placeholder;
+pub mod presence;
+pub mod projection;
+pub mod pressure;
+pub mod preview;
+#[cfg(debug_assertions)]
+mod prometheus;
+pub mod quality;
+pub mod query_cache;
+pub mod registry;
+pub mod replication;
+pub mod request_ids;
+pub mod resubscribe;
+pub mod retry;
+mod result;
+pub mod sampling;
+pub mod schema_check;
+pub mod sequence;
+pub mod sharding;
+pub mod spans;
+pub mod storage;
+pub mod storage_encryption;
+#[cfg(feature = "stub-server")]
+pub mod stub_server;
+pub mod subscription;
+pub mod subscription_stream;
+pub mod supervisor;
+pub mod timeout;
+mod value;
+pub mod write_barrier;
+
 use std::{
     collections::{BTreeMap, HashMap},
+    future::Future,
+    ops::Deref,
     sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
+        atomic::{AtomicBool, AtomicI64, AtomicU64, AtomicU8, Ordering},
+        Arc, Weak,
     },
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
-#[cfg(debug_assertions)]
-use android_logger::Config;
-use async_once_cell::OnceCell;
 use convex::{
     ConvexClient,
     ConvexClientBuilder,
@@ -24,79 +108,134 @@ use futures::{
     pin_mut, select_biased, FutureExt, StreamExt,
 };
 use log::debug; // Logging for debugging purposes
-#[cfg(debug_assertions)]
-use log::LevelFilter;
 use parking_lot::Mutex;
-use base64::Engine;
-use serde::Deserialize;
+
+pub use crate::subscription::{
+    SubscribeOptions, SubscriptionCloseReason, SubscriptionEvent, SubscriptionState,
+};
+use crate::{
+    action_cache::ActionCache,
+    args::parse_json_args,
+    audit::{AuditLog, AuditOperation, AuditStatus, PendingAudit},
+    auth_changes::{is_expired, next_reconnect, AuthChangeReason, AuthChanges},
+    auth_refresh::AuthRefreshConfig,
+    backoff::exponential_backoff,
+    batching::SubscribeBatcher,
+    budget::BudgetGuard,
+    call_metrics::CallMetrics,
+    cancellation::AbortOnDrop,
+    connection::{ConnectionCloseReason, ConnectionManager, SocketStates},
+    convex_value::{convex_args, ConvexValue},
+    deferred::DeferredMutations,
+    failover::{active_client, FailoverState, FailoverTask},
+    encryption::FieldEncryption,
+    errors::{with_code, ErrorCode},
+    events::{next_auth_rejection, skip_pending, EventBus, EventSubscriber},
+    faults::FaultInjector,
+    hints::UiHints,
+    instances::ClientInstance,
+    interceptors::{CallKind, Interceptors},
+    jwt::decode_jwt_subject,
+    keyed::KeyedSubscriptions,
+    lifecycle::Lifecycle,
+    logout::AuthSession,
+    memory::{memory_events, MemoryReleasedEvent},
+    metrics::RuntimeMonitor,
+    multiplex::SharedSubscriptions,
+    mutation_status::{mutation_events, MutationEvents},
+    optimistic::OptimisticStore,
+    options::ClientOptions,
+    ordered::OrderedCalls,
+    outbox::Outbox,
+    panics::{catch_panics, catch_panics_sync},
+    persisted_results::{spawn_flush_loop, ResultPersistence},
+    placeholder::LastValues,
+    presence::now_millis,
+    pressure::{throttle_subscriber, UiPressure},
+    quality::QualityTracker,
+    query_cache::QueryCache,
+    registry::{CountingSubscriber, SubscriptionRegistry},
+    replication::Replica,
+    sharding::{subscribe_sharded, ShardRegistry},
+    spans::{SpanOperation, TraceExporter},
+    resubscribe::{ManagedSubscription, ResubscribeScheduler, SubscriptionPriority},
+    sampling::{TelemetryClass, TelemetrySampler},
+    schema_check::SchemaCheck,
+    storage::ScopedStorage,
+    storage_encryption::StorageCipher,
+    subscription::{EventForwarder, SubscriptionStateMachine},
+    supervisor::DartSupervisor,
+    timeout::with_timeout,
+    value::value_to_json_string,
+    write_barrier::WriteBarrier,
+};
 
 // Custom error type for Convex client operations, exposed to Dart.
 #[derive(Debug, thiserror::Error)]
 #[frb]
 pub enum ClientError {
-    /// An internal error within the mobile Convex client.
+    /// An internal error within the mobile Convex client, or a failure it
+    /// detected on the way to the backend, such as a lost connection.
     #[error("InternalError: {msg}")]
-    InternalError { msg: String },
-    /// An application-specific error from a remote Convex backend function.
-    #[error("ConvexError: {data}")]
-    ConvexError { data: String },
+    InternalError {
+        msg: String,
+        code: errors::ErrorCode,
+        retryable: bool,
+    },
+    /// An application-specific error from a remote Convex backend function,
+    /// with the data it was thrown with.
+    #[error("ConvexError: {message}")]
+    ConvexError {
+        message: String,
+        data: ConvexValue,
+        code: errors::ErrorCode,
+        retryable: bool,
+    },
     /// An unexpected server-side error from a remote Convex function.
     #[error("ServerError: {msg}")]
-    ServerError { msg: String },
+    ServerError {
+        msg: String,
+        code: errors::ErrorCode,
+        retryable: bool,
+    },
+    /// The call did not complete within its timeout.
+    #[error("Timeout: no result within {timeout_ms} ms")]
+    Timeout {
+        timeout_ms: u64,
+        code: errors::ErrorCode,
+        retryable: bool,
+    },
 }
 
 impl From<anyhow::Error> for ClientError {
     fn from(value: anyhow::Error) -> Self {
-        Self::InternalError {
-            msg: value.to_string(),
-        }
+        let code = value
+            .downcast_ref::<errors::CodedError>()
+            .map_or(errors::ErrorCode::Internal, |coded| coded.code);
+        Self::coded(code, value.to_string())
     }
 }
 
-/// JWT claims structure for extracting expiration time.
-#[derive(Deserialize)]
-struct JwtClaims {
-    exp: u64,
-}
-
-/// Decodes a JWT token and extracts the expiration timestamp.
-/// Returns None if the token is malformed or doesn't contain an exp claim.
-fn decode_jwt_expiry(token: &str) -> Option<u64> {
-    // JWT format: header.payload.signature
-    let parts: Vec<&str> = token.split('.').collect();
-    if parts.len() != 3 {
-        return None;
-    }
-
-    // Decode payload (second part) using URL-safe base64
-    let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
-        .decode(parts[1])
-        .ok()?;
-
-    let claims: JwtClaims = serde_json::from_slice(&payload).ok()?;
-    Some(claims.exp)
-}
-
 /// WebSocket connection state exposed to Flutter/Dart.
 ///
 /// This enum represents the current state of the WebSocket connection
 /// to the Convex backend, allowing real-time connection monitoring.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 #[frb]
 pub enum WebSocketConnectionState {
     /// The WebSocket is open and connected to the Convex backend.
     Connected,
-    /// The WebSocket is closed and is connecting or reconnecting.
+    /// The WebSocket of a newly built client is being opened.
     Connecting,
-}
-
-impl From<ConvexWebSocketState> for WebSocketConnectionState {
-    fn from(state: ConvexWebSocketState) -> Self {
-        match state {
-            ConvexWebSocketState::Connected => WebSocketConnectionState::Connected,
-            ConvexWebSocketState::Connecting => WebSocketConnectionState::Connecting,
-        }
-    }
+    /// The WebSocket closed. After [`ConnectionCloseReason::ConnectionLost`]
+    /// the Convex client reconnects by itself; otherwise the next call, or
+    /// [`MobileConvexClient::connect`], opens a new one.
+    Closed { reason: ConnectionCloseReason },
+    /// Building the client failed; the next attempt starts in `retry_in_ms`.
+    Backoff { retry_in_ms: u64, attempt: u32 },
+    /// Building the client failed on every attempt. Calls made later start
+    /// over, as does [`MobileConvexClient::reconnect`].
+    Failed { reason: String },
 }
 
 /// Trait defining the interface for handling subscription updates.
@@ -104,6 +243,7 @@ impl From<ConvexWebSocketState> for WebSocketConnectionState {
 pub trait QuerySubscriber: Send + Sync {
     fn on_update(&self, value: String); // Called when a new update is received
     fn on_error(&self, message: String, value: Option<String>); // Called on error with optional value
+    fn on_done(&self) {} // Called once the stream ended; no more updates will arrive
 }
 
 /// Adapter struct to implement QuerySubscriber using Dart callbacks.
@@ -122,30 +262,121 @@ impl QuerySubscriber for CallbackSubscriber {
     }
 }
 
+/// Forwards an update or error event of a subscription to `name` to a
+/// [`QuerySubscriber`]. Values are not logged, since logs may end up in a
+/// plaintext file (see [`crate::logging`]).
+pub(crate) fn deliver_to_subscriber(
+    name: &str,
+    subscriber: &dyn QuerySubscriber,
+    event: Option<SubscriptionEvent>,
+) {
+    match event {
+        Some(SubscriptionEvent::Update { value }) => {
+            debug!("Updating {name} with {} bytes", value.len());
+            subscriber.on_update(value);
+        }
+        Some(SubscriptionEvent::Error { message, data }) => {
+            subscriber.on_error(message, data);
+        }
+        Some(SubscriptionEvent::Closed {
+            reason: SubscriptionCloseReason::StreamEnded,
+        }) => subscriber.on_done(),
+        _ => {}
+    }
+}
+
 /// Opaque type for Dart, representing a subscription handle with cancellation.
+///
+/// Dropping the handle, e.g. when Dart garbage-collects it, cancels the
+/// subscription.
 #[frb(opaque)]
 pub struct SubscriptionHandle {
     cancel_sender: Arc<Mutex<Option<Sender<()>>>>, // Sender to cancel the subscription
+    priority: Arc<AtomicU8>, // Re-subscription priority after a reconnect
+    owned: bool, // Whether dropping cancels; false for handles shared internally
 }
 
 impl SubscriptionHandle {
     fn new(cancel_sender: Sender<()>) -> Self {
+        let priority = Arc::new(AtomicU8::new(SubscriptionPriority::Normal as u8));
+        Self::with_priority(cancel_sender, priority)
+    }
+
+    fn with_priority(cancel_sender: Sender<()>, priority: Arc<AtomicU8>) -> Self {
         SubscriptionHandle {
             cancel_sender: Arc::new(Mutex::new(Some(cancel_sender))),
+            priority,
+            owned: true,
         }
     }
 
-    /// Cancels the subscription by sending a cancellation signal.
+    /// Returns a handle controlling the same subscription, which does not
+    /// cancel it when dropped.
+    pub(crate) fn share(&self) -> SubscriptionHandle {
+        SubscriptionHandle {
+            cancel_sender: self.cancel_sender.clone(),
+            priority: self.priority.clone(),
+            owned: false,
+        }
+    }
+
+    /// Returns whether the subscription is neither cancelled nor ended.
     #[frb(sync)]
-    pub fn cancel(&self) {
-        if let Some(sender) = self.cancel_sender.lock().take() {
-            sender.send(()).unwrap();
+    pub fn is_active(&self) -> bool {
+        self.state() == SubscriptionState::Active
+    }
+
+    /// Returns whether the subscription is active, has ended or was
+    /// cancelled.
+    #[frb(sync)]
+    pub fn state(&self) -> SubscriptionState {
+        match self.cancel_sender.lock().as_ref() {
+            None => SubscriptionState::Cancelled,
+            Some(sender) if sender.is_canceled() => SubscriptionState::Ended,
+            Some(_) => SubscriptionState::Active,
+        }
+    }
+
+    /// Cancels the subscription unless it has already been cancelled or
+    /// ended, returning whether it did.
+    pub(crate) fn stop(&self) -> bool {
+        let mut cancel_sender = self.cancel_sender.lock();
+        // An ended subscription keeps its sender so it is reported as ended.
+        if cancel_sender.as_ref().is_some_and(Sender::is_canceled) {
+            return false;
+        }
+        cancel_sender
+            .take()
+            .is_some_and(|sender| sender.send(()).is_ok())
+    }
+
+    /// Changes the order in which this subscription is re-established after
+    /// a reconnect, e.g. when its screen becomes visible.
+    #[frb(sync)]
+    pub fn set_priority(&self, priority: SubscriptionPriority) {
+        self.priority.store(priority as u8, Ordering::SeqCst);
+    }
+
+    /// Cancels the subscription by sending a cancellation signal. Returns
+    /// `false` if it had already been cancelled or ended; calling it again
+    /// is harmless.
+    #[frb(sync)]
+    pub fn cancel(&self) -> bool {
+        self.stop()
+    }
+}
+
+impl Drop for SubscriptionHandle {
+    fn drop(&mut self) {
+        if self.owned {
+            self.stop();
         }
     }
 }
 
 /// Opaque type for Dart, representing an auth session handle with lifecycle management.
 /// Used to control the token refresh loop and check authentication state.
+/// Dropping the handle disposes it.
 #[frb(opaque)]
 pub struct AuthHandle {
     cancel_sender: Arc<Mutex<Option<Sender<()>>>>,
@@ -173,65 +404,447 @@ impl AuthHandle {
     pub fn is_authenticated(&self) -> bool {
         self.is_authenticated.load(Ordering::SeqCst)
     }
+
+    /// Returns whether the token refresh loop is still running.
+    #[frb(sync)]
+    pub fn is_active(&self) -> bool {
+        self.cancel_sender
+            .lock()
+            .as_ref()
+            .is_some_and(|sender| !sender.is_canceled())
+    }
+}
+
+impl Drop for AuthHandle {
+    fn drop(&mut self) {
+        self.dispose();
+    }
+}
+
+/// Awaits a token from `fetch_token`, or returns `None` as soon as the auth
+/// session is cancelled. The Dart future is dropped on cancellation, so a
+/// slow identity provider is no longer waited for.
+async fn fetch_unless_cancelled(
+    token: DartFnFuture<Option<String>>,
+    mut cancel: &mut futures::future::Fuse<oneshot::Receiver<()>>,
+) -> Option<Option<String>> {
+    select_biased! {
+        _ = cancel => None,
+        token = token.fuse() => Some(token),
+    }
+}
+
+/// Returns how long to wait before fetching a new token after the backend
+/// rejected `rejected` tokens in a row: not at all after the first, then
+/// from 1 second doubling up to a minute.
+fn rejection_backoff(rejected: u32) -> Duration {
+    match rejected {
+        0 | 1 => Duration::ZERO,
+        n => exponential_backoff(1_000, 60_000, n - 1),
+    }
 }
 
 /// Adapter for Dart functions as subscribers, handling async callbacks.
 pub struct CallbackSubscriberDartFn {
     on_update: Box<dyn Fn(String) -> DartFnFuture<()> + Send + Sync>, // Async update callback
     on_error: Box<dyn Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync>, // Async error callback
+    on_done: Option<Box<dyn Fn() -> DartFnFuture<()> + Send + Sync>>, // Async end-of-stream callback
+    calls: OrderedCalls, // Invokes the callbacks in the order of the events
 }
 
 impl QuerySubscriber for CallbackSubscriberDartFn {
     fn on_update(&self, value: String) {
-        let future = (self.on_update)(value);
-        tokio::spawn(async move {
-            let _ = future.await; // Await the future, ignoring the result
-        });
+        self.calls.call((self.on_update)(value));
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.calls.call((self.on_error)(message, value));
+    }
+
+    fn on_done(&self) {
+        if let Some(on_done) = &self.on_done {
+            self.calls.call(on_done());
+        }
+    }
+}
+
+/// Resolves with the first update or error of a subscription.
+#[frb(ignore)]
+struct FirstResultSubscriber {
+    sender: Mutex<Option<Sender<Result<String, ClientError>>>>,
+}
+
+impl FirstResultSubscriber {
+    fn resolve(&self, result: Result<String, ClientError>) {
+        if let Some(sender) = self.sender.lock().take() {
+            let _ = sender.send(result);
+        }
+    }
+}
+
+impl QuerySubscriber for FirstResultSubscriber {
+    fn on_update(&self, value: String) {
+        self.resolve(Ok(value));
     }
 
     fn on_error(&self, message: String, value: Option<String>) {
-        let future = (self.on_error)(message, value);
-        tokio::spawn(async move {
-            let _ = future.await;
+        self.resolve(Err(match value {
+            Some(data) => ClientError::convex_json(message, &data),
+            None => ClientError::server(message),
+        }));
+    }
+
+    fn on_done(&self) {
+        // Dropping the sender fails the pending call.
+        self.sender.lock().take();
+    }
+}
+
+/// Slot holding the Convex client in use; replaced on failover.
+pub(crate) type ClientSlot = Arc<tokio::sync::Mutex<Option<ConvexClient>>>;
+
+/// Builds Convex clients wired to the client's connection-state tracking.
+#[derive(Clone)]
+pub(crate) struct ClientFactory {
+    client_id: String,
+    connection_state: Arc<tokio::sync::watch::Sender<WebSocketConnectionState>>,
+    quality: Arc<QualityTracker>,
+    // Incremented per built client; only the newest one reports its state
+    generation: Arc<AtomicU64>,
+    // Unix time in milliseconds of the last transition to Connected; 0 if none
+    last_connected_at: Arc<AtomicI64>,
+    rt: tokio::runtime::Handle,
+}
+
+impl ClientFactory {
+    /// Stops state reporting of the current client, which is being dropped
+    /// for `reason`.
+    fn retire(&self, reason: ConnectionCloseReason) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        let state = WebSocketConnectionState::Closed { reason };
+        self.quality.record_state(&state);
+        self.connection_state.send_replace(state);
+    }
+
+    async fn build(&self, url: &str) -> anyhow::Result<ConvexClient> {
+        // Build client directly without spawning a task
+        // This ensures callback is registered BEFORE connection starts
+        debug!("Building the Convex client for {url}");
+        let mut builder = ConvexClientBuilder::new(url).with_client_id(&self.client_id);
+
+        // Register state change callback BEFORE building. States are
+        // published through `connection_state`, which all listeners follow.
+        let (internal_tx, mut internal_rx) =
+            tokio::sync::mpsc::channel::<ConvexWebSocketState>(10);
+        builder = builder.with_on_state_change(internal_tx);
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        if generation > 1 {
+            // A replacement client starts out connecting.
+            let state = WebSocketConnectionState::Connecting;
+            self.quality.record_state(&state);
+            self.connection_state.send_replace(state);
+        }
+        let current_generation = self.generation.clone();
+        let connection_state = self.connection_state.clone();
+        let quality = self.quality.clone();
+        let last_connected_at = self.last_connected_at.clone();
+        self.rt.spawn(async move {
+            let mut states = SocketStates::default();
+            while let Some(state) = internal_rx.recv().await {
+                if current_generation.load(Ordering::SeqCst) != generation {
+                    break;
+                }
+                let state = states.report(state);
+                if state == WebSocketConnectionState::Connected {
+                    last_connected_at.store(now_millis(), Ordering::SeqCst);
+                }
+                quality.record_state(&state);
+                connection_state.send_replace(state);
+            }
         });
+
+        let result = builder.build().await;
+        match &result {
+            Ok(_) => debug!("Built the Convex client; connecting"),
+            Err(e) => log::warn!("Failed to build the Convex client: {e:?}"),
+        }
+        result
     }
 }
 
 /// Main Convex client struct, opaque to Dart, managing connections and operations.
 #[frb(opaque)]
 pub struct MobileConvexClient {
-    deployment_url: String,         // URL of the Convex deployment
-    client_id: String,              // Client ID for authentication
-    client: OnceCell<ConvexClient>, // Lazy-initialized Convex client
-    rt: tokio::runtime::Runtime,    // Tokio runtime for async operations
-    // Channel sender for WebSocket state change notifications
-    state_change_sender: Arc<Mutex<Option<tokio::sync::mpsc::Sender<ConvexWebSocketState>>>>,
+    inner: Arc<ClientInner>,
+}
+
+/// State of a [`MobileConvexClient`], shared with background tasks that
+/// call back into the client, e.g. outbox replay.
+#[frb(ignore)]
+pub struct ClientInner {
+    client: ClientSlot,             // Lazy-initialized Convex client
+    client_factory: ClientFactory,  // Builds clients for the primary and fallbacks
+    connection: ConnectionManager,  // Retries builds and keeps the last failure
+    rt: tokio::runtime::Handle,     // Handle of the runtime for async operations
+    // Tokio runtime owned by the client; taken on close
+    runtime: Mutex<Option<tokio::runtime::Runtime>>,
+    closed: AtomicBool, // Whether close() was called
+    // Latest WebSocket state, observed by internal subsystems (e.g. presence)
+    connection_state: Arc<tokio::sync::watch::Sender<WebSocketConnectionState>>,
+    // Estimated server clock minus local clock, in milliseconds
+    clock_offset_ms: Arc<AtomicI64>,
+    options: ClientOptions, // Client-wide behavior options
+    // Limits re-subscriptions after a reconnect, if configured
+    resubscribe_scheduler: Option<Arc<ResubscribeScheduler>>,
+    // Collects subscriptions of the same window, if configured
+    subscribe_batcher: Option<Arc<SubscribeBatcher>>,
+    keyed: KeyedSubscriptions, // Subscriptions replaced by key
+    lifecycle: Arc<Lifecycle>, // Subscriptions stopped while paused
+    // Subject of the current auth token, recorded in the audit log
+    auth_identity: Arc<Mutex<Option<String>>>,
+    // Current auth token, re-applied to the client after a failover
+    auth_token: Arc<Mutex<Option<String>>>,
+    // Bumped whenever auth is set, so superseded refresh loops leave it alone
+    auth_generation: Arc<AtomicU64>,
+    auth_session: AuthSession, // Refresh loop and subscriptions ended by logout
+    auth_changes: Arc<AuthChanges>, // Auth changes with their reasons
+    missing_auth_warned: AtomicBool, // Whether the missing-auth warning was logged
+    failover: Arc<FailoverState>, // Deployment list and the active deployment
+    audit_log: Option<Arc<AuditLog>>, // On-device audit log, if enabled
+    quality: Arc<QualityTracker>, // Rolling connection-quality estimate
+    ui_hints: Arc<UiHints>, // User-facing status hint
+    runtime_monitor: Arc<RuntimeMonitor>, // Health of the Tokio runtime
+    telemetry_sampler: Arc<TelemetrySampler>, // Decides which telemetry is recorded
+    ui_pressure: tokio::sync::watch::Sender<UiPressure>, // UI load reported by the app
+    query_cache: Arc<QueryCache>, // Cached one-shot query results
+    storage: Option<ScopedStorage>, // On-disk partitions, if a storage root is set
+    shards: Arc<Mutex<ShardRegistry>>, // Sharded subscriptions and their mappings
+    action_cache: Arc<ActionCache>, // Memoized action results
+    // Compares the backend fingerprint on connect, if configured
+    schema_check: Option<Arc<SchemaCheck>>,
+    budget_guard: Option<Arc<BudgetGuard>>, // Enforces the usage budget, if configured
+    faults: FaultInjector, // Latency and failures injected per function
+    registry: SubscriptionRegistry, // Live subscriptions, for finding leaks
+    last_values: Arc<LastValues>, // Last subscription values, shown as placeholders
+    field_encryption: FieldEncryption, // Fields encrypted end to end, if set
+    // Held shared by mutations and exclusively by mutation sequences
+    mutation_order: tokio::sync::RwLock<()>,
+    // Upstream subscriptions shared by identical subscriptions, if enabled
+    shared_subscriptions: Arc<SharedSubscriptions>,
+    deferred: Arc<DeferredMutations>, // Queued low-priority mutations
+    instance: ClientInstance, // Counts clients of the same deployment
+    // Reports what was released under memory pressure
+    memory_events: tokio::sync::broadcast::Sender<MemoryReleasedEvent>,
+    mutation_events: MutationEvents, // Lifecycle of tracked mutations
+    dart_supervisor: Arc<DartSupervisor>, // Liveness of the Dart isolate
+    outbox: Arc<Outbox>, // Mutations queued while offline
+    optimistic: OptimisticStore, // Pending optimistic updates
+    storage_cipher: StorageCipher, // Encrypts files once the app sets a key
+    replica: Arc<Replica>, // Local tables of replicated queries
+    write_barrier: Arc<WriteBarrier>, // Mutations in flight, by sequence number
+    events: Arc<EventBus>, // Client events broadcast to `events` listeners
+    call_metrics: Arc<CallMetrics>, // Per-function latencies, sizes and retries
+    trace_exporter: Mutex<Option<TraceExporter>>, // Exports call spans, once enabled
+    interceptors: Interceptors, // Intercept calls and their results
+    _incognito_log: Option<logging::IncognitoLogGuard>, // Held by incognito clients
+}
+
+impl Deref for MobileConvexClient {
+    type Target = ClientInner;
+
+    fn deref(&self) -> &ClientInner {
+        &self.inner
+    }
+}
+
+impl Drop for ClientInner {
+    fn drop(&mut self) {
+        // The last reference may be dropped by a task on the runtime itself,
+        // where blocking on its shutdown is not allowed.
+        if let Some(runtime) = self.runtime.get_mut().take() {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+/// A [`MobileConvexClient`] held by a background task without keeping the
+/// client alive.
+#[derive(Clone)]
+pub(crate) struct WeakClient(Weak<ClientInner>);
+
+impl WeakClient {
+    /// Returns the client unless it was dropped.
+    pub(crate) fn upgrade(&self) -> Option<MobileConvexClient> {
+        let inner = self.0.upgrade()?;
+        Some(MobileConvexClient { inner })
+    }
 }
 
 impl MobileConvexClient {
     /// Creates a new MobileConvexClient instance with the given deployment URL and client ID.
     #[frb(sync)]
     pub fn new(deployment_url: String, client_id: String) -> MobileConvexClient {
-        #[cfg(debug_assertions)]
-        android_logger::init_once(Config::default().with_max_level(LevelFilter::Error));
+        Self::new_with_options(deployment_url, client_id, ClientOptions::default())
+    }
+
+    /// Creates a new MobileConvexClient instance with explicit client options.
+    #[frb(sync)]
+    pub fn new_with_options(
+        deployment_url: String,
+        client_id: String,
+        mut options: ClientOptions,
+    ) -> MobileConvexClient {
+        options.enforce_incognito();
+        let incognito_log = options.incognito.then(logging::IncognitoLogGuard::new);
+        logging::install();
+        panics::install();
         let rt = tokio::runtime::Builder::new_multi_thread()
             .enable_all()
             .build()
             .unwrap();
-        MobileConvexClient {
-            deployment_url,
+        let telemetry_sampler = Arc::new(TelemetrySampler::new(options.telemetry_sampling.clone()));
+        let runtime_monitor = RuntimeMonitor::new(rt.handle().clone(), telemetry_sampler.clone());
+        let quality = Arc::new(QualityTracker::new());
+        #[cfg(debug_assertions)]
+        if let Some(port) = options.debug_metrics_port {
+            let quality = quality.clone();
+            let runtime_monitor = runtime_monitor.clone();
+            let source: prometheus::MetricsSource = Arc::new(move || metrics::ClientMetrics {
+                connection: quality.current(),
+                runtime: runtime_monitor.current(),
+            });
+            rt.spawn(prometheus::run_metrics_endpoint(port, source));
+        }
+        let storage = options
+            .storage_root
+            .as_ref()
+            .map(|root| ScopedStorage::new(root, &deployment_url));
+        let auth_identity = Arc::new(Mutex::new(None));
+        let storage_cipher = StorageCipher::new(options.require_storage_key);
+        let outbox = Outbox::new(
+            storage.clone(),
+            auth_identity.clone(),
+            storage_cipher.clone(),
+        );
+        let replica = Replica::new(
+            storage.clone(),
+            auth_identity.clone(),
+            storage_cipher.clone(),
+        );
+        let persistence = options.result_cache_persistence.clone().and_then(|persisted| {
+            let storage = storage.clone()?;
+            let cipher = storage_cipher.clone();
+            Some(ResultPersistence::new(storage, auth_identity.clone(), persisted, cipher))
+        });
+        let last_values = Arc::new(LastValues::new(options.result_cache.clone(), persistence));
+        if let Some(persisted) = &options.result_cache_persistence {
+            spawn_flush_loop(rt.handle(), Arc::downgrade(&last_values), persisted);
+        }
+        let connection_state = Arc::new(tokio::sync::watch::Sender::new(
+            WebSocketConnectionState::Connecting,
+        ));
+        let instance = ClientInstance::register(&deployment_url, &client_id);
+        let client_factory = ClientFactory {
             client_id,
-            client: OnceCell::new(),
-            rt,
-            state_change_sender: Arc::new(Mutex::new(None)),
+            connection_state: connection_state.clone(),
+            quality: quality.clone(),
+            generation: Arc::new(AtomicU64::new(0)),
+            last_connected_at: Arc::new(AtomicI64::new(0)),
+            rt: rt.handle().clone(),
+        };
+        let failover = FailoverState::new(&deployment_url, options.failover.as_ref());
+        let ui_hints = Arc::new(UiHints::new());
+        let mutation_events = mutation_events();
+        let deferred = DeferredMutations::new(&options.deferred_mutations, mutation_events.clone());
+        rt.spawn(ui_hints.clone().track_connection(connection_state.subscribe()));
+        let connection =
+            ConnectionManager::new(options.slow_initialization_ms, connection_state.clone());
+        let events = Arc::new(EventBus::default());
+        rt.spawn(events.clone().track_connection(connection_state.subscribe()));
+        let call_metrics = Arc::new(CallMetrics::default());
+        rt.spawn(call_metrics.clone().track_connection(connection_state.subscribe()));
+        let shards = Arc::new(Mutex::new(ShardRegistry::new(options.int64_encoding)));
+        let budget_guard = options.usage_budget.clone().map(BudgetGuard::new);
+        let schema_check = options.schema_check.clone().map(|check_options| {
+            Arc::new(SchemaCheck::with_storage(check_options, storage.as_ref()))
+        });
+        let inner = ClientInner {
+            client: Arc::new(tokio::sync::Mutex::new(None)),
+            client_factory,
+            connection,
+            rt: rt.handle().clone(),
+            runtime: Mutex::new(Some(rt)),
+            closed: AtomicBool::new(false),
+            connection_state,
+            clock_offset_ms: Arc::new(AtomicI64::new(0)),
+            resubscribe_scheduler: options
+                .max_concurrent_resubscribes
+                .map(ResubscribeScheduler::new),
+            subscribe_batcher: options
+                .subscribe_batch_window_ms
+                .map(SubscribeBatcher::new),
+            keyed: KeyedSubscriptions::default(),
+            lifecycle: Arc::new(Lifecycle::default()),
+            auth_identity,
+            auth_token: Arc::new(Mutex::new(None)),
+            auth_generation: Arc::new(AtomicU64::new(0)),
+            auth_session: AuthSession::new(),
+            auth_changes: Arc::new(AuthChanges::default()),
+            missing_auth_warned: AtomicBool::new(false),
+            failover,
+            audit_log: options.audit_log.clone().map(AuditLog::new),
+            deferred,
+            instance,
+            options,
+            quality,
+            ui_hints,
+            runtime_monitor,
+            telemetry_sampler,
+            ui_pressure: tokio::sync::watch::Sender::new(UiPressure::Normal),
+            query_cache: Arc::new(QueryCache::default()),
+            storage,
+            shards,
+            action_cache: Arc::new(ActionCache::default()),
+            schema_check,
+            budget_guard,
+            faults: FaultInjector::default(),
+            registry: SubscriptionRegistry::default(),
+            last_values,
+            field_encryption: FieldEncryption::default(),
+            mutation_order: tokio::sync::RwLock::new(()),
+            shared_subscriptions: Arc::new(SharedSubscriptions::default()),
+            memory_events: memory_events(),
+            outbox,
+            mutation_events,
+            dart_supervisor: Arc::default(),
+            optimistic: OptimisticStore::default(),
+            storage_cipher,
+            replica,
+            write_barrier: Arc::default(),
+            events,
+            call_metrics,
+            trace_exporter: Mutex::new(None),
+            interceptors: Interceptors::default(),
+            _incognito_log: incognito_log,
+        };
+        MobileConvexClient {
+            inner: Arc::new(inner),
         }
     }
 
-    /// Sets up WebSocket connection state change listener.
+    /// Returns a handle for background tasks, which stop once the client is
+    /// dropped.
+    pub(crate) fn downgrade(&self) -> WeakClient {
+        WeakClient(Arc::downgrade(&self.inner))
+    }
+
+    /// Adds a WebSocket connection state change listener.
     ///
-    /// Must be called BEFORE any queries/mutations to capture all state changes.
-    /// The callback will be invoked whenever the WebSocket transitions between
-    /// Connected and Connecting states.
+    /// Listeners can be added at any time and any number of them can be
+    /// registered. Each one is invoked with the current state right away and
+    /// then whenever the WebSocket transitions between Connected and
+    /// Connecting states. A listener that is still busy with its previous
+    /// state receives only the latest one.
     ///
     /// # Arguments
     ///
@@ -251,93 +864,268 @@ impl MobileConvexClient {
         &self,
         on_state_change: impl Fn(WebSocketConnectionState) -> DartFnFuture<()> + Send + Sync + 'static,
     ) -> Result<(), ClientError> {
-        println!("RUST: on_websocket_state_change() called");
+        let mut state_rx = self.connection_state.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                let state = state_rx.borrow_and_update().clone();
+                on_state_change(state).await;
+                if state_rx.changed().await.is_err() {
+                    break;
+                }
+            }
+            debug!("WebSocket state listener ended");
+        });
+        Ok(())
+    }
 
-        // Create tokio mpsc channel for receiving state changes from convex client
-        let (state_tx, mut state_rx) = tokio::sync::mpsc::channel::<ConvexWebSocketState>(10);
-        println!("RUST: Created mpsc channel for state changes");
+    /// Returns the current WebSocket connection state.
+    #[frb(sync)]
+    pub fn connection_state(&self) -> WebSocketConnectionState {
+        self.connection_state.borrow().clone()
+    }
+
+    /// Returns when the WebSocket last became connected, as Unix time in
+    /// milliseconds, or `None` if it never was.
+    #[frb(sync)]
+    pub fn last_connected_at_ms(&self) -> Option<i64> {
+        let at = self.client_factory.last_connected_at.load(Ordering::SeqCst);
+        (at > 0).then_some(at)
+    }
 
-        // Store sender for use when initializing the client
-        {
-            let mut sender = self.state_change_sender.lock();
-            *sender = Some(state_tx);
-            println!("RUST: Stored state_tx in state_change_sender");
+    /// Retrieves or initializes a connected Convex client.
+    ///
+    /// Clients are built for the active deployment, so after a failover a
+    /// rebuilt client stays with the fallback.
+    async fn connected_client(&self) -> anyhow::Result<ConvexClient> {
+        self.ensure_open()?;
+        let _waiting = self.connection.caller_waiting();
+        let mut slot = self.client.lock().await;
+        if let Some(client) = slot.as_ref() {
+            return Ok(client.clone());
         }
+        let client = self
+            .connection
+            .build(&self.client_factory, self.failover.active_url(), &self.options.connect_retry)
+            .await?;
+        *slot = Some(client.clone());
+        self.start_failover();
+        self.start_schema_check(&client);
+        Ok(client)
+    }
 
-        // Spawn task to listen for state changes and call Dart callback
-        let on_state_change = Arc::new(on_state_change);
-        println!("RUST: Spawning listener task for state changes");
-        self.rt.spawn(async move {
-            println!("RUST: Listener task started, waiting for state changes");
-            while let Some(state) = state_rx.recv().await {
-                println!("RUST: Received state change from channel: {:?}", state);
-                let dart_state = WebSocketConnectionState::from(state);
-                println!("RUST: Converted to Dart state: {:?}", dart_state);
-                let callback = on_state_change.clone();
-                let future = (callback)(dart_state);
-                println!("RUST: Calling Dart callback");
-                let _ = future.await;
-                println!("RUST: Dart callback completed");
+    /// Fails once the client has been closed.
+    fn ensure_open(&self) -> anyhow::Result<()> {
+        if self.closed.load(Ordering::SeqCst) {
+            anyhow::bail!("Client is closed");
+        }
+        Ok(())
+    }
+
+    /// Starts the failover task, if configured and not started yet.
+    fn start_failover(&self) {
+        let Some(options) = &self.options.failover else {
+            return;
+        };
+        if !self.connection.start_failover_once() {
+            return;
+        }
+        self.rt.spawn(
+            FailoverTask {
+                state: self.failover.clone(),
+                options: options.clone(),
+                factory: self.client_factory.clone(),
+                slot: self.client.clone(),
+                auth_token: self.auth_token.clone(),
+                state_rx: self.connection_state.subscribe(),
+                client: self.downgrade(),
             }
-            println!("RUST: Listener task exiting (channel closed)");
-        });
+            .run(),
+        );
+    }
 
-        println!("RUST: on_websocket_state_change() returning");
+    /// Closes the client: cancels all subscriptions and background tasks,
+    /// including the auth refresh loop, closes the WebSocket and shuts the
+    /// runtime down. Every later call fails with an error. Closing twice is a
+    /// no-op.
+    #[frb]
+    pub async fn close(&self) -> Result<(), ClientError> {
+        if self.closed.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+        debug!("Closing client");
+        self.flush_deferred().await;
+        self.instance.unregister();
+        self.client_factory.retire(ConnectionCloseReason::ClientClosed);
+        self.connection.mark_disconnected();
+        // Drop our reference first; the remaining clones live in tasks that
+        // are dropped with the runtime.
+        self.client.lock().await.take();
+        if let Some(runtime) = self.runtime.lock().take() {
+            runtime.shutdown_background();
+        }
         Ok(())
     }
 
-    /// Retrieves or initializes a connected Convex client.
-    async fn connected_client(&self) -> anyhow::Result<ConvexClient> {
-        let url = self.deployment_url.clone();
-        let state_sender = self.state_change_sender.lock().clone();
-
-        println!("RUST: connected_client() called with sender: {:?}", state_sender.is_some());
-
-        self.client
-            .get_or_try_init(async {
-                let client_id = self.client_id.to_owned();
-
-                // Build client directly without spawning a task
-                // This ensures callback is registered BEFORE connection starts
-                println!("RUST: Building ConvexClient directly (no task spawn)");
-                let mut builder = ConvexClientBuilder::new(url.as_str())
-                    .with_client_id(&client_id);
-
-                // Register state change callback BEFORE building
-                if let Some(sender) = state_sender {
-                    println!("RUST: Registering state change callback with builder");
-                    builder = builder.with_on_state_change(sender);
-                } else {
-                    println!("RUST WARNING: No sender available - state changes will not be emitted");
-                }
+    /// Parses FFI arguments according to the client's options.
+    fn parse_args(
+        &self,
+        raw_args: HashMap<String, String>,
+    ) -> Result<BTreeMap<String, Value>, ClientError> {
+        catch_panics_sync(|| {
+            let args = parse_json_args(
+                raw_args,
+                self.options.null_handling,
+                self.options.blank_args,
+                &self.options.type_codecs,
+            )
+            .map_err(|e| with_code(e, ErrorCode::ValidationFailed))?;
+            Ok(self.field_encryption.encrypt_args(args)?)
+        })
+    }
 
-                println!("RUST: Calling builder.build() - connection will start now");
-                let result = builder.build().await;
-                match &result {
-                    Ok(_) => println!("RUST: ConvexClient built successfully"),
-                    Err(e) => println!("RUST ERROR: Failed to build ConvexClient: {:?}", e),
-                }
-                result
-            })
-            .await
-            .map(|client_ref| client_ref.clone())
+    /// Validates structured FFI arguments according to the client's options.
+    fn convert_args(
+        &self,
+        args: HashMap<String, ConvexValue>,
+    ) -> Result<BTreeMap<String, Value>, ClientError> {
+        catch_panics_sync(|| {
+            let args = convex_args(args, self.options.null_handling)
+                .map_err(|e| with_code(e, ErrorCode::ValidationFailed))?;
+            Ok(self.field_encryption.encrypt_args(args)?)
+        })
     }
 
-    /// Executes a query on the Convex backend.
+    /// Starts an audit log entry if the audit log is enabled.
+    fn begin_audit(
+        &self,
+        operation: AuditOperation,
+        name: &str,
+        args: &BTreeMap<String, Value>,
+    ) -> Option<PendingAudit> {
+        let log = self.audit_log.as_ref()?;
+        if !self.telemetry_sampler.sample(TelemetryClass::Audit, Some(name)) {
+            return None;
+        }
+        Some(log.begin(operation, name, args, self.auth_identity.lock().clone()))
+    }
+
+    /// Feeds a completed call into the connection quality, if sampled.
+    fn record_call(&self, name: &str, elapsed: Duration, transport_ok: bool) {
+        if self.telemetry_sampler.sample(TelemetryClass::CallLatency, Some(name)) {
+            self.quality.record_call(elapsed, transport_ok);
+        }
+    }
+
+    /// Executes a query on the Convex backend. Fails with
+    /// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
     #[frb]
     pub async fn query(
         &self,
         name: String,
         args: HashMap<String, String>,
+        timeout_ms: Option<u64>,
     ) -> Result<String, ClientError> {
-        let mut client = self.connected_client().await?;
+        with_timeout(timeout_ms, async {
+            let args = self.parse_args(args)?;
+            self.format_result(self.internal_query(name, args).await?)
+        })
+        .await
+    }
+
+    /// Executes a query with structured arguments. Fails with
+    /// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+    #[frb]
+    pub async fn query_with_values(
+        &self,
+        name: String,
+        args: HashMap<String, ConvexValue>,
+        timeout_ms: Option<u64>,
+    ) -> Result<String, ClientError> {
+        with_timeout(timeout_ms, async {
+            let args = self.convert_args(args)?;
+            self.format_result(self.internal_query(name, args).await?)
+        })
+        .await
+    }
+
+    /// Internal method for query logic, passing the query through the
+    /// interceptors.
+    async fn internal_query(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
+    ) -> anyhow::Result<FunctionResult> {
+        self.internal_query_via(name, args, |mut client, name, args| async move {
+            client.query(name.as_str(), args).await
+        })
+        .await
+    }
+
+    /// Like [`MobileConvexClient::internal_query`], but runs the query with
+    /// `run` once it passed the interceptors, budget and instrumentation.
+    async fn internal_query_via<F, Fut>(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
+        run: F,
+    ) -> anyhow::Result<FunctionResult>
+    where
+        F: FnOnce(ConvexClient, String, BTreeMap<String, Value>) -> Fut,
+        Fut: Future<Output = anyhow::Result<FunctionResult>>,
+    {
+        catch_panics(
+            self.interceptors
+                .run(CallKind::Query, name, args, |name, args| {
+                    self.send_with_request_id(CallKind::Query, name, args, |name, args| {
+                        self.send_query(name, args, run)
+                    })
+                }),
+        )
+        .await
+    }
+
+    async fn send_query<F, Fut>(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
+        run: F,
+    ) -> anyhow::Result<FunctionResult>
+    where
+        F: FnOnce(ConvexClient, String, BTreeMap<String, Value>) -> Fut,
+        Fut: Future<Output = anyhow::Result<FunctionResult>>,
+    {
+        if let Err(message) = self.faults.apply(&name).await {
+            return Ok(FunctionResult::ErrorMessage(message));
+        }
+        let _in_flight = self.deferred.call_started();
+        let client = self.connected_client().await?;
         debug!("got the client");
-        let result = client.query(name.as_str(), parse_json_args(args)).await?;
+        let usage = self.begin_usage(&name, &args).await?;
+        let audit = self.begin_audit(AuditOperation::Query, &name, &args);
+        let call = self.begin_metrics(&name, &args);
+        let span = self.begin_span(SpanOperation::Query, &name, Some(&args));
+        let started = Instant::now();
+        let result = run(client, name.clone(), args).await;
+        self.record_call(&name, started.elapsed(), result.is_ok());
+        self.diagnose_auth(&name, &result);
+        span.finish(AuditStatus::of(&result));
+        if let Some(call) = call {
+            call.finish(&result);
+        }
+        if let Some(audit) = audit {
+            audit.finish(AuditStatus::of(&result));
+        }
+        if let Some(usage) = usage {
+            usage.finish(&result);
+        }
         debug!("got the result");
-        handle_direct_function_result(result)
+        result.map_err(|e| with_code(e, ErrorCode::Network))
     }
 
-    /// Subscribes to real-time updates from a Convex query.
+    /// Subscribes to real-time updates from a Convex query, as configured by
+    /// `options`. `on_done` is called once the subscription stream ended and
+    /// no more updates will ever arrive, as opposed to waiting for the next
+    /// update. Cancelling does not call `on_done`.
     #[frb]
     pub async fn subscribe(
         &self,
@@ -345,32 +1133,291 @@ impl MobileConvexClient {
         args: HashMap<String, String>,
         on_update: impl Fn(String) -> DartFnFuture<()> + Send + Sync + 'static,
         on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_done: impl Fn() -> DartFnFuture<()> + Send + Sync + 'static,
+        options: Option<SubscribeOptions>,
     ) -> Result<SubscriptionHandle, ClientError> {
-        let subscriber = Arc::new(CallbackSubscriberDartFn {
-            on_update: Box::new(on_update),
-            on_error: Box::new(on_error),
-        });
-        self.internal_subscribe(name, args, subscriber)
+        let options = options.unwrap_or_default();
+        options.validate()?;
+        let args = self.subscription_args(args, &options)?;
+        let delivery = match options.backpressure {
+            Some(backpressure) => self.backpressure_subscriber(
+                backpressure,
+                Box::new(on_update),
+                Box::new(on_error),
+                Box::new(on_done),
+            ),
+            None => Arc::new(CallbackSubscriberDartFn {
+                on_update: Box::new(on_update),
+                on_error: Box::new(on_error),
+                on_done: Some(Box::new(on_done)),
+                calls: OrderedCalls::default(),
+            }),
+        };
+        self.internal_subscribe(name, args, options.wrap(delivery), options.priority)
             .await
             .map_err(Into::into)
     }
 
-    /// Internal method for subscription logic.
-    async fn internal_subscribe(
+    /// Parses `args` together with the structured arguments of `options`.
+    fn subscription_args(
+        &self,
+        args: HashMap<String, String>,
+        options: &SubscribeOptions,
+    ) -> Result<BTreeMap<String, Value>, ClientError> {
+        let mut args = self.parse_args(args)?;
+        if !options.value_args.is_empty() {
+            args.extend(self.convert_args(options.value_args.clone())?);
+        }
+        Ok(args)
+    }
+
+    /// Subscribes to a Convex query, resolves with its first result and
+    /// cancels the subscription. Unlike [`MobileConvexClient::query`], the
+    /// result is read through the subscription set and is therefore
+    /// consistent with the client's other subscriptions. Query errors are
+    /// returned like those of `query`.
+    #[frb]
+    pub async fn subscribe_once(
         &self,
         name: String,
         args: HashMap<String, String>,
+    ) -> Result<String, ClientError> {
+        let (sender, receiver) = oneshot::channel();
+        let subscriber = Arc::new(FirstResultSubscriber {
+            sender: Mutex::new(Some(sender)),
+        });
+        let args = self.parse_args(args)?;
+        // Dropping the handle once resolved cancels the subscription.
+        let _handle = self
+            .internal_subscribe(name, args, subscriber, SubscriptionPriority::Normal)
+            .await?;
+        receiver.await.unwrap_or_else(|_| {
+            Err(ClientError::internal("Subscription ended before producing a result"))
+        })
+    }
+
+    /// Subscribes to a Convex query and delivers every lifecycle signal as a
+    /// single [`SubscriptionEvent`] through `on_event`.
+    ///
+    /// Events are delivered in order: each callback is awaited before the
+    /// next event is produced. The last event is always
+    /// [`SubscriptionEvent::Closed`]. The subscription is made like those of
+    /// [`MobileConvexClient::subscribe`], so it is also paused and resumed,
+    /// and takes the same `options` except for a backpressure policy.
+    #[frb]
+    pub async fn subscribe_with_events(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        on_event: impl Fn(SubscriptionEvent) -> DartFnFuture<()> + Send + Sync + 'static,
+        options: Option<SubscribeOptions>,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        let options = options.unwrap_or_default();
+        if options.backpressure.is_some() {
+            return Err(ClientError::internal(
+                "Backpressure is not supported for subscription events",
+            ));
+        }
+        options.validate()?;
+        let args = self.subscription_args(args, &options)?;
+        let mut state_rx = self.connection_state.subscribe();
+        state_rx.mark_unchanged();
+        let mut paused_rx = self.lifecycle.watch();
+        paused_rx.mark_unchanged();
+        let (deliveries, mut delivered) = tokio::sync::mpsc::unbounded_channel();
+        let subscriber = options.wrap(Arc::new(EventForwarder(deliveries)));
+        let inner = self
+            .internal_subscribe(name, args, subscriber, options.priority)
+            .await?;
+        let (cancel_sender, cancel_receiver) = oneshot::channel::<()>();
+        let handle = SubscriptionHandle::with_priority(cancel_sender, inner.priority.clone());
+        self.rt.spawn(async move {
+            let cancel_fut = cancel_receiver.fuse();
+            pin_mut!(cancel_fut);
+            let connected = *state_rx.borrow() == WebSocketConnectionState::Connected;
+            let mut machine = SubscriptionStateMachine::new(connected);
+            let paused = *paused_rx.borrow();
+            if let Some(event) = machine.on_pause_change(paused) {
+                let _ = on_event(event).await;
+            }
+            loop {
+                // Watch changes are read once the borrows held by `changed()`
+                // are released.
+                enum Input {
+                    Delivered(Option<SubscriptionEvent>),
+                    Connection(bool),
+                    Pause(bool),
+                }
+                let input = {
+                    let state_fut = state_rx.changed().fuse();
+                    let paused_fut = paused_rx.changed().fuse();
+                    pin_mut!(state_fut, paused_fut);
+                    select_biased! {
+                        _ = cancel_fut => {
+                            inner.stop();
+                            Input::Delivered(Some(SubscriptionEvent::Closed {
+                                reason: SubscriptionCloseReason::Cancelled,
+                            }))
+                        }
+                        event = delivered.recv().fuse() => Input::Delivered(event),
+                        changed = state_fut => Input::Connection(changed.is_ok()),
+                        changed = paused_fut => Input::Pause(changed.is_ok()),
+                    }
+                };
+                let event = match input {
+                    Input::Delivered(Some(event)) => machine.on_delivery(event),
+                    // The client is gone.
+                    Input::Delivered(None) | Input::Connection(false) | Input::Pause(false) => {
+                        machine.close(SubscriptionCloseReason::StreamEnded)
+                    }
+                    Input::Connection(true) => machine.on_connection_change(
+                        *state_rx.borrow_and_update() == WebSocketConnectionState::Connected,
+                    ),
+                    Input::Pause(true) => machine.on_pause_change(*paused_rx.borrow_and_update()),
+                };
+                let closed = matches!(event, Some(SubscriptionEvent::Closed { .. }));
+                if let Some(event) = event {
+                    let _ = on_event(event).await;
+                }
+                if closed {
+                    break;
+                }
+            }
+            debug!("Subscription closed");
+        });
+        Ok(handle)
+    }
+
+    /// Internal method for subscription logic. Subscriptions made while
+    /// authenticated are cancelled by [`MobileConvexClient::logout`], and all
+    /// of them are stopped by [`MobileConvexClient::pause`].
+    async fn internal_subscribe(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
+        subscriber: Arc<dyn QuerySubscriber>,
+        priority: SubscriptionPriority,
+    ) -> anyhow::Result<SubscriptionHandle> {
+        catch_panics(self.subscribe_unguarded(name, args, subscriber, priority)).await
+    }
+
+    /// [`MobileConvexClient::internal_subscribe`], without catching panics.
+    async fn subscribe_unguarded(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
         subscriber: Arc<dyn QuerySubscriber>,
+        priority: SubscriptionPriority,
+    ) -> anyhow::Result<SubscriptionHandle> {
+        self.faults.apply(&name).await.map_err(anyhow::Error::msg)?;
+        self.begin_usage(&name, &args).await?;
+        let subscriber = Arc::new(EventSubscriber {
+            inner: subscriber,
+            name: name.clone(),
+            events: self.events.clone(),
+        });
+        let (subscriber, updates) = CountingSubscriber::new(subscriber);
+        let optimistic = self.optimistic.subscriber(&name, &args, subscriber);
+        let subscriber = self.caching_subscriber(&name, &args, optimistic.clone());
+        let subscriber = self.decoding_subscriber(subscriber);
+        let authenticated = self.auth_token.lock().is_some();
+        let handle = if self.lifecycle.is_paused() {
+            self.lifecycle
+                .park_new(name.clone(), args.clone(), subscriber, priority)
+        } else {
+            let priority = Arc::new(AtomicU8::new(priority as u8));
+            let handle = self
+                .establish_subscription(name.clone(), args.clone(), subscriber.clone(), priority)
+                .await?;
+            self.lifecycle
+                .track(name.clone(), args.clone(), subscriber, &handle);
+            handle
+        };
+        if authenticated {
+            self.auth_session.track_subscription(&handle);
+        }
+        self.registry.register(&name, &args, updates, &handle);
+        self.optimistic.register(optimistic, &handle);
+        Ok(handle)
+    }
+
+    async fn establish_subscription(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
+        subscriber: Arc<dyn QuerySubscriber>,
+        priority: Arc<AtomicU8>,
+    ) -> anyhow::Result<SubscriptionHandle> {
+        let subscriber = throttle_subscriber(
+            &self.rt,
+            subscriber,
+            self.ui_pressure.subscribe(),
+            self.options.pressure_throttle.clone(),
+        );
+        if self.options.share_subscriptions {
+            return self
+                .join_shared_subscription(name, args, subscriber, priority)
+                .await;
+        }
+        self.establish_upstream_subscription(name, args, subscriber, priority)
+            .await
+    }
+
+    /// Subscribes to the server, without throttling or sharing.
+    pub(crate) async fn establish_upstream_subscription(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
+        subscriber: Arc<dyn QuerySubscriber>,
+        priority: Arc<AtomicU8>,
     ) -> anyhow::Result<SubscriptionHandle> {
         let mut client = self.connected_client().await?;
         debug!("New subscription");
-        let mut subscription = client
-            .subscribe(name.as_str(), parse_json_args(args))
-            .await?;
+        let audit = self.begin_audit(AuditOperation::Subscribe, &name, &args);
+        let span = self.begin_span(SpanOperation::Subscribe, &name, Some(&args));
+        let sharded = if self.field_encryption.encrypts_any(&args) {
+            None
+        } else {
+            subscribe_sharded(&self.rt, &self.shards, &client, &name, &args, subscriber.clone())
+        };
+        if let Some(cancel_sender) = sharded {
+            if let Some(audit) = audit {
+                audit.finish(AuditStatus::Success);
+            }
+            span.finish(AuditStatus::Success);
+            return Ok(SubscriptionHandle::with_priority(cancel_sender, priority));
+        }
+        let subscription = self.batched_subscribe(&mut client, &name, args.clone()).await;
+        let status = if subscription.is_ok() {
+            AuditStatus::Success
+        } else {
+            AuditStatus::TransportError
+        };
+        if let Some(audit) = audit {
+            audit.finish(status);
+        }
+        span.finish(status);
+        let mut subscription = subscription?;
         let (cancel_sender, cancel_receiver) = oneshot::channel::<()>();
+        if let Some(scheduler) = &self.resubscribe_scheduler {
+            let managed = ManagedSubscription {
+                client,
+                name,
+                args,
+                subscriber,
+                priority: priority.clone(),
+                scheduler: scheduler.clone(),
+                state_rx: self.connection_state.subscribe(),
+                int64_encoding: self.options.int64_encoding,
+            };
+            self.rt.spawn(managed.run(subscription, cancel_receiver));
+            return Ok(SubscriptionHandle::with_priority(cancel_sender, priority));
+        }
+        let int64_encoding = self.options.int64_encoding;
         self.rt.spawn(async move {
             let cancel_fut = cancel_receiver.fuse();
             pin_mut!(cancel_fut);
+            let mut machine = SubscriptionStateMachine::new(true).with_int64_encoding(int64_encoding);
             loop {
                 select_biased! {
                     new_val = subscription.next().fuse() => {
@@ -378,26 +1425,11 @@ impl MobileConvexClient {
                             Some(val) => val,
                             None => {
                                 log::warn!("Subscription stream ended for {}", &name);
+                                subscriber.on_done();
                                 break;
                             }
                         };
-                        match new_val {
-                            FunctionResult::Value(value) => {
-                                debug!("Updating with {value:?}");
-                                subscriber.on_update(serde_json::to_string(
-                                    &serde_json::Value::from(value),
-                                ).unwrap());
-                            }
-                            FunctionResult::ErrorMessage(message) => {
-                                subscriber.on_error(message, None);
-                            }
-                            FunctionResult::ConvexError(error) => subscriber.on_error(
-                                error.message,
-                                Some(serde_json::ser::to_string(
-                                    &serde_json::Value::from(error.data),
-                                ).unwrap()),
-                            ),
-                        }
+                        deliver_to_subscriber(&name, &*subscriber, machine.on_result(new_val));
                     }
                     _ = cancel_fut => {
                         break;
@@ -406,67 +1438,281 @@ impl MobileConvexClient {
             }
             debug!("Subscription canceled");
         });
-        Ok(SubscriptionHandle::new(cancel_sender))
+        Ok(SubscriptionHandle::with_priority(cancel_sender, priority))
     }
 
-    /// Executes a mutation on the Convex backend.
+    /// Executes a mutation on the Convex backend. Fails with
+    /// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
     #[frb]
     pub async fn mutation(
         &self,
         name: String,
         args: HashMap<String, String>,
+        timeout_ms: Option<u64>,
     ) -> Result<String, ClientError> {
-        let result = self.internal_mutation(name, args).await?;
-        handle_direct_function_result(result)
+        with_timeout(timeout_ms, async {
+            let args = self.parse_args(args)?;
+            let result = self.internal_mutation(name.clone(), args).await?;
+            if matches!(result, FunctionResult::Value(_)) {
+                self.invalidate_after_mutation(&name).await;
+            }
+            self.format_result(result)
+        })
+        .await
     }
 
-    /// Internal method for mutation logic.
+    /// Executes a mutation with structured arguments. Fails with
+    /// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+    #[frb]
+    pub async fn mutation_with_values(
+        &self,
+        name: String,
+        args: HashMap<String, ConvexValue>,
+        timeout_ms: Option<u64>,
+    ) -> Result<String, ClientError> {
+        with_timeout(timeout_ms, async {
+            let args = self.convert_args(args)?;
+            let result = self.internal_mutation(name.clone(), args).await?;
+            if matches!(result, FunctionResult::Value(_)) {
+                self.invalidate_after_mutation(&name).await;
+            }
+            self.format_result(result)
+        })
+        .await
+    }
+
+    /// Internal method for mutation logic. Waits for a running mutation
+    /// sequence to finish first.
     async fn internal_mutation(
         &self,
         name: String,
-        args: HashMap<String, String>,
+        args: BTreeMap<String, Value>,
+    ) -> anyhow::Result<FunctionResult> {
+        let _order = self.mutation_order.read().await;
+        self.unordered_mutation(name, args).await
+    }
+
+    /// Executes a mutation regardless of running mutation sequences,
+    /// passing it through the interceptors.
+    pub(crate) async fn unordered_mutation(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
+    ) -> anyhow::Result<FunctionResult> {
+        catch_panics(
+            self.interceptors
+                .run(CallKind::Mutation, name, args, |name, args| {
+                    self.send_with_request_id(CallKind::Mutation, name, args, |name, args| {
+                        self.send_mutation(name, args)
+                    })
+                }),
+        )
+        .await
+    }
+
+    async fn send_mutation(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
     ) -> anyhow::Result<FunctionResult> {
+        if let Err(message) = self.faults.apply(&name).await {
+            return Ok(FunctionResult::ErrorMessage(message));
+        }
+        let _in_flight = self.deferred.call_started();
         let mut client = self.connected_client().await?;
-        self.rt
-            .spawn(async move { client.mutation(&name, parse_json_args(args)).await })
-            .await?
+        let usage = self.begin_usage(&name, &args).await?;
+        let audit = self.begin_audit(AuditOperation::Mutation, &name, &args);
+        let call = self.begin_metrics(&name, &args);
+        let span = self.begin_span(SpanOperation::Mutation, &name, Some(&args));
+        let _pending = self.ui_hints.mutation_started();
+        let _write = self.write_barrier.write_started();
+        let started = Instant::now();
+        let function = name.clone();
+        let result = AbortOnDrop(
+            self.rt
+                .spawn(async move { client.mutation(&function, args).await }),
+        )
+        .await?;
+        self.record_call(&name, started.elapsed(), result.is_ok());
+        self.diagnose_auth(&name, &result);
+        span.finish(AuditStatus::of(&result));
+        if let Some(call) = call {
+            call.finish(&result);
+        }
+        if let Some(audit) = audit {
+            audit.finish(AuditStatus::of(&result));
+        }
+        if let Some(usage) = usage {
+            usage.finish(&result);
+        }
+        result.map_err(|e| with_code(e, ErrorCode::Network))
     }
 
-    /// Executes an action on the Convex backend.
+    /// Executes an action on the Convex backend. Fails with
+    /// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
     #[frb]
     pub async fn action(
         &self,
         name: String,
         args: HashMap<String, String>,
+        timeout_ms: Option<u64>,
     ) -> Result<String, ClientError> {
-        debug!("Running action: {}", name);
-        let result = self.internal_action(name, args).await?;
-        debug!("Got action result: {:?}", result);
-        handle_direct_function_result(result)
+        with_timeout(timeout_ms, async {
+            debug!("Running action: {}", name);
+            let args = self.parse_args(args)?;
+            let result = self.internal_action(name, args).await?;
+            self.format_result(result)
+        })
+        .await
     }
 
-    /// Internal method for action logic.
+    /// Executes an action with structured arguments. Fails with
+    /// [`ClientError::Timeout`] after `timeout_ms` milliseconds, if set.
+    #[frb]
+    pub async fn action_with_values(
+        &self,
+        name: String,
+        args: HashMap<String, ConvexValue>,
+        timeout_ms: Option<u64>,
+    ) -> Result<String, ClientError> {
+        with_timeout(timeout_ms, async {
+            let args = self.convert_args(args)?;
+            let result = self.internal_action(name, args).await?;
+            self.format_result(result)
+        })
+        .await
+    }
+
+    /// Internal method for action logic, passing the action through the
+    /// interceptors.
     async fn internal_action(
         &self,
         name: String,
-        args: HashMap<String, String>,
+        args: BTreeMap<String, Value>,
     ) -> anyhow::Result<FunctionResult> {
+        catch_panics(
+            self.interceptors
+                .run(CallKind::Action, name, args, |name, args| {
+                    self.send_with_request_id(CallKind::Action, name, args, |name, args| {
+                        self.send_action(name, args)
+                    })
+                }),
+        )
+        .await
+    }
+
+    async fn send_action(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
+    ) -> anyhow::Result<FunctionResult> {
+        if let Err(message) = self.faults.apply(&name).await {
+            return Ok(FunctionResult::ErrorMessage(message));
+        }
+        let _in_flight = self.deferred.call_started();
         let mut client = self.connected_client().await?;
         debug!("Running action: {}", name);
-        self.rt
-            .spawn(async move { client.action(&name, parse_json_args(args)).await })
-            .await?
+        let usage = self.begin_usage(&name, &args).await?;
+        let audit = self.begin_audit(AuditOperation::Action, &name, &args);
+        let call = self.begin_metrics(&name, &args);
+        let span = self.begin_span(SpanOperation::Action, &name, Some(&args));
+        let started = Instant::now();
+        let function = name.clone();
+        let result = AbortOnDrop(
+            self.rt
+                .spawn(async move { client.action(&function, args).await }),
+        )
+        .await?;
+        self.record_call(&name, started.elapsed(), result.is_ok());
+        self.diagnose_auth(&name, &result);
+        span.finish(AuditStatus::of(&result));
+        if let Some(call) = call {
+            call.finish(&result);
+        }
+        if let Some(audit) = audit {
+            audit.finish(AuditStatus::of(&result));
+        }
+        if let Some(usage) = usage {
+            usage.finish(&result);
+        }
+        result.map_err(|e| with_code(e, ErrorCode::Network))
+    }
+
+    /// Returns the retained audit log entries as JSON lines, oldest first.
+    #[frb]
+    pub async fn export_audit_log(&self) -> Result<String, ClientError> {
+        Ok(self.enabled_audit_log()?.export().map_err(anyhow::Error::from)?)
+    }
+
+    /// Deletes all retained audit log entries.
+    #[frb]
+    pub async fn clear_audit_log(&self) -> Result<(), ClientError> {
+        Ok(self.enabled_audit_log()?.clear().map_err(anyhow::Error::from)?)
+    }
+
+    fn enabled_audit_log(&self) -> Result<&Arc<AuditLog>, ClientError> {
+        self.audit_log.as_ref().ok_or_else(|| ClientError::internal("Audit log is not enabled"))
     }
 
     /// Sets authentication token for the client.
     #[frb]
     pub async fn set_auth(&self, token: Option<String>) -> Result<(), ClientError> {
+        if token.is_some() {
+            self.ensure_auth_enabled()?;
+        }
         Ok(self.internal_set_auth(token).await?)
     }
 
+    /// Returns whether an auth token is currently set. Always `false` for
+    /// clients created with [`ClientOptions::unauthenticated`].
+    #[frb(sync)]
+    pub fn is_authenticated(&self) -> bool {
+        !self.options.unauthenticated && self.auth_token.lock().is_some()
+    }
+
+    /// Rejects auth tokens when the client runs without authentication.
+    fn ensure_auth_enabled(&self) -> Result<(), ClientError> {
+        if self.options.unauthenticated {
+            return Err(ClientError::internal(
+                "Authentication is disabled by ClientOptions::unauthenticated",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Reports a function failing as its auth token was rejected, and warns
+    /// once when a function fails while no auth token is set, unless the
+    /// client is declared to run without authentication.
+    fn diagnose_auth(&self, name: &str, result: &anyhow::Result<FunctionResult>) {
+        if let Ok(FunctionResult::ConvexError(error)) = result {
+            let data = value_to_json_string(error.data.clone());
+            self.events.report_error(name, &error.message, Some(&data));
+        }
+        if self.options.unauthenticated || self.auth_token.lock().is_some() {
+            return;
+        }
+        if let Ok(FunctionResult::ErrorMessage(message)) = result {
+            if !self.missing_auth_warned.swap(true, Ordering::Relaxed) {
+                log::warn!(
+                    "{name} failed while no auth token is set: {message}. Call set_auth, or set \
+                     ClientOptions::unauthenticated if the app intentionally runs without auth."
+                );
+            }
+        }
+    }
+
     /// Internal method for setting authentication.
     async fn internal_set_auth(&self, token: Option<String>) -> anyhow::Result<()> {
         let mut client = self.connected_client().await?;
+        self.auth_generation.fetch_add(1, Ordering::SeqCst);
+        *self.auth_identity.lock() = token.as_deref().and_then(decode_jwt_subject);
+        *self.auth_token.lock() = token.clone();
+        self.events.set_authenticated(token.is_some());
+        let reason = match token {
+            Some(_) => AuthChangeReason::SignedIn,
+            None => AuthChangeReason::SignedOut,
+        };
+        self.auth_changes.emit(token.is_some(), reason);
         self.rt
             .spawn(async move { client.set_auth(token).await })
             .await
@@ -477,17 +1723,44 @@ impl MobileConvexClient {
     ///
     /// The `fetch_token` callback is called:
     /// - Immediately to get the initial token
-    /// - Automatically when the token is about to expire (60 seconds before expiry)
+    /// - Automatically when the token is about to expire (60 seconds before expiry,
+    ///   see [`MobileConvexClient::set_auth_with_refresh_config`])
+    /// - Immediately when the backend rejects the token, see
+    ///   [`events::ClientEvent::AuthRejected`]; if it keeps rejecting new tokens,
+    ///   after a delay doubling from 1 second up to a minute
+    /// - Immediately when the WebSocket reconnects after the token expired;
+    ///   an unexpired token is applied again instead, see
+    ///   [`auth_changes`]
     ///
     /// The `on_auth_change` callback is called whenever auth state changes.
     ///
     /// Returns an AuthHandle that can be used to dispose the auth session.
+    /// Disposing stops waiting on a pending `fetch_token` call; a token it
+    /// returns afterwards is never applied. A later `set_auth`,
+    /// `set_auth_with_refresh` or `logout` ends the session too, without its
+    /// refresh loop clearing the newer auth.
     #[frb]
     pub async fn set_auth_with_refresh(
         &self,
         fetch_token: impl Fn() -> DartFnFuture<Option<String>> + Send + Sync + 'static,
         on_auth_change: impl Fn(bool) -> DartFnFuture<()> + Send + Sync + 'static,
     ) -> Result<AuthHandle, ClientError> {
+        let config = AuthRefreshConfig::default();
+        self.set_auth_with_refresh_config(config, fetch_token, on_auth_change)
+            .await
+    }
+
+    /// Like [`MobileConvexClient::set_auth_with_refresh`], but refreshes the
+    /// token on the schedule of `config`, as described in the
+    /// [module docs](crate::auth_refresh).
+    #[frb]
+    pub async fn set_auth_with_refresh_config(
+        &self,
+        config: AuthRefreshConfig,
+        fetch_token: impl Fn() -> DartFnFuture<Option<String>> + Send + Sync + 'static,
+        on_auth_change: impl Fn(bool) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<AuthHandle, ClientError> {
+        self.ensure_auth_enabled()?;
         let is_authenticated = Arc::new(AtomicBool::new(false));
         let (cancel_sender, cancel_receiver) = oneshot::channel::<()>();
 
@@ -496,38 +1769,49 @@ impl MobileConvexClient {
 
         let fetch_token = Arc::new(fetch_token);
         let on_auth_change = Arc::new(on_auth_change);
-
-        // Buffer time before token expiry to trigger refresh (60 seconds)
-        const REFRESH_BUFFER_SECS: u64 = 60;
-        // Minimum refresh interval to prevent tight loops on errors
-        const MIN_REFRESH_INTERVAL_SECS: u64 = 5;
-        // Default refresh interval when JWT can't be decoded (5 minutes)
-        const DEFAULT_REFRESH_INTERVAL_SECS: u64 = 300;
+        let auth_identity = self.auth_identity.clone();
+        let auth_token = self.auth_token.clone();
+        let ui_hints = self.ui_hints.clone();
+        let client_slot = self.client.clone();
+        let events = self.events.clone();
+        let auth_changes = self.auth_changes.clone();
+        let mut state_rx = self.connection_state.subscribe();
+
+        let mut rejections = events.subscribe();
+        let handle = AuthHandle::new(cancel_sender, is_authenticated);
+        let session = handle.cancel_sender.clone();
+        let auth_generation = self.auth_generation.clone();
+        let generation = auth_generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let is_latest = move || auth_generation.load(Ordering::SeqCst) == generation;
+        // Whether this session may still change auth: it is neither disposed
+        // nor superseded by a later set_auth, refresh session or logout.
+        let is_current = {
+            let is_latest = is_latest.clone();
+            move || session.lock().is_some() && is_latest()
+        };
 
         // Spawn the token refresh loop
         self.rt.spawn(async move {
             let mut cancel_fut = cancel_receiver.fuse();
             let mut was_authenticated = false;
-
-            loop {
+            // Tokens the backend rejected in a row.
+            let mut rejected = 0;
+            // Whether the token is fetched as the last one expired offline.
+            let mut expired = false;
+
+            // `true` if the session ended by being disposed or superseded,
+            // `false` if fetch_token returned None.
+            let cancelled = 'refresh: loop {
                 // Fetch token from Dart
+                ui_hints.set_signing_in(!was_authenticated);
                 let fetch_token_clone = fetch_token.clone();
                 let token_future = (fetch_token_clone)();
-
-                let token_result = select_biased! {
-                    _ = cancel_fut => {
-                        // Cancelled - clear auth and exit
-                        debug!("Auth refresh cancelled");
-                        let mut client = client.clone();
-                        let _ = client.set_auth(None).await;
-                        if was_authenticated {
-                            let on_auth_change_clone = on_auth_change.clone();
-                            let future = (on_auth_change_clone)(false);
-                            let _ = future.await;
-                        }
-                        break;
-                    }
-                    token = token_future.fuse() => token,
+                let token_result = fetch_unless_cancelled(token_future, &mut cancel_fut).await;
+                ui_hints.set_signing_in(false);
+                // A token arriving as the session ends is discarded.
+                let Some(token_result) = token_result.filter(|_| is_current()) else {
+                    debug!("Auth refresh cancelled");
+                    break true;
                 };
 
                 let now_secs = SystemTime::now()
@@ -538,8 +1822,24 @@ impl MobileConvexClient {
                 match token_result {
                     Some(token) => {
                         // Set the token
-                        let mut client = client.clone();
+                        let mut client = active_client(&client_slot, &client).await;
                         client.set_auth(Some(token.clone())).await;
+                        if !is_current() {
+                            // Ended while the token was applied; cleared below.
+                            break true;
+                        }
+                        *auth_identity.lock() = decode_jwt_subject(&token);
+                        *auth_token.lock() = Some(token.clone());
+                        events.set_authenticated(true);
+                        // Rejections so far concern the previous token.
+                        skip_pending(&mut rejections);
+
+                        if expired {
+                            expired = false;
+                            auth_changes.emit(true, AuthChangeReason::ConnectedReauth);
+                        } else if !was_authenticated {
+                            auth_changes.emit(true, AuthChangeReason::SignedIn);
+                        }
 
                         // Notify state change if needed
                         if !was_authenticated {
@@ -553,49 +1853,82 @@ impl MobileConvexClient {
                         }
 
                         // Decode expiry and schedule next refresh
-                        let sleep_duration = if let Some(exp) = decode_jwt_expiry(&token) {
-                            let refresh_at = exp.saturating_sub(REFRESH_BUFFER_SECS);
-                            if refresh_at > now_secs {
-                                Duration::from_secs(refresh_at - now_secs)
-                            } else {
-                                // Token already expired or about to, refresh immediately
-                                Duration::from_secs(MIN_REFRESH_INTERVAL_SECS)
-                            }
-                        } else {
-                            // Can't decode JWT, use default refresh interval
-                            debug!("Could not decode JWT expiry, using default refresh interval");
-                            Duration::from_secs(DEFAULT_REFRESH_INTERVAL_SECS)
-                        };
+                        let sleep_duration = config.refresh_delay(&token, now_secs);
 
                         debug!("Next token refresh in {:?}", sleep_duration);
 
-                        // Sleep until refresh time or cancellation
+                        // Sleep until refresh time, rejection or cancellation,
+                        // checking the token after every reconnect
                         let sleep_fut = tokio::time::sleep(sleep_duration).fuse();
-                        pin_mut!(sleep_fut);
-                        select_biased! {
-                            _ = cancel_fut => {
-                                debug!("Auth refresh cancelled during sleep");
-                                let mut client = client.clone();
-                                let _ = client.set_auth(None).await;
-                                if was_authenticated {
-                                    let on_auth_change_clone = on_auth_change.clone();
-                                    let future = (on_auth_change_clone)(false);
-                                    let _ = future.await;
+                        let rejection_fut = next_auth_rejection(&mut rejections).fuse();
+                        pin_mut!(sleep_fut, rejection_fut);
+                        let delay = loop {
+                            let reconnect_fut = next_reconnect(&mut state_rx).fuse();
+                            pin_mut!(reconnect_fut);
+                            select_biased! {
+                                _ = cancel_fut => {
+                                    debug!("Auth refresh cancelled during sleep");
+                                    break 'refresh true;
+                                }
+                                _ = rejection_fut => {
+                                    rejected += 1;
+                                    let delay = rejection_backoff(rejected);
+                                    log::warn!(
+                                        "The backend rejected the auth token, fetching a new \
+                                         one in {delay:?}"
+                                    );
+                                    break delay;
+                                }
+                                _ = reconnect_fut => {
+                                    let now_secs = SystemTime::now()
+                                        .duration_since(UNIX_EPOCH)
+                                        .unwrap()
+                                        .as_secs();
+                                    if is_expired(&token, now_secs) {
+                                        debug!("Auth token expired while disconnected");
+                                        expired = true;
+                                        events.set_authenticated(false);
+                                        auth_changes.emit(false, AuthChangeReason::TokenExpired);
+                                        break Duration::ZERO;
+                                    }
+                                    debug!("Re-applying the auth token after reconnecting");
+                                    let mut client = active_client(&client_slot, &client).await;
+                                    client.set_auth(Some(token.clone())).await;
+                                    if !is_current() {
+                                        break 'refresh true;
+                                    }
+                                    auth_changes.emit(true, AuthChangeReason::ConnectedReauth);
+                                }
+                                _ = sleep_fut => {
+                                    // Time to refresh, continue loop
+                                    rejected = 0;
+                                    break Duration::ZERO;
                                 }
-                                break;
                             }
-                            _ = sleep_fut => {
-                                // Time to refresh, continue loop
+                        };
+                        if !delay.is_zero() {
+                            let backoff_fut = tokio::time::sleep(delay).fuse();
+                            pin_mut!(backoff_fut);
+                            select_biased! {
+                                _ = cancel_fut => {
+                                    debug!("Auth refresh cancelled during backoff");
+                                    break 'refresh true;
+                                }
+                                _ = backoff_fut => {}
                             }
                         }
                     }
                     None => {
                         // No token - clear auth
                         debug!("Token fetcher returned None, clearing auth");
-                        let mut client = client.clone();
+                        let mut client = active_client(&client_slot, &client).await;
                         let _ = client.set_auth(None).await;
+                        *auth_identity.lock() = None;
+                        *auth_token.lock() = None;
+                        events.set_authenticated(false);
 
                         if was_authenticated {
+                            auth_changes.emit(false, AuthChangeReason::TokenRefreshFailed);
                             is_auth_clone.store(false, Ordering::SeqCst);
                             let on_auth_change_clone = on_auth_change.clone();
                             let future = (on_auth_change_clone)(false);
@@ -605,43 +1938,170 @@ impl MobileConvexClient {
                         }
 
                         // Exit the loop when fetch_token returns None
-                        break;
+                        break false;
+                    }
+                }
+            };
+
+            if cancelled {
+                ui_hints.set_signing_in(false);
+                is_auth_clone.store(false, Ordering::SeqCst);
+                // Leave the auth of a later session alone.
+                if is_latest() {
+                    let mut client = active_client(&client_slot, &client).await;
+                    let _ = client.set_auth(None).await;
+                    *auth_identity.lock() = None;
+                    *auth_token.lock() = None;
+                    events.set_authenticated(false);
+                    if was_authenticated {
+                        auth_changes.emit(false, AuthChangeReason::SignedOut);
                     }
                 }
+                if was_authenticated {
+                    let future = (on_auth_change)(false);
+                    let _ = future.await;
+                }
             }
 
             debug!("Auth refresh loop ended");
         });
 
-        Ok(AuthHandle::new(cancel_sender, is_authenticated))
+        self.auth_session.track_refresh(handle.cancel_sender.clone());
+        Ok(handle)
     }
 }
 
-/// Utility function to parse HashMap arguments into Convex Value format.
-fn parse_json_args(raw_args: HashMap<String, String>) -> BTreeMap<String, Value> {
-    raw_args
-        .into_iter()
-        .map(|(k, v)| {
-            (
-                k,
-                Value::try_from(
-                    serde_json::from_str::<serde_json::Value>(&v)
-                        .expect("Invalid JSON data from FFI"),
-                )
-                .expect("Invalid Convex data from FFI"),
-            )
-        })
-        .collect()
-}
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+
+    use super::*;
+    use crate::faults::FunctionFault;
+
+    #[test]
+    fn connection_state_is_available_synchronously() {
+        let client = MobileConvexClient::new("https://example.convex.cloud".into(), "test".into());
+        assert_eq!(client.connection_state(), WebSocketConnectionState::Connecting);
+        assert_eq!(client.last_connected_at_ms(), None);
+        block_on(client.close()).unwrap();
+    }
+
+    #[test]
+    fn close_is_idempotent_and_rejects_later_calls() {
+        let client = MobileConvexClient::new("https://example.convex.cloud".into(), "test".into());
+        block_on(client.close()).unwrap();
+        block_on(client.close()).unwrap();
+        match block_on(client.query("messages:list".into(), HashMap::new(), None)) {
+            Err(ClientError::InternalError { msg, .. }) => assert_eq!(msg, "Client is closed"),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn slow_calls_time_out() {
+        let client = MobileConvexClient::new("https://example.convex.cloud".into(), "test".into());
+        let fault = FunctionFault {
+            latency_ms: 60_000,
+            failure_rate: 0.0,
+        };
+        block_on(client.set_function_fault("messages:list".into(), fault)).unwrap();
+        let query = client.query("messages:list".into(), HashMap::new(), Some(50));
+        match client.rt.block_on(query) {
+            Err(ClientError::Timeout { timeout_ms, .. }) => assert_eq!(timeout_ms, 50),
+            other => panic!("unexpected result: {other:?}"),
+        }
+        block_on(client.close()).unwrap();
+    }
+
+    #[test]
+    fn disposing_auth_abandons_the_token_fetch() {
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+        let handle = AuthHandle::new(cancel_tx, Arc::new(AtomicBool::new(false)));
+        let mut cancel = cancel_rx.fuse();
+        handle.dispose();
+        let pending: DartFnFuture<Option<String>> = Box::pin(futures::future::pending());
+        assert_eq!(block_on(fetch_unless_cancelled(pending, &mut cancel)), None);
+
+        // A token resolving together with the disposal is not applied.
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+        let handle = AuthHandle::new(cancel_tx, Arc::new(AtomicBool::new(false)));
+        let mut cancel = cancel_rx.fuse();
+        handle.dispose();
+        let ready: DartFnFuture<Option<String>> = Box::pin(async { Some("token".into()) });
+        assert_eq!(block_on(fetch_unless_cancelled(ready, &mut cancel)), None);
+        assert!(!handle.is_active());
+
+        let (_cancel_tx, cancel_rx) = oneshot::channel::<()>();
+        let mut cancel = cancel_rx.fuse();
+        let ready: DartFnFuture<Option<String>> = Box::pin(async { Some("token".into()) });
+        assert_eq!(
+            block_on(fetch_unless_cancelled(ready, &mut cancel)),
+            Some(Some("token".into()))
+        );
+    }
+
+    #[test]
+    fn rejected_tokens_are_refetched_with_backoff() {
+        let delays: Vec<_> = (1..=9).map(|n| rejection_backoff(n).as_secs()).collect();
+        assert_eq!(delays, [0, 1, 2, 4, 8, 16, 32, 60, 60]);
+    }
+
+    #[test]
+    fn cancel_is_idempotent_and_reports_the_state() {
+        let (cancel_tx, mut cancel_rx) = oneshot::channel();
+        let handle = SubscriptionHandle::new(cancel_tx);
+        assert_eq!(handle.state(), SubscriptionState::Active);
+        assert!(handle.cancel());
+        assert!(!handle.cancel());
+        assert_eq!(handle.state(), SubscriptionState::Cancelled);
+        assert_eq!(cancel_rx.try_recv(), Ok(Some(())));
+
+        let (cancel_tx, cancel_rx) = oneshot::channel::<()>();
+        let handle = SubscriptionHandle::new(cancel_tx);
+        drop(cancel_rx);
+        assert_eq!(handle.state(), SubscriptionState::Ended);
+        assert!(!handle.cancel());
+        assert_eq!(handle.state(), SubscriptionState::Ended);
+    }
+
+    #[test]
+    fn dropping_a_handle_cancels_unless_it_is_shared() {
+        let (cancel_tx, mut cancel_rx) = oneshot::channel();
+        let handle = SubscriptionHandle::new(cancel_tx);
+        let shared = handle.share();
+        assert!(handle.is_active());
+        drop(shared);
+        assert_eq!(cancel_rx.try_recv(), Ok(None));
+        drop(handle);
+        assert_eq!(cancel_rx.try_recv(), Ok(Some(())));
+
+        let (cancel_tx, mut cancel_rx) = oneshot::channel();
+        drop(AuthHandle::new(cancel_tx, Arc::new(AtomicBool::new(true))));
+        assert_eq!(cancel_rx.try_recv(), Ok(Some(())));
+    }
+
+    #[test]
+    fn first_result_subscriber_resolves_once() {
+        let (sender, mut receiver) = oneshot::channel();
+        let subscriber = FirstResultSubscriber {
+            sender: Mutex::new(Some(sender)),
+        };
+        subscriber.on_error("boom".into(), Some("{\"code\":1}".into()));
+        subscriber.on_update("[]".into());
+        match receiver.try_recv() {
+            Ok(Some(Err(ClientError::ConvexError { message, data, .. }))) => {
+                assert_eq!(message, "boom");
+                let code = [("code".to_owned(), ConvexValue::Float64(1.0))];
+                assert_eq!(data, ConvexValue::Object(code.into()));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
 
-/// Utility function to handle and serialize FunctionResult into a string or error.
-fn handle_direct_function_result(result: FunctionResult) -> Result<String, ClientError> {
-    match result {
-        FunctionResult::Value(v) => serde_json::to_string(&serde_json::Value::from(v))
-            .map_err(|e| ClientError::InternalError { msg: e.to_string() }),
-        FunctionResult::ConvexError(e) => Err(ClientError::ConvexError {
-            data: serde_json::ser::to_string(&serde_json::Value::from(e.data)).unwrap(),
-        }),
-        FunctionResult::ErrorMessage(msg) => Err(ClientError::ServerError { msg }),
+        let (sender, receiver) = oneshot::channel();
+        let subscriber = FirstResultSubscriber {
+            sender: Mutex::new(Some(sender)),
+        };
+        subscriber.on_done();
+        assert!(block_on(receiver).is_err());
     }
 }
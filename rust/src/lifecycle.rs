@@ -0,0 +1,364 @@
+//! Pausing subscriptions while the app is in the background.
+//!
+//! [`MobileConvexClient::pause`] stops every subscription made through
+//! [`MobileConvexClient::subscribe`] and its variants, including event,
+//! typed, sharded, paginated and presence subscriptions and the status
+//! watches of [`MobileConvexClient::action_with_progress`], and closes the
+//! WebSocket, but keeps their [`SubscriptionHandle`]s valid: cancelling a
+//! paused subscription works as usual. [`MobileConvexClient::resume`]
+//! reconnects and re-establishes the subscriptions that were not cancelled
+//! in the meantime, highest priority first and limited by
+//! [`crate::options::ClientOptions::max_concurrent_resubscribes`] if set.
+//! Subscriptions made while paused are established on resume as well.
+//! Subscriptions made with [`MobileConvexClient::subscribe_with_events`]
+//! report both as [`crate::subscription::SubscriptionEvent::Paused`] and
+//! [`crate::subscription::SubscriptionEvent::Resumed`].
+//!
+//! [`MobileConvexClient::disconnect`] stops subscriptions the same way, and
+//! [`MobileConvexClient::connect`] re-establishes them like `resume`.
+
+use std::{
+    cmp::Reverse,
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc,
+    },
+};
+
+use convex::Value;
+use flutter_rust_bridge::frb;
+use futures::{channel::oneshot, future::join_all};
+use log::debug;
+use parking_lot::Mutex;
+use tokio::sync::watch;
+
+use crate::{
+    connection::ConnectionCloseReason, resubscribe::SubscriptionPriority, ClientError,
+    MobileConvexClient, QuerySubscriber, SubscriptionHandle,
+};
+
+/// A subscription that can be stopped and re-established.
+pub(crate) struct PausableSubscription {
+    name: String,
+    args: BTreeMap<String, Value>,
+    subscriber: Arc<dyn QuerySubscriber>,
+    handle: SubscriptionHandle,
+    // Receives the handle's cancellation while paused
+    parked: Option<oneshot::Receiver<()>>,
+}
+
+impl PausableSubscription {
+    fn priority(&self) -> SubscriptionPriority {
+        SubscriptionPriority::from_u8(self.handle.priority.load(Ordering::SeqCst))
+    }
+
+    /// Returns whether the subscription is neither cancelled nor ended.
+    fn is_active(&mut self) -> bool {
+        match &mut self.parked {
+            Some(parked) => matches!(parked.try_recv(), Ok(None)),
+            None => self.handle.is_active(),
+        }
+    }
+
+    /// Stops the running subscription, leaving the handle to cancel the
+    /// paused one. Returns `false` if it had already ended.
+    fn park(&mut self) -> bool {
+        if self.parked.is_some() {
+            return true;
+        }
+        let mut cancel_sender = self.handle.cancel_sender.lock();
+        match cancel_sender.take() {
+            Some(sender) if !sender.is_canceled() => {
+                let _ = sender.send(());
+                let (parked_tx, parked_rx) = oneshot::channel();
+                *cancel_sender = Some(parked_tx);
+                self.parked = Some(parked_rx);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct Lifecycle {
+    paused: watch::Sender<bool>,
+    subscriptions: Mutex<Vec<PausableSubscription>>,
+}
+
+impl Lifecycle {
+    pub(crate) fn is_paused(&self) -> bool {
+        *self.paused.borrow()
+    }
+
+    /// Returns a receiver of whether the client is paused.
+    pub(crate) fn watch(&self) -> watch::Receiver<bool> {
+        self.paused.subscribe()
+    }
+
+    /// Records a running subscription so it can be paused.
+    pub(crate) fn track(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
+        subscriber: Arc<dyn QuerySubscriber>,
+        handle: &SubscriptionHandle,
+    ) {
+        let mut subscriptions = self.subscriptions.lock();
+        subscriptions.retain_mut(PausableSubscription::is_active);
+        subscriptions.push(PausableSubscription {
+            name,
+            args,
+            subscriber,
+            handle: handle.share(),
+            parked: None,
+        });
+    }
+
+    /// Returns the handle of a subscription made while paused, which is
+    /// established on resume.
+    pub(crate) fn park_new(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
+        subscriber: Arc<dyn QuerySubscriber>,
+        priority: SubscriptionPriority,
+    ) -> SubscriptionHandle {
+        let (parked_tx, parked_rx) = oneshot::channel();
+        let handle =
+            SubscriptionHandle::with_priority(parked_tx, Arc::new(AtomicU8::new(priority as u8)));
+        self.subscriptions.lock().push(PausableSubscription {
+            name,
+            args,
+            subscriber,
+            handle: handle.share(),
+            parked: Some(parked_rx),
+        });
+        handle
+    }
+
+    /// Stops all running subscriptions, returning how many are paused.
+    pub(crate) fn pause(&self) -> usize {
+        self.paused.send_replace(true);
+        let mut subscriptions = self.subscriptions.lock();
+        subscriptions.retain_mut(PausableSubscription::park);
+        debug!("Paused {} subscriptions", subscriptions.len());
+        subscriptions.len()
+    }
+
+    /// Ends the pause, returning the subscriptions to re-establish, highest
+    /// priority first, or `None` if not paused.
+    fn resume(&self) -> Option<Vec<PausableSubscription>> {
+        if !self.paused.send_replace(false) {
+            return None;
+        }
+        let mut subscriptions = self.subscriptions.lock();
+        let (mut parked, running): (Vec<_>, Vec<_>) = std::mem::take(&mut *subscriptions)
+            .into_iter()
+            .partition(|subscription| subscription.parked.is_some());
+        *subscriptions = running;
+        parked.retain_mut(PausableSubscription::is_active);
+        parked.sort_by_key(|subscription| Reverse(subscription.priority()));
+        Some(parked)
+    }
+
+    /// Stops the running subscriptions so they can be re-established on
+    /// another client, returning them highest priority first. Paused ones
+    /// are left to [`MobileConvexClient::resume`].
+    fn park_running(&self) -> Vec<PausableSubscription> {
+        let mut subscriptions = self.subscriptions.lock();
+        let (running, parked): (Vec<_>, Vec<_>) = std::mem::take(&mut *subscriptions)
+            .into_iter()
+            .partition(|subscription| subscription.parked.is_none());
+        *subscriptions = parked;
+        let mut moved: Vec<_> = running
+            .into_iter()
+            .filter_map(|mut subscription| subscription.park().then_some(subscription))
+            .collect();
+        moved.sort_by_key(|subscription| Reverse(subscription.priority()));
+        moved
+    }
+
+    /// Puts subscriptions back after resuming failed.
+    fn restore(&self, parked: Vec<PausableSubscription>) {
+        self.paused.send_replace(true);
+        self.subscriptions.lock().extend(parked);
+    }
+
+    /// Hands control of the re-established subscription `running` to the
+    /// handle of `subscription`, unless it was cancelled in the meantime.
+    fn reinstate(&self, mut subscription: PausableSubscription, running: SubscriptionHandle) {
+        let sender = running.cancel_sender.lock().take();
+        if !subscription.is_active() {
+            running.stop();
+            return;
+        }
+        *subscription.handle.cancel_sender.lock() = sender;
+        subscription.parked = None;
+        // Paused again while re-establishing.
+        if self.is_paused() && !subscription.park() {
+            return;
+        }
+        self.subscriptions.lock().push(subscription);
+    }
+}
+
+impl MobileConvexClient {
+    /// Stops all subscriptions and closes the WebSocket, e.g. when the app
+    /// goes to the background. Subscription handles stay valid and their
+    /// subscriptions are re-established by [`MobileConvexClient::resume`].
+    ///
+    /// Presence heartbeats started by [`MobileConvexClient::start_presence`]
+    /// are paused through their own handle. Changed subscription results
+    /// are persisted, if enabled (see [`crate::persisted_results`]).
+    #[frb]
+    pub async fn pause(&self) -> Result<(), ClientError> {
+        self.ensure_open()?;
+        self.lifecycle.pause();
+        self.last_values.flush();
+        self.disconnect_for(ConnectionCloseReason::Paused).await
+    }
+
+    /// Reconnects and re-establishes the subscriptions stopped by
+    /// [`MobileConvexClient::pause`]. If connecting fails, the client stays
+    /// paused and `resume` can be retried.
+    #[frb]
+    pub async fn resume(&self) -> Result<(), ClientError> {
+        self.ensure_open()?;
+        let parked = self.lifecycle.resume();
+        if let Err(e) = self.connected_client().await {
+            if let Some(parked) = parked {
+                self.lifecycle.restore(parked);
+            }
+            return Err(e.into());
+        }
+        let parked = parked.unwrap_or_default();
+        debug!("Resuming {} subscriptions", parked.len());
+        self.reestablish(parked).await;
+        Ok(())
+    }
+
+    /// Re-establishes the running subscriptions on the current client, e.g.
+    /// after a failover replaced it.
+    pub(crate) async fn move_subscriptions(&self) {
+        let moved = self.lifecycle.park_running();
+        debug!("Moving {} subscriptions to the new client", moved.len());
+        self.reestablish(moved).await;
+    }
+
+    /// Re-establishes parked subscriptions in order, limited by the
+    /// [`crate::resubscribe::ResubscribeScheduler`] if configured.
+    async fn reestablish(&self, parked: Vec<PausableSubscription>) {
+        join_all(parked.into_iter().map(|subscription| async move {
+            let _permit = match &self.resubscribe_scheduler {
+                Some(scheduler) => Some(scheduler.acquire(subscription.priority()).await),
+                None => None,
+            };
+            let established = self
+                .establish_subscription(
+                    subscription.name.clone(),
+                    subscription.args.clone(),
+                    subscription.subscriber.clone(),
+                    subscription.handle.priority.clone(),
+                )
+                .await;
+            match established {
+                Ok(running) => self.lifecycle.reinstate(subscription, running),
+                Err(e) => subscription.subscriber.on_error(e.to_string(), None),
+            }
+        }))
+        .await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Ignore;
+
+    impl QuerySubscriber for Ignore {
+        fn on_update(&self, _value: String) {}
+
+        fn on_error(&self, _message: String, _value: Option<String>) {}
+    }
+
+    fn track(lifecycle: &Lifecycle, name: &str) -> (SubscriptionHandle, oneshot::Receiver<()>) {
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+        let handle = SubscriptionHandle::new(cancel_tx);
+        lifecycle.track(name.into(), BTreeMap::new(), Arc::new(Ignore), &handle);
+        (handle, cancel_rx)
+    }
+
+    #[test]
+    fn paused_subscriptions_keep_their_handles() {
+        let lifecycle = Lifecycle::default();
+        let (low, mut low_rx) = track(&lifecycle, "low");
+        low.set_priority(SubscriptionPriority::Low);
+        let (high, mut high_rx) = track(&lifecycle, "high");
+        high.set_priority(SubscriptionPriority::High);
+        let (cancelled, mut cancelled_rx) = track(&lifecycle, "cancelled");
+
+        lifecycle.pause();
+        assert_eq!(low_rx.try_recv(), Ok(Some(())));
+        assert_eq!(high_rx.try_recv(), Ok(Some(())));
+        assert_eq!(cancelled_rx.try_recv(), Ok(Some(())));
+        assert!(low.is_active() && high.is_active());
+        cancelled.stop();
+
+        let parked = lifecycle.resume().unwrap();
+        let names: Vec<_> = parked.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["high", "low"]);
+        assert!(lifecycle.resume().is_none());
+
+        let mut running_rx = Vec::new();
+        for subscription in parked {
+            let (running_tx, rx) = oneshot::channel();
+            lifecycle.reinstate(subscription, SubscriptionHandle::new(running_tx));
+            running_rx.push(rx);
+        }
+        high.stop();
+        assert_eq!(running_rx[0].try_recv(), Ok(Some(())));
+        assert_eq!(running_rx[1].try_recv(), Ok(None));
+    }
+
+    #[test]
+    fn running_subscriptions_are_moved_highest_priority_first() {
+        let lifecycle = Lifecycle::default();
+        let (low, mut low_rx) = track(&lifecycle, "low");
+        low.set_priority(SubscriptionPriority::Low);
+        let (high, mut high_rx) = track(&lifecycle, "high");
+        high.set_priority(SubscriptionPriority::High);
+
+        let moved = lifecycle.park_running();
+        let names: Vec<_> = moved.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["high", "low"]);
+        assert_eq!(low_rx.try_recv(), Ok(Some(())));
+        assert_eq!(high_rx.try_recv(), Ok(Some(())));
+        assert!(low.is_active() && high.is_active());
+        assert!(!lifecycle.is_paused());
+
+        for subscription in moved {
+            let (running_tx, _running_rx) = oneshot::channel();
+            lifecycle.reinstate(subscription, SubscriptionHandle::new(running_tx));
+        }
+        lifecycle.pause();
+        assert!(lifecycle.park_running().is_empty());
+    }
+
+    #[test]
+    fn subscriptions_made_while_paused_start_on_resume() {
+        let lifecycle = Lifecycle::default();
+        lifecycle.pause();
+        let handle = lifecycle.park_new(
+            "new".into(),
+            BTreeMap::new(),
+            Arc::new(Ignore),
+            SubscriptionPriority::Normal,
+        );
+        assert!(handle.is_active());
+        assert_eq!(lifecycle.resume().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,278 @@
+//! Keyed list diffs for animated lists.
+//!
+//! `AnimatedList` and `SliverAnimatedList` need to be told which items were
+//! inserted, removed or moved. Diffing thousands of items in Dart on every
+//! update is slow, so [`MobileConvexClient::subscribe_list`] diffs
+//! array-valued results in Rust, matching items by an id field, and delivers
+//! the operations. Results that are not arrays, or have duplicate ids, are
+//! delivered whole.
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use parking_lot::Mutex;
+use serde_json::Value as JsonValue;
+use tokio::sync::mpsc;
+
+use crate::{
+    resubscribe::SubscriptionPriority, ClientError, MobileConvexClient, QuerySubscriber,
+    SubscriptionHandle,
+};
+
+/// One operation of a [`ListUpdate::Diff`]. Indices refer to the list with
+/// all earlier operations applied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum ListChange {
+    /// `item`, serialized as JSON, was inserted at `index`.
+    Insert { index: u32, item: String },
+    /// The item at `index` was removed.
+    Remove { index: u32 },
+    /// The item at `from` was moved to `to`.
+    Move { from: u32, to: u32 },
+    /// The item at `index` kept its id but changed to `item`.
+    Change { index: u32, item: String },
+}
+
+/// An update delivered by [`MobileConvexClient::subscribe_list`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum ListUpdate {
+    /// The whole result, serialized as JSON; replaces the list held by the
+    /// app. Sent first, after errors and for results that cannot be diffed.
+    Reset { value: String },
+    /// Operations turning the previous list into the new one, in order.
+    Diff { changes: Vec<ListChange> },
+}
+
+/// Returns the id of `item`, or the whole item if it has no `id_field`.
+fn item_key(item: &JsonValue, id_field: &str) -> String {
+    match item.get(id_field) {
+        Some(id) => id.to_string(),
+        None => item.to_string(),
+    }
+}
+
+/// Returns the keys of `items`, or `None` if they are not unique.
+fn unique_keys(items: &[JsonValue], id_field: &str) -> Option<Vec<String>> {
+    let keys: Vec<String> = items.iter().map(|item| item_key(item, id_field)).collect();
+    let distinct: HashSet<&String> = keys.iter().collect();
+    (distinct.len() == keys.len()).then_some(keys)
+}
+
+/// Computes the operations turning `old` into `new`: removals from the end,
+/// then moves, inserts and changes from the front.
+fn diff_lists(old: &[JsonValue], new: &[JsonValue], id_field: &str) -> Option<Vec<ListChange>> {
+    let old_keys = unique_keys(old, id_field)?;
+    let new_keys = unique_keys(new, id_field)?;
+    let in_new: HashSet<&String> = new_keys.iter().collect();
+    let mut changes = Vec::new();
+
+    // Keys of the list as the operations so far leave it.
+    let mut current: Vec<&String> = Vec::with_capacity(old.len());
+    for key in &old_keys {
+        if in_new.contains(key) {
+            current.push(key);
+        }
+    }
+    for (index, key) in old_keys.iter().enumerate().rev() {
+        if !in_new.contains(key) {
+            changes.push(ListChange::Remove {
+                index: index as u32,
+            });
+        }
+    }
+
+    let old_items: HashMap<&String, &JsonValue> = old_keys.iter().zip(old).collect();
+    for (to, (key, item)) in new_keys.iter().zip(new).enumerate() {
+        let Some(&old_item) = old_items.get(key) else {
+            current.insert(to, key);
+            changes.push(ListChange::Insert {
+                index: to as u32,
+                item: item.to_string(),
+            });
+            continue;
+        };
+        if current[to] != key {
+            let from = current
+                .iter()
+                .skip(to)
+                .position(|current_key| *current_key == key)?
+                + to;
+            let moved = current.remove(from);
+            current.insert(to, moved);
+            changes.push(ListChange::Move {
+                from: from as u32,
+                to: to as u32,
+            });
+        }
+        if old_item != item {
+            changes.push(ListChange::Change {
+                index: to as u32,
+                item: item.to_string(),
+            });
+        }
+    }
+    Some(changes)
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+struct ListState {
+    last: Option<Vec<JsonValue>>,
+}
+
+impl ListState {
+    /// Returns what to deliver for `value`, and remembers it.
+    fn next(&mut self, value: String, id_field: &str) -> ListUpdate {
+        let Ok(JsonValue::Array(items)) = serde_json::from_str::<JsonValue>(&value) else {
+            self.last = None;
+            return ListUpdate::Reset { value };
+        };
+        let changes = self
+            .last
+            .as_ref()
+            .and_then(|last| diff_lists(last, &items, id_field));
+        self.last = Some(items);
+        match changes {
+            Some(changes) => ListUpdate::Diff { changes },
+            None => ListUpdate::Reset { value },
+        }
+    }
+}
+
+enum ListEvent {
+    Update(ListUpdate),
+    Error(String, Option<String>),
+}
+
+/// Turns list results into diffs, handed in order to a delivery task.
+#[frb(ignore)]
+struct ListSubscriber {
+    state: Mutex<ListState>,
+    id_field: String,
+    events: mpsc::UnboundedSender<ListEvent>,
+}
+
+impl QuerySubscriber for ListSubscriber {
+    fn on_update(&self, value: String) {
+        let update = self.state.lock().next(value, &self.id_field);
+        let _ = self.events.send(ListEvent::Update(update));
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.state.lock().last = None;
+        let _ = self.events.send(ListEvent::Error(message, value));
+    }
+}
+
+impl MobileConvexClient {
+    /// Subscribes to a Convex query returning an array, delivering each
+    /// update as a [`ListUpdate`] against the previous one. Items are
+    /// matched by their `id_field`, e.g. `"_id"`. Callbacks are awaited in
+    /// order, so diffs apply in sequence.
+    #[frb]
+    pub async fn subscribe_list(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        id_field: String,
+        on_update: impl Fn(ListUpdate) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        let args = self.parse_args(args)?;
+        let (events, mut receiver) = mpsc::unbounded_channel();
+        // The delivery task ends once the subscriber is dropped.
+        self.rt.spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                match event {
+                    ListEvent::Update(update) => on_update(update).await,
+                    ListEvent::Error(message, data) => on_error(message, data).await,
+                }
+            }
+        });
+        let subscriber = Arc::new(ListSubscriber {
+            state: Mutex::default(),
+            id_field,
+            events,
+        });
+        self.internal_subscribe(name, args, subscriber, SubscriptionPriority::Normal)
+            .await
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn apply(mut items: Vec<JsonValue>, changes: &[ListChange]) -> Vec<JsonValue> {
+        let parse = |item: &String| serde_json::from_str(item).unwrap();
+        for change in changes {
+            match change {
+                ListChange::Insert { index, item } => items.insert(*index as usize, parse(item)),
+                ListChange::Remove { index } => {
+                    items.remove(*index as usize);
+                }
+                ListChange::Move { from, to } => {
+                    let item = items.remove(*from as usize);
+                    items.insert(*to as usize, item);
+                }
+                ListChange::Change { index, item } => items[*index as usize] = parse(item),
+            }
+        }
+        items
+    }
+
+    fn items(entries: &[(&str, i32)]) -> Vec<JsonValue> {
+        entries
+            .iter()
+            .map(|(id, n)| json!({"_id": id, "n": n}))
+            .collect()
+    }
+
+    #[test]
+    fn diffs_turn_the_old_list_into_the_new_one() {
+        let old = items(&[("a", 1), ("b", 1), ("c", 1), ("d", 1), ("e", 1)]);
+        let new = items(&[("d", 1), ("a", 2), ("f", 1), ("c", 1), ("b", 1)]);
+        let changes = diff_lists(&old, &new, "_id").unwrap();
+        assert_eq!(apply(old, &changes), new);
+        assert!(changes.contains(&ListChange::Remove { index: 4 }));
+        assert!(changes
+            .iter()
+            .any(|c| matches!(c, ListChange::Change { .. })));
+    }
+
+    #[test]
+    fn unchanged_lists_need_no_operations() {
+        let list = items(&[("a", 1), ("b", 2)]);
+        assert_eq!(diff_lists(&list, &list, "_id"), Some(vec![]));
+    }
+
+    #[test]
+    fn lists_that_cannot_be_diffed_are_reset() {
+        let mut state = ListState::default();
+        let first = json!([{"_id": "a"}]).to_string();
+        assert!(matches!(state.next(first, "_id"), ListUpdate::Reset { .. }));
+        let duplicates = json!([{"_id": "a"}, {"_id": "a"}]).to_string();
+        assert!(matches!(
+            state.next(duplicates, "_id"),
+            ListUpdate::Reset { .. }
+        ));
+        let single = json!([{"_id": "a"}]).to_string();
+        assert!(matches!(
+            state.next(single.clone(), "_id"),
+            ListUpdate::Reset { .. }
+        ));
+        assert!(matches!(state.next(single, "_id"), ListUpdate::Diff { .. }));
+        assert!(matches!(
+            state.next("null".into(), "_id"),
+            ListUpdate::Reset { .. }
+        ));
+    }
+}
@@ -0,0 +1,418 @@
+//! Forwarding of the client's logs to Dart.
+//!
+//! The client logs through the `log` facade, which used to reach only
+//! logcat, and only in debug builds. The process now installs a single
+//! logger that hands each record to the platform log as before and, once
+//! [`MobileConvexClient::set_log_listener`] was called, also to a Dart
+//! callback as a [`LogRecord`], in the order the records were logged. The
+//! listener is process-wide: a later call replaces it, and it stops when
+//! the client that registered it is closed.
+//!
+//! The platform log is logcat on Android, the unified log (subsystem
+//! `convex_flutter`) on iOS and macOS, and stderr elsewhere. It receives
+//! errors in debug builds and nothing in release builds unless the app
+//! raises its level with [`MobileConvexClient::set_log_level`], e.g. to
+//! trace connection issues. [`MobileConvexClient::set_log_file`] also
+//! appends the records to a file, e.g. to attach to a bug report. Nothing is
+//! written to the file while an incognito client exists (see
+//! [`crate::options::ClientOptions::incognito`]), and no file can be set
+//! then.
+
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Once,
+    },
+};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use parking_lot::{Mutex, RwLock};
+use tokio::sync::mpsc;
+
+use crate::{presence::now_millis, ClientError, MobileConvexClient};
+
+/// Severity of a log record, or the most verbose severity let through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[frb]
+pub enum LogLevel {
+    /// Nothing is logged; never the level of a record.
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<Level> for LogLevel {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Error => LogLevel::Error,
+            Level::Warn => LogLevel::Warn,
+            Level::Info => LogLevel::Info,
+            Level::Debug => LogLevel::Debug,
+            Level::Trace => LogLevel::Trace,
+        }
+    }
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Off => LevelFilter::Off,
+            LogLevel::Error => LevelFilter::Error,
+            LogLevel::Warn => LevelFilter::Warn,
+            LogLevel::Info => LevelFilter::Info,
+            LogLevel::Debug => LevelFilter::Debug,
+            LogLevel::Trace => LevelFilter::Trace,
+        }
+    }
+}
+
+/// A record logged by the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct LogRecord {
+    pub level: LogLevel,
+    /// Module that logged the record, e.g. `convex_flutter::outbox`.
+    pub target: String,
+    pub message: String,
+    pub timestamp_ms: i64,
+}
+
+/// The Dart listener and the most verbose level it receives.
+struct Listener {
+    level: LevelFilter,
+    records: mpsc::UnboundedSender<LogRecord>,
+}
+
+static LISTENER: RwLock<Option<Listener>> = RwLock::new(None);
+/// Most verbose level written to the platform log.
+static PLATFORM_LEVEL: RwLock<LevelFilter> = RwLock::new(if cfg!(debug_assertions) {
+    LevelFilter::Error
+} else {
+    LevelFilter::Off
+});
+static LOG_FILE: Mutex<Option<LogFile>> = Mutex::new(None);
+/// Number of incognito clients alive, which keep records out of the file.
+static INCOGNITO_CLIENTS: AtomicUsize = AtomicUsize::new(0);
+static INSTALL: Once = Once::new();
+
+/// Size in bytes after which the log file is moved to `<path>.old` and
+/// started over.
+const MAX_LOG_FILE_BYTES: u64 = 4 << 20;
+
+fn platform_level() -> LevelFilter {
+    *PLATFORM_LEVEL.read()
+}
+
+#[cfg(target_os = "android")]
+fn platform_logger() -> Box<dyn Log> {
+    use android_logger::{AndroidLogger, Config};
+
+    // Filtered by `PLATFORM_LEVEL` instead.
+    let config = Config::default().with_max_level(LevelFilter::Trace);
+    Box::new(AndroidLogger::new(config))
+}
+
+#[cfg(any(target_os = "ios", target_os = "macos"))]
+fn platform_logger() -> Box<dyn Log> {
+    Box::new(oslog::OsLogger::new("convex_flutter"))
+}
+
+#[cfg(not(any(target_os = "android", target_os = "ios", target_os = "macos")))]
+fn platform_logger() -> Box<dyn Log> {
+    Box::new(StderrLogger)
+}
+
+/// Writes records to stderr, on platforms without a system log.
+#[cfg(not(any(target_os = "android", target_os = "ios", target_os = "macos")))]
+struct StderrLogger;
+
+#[cfg(not(any(target_os = "android", target_os = "ios", target_os = "macos")))]
+impl Log for StderrLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        eprintln!("[{} {}] {}", record.level(), record.target(), record.args());
+    }
+
+    fn flush(&self) {}
+}
+
+/// Keeps records out of the log file while an incognito client holds it.
+pub(crate) struct IncognitoLogGuard(());
+
+impl IncognitoLogGuard {
+    pub(crate) fn new() -> Self {
+        INCOGNITO_CLIENTS.fetch_add(1, Ordering::SeqCst);
+        IncognitoLogGuard(())
+    }
+}
+
+impl Drop for IncognitoLogGuard {
+    fn drop(&mut self) {
+        INCOGNITO_CLIENTS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+fn incognito_clients_alive() -> bool {
+    INCOGNITO_CLIENTS.load(Ordering::SeqCst) > 0
+}
+
+/// The file records are appended to.
+struct LogFile {
+    path: PathBuf,
+    level: LevelFilter,
+    max_bytes: u64,
+    file: File,
+    written: u64,
+}
+
+impl LogFile {
+    fn open(path: PathBuf, level: LevelFilter, max_bytes: u64) -> io::Result<Self> {
+        let file = File::options().create(true).append(true).open(&path)?;
+        let written = file.metadata()?.len();
+        Ok(LogFile {
+            path,
+            level,
+            max_bytes,
+            file,
+            written,
+        })
+    }
+
+    fn write(&mut self, record: &Record) -> io::Result<()> {
+        let line = format!(
+            "{} {:<5} {}: {}\n",
+            now_millis(),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+        if self.written > 0 && self.written + line.len() as u64 > self.max_bytes {
+            let mut old = self.path.clone().into_os_string();
+            old.push(".old");
+            fs::rename(&self.path, old)?;
+            *self = LogFile::open(self.path.clone(), self.level, self.max_bytes)?;
+        }
+        self.file.write_all(line.as_bytes())?;
+        self.written += line.len() as u64;
+        Ok(())
+    }
+}
+
+/// The logger of the process, dispatching to the platform log, the log
+/// file and the Dart listener.
+struct Dispatcher {
+    platform: Box<dyn Log>,
+}
+
+impl Log for Dispatcher {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if record.level() <= platform_level() {
+            self.platform.log(record);
+        }
+        if let Some(file) = LOG_FILE.lock().as_mut() {
+            if record.level() <= file.level && !incognito_clients_alive() {
+                // Failures cannot be logged; the record is dropped.
+                let _ = file.write(record);
+            }
+        }
+        if let Some(listener) = LISTENER.read().as_ref() {
+            if record.level() <= listener.level {
+                let _ = listener.records.send(LogRecord {
+                    level: record.level().into(),
+                    target: record.target().to_owned(),
+                    message: record.args().to_string(),
+                    timestamp_ms: now_millis(),
+                });
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// The most verbose level anyone receives.
+fn max_level() -> LevelFilter {
+    let listener = LISTENER.read().as_ref().map(|listener| listener.level);
+    let file = LOG_FILE.lock().as_ref().map(|file| file.level);
+    platform_level()
+        .max(listener.unwrap_or(LevelFilter::Off))
+        .max(file.unwrap_or(LevelFilter::Off))
+}
+
+/// Lets records through up to the most verbose level anyone receives.
+fn update_max_level() {
+    log::set_max_level(max_level());
+}
+
+/// Installs the dispatcher as the process's logger, once. Loggers
+/// installed by the app before keep precedence.
+pub(crate) fn install() {
+    INSTALL.call_once(|| {
+        let dispatcher = Dispatcher {
+            platform: platform_logger(),
+        };
+        if log::set_boxed_logger(Box::new(dispatcher)).is_ok() {
+            update_max_level();
+        }
+    });
+}
+
+impl MobileConvexClient {
+    /// Calls `on_record` with every record at `level` or more severe from
+    /// now on, as described in the [module docs](crate::logging).
+    #[frb]
+    pub async fn set_log_listener(
+        &self,
+        level: LogLevel,
+        on_record: impl Fn(LogRecord) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        install();
+        let (records, mut received) = mpsc::unbounded_channel();
+        *LISTENER.write() = Some(Listener {
+            level: level.into(),
+            records,
+        });
+        update_max_level();
+        self.rt.spawn(async move {
+            // Ends once the listener is replaced or removed.
+            while let Some(record) = received.recv().await {
+                on_record(record).await;
+            }
+        });
+        Ok(())
+    }
+
+    /// Writes records at `level` or more severe to the platform log, e.g.
+    /// logcat, from now on, as described in the
+    /// [module docs](crate::logging). Applies to the whole process.
+    #[frb(sync)]
+    pub fn set_log_level(&self, level: LogLevel) {
+        install();
+        *PLATFORM_LEVEL.write() = level.into();
+        update_max_level();
+    }
+
+    /// Appends records at `level` or more severe to the file at `path`, as
+    /// described in the [module docs](crate::logging), or stops writing
+    /// the file if `path` is `None`. Once the file exceeds 4 MiB it is
+    /// moved to `<path>.old` and started over. Applies to the whole
+    /// process. Fails while an incognito client exists.
+    #[frb(sync)]
+    pub fn set_log_file(&self, path: Option<String>, level: LogLevel) -> Result<(), ClientError> {
+        if path.is_some() && incognito_clients_alive() {
+            return Err(ClientError::internal(
+                "No log file can be set while an incognito client exists",
+            ));
+        }
+        install();
+        let file = path
+            .map(|path| LogFile::open(path.into(), level.into(), MAX_LOG_FILE_BYTES))
+            .transpose()
+            .map_err(anyhow::Error::from)?;
+        *LOG_FILE.lock() = file;
+        update_max_level();
+        Ok(())
+    }
+
+    /// Stops forwarding records to the listener set with
+    /// [`MobileConvexClient::set_log_listener`].
+    #[frb(sync)]
+    pub fn remove_log_listener(&self) {
+        *LISTENER.write() = None;
+        update_max_level();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_up_to_the_listener_level_are_forwarded() {
+        let dispatcher = Dispatcher {
+            platform: platform_logger(),
+        };
+        let (records, mut received) = mpsc::unbounded_channel();
+        *LISTENER.write() = Some(Listener {
+            level: LogLevel::Info.into(),
+            records,
+        });
+        for level in [Level::Debug, Level::Warn] {
+            dispatcher.log(
+                &Record::builder()
+                    .level(level)
+                    .target("logging_test")
+                    .args(format_args!("level {level}"))
+                    .build(),
+            );
+        }
+        *LISTENER.write() = None;
+
+        // Other tests may log concurrently.
+        let mut forwarded = Vec::new();
+        while let Ok(record) = received.try_recv() {
+            if record.target == "logging_test" {
+                forwarded.push((record.level, record.message));
+            }
+        }
+        assert_eq!(forwarded, [(LogLevel::Warn, "level WARN".to_owned())]);
+        assert!(dispatcher.enabled(&Metadata::builder().level(Level::Error).build()));
+    }
+
+    #[test]
+    fn incognito_clients_keep_the_log_file_unset() {
+        let options = crate::options::ClientOptions {
+            incognito: true,
+            ..Default::default()
+        };
+        let client = MobileConvexClient::new_with_options(
+            "https://prod.convex.cloud".into(),
+            "test".into(),
+            options,
+        );
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("convex.log");
+        let set = client.set_log_file(Some(path.to_string_lossy().into_owned()), LogLevel::Debug);
+        assert!(set.is_err());
+        assert!(!path.exists());
+        client.set_log_file(None, LogLevel::Debug).unwrap();
+    }
+
+    #[test]
+    fn log_files_are_rotated_once_full() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("convex.log");
+        let mut file = LogFile::open(path.clone(), LevelFilter::Info, 70).unwrap();
+        for message in ["first", "second", "third"] {
+            file.write(
+                &Record::builder()
+                    .level(Level::Info)
+                    .target("sync")
+                    .args(format_args!("{message}"))
+                    .build(),
+            )
+            .unwrap();
+        }
+
+        let current = fs::read_to_string(&path).unwrap();
+        let old = fs::read_to_string(dir.path().join("convex.log.old")).unwrap();
+        assert!(old.contains("INFO  sync: first\n"));
+        assert!(old.contains("INFO  sync: second\n"));
+        assert!(current.ends_with("INFO  sync: third\n"));
+        assert_eq!(current.lines().count(), 1);
+    }
+}
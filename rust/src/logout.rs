@@ -0,0 +1,173 @@
+//! Coordinated logout.
+//!
+//! Signing out used to take several steps in Dart: disposing the auth
+//! handle, clearing auth, cancelling subscriptions made for the user and
+//! wiping caches, each of which could race with the others.
+//! [`MobileConvexClient::logout`] performs all of them natively and reports
+//! completion through a single [`LogoutEvent`].
+//!
+//! Subscriptions made through [`MobileConvexClient::subscribe`] and its
+//! variants while an auth token was set count as auth-scoped and are
+//! cancelled. Mutations already sent cannot be recalled; the client keeps no
+//! queue of unsent writes.
+
+use std::sync::{atomic::Ordering, Arc};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use futures::channel::oneshot::Sender;
+use log::debug;
+use parking_lot::Mutex;
+use tokio::sync::broadcast;
+
+use crate::{
+    auth_changes::AuthChangeReason, ClientError, MobileConvexClient, SubscriptionHandle,
+};
+
+/// Emitted once a logout has completed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct LogoutEvent {
+    /// Number of auth-scoped subscriptions that were cancelled.
+    pub cancelled_subscriptions: u32,
+    /// Whether the on-disk data of the signed-out identity was deleted.
+    pub cleared_user_data: bool,
+}
+
+/// Cancel sender shared with an [`crate::AuthHandle`].
+type RefreshCancel = Arc<Mutex<Option<Sender<()>>>>;
+
+/// Auth-scoped state torn down by a logout.
+pub(crate) struct AuthSession {
+    refresh_cancel: Mutex<Option<RefreshCancel>>,
+    subscriptions: Mutex<Vec<SubscriptionHandle>>,
+    events: broadcast::Sender<LogoutEvent>,
+}
+
+impl AuthSession {
+    pub(crate) fn new() -> Self {
+        AuthSession {
+            refresh_cancel: Mutex::new(None),
+            subscriptions: Mutex::new(Vec::new()),
+            events: broadcast::channel(4).0,
+        }
+    }
+
+    /// Records the cancel sender of the running auth refresh loop.
+    pub(crate) fn track_refresh(&self, cancel_sender: RefreshCancel) {
+        *self.refresh_cancel.lock() = Some(cancel_sender);
+    }
+
+    /// Records a subscription made while authenticated.
+    pub(crate) fn track_subscription(&self, handle: &SubscriptionHandle) {
+        let mut subscriptions = self.subscriptions.lock();
+        subscriptions.retain(SubscriptionHandle::is_active);
+        subscriptions.push(handle.share());
+    }
+
+    fn stop_refresh(&self) {
+        let cancel_sender = self.refresh_cancel.lock().take();
+        if let Some(sender) = cancel_sender.and_then(|sender| sender.lock().take()) {
+            let _ = sender.send(());
+        }
+    }
+
+    /// Cancels all tracked subscriptions, returning how many were active.
+    fn cancel_subscriptions(&self) -> u32 {
+        let subscriptions = std::mem::take(&mut *self.subscriptions.lock());
+        let mut cancelled = 0;
+        for handle in subscriptions.iter().filter(|handle| handle.is_active()) {
+            handle.stop();
+            cancelled += 1;
+        }
+        cancelled
+    }
+}
+
+impl MobileConvexClient {
+    /// Signs the user out: stops the auth refresh loop, clears auth on the
+    /// connection, cancels auth-scoped subscriptions and purges the query
+    /// and action caches. With `clear_user_data`, the identity's on-disk
+    /// data is deleted too, as by [`MobileConvexClient::clear_user_data`].
+    ///
+    /// Listeners registered with [`MobileConvexClient::on_logout`] are
+    /// notified once everything is done.
+    #[frb]
+    pub async fn logout(&self, clear_user_data: bool) -> Result<LogoutEvent, ClientError> {
+        self.ensure_open()?;
+        debug!("Logging out");
+        self.auth_session.stop_refresh();
+        self.auth_generation.fetch_add(1, Ordering::SeqCst);
+        let client = self.client.lock().await.clone();
+        if let Some(mut client) = client {
+            self.rt
+                .spawn(async move { client.set_auth(None).await })
+                .await
+                .map_err(anyhow::Error::from)?;
+        }
+        let identity = self.auth_identity.lock().take();
+        if self.auth_token.lock().take().is_some() {
+            self.auth_changes.emit(false, AuthChangeReason::SignedOut);
+        }
+
+        let cancelled_subscriptions = self.auth_session.cancel_subscriptions();
+        self.query_cache.clear();
+        self.action_cache.clear();
+        self.last_values.clear();
+        if clear_user_data {
+            self.clear_identity_data(identity.as_deref())?;
+        }
+
+        let event = LogoutEvent {
+            cancelled_subscriptions,
+            cleared_user_data: clear_user_data,
+        };
+        let _ = self.auth_session.events.send(event.clone());
+        Ok(event)
+    }
+
+    /// Registers a callback invoked whenever a logout has completed.
+    #[frb]
+    pub async fn on_logout(
+        &self,
+        on_logout: impl Fn(LogoutEvent) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let mut events = self.auth_session.events.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => on_logout(event).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::oneshot;
+
+    use super::*;
+
+    #[test]
+    fn logout_stops_refresh_and_auth_scoped_subscriptions() {
+        let session = AuthSession::new();
+        let (refresh_tx, mut refresh_rx) = oneshot::channel();
+        session.track_refresh(Arc::new(Mutex::new(Some(refresh_tx))));
+        let (active_tx, mut active_rx) = oneshot::channel();
+        let (ended_tx, ended_rx) = oneshot::channel::<()>();
+        let active = SubscriptionHandle::new(active_tx);
+        let ended = SubscriptionHandle::new(ended_tx);
+        session.track_subscription(&active);
+        session.track_subscription(&ended);
+        drop(ended_rx);
+
+        session.stop_refresh();
+        assert_eq!(refresh_rx.try_recv(), Ok(Some(())));
+        assert_eq!(session.cancel_subscriptions(), 1);
+        assert_eq!(active_rx.try_recv(), Ok(Some(())));
+        assert_eq!(session.cancel_subscriptions(), 0);
+    }
+}
@@ -0,0 +1,126 @@
+//! Releasing memory when the device runs low.
+//!
+//! Neither Android nor iOS signals memory pressure to native libraries
+//! directly, so the app forwards `onTrimMemory` or
+//! `didReceiveMemoryWarning` (e.g. via `WidgetsBindingObserver`) to
+//! [`MobileConvexClient::notify_memory_pressure`]. The client then drops
+//! what it can rebuild: cached query and action results and, when pressure
+//! is critical, the values remembered for placeholders. Deferred mutations
+//! are sent right away instead of being kept queued. What was released is
+//! returned and reported to listeners of
+//! [`MobileConvexClient::on_memory_released`].
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::debug;
+use tokio::sync::broadcast;
+
+use crate::{ClientError, MobileConvexClient};
+
+/// Severity of the memory pressure reported by the OS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum MemoryPressure {
+    /// Memory is getting low, e.g. `TRIM_MEMORY_RUNNING_LOW`.
+    Moderate,
+    /// The app is about to be killed, e.g. `TRIM_MEMORY_RUNNING_CRITICAL`
+    /// or an iOS memory warning.
+    Critical,
+}
+
+/// What the client released in response to memory pressure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct MemoryReleasedEvent {
+    pub pressure: MemoryPressure,
+    /// Cached one-shot query results dropped.
+    pub query_cache_entries: u32,
+    /// Memoized action results dropped.
+    pub action_cache_entries: u32,
+    /// Values remembered for watch placeholders dropped.
+    pub placeholder_values: u32,
+    /// Deferred mutations sent early.
+    pub deferred_mutations_sent: u32,
+    /// Approximate size of the dropped results.
+    pub bytes_released: u64,
+}
+
+pub(crate) fn memory_events() -> broadcast::Sender<MemoryReleasedEvent> {
+    broadcast::channel(4).0
+}
+
+impl MobileConvexClient {
+    /// Releases memory the client can do without, according to
+    /// `pressure`, and returns what was released.
+    #[frb]
+    pub async fn notify_memory_pressure(&self, pressure: MemoryPressure) -> MemoryReleasedEvent {
+        let (query_cache_entries, query_bytes) = self.query_cache.release();
+        let (action_cache_entries, action_bytes) = self.action_cache.release();
+        let (placeholder_values, placeholder_bytes) = match pressure {
+            MemoryPressure::Moderate => (0, 0),
+            MemoryPressure::Critical => self.last_values.release(),
+        };
+        let deferred_mutations_sent = self.deferred.queued();
+        if deferred_mutations_sent > 0 {
+            self.flush_deferred().await;
+        }
+        let event = MemoryReleasedEvent {
+            pressure,
+            query_cache_entries: query_cache_entries as u32,
+            action_cache_entries: action_cache_entries as u32,
+            placeholder_values: placeholder_values as u32,
+            deferred_mutations_sent: deferred_mutations_sent as u32,
+            bytes_released: (query_bytes + action_bytes + placeholder_bytes) as u64,
+        };
+        debug!("Released memory under pressure: {event:?}");
+        let _ = self.memory_events.send(event.clone());
+        event
+    }
+
+    /// Registers a callback invoked whenever memory was released in
+    /// response to [`MobileConvexClient::notify_memory_pressure`].
+    #[frb]
+    pub async fn on_memory_released(
+        &self,
+        on_released: impl Fn(MemoryReleasedEvent) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let mut events = self.memory_events.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => on_released(event).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    #[test]
+    fn caches_are_released_by_pressure() {
+        let client = MobileConvexClient::new("https://example.convex.cloud".into(), "test".into());
+        client
+            .query_cache
+            .store("q", BTreeMap::new(), "[1,2,3]".into());
+
+        let event = client
+            .rt
+            .block_on(client.notify_memory_pressure(MemoryPressure::Moderate));
+        assert_eq!(event.query_cache_entries, 1);
+        assert_eq!(event.bytes_released, "q{}[1,2,3]".len() as u64);
+        assert_eq!(client.query_cache.get("q", &BTreeMap::new()), None);
+
+        let event = client
+            .rt
+            .block_on(client.notify_memory_pressure(MemoryPressure::Critical));
+        assert_eq!(event.query_cache_entries, 0);
+        assert_eq!(event.bytes_released, 0);
+    }
+}
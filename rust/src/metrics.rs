@@ -0,0 +1,270 @@
+//! Client health metrics.
+//!
+//! Besides the connection quality, the native layer reports the health of its
+//! own Tokio runtime so sluggish updates can be attributed to the device
+//! (overloaded or blocked worker threads) rather than the network. Poll times
+//! are only exposed by Tokio with `--cfg tokio_unstable`, so a probe task
+//! measures scheduling lag instead: how late a timer wakes up is a direct
+//! measure of how long runnable tasks wait for a worker. The probe starts
+//! when the metrics are first read, so the first read reports no lag yet,
+//! and stops with the client.
+
+use std::{
+    collections::VecDeque,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use flutter_rust_bridge::frb;
+use parking_lot::Mutex;
+use tokio::{runtime::Handle, task::AbortHandle};
+
+use crate::{
+    quality::ConnectionQuality,
+    sampling::{TelemetryClass, TelemetrySampler},
+    MobileConvexClient,
+};
+
+/// How often the probe task samples the runtime.
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+/// Number of samples the lag statistics cover.
+const LAG_WINDOW: usize = 60;
+
+/// Health of the native async runtime.
+#[derive(Debug, Clone, PartialEq)]
+#[frb]
+pub struct RuntimeMetrics {
+    /// Number of worker threads.
+    pub workers: u32,
+    /// Tasks currently alive (spawned and not yet completed).
+    pub alive_tasks: u64,
+    /// Tasks waiting in the global scheduling queue.
+    pub global_queue_depth: u64,
+    /// Fraction of time the workers were busy during the last sample, 0 to 1.
+    /// Values near 1 mean the runtime is saturated or a worker is blocked.
+    pub worker_utilization: f64,
+    /// Average delay before a woken task ran, over the last minute.
+    pub mean_scheduling_lag_ms: f64,
+    /// Longest delay before a woken task ran, over the last minute.
+    pub max_scheduling_lag_ms: f64,
+}
+
+/// Point-in-time snapshot of all client metrics.
+#[derive(Debug, Clone, PartialEq)]
+#[frb]
+pub struct ClientMetrics {
+    pub connection: ConnectionQuality,
+    pub runtime: RuntimeMetrics,
+}
+
+/// Scheduling lag samples over a sliding window.
+#[derive(Debug, Default)]
+#[frb(ignore)]
+struct LagWindow {
+    samples: VecDeque<Duration>,
+}
+
+impl LagWindow {
+    fn push(&mut self, lag: Duration) {
+        if self.samples.len() == LAG_WINDOW {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(lag);
+    }
+
+    fn mean_ms(&self) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let total: Duration = self.samples.iter().sum();
+        total.as_secs_f64() * 1000.0 / self.samples.len() as f64
+    }
+
+    fn max_ms(&self) -> f64 {
+        self.samples
+            .iter()
+            .max()
+            .map_or(0.0, |lag| lag.as_secs_f64() * 1000.0)
+    }
+}
+
+#[derive(Debug, Default)]
+#[frb(ignore)]
+struct ProbeState {
+    lag: LagWindow,
+    utilization: f64,
+}
+
+/// Samples the runtime in a background task.
+pub(crate) struct RuntimeMonitor {
+    handle: Handle,
+    sampler: Arc<TelemetrySampler>,
+    state: Mutex<ProbeState>,
+    probe: Mutex<Option<AbortHandle>>, // Started by the first read
+}
+
+impl Drop for RuntimeMonitor {
+    fn drop(&mut self) {
+        if let Some(probe) = self.probe.get_mut().take() {
+            probe.abort();
+        }
+    }
+}
+
+impl RuntimeMonitor {
+    /// Creates the monitor of `handle`'s runtime. Probes not picked by
+    /// `sampler` are skipped.
+    pub(crate) fn new(handle: Handle, sampler: Arc<TelemetrySampler>) -> Arc<Self> {
+        Arc::new(RuntimeMonitor {
+            handle,
+            sampler,
+            state: Mutex::new(ProbeState::default()),
+            probe: Mutex::new(None),
+        })
+    }
+
+    /// Spawns the probe task unless it is running.
+    fn start_probe(self: &Arc<Self>) {
+        let mut probe = self.probe.lock();
+        if probe.is_none() {
+            *probe = Some(self.spawn_probe());
+        }
+    }
+
+    fn spawn_probe(self: &Arc<Self>) -> AbortHandle {
+        // Only holds a weak reference, so it does not keep the monitor alive.
+        let weak = Arc::downgrade(self);
+        let sampler = self.sampler.clone();
+        let mut busy = total_busy(&self.handle);
+        self.handle
+            .spawn(async move {
+                let mut sampled_at = Instant::now();
+                loop {
+                    let due = tokio::time::Instant::now() + SAMPLE_INTERVAL;
+                    tokio::time::sleep_until(due).await;
+                    let lag = tokio::time::Instant::now().saturating_duration_since(due);
+                    let Some(monitor) = weak.upgrade() else {
+                        break;
+                    };
+                    if !sampler.sample(TelemetryClass::RuntimeProbe, None) {
+                        continue;
+                    }
+                    let now_busy = total_busy(&monitor.handle);
+                    let now = Instant::now();
+                    let utilization = utilization(
+                        now_busy.saturating_sub(busy),
+                        now.duration_since(sampled_at),
+                        monitor.handle.metrics().num_workers(),
+                    );
+                    let mut state = monitor.state.lock();
+                    state.lag.push(lag);
+                    state.utilization = utilization;
+                    busy = now_busy;
+                    sampled_at = now;
+                }
+            })
+            .abort_handle()
+    }
+
+    /// Returns the current metrics, starting the probe on the first call.
+    pub(crate) fn current(self: &Arc<Self>) -> RuntimeMetrics {
+        self.start_probe();
+        let metrics = self.handle.metrics();
+        let state = self.state.lock();
+        RuntimeMetrics {
+            workers: metrics.num_workers() as u32,
+            alive_tasks: metrics.num_alive_tasks() as u64,
+            global_queue_depth: metrics.global_queue_depth() as u64,
+            worker_utilization: state.utilization,
+            mean_scheduling_lag_ms: state.lag.mean_ms(),
+            max_scheduling_lag_ms: state.lag.max_ms(),
+        }
+    }
+}
+
+/// Total time all workers have spent busy since the runtime started.
+fn total_busy(handle: &Handle) -> Duration {
+    let metrics = handle.metrics();
+    (0..metrics.num_workers())
+        .map(|worker| metrics.worker_total_busy_duration(worker))
+        .sum()
+}
+
+/// Share of the available worker time spent busy, clamped to 0..=1.
+fn utilization(busy: Duration, elapsed: Duration, workers: usize) -> f64 {
+    let available = elapsed.as_secs_f64() * workers as f64;
+    if available <= 0.0 {
+        return 0.0;
+    }
+    (busy.as_secs_f64() / available).clamp(0.0, 1.0)
+}
+
+impl MobileConvexClient {
+    /// Returns a snapshot of the connection and runtime health metrics.
+    #[frb(sync)]
+    pub fn metrics(&self) -> ClientMetrics {
+        ClientMetrics {
+            connection: self.quality.current(),
+            runtime: self.runtime_monitor.current(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lag_window_keeps_the_latest_samples() {
+        let mut window = LagWindow::default();
+        assert_eq!(window.mean_ms(), 0.0);
+        assert_eq!(window.max_ms(), 0.0);
+        window.push(Duration::from_millis(500));
+        for _ in 0..LAG_WINDOW {
+            window.push(Duration::from_millis(2));
+        }
+        assert_eq!(window.max_ms(), 2.0);
+        assert!((window.mean_ms() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn utilization_is_relative_to_all_workers() {
+        let second = Duration::from_secs(1);
+        assert_eq!(utilization(second, second, 4), 0.25);
+        assert_eq!(utilization(second * 8, second, 4), 1.0);
+        assert_eq!(utilization(second, Duration::ZERO, 4), 0.0);
+    }
+
+    #[test]
+    fn reports_runtime_shape() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .build()
+            .unwrap();
+        let sampler = Arc::new(TelemetrySampler::new(Default::default()));
+        let monitor = RuntimeMonitor::new(rt.handle().clone(), sampler);
+        let metrics = monitor.current();
+        assert_eq!(metrics.workers, 2);
+        assert_eq!(metrics.worker_utilization, 0.0);
+    }
+
+    #[test]
+    fn probe_runs_from_the_first_read_until_dropped() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let sampler = Arc::new(TelemetrySampler::new(Default::default()));
+        let monitor = RuntimeMonitor::new(rt.handle().clone(), sampler);
+        assert!(monitor.probe.lock().is_none());
+
+        monitor.current();
+        let probe = monitor.probe.lock().clone().unwrap();
+        monitor.current();
+        assert!(!probe.is_finished());
+        drop(monitor);
+        rt.block_on(async {
+            while !probe.is_finished() {
+                tokio::task::yield_now().await;
+            }
+        });
+    }
+}
@@ -0,0 +1,290 @@
+//! One upstream subscription per query and arguments.
+//!
+//! Widgets showing the same data subscribe independently. With
+//! [`crate::options::ClientOptions::share_subscriptions`], subscriptions of
+//! the same function with equal arguments share one upstream subscription
+//! whose results are fanned out to all of them. A subscriber joining later
+//! receives the latest result right away, and the upstream subscription is
+//! cancelled together with its last subscriber. The upstream subscription
+//! keeps the priority of the subscriber that started it.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicU64, AtomicU8, Ordering},
+        Arc,
+    },
+};
+
+use convex::Value;
+use flutter_rust_bridge::frb;
+use futures::{channel::oneshot, pin_mut, select_biased, FutureExt};
+use log::debug;
+use parking_lot::Mutex;
+use tokio::sync::watch;
+
+use crate::{value::value_to_json_string, MobileConvexClient, QuerySubscriber, SubscriptionHandle};
+
+/// Key: function name and the canonical JSON of the arguments.
+type ShareKey = (String, String);
+
+fn share_key(name: &str, args: &BTreeMap<String, Value>) -> ShareKey {
+    (
+        name.to_owned(),
+        value_to_json_string(Value::Object(args.clone())),
+    )
+}
+
+#[derive(Clone)]
+#[frb(ignore)]
+enum Delivery {
+    Update(String),
+    Error(String, Option<String>),
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+struct FanoutState {
+    members: Vec<(u64, Arc<dyn QuerySubscriber>)>,
+    last: Option<Delivery>,
+}
+
+/// Fans the results of one upstream subscription out to its members.
+#[frb(ignore)]
+struct Fanout {
+    state: Mutex<FanoutState>,
+    upstream: Mutex<Option<SubscriptionHandle>>,
+    ended: watch::Sender<bool>,
+}
+
+impl Fanout {
+    fn new() -> Self {
+        Fanout {
+            state: Mutex::default(),
+            upstream: Mutex::new(None),
+            ended: watch::Sender::new(false),
+        }
+    }
+
+    fn is_ended(&self) -> bool {
+        *self.ended.borrow()
+    }
+
+    /// Adds a member, replaying the latest result to it.
+    fn join(&self, id: u64, subscriber: Arc<dyn QuerySubscriber>) {
+        let mut state = self.state.lock();
+        match &state.last {
+            Some(Delivery::Update(value)) => subscriber.on_update(value.clone()),
+            Some(Delivery::Error(message, data)) => {
+                subscriber.on_error(message.clone(), data.clone())
+            }
+            None => {}
+        }
+        state.members.push((id, subscriber));
+    }
+
+    /// Removes a member, returning whether none are left.
+    fn leave(&self, id: u64) -> bool {
+        let mut state = self.state.lock();
+        state.members.retain(|(member, _)| *member != id);
+        state.members.is_empty()
+    }
+
+    /// Records `delivery` as the latest result and returns the members.
+    fn deliver(&self, delivery: Delivery) -> Vec<Arc<dyn QuerySubscriber>> {
+        let mut state = self.state.lock();
+        state.last = Some(delivery);
+        state
+            .members
+            .iter()
+            .map(|(_, member)| member.clone())
+            .collect()
+    }
+
+    /// Keeps the upstream subscription, unless every member already left.
+    fn install(&self, upstream: SubscriptionHandle) {
+        let mut slot = self.upstream.lock();
+        if self.state.lock().members.is_empty() {
+            upstream.stop();
+        } else {
+            *slot = Some(upstream);
+        }
+    }
+
+    /// Ends the fanout after its upstream subscription could not be made.
+    fn abort(&self, message: String) {
+        self.ended.send_replace(true);
+        let members: Vec<_> = std::mem::take(&mut self.state.lock().members);
+        for (_, member) in members {
+            member.on_error(message.clone(), None);
+        }
+    }
+}
+
+impl QuerySubscriber for Fanout {
+    fn on_update(&self, value: String) {
+        for member in self.deliver(Delivery::Update(value.clone())) {
+            member.on_update(value.clone());
+        }
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        for member in self.deliver(Delivery::Error(message.clone(), value.clone())) {
+            member.on_error(message.clone(), value.clone());
+        }
+    }
+
+    fn on_done(&self) {
+        self.ended.send_replace(true);
+        let members: Vec<_> = self.state.lock().members.clone();
+        for (_, member) in members {
+            member.on_done();
+        }
+    }
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct SharedSubscriptions {
+    fanouts: Mutex<HashMap<ShareKey, Arc<Fanout>>>,
+    next_id: AtomicU64,
+}
+
+impl SharedSubscriptions {
+    /// Returns the live fanout of `key` and whether it was just created, in
+    /// which case it still needs an upstream subscription.
+    fn fanout(&self, key: &ShareKey) -> (Arc<Fanout>, bool) {
+        let mut fanouts = self.fanouts.lock();
+        if let Some(fanout) = fanouts.get(key).filter(|fanout| !fanout.is_ended()) {
+            return (fanout.clone(), false);
+        }
+        let fanout = Arc::new(Fanout::new());
+        fanouts.insert(key.clone(), fanout.clone());
+        (fanout, true)
+    }
+
+    fn remove(&self, key: &ShareKey, fanout: &Arc<Fanout>) {
+        let mut fanouts = self.fanouts.lock();
+        if fanouts.get(key).is_some_and(|f| Arc::ptr_eq(f, fanout)) {
+            fanouts.remove(key);
+        }
+    }
+
+    /// Removes member `id`, cancelling the upstream subscription if it was
+    /// the last one.
+    fn leave(&self, key: &ShareKey, fanout: &Arc<Fanout>, id: u64) {
+        if !fanout.leave(id) {
+            return;
+        }
+        self.remove(key, fanout);
+        if let Some(upstream) = fanout.upstream.lock().take() {
+            debug!("Last subscriber of {} left", key.0);
+            upstream.stop();
+        }
+    }
+}
+
+impl MobileConvexClient {
+    /// Subscribes `subscriber` through the upstream subscription shared by
+    /// all subscriptions of `name` with `args`, making it if needed.
+    pub(crate) async fn join_shared_subscription(
+        &self,
+        name: String,
+        args: BTreeMap<String, Value>,
+        subscriber: Arc<dyn QuerySubscriber>,
+        priority: Arc<AtomicU8>,
+    ) -> anyhow::Result<SubscriptionHandle> {
+        let key = share_key(&name, &args);
+        let (fanout, created) = self.shared_subscriptions.fanout(&key);
+        let id = self
+            .shared_subscriptions
+            .next_id
+            .fetch_add(1, Ordering::SeqCst);
+        fanout.join(id, subscriber);
+        if created {
+            let upstream = self
+                .establish_upstream_subscription(name, args, fanout.clone(), priority.clone())
+                .await;
+            match upstream {
+                Ok(upstream) => fanout.install(upstream),
+                Err(e) => {
+                    fanout.leave(id);
+                    self.shared_subscriptions.remove(&key, &fanout);
+                    fanout.abort(e.to_string());
+                    return Err(e);
+                }
+            }
+        } else {
+            debug!("Sharing the subscription to {name}");
+        }
+        let (cancel_sender, cancel_receiver) = oneshot::channel::<()>();
+        let mut ended = fanout.ended.subscribe();
+        let shared = self.shared_subscriptions.clone();
+        self.rt.spawn(async move {
+            let ended_fut = ended.wait_for(|ended| *ended).fuse();
+            pin_mut!(ended_fut);
+            select_biased! {
+                _ = cancel_receiver.fuse() => {},
+                _ = ended_fut => {},
+            }
+            shared.leave(&key, &fanout, id);
+        });
+        Ok(SubscriptionHandle::with_priority(cancel_sender, priority))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl QuerySubscriber for Recorder {
+        fn on_update(&self, value: String) {
+            self.events.lock().push(value);
+        }
+
+        fn on_error(&self, message: String, _value: Option<String>) {
+            self.events.lock().push(format!("error: {message}"));
+        }
+    }
+
+    #[test]
+    fn members_share_results_and_the_last_one_cancels_upstream() {
+        let shared = SharedSubscriptions::default();
+        let key = share_key("messages:list", &BTreeMap::new());
+        let (fanout, created) = shared.fanout(&key);
+        assert!(created);
+        let (first, second) = (Arc::new(Recorder::default()), Arc::new(Recorder::default()));
+        fanout.join(0, first.clone());
+        let (upstream_tx, mut upstream_rx) = oneshot::channel();
+        fanout.install(SubscriptionHandle::new(upstream_tx));
+        fanout.on_update("[1]".into());
+
+        let (same, created) = shared.fanout(&key);
+        assert!(Arc::ptr_eq(&same, &fanout) && !created);
+        same.join(1, second.clone());
+        fanout.on_update("[1,2]".into());
+        assert_eq!(*first.events.lock(), ["[1]", "[1,2]"]);
+        assert_eq!(*second.events.lock(), ["[1]", "[1,2]"]);
+
+        shared.leave(&key, &fanout, 0);
+        assert_eq!(upstream_rx.try_recv(), Ok(None));
+        shared.leave(&key, &fanout, 1);
+        assert_eq!(upstream_rx.try_recv(), Ok(Some(())));
+        assert!(shared.fanout(&key).1);
+    }
+
+    #[test]
+    fn upstream_made_after_everyone_left_is_cancelled() {
+        let fanout = Fanout::new();
+        fanout.join(0, Arc::new(Recorder::default()));
+        fanout.leave(0);
+        let (upstream_tx, mut upstream_rx) = oneshot::channel();
+        fanout.install(SubscriptionHandle::new(upstream_tx));
+        assert_eq!(upstream_rx.try_recv(), Ok(Some(())));
+    }
+}
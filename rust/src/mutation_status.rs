@@ -0,0 +1,178 @@
+//! Lifecycle events of individual mutations.
+//!
+//! A chat showing a single or double tick per message needs to know where
+//! each of its writes is. Mutations made with
+//! [`MobileConvexClient::mutation_tracked`] or
+//! [`MobileConvexClient::mutation_deferred_tracked`] carry an id chosen by
+//! the app, e.g. the local id of the message, and report every step as a
+//! [`MutationLifecycleEvent`] to listeners of
+//! [`MobileConvexClient::on_mutation_lifecycle`]:
+//!
+//! ```text
+//! Queued → Sending → Retrying(n) → Sending → … → Succeeded / Failed
+//! ```
+//!
+//! `Queued` is reported for deferred mutations only, and `Retrying` for
+//! tracked mutations failing for a reason other than the function's own
+//! error, such as the connection. Deferred mutations are not retried.
+
+use std::collections::HashMap;
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::debug;
+use tokio::sync::broadcast;
+
+use crate::{presence::now_millis, ClientError, MobileConvexClient};
+
+/// Where a mutation is in its lifecycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum MutationStatus {
+    /// Waiting in the deferred mutation queue.
+    Queued,
+    /// Sent to the backend, awaiting its result.
+    Sending,
+    /// Attempt `attempt` failed and the mutation will be sent again.
+    Retrying { attempt: u32, error: String },
+    /// Committed by the backend; `completed_at_ms` is the client time the
+    /// result arrived, in milliseconds since the epoch.
+    Succeeded { completed_at_ms: i64 },
+    /// Failed for good.
+    Failed { error: String },
+}
+
+impl MutationStatus {
+    /// Returns whether no further status follows.
+    fn is_final(&self) -> bool {
+        matches!(
+            self,
+            MutationStatus::Succeeded { .. } | MutationStatus::Failed { .. }
+        )
+    }
+}
+
+/// A status change of a tracked mutation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct MutationLifecycleEvent {
+    /// The id the mutation was tracked with.
+    pub mutation_id: String,
+    /// Name of the mutation function.
+    pub name: String,
+    pub status: MutationStatus,
+}
+
+pub(crate) type MutationEvents = broadcast::Sender<MutationLifecycleEvent>;
+
+pub(crate) fn mutation_events() -> MutationEvents {
+    broadcast::channel(64).0
+}
+
+/// Reports `status` of the mutation `mutation_id` to `events`.
+pub(crate) fn report(
+    events: &MutationEvents,
+    mutation_id: &str,
+    name: &str,
+    status: MutationStatus,
+) {
+    if status.is_final() {
+        debug!("Mutation {name} ({mutation_id}) finished: {status:?}");
+    }
+    let _ = events.send(MutationLifecycleEvent {
+        mutation_id: mutation_id.to_owned(),
+        name: name.to_owned(),
+        status,
+    });
+}
+
+/// Returns the final status of a mutation result.
+pub(crate) fn final_status<T>(result: &Result<T, ClientError>) -> MutationStatus {
+    match result {
+        Ok(_) => MutationStatus::Succeeded {
+            completed_at_ms: now_millis(),
+        },
+        Err(error) => MutationStatus::Failed {
+            error: error.to_string(),
+        },
+    }
+}
+
+impl MobileConvexClient {
+    /// Executes a mutation like [`MobileConvexClient::mutation`], reporting
+    /// its lifecycle under `mutation_id`. Attempts failing for a reason
+    /// other than the function's own error are retried with the backoff of
+    /// [`crate::options::ClientOptions::mutation_retry`], up to
+    /// `max_attempts` attempts in total.
+    #[frb]
+    pub async fn mutation_tracked(
+        &self,
+        mutation_id: String,
+        name: String,
+        args: HashMap<String, String>,
+        max_attempts: u32,
+    ) -> Result<String, ClientError> {
+        let parsed = self.parse_args(args).inspect_err(|error| {
+            report(
+                &self.mutation_events,
+                &mutation_id,
+                &name,
+                MutationStatus::Failed {
+                    error: error.to_string(),
+                },
+            )
+        })?;
+        let (result, _) = self
+            .send_with_retries(&mutation_id, &name, parsed, max_attempts)
+            .await;
+        result
+    }
+
+    /// Registers a callback invoked with every status change of tracked
+    /// mutations. A callback still busy with an earlier event may miss some.
+    #[frb]
+    pub async fn on_mutation_lifecycle(
+        &self,
+        on_event: impl Fn(MutationLifecycleEvent) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let mut events = self.mutation_events.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => on_event(event).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn results_map_to_final_statuses() {
+        let events = mutation_events();
+        let mut listener = events.subscribe();
+        report(&events, "m1", "messages:send", MutationStatus::Sending);
+        let failed = Err::<(), _>(ClientError::server("boom".into()));
+        report(&events, "m1", "messages:send", final_status(&failed));
+
+        assert_eq!(listener.try_recv().unwrap().status, MutationStatus::Sending);
+        let event = listener.try_recv().unwrap();
+        assert_eq!(event.mutation_id, "m1");
+        assert_eq!(
+            event.status,
+            MutationStatus::Failed {
+                error: "ServerError: boom".into()
+            }
+        );
+        assert!(event.status.is_final());
+        assert!(matches!(
+            final_status(&Ok(())),
+            MutationStatus::Succeeded { completed_at_ms } if completed_at_ms > 0
+        ));
+    }
+}
@@ -0,0 +1,296 @@
+//! Optimistic updates of subscription results.
+//!
+//! A chat that waits for the server before showing a sent message feels
+//! slow. [`MobileConvexClient::mutation_optimistic`] takes, along with the
+//! mutation, an [`OptimisticUpdate`] per affected query: a JSON Patch applied
+//! right away to the latest result of every subscription to that query and
+//! arguments. Later server values are delivered with the pending patches
+//! applied on top. When the mutation completes, its patches are dropped and
+//! the subscriptions fall back to the server values, which then include the
+//! mutation's writes, since the client resolves a mutation only once its
+//! writes are reflected in query results. A failed mutation is rolled back
+//! the same way. Patches that no longer apply, e.g. because the entry they
+//! edit was removed, are skipped.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use anyhow::Context;
+use convex::Value;
+use flutter_rust_bridge::frb;
+use parking_lot::Mutex;
+use serde_json::Value as JsonValue;
+
+use crate::{
+    value::value_to_json_string, ClientError, MobileConvexClient, QuerySubscriber,
+    SubscriptionHandle,
+};
+
+/// An optimistic change to the results of a query while a mutation is in
+/// flight.
+#[derive(Debug, Clone)]
+#[frb]
+pub struct OptimisticUpdate {
+    /// Name of the query function.
+    pub query_name: String,
+    /// Arguments of the affected subscriptions, as for
+    /// [`MobileConvexClient::subscribe`].
+    pub query_args: HashMap<String, String>,
+    /// An [RFC 6902](https://www.rfc-editor.org/rfc/rfc6902) JSON Patch,
+    /// serialized as JSON, applied to the query's result.
+    pub patch: String,
+}
+
+/// Query key: function name and the canonical JSON of the arguments.
+type QueryKey = (String, String);
+
+fn query_key(name: &str, args: &BTreeMap<String, Value>) -> QueryKey {
+    (
+        name.to_owned(),
+        value_to_json_string(Value::Object(args.clone())),
+    )
+}
+
+/// The patches of one in-flight mutation.
+struct Layer {
+    id: u64,
+    patches: Vec<(QueryKey, json_patch::Patch)>,
+}
+
+type Layers = Arc<Mutex<Vec<Layer>>>;
+
+/// Applies the patches of all pending layers for `key` to `value`, oldest
+/// mutation first.
+fn apply_layers(layers: &[Layer], key: &QueryKey, value: &str) -> String {
+    let mut patches = layers
+        .iter()
+        .flat_map(|layer| &layer.patches)
+        .filter(|(patch_key, _)| patch_key == key)
+        .peekable();
+    if patches.peek().is_none() {
+        return value.to_owned();
+    }
+    let Ok(mut document) = serde_json::from_str::<JsonValue>(value) else {
+        return value.to_owned();
+    };
+    for (_, patch) in patches {
+        // A failed patch leaves the document unchanged.
+        let _ = json_patch::patch(&mut document, patch);
+    }
+    document.to_string()
+}
+
+/// Remembers the latest server value of a subscription and delivers it
+/// with the pending optimistic patches applied.
+#[frb(ignore)]
+pub(crate) struct OptimisticSubscriber {
+    inner: Arc<dyn QuerySubscriber>,
+    key: QueryKey,
+    layers: Layers,
+    // Latest server value; locked while delivering to keep updates in order
+    server_value: Mutex<Option<String>>,
+}
+
+impl OptimisticSubscriber {
+    /// Delivers the latest server value again with the current patches.
+    fn refresh(&self) {
+        let server_value = self.server_value.lock();
+        if let Some(value) = server_value.as_deref() {
+            let view = apply_layers(&self.layers.lock(), &self.key, value);
+            self.inner.on_update(view);
+        }
+    }
+}
+
+impl QuerySubscriber for OptimisticSubscriber {
+    fn on_update(&self, value: String) {
+        let mut server_value = self.server_value.lock();
+        let view = apply_layers(&self.layers.lock(), &self.key, &value);
+        *server_value = Some(value);
+        self.inner.on_update(view);
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.inner.on_error(message, value);
+    }
+
+    fn on_done(&self) {
+        self.inner.on_done();
+    }
+}
+
+struct TrackedSubscriber {
+    subscriber: Arc<OptimisticSubscriber>,
+    handle: SubscriptionHandle,
+}
+
+/// The pending optimistic layers and the subscriptions they apply to.
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct OptimisticStore {
+    layers: Layers,
+    subscribers: Mutex<Vec<TrackedSubscriber>>,
+    next_layer: AtomicU64,
+}
+
+impl OptimisticStore {
+    /// Wraps the subscriber of a subscription to `name` with `args`.
+    pub(crate) fn subscriber(
+        &self,
+        name: &str,
+        args: &BTreeMap<String, Value>,
+        inner: Arc<dyn QuerySubscriber>,
+    ) -> Arc<OptimisticSubscriber> {
+        Arc::new(OptimisticSubscriber {
+            inner,
+            key: query_key(name, args),
+            layers: self.layers.clone(),
+            server_value: Mutex::new(None),
+        })
+    }
+
+    /// Records the subscription controlled by `handle`, so optimistic
+    /// updates reach it.
+    pub(crate) fn register(
+        &self,
+        subscriber: Arc<OptimisticSubscriber>,
+        handle: &SubscriptionHandle,
+    ) {
+        let mut subscribers = self.subscribers.lock();
+        subscribers.retain(|tracked| tracked.handle.is_active());
+        subscribers.push(TrackedSubscriber {
+            subscriber,
+            handle: handle.share(),
+        });
+    }
+
+    /// Redelivers the results of the active subscriptions to `keys`.
+    fn refresh(&self, keys: &[QueryKey]) {
+        let affected: Vec<_> = {
+            let mut subscribers = self.subscribers.lock();
+            subscribers.retain(|tracked| tracked.handle.is_active());
+            subscribers
+                .iter()
+                .filter(|tracked| keys.contains(&tracked.subscriber.key))
+                .map(|tracked| tracked.subscriber.clone())
+                .collect()
+        };
+        for subscriber in affected {
+            subscriber.refresh();
+        }
+    }
+
+    /// Applies `patches` until the layer returned is removed.
+    fn push(&self, patches: Vec<(QueryKey, json_patch::Patch)>) -> u64 {
+        let id = self.next_layer.fetch_add(1, Ordering::SeqCst);
+        let keys: Vec<_> = patches.iter().map(|(key, _)| key.clone()).collect();
+        self.layers.lock().push(Layer { id, patches });
+        self.refresh(&keys);
+        id
+    }
+
+    /// Drops the layer `id` and redelivers what it patched.
+    fn remove(&self, id: u64) {
+        let removed = {
+            let mut layers = self.layers.lock();
+            let position = layers.iter().position(|layer| layer.id == id);
+            position.map(|position| layers.remove(position))
+        };
+        if let Some(layer) = removed {
+            let keys: Vec<_> = layer.patches.into_iter().map(|(key, _)| key).collect();
+            self.refresh(&keys);
+        }
+    }
+}
+
+/// Removes a layer when dropped, also if the mutation's future is.
+struct LayerGuard<'a> {
+    store: &'a OptimisticStore,
+    id: u64,
+}
+
+impl Drop for LayerGuard<'_> {
+    fn drop(&mut self) {
+        self.store.remove(self.id);
+    }
+}
+
+impl MobileConvexClient {
+    /// Executes a mutation like [`MobileConvexClient::mutation`], showing
+    /// `updates` in the affected subscriptions until it completes, as
+    /// described in the [module docs](crate::optimistic).
+    #[frb]
+    pub async fn mutation_optimistic(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        updates: Vec<OptimisticUpdate>,
+    ) -> Result<String, ClientError> {
+        let mut patches = Vec::with_capacity(updates.len());
+        for update in updates {
+            let key = query_key(&update.query_name, &self.parse_args(update.query_args)?);
+            let patch = serde_json::from_str(&update.patch)
+                .with_context(|| format!("Invalid optimistic patch for {}", update.query_name))?;
+            patches.push((key, patch));
+        }
+        let _layer = LayerGuard {
+            store: &self.optimistic,
+            id: self.optimistic.push(patches),
+        };
+        self.mutation(name, args, None).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::oneshot;
+    use maplit::btreemap;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<String>>);
+
+    impl QuerySubscriber for Recorder {
+        fn on_update(&self, value: String) {
+            self.0.lock().push(value);
+        }
+
+        fn on_error(&self, _message: String, _value: Option<String>) {}
+    }
+
+    #[test]
+    fn patches_apply_until_their_layer_is_removed() {
+        let store = OptimisticStore::default();
+        let recorder = Arc::new(Recorder::default());
+        let args = btreemap! {"channel".to_owned() => Value::from("general")};
+        let subscriber = store.subscriber("messages:list", &args, recorder.clone());
+        let (cancel_tx, _cancel_rx) = oneshot::channel();
+        let handle = SubscriptionHandle::new(cancel_tx);
+        store.register(subscriber.clone(), &handle);
+        subscriber.on_update(r#"["a"]"#.into());
+
+        let append = serde_json::from_str(r#"[{"op":"add","path":"/-","value":"b"}]"#).unwrap();
+        let layer = store.push(vec![(query_key("messages:list", &args), append)]);
+        let other = serde_json::from_str(r#"[{"op":"add","path":"/-","value":"x"}]"#).unwrap();
+        let unrelated = store.push(vec![(query_key("messages:list", &BTreeMap::new()), other)]);
+        subscriber.on_update(r#"["a","c"]"#.into());
+        store.remove(layer);
+        store.remove(unrelated);
+
+        assert_eq!(
+            *recorder.0.lock(),
+            [
+                r#"["a"]"#,
+                r#"["a","b"]"#,
+                r#"["a","c","b"]"#,
+                r#"["a","c"]"#
+            ]
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! Client-wide options applied by [`crate::MobileConvexClient`].
+
+use flutter_rust_bridge::frb;
+use serde::Deserialize;
+
+use crate::{
+    audit::AuditLogOptions, budget::UsageBudget, chunked::ChunkedResultOptions, codecs::TypeCodec,
+    connection::ConnectRetryOptions, deferred::DeferredMutationOptions, failover::FailoverOptions,
+    file_storage::FileStorageOptions, persisted_results::PersistedResultOptions,
+    placeholder::ResultCacheOptions, pressure::PressureThrottle, preview::PreviewOptions,
+    request_ids::RequestIdOptions, retry::MutationRetryOptions, sampling::TelemetrySampling,
+    schema_check::SchemaCheckOptions,
+};
+
+/// How `null` values in function arguments are sent to Convex.
+///
+/// Convex distinguishes an absent field from a field set to `null`. Dart has
+/// no `undefined`, so apps that build partial updates from nullable fields can
+/// opt into treating `null` object fields as absent. Independently of this
+/// setting, the JSON marker `{"$undefined": true}` always stands for
+/// `undefined`: it is omitted from objects and becomes `null` inside arrays,
+/// matching `JSON.stringify`.
+///
+/// Results are never rewritten: absent fields stay absent and explicit
+/// `null`s stay `null` in the serialized JSON.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[frb]
+pub enum NullHandling {
+    /// `null` is sent as an explicit Convex `null`.
+    #[default]
+    Preserve,
+    /// Object fields (including top-level arguments) set to `null` are omitted.
+    OmitNullFields,
+}
+
+/// How arguments whose JSON text is empty or only whitespace are treated.
+///
+/// Such text is not valid JSON; it typically comes from an unset text field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[frb]
+pub enum BlankArgHandling {
+    /// The call fails with an error naming the argument.
+    #[default]
+    Error,
+    /// The argument is sent as `null`, subject to [`NullHandling`].
+    Null,
+    /// The argument is left out, as if it were `undefined`.
+    Skip,
+}
+
+/// How `Int64` values are written into JSON results.
+///
+/// JSON numbers lose precision beyond 2^53 in many decoders, so Convex's
+/// tagged form is the default. Structured [`crate::convex_value::ConvexValue`]
+/// results carry exact 64-bit integers regardless of this setting, except
+/// for updates of [`crate::MobileConvexClient::subscribe_typed`] with
+/// [`Int64Encoding::String`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[frb]
+pub enum Int64Encoding {
+    /// `{"$integer": "<base64 little-endian>"}`, as in Convex's JSON format.
+    #[default]
+    Tagged,
+    /// A plain JSON integer, decoded as a Dart `int` on native platforms.
+    Number,
+    /// A decimal string, suitable for `BigInt.parse`.
+    String,
+}
+
+/// Options for constructing a [`crate::MobileConvexClient`].
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct ClientOptions {
+    /// How `null` values in arguments are serialized.
+    pub null_handling: NullHandling,
+    /// Maximum number of subscriptions re-established concurrently after a
+    /// reconnect, ordered by [`crate::resubscribe::SubscriptionPriority`].
+    /// `None` lets the client resend all subscriptions at once.
+    pub max_concurrent_resubscribes: Option<u32>,
+    /// Enables the on-device audit log of queries, subscriptions, mutations
+    /// and actions. Disabled when `None`.
+    pub audit_log: Option<AuditLogOptions>,
+    /// How strongly subscription updates are throttled under UI pressure.
+    pub pressure_throttle: PressureThrottle,
+    /// Directory below which on-disk state is partitioned by deployment and
+    /// auth identity. No state is written to disk when `None`.
+    pub storage_root: Option<String>,
+    /// Serves the client metrics in Prometheus text format on
+    /// `127.0.0.1:<port>`. Only honoured in debug builds.
+    pub debug_metrics_port: Option<u16>,
+    /// Fallback deployments to switch to when the primary is unreachable.
+    /// Failover is disabled when `None`.
+    pub failover: Option<FailoverOptions>,
+    /// Declares that the app intentionally runs without authentication.
+    /// Auth tokens and token refresh are rejected, `is_authenticated` is
+    /// always `false`, and no warnings about missing auth are logged.
+    pub unauthenticated: bool,
+    /// How `Int64` values appear in JSON results and subscription updates.
+    pub int64_encoding: Int64Encoding,
+    /// How arguments with empty or whitespace-only JSON text are treated.
+    pub blank_args: BlankArgHandling,
+    /// How building the Convex client is retried when it fails.
+    pub connect_retry: ConnectRetryOptions,
+    /// Window in milliseconds within which new subscriptions are collected
+    /// and established together. `None` establishes each one immediately.
+    pub subscribe_batch_window_ms: Option<u64>,
+    /// Checks on connect whether the backend changed since the last run.
+    /// Disabled when `None`.
+    pub schema_check: Option<SchemaCheckOptions>,
+    /// Keeps all client state in memory, e.g. for privacy-sensitive flows
+    /// and shared-device kiosk sessions. [`Self::storage_root`] and
+    /// [`Self::audit_log`] are ignored and nothing is written to disk, nor
+    /// to the log file of [`crate::MobileConvexClient::set_log_file`] while
+    /// the client exists.
+    pub incognito: bool,
+    /// Conversions of custom Dart types in arguments and results, as
+    /// described in [`crate::codecs`].
+    pub type_codecs: Vec<TypeCodec>,
+    /// Per-function limits on calls and bandwidth. Unlimited when `None`.
+    pub usage_budget: Option<UsageBudget>,
+    /// Shares one server subscription among subscriptions of the same
+    /// function with equal arguments. A later subscriber receives the latest
+    /// result right away; the shared subscription keeps the priority of the
+    /// first one and is cancelled with the last one.
+    pub share_subscriptions: bool,
+    /// Limits of the queue of [`crate::MobileConvexClient::mutation_deferred`].
+    pub deferred_mutations: DeferredMutationOptions,
+    /// Time in milliseconds after which a build of the Convex client still
+    /// in progress is reported through
+    /// [`crate::MobileConvexClient::on_slow_initialization`]. Not reported
+    /// when `None`.
+    pub slow_initialization_ms: Option<u64>,
+    /// Which telemetry events are recorded; all of them by default. Can be
+    /// changed later with
+    /// [`crate::MobileConvexClient::set_telemetry_sampling`].
+    pub telemetry_sampling: TelemetrySampling,
+    /// How [`crate::MobileConvexClient::connect_preview`] finds preview
+    /// deployments.
+    pub previews: PreviewOptions,
+    /// Backoff and idempotency key of retried mutations, see
+    /// [`crate::retry`].
+    pub mutation_retry: MutationRetryOptions,
+    /// Limits of the last subscription results kept for
+    /// [`crate::MobileConvexClient::get_cached`] and placeholders.
+    pub result_cache: ResultCacheOptions,
+    /// Persists the last subscription results for the next app start, see
+    /// [`crate::persisted_results`]. Requires [`Self::storage_root`].
+    pub result_cache_persistence: Option<PersistedResultOptions>,
+    /// Writes no files until [`crate::MobileConvexClient::set_storage_key`]
+    /// was called, so nothing is stored in plaintext.
+    pub require_storage_key: bool,
+    /// Site URL serving the deployment's HTTP actions, see
+    /// [`crate::http_actions`]. Derived from `*.convex.cloud` deployment URLs
+    /// when `None`.
+    pub http_actions_url: Option<String>,
+    /// How [`crate::MobileConvexClient::upload_file`] gets upload URLs.
+    pub file_storage: FileStorageOptions,
+    /// When [`crate::MobileConvexClient::query_streamed`] and
+    /// [`crate::MobileConvexClient::subscribe_chunked`] split results.
+    pub chunked_results: ChunkedResultOptions,
+    /// How the request IDs of [`crate::request_ids`] are reported.
+    pub request_ids: RequestIdOptions,
+}
+
+impl ClientOptions {
+    /// Drops the settings that write to disk if [`Self::incognito`] is set.
+    pub(crate) fn enforce_incognito(&mut self) {
+        if self.incognito {
+            self.storage_root = None;
+            self.audit_log = None;
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! In-order delivery of Dart callbacks.
+//!
+//! A Dart callback is only invoked once its future is first polled, so
+//! spawning a task per call lets two rapid updates reach Dart in either
+//! order. [`OrderedCalls`] queues the futures of one subscriber and awaits
+//! them one after another on a single task instead.
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use parking_lot::Mutex;
+use tokio::sync::mpsc;
+
+/// Awaits the queued callback futures one at a time, in queue order.
+#[frb(ignore)]
+pub(crate) struct OrderedCalls {
+    queue: mpsc::UnboundedSender<DartFnFuture<()>>,
+    // Taken by the task started with the first call
+    queued: Mutex<Option<mpsc::UnboundedReceiver<DartFnFuture<()>>>>,
+}
+
+impl Default for OrderedCalls {
+    fn default() -> Self {
+        let (queue, queued) = mpsc::unbounded_channel();
+        OrderedCalls {
+            queue,
+            queued: Mutex::new(Some(queued)),
+        }
+    }
+}
+
+impl OrderedCalls {
+    /// Queues `future` after the calls queued before. Must be called within
+    /// the Tokio runtime.
+    pub(crate) fn call(&self, future: DartFnFuture<()>) {
+        if let Some(mut queued) = self.queued.lock().take() {
+            // Ends once the subscriber holding the queue is dropped.
+            tokio::spawn(async move {
+                while let Some(future) = queued.recv().await {
+                    future.await;
+                }
+            });
+        }
+        let _ = self.queue.send(future);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn calls_complete_in_queue_order() {
+        let calls = OrderedCalls::default();
+        let completed = Arc::new(Mutex::new(Vec::new()));
+        for (index, delay_ms) in [(0, 30), (1, 0), (2, 10)] {
+            let completed = completed.clone();
+            calls.call(Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+                completed.lock().push(index);
+            }));
+        }
+        drop(calls);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(*completed.lock(), [0, 1, 2]);
+    }
+}
@@ -0,0 +1,708 @@
+//! Offline outbox of mutations.
+//!
+//! Field apps keep working without a connection.
+//! [`MobileConvexClient::mutation_offline`] sends a mutation right away while
+//! the WebSocket is connected. Otherwise, or if sending fails on the way, the
+//! mutation is appended to the outbox, an SQLite database in the storage
+//! partition of the current identity (see [`crate::storage`]), so it
+//! survives app restarts. Whenever the WebSocket connects, queued mutations
+//! are replayed in order, through interceptors, faults and usage budgets
+//! like any other mutation, and each completion is delivered to the
+//! callbacks registered with [`MobileConvexClient::on_outbox_completion`];
+//! register one at startup so mutations queued by an earlier run are
+//! replayed as well.
+//!
+//! Without a storage root, and for incognito clients, the outbox is kept in
+//! memory only. Queued mutations are encrypted once the app sets a storage
+//! key (see [`crate::storage_encryption`]). A mutation stays queued until
+//! the backend answered it; one that was sent but not answered before the
+//! connection dropped is sent again, so queued mutations should be
+//! idempotent (see [`crate::retry`]).
+//!
+//! A replayed mutation rejected by the function, e.g. because of a
+//! validation conflict with changes made meanwhile, is dropped and reported
+//! with its error. A resolver registered with
+//! [`MobileConvexClient::on_outbox_conflict`] can instead decide to retry it
+//! on the next replay, or to send it again right away with other arguments.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use anyhow::Context;
+use convex::Value;
+use flutter_rust_bridge::{frb, DartFnFuture};
+use futures::{future::BoxFuture, pin_mut, select_biased, FutureExt};
+use log::{debug, warn};
+use parking_lot::Mutex;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use tokio::sync::{broadcast, watch, Notify};
+use uuid::Uuid;
+
+use crate::{
+    args::parse_json_args,
+    presence::now_millis,
+    storage::ScopedStorage,
+    storage_encryption::{is_locked, StorageCipher},
+    value::value_to_json_string,
+    ClientError, MobileConvexClient, WeakClient, WebSocketConnectionState,
+};
+
+/// Name of the outbox database in a storage partition.
+const DATABASE_FILE: &str = "outbox.sqlite";
+
+/// Name of the JSON file earlier versions kept the outbox in, moved into
+/// the database when found.
+const LEGACY_FILE: &str = "outbox.json";
+
+/// What [`MobileConvexClient::mutation_offline`] did with a mutation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum OfflineMutationResult {
+    /// Sent and answered; `value` is the mutation's return value.
+    Completed { value: String },
+    /// Queued in the outbox; its completion is reported under `outbox_id`.
+    Queued { outbox_id: String },
+}
+
+/// The outcome of a mutation replayed from the outbox.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct OutboxCompletion {
+    pub outbox_id: String,
+    /// Name of the mutation function.
+    pub name: String,
+    /// The mutation's return value, serialized as JSON, if it succeeded.
+    pub value: Option<String>,
+    /// Why the mutation failed, if it did.
+    pub error: Option<String>,
+}
+
+/// A replayed mutation rejected by the function, as passed to the resolver
+/// registered with [`MobileConvexClient::on_outbox_conflict`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct OutboxConflict {
+    pub outbox_id: String,
+    /// Name of the mutation function.
+    pub name: String,
+    /// The queued arguments, serialized as a JSON object.
+    pub args: String,
+    /// Why the function rejected the mutation.
+    pub error: String,
+    /// The serialized ConvexError payload, if the function threw one.
+    pub data: Option<String>,
+}
+
+/// What to do with a replayed mutation the function rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum ConflictResolution {
+    /// Keeps it first in the outbox, to be sent again on the next replay,
+    /// i.e. when the WebSocket reconnects or another mutation is queued.
+    Retry,
+    /// Removes it, reporting its error as its completion.
+    Drop,
+    /// Sends it again right away with `args`, given as for
+    /// [`MobileConvexClient::mutation`].
+    Transform { args: HashMap<String, String> },
+}
+
+/// [`ConflictResolution`] with parsed arguments.
+enum Resolved {
+    Retry,
+    Drop,
+    Transform(BTreeMap<String, Value>),
+}
+
+type ConflictResolver = dyn Fn(OutboxConflict) -> BoxFuture<'static, Resolved> + Send + Sync;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OutboxEntry {
+    id: String,
+    name: String,
+    /// Arguments in Convex's JSON format.
+    args: JsonValue,
+    queued_at_ms: i64,
+}
+
+/// The outbox database of one identity, or an in-memory one without
+/// storage.
+struct Store {
+    connection: Connection,
+    on_disk: bool, // Whether entries are written to disk, encrypted
+}
+
+impl Store {
+    fn open(path: Option<&PathBuf>) -> anyhow::Result<Self> {
+        let connection = match path {
+            Some(path) => {
+                if let Some(dir) = path.parent() {
+                    fs::create_dir_all(dir)?;
+                }
+                Connection::open(path).with_context(|| format!("opening {}", path.display()))?
+            }
+            None => Connection::open_in_memory()?,
+        };
+        // Each change is synced before the call returns, so nothing that
+        // was reported as queued is lost to a crash or power loss.
+        connection.execute_batch(
+            "PRAGMA journal_mode = WAL;
+             PRAGMA synchronous = FULL;
+             CREATE TABLE IF NOT EXISTS entries (
+                 seq INTEGER PRIMARY KEY AUTOINCREMENT,
+                 id TEXT NOT NULL UNIQUE,
+                 entry BLOB NOT NULL
+             );",
+        )?;
+        Ok(Store {
+            connection,
+            on_disk: path.is_some(),
+        })
+    }
+
+    fn encode(&self, entry: &OutboxEntry, cipher: &StorageCipher) -> anyhow::Result<Vec<u8>> {
+        let bytes = serde_json::to_vec(entry)?;
+        Ok(if self.on_disk {
+            cipher.encrypt(bytes)?
+        } else {
+            bytes
+        })
+    }
+
+    fn decode(&self, bytes: Vec<u8>, cipher: &StorageCipher) -> anyhow::Result<OutboxEntry> {
+        Ok(serde_json::from_slice(&cipher.decrypt(bytes)?)?)
+    }
+
+    fn insert(&self, entries: &[OutboxEntry], cipher: &StorageCipher) -> anyhow::Result<()> {
+        let transaction = self.connection.unchecked_transaction()?;
+        for entry in entries {
+            transaction.execute(
+                "INSERT OR IGNORE INTO entries (id, entry) VALUES (?1, ?2)",
+                params![entry.id, self.encode(entry, cipher)?],
+            )?;
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Moves the entries of a JSON outbox file written by an earlier
+    /// version into the database. A file that cannot be read before the
+    /// storage key is set is moved once it is.
+    fn migrate(&self, file: &Path, cipher: &StorageCipher) -> anyhow::Result<()> {
+        let bytes = match cipher.read(file) {
+            Ok(bytes) => bytes,
+            Err(e) if is_locked(&e) => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        let entries: Vec<OutboxEntry> = serde_json::from_slice(&bytes)?;
+        self.insert(&entries, cipher)?;
+        fs::remove_file(file)?;
+        Ok(())
+    }
+}
+
+/// The queued mutations and the task replaying them.
+pub(crate) struct Outbox {
+    storage: Option<ScopedStorage>,
+    identity: Arc<Mutex<Option<String>>>,
+    cipher: StorageCipher,
+    // Open databases, by file, or the in-memory one under `None`
+    stores: Mutex<HashMap<Option<PathBuf>, Store>>,
+    wake: Notify,
+    replaying: AtomicBool,
+    conflict_resolver: Mutex<Option<Arc<ConflictResolver>>>,
+    completions: broadcast::Sender<OutboxCompletion>,
+}
+
+impl Outbox {
+    pub(crate) fn new(
+        storage: Option<ScopedStorage>,
+        identity: Arc<Mutex<Option<String>>>,
+        cipher: StorageCipher,
+    ) -> Arc<Self> {
+        Arc::new(Outbox {
+            storage,
+            identity,
+            cipher,
+            stores: Mutex::default(),
+            wake: Notify::new(),
+            replaying: AtomicBool::new(false),
+            conflict_resolver: Mutex::new(None),
+            completions: broadcast::channel(16).0,
+        })
+    }
+
+    /// Path of the current identity's database, or `None` without storage.
+    fn path(&self) -> Option<PathBuf> {
+        let identity = self.identity.lock().clone();
+        let storage = self.storage.as_ref()?;
+        Some(storage.partition(identity.as_deref()).join(DATABASE_FILE))
+    }
+
+    /// Runs `f` with the current identity's store and the cipher on a
+    /// blocking thread, since every write waits for the disk.
+    async fn with_store<T: Send + 'static>(
+        self: &Arc<Self>,
+        f: impl FnOnce(&Store, &StorageCipher) -> anyhow::Result<T> + Send + 'static,
+    ) -> anyhow::Result<T> {
+        let outbox = self.clone();
+        tokio::task::spawn_blocking(move || outbox.with_store_blocking(f)).await?
+    }
+
+    /// Runs `f` on the current identity's store, opening it first if
+    /// needed. A database deleted with the identity's data is opened anew.
+    /// Blocks on the database.
+    fn with_store_blocking<T>(
+        &self,
+        f: impl FnOnce(&Store, &StorageCipher) -> anyhow::Result<T>,
+    ) -> anyhow::Result<T> {
+        let path = self.path();
+        let mut stores = self.stores.lock();
+        if path.as_ref().is_some_and(|path| !path.exists()) {
+            stores.remove(&path);
+        }
+        if !stores.contains_key(&path) {
+            let store = Store::open(path.as_ref())?;
+            stores.insert(path.clone(), store);
+        }
+        let store = &stores[&path];
+        let legacy = path.as_ref().map(|path| path.with_file_name(LEGACY_FILE));
+        if let Some(legacy) = legacy.filter(|legacy| legacy.exists()) {
+            store.migrate(&legacy, &self.cipher)?;
+        }
+        f(store, &self.cipher)
+    }
+
+    async fn push(
+        self: &Arc<Self>,
+        name: String,
+        args: BTreeMap<String, Value>,
+    ) -> anyhow::Result<String> {
+        let entry = OutboxEntry {
+            id: Uuid::new_v4().to_string(),
+            name,
+            args: JsonValue::from(Value::Object(args)),
+            queued_at_ms: now_millis(),
+        };
+        let id = entry.id.clone();
+        self.with_store(move |store, cipher| store.insert(&[entry], cipher))
+            .await?;
+        self.wake.notify_one();
+        Ok(id)
+    }
+
+    /// Returns the number of queued mutations.
+    async fn len(self: &Arc<Self>) -> anyhow::Result<usize> {
+        self.with_store(|store, _| {
+            let len: i64 =
+                store
+                    .connection
+                    .query_row("SELECT COUNT(*) FROM entries", [], |row| row.get(0))?;
+            Ok(len as usize)
+        })
+        .await
+    }
+
+    async fn first(self: &Arc<Self>) -> anyhow::Result<Option<OutboxEntry>> {
+        self.with_store(|store, cipher| {
+            let bytes = store
+                .connection
+                .query_row(
+                    "SELECT entry FROM entries ORDER BY seq LIMIT 1",
+                    [],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            bytes.map(|bytes| store.decode(bytes, cipher)).transpose()
+        })
+        .await
+    }
+
+    async fn remove(self: &Arc<Self>, id: &str) -> anyhow::Result<()> {
+        let id = id.to_owned();
+        self.with_store(move |store, _| {
+            store
+                .connection
+                .execute("DELETE FROM entries WHERE id = ?1", params![id])?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn replace_args(
+        self: &Arc<Self>,
+        id: &str,
+        args: BTreeMap<String, Value>,
+    ) -> anyhow::Result<()> {
+        let id = id.to_owned();
+        self.with_store(move |store, cipher| {
+            let bytes = store
+                .connection
+                .query_row(
+                    "SELECT entry FROM entries WHERE id = ?1",
+                    params![id],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            let Some(bytes) = bytes else {
+                return Ok(());
+            };
+            let mut entry = store.decode(bytes, cipher)?;
+            entry.args = JsonValue::from(Value::Object(args));
+            store.connection.execute(
+                "UPDATE entries SET entry = ?2 WHERE id = ?1",
+                params![id, store.encode(&entry, cipher)?],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    /// Asks the registered resolver what to do with a rejected mutation;
+    /// without one, it is dropped.
+    async fn resolve(&self, entry: &OutboxEntry, error: &ClientError) -> Resolved {
+        let resolver = self.conflict_resolver.lock().clone();
+        let Some(resolver) = resolver else {
+            return Resolved::Drop;
+        };
+        let args = match Value::try_from(entry.args.clone()) {
+            Ok(args) => value_to_json_string(args),
+            Err(_) => entry.args.to_string(),
+        };
+        let data = match error {
+            ClientError::ConvexError { data, .. } => {
+                Some(value_to_json_string(data.clone().into()))
+            }
+            _ => None,
+        };
+        resolver(OutboxConflict {
+            outbox_id: entry.id.clone(),
+            name: entry.name.clone(),
+            args,
+            error: error.to_string(),
+            data,
+        })
+        .await
+    }
+
+    /// Replays queued mutations now if connected, e.g. once queued
+    /// mutations can be decrypted.
+    pub(crate) fn wake_replay(&self) {
+        self.wake.notify_one();
+    }
+
+    /// Sends queued mutations in order until the outbox is empty or sending
+    /// fails.
+    async fn send_queued(self: &Arc<Self>, client: &MobileConvexClient) {
+        loop {
+            let entry = match self.first().await {
+                Ok(Some(entry)) => entry,
+                Ok(None) => return,
+                Err(e) => {
+                    warn!("Reading the outbox failed: {e}");
+                    return;
+                }
+            };
+            let result = match Value::try_from(entry.args.clone()) {
+                Ok(Value::Object(args)) => {
+                    match client.internal_mutation(entry.name.clone(), args).await {
+                        Ok(result) => {
+                            let formatted = client.format_result(result);
+                            if formatted.is_ok() {
+                                client.invalidate_after_mutation(&entry.name).await;
+                            }
+                            formatted
+                        }
+                        Err(e) => {
+                            debug!(
+                                "Replaying {} failed, retrying on reconnect: {e}",
+                                entry.name
+                            );
+                            return;
+                        }
+                    }
+                }
+                _ => Err(anyhow::anyhow!("The queued arguments are invalid").into()),
+            };
+            if let Err(
+                error @ (ClientError::ConvexError { .. } | ClientError::ServerError { .. }),
+            ) = &result
+            {
+                match self.resolve(&entry, error).await {
+                    Resolved::Drop => {}
+                    Resolved::Retry => {
+                        debug!("Keeping rejected {} for the next replay", entry.name);
+                        return;
+                    }
+                    Resolved::Transform(args) => {
+                        if let Err(e) = self.replace_args(&entry.id, args).await {
+                            warn!("Updating {} in the outbox failed: {e}", entry.id);
+                            return;
+                        }
+                        continue;
+                    }
+                }
+            }
+            if let Err(e) = self.remove(&entry.id).await {
+                warn!("Removing {} from the outbox failed: {e}", entry.id);
+                return;
+            }
+            let (value, error) = match result {
+                Ok(value) => (Some(value), None),
+                Err(error) => (None, Some(error.to_string())),
+            };
+            let _ = self.completions.send(OutboxCompletion {
+                outbox_id: entry.id,
+                name: entry.name,
+                value,
+                error,
+            });
+        }
+    }
+
+    /// Replays the outbox whenever the WebSocket connects or mutations are
+    /// queued while connected.
+    async fn replay(
+        self: Arc<Self>,
+        client: WeakClient,
+        mut state: watch::Receiver<WebSocketConnectionState>,
+    ) {
+        loop {
+            let connected = state
+                .wait_for(|state| *state == WebSocketConnectionState::Connected)
+                .await
+                .is_ok();
+            if !connected {
+                return;
+            }
+            let Some(client) = client.upgrade() else {
+                return;
+            };
+            self.send_queued(&client).await;
+            drop(client);
+            let changed_fut = state.changed().fuse();
+            let wake_fut = self.wake.notified().fuse();
+            pin_mut!(changed_fut, wake_fut);
+            select_biased! {
+                changed = changed_fut => if changed.is_err() { return },
+                _ = wake_fut => {},
+            }
+        }
+    }
+}
+
+impl MobileConvexClient {
+    /// Starts replaying the outbox unless already started.
+    fn start_outbox_replay(&self) {
+        if self.outbox.replaying.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        self.rt.spawn(
+            self.outbox
+                .clone()
+                .replay(self.downgrade(), self.connection_state.subscribe()),
+        );
+    }
+
+    /// Executes a mutation now if connected, or queues it in the outbox
+    /// otherwise, as described in the [module docs](crate::outbox).
+    #[frb]
+    pub async fn mutation_offline(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+    ) -> Result<OfflineMutationResult, ClientError> {
+        self.ensure_open()?;
+        let args = self.parse_args(args)?;
+        self.start_outbox_replay();
+        let connected = *self.connection_state.borrow() == WebSocketConnectionState::Connected;
+        if connected {
+            match self.internal_mutation(name.clone(), args.clone()).await {
+                Ok(result) => {
+                    let value = self.format_result(result)?;
+                    self.invalidate_after_mutation(&name).await;
+                    return Ok(OfflineMutationResult::Completed { value });
+                }
+                Err(e) => debug!("Sending {name} failed, queueing it: {e}"),
+            }
+        }
+        let outbox_id = self.outbox.push(name, args).await?;
+        Ok(OfflineMutationResult::Queued { outbox_id })
+    }
+
+    /// Registers the resolver deciding what to do with replayed mutations
+    /// the function rejects, replacing any registered before, as described
+    /// in the [module docs](crate::outbox). Replaying waits for it.
+    #[frb]
+    pub async fn on_outbox_conflict(
+        &self,
+        resolve: impl Fn(OutboxConflict) -> DartFnFuture<ConflictResolution> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let null_handling = self.options.null_handling;
+        let blank_args = self.options.blank_args;
+        let codecs = self.options.type_codecs.clone();
+        let encryption = self.field_encryption.clone();
+        let resolver = move |conflict: OutboxConflict| -> BoxFuture<'static, Resolved> {
+            let resolution = resolve(conflict);
+            let codecs = codecs.clone();
+            let encryption = encryption.clone();
+            Box::pin(async move {
+                match resolution.await {
+                    ConflictResolution::Retry => Resolved::Retry,
+                    ConflictResolution::Drop => Resolved::Drop,
+                    ConflictResolution::Transform { args } => {
+                        let parsed = parse_json_args(args, null_handling, blank_args, &codecs)
+                            .and_then(|args| encryption.encrypt_args(args));
+                        match parsed {
+                            Ok(args) => Resolved::Transform(args),
+                            Err(e) => {
+                                warn!(
+                                    "Dropping a conflicting mutation, invalid new arguments: {e}"
+                                );
+                                Resolved::Drop
+                            }
+                        }
+                    }
+                }
+            })
+        };
+        *self.outbox.conflict_resolver.lock() = Some(Arc::new(resolver));
+        Ok(())
+    }
+
+    /// Returns the number of mutations waiting in the outbox.
+    #[frb]
+    pub async fn outbox_len(&self) -> Result<u32, ClientError> {
+        Ok(self.outbox.len().await? as u32)
+    }
+
+    /// Registers a callback invoked with the outcome of every mutation
+    /// replayed from the outbox, and starts replaying mutations queued by an
+    /// earlier run.
+    #[frb]
+    pub async fn on_outbox_completion(
+        &self,
+        on_completion: impl Fn(OutboxCompletion) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let mut completions = self.outbox.completions.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                match completions.recv().await {
+                    Ok(completion) => on_completion(completion).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        self.start_outbox_replay();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::btreemap;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn queued_mutations_survive_in_the_identity_partition() {
+        let root = tempfile::tempdir().unwrap();
+        let storage = || Some(ScopedStorage::new(root.path(), "https://a.convex.cloud"));
+        let identity = Arc::new(Mutex::new(Some("user-1".to_owned())));
+        let outbox = Outbox::new(storage(), identity.clone(), StorageCipher::new(false));
+        let args = btreemap! { "count".to_owned() => Value::Int64(3) };
+        let first = outbox
+            .push("counter:add".into(), args.clone())
+            .await
+            .unwrap();
+        let second = outbox
+            .push("counter:add".into(), BTreeMap::new())
+            .await
+            .unwrap();
+
+        // Another client of the same identity, e.g. after a restart.
+        let restarted = Outbox::new(storage(), identity.clone(), StorageCipher::new(false));
+        let entry = restarted.first().await.unwrap().unwrap();
+        assert_eq!(entry.id, first);
+        assert_eq!(Value::try_from(entry.args).unwrap(), Value::Object(args));
+        restarted.remove(&first).await.unwrap();
+        assert_eq!(restarted.first().await.unwrap().unwrap().id, second);
+
+        *identity.lock() = Some("user-2".to_owned());
+        assert!(restarted.first().await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn json_outboxes_of_earlier_versions_are_moved_into_the_database() {
+        let root = tempfile::tempdir().unwrap();
+        let storage = ScopedStorage::new(root.path(), "https://a.convex.cloud");
+        let legacy = storage.partition(None).join(LEGACY_FILE);
+        let queued = OutboxEntry {
+            id: "queued".into(),
+            name: "messages:send".into(),
+            args: JsonValue::from(Value::Object(BTreeMap::new())),
+            queued_at_ms: 1,
+        };
+        let cipher = StorageCipher::new(false);
+        cipher
+            .write(&legacy, serde_json::to_vec(&[queued]).unwrap())
+            .unwrap();
+
+        let outbox = Outbox::new(Some(storage), Arc::default(), cipher);
+        let later = outbox
+            .push("messages:send".into(), BTreeMap::new())
+            .await
+            .unwrap();
+        assert!(!legacy.exists());
+        assert_eq!(outbox.len().await.unwrap(), 2);
+        assert_eq!(outbox.first().await.unwrap().unwrap().id, "queued");
+        outbox.remove("queued").await.unwrap();
+        assert_eq!(outbox.first().await.unwrap().unwrap().id, later);
+    }
+
+    #[tokio::test]
+    async fn rejected_mutations_are_resolved_by_the_registered_resolver() {
+        let outbox = Outbox::new(None, Arc::default(), StorageCipher::new(false));
+        let id = outbox
+            .push("todos:rename".into(), BTreeMap::new())
+            .await
+            .unwrap();
+        let entry = outbox.first().await.unwrap().unwrap();
+        let rejection = ClientError::convex("Uncaught ConvexError".into(), Value::from("stale"));
+        assert!(matches!(
+            outbox.resolve(&entry, &rejection).await,
+            Resolved::Drop
+        ));
+
+        let conflicts = Arc::new(Mutex::new(Vec::new()));
+        let seen = conflicts.clone();
+        let resolver = move |conflict: OutboxConflict| -> BoxFuture<'static, Resolved> {
+            seen.lock().push(conflict);
+            let args = btreemap! { "version".to_owned() => Value::Int64(2) };
+            Box::pin(async move { Resolved::Transform(args) })
+        };
+        *outbox.conflict_resolver.lock() = Some(Arc::new(resolver));
+        let Resolved::Transform(args) = outbox.resolve(&entry, &rejection).await else {
+            panic!("expected new arguments");
+        };
+        outbox.replace_args(&id, args.clone()).await.unwrap();
+
+        let conflict = conflicts.lock()[0].clone();
+        assert_eq!(conflict.outbox_id, id);
+        assert_eq!(conflict.args, "{}");
+        assert_eq!(conflict.data.as_deref(), Some(r#""stale""#));
+        let entry = outbox.first().await.unwrap().unwrap();
+        assert_eq!(Value::try_from(entry.args).unwrap(), Value::Object(args));
+    }
+}
@@ -0,0 +1,740 @@
+//! Paginated subscriptions, the equivalent of convex-js `usePaginatedQuery`.
+//!
+//! [`MobileConvexClient::subscribe_paginated`] subscribes to a query taking
+//! `paginationOpts` one page at a time and delivers the items of all loaded
+//! pages as one array. [`PaginatedQueryHandle::load_more`] adds a page
+//! starting at the previous page's `continueCursor`. Before that, the
+//! previous page is re-subscribed with that cursor as its `endCursor`, so
+//! documents inserted later land in exactly one page instead of shifting
+//! across page boundaries. When the backend reports that a page grew too
+//! large (`pageStatus` of `SplitRecommended` or `SplitRequired`), the page
+//! is replaced by two pages split at its `splitCursor`.
+//!
+//! Pages are subscribed directly on the Convex client, like shards (see
+//! [`crate::sharding`]), and are re-established by it after a reconnect.
+//! Convex's sync protocol can also keep page boundaries in query journals,
+//! but the Convex Rust client neither sends nor exposes them. The pinned
+//! end cursors serve the same purpose: a re-established page covers exactly
+//! the documents it covered before. They are delivered as
+//! [`PaginatedUpdate::page_boundaries`], and
+//! [`MobileConvexClient::subscribe_paginated_from`] restores the same pages
+//! from them, e.g. after the app restarted. [`MobileConvexClient::pause`]
+//! unsubscribes all pages and [`MobileConvexClient::resume`] subscribes to
+//! them again with the same cursors.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use convex::{ConvexClient, FunctionResult, Value};
+use flutter_rust_bridge::{frb, DartFnFuture};
+use futures::{pin_mut, select_biased, FutureExt, StreamExt};
+use log::debug;
+use parking_lot::Mutex;
+use tokio::{
+    sync::{mpsc, watch},
+    task::AbortHandle,
+};
+
+use crate::{
+    codecs::{decode_fields, TypeCodec},
+    options::Int64Encoding,
+    value::value_to_json_string_as,
+    ClientError, MobileConvexClient,
+};
+
+/// Argument receiving the pagination options.
+const PAGINATION_OPTS: &str = "paginationOpts";
+
+/// Loading state of a paginated subscription.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum PaginationStatus {
+    /// The first page has not been loaded yet.
+    LoadingFirstPage,
+    /// All requested pages are loaded and more items exist.
+    CanLoadMore,
+    /// A page requested with `load_more` is still loading.
+    LoadingMore,
+    /// All items are loaded.
+    Exhausted,
+}
+
+/// The merged results of a paginated subscription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct PaginatedUpdate {
+    /// The items of all loaded pages, in order, as a JSON array.
+    pub results: String,
+    pub status: PaginationStatus,
+    /// The end cursors of all pages but the last, to pass to
+    /// [`MobileConvexClient::subscribe_paginated_from`].
+    pub page_boundaries: Vec<String>,
+}
+
+/// A page returned by a paginated query.
+#[derive(Debug, Clone, PartialEq)]
+struct PageResult {
+    items: Vec<Value>,
+    is_done: bool,
+    continue_cursor: String,
+    // Set when the backend asks for the page to be split.
+    split_cursor: Option<String>,
+}
+
+impl PageResult {
+    fn parse(value: Value) -> Option<PageResult> {
+        let Value::Object(mut fields) = value else {
+            return None;
+        };
+        let Some(Value::Array(items)) = fields.remove("page") else {
+            return None;
+        };
+        let Some(Value::Boolean(is_done)) = fields.remove("isDone") else {
+            return None;
+        };
+        let Some(Value::String(continue_cursor)) = fields.remove("continueCursor") else {
+            return None;
+        };
+        let split = matches!(
+            fields.get("pageStatus"),
+            Some(Value::String(status)) if status == "SplitRecommended" || status == "SplitRequired"
+        );
+        let split_cursor = match fields.remove("splitCursor") {
+            Some(Value::String(cursor)) if split => Some(cursor),
+            _ => None,
+        };
+        Some(PageResult {
+            items,
+            is_done,
+            continue_cursor,
+            split_cursor,
+        })
+    }
+}
+
+struct Page {
+    id: u64,
+    cursor: Option<String>,
+    end_cursor: Option<String>,
+    num_items: u32,
+    result: Option<PageResult>,
+}
+
+/// A change to the page subscriptions.
+#[derive(Debug, PartialEq)]
+enum PageCommand {
+    Subscribe { page: u64, options: Value },
+    Unsubscribe { page: u64 },
+}
+
+/// The pages of a paginated subscription.
+struct Pagination {
+    query_id: u64,
+    pages: Vec<Page>,
+    next_page: u64,
+}
+
+impl Pagination {
+    /// Starts with a first page of `num_items` items.
+    fn new(query_id: u64, num_items: u32) -> (Self, Vec<PageCommand>) {
+        let mut pagination = Pagination {
+            query_id,
+            pages: Vec::new(),
+            next_page: 0,
+        };
+        let command = pagination.add_page(0, None, None, num_items, None);
+        (pagination, vec![command])
+    }
+
+    /// Starts with the pages ending at `boundaries`, followed by a page of
+    /// `num_items` items.
+    fn restore(query_id: u64, boundaries: Vec<String>, num_items: u32) -> (Self, Vec<PageCommand>) {
+        let mut pagination = Pagination {
+            query_id,
+            pages: Vec::new(),
+            next_page: 0,
+        };
+        let mut cursor = None;
+        let mut commands = Vec::new();
+        for (index, boundary) in boundaries.into_iter().enumerate() {
+            commands.push(pagination.add_page(
+                index,
+                cursor,
+                Some(boundary.clone()),
+                num_items,
+                None,
+            ));
+            cursor = Some(boundary);
+        }
+        let index = pagination.pages.len();
+        commands.push(pagination.add_page(index, cursor, None, num_items, None));
+        (pagination, commands)
+    }
+
+    /// Inserts a page at `index`, returning the command subscribing to it.
+    fn add_page(
+        &mut self,
+        index: usize,
+        cursor: Option<String>,
+        end_cursor: Option<String>,
+        num_items: u32,
+        result: Option<PageResult>,
+    ) -> PageCommand {
+        let id = self.next_page;
+        self.next_page += 1;
+        let page = Page {
+            id,
+            cursor,
+            end_cursor,
+            num_items,
+            result,
+        };
+        let command = self.subscribe(&page);
+        self.pages.insert(index, page);
+        command
+    }
+
+    /// Returns the command subscribing to `page`.
+    fn subscribe(&self, page: &Page) -> PageCommand {
+        let mut options = BTreeMap::from([
+            ("numItems".to_owned(), Value::Float64(page.num_items as f64)),
+            (
+                "cursor".to_owned(),
+                page.cursor.clone().map_or(Value::Null, Value::String),
+            ),
+            ("id".to_owned(), Value::Float64(self.query_id as f64)),
+        ]);
+        if let Some(end_cursor) = &page.end_cursor {
+            options.insert("endCursor".to_owned(), Value::String(end_cursor.clone()));
+        }
+        PageCommand::Subscribe {
+            page: page.id,
+            options: Value::Object(options),
+        }
+    }
+
+    /// Returns the commands subscribing to every page again, e.g. after the
+    /// client resumed.
+    fn resubscribe(&self) -> Vec<PageCommand> {
+        self.pages.iter().map(|page| self.subscribe(page)).collect()
+    }
+
+    /// Records the result of page `id`, splitting the page if asked to.
+    fn on_result(&mut self, id: u64, result: PageResult) -> Vec<PageCommand> {
+        let Some(index) = self.pages.iter().position(|page| page.id == id) else {
+            // A page replaced in the meantime.
+            return Vec::new();
+        };
+        let Some(split_cursor) = result.split_cursor.clone() else {
+            self.pages[index].result = Some(result);
+            return Vec::new();
+        };
+        let page = self.pages.remove(index);
+        let end_cursor = page.end_cursor.unwrap_or(result.continue_cursor);
+        debug!("Splitting page {id} of paginated query {}", self.query_id);
+        vec![
+            PageCommand::Unsubscribe { page: id },
+            self.add_page(
+                index,
+                page.cursor,
+                Some(split_cursor.clone()),
+                page.num_items,
+                None,
+            ),
+            self.add_page(
+                index + 1,
+                Some(split_cursor),
+                Some(end_cursor),
+                page.num_items,
+                None,
+            ),
+        ]
+    }
+
+    /// Adds a page of `num_items` items after the last one, pinning the
+    /// end of the last page. Returns `None` unless more can be loaded.
+    fn load_more(&mut self, num_items: u32) -> Option<Vec<PageCommand>> {
+        if self.status() != PaginationStatus::CanLoadMore {
+            return None;
+        }
+        let index = self.pages.len() - 1;
+        let last = &self.pages[index];
+        let continue_cursor = last.result.as_ref()?.continue_cursor.clone();
+        let mut commands = Vec::new();
+        if last.end_cursor.is_none() {
+            let last = self.pages.remove(index);
+            commands.push(PageCommand::Unsubscribe { page: last.id });
+            commands.push(self.add_page(
+                index,
+                last.cursor,
+                Some(continue_cursor.clone()),
+                last.num_items,
+                last.result,
+            ));
+        }
+        commands.push(self.add_page(index + 1, Some(continue_cursor), None, num_items, None));
+        Some(commands)
+    }
+
+    fn status(&self) -> PaginationStatus {
+        match (self.pages.first(), self.pages.last()) {
+            (Some(Page { result: None, .. }), _) | (None, _) => PaginationStatus::LoadingFirstPage,
+            (_, Some(Page { result: None, .. })) => PaginationStatus::LoadingMore,
+            (
+                _,
+                Some(Page {
+                    result: Some(result),
+                    ..
+                }),
+            ) if result.is_done => PaginationStatus::Exhausted,
+            _ => PaginationStatus::CanLoadMore,
+        }
+    }
+
+    /// The end cursors of the pinned pages.
+    fn boundaries(&self) -> Vec<String> {
+        self.pages
+            .iter()
+            .filter_map(|page| page.end_cursor.clone())
+            .collect()
+    }
+
+    /// The items of the pages loaded so far, up to the first one missing.
+    fn results(&self) -> Vec<Value> {
+        self.pages
+            .iter()
+            .map_while(|page| page.result.as_ref())
+            .flat_map(|result| result.items.iter().cloned())
+            .collect()
+    }
+}
+
+enum PaginationEvent {
+    Update(PaginatedUpdate),
+    Error(String, Option<String>),
+}
+
+/// Opaque type for Dart, controlling a paginated subscription. Dropping
+/// the handle cancels it.
+#[frb(opaque)]
+pub struct PaginatedQueryHandle {
+    status: Arc<Mutex<PaginationStatus>>,
+    // Sends `load_more` requests; dropped to cancel.
+    requests: Mutex<Option<mpsc::UnboundedSender<u32>>>,
+}
+
+impl PaginatedQueryHandle {
+    /// Requests another page of `num_items` items. Returns `false` if no
+    /// more items exist, a page is still loading or the subscription was
+    /// cancelled.
+    #[frb(sync)]
+    pub fn load_more(&self, num_items: u32) -> bool {
+        let mut status = self.status.lock();
+        if *status != PaginationStatus::CanLoadMore {
+            return false;
+        }
+        let sent = self
+            .requests
+            .lock()
+            .as_ref()
+            .is_some_and(|requests| requests.send(num_items.max(1)).is_ok());
+        if sent {
+            *status = PaginationStatus::LoadingMore;
+        }
+        sent
+    }
+
+    /// Returns the current loading state.
+    #[frb(sync)]
+    pub fn status(&self) -> PaginationStatus {
+        *self.status.lock()
+    }
+
+    /// Cancels all page subscriptions.
+    #[frb(sync)]
+    pub fn cancel(&self) {
+        self.requests.lock().take();
+    }
+}
+
+/// Everything the coordinator of a paginated subscription needs.
+struct PaginationTask {
+    client: ConvexClient,
+    name: String,
+    args: BTreeMap<String, Value>,
+    status: Arc<Mutex<PaginationStatus>>,
+    events: mpsc::UnboundedSender<PaginationEvent>,
+    int64_encoding: Int64Encoding,
+    type_codecs: Vec<TypeCodec>,
+}
+
+impl PaginationTask {
+    /// Runs until the handle is cancelled or dropped. Pages are
+    /// unsubscribed while `pause` says the client is paused.
+    async fn run(
+        self,
+        mut pagination: Pagination,
+        commands: Vec<PageCommand>,
+        mut requests: mpsc::UnboundedReceiver<u32>,
+        mut pause: watch::Receiver<bool>,
+    ) {
+        let (results_sender, mut results) = mpsc::unbounded_channel();
+        let mut pages: HashMap<u64, AbortHandle> = HashMap::new();
+        let mut delivered = None;
+        let mut commands = commands;
+        let mut paused = *pause.borrow_and_update();
+        loop {
+            for command in commands.drain(..) {
+                match command {
+                    // Subscribed again on resume.
+                    PageCommand::Subscribe { .. } if paused => {}
+                    PageCommand::Subscribe { page, options } => {
+                        let task = self.subscribe_page(page, options, results_sender.clone());
+                        pages.insert(page, task);
+                    }
+                    PageCommand::Unsubscribe { page } => {
+                        if let Some(task) = pages.remove(&page) {
+                            task.abort();
+                        }
+                    }
+                }
+            }
+            let status = pagination.status();
+            *self.status.lock() = status;
+            let update = PaginatedUpdate {
+                results: self.results_json(pagination.results()),
+                status,
+                page_boundaries: pagination.boundaries(),
+            };
+            if delivered.as_ref() != Some(&update) {
+                delivered = Some(update.clone());
+                let _ = self.events.send(PaginationEvent::Update(update));
+            }
+
+            let pause_changed = {
+                let request = requests.recv().fuse();
+                let result = results.recv().fuse();
+                let pause_changed = pause.changed().fuse();
+                pin_mut!(request, result, pause_changed);
+                select_biased! {
+                    changed = pause_changed => match changed {
+                        Ok(()) => true,
+                        // The client is gone.
+                        Err(_) => break,
+                    },
+                    num_items = request => match num_items {
+                        Some(num_items) => {
+                            commands = pagination.load_more(num_items).unwrap_or_default();
+                            false
+                        }
+                        None => break,
+                    },
+                    result = result => {
+                        let Some((page, result)) = result else {
+                            break;
+                        };
+                        commands = self.on_page_result(&mut pagination, page, result);
+                        false
+                    }
+                }
+            };
+            if pause_changed {
+                paused = *pause.borrow_and_update();
+                if paused {
+                    debug!("Pausing {} pages of {}", pages.len(), self.name);
+                    for (_, task) in pages.drain() {
+                        task.abort();
+                    }
+                } else {
+                    commands = pagination.resubscribe();
+                }
+            }
+        }
+        for task in pages.into_values() {
+            task.abort();
+        }
+        debug!("Paginated subscription to {} canceled", self.name);
+    }
+
+    /// Subscribes to one page, forwarding its results until aborted.
+    fn subscribe_page(
+        &self,
+        page: u64,
+        options: Value,
+        results: mpsc::UnboundedSender<(u64, FunctionResult)>,
+    ) -> AbortHandle {
+        let mut client = self.client.clone();
+        let name = self.name.clone();
+        let mut args = self.args.clone();
+        args.insert(PAGINATION_OPTS.to_owned(), options);
+        tokio::spawn(async move {
+            let mut subscription = match client.subscribe(&name, args).await {
+                Ok(subscription) => subscription,
+                Err(e) => {
+                    let _ = results.send((page, FunctionResult::ErrorMessage(e.to_string())));
+                    return;
+                }
+            };
+            while let Some(result) = subscription.next().await {
+                if results.send((page, result)).is_err() {
+                    break;
+                }
+            }
+        })
+        .abort_handle()
+    }
+
+    fn on_page_result(
+        &self,
+        pagination: &mut Pagination,
+        page: u64,
+        result: FunctionResult,
+    ) -> Vec<PageCommand> {
+        let (message, data) = match result {
+            FunctionResult::Value(value) => match PageResult::parse(value) {
+                Some(result) => return pagination.on_result(page, result),
+                None => (
+                    format!("{} did not return a pagination result", self.name),
+                    None,
+                ),
+            },
+            FunctionResult::ConvexError(error) => (
+                error.message,
+                Some(value_to_json_string_as(error.data, self.int64_encoding)),
+            ),
+            FunctionResult::ErrorMessage(message) => (message, None),
+        };
+        let _ = self.events.send(PaginationEvent::Error(message, data));
+        Vec::new()
+    }
+
+    fn results_json(&self, items: Vec<Value>) -> String {
+        let json = value_to_json_string_as(Value::Array(items), self.int64_encoding);
+        decode_fields(json, &self.type_codecs)
+    }
+}
+
+impl MobileConvexClient {
+    /// Subscribes to a paginated query, starting with a page of
+    /// `initial_num_items` items, as described in the
+    /// [module docs](crate::pagination). `args` must not contain
+    /// `paginationOpts`; it is added per page. Updates carry the items of
+    /// all loaded pages and are awaited in order.
+    #[frb]
+    pub async fn subscribe_paginated(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        initial_num_items: u32,
+        on_update: impl Fn(PaginatedUpdate) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<PaginatedQueryHandle, ClientError> {
+        let query_id = uuid::Uuid::new_v4().as_u64_pair().0 >> 12;
+        let (pagination, commands) = Pagination::new(query_id, initial_num_items.max(1));
+        self.start_paginated(name, args, pagination, commands, on_update, on_error)
+            .await
+    }
+
+    /// Like [`MobileConvexClient::subscribe_paginated`], but starts with the
+    /// pages delimited by `page_boundaries` from an earlier
+    /// [`PaginatedUpdate`], followed by a page of `num_items` items.
+    #[frb]
+    pub async fn subscribe_paginated_from(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        page_boundaries: Vec<String>,
+        num_items: u32,
+        on_update: impl Fn(PaginatedUpdate) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<PaginatedQueryHandle, ClientError> {
+        let query_id = uuid::Uuid::new_v4().as_u64_pair().0 >> 12;
+        let (pagination, commands) =
+            Pagination::restore(query_id, page_boundaries, num_items.max(1));
+        self.start_paginated(name, args, pagination, commands, on_update, on_error)
+            .await
+    }
+
+    /// Starts the coordinator of `pagination`.
+    async fn start_paginated(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        pagination: Pagination,
+        commands: Vec<PageCommand>,
+        on_update: impl Fn(PaginatedUpdate) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<PaginatedQueryHandle, ClientError> {
+        let args = self.parse_args(args)?;
+        if args.contains_key(PAGINATION_OPTS) {
+            return Err(ClientError::internal(format!(
+                "`{PAGINATION_OPTS}` is set per page and must not be passed"
+            )));
+        }
+        let client = self.connected_client().await?;
+        let (events, mut receiver) = mpsc::unbounded_channel();
+        let encryption = self.field_encryption.clone();
+        // The delivery task ends once the coordinator is done.
+        self.rt.spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                match event {
+                    PaginationEvent::Update(mut update) => {
+                        update.results = encryption.decrypt_result(update.results);
+                        on_update(update).await
+                    }
+                    PaginationEvent::Error(message, data) => on_error(message, data).await,
+                }
+            }
+        });
+        let status = Arc::new(Mutex::new(pagination.status()));
+        let (requests, request_receiver) = mpsc::unbounded_channel();
+        let task = PaginationTask {
+            client,
+            name,
+            args,
+            status: status.clone(),
+            events,
+            int64_encoding: self.options.int64_encoding,
+            type_codecs: self.options.type_codecs.clone(),
+        };
+        self.rt.spawn(task.run(
+            pagination,
+            commands,
+            request_receiver,
+            self.lifecycle.watch(),
+        ));
+        Ok(PaginatedQueryHandle {
+            status,
+            requests: Mutex::new(Some(requests)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(items: &[i64], continue_cursor: &str, is_done: bool) -> PageResult {
+        PageResult {
+            items: items.iter().map(|&item| Value::Int64(item)).collect(),
+            is_done,
+            continue_cursor: continue_cursor.into(),
+            split_cursor: None,
+        }
+    }
+
+    fn subscribed(commands: &[PageCommand]) -> Vec<u64> {
+        commands
+            .iter()
+            .filter_map(|command| match command {
+                PageCommand::Subscribe { page, .. } => Some(*page),
+                PageCommand::Unsubscribe { .. } => None,
+            })
+            .collect()
+    }
+
+    fn cursors(pagination: &Pagination) -> Vec<(Option<&str>, Option<&str>)> {
+        pagination
+            .pages
+            .iter()
+            .map(|page| (page.cursor.as_deref(), page.end_cursor.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn pages_are_loaded_pinned_and_merged() {
+        let (mut pagination, commands) = Pagination::new(7, 2);
+        assert_eq!(subscribed(&commands), [0]);
+        assert_eq!(pagination.status(), PaginationStatus::LoadingFirstPage);
+        assert!(pagination.load_more(2).is_none());
+
+        pagination.on_result(0, page(&[1, 2], "c1", false));
+        assert_eq!(pagination.status(), PaginationStatus::CanLoadMore);
+
+        let commands = pagination.load_more(3).unwrap();
+        assert_eq!(commands[0], PageCommand::Unsubscribe { page: 0 });
+        assert_eq!(subscribed(&commands), [1, 2]);
+        assert_eq!(
+            cursors(&pagination),
+            [(None, Some("c1")), (Some("c1"), None)]
+        );
+        assert_eq!(pagination.status(), PaginationStatus::LoadingMore);
+        // The pinned page keeps its items while it is re-subscribed.
+        assert_eq!(pagination.results(), [Value::Int64(1), Value::Int64(2)]);
+
+        pagination.on_result(0, page(&[9], "stale", false));
+        pagination.on_result(2, page(&[3], "c2", true));
+        assert_eq!(pagination.status(), PaginationStatus::Exhausted);
+        assert_eq!(
+            pagination.results(),
+            [Value::Int64(1), Value::Int64(2), Value::Int64(3)]
+        );
+    }
+
+    #[test]
+    fn pages_are_split_at_the_split_cursor() {
+        let (mut pagination, _) = Pagination::new(7, 100);
+        let mut large = page(&[1, 2, 3], "end", false);
+        large.split_cursor = Some("middle".into());
+        let commands = pagination.on_result(0, large);
+        assert_eq!(commands[0], PageCommand::Unsubscribe { page: 0 });
+        assert_eq!(subscribed(&commands), [1, 2]);
+        assert_eq!(
+            cursors(&pagination),
+            [(None, Some("middle")), (Some("middle"), Some("end"))]
+        );
+        let PageCommand::Subscribe {
+            options: Value::Object(options),
+            ..
+        } = &commands[2]
+        else {
+            panic!("expected a subscription");
+        };
+        assert_eq!(options["endCursor"], Value::String("end".into()));
+        assert_eq!(options["id"], Value::Float64(7.0));
+    }
+
+    #[test]
+    fn pages_are_restored_from_their_boundaries() {
+        let (mut pagination, _) = Pagination::new(7, 2);
+        pagination.on_result(0, page(&[1, 2], "c1", false));
+        pagination.load_more(2).unwrap();
+        let boundaries = pagination.boundaries();
+        assert_eq!(boundaries, ["c1"]);
+
+        let (restored, commands) = Pagination::restore(8, boundaries, 2);
+        assert_eq!(subscribed(&commands), [0, 1]);
+        assert_eq!(cursors(&restored), cursors(&pagination));
+        assert_eq!(restored.status(), PaginationStatus::LoadingFirstPage);
+    }
+
+    #[test]
+    fn resuming_subscribes_to_the_same_pages() {
+        let (mut pagination, _) = Pagination::new(7, 2);
+        pagination.on_result(0, page(&[1, 2], "c1", false));
+        let mut loaded = pagination.load_more(2).unwrap();
+        loaded.retain(|command| matches!(command, PageCommand::Subscribe { .. }));
+        assert_eq!(pagination.resubscribe(), loaded);
+        // Pages keep their items until their subscriptions deliver again.
+        assert_eq!(pagination.results().len(), 2);
+    }
+
+    #[test]
+    fn pagination_results_are_parsed() {
+        let value = Value::Object(BTreeMap::from([
+            ("page".into(), Value::Array(vec![Value::Null])),
+            ("isDone".into(), Value::Boolean(false)),
+            ("continueCursor".into(), Value::String("c".into())),
+            ("splitCursor".into(), Value::String("s".into())),
+            (
+                "pageStatus".into(),
+                Value::String("SplitRecommended".into()),
+            ),
+        ]));
+        let parsed = PageResult::parse(value).unwrap();
+        assert_eq!(parsed.split_cursor.as_deref(), Some("s"));
+        assert!(PageResult::parse(Value::Array(vec![])).is_none());
+    }
+}
@@ -0,0 +1,175 @@
+//! Containment and reporting of panics.
+//!
+//! A panic in the client, e.g. a failed `unwrap` in argument parsing, used
+//! to surface in Dart as an opaque `PanicException` at best. Queries,
+//! mutations, actions and subscribe calls now catch panics and fail with
+//! [`ClientError::InternalError`] naming the panic instead, leaving the
+//! client usable. Panics in the client's background tasks end only that
+//! task.
+//!
+//! The client also installs a panic hook, once per process, which logs
+//! every panic and, once [`MobileConvexClient::set_panic_reporter`] was
+//! called, hands it with its backtrace to a Dart callback as a
+//! [`PanicReport`], e.g. for a crash reporting service. Hooks installed
+//! before, such as the default one printing to stderr, still run.
+
+use std::{
+    any::Any,
+    backtrace::Backtrace,
+    future::Future,
+    panic::{self, AssertUnwindSafe, PanicHookInfo},
+    sync::Once,
+    thread,
+};
+
+use anyhow::anyhow;
+use flutter_rust_bridge::{frb, DartFnFuture};
+use futures::FutureExt;
+use log::error;
+use parking_lot::RwLock;
+use tokio::sync::mpsc;
+
+use crate::{presence::now_millis, ClientError, MobileConvexClient};
+
+/// A panic in the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct PanicReport {
+    pub message: String,
+    /// Source location of the panic, e.g. `src/args.rs:42:17`.
+    pub location: Option<String>,
+    /// Name of the panicking thread, if it has one.
+    pub thread: Option<String>,
+    pub backtrace: String,
+    pub timestamp_ms: i64,
+}
+
+static REPORTER: RwLock<Option<mpsc::UnboundedSender<PanicReport>>> = RwLock::new(None);
+static INSTALL: Once = Once::new();
+
+/// Returns the message a panic was raised with.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_owned()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_owned()
+    }
+}
+
+impl PanicReport {
+    fn of(info: &PanicHookInfo) -> Self {
+        PanicReport {
+            message: panic_message(info.payload()),
+            location: info.location().map(ToString::to_string),
+            thread: thread::current().name().map(ToOwned::to_owned),
+            backtrace: Backtrace::force_capture().to_string(),
+            timestamp_ms: now_millis(),
+        }
+    }
+}
+
+/// Installs the panic hook described in the [module docs](crate::panics),
+/// once.
+pub(crate) fn install() {
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let report = PanicReport::of(info);
+            error!(
+                "Panicked at {}: {}",
+                report.location.as_deref().unwrap_or("<unknown>"),
+                report.message
+            );
+            if let Some(reporter) = REPORTER.read().as_ref() {
+                let _ = reporter.send(report);
+            }
+            previous(info);
+        }));
+    });
+}
+
+fn panic_error(payload: Box<dyn Any + Send>) -> anyhow::Error {
+    anyhow!("Panicked: {}", panic_message(payload.as_ref()))
+}
+
+/// Runs `fut`, failing with an internal error if it panics.
+pub(crate) async fn catch_panics<T>(
+    fut: impl Future<Output = anyhow::Result<T>>,
+) -> anyhow::Result<T> {
+    AssertUnwindSafe(fut)
+        .catch_unwind()
+        .await
+        .unwrap_or_else(|payload| Err(panic_error(payload)))
+}
+
+/// Runs `f`, failing with an internal error if it panics.
+pub(crate) fn catch_panics_sync<T>(
+    f: impl FnOnce() -> Result<T, ClientError>,
+) -> Result<T, ClientError> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(panic_error(payload).into()))
+}
+
+impl MobileConvexClient {
+    /// Calls `on_panic` with every panic in the process from now on, as
+    /// described in the [module docs](crate::panics). A later call
+    /// replaces the reporter.
+    #[frb]
+    pub async fn set_panic_reporter(
+        &self,
+        on_panic: impl Fn(PanicReport) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        install();
+        let (reports, mut received) = mpsc::unbounded_channel();
+        *REPORTER.write() = Some(reports);
+        self.rt.spawn(async move {
+            // Ends once the reporter is replaced or removed.
+            while let Some(report) = received.recv().await {
+                on_panic(report).await;
+            }
+        });
+        Ok(())
+    }
+
+    /// Stops handing panics to the reporter set with
+    /// [`MobileConvexClient::set_panic_reporter`].
+    #[frb(sync)]
+    pub fn remove_panic_reporter(&self) {
+        *REPORTER.write() = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn panics_become_internal_errors_and_are_reported() {
+        install();
+        let (reports, mut received) = mpsc::unbounded_channel();
+        *REPORTER.write() = Some(reports);
+
+        let result: anyhow::Result<()> = catch_panics(async { panic!("bad args") }).await;
+        match ClientError::from(result.unwrap_err()) {
+            ClientError::InternalError { msg, .. } => assert_eq!(msg, "Panicked: bad args"),
+            other => panic!("unexpected error: {other:?}"),
+        }
+        // Tests panicking in parallel are reported as well.
+        let report = loop {
+            let report = received.recv().await.unwrap();
+            if report.message == "bad args" {
+                break report;
+            }
+        };
+        assert!(report.location.unwrap().starts_with("src/panics.rs"));
+
+        let empty: Vec<u32> = Vec::new();
+        let result = catch_panics_sync(|| Ok::<_, ClientError>(empty[0]));
+        assert!(matches!(result, Err(ClientError::InternalError { msg, .. })
+            if msg.contains("index out of bounds")));
+        assert!(catch_panics_sync(|| Ok::<_, ClientError>(1)).is_ok());
+        *REPORTER.write() = None;
+    }
+}
@@ -0,0 +1,184 @@
+//! Delivery of subscription updates as JSON patches.
+//!
+//! Subscriptions to large documents transfer the whole serialized value
+//! across the FFI on every change, however small.
+//! [`MobileConvexClient::subscribe_with_patches`] instead delivers an
+//! [RFC 6902](https://www.rfc-editor.org/rfc/rfc6902) JSON Patch computed in
+//! Rust against the previously delivered value. A full snapshot is sent
+//! first, after every error, every `snapshot_interval` updates and whenever
+//! the patch would be larger than the value itself, so the Dart side can
+//! resynchronize.
+
+use std::{collections::HashMap, sync::Arc};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use parking_lot::Mutex;
+use serde_json::Value as JsonValue;
+use tokio::sync::mpsc;
+
+use crate::{
+    resubscribe::SubscriptionPriority, ClientError, MobileConvexClient, QuerySubscriber,
+    SubscriptionHandle,
+};
+
+/// A subscription update delivered by
+/// [`MobileConvexClient::subscribe_with_patches`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum PatchUpdate {
+    /// The full value, serialized as JSON; replaces what the app holds.
+    Snapshot { value: String },
+    /// A JSON Patch, serialized as JSON, to apply to the value held by the
+    /// app.
+    Patch { operations: String },
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+struct PatchState {
+    last: Option<JsonValue>,
+    patches_since_snapshot: u32,
+}
+
+impl PatchState {
+    /// Returns what to deliver for `value`, and remembers it.
+    fn next(&mut self, value: String, snapshot_interval: u32) -> PatchUpdate {
+        let Ok(current) = serde_json::from_str::<JsonValue>(&value) else {
+            self.last = None;
+            return PatchUpdate::Snapshot { value };
+        };
+        let previous = self.last.replace(current);
+        let due = snapshot_interval > 0 && self.patches_since_snapshot >= snapshot_interval;
+        if let (Some(previous), Some(current), false) = (previous, &self.last, due) {
+            let patch = json_patch::diff(&previous, current);
+            let operations = serde_json::to_string(&patch).unwrap_or_default();
+            if !operations.is_empty() && operations.len() < value.len() {
+                self.patches_since_snapshot += 1;
+                return PatchUpdate::Patch { operations };
+            }
+        }
+        self.patches_since_snapshot = 0;
+        PatchUpdate::Snapshot { value }
+    }
+}
+
+enum PatchEvent {
+    Update(PatchUpdate),
+    Error(String, Option<String>),
+}
+
+/// Turns updates into patches, handed in order to a delivery task.
+#[frb(ignore)]
+struct PatchSubscriber {
+    state: Mutex<PatchState>,
+    snapshot_interval: u32,
+    events: mpsc::UnboundedSender<PatchEvent>,
+}
+
+impl QuerySubscriber for PatchSubscriber {
+    fn on_update(&self, value: String) {
+        let update = self.state.lock().next(value, self.snapshot_interval);
+        let _ = self.events.send(PatchEvent::Update(update));
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        // The app may drop its value on errors; start over with a snapshot.
+        self.state.lock().last = None;
+        let _ = self.events.send(PatchEvent::Error(message, value));
+    }
+}
+
+impl MobileConvexClient {
+    /// Subscribes to a Convex query, delivering each update as a
+    /// [`PatchUpdate`] against the previous one. A full snapshot is also
+    /// sent every `snapshot_interval` updates, or only when needed if it is
+    /// `0`. Callbacks are awaited in order, so patches apply in sequence.
+    #[frb]
+    pub async fn subscribe_with_patches(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        snapshot_interval: u32,
+        on_patch: impl Fn(PatchUpdate) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        let args = self.parse_args(args)?;
+        let (events, mut receiver) = mpsc::unbounded_channel();
+        // The delivery task ends once the subscriber is dropped.
+        self.rt.spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                match event {
+                    PatchEvent::Update(update) => on_patch(update).await,
+                    PatchEvent::Error(message, data) => on_error(message, data).await,
+                }
+            }
+        });
+        let subscriber = Arc::new(PatchSubscriber {
+            state: Mutex::default(),
+            snapshot_interval,
+            events,
+        });
+        self.internal_subscribe(name, args, subscriber, SubscriptionPriority::Normal)
+            .await
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use json_patch::Patch;
+    use serde_json::json;
+
+    use super::*;
+
+    fn document(title: &str) -> String {
+        json!({"title": title, "body": "x".repeat(200)}).to_string()
+    }
+
+    #[test]
+    fn patches_apply_to_the_previous_value() {
+        let mut state = PatchState::default();
+        let first = document("draft");
+        assert_eq!(
+            state.next(first.clone(), 0),
+            PatchUpdate::Snapshot {
+                value: first.clone()
+            }
+        );
+        let PatchUpdate::Patch { operations } = state.next(document("final"), 0) else {
+            panic!("expected a patch");
+        };
+        let patch: Patch = serde_json::from_str(&operations).unwrap();
+        let mut value: JsonValue = serde_json::from_str(&first).unwrap();
+        json_patch::patch(&mut value, &patch).unwrap();
+        assert_eq!(value["title"], "final");
+    }
+
+    #[test]
+    fn snapshots_are_sent_periodically_and_when_smaller() {
+        let mut state = PatchState::default();
+        state.next(document("0"), 2);
+        assert!(matches!(
+            state.next(document("1"), 2),
+            PatchUpdate::Patch { .. }
+        ));
+        assert!(matches!(
+            state.next(document("2"), 2),
+            PatchUpdate::Patch { .. }
+        ));
+        assert!(matches!(
+            state.next(document("3"), 2),
+            PatchUpdate::Snapshot { .. }
+        ));
+        assert!(matches!(
+            state.next(document("4"), 2),
+            PatchUpdate::Patch { .. }
+        ));
+
+        state.next("[1]".into(), 0);
+        assert!(matches!(
+            state.next("[2]".into(), 0),
+            PatchUpdate::Snapshot { .. }
+        ));
+    }
+}
@@ -0,0 +1,252 @@
+//! Subscription results persisted across app restarts.
+//!
+//! On a cold start without a connection, every screen shows a spinner
+//! until the WebSocket connects. With
+//! [`crate::options::ClientOptions::result_cache_persistence`] set, the last
+//! subscription results (see [`crate::placeholder`]) are also written to a
+//! file in the storage partition of the current identity (see
+//! [`crate::storage`]), at most every
+//! [`PersistedResultOptions::flush_interval_ms`] and when the client is
+//! paused. After a restart they are available right away:
+//! [`crate::MobileConvexClient::watch`] emits them as
+//! [`crate::placeholder::WatchEvent::Cached`] before connecting, and
+//! [`crate::MobileConvexClient::get_cached`] returns them.
+//!
+//! The file is encrypted once the app sets a storage key (see
+//! [`crate::storage_encryption`]); encrypted results are loaded once it is.
+//! Results older than [`PersistedResultOptions::ttl_ms`] are dropped, as are
+//! the oldest ones beyond [`PersistedResultOptions::max_bytes`]. Nothing is
+//! persisted without a storage root, for incognito clients, or while field
+//! encryption is set.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::{Arc, Weak},
+    time::Duration,
+};
+
+use flutter_rust_bridge::frb;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    placeholder::LastValues,
+    presence::now_millis,
+    storage::ScopedStorage,
+    storage_encryption::{is_locked, StorageCipher},
+};
+
+/// Name of the results file in a storage partition.
+const RESULTS_FILE: &str = "results.json";
+
+/// How subscription results are persisted.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct PersistedResultOptions {
+    /// Age in milliseconds after which a persisted result is dropped.
+    pub ttl_ms: u64,
+    /// Approximate size in bytes of the results file.
+    pub max_bytes: u64,
+    /// Time between writes of changed results.
+    pub flush_interval_ms: u64,
+}
+
+impl Default for PersistedResultOptions {
+    fn default() -> Self {
+        PersistedResultOptions {
+            ttl_ms: 7 * 24 * 60 * 60 * 1_000,
+            max_bytes: 2 << 20,
+            flush_interval_ms: 5_000,
+        }
+    }
+}
+
+/// A subscription result as stored in the results file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PersistedResult {
+    pub(crate) name: String,
+    /// Canonical JSON of the arguments.
+    pub(crate) args: String,
+    pub(crate) value: String,
+    pub(crate) stored_at_ms: i64,
+}
+
+impl PersistedResult {
+    fn size(&self) -> usize {
+        self.name.len() + self.args.len() + self.value.len()
+    }
+}
+
+/// The results file of the current identity.
+pub(crate) struct ResultPersistence {
+    storage: ScopedStorage,
+    identity: Arc<Mutex<Option<String>>>,
+    options: PersistedResultOptions,
+    cipher: StorageCipher,
+    // File whose results were loaded into memory
+    loaded: Mutex<Option<PathBuf>>,
+}
+
+impl ResultPersistence {
+    pub(crate) fn new(
+        storage: ScopedStorage,
+        identity: Arc<Mutex<Option<String>>>,
+        options: PersistedResultOptions,
+        cipher: StorageCipher,
+    ) -> Self {
+        ResultPersistence {
+            storage,
+            identity,
+            options,
+            cipher,
+            loaded: Mutex::new(None),
+        }
+    }
+
+    fn file(&self) -> PathBuf {
+        let identity = self.identity.lock().clone();
+        self.storage
+            .partition(identity.as_deref())
+            .join(RESULTS_FILE)
+    }
+
+    fn is_fresh(&self, result: &PersistedResult, now_ms: i64) -> bool {
+        now_ms.saturating_sub(result.stored_at_ms) <= self.options.ttl_ms as i64
+    }
+
+    fn load(&self, file: &Path) -> io::Result<Vec<PersistedResult>> {
+        let results: Vec<PersistedResult> = match self.cipher.read(file) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e),
+        };
+        let now_ms = now_millis();
+        Ok(results
+            .into_iter()
+            .filter(|result| self.is_fresh(result, now_ms))
+            .collect())
+    }
+
+    /// Returns the results persisted for the current identity, unless they
+    /// are already in memory or encrypted with a key not set yet. A file
+    /// that cannot be read otherwise counts as empty.
+    pub(crate) fn take_unloaded(&self) -> Option<Vec<PersistedResult>> {
+        let file = self.file();
+        let mut loaded = self.loaded.lock();
+        if loaded.as_ref() == Some(&file) {
+            return None;
+        }
+        let results = match self.load(&file) {
+            Ok(results) => results,
+            Err(e) if is_locked(&e) => return None,
+            Err(e) => {
+                log::warn!("Discarding unreadable persisted results: {e}");
+                Vec::new()
+            }
+        };
+        *loaded = Some(file);
+        Some(results)
+    }
+
+    /// Makes the next [`Self::take_unloaded`] read the file again, e.g.
+    /// after the results in memory were dropped.
+    pub(crate) fn forget_loaded(&self) {
+        *self.loaded.lock() = None;
+    }
+
+    /// Replaces the persisted results of the current identity with the
+    /// newest of `results`.
+    pub(crate) fn save(&self, mut results: Vec<PersistedResult>) -> io::Result<()> {
+        let file = self.file();
+        if self.loaded.lock().as_ref() != Some(&file) {
+            // The results in memory belong to another identity.
+            return Ok(());
+        }
+        let now_ms = now_millis();
+        results.retain(|result| self.is_fresh(result, now_ms));
+        results.sort_by_key(|result| std::cmp::Reverse(result.stored_at_ms));
+        let mut bytes = 0;
+        results.retain(|result| {
+            bytes += result.size();
+            bytes as u64 <= self.options.max_bytes
+        });
+        self.cipher.write(&file, serde_json::to_vec(&results)?)
+    }
+}
+
+/// Writes changed results of `last_values` every `flush_interval_ms` until
+/// the client is dropped.
+pub(crate) fn spawn_flush_loop(
+    rt: &tokio::runtime::Handle,
+    last_values: Weak<LastValues>,
+    options: &PersistedResultOptions,
+) {
+    let interval = Duration::from_millis(options.flush_interval_ms.max(1));
+    rt.spawn(async move {
+        loop {
+            tokio::time::sleep(interval).await;
+            let Some(last_values) = last_values.upgrade() else {
+                break;
+            };
+            last_values.flush();
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(name: &str, value: &str, stored_at_ms: i64) -> PersistedResult {
+        PersistedResult {
+            name: name.into(),
+            args: "{}".into(),
+            value: value.into(),
+            stored_at_ms,
+        }
+    }
+
+    #[test]
+    fn newest_fresh_results_are_kept_per_identity() {
+        let root = tempfile::tempdir().unwrap();
+        let storage = ScopedStorage::new(root.path(), "https://a.convex.cloud");
+        let identity = Arc::new(Mutex::new(Some("user-1".to_owned())));
+        let options = PersistedResultOptions {
+            ttl_ms: 60_000,
+            max_bytes: 40,
+            flush_interval_ms: 1_000,
+        };
+        let cipher = StorageCipher::new(false);
+        let persistence = ResultPersistence::new(
+            storage.clone(),
+            identity.clone(),
+            options.clone(),
+            cipher.clone(),
+        );
+        assert_eq!(persistence.take_unloaded(), Some(Vec::new()));
+        assert_eq!(persistence.take_unloaded(), None);
+
+        let now_ms = now_millis();
+        persistence
+            .save(vec![
+                result("a", "1", now_ms - 10),
+                result("b", "2", now_ms),
+                result("expired", "3", now_ms - 120_000),
+                result("c", &"x".repeat(30), now_ms - 20),
+            ])
+            .unwrap();
+
+        // A restarted client of the same identity.
+        let restarted = ResultPersistence::new(storage, identity.clone(), options, cipher);
+        let restored = restarted.take_unloaded().unwrap();
+        assert_eq!(
+            restored,
+            [result("b", "2", now_ms), result("a", "1", now_ms - 10)]
+        );
+        *identity.lock() = Some("user-2".to_owned());
+        assert_eq!(restarted.take_unloaded(), Some(Vec::new()));
+    }
+}
@@ -0,0 +1,463 @@
+//! What to show before the first server value of a subscription.
+//!
+//! Widgets built on subscriptions each decided on their own whether to show
+//! a spinner, stale data or nothing until the first result arrives.
+//! [`MobileConvexClient::watch`] takes a [`PlaceholderPolicy`] and emits the
+//! placeholder in Rust, ahead of any server value, so all of them behave the
+//! same. Cached values are the last results delivered to any subscription
+//! of the same query and arguments, or else those of
+//! [`MobileConvexClient::cached_query`].
+//!
+//! The last results are also returned by [`MobileConvexClient::get_cached`],
+//! so a screen can render right away while its subscription starts. How many
+//! are kept is set by [`crate::options::ClientOptions::result_cache`] or
+//! [`MobileConvexClient::configure_result_cache`], and they can be kept
+//! across app restarts (see [`crate::persisted_results`]).
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use convex::Value;
+use flutter_rust_bridge::{frb, DartFnFuture};
+use parking_lot::Mutex;
+use serde::Deserialize;
+
+use crate::{
+    encryption::FieldEncryption,
+    events::ClientEvent,
+    ordered::OrderedCalls,
+    persisted_results::{PersistedResult, ResultPersistence},
+    presence::now_millis,
+    resubscribe::SubscriptionPriority,
+    storage_encryption::is_locked,
+    value::value_to_json_string,
+    ClientError, MobileConvexClient, QuerySubscriber, SubscriptionHandle,
+};
+
+/// Limits of the last subscription results kept by the client. The least
+/// recently updated results are evicted first.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct ResultCacheOptions {
+    /// Number of results kept; `0` disables the cache.
+    pub max_entries: u32,
+    /// Approximate total size in bytes of the results kept.
+    pub max_bytes: u64,
+}
+
+impl Default for ResultCacheOptions {
+    fn default() -> Self {
+        ResultCacheOptions {
+            max_entries: 256,
+            max_bytes: 8 << 20,
+        }
+    }
+}
+
+/// What [`MobileConvexClient::watch`] emits before the first server value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[frb]
+pub enum PlaceholderPolicy {
+    /// The last known value as [`WatchEvent::Cached`], or
+    /// [`WatchEvent::Loading`] if there is none.
+    #[default]
+    Cached,
+    /// [`WatchEvent::Loading`], even if a value is cached.
+    Loading,
+    /// Nothing; the first event is the first server value or error.
+    Wait,
+}
+
+/// An event of a [`MobileConvexClient::watch`] subscription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum WatchEvent {
+    /// No value is available yet.
+    Loading,
+    /// A cached value, shown until the server's value arrives.
+    Cached { value: String },
+    /// A value from the server, serialized as JSON.
+    Update { value: String },
+    /// The query failed; `data` holds the serialized ConvexError payload, if any.
+    Error {
+        message: String,
+        data: Option<String>,
+    },
+}
+
+/// Cache key: function name and the canonical JSON of the arguments.
+type CacheKey = (String, String);
+
+fn cache_key(name: &str, args: &BTreeMap<String, Value>) -> CacheKey {
+    (
+        name.to_owned(),
+        value_to_json_string(Value::Object(args.clone())),
+    )
+}
+
+fn entry_size((name, args): &CacheKey, value: &str) -> usize {
+    name.len() + args.len() + value.len()
+}
+
+#[frb(ignore)]
+struct CachedValue {
+    tick: u64,
+    value: String,
+    stored_at_ms: i64,
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+struct Entries {
+    values: HashMap<CacheKey, CachedValue>,
+    bytes: usize,
+}
+
+impl Entries {
+    /// Evicts the least recently updated values until both limits are met.
+    fn evict(&mut self, options: &ResultCacheOptions) {
+        while self.values.len() > options.max_entries as usize
+            || self.bytes as u64 > options.max_bytes
+        {
+            let oldest = self
+                .values
+                .iter()
+                .min_by_key(|(_, cached)| cached.tick)
+                .map(|(key, _)| key.clone());
+            let Some(oldest) = oldest else { break };
+            if let Some(cached) = self.values.remove(&oldest) {
+                self.bytes -= entry_size(&oldest, &cached.value);
+            }
+        }
+    }
+
+    /// Adds or replaces the value of `key`.
+    fn insert(&mut self, key: CacheKey, value: CachedValue) {
+        self.bytes += entry_size(&key, &value.value);
+        if let Some(replaced) = self.values.insert(key.clone(), value) {
+            self.bytes -= entry_size(&key, &replaced.value);
+        }
+    }
+}
+
+/// The last values delivered to subscriptions, least recently updated
+/// evicted first, optionally persisted (see [`crate::persisted_results`]).
+pub(crate) struct LastValues {
+    entries: Mutex<Entries>,
+    options: Mutex<ResultCacheOptions>,
+    clock: AtomicU64,
+    persistence: Option<ResultPersistence>,
+    dirty: AtomicBool, // Whether values changed since they were persisted
+}
+
+impl LastValues {
+    pub(crate) fn new(options: ResultCacheOptions, persistence: Option<ResultPersistence>) -> Self {
+        LastValues {
+            entries: Mutex::default(),
+            options: Mutex::new(options),
+            // Restored values are older than any stored at runtime.
+            clock: AtomicU64::new(1),
+            persistence,
+            dirty: AtomicBool::new(false),
+        }
+    }
+
+    /// Replaces the values in memory with the persisted ones when they
+    /// were not loaded yet, e.g. at startup or after the identity changed.
+    fn restore(&self) {
+        let Some(persistence) = &self.persistence else {
+            return;
+        };
+        let Some(results) = persistence.take_unloaded() else {
+            return;
+        };
+        let options = self.options.lock().clone();
+        let mut entries = self.entries.lock();
+        *entries = Entries::default();
+        for result in results {
+            let cached = CachedValue {
+                tick: 0,
+                value: result.value,
+                stored_at_ms: result.stored_at_ms,
+            };
+            entries.insert((result.name, result.args), cached);
+        }
+        entries.evict(&options);
+        self.dirty.store(false, Ordering::SeqCst);
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<String> {
+        self.restore();
+        let entries = self.entries.lock();
+        entries.values.get(key).map(|cached| cached.value.clone())
+    }
+
+    fn store(&self, key: CacheKey, value: String) {
+        self.restore();
+        let cached = CachedValue {
+            tick: self.clock.fetch_add(1, Ordering::SeqCst),
+            value,
+            stored_at_ms: now_millis(),
+        };
+        let options = self.options.lock().clone();
+        let mut entries = self.entries.lock();
+        entries.insert(key, cached);
+        entries.evict(&options);
+        self.dirty.store(true, Ordering::SeqCst);
+    }
+
+    /// Persists the values if they changed since the last time.
+    pub(crate) fn flush(&self) {
+        let Some(persistence) = &self.persistence else {
+            return;
+        };
+        if !self.dirty.swap(false, Ordering::SeqCst) {
+            return;
+        }
+        let results = self
+            .entries
+            .lock()
+            .values
+            .iter()
+            .map(|((name, args), cached)| PersistedResult {
+                name: name.clone(),
+                args: args.clone(),
+                value: cached.value.clone(),
+                stored_at_ms: cached.stored_at_ms,
+            })
+            .collect();
+        match persistence.save(results) {
+            Ok(()) => {}
+            // Written once the app sets the storage key.
+            Err(e) if is_locked(&e) => self.dirty.store(true, Ordering::SeqCst),
+            Err(e) => log::warn!("Failed to persist subscription results: {e}"),
+        }
+    }
+
+    /// Applies new limits, evicting values that exceed them.
+    fn configure(&self, options: ResultCacheOptions) {
+        self.entries.lock().evict(&options);
+        *self.options.lock() = options;
+    }
+
+    /// Forgets all values in memory, e.g. on logout. Persisted values of
+    /// the next identity are loaded when first needed.
+    pub(crate) fn clear(&self) {
+        *self.entries.lock() = Entries::default();
+        self.dirty.store(false, Ordering::SeqCst);
+        if let Some(persistence) = &self.persistence {
+            persistence.forget_loaded();
+        }
+    }
+
+    /// Forgets all values in memory and their storage, returning how many
+    /// there were and their approximate size in bytes. Persisted values are
+    /// kept.
+    pub(crate) fn release(&self) -> (usize, usize) {
+        self.flush();
+        let entries = std::mem::take(&mut *self.entries.lock());
+        if let Some(persistence) = &self.persistence {
+            persistence.forget_loaded();
+        }
+        (entries.values.len(), entries.bytes)
+    }
+}
+
+type OnWatchEvent = dyn Fn(WatchEvent) -> DartFnFuture<()> + Send + Sync;
+
+/// Remembers the last value passed to `inner`, unless it may hold
+/// decrypted fields.
+#[frb(ignore)]
+pub(crate) struct CachingSubscriber {
+    inner: Arc<dyn QuerySubscriber>,
+    last_values: Arc<LastValues>,
+    key: CacheKey,
+    encryption: FieldEncryption,
+}
+
+impl QuerySubscriber for CachingSubscriber {
+    fn on_update(&self, value: String) {
+        if !self.encryption.is_set() {
+            self.last_values.store(self.key.clone(), value.clone());
+        }
+        self.inner.on_update(value);
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.inner.on_error(message, value);
+    }
+
+    fn on_done(&self) {
+        self.inner.on_done();
+    }
+}
+
+/// Delivers server values as [`WatchEvent`]s.
+#[frb(ignore)]
+struct WatchSubscriber {
+    on_event: Box<OnWatchEvent>,
+    calls: OrderedCalls,
+}
+
+impl WatchSubscriber {
+    fn emit(&self, event: WatchEvent) {
+        self.calls.call((self.on_event)(event));
+    }
+}
+
+impl QuerySubscriber for WatchSubscriber {
+    fn on_update(&self, value: String) {
+        self.emit(WatchEvent::Update { value });
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.emit(WatchEvent::Error {
+            message,
+            data: value,
+        });
+    }
+}
+
+impl MobileConvexClient {
+    /// Returns `subscriber` for a subscription to `name` with `args`,
+    /// remembering its last value.
+    pub(crate) fn caching_subscriber(
+        &self,
+        name: &str,
+        args: &BTreeMap<String, Value>,
+        subscriber: Arc<dyn QuerySubscriber>,
+    ) -> Arc<dyn QuerySubscriber> {
+        Arc::new(CachingSubscriber {
+            inner: subscriber,
+            last_values: self.last_values.clone(),
+            key: cache_key(name, args),
+            encryption: self.field_encryption.clone(),
+        })
+    }
+
+    /// Returns the placeholder `policy` calls for before the first server
+    /// value of `name` with `args`.
+    fn placeholder(
+        &self,
+        policy: PlaceholderPolicy,
+        key: &CacheKey,
+        args: &BTreeMap<String, Value>,
+    ) -> Option<WatchEvent> {
+        match policy {
+            PlaceholderPolicy::Cached => {
+                let cached = self
+                    .last_values
+                    .get(key)
+                    .or_else(|| self.query_cache.get(&key.0, args));
+                Some(match cached {
+                    Some(value) => {
+                        let name = key.0.clone();
+                        self.events.emit(ClientEvent::CacheHit { name });
+                        WatchEvent::Cached {
+                            value: self.decode_result(value),
+                        }
+                    }
+                    None => WatchEvent::Loading,
+                })
+            }
+            PlaceholderPolicy::Loading => Some(WatchEvent::Loading),
+            PlaceholderPolicy::Wait => None,
+        }
+    }
+
+    /// Subscribes to a Convex query, first emitting the placeholder chosen
+    /// by `placeholder`. The placeholder is always delivered before any
+    /// server value.
+    #[frb]
+    pub async fn watch(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        placeholder: PlaceholderPolicy,
+        on_event: impl Fn(WatchEvent) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        let args = self.parse_args(args)?;
+        let key = cache_key(&name, &args);
+        if let Some(event) = self.placeholder(placeholder, &key, &args) {
+            on_event(event).await;
+        }
+        let subscriber = Arc::new(WatchSubscriber {
+            on_event: Box::new(on_event),
+            calls: OrderedCalls::default(),
+        });
+        self.internal_subscribe(name, args, subscriber, SubscriptionPriority::Normal)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Returns the last result of a subscription to `name` with `args`,
+    /// serialized as JSON, if one is cached.
+    #[frb(sync)]
+    pub fn get_cached(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+    ) -> Result<Option<String>, ClientError> {
+        let args = self.parse_args(args)?;
+        Ok(self.last_values.get(&cache_key(&name, &args)))
+    }
+
+    /// Changes how many subscription results are cached, evicting those
+    /// exceeding the new limits.
+    #[frb(sync)]
+    pub fn configure_result_cache(&self, options: ResultCacheOptions) {
+        self.last_values.configure(options);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(name: &str) -> CacheKey {
+        cache_key(name, &BTreeMap::new())
+    }
+
+    #[test]
+    fn least_recently_updated_values_are_evicted() {
+        let capacity = ResultCacheOptions::default().max_entries as usize;
+        let last_values = LastValues::new(ResultCacheOptions::default(), None);
+        for i in 0..capacity {
+            last_values.store(key(&format!("q{i}")), i.to_string());
+        }
+        last_values.store(key("q0"), "updated".into());
+        last_values.store(key("new"), "1".into());
+
+        assert_eq!(last_values.entries.lock().values.len(), capacity);
+        assert!(last_values.get(&key("new")).is_some());
+        assert!(last_values.get(&key("q1")).is_none());
+        assert_eq!(last_values.get(&key("q0")).as_deref(), Some("updated"));
+    }
+
+    #[test]
+    fn values_are_evicted_beyond_the_size_limit() {
+        let last_values = LastValues::new(ResultCacheOptions::default(), None);
+        last_values.store(key("a"), "x".repeat(100));
+        last_values.store(key("b"), "y".repeat(100));
+        last_values.store(key("b"), "z".repeat(10));
+        let bytes = last_values.entries.lock().bytes;
+        assert_eq!(
+            bytes,
+            entry_size(&key("a"), "") + 100 + entry_size(&key("b"), "") + 10
+        );
+
+        last_values.configure(ResultCacheOptions {
+            max_entries: 10,
+            max_bytes: 50,
+        });
+        assert!(last_values.get(&key("a")).is_none());
+        assert_eq!(last_values.get(&key("b")), Some("z".repeat(10)));
+        assert_eq!(last_values.release(), (1, entry_size(&key("b"), "") + 10));
+    }
+}
@@ -0,0 +1,359 @@
+//! Presence helpers: a periodic heartbeat mutation and a watcher that turns
+//! heartbeat timestamps into online/offline status.
+//!
+//! The heartbeat loop only runs while the WebSocket is connected and the
+//! handle is not paused (e.g. while the app is backgrounded). When the
+//! heartbeat mutation returns a number it is treated as the server's
+//! `Date.now()`, and the difference to the local clock (corrected for half the
+//! round trip) is used to compensate clock skew when evaluating presence.
+
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use convex::{FunctionResult, Value};
+use flutter_rust_bridge::{frb, DartFnFuture};
+use futures::{channel::oneshot, pin_mut, select_biased, FutureExt};
+use log::debug;
+use parking_lot::Mutex;
+use tokio::sync::{mpsc, Notify};
+
+use crate::{
+    resubscribe::SubscriptionPriority,
+    subscription::{EventForwarder, SubscriptionEvent},
+    value::json_to_value_as,
+    ClientError, MobileConvexClient, SubscriptionHandle, WebSocketConnectionState,
+};
+
+/// Options controlling how heartbeat documents are interpreted.
+#[derive(Debug, Clone)]
+#[frb]
+pub struct PresenceOptions {
+    /// Field holding the user identifier in each heartbeat document.
+    pub user_field: String,
+    /// Field holding the last heartbeat time (server milliseconds since epoch).
+    pub last_seen_field: String,
+    /// A user is online if their last heartbeat is at most this old.
+    pub online_threshold_ms: u64,
+}
+
+/// Online/offline status of a single user derived from their last heartbeat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct PresenceStatus {
+    pub user_id: String,
+    pub is_online: bool,
+    /// Last heartbeat time in server milliseconds since epoch.
+    pub last_seen_ms: i64,
+}
+
+/// Opaque type for Dart, controlling a running presence heartbeat.
+#[frb(opaque)]
+pub struct PresenceHandle {
+    cancel_sender: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+    paused: Arc<AtomicBool>,
+    wake: Arc<Notify>,
+}
+
+impl PresenceHandle {
+    /// Pauses heartbeats, e.g. when the app moves to the background.
+    #[frb(sync)]
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::SeqCst);
+    }
+
+    /// Resumes heartbeats and sends one immediately.
+    #[frb(sync)]
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::SeqCst);
+        self.wake.notify_one();
+    }
+
+    /// Returns whether heartbeats are currently paused by the caller.
+    #[frb(sync)]
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Stops the heartbeat loop.
+    #[frb(sync)]
+    pub fn stop(&self) {
+        if let Some(sender) = self.cancel_sender.lock().take() {
+            let _ = sender.send(());
+        }
+    }
+}
+
+impl MobileConvexClient {
+    /// Starts sending `mutation_name` with `args` every `interval_ms`.
+    ///
+    /// Heartbeats are skipped while the WebSocket is not connected or the
+    /// returned handle is paused, and one is sent immediately on reconnect
+    /// or resume. If the mutation returns a number it is used as the server
+    /// time to estimate clock skew for [`MobileConvexClient::subscribe_presence`].
+    /// Heartbeats are sent like any other mutation, through the interceptors
+    /// and call metrics, and stop once the client is dropped.
+    #[frb]
+    pub async fn start_presence(
+        &self,
+        mutation_name: String,
+        args: HashMap<String, String>,
+        interval_ms: u64,
+    ) -> Result<PresenceHandle, ClientError> {
+        self.connected_client().await?;
+        let client = self.downgrade();
+        let args = self.parse_args(args)?;
+        let interval = Duration::from_millis(interval_ms.max(1));
+        let mut state_rx = self.connection_state.subscribe();
+        let clock_offset_ms = self.clock_offset_ms.clone();
+        let paused = Arc::new(AtomicBool::new(false));
+        let wake = Arc::new(Notify::new());
+        let (cancel_sender, cancel_receiver) = oneshot::channel::<()>();
+
+        let loop_paused = paused.clone();
+        let loop_wake = wake.clone();
+        self.rt.spawn(async move {
+            let mut cancel_fut = cancel_receiver.fuse();
+            loop {
+                let connected =
+                    *state_rx.borrow_and_update() == WebSocketConnectionState::Connected;
+                if connected && !loop_paused.load(Ordering::SeqCst) {
+                    let Some(client) = client.upgrade() else {
+                        break;
+                    };
+                    let sent_at = Instant::now();
+                    let result = client
+                        .internal_mutation(mutation_name.clone(), args.clone())
+                        .await;
+                    drop(client);
+                    match result {
+                        Ok(FunctionResult::Value(value)) => {
+                            if let Some(server_ms) = value_as_millis(&value) {
+                                let rtt_ms = sent_at.elapsed().as_millis() as i64;
+                                let offset = server_ms - (now_millis() - rtt_ms / 2);
+                                clock_offset_ms.store(offset, Ordering::SeqCst);
+                            }
+                        }
+                        Ok(other) => debug!("Presence heartbeat failed: {other:?}"),
+                        Err(e) => debug!("Presence heartbeat failed: {e}"),
+                    }
+                }
+
+                let sleep_fut = tokio::time::sleep(interval).fuse();
+                let wake_fut = loop_wake.notified().fuse();
+                let changed_fut = state_rx.changed().fuse();
+                pin_mut!(sleep_fut, wake_fut, changed_fut);
+                select_biased! {
+                    _ = cancel_fut => break,
+                    _ = wake_fut => {}
+                    // Re-evaluated at the top of the loop, so reconnecting
+                    // sends a heartbeat right away.
+                    changed = changed_fut => {
+                        if changed.is_err() {
+                            break;
+                        }
+                    }
+                    _ = sleep_fut => {}
+                }
+            }
+            debug!("Presence heartbeat stopped");
+        });
+
+        Ok(PresenceHandle {
+            cancel_sender: Arc::new(Mutex::new(Some(cancel_sender))),
+            paused,
+            wake,
+        })
+    }
+
+    /// Subscribes to a query returning heartbeat documents and delivers the
+    /// derived online/offline status of each user.
+    ///
+    /// Statuses are re-evaluated as time passes, so users drop to offline
+    /// without a server update. The server clock is estimated using the skew
+    /// measured by [`MobileConvexClient::start_presence`].
+    #[frb]
+    pub async fn subscribe_presence(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        options: PresenceOptions,
+        on_update: impl Fn(Vec<PresenceStatus>) -> DartFnFuture<()> + Send + Sync + 'static,
+        on_error: impl Fn(String, Option<String>) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        let args = self.parse_args(args)?;
+        let (deliveries, mut delivered) = mpsc::unbounded_channel();
+        let handle = self
+            .internal_subscribe(
+                name.clone(),
+                args,
+                Arc::new(EventForwarder(deliveries)),
+                SubscriptionPriority::Normal,
+            )
+            .await?;
+        let clock_offset_ms = self.clock_offset_ms.clone();
+        let int64_encoding = self.options.int64_encoding;
+        let recheck = Duration::from_millis((options.online_threshold_ms / 2).max(1000));
+
+        self.rt.spawn(async move {
+            let mut heartbeats: Vec<(String, i64)> = Vec::new();
+            let mut last_delivered: Option<Vec<PresenceStatus>> = None;
+            loop {
+                let tick = tokio::time::sleep(recheck).fuse();
+                let event = delivered.recv().fuse();
+                pin_mut!(tick, event);
+                select_biased! {
+                    // Ends once the subscription is cancelled and drops the
+                    // forwarder.
+                    event = event => match event {
+                        Some(SubscriptionEvent::Update { value }) => {
+                            let value = serde_json::from_str(&value)
+                                .map_err(anyhow::Error::from)
+                                .and_then(|json| json_to_value_as(json, int64_encoding));
+                            match value {
+                                Ok(value) => heartbeats = extract_heartbeats(&value, &options),
+                                Err(e) => debug!("Invalid presence result of {name}: {e}"),
+                            }
+                        }
+                        Some(SubscriptionEvent::Error { message, data }) => {
+                            let _ = on_error(message, data).await;
+                            continue;
+                        }
+                        Some(SubscriptionEvent::Closed { .. }) | None => {
+                            log::warn!("Presence subscription stream ended for {}", &name);
+                            break;
+                        }
+                        Some(_) => continue,
+                    },
+                    _ = tick => {}
+                }
+
+                let server_now = now_millis() + clock_offset_ms.load(Ordering::SeqCst);
+                let statuses = evaluate_presence(&heartbeats, server_now, &options);
+                if last_delivered.as_ref() != Some(&statuses) {
+                    last_delivered = Some(statuses.clone());
+                    let _ = on_update(statuses).await;
+                }
+            }
+            debug!("Presence subscription canceled");
+        });
+
+        Ok(handle)
+    }
+
+    /// Returns the estimated server clock minus the local clock, in milliseconds.
+    #[frb(sync)]
+    pub fn clock_offset_ms(&self) -> i64 {
+        self.clock_offset_ms.load(Ordering::SeqCst)
+    }
+}
+
+/// Milliseconds since the Unix epoch on the local clock.
+pub(crate) fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+fn value_as_millis(value: &Value) -> Option<i64> {
+    match value {
+        Value::Int64(n) => Some(*n),
+        // `Int64`s of subscription results, with `Int64Encoding::String`.
+        Value::String(s) => s.parse().ok(),
+        Value::Float64(n) if n.is_finite() => Some(*n as i64),
+        _ => None,
+    }
+}
+
+/// Pulls `(user_id, last_seen_ms)` pairs out of an array of heartbeat documents.
+fn extract_heartbeats(value: &Value, options: &PresenceOptions) -> Vec<(String, i64)> {
+    let Value::Array(docs) = value else {
+        return Vec::new();
+    };
+    docs.iter()
+        .filter_map(|doc| {
+            let Value::Object(fields) = doc else {
+                return None;
+            };
+            let user_id = match fields.get(&options.user_field)? {
+                Value::String(s) => s.clone(),
+                other => serde_json::Value::from(other.clone()).to_string(),
+            };
+            let last_seen = value_as_millis(fields.get(&options.last_seen_field)?)?;
+            Some((user_id, last_seen))
+        })
+        .collect()
+}
+
+fn evaluate_presence(
+    heartbeats: &[(String, i64)],
+    server_now_ms: i64,
+    options: &PresenceOptions,
+) -> Vec<PresenceStatus> {
+    heartbeats
+        .iter()
+        .map(|(user_id, last_seen_ms)| PresenceStatus {
+            user_id: user_id.clone(),
+            is_online: server_now_ms.saturating_sub(*last_seen_ms)
+                <= options.online_threshold_ms as i64,
+            last_seen_ms: *last_seen_ms,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    fn options() -> PresenceOptions {
+        PresenceOptions {
+            user_field: "user".into(),
+            last_seen_field: "lastSeen".into(),
+            online_threshold_ms: 10_000,
+        }
+    }
+
+    fn heartbeat(user: Value, last_seen: Value) -> Value {
+        Value::Object(BTreeMap::from([
+            ("user".to_string(), user),
+            ("lastSeen".to_string(), last_seen),
+        ]))
+    }
+
+    #[test]
+    fn extracts_heartbeats_and_skips_malformed_documents() {
+        let value = Value::Array(vec![
+            heartbeat(Value::String("a".into()), Value::Float64(1000.0)),
+            heartbeat(Value::Boolean(true), Value::Int64(2000)),
+            heartbeat(Value::String("c".into()), Value::String("soon".into())),
+            heartbeat(Value::String("d".into()), Value::String("3000".into())),
+            Value::Null,
+        ]);
+        assert_eq!(
+            extract_heartbeats(&value, &options()),
+            vec![
+                ("a".to_string(), 1000),
+                ("true".to_string(), 2000),
+                ("d".to_string(), 3000)
+            ]
+        );
+        assert!(extract_heartbeats(&Value::Null, &options()).is_empty());
+    }
+
+    #[test]
+    fn users_go_offline_after_the_threshold() {
+        let heartbeats = vec![("a".to_string(), 0), ("b".to_string(), 5_000)];
+        let statuses = evaluate_presence(&heartbeats, 12_000, &options());
+        assert!(!statuses[0].is_online);
+        assert!(statuses[1].is_online);
+        assert_eq!(statuses[1].last_seen_ms, 5_000);
+    }
+}
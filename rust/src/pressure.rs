@@ -0,0 +1,301 @@
+//! Cooperative flow control between the UI and subscription delivery.
+//!
+//! The app reports how hard the UI is struggling (e.g. from dropped frames)
+//! with [`MobileConvexClient::set_ui_pressure`]. While pressure is elevated,
+//! subscription updates are delivered at most once per throttle interval and
+//! intermediate values are dropped in favour of the latest one. Errors are
+//! never dropped and keep their order relative to updates. When pressure
+//! returns to normal, any held-back update is delivered right away.
+
+use std::{sync::Arc, time::Duration};
+
+use flutter_rust_bridge::frb;
+use futures::{pin_mut, select_biased, FutureExt};
+use serde::Deserialize;
+use tokio::{
+    sync::{mpsc, watch},
+    time::Instant,
+};
+
+use crate::{MobileConvexClient, QuerySubscriber, SubscriptionCloseReason, SubscriptionEvent};
+
+/// UI load reported by the app.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[frb]
+pub enum UiPressure {
+    /// Frames are rendered on time; updates are delivered at full rate.
+    #[default]
+    Normal,
+    /// Some frames are dropped.
+    Moderate,
+    /// Most frames are dropped.
+    High,
+}
+
+/// Minimum time between two delivered updates of a subscription per pressure level.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct PressureThrottle {
+    pub moderate_interval_ms: u64,
+    pub high_interval_ms: u64,
+}
+
+impl Default for PressureThrottle {
+    fn default() -> Self {
+        PressureThrottle {
+            moderate_interval_ms: 250,
+            high_interval_ms: 1000,
+        }
+    }
+}
+
+impl PressureThrottle {
+    fn interval(&self, pressure: UiPressure) -> Duration {
+        Duration::from_millis(match pressure {
+            UiPressure::Normal => 0,
+            UiPressure::Moderate => self.moderate_interval_ms,
+            UiPressure::High => self.high_interval_ms,
+        })
+    }
+}
+
+/// Subscriber that hands events to a delivery task applying the throttle.
+#[frb(ignore)]
+struct ThrottledSubscriber {
+    events: mpsc::UnboundedSender<SubscriptionEvent>,
+}
+
+impl QuerySubscriber for ThrottledSubscriber {
+    fn on_update(&self, value: String) {
+        let _ = self.events.send(SubscriptionEvent::Update { value });
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        let _ = self.events.send(SubscriptionEvent::Error {
+            message,
+            data: value,
+        });
+    }
+
+    fn on_done(&self) {
+        let _ = self.events.send(SubscriptionEvent::Closed {
+            reason: SubscriptionCloseReason::StreamEnded,
+        });
+    }
+}
+
+/// Wraps `subscriber` so its updates are throttled under UI pressure. The
+/// delivery task ends once the returned subscriber is dropped.
+pub(crate) fn throttle_subscriber(
+    rt: &tokio::runtime::Handle,
+    subscriber: Arc<dyn QuerySubscriber>,
+    pressure: watch::Receiver<UiPressure>,
+    throttle: PressureThrottle,
+) -> Arc<dyn QuerySubscriber> {
+    let (events, receiver) = mpsc::unbounded_channel();
+    rt.spawn(deliver(receiver, subscriber, pressure, throttle));
+    Arc::new(ThrottledSubscriber { events })
+}
+
+async fn deliver(
+    mut receiver: mpsc::UnboundedReceiver<SubscriptionEvent>,
+    subscriber: Arc<dyn QuerySubscriber>,
+    mut pressure: watch::Receiver<UiPressure>,
+    throttle: PressureThrottle,
+) {
+    let mut last_update: Option<Instant> = None;
+    while let Some(first) = receiver.recv().await {
+        if matches!(first, SubscriptionEvent::Update { .. }) {
+            // Hold the update back until the interval for the current
+            // pressure has passed; re-evaluate whenever pressure changes.
+            while let Some(last) = last_update {
+                let deadline = last + throttle.interval(*pressure.borrow_and_update());
+                if Instant::now() >= deadline {
+                    break;
+                }
+                let sleep_fut = tokio::time::sleep_until(deadline).fuse();
+                let changed_fut = pressure.changed().fuse();
+                pin_mut!(sleep_fut, changed_fut);
+                select_biased! {
+                    _ = sleep_fut => break,
+                    changed = changed_fut => {
+                        if changed.is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut pending = vec![first];
+        while let Ok(event) = receiver.try_recv() {
+            pending.push(event);
+        }
+        for event in coalesce_updates(pending) {
+            match event {
+                SubscriptionEvent::Update { value } => {
+                    last_update = Some(Instant::now());
+                    subscriber.on_update(value);
+                }
+                SubscriptionEvent::Error { message, data } => subscriber.on_error(message, data),
+                SubscriptionEvent::Closed { .. } => subscriber.on_done(),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Collapses runs of consecutive updates into the latest one.
+pub(crate) fn coalesce_updates(events: Vec<SubscriptionEvent>) -> Vec<SubscriptionEvent> {
+    let mut coalesced: Vec<SubscriptionEvent> = Vec::with_capacity(events.len());
+    for event in events {
+        if let (Some(SubscriptionEvent::Update { .. }), SubscriptionEvent::Update { .. }) =
+            (coalesced.last(), &event)
+        {
+            coalesced.pop();
+        }
+        coalesced.push(event);
+    }
+    coalesced
+}
+
+impl MobileConvexClient {
+    /// Reports the current UI load. Elevated pressure throttles subscription
+    /// updates as configured by [`crate::options::ClientOptions::pressure_throttle`].
+    #[frb(sync)]
+    pub fn set_ui_pressure(&self, pressure: UiPressure) {
+        self.ui_pressure.send_if_modified(|current| {
+            let changed = *current != pressure;
+            *current = pressure;
+            changed
+        });
+    }
+
+    /// Returns the UI load last reported with [`MobileConvexClient::set_ui_pressure`].
+    #[frb(sync)]
+    pub fn ui_pressure(&self) -> UiPressure {
+        *self.ui_pressure.borrow()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parking_lot::Mutex;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl QuerySubscriber for Recorder {
+        fn on_update(&self, value: String) {
+            self.events.lock().push(value);
+        }
+
+        fn on_error(&self, message: String, _value: Option<String>) {
+            self.events.lock().push(format!("error: {message}"));
+        }
+
+        fn on_done(&self) {
+            self.events.lock().push("done".into());
+        }
+    }
+
+    fn update(value: &str) -> SubscriptionEvent {
+        SubscriptionEvent::Update {
+            value: value.into(),
+        }
+    }
+
+    fn start(
+        pressure: UiPressure,
+    ) -> (
+        mpsc::UnboundedSender<SubscriptionEvent>,
+        Arc<Recorder>,
+        watch::Sender<UiPressure>,
+    ) {
+        let recorder = Arc::new(Recorder::default());
+        let (events, receiver) = mpsc::unbounded_channel();
+        let (pressure_tx, pressure_rx) = watch::channel(pressure);
+        let throttle = PressureThrottle {
+            moderate_interval_ms: 100,
+            high_interval_ms: 10_000,
+        };
+        tokio::spawn(deliver(receiver, recorder.clone(), pressure_rx, throttle));
+        (events, recorder, pressure_tx)
+    }
+
+    async fn settle() {
+        tokio::time::sleep(Duration::from_millis(30)).await;
+    }
+
+    #[test]
+    fn consecutive_updates_are_coalesced_around_errors() {
+        let error = SubscriptionEvent::Error {
+            message: "boom".into(),
+            data: None,
+        };
+        let events = vec![update("1"), update("2"), error.clone(), update("3")];
+        assert_eq!(
+            coalesce_updates(events),
+            vec![update("2"), error, update("3")]
+        );
+    }
+
+    #[tokio::test]
+    async fn normal_pressure_delivers_every_update() {
+        let (events, recorder, _pressure) = start(UiPressure::Normal);
+        for value in ["1", "2", "3"] {
+            events.send(update(value)).unwrap();
+            settle().await;
+        }
+        assert_eq!(*recorder.events.lock(), ["1", "2", "3"]);
+    }
+
+    #[tokio::test]
+    async fn high_pressure_holds_back_until_it_subsides() {
+        let (events, recorder, pressure) = start(UiPressure::High);
+        events.send(update("1")).unwrap();
+        settle().await;
+        events.send(update("2")).unwrap();
+        events.send(update("3")).unwrap();
+        settle().await;
+        assert_eq!(*recorder.events.lock(), ["1"]);
+
+        pressure.send(UiPressure::Normal).unwrap();
+        settle().await;
+        assert_eq!(*recorder.events.lock(), ["1", "3"]);
+    }
+
+    #[tokio::test]
+    async fn moderate_pressure_limits_the_rate() {
+        let (events, recorder, _pressure) = start(UiPressure::Moderate);
+        for value in ["1", "2", "3"] {
+            events.send(update(value)).unwrap();
+            settle().await;
+        }
+        assert_eq!(*recorder.events.lock(), ["1"]);
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(*recorder.events.lock(), ["1", "3"]);
+    }
+
+    #[tokio::test]
+    async fn end_of_stream_follows_held_back_updates() {
+        let (events, recorder, pressure) = start(UiPressure::High);
+        events.send(update("1")).unwrap();
+        settle().await;
+        events.send(update("2")).unwrap();
+        events
+            .send(SubscriptionEvent::Closed {
+                reason: SubscriptionCloseReason::StreamEnded,
+            })
+            .unwrap();
+        settle().await;
+        pressure.send(UiPressure::Normal).unwrap();
+        settle().await;
+        assert_eq!(*recorder.events.lock(), ["1", "2", "done"]);
+    }
+}
@@ -0,0 +1,130 @@
+//! Switching to preview deployments.
+//!
+//! Testers point release candidates at the preview deployment of a branch or
+//! pull request from a debug menu. [`MobileConvexClient::connect_preview`]
+//! resolves what they enter, a deployment name like `happy-otter-123`, a
+//! URL or a PR identifier like `#42`, and creates a separate client for it.
+//! That client starts signed out and, being keyed by its own deployment URL,
+//! keeps its on-disk data in its own partition (see [`crate::storage`]).
+//!
+//! Convex does not publish which deployment belongs to a PR, so PR
+//! identifiers are looked up in [`PreviewOptions::aliases`], e.g. filled from
+//! a file published by CI.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail};
+use flutter_rust_bridge::frb;
+use serde::Deserialize;
+
+use crate::{ClientError, MobileConvexClient};
+
+/// How preview deployments are found.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct PreviewOptions {
+    /// URL of a deployment with `{name}` standing for the deployment name.
+    pub url_template: String,
+    /// Deployment names or URLs by alias. PR identifiers are looked up as
+    /// `pr-<number>`.
+    pub aliases: HashMap<String, String>,
+}
+
+impl Default for PreviewOptions {
+    fn default() -> Self {
+        PreviewOptions {
+            url_template: "https://{name}.convex.cloud".into(),
+            aliases: HashMap::new(),
+        }
+    }
+}
+
+/// Returns `pr-<number>` if `preview` is a PR identifier such as `#42`,
+/// `PR 42`, `pr-42` or `42`.
+fn pull_request_alias(preview: &str) -> Option<String> {
+    let lower = preview.to_ascii_lowercase();
+    let number = lower
+        .strip_prefix('#')
+        .or_else(|| lower.strip_prefix("pr"))
+        .map(|rest| rest.trim_start_matches(['-', ' ', '#']))
+        .unwrap_or(&lower);
+    (!number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()))
+        .then(|| format!("pr-{number}"))
+}
+
+fn is_deployment_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+/// Resolves a deployment name, URL, alias or PR identifier to a URL.
+fn resolve_preview(preview: &str, options: &PreviewOptions) -> anyhow::Result<String> {
+    let preview = preview.trim();
+    let target = match pull_request_alias(preview) {
+        Some(alias) => options
+            .aliases
+            .get(&alias)
+            .ok_or_else(|| anyhow!("No preview deployment is known for {alias}"))?,
+        None => options.aliases.get(preview).map_or(preview, String::as_str),
+    };
+    if target.starts_with("https://") || target.starts_with("http://") {
+        return Ok(target.trim_end_matches('/').to_owned());
+    }
+    if !is_deployment_name(target) {
+        bail!("`{target}` is neither a deployment name nor a URL");
+    }
+    Ok(options.url_template.replace("{name}", target))
+}
+
+impl MobileConvexClient {
+    /// Creates a client for the preview deployment named by `preview`, as
+    /// described in the [module docs](crate::preview). It uses this
+    /// client's options without failover; closing either client leaves the
+    /// other one open.
+    #[frb(sync)]
+    pub fn connect_preview(
+        &self,
+        preview: String,
+        client_id: String,
+    ) -> Result<MobileConvexClient, ClientError> {
+        let url = resolve_preview(&preview, &self.options.previews)?;
+        let mut options = self.options.clone();
+        options.failover = None;
+        Ok(Self::new_with_options(url, client_id, options))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn previews_resolve_by_name_url_and_pull_request() {
+        let options = PreviewOptions {
+            aliases: HashMap::from([
+                ("pr-42".to_owned(), "brave-fox-7".to_owned()),
+                (
+                    "staging".to_owned(),
+                    "https://staging.example.com/".to_owned(),
+                ),
+            ]),
+            ..PreviewOptions::default()
+        };
+        let resolve = |preview| resolve_preview(preview, &options).unwrap();
+        assert_eq!(
+            resolve("happy-otter-123"),
+            "https://happy-otter-123.convex.cloud"
+        );
+        assert_eq!(resolve("http://localhost:3210"), "http://localhost:3210");
+        assert_eq!(resolve("staging"), "https://staging.example.com");
+        for pull_request in ["#42", "PR 42", "pr-42", " 42 "] {
+            assert_eq!(resolve(pull_request), "https://brave-fox-7.convex.cloud");
+        }
+
+        assert!(resolve_preview("#7", &options).is_err());
+        assert!(resolve_preview("Not a name", &options).is_err());
+    }
+}
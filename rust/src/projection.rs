@@ -0,0 +1,156 @@
+//! Projection of results to selected fields.
+//!
+//! A widget showing one counter of a large document still receives the whole
+//! serialized document on every update.
+//! [`MobileConvexClient::query_projected`] and
+//! [`crate::subscription::SubscribeOptions::projection`] take a list of
+//! [JSON pointers](https://www.rfc-editor.org/rfc/rfc6901), e.g.
+//! `/stats/unread`, and deliver an object mapping each pointer to the value
+//! it selects, or `null` if it selects nothing. Only that object crosses into
+//! Dart.
+
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::bail;
+use flutter_rust_bridge::frb;
+use parking_lot::Mutex;
+use serde_json::{Map, Value as JsonValue};
+
+use crate::{ClientError, MobileConvexClient, QuerySubscriber};
+
+/// Fails unless every entry of `projection` is a JSON pointer.
+pub(crate) fn validate_projection(projection: &[String]) -> anyhow::Result<()> {
+    if projection.is_empty() {
+        bail!("a projection needs at least one JSON pointer");
+    }
+    if let Some(pointer) = projection
+        .iter()
+        .find(|pointer| !pointer.is_empty() && !pointer.starts_with('/'))
+    {
+        bail!("`{pointer}` is not a JSON pointer; pointers start with `/`");
+    }
+    Ok(())
+}
+
+/// Returns the object mapping each pointer of `projection` to its value in
+/// `json`. Results that are not JSON are returned as they are.
+fn project(json: String, projection: &[String]) -> String {
+    let Ok(value) = serde_json::from_str::<JsonValue>(&json) else {
+        return json;
+    };
+    let selected: Map<String, JsonValue> = projection
+        .iter()
+        .map(|pointer| {
+            let field = value.pointer(pointer).cloned().unwrap_or(JsonValue::Null);
+            (pointer.clone(), field)
+        })
+        .collect();
+    JsonValue::Object(selected).to_string()
+}
+
+/// Projects updates, passing on those whose projection changed.
+#[frb(ignore)]
+pub(crate) struct ProjectingSubscriber {
+    inner: Arc<dyn QuerySubscriber>,
+    projection: Vec<String>,
+    last: Mutex<Option<String>>,
+}
+
+impl ProjectingSubscriber {
+    pub(crate) fn new(inner: Arc<dyn QuerySubscriber>, projection: Vec<String>) -> Self {
+        ProjectingSubscriber {
+            inner,
+            projection,
+            last: Mutex::new(None),
+        }
+    }
+}
+
+impl QuerySubscriber for ProjectingSubscriber {
+    fn on_update(&self, value: String) {
+        let projected = project(value, &self.projection);
+        {
+            let mut last = self.last.lock();
+            if last.as_ref() == Some(&projected) {
+                return;
+            }
+            *last = Some(projected.clone());
+        }
+        self.inner.on_update(projected);
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.last.lock().take();
+        self.inner.on_error(message, value);
+    }
+
+    fn on_done(&self) {
+        self.inner.on_done();
+    }
+}
+
+impl MobileConvexClient {
+    /// Like [`MobileConvexClient::query`], but returns only the fields
+    /// selected by the JSON pointers in `projection`.
+    #[frb]
+    pub async fn query_projected(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        projection: Vec<String>,
+    ) -> Result<String, ClientError> {
+        validate_projection(&projection)?;
+        let result = self.query(name, args, None).await?;
+        Ok(project(result, &projection))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        updates: Mutex<Vec<String>>,
+    }
+
+    impl QuerySubscriber for Recorder {
+        fn on_update(&self, value: String) {
+            self.updates.lock().push(value);
+        }
+
+        fn on_error(&self, _message: String, _value: Option<String>) {}
+    }
+
+    #[test]
+    fn only_selected_fields_are_delivered_when_they_change() {
+        let recorder = Arc::new(Recorder::default());
+        let projection = vec!["/stats/unread".to_owned(), "/missing".to_owned()];
+        assert!(validate_projection(&projection).is_ok());
+        let subscriber = ProjectingSubscriber {
+            inner: recorder.clone(),
+            projection,
+            last: Mutex::new(None),
+        };
+        for (unread, title) in [(1, "a"), (1, "b"), (2, "b")] {
+            let document = json!({"title": title, "stats": {"unread": unread}});
+            subscriber.on_update(document.to_string());
+        }
+        assert_eq!(
+            *recorder.updates.lock(),
+            [
+                json!({"/stats/unread": 1, "/missing": null}).to_string(),
+                json!({"/stats/unread": 2, "/missing": null}).to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn projections_must_be_json_pointers() {
+        assert!(validate_projection(&[]).is_err());
+        assert!(validate_projection(&["stats.unread".to_owned()]).is_err());
+        assert!(validate_projection(&["".to_owned(), "/a~1b/0".to_owned()]).is_ok());
+    }
+}
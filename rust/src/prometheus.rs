@@ -0,0 +1,204 @@
+//! Debug-only HTTP endpoint serving [`ClientMetrics`] in the Prometheus text
+//! exposition format.
+//!
+//! Enabled with [`crate::options::ClientOptions::debug_metrics_port`] and only
+//! in debug builds. It binds to `127.0.0.1`, so it is reachable from the
+//! device itself or through `adb forward tcp:<port> tcp:<port>`. Every request
+//! is answered with the current metrics regardless of path or method.
+
+use std::{fmt::Write as _, sync::Arc, time::Duration};
+
+use log::{info, warn};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+use crate::metrics::ClientMetrics;
+
+/// Pause after a failed accept, e.g. when out of file descriptors, so the
+/// loop does not spin.
+const ACCEPT_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// Source of the metrics served on each scrape.
+pub(crate) type MetricsSource = Arc<dyn Fn() -> ClientMetrics + Send + Sync>;
+
+/// Binds the endpoint on `port` and serves scrapes until the runtime stops.
+pub(crate) async fn run_metrics_endpoint(port: u16, source: MetricsSource) {
+    match TcpListener::bind(("127.0.0.1", port)).await {
+        Ok(listener) => {
+            if let Ok(addr) = listener.local_addr() {
+                info!("Serving Prometheus metrics on http://{addr}/metrics");
+            }
+            serve(listener, source).await;
+        }
+        Err(e) => warn!("Failed to bind metrics endpoint on port {port}: {e}"),
+    }
+}
+
+async fn serve(listener: TcpListener, source: MetricsSource) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                warn!("Accepting a metrics scrape failed: {e}");
+                tokio::time::sleep(ACCEPT_RETRY_DELAY).await;
+                continue;
+            }
+        };
+        let source = source.clone();
+        tokio::spawn(async move {
+            if let Err(e) = respond(stream, source).await {
+                warn!("Metrics scrape failed: {e}");
+            }
+        });
+    }
+}
+
+async fn respond(mut stream: TcpStream, source: MetricsSource) -> std::io::Result<()> {
+    // Read the request head; its content is irrelevant.
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") && request.len() < 8192 {
+        let read = stream.read(&mut buf).await?;
+        if read == 0 {
+            break;
+        }
+        request.extend_from_slice(&buf[..read]);
+    }
+    let body = render(&source());
+    let response = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Renders metrics in the Prometheus text exposition format.
+pub(crate) fn render(metrics: &ClientMetrics) -> String {
+    let connection = &metrics.connection;
+    let runtime = &metrics.runtime;
+    let connected = connection.level != crate::quality::ConnectionQualityLevel::Offline;
+    let gauges: [(&str, &str, f64); 11] = [
+        (
+            "convex_connection_connected",
+            "Whether the WebSocket is connected (1) or not (0).",
+            if connected { 1.0 } else { 0.0 },
+        ),
+        (
+            "convex_connection_quality_score",
+            "Rolling connection quality score from 0 to 100.",
+            connection.score.into(),
+        ),
+        (
+            "convex_connection_rtt_ms",
+            "Moving average of call round-trip times in milliseconds.",
+            connection.rtt_ms,
+        ),
+        (
+            "convex_connection_failure_rate",
+            "Moving average of the fraction of calls failing in transport.",
+            connection.failure_rate,
+        ),
+        (
+            "convex_connection_recent_reconnects",
+            "Reconnects during the last five minutes.",
+            connection.recent_reconnects.into(),
+        ),
+        (
+            "convex_runtime_workers",
+            "Number of runtime worker threads.",
+            runtime.workers.into(),
+        ),
+        (
+            "convex_runtime_alive_tasks",
+            "Number of alive runtime tasks.",
+            runtime.alive_tasks as f64,
+        ),
+        (
+            "convex_runtime_global_queue_depth",
+            "Tasks waiting in the global scheduling queue.",
+            runtime.global_queue_depth as f64,
+        ),
+        (
+            "convex_runtime_worker_utilization",
+            "Fraction of time the workers were busy during the last sample.",
+            runtime.worker_utilization,
+        ),
+        (
+            "convex_runtime_scheduling_lag_mean_ms",
+            "Average delay before a woken task ran, over the last minute.",
+            runtime.mean_scheduling_lag_ms,
+        ),
+        (
+            "convex_runtime_scheduling_lag_max_ms",
+            "Longest delay before a woken task ran, over the last minute.",
+            runtime.max_scheduling_lag_ms,
+        ),
+    ];
+    let mut out = String::new();
+    for (name, help, value) in gauges {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} gauge");
+        let _ = writeln!(out, "{name} {value}");
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        metrics::RuntimeMetrics,
+        quality::{ConnectionQuality, ConnectionQualityLevel},
+    };
+
+    fn sample() -> ClientMetrics {
+        ClientMetrics {
+            connection: ConnectionQuality {
+                score: 87,
+                level: ConnectionQualityLevel::Excellent,
+                rtt_ms: 120.5,
+                failure_rate: 0.0,
+                recent_reconnects: 1,
+            },
+            runtime: RuntimeMetrics {
+                workers: 4,
+                alive_tasks: 12,
+                global_queue_depth: 0,
+                worker_utilization: 0.25,
+                mean_scheduling_lag_ms: 0.5,
+                max_scheduling_lag_ms: 3.0,
+            },
+        }
+    }
+
+    #[test]
+    fn renders_gauges_with_help_and_type() {
+        let text = render(&sample());
+        assert!(text.contains("# TYPE convex_connection_quality_score gauge\n"));
+        assert!(text.contains("\nconvex_connection_quality_score 87\n"));
+        assert!(text.contains("\nconvex_connection_connected 1\n"));
+        assert!(text.contains("\nconvex_connection_rtt_ms 120.5\n"));
+        assert!(text.contains("\nconvex_runtime_worker_utilization 0.25\n"));
+        assert_eq!(text.lines().count(), 33);
+    }
+
+    #[tokio::test]
+    async fn serves_metrics_over_http() {
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve(listener, Arc::new(sample)));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with(&render(&sample())));
+    }
+}
@@ -0,0 +1,252 @@
+//! Rolling connection-quality score.
+//!
+//! Combines an exponential moving average of call round-trip times, an
+//! exponential moving average of transport failures and the number of recent
+//! reconnects into a single 0-100 score, so apps can degrade features on poor
+//! networks before calls start failing outright.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use parking_lot::Mutex;
+use tokio::sync::watch;
+
+use crate::{ClientError, MobileConvexClient, WebSocketConnectionState};
+
+/// Weight of the newest sample in the moving averages.
+const EMA_ALPHA: f64 = 0.2;
+/// Round-trip times at or below this are considered perfect.
+const GOOD_RTT_MS: f64 = 150.0;
+/// Round-trip times at or above this are considered unusable.
+const BAD_RTT_MS: f64 = 2000.0;
+/// Window in which reconnects count against the score.
+const RECONNECT_WINDOW: Duration = Duration::from_secs(300);
+/// Number of reconnects within the window that zeroes the reconnect component.
+const RECONNECTS_FOR_WORST: f64 = 5.0;
+/// Minimum score change that is published without a level change.
+const MIN_PUBLISHED_DELTA: u8 = 5;
+
+/// Coarse classification of [`ConnectionQuality::score`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum ConnectionQualityLevel {
+    Excellent,
+    Good,
+    Fair,
+    Poor,
+    /// The WebSocket is not connected.
+    Offline,
+}
+
+/// Snapshot of the rolling connection-quality estimate.
+#[derive(Debug, Clone, PartialEq)]
+#[frb]
+pub struct ConnectionQuality {
+    /// 0 (unusable) to 100 (perfect).
+    pub score: u8,
+    pub level: ConnectionQualityLevel,
+    /// Moving average of call round-trip times in milliseconds.
+    pub rtt_ms: f64,
+    /// Moving average of the fraction of calls that failed in transport.
+    pub failure_rate: f64,
+    /// Reconnects observed during the last five minutes.
+    pub recent_reconnects: u32,
+}
+
+struct QualityState {
+    rtt_ms: Option<f64>,
+    failure_rate: f64,
+    reconnects: VecDeque<Instant>,
+    connected: bool,
+}
+
+/// Collects samples and publishes [`ConnectionQuality`] snapshots.
+pub(crate) struct QualityTracker {
+    state: Mutex<QualityState>,
+    published: watch::Sender<ConnectionQuality>,
+}
+
+impl QualityTracker {
+    pub(crate) fn new() -> Self {
+        let state = QualityState {
+            rtt_ms: None,
+            failure_rate: 0.0,
+            reconnects: VecDeque::new(),
+            connected: false,
+        };
+        let initial = state.snapshot(Instant::now());
+        QualityTracker {
+            state: Mutex::new(state),
+            published: watch::Sender::new(initial),
+        }
+    }
+
+    /// Records a completed call. `transport_ok` is false when the call failed
+    /// to reach the backend (as opposed to a function returning an error).
+    pub(crate) fn record_call(&self, elapsed: Duration, transport_ok: bool) {
+        let mut state = self.state.lock();
+        if transport_ok {
+            let sample = elapsed.as_secs_f64() * 1000.0;
+            state.rtt_ms = Some(match state.rtt_ms {
+                Some(avg) => avg + EMA_ALPHA * (sample - avg),
+                None => sample,
+            });
+        }
+        let failure = if transport_ok { 0.0 } else { 1.0 };
+        state.failure_rate += EMA_ALPHA * (failure - state.failure_rate);
+        self.publish(&mut state);
+    }
+
+    /// Records a WebSocket state transition.
+    pub(crate) fn record_state(&self, new_state: &WebSocketConnectionState) {
+        let mut state = self.state.lock();
+        let connected = *new_state == WebSocketConnectionState::Connected;
+        if state.connected && !connected {
+            state.reconnects.push_back(Instant::now());
+        }
+        state.connected = connected;
+        self.publish(&mut state);
+    }
+
+    pub(crate) fn current(&self) -> ConnectionQuality {
+        self.state.lock().snapshot(Instant::now())
+    }
+
+    pub(crate) fn subscribe(&self) -> watch::Receiver<ConnectionQuality> {
+        self.published.subscribe()
+    }
+
+    fn publish(&self, state: &mut QualityState) {
+        let now = Instant::now();
+        while state
+            .reconnects
+            .front()
+            .is_some_and(|at| now.duration_since(*at) > RECONNECT_WINDOW)
+        {
+            state.reconnects.pop_front();
+        }
+        let next = state.snapshot(now);
+        self.published.send_if_modified(|current| {
+            let significant = current.level != next.level
+                || current.score.abs_diff(next.score) >= MIN_PUBLISHED_DELTA;
+            if significant {
+                *current = next;
+            }
+            significant
+        });
+    }
+}
+
+impl QualityState {
+    fn snapshot(&self, now: Instant) -> ConnectionQuality {
+        let recent_reconnects = self
+            .reconnects
+            .iter()
+            .filter(|at| now.duration_since(**at) <= RECONNECT_WINDOW)
+            .count() as u32;
+        let rtt_ms = self.rtt_ms.unwrap_or(0.0);
+        let rtt_penalty = ((rtt_ms - GOOD_RTT_MS) / (BAD_RTT_MS - GOOD_RTT_MS)).clamp(0.0, 1.0);
+        let reconnect_penalty = (recent_reconnects as f64 / RECONNECTS_FOR_WORST).min(1.0);
+        let quality = 1.0 - (0.5 * rtt_penalty + 0.3 * self.failure_rate + 0.2 * reconnect_penalty);
+        let score = if self.connected {
+            (quality.clamp(0.0, 1.0) * 100.0).round() as u8
+        } else {
+            0
+        };
+        let level = match score {
+            _ if !self.connected => ConnectionQualityLevel::Offline,
+            80.. => ConnectionQualityLevel::Excellent,
+            60..=79 => ConnectionQualityLevel::Good,
+            35..=59 => ConnectionQualityLevel::Fair,
+            _ => ConnectionQualityLevel::Poor,
+        };
+        ConnectionQuality {
+            score,
+            level,
+            rtt_ms,
+            failure_rate: self.failure_rate,
+            recent_reconnects,
+        }
+    }
+}
+
+impl MobileConvexClient {
+    /// Returns the current connection-quality estimate.
+    #[frb(sync)]
+    pub fn connection_quality(&self) -> ConnectionQuality {
+        self.quality.current()
+    }
+
+    /// Registers a callback invoked with the current connection quality and
+    /// then whenever the score changes significantly or the level changes.
+    #[frb]
+    pub async fn on_connection_quality_change(
+        &self,
+        on_change: impl Fn(ConnectionQuality) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let mut quality_rx = self.quality.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                let quality = quality_rx.borrow_and_update().clone();
+                let _ = on_change(quality).await;
+                if quality_rx.changed().await.is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disconnected_is_offline() {
+        let tracker = QualityTracker::new();
+        let quality = tracker.current();
+        assert_eq!(quality.score, 0);
+        assert_eq!(quality.level, ConnectionQualityLevel::Offline);
+    }
+
+    #[test]
+    fn fast_calls_are_excellent() {
+        let tracker = QualityTracker::new();
+        tracker.record_state(&WebSocketConnectionState::Connected);
+        tracker.record_call(Duration::from_millis(50), true);
+        let quality = tracker.current();
+        assert_eq!(quality.score, 100);
+        assert_eq!(quality.level, ConnectionQualityLevel::Excellent);
+    }
+
+    #[test]
+    fn failures_and_reconnects_lower_the_score() {
+        let tracker = QualityTracker::new();
+        tracker.record_state(&WebSocketConnectionState::Connected);
+        for _ in 0..3 {
+            tracker.record_state(&WebSocketConnectionState::Connecting);
+            tracker.record_state(&WebSocketConnectionState::Connected);
+        }
+        for _ in 0..10 {
+            tracker.record_call(Duration::from_millis(3000), false);
+        }
+        let quality = tracker.current();
+        assert_eq!(quality.recent_reconnects, 3);
+        assert!(quality.failure_rate > 0.8);
+        assert!(quality.score < 80, "score was {}", quality.score);
+    }
+
+    #[test]
+    fn small_changes_are_not_published() {
+        let tracker = QualityTracker::new();
+        tracker.record_state(&WebSocketConnectionState::Connected);
+        let mut rx = tracker.subscribe();
+        rx.mark_unchanged();
+        tracker.record_call(Duration::from_millis(160), true);
+        assert!(!rx.has_changed().unwrap());
+    }
+}
@@ -0,0 +1,303 @@
+//! In-memory cache of one-shot query results with declared dependencies.
+//!
+//! Queries read through [`MobileConvexClient::cached_query`] are cached by
+//! function name and arguments. Apps declare which mutations and other
+//! queries a query depends on; when a mutation succeeds, every cached query
+//! depending on it, directly or through other queries, is invalidated and
+//! refetched before the mutation call returns, even though no subscription
+//! keeps it live. Refetches are queries like any other, so interceptors,
+//! injected faults and deferred mutations apply to them as well.
+
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+
+use convex::{FunctionResult, Value};
+use flutter_rust_bridge::frb;
+use futures::future::join_all;
+use log::debug;
+use parking_lot::Mutex;
+
+use crate::{
+    events::ClientEvent,
+    result::handle_direct_function_result,
+    value::{value_to_json_string, value_to_json_string_as},
+    ClientError, MobileConvexClient,
+};
+
+/// Something a cached query depends on, identified by function name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[frb]
+pub enum QueryDependency {
+    /// Invalidate after a successful call of this mutation.
+    Mutation { name: String },
+    /// Invalidate whenever this query is invalidated.
+    Query { name: String },
+}
+
+/// Cache key: function name and the canonical JSON of the arguments.
+type CacheKey = (String, String);
+
+#[frb(ignore)]
+struct CacheEntry {
+    args: BTreeMap<String, Value>,
+    value: Option<String>,
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+struct CacheState {
+    entries: HashMap<CacheKey, CacheEntry>,
+    // Query name -> its declared dependencies.
+    dependencies: HashMap<String, HashSet<QueryDependency>>,
+}
+
+/// A cached query scheduled for refetching.
+pub(crate) struct Refetch {
+    name: String,
+    args: BTreeMap<String, Value>,
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct QueryCache {
+    state: Mutex<CacheState>,
+}
+
+fn cache_key(name: &str, args: &BTreeMap<String, Value>) -> CacheKey {
+    (
+        name.to_owned(),
+        value_to_json_string(Value::Object(args.clone())),
+    )
+}
+
+impl QueryCache {
+    pub(crate) fn declare(&self, query: String, dependencies: Vec<QueryDependency>) {
+        self.state
+            .lock()
+            .dependencies
+            .entry(query)
+            .or_default()
+            .extend(dependencies);
+    }
+
+    pub(crate) fn get(&self, name: &str, args: &BTreeMap<String, Value>) -> Option<String> {
+        let state = self.state.lock();
+        state.entries.get(&cache_key(name, args))?.value.clone()
+    }
+
+    pub(crate) fn store(&self, name: &str, args: BTreeMap<String, Value>, value: String) {
+        let key = cache_key(name, &args);
+        self.state.lock().entries.insert(
+            key,
+            CacheEntry {
+                args,
+                value: Some(value),
+            },
+        );
+    }
+
+    /// Invalidates cached queries depending on `dependency`, transitively, and
+    /// returns the entries to refetch.
+    pub(crate) fn invalidate(&self, dependency: QueryDependency) -> Vec<Refetch> {
+        let mut state = self.state.lock();
+        let mut affected: HashSet<String> = HashSet::new();
+        if let QueryDependency::Query { name } = &dependency {
+            affected.insert(name.clone());
+        }
+        let mut queue = VecDeque::from([dependency]);
+        while let Some(dependency) = queue.pop_front() {
+            for (query, dependencies) in &state.dependencies {
+                if dependencies.contains(&dependency) && affected.insert(query.clone()) {
+                    queue.push_back(QueryDependency::Query {
+                        name: query.clone(),
+                    });
+                }
+            }
+        }
+        state
+            .entries
+            .iter_mut()
+            .filter(|((name, _), _)| affected.contains(name))
+            .map(|((name, _), entry)| {
+                entry.value = None;
+                Refetch {
+                    name: name.clone(),
+                    args: entry.args.clone(),
+                }
+            })
+            .collect()
+    }
+
+    /// Drops all cached results, keeping the declared dependencies.
+    pub(crate) fn clear(&self) {
+        self.state.lock().entries.clear();
+    }
+
+    /// Drops all cached results and their storage, returning how many there
+    /// were and their approximate size in bytes.
+    pub(crate) fn release(&self) -> (usize, usize) {
+        let mut state = self.state.lock();
+        let released = state.entries.len();
+        let bytes = state
+            .entries
+            .iter()
+            .map(|((name, args), entry)| {
+                name.len() + args.len() + entry.value.as_ref().map_or(0, String::len)
+            })
+            .sum();
+        state.entries = HashMap::new();
+        (released, bytes)
+    }
+
+    fn is_invalidated(&self, name: &str, args: &BTreeMap<String, Value>) -> bool {
+        let state = self.state.lock();
+        state
+            .entries
+            .get(&cache_key(name, args))
+            .is_some_and(|entry| entry.value.is_none())
+    }
+}
+
+impl MobileConvexClient {
+    /// Refetches invalidated queries concurrently, like any other query.
+    async fn refetch(&self, refetches: Vec<Refetch>) {
+        join_all(
+            refetches
+                .into_iter()
+                .map(|Refetch { name, args }| async move {
+                    debug!("Refetching cached query {name}");
+                    match self.internal_query(name.clone(), args.clone()).await {
+                        // Skip if a newer result was stored in the meantime.
+                        Ok(FunctionResult::Value(value))
+                            if self.query_cache.is_invalidated(&name, &args) =>
+                        {
+                            let value = value_to_json_string_as(value, self.options.int64_encoding);
+                            self.query_cache.store(&name, args, value);
+                        }
+                        Ok(FunctionResult::Value(_)) => {}
+                        Ok(other) => debug!("Refetching {name} failed: {other:?}"),
+                        Err(e) => debug!("Refetching {name} failed: {e}"),
+                    }
+                }),
+        )
+        .await;
+    }
+
+    /// Executes a query, answering from the cache when possible.
+    #[frb]
+    pub async fn cached_query(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+    ) -> Result<String, ClientError> {
+        let args = self.parse_args(args)?;
+        if let Some(value) = self.query_cache.get(&name, &args) {
+            self.events.emit(ClientEvent::CacheHit { name });
+            return Ok(self.decode_result(value));
+        }
+        let result = self.internal_query(name.clone(), args.clone()).await?;
+        // Cached as received, so encrypted fields stay encrypted.
+        let value = handle_direct_function_result(result, self.options.int64_encoding)?;
+        self.query_cache.store(&name, args, value.clone());
+        Ok(self.decode_result(value))
+    }
+
+    /// Declares what the cached results of query `query_name` depend on.
+    /// Declarations accumulate across calls.
+    #[frb(sync)]
+    pub fn declare_query_dependencies(
+        &self,
+        query_name: String,
+        dependencies: Vec<QueryDependency>,
+    ) {
+        self.query_cache.declare(query_name, dependencies);
+    }
+
+    /// Invalidates and refetches the cached results of `query_name` and of
+    /// all queries depending on it.
+    #[frb]
+    pub async fn invalidate_cached_query(&self, query_name: String) -> Result<(), ClientError> {
+        let refetches = self
+            .query_cache
+            .invalidate(QueryDependency::Query { name: query_name });
+        self.refetch(refetches).await;
+        Ok(())
+    }
+
+    /// Invalidates and refetches cached queries depending on a mutation that
+    /// just succeeded.
+    pub(crate) async fn invalidate_after_mutation(&self, mutation_name: &str) {
+        let refetches = self.query_cache.invalidate(QueryDependency::Mutation {
+            name: mutation_name.to_owned(),
+        });
+        self.refetch(refetches).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use maplit::btreemap;
+
+    use super::*;
+
+    fn mutation(name: &str) -> QueryDependency {
+        QueryDependency::Mutation { name: name.into() }
+    }
+
+    fn query(name: &str) -> QueryDependency {
+        QueryDependency::Query { name: name.into() }
+    }
+
+    fn refetched(mut refetches: Vec<Refetch>) -> Vec<String> {
+        refetches.sort_by(|a, b| a.name.cmp(&b.name));
+        refetches.into_iter().map(|r| r.name).collect()
+    }
+
+    #[test]
+    fn results_are_cached_per_arguments() {
+        let cache = QueryCache::default();
+        let one = btreemap! {"id".to_string() => Value::Float64(1.0)};
+        let two = btreemap! {"id".to_string() => Value::Float64(2.0)};
+        cache.store("get", one.clone(), "a".into());
+        assert_eq!(cache.get("get", &one), Some("a".into()));
+        assert_eq!(cache.get("get", &two), None);
+        assert_eq!(cache.get("other", &one), None);
+    }
+
+    #[test]
+    fn mutations_invalidate_dependents_transitively() {
+        let cache = QueryCache::default();
+        cache.declare("list".into(), vec![mutation("send")]);
+        cache.declare("count".into(), vec![query("list")]);
+        cache.declare("summary".into(), vec![query("count")]);
+        cache.declare("unrelated".into(), vec![mutation("other")]);
+        for name in ["list", "count", "summary", "unrelated"] {
+            cache.store(name, BTreeMap::new(), "v".into());
+        }
+
+        let refetches = cache.invalidate(mutation("send"));
+        assert_eq!(refetched(refetches), ["count", "list", "summary"]);
+        assert_eq!(cache.get("summary", &BTreeMap::new()), None);
+        assert_eq!(cache.get("unrelated", &BTreeMap::new()), Some("v".into()));
+    }
+
+    #[test]
+    fn invalidating_a_query_includes_itself_and_survives_cycles() {
+        let cache = QueryCache::default();
+        cache.declare("a".into(), vec![query("b")]);
+        cache.declare("b".into(), vec![query("a")]);
+        cache.store("a", BTreeMap::new(), "v".into());
+        cache.store("b", BTreeMap::new(), "v".into());
+        assert_eq!(refetched(cache.invalidate(query("a"))), ["a", "b"]);
+    }
+
+    #[test]
+    fn refetch_result_is_only_stored_while_invalidated() {
+        let cache = QueryCache::default();
+        cache.declare("list".into(), vec![mutation("send")]);
+        cache.store("list", BTreeMap::new(), "old".into());
+        cache.invalidate(mutation("send"));
+        assert!(cache.is_invalidated("list", &BTreeMap::new()));
+        cache.store("list", BTreeMap::new(), "new".into());
+        assert!(!cache.is_invalidated("list", &BTreeMap::new()));
+    }
+}
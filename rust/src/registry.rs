@@ -0,0 +1,199 @@
+//! Registry of live subscriptions, for finding leaks.
+//!
+//! Every subscription made through [`MobileConvexClient::subscribe`] and its
+//! variants is recorded with its function name, a hash of its arguments, its
+//! creation time and the number of updates delivered so far.
+//! [`MobileConvexClient::active_subscriptions`] lists those neither cancelled
+//! nor ended, and [`MobileConvexClient::cancel_all_subscriptions`] cancels
+//! them.
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use convex::Value;
+use flutter_rust_bridge::frb;
+use parking_lot::Mutex;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    presence::now_millis, value::value_to_json_string, MobileConvexClient, QuerySubscriber,
+    SubscriptionHandle,
+};
+
+/// A live subscription, as listed by
+/// [`MobileConvexClient::active_subscriptions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct SubscriptionInfo {
+    /// Registration order, unique per client.
+    pub id: u64,
+    pub name: String,
+    /// Short hash of the arguments, equal for equal arguments.
+    pub args_hash: String,
+    /// Unix time in milliseconds of subscribing.
+    pub created_at_ms: i64,
+    /// Updates delivered so far.
+    pub update_count: u64,
+}
+
+fn args_hash(args: &BTreeMap<String, Value>) -> String {
+    let json = value_to_json_string(Value::Object(args.clone()));
+    hex::encode(&Sha256::digest(json.as_bytes())[..8])
+}
+
+struct RegisteredSubscription {
+    info: SubscriptionInfo,
+    updates: Arc<AtomicU64>,
+    handle: SubscriptionHandle,
+}
+
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct SubscriptionRegistry {
+    next_id: AtomicU64,
+    subscriptions: Mutex<Vec<RegisteredSubscription>>,
+}
+
+impl SubscriptionRegistry {
+    /// Records a subscription whose delivered updates are counted in
+    /// `updates`.
+    pub(crate) fn register(
+        &self,
+        name: &str,
+        args: &BTreeMap<String, Value>,
+        updates: Arc<AtomicU64>,
+        handle: &SubscriptionHandle,
+    ) {
+        let info = SubscriptionInfo {
+            id: self.next_id.fetch_add(1, Ordering::SeqCst),
+            name: name.to_owned(),
+            args_hash: args_hash(args),
+            created_at_ms: now_millis(),
+            update_count: 0,
+        };
+        let mut subscriptions = self.subscriptions.lock();
+        subscriptions.retain(|subscription| subscription.handle.is_active());
+        subscriptions.push(RegisteredSubscription {
+            info,
+            updates,
+            handle: handle.share(),
+        });
+    }
+
+    fn active(&self) -> Vec<SubscriptionInfo> {
+        let mut subscriptions = self.subscriptions.lock();
+        subscriptions.retain(|subscription| subscription.handle.is_active());
+        subscriptions
+            .iter()
+            .map(|subscription| SubscriptionInfo {
+                update_count: subscription.updates.load(Ordering::SeqCst),
+                ..subscription.info.clone()
+            })
+            .collect()
+    }
+
+    /// Cancels all subscriptions, returning how many were live.
+    fn cancel_all(&self) -> u32 {
+        let subscriptions = std::mem::take(&mut *self.subscriptions.lock());
+        subscriptions
+            .into_iter()
+            .filter(|subscription| subscription.handle.stop())
+            .count() as u32
+    }
+}
+
+/// Counts the updates passed to `inner`.
+#[frb(ignore)]
+pub(crate) struct CountingSubscriber {
+    inner: Arc<dyn QuerySubscriber>,
+    updates: Arc<AtomicU64>,
+}
+
+impl CountingSubscriber {
+    pub(crate) fn new(inner: Arc<dyn QuerySubscriber>) -> (Arc<Self>, Arc<AtomicU64>) {
+        let updates = Arc::new(AtomicU64::new(0));
+        let subscriber = Arc::new(CountingSubscriber {
+            inner,
+            updates: updates.clone(),
+        });
+        (subscriber, updates)
+    }
+}
+
+impl QuerySubscriber for CountingSubscriber {
+    fn on_update(&self, value: String) {
+        self.updates.fetch_add(1, Ordering::SeqCst);
+        self.inner.on_update(value);
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.inner.on_error(message, value);
+    }
+
+    fn on_done(&self) {
+        self.inner.on_done();
+    }
+}
+
+impl MobileConvexClient {
+    /// Returns the subscriptions that are neither cancelled nor ended,
+    /// oldest first.
+    #[frb(sync)]
+    pub fn active_subscriptions(&self) -> Vec<SubscriptionInfo> {
+        self.registry.active()
+    }
+
+    /// Cancels every live subscription, returning how many there were.
+    #[frb(sync)]
+    pub fn cancel_all_subscriptions(&self) -> u32 {
+        self.registry.cancel_all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::oneshot;
+    use maplit::btreemap;
+
+    use super::*;
+
+    struct Ignore;
+
+    impl QuerySubscriber for Ignore {
+        fn on_update(&self, _value: String) {}
+
+        fn on_error(&self, _message: String, _value: Option<String>) {}
+    }
+
+    #[test]
+    fn live_subscriptions_are_listed_and_cancelled() {
+        let registry = SubscriptionRegistry::default();
+        let (subscriber, updates) = CountingSubscriber::new(Arc::new(Ignore));
+        let (cancel_tx, mut cancel_rx) = oneshot::channel();
+        let handle = SubscriptionHandle::new(cancel_tx);
+        let args = btreemap! {"channel".to_owned() => Value::from("general")};
+        registry.register("messages:list", &args, updates, &handle);
+        let (ended_tx, ended_rx) = oneshot::channel::<()>();
+        let ended = SubscriptionHandle::new(ended_tx);
+        registry.register("messages:count", &args, Arc::default(), &ended);
+        drop(ended_rx);
+
+        subscriber.on_update("[]".into());
+        subscriber.on_update("[1]".into());
+        let active = registry.active();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].name, "messages:list");
+        assert_eq!(active[0].update_count, 2);
+        assert_eq!(active[0].args_hash, args_hash(&args));
+        assert_ne!(active[0].args_hash, args_hash(&BTreeMap::new()));
+
+        assert_eq!(registry.cancel_all(), 1);
+        assert_eq!(cancel_rx.try_recv(), Ok(Some(())));
+        assert!(registry.active().is_empty());
+    }
+}
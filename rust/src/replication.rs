@@ -0,0 +1,420 @@
+//! Local replicas of subscribed documents.
+//!
+//! Apps that work offline want to read their data without waiting for the
+//! connection. [`MobileConvexClient::replicate_query`] subscribes to a query
+//! returning documents, a list of them or a single one, and mirrors them
+//! into a local table, keyed by `_id`. Each update inserts and updates the
+//! documents it returns and removes those the same query returned before
+//! but no longer does; documents another replicated query still returns are
+//! kept. [`MobileConvexClient::replica_get`] and
+//! [`MobileConvexClient::replica_query`] read the table without a round
+//! trip, also while offline or after a restart.
+//!
+//! Tables are stored in a SQLite database in the storage partition of the
+//! current identity (see [`crate::storage`]), one row per document, so an
+//! update only writes the documents it changed. Rows are encrypted once the
+//! app sets a storage key (see [`crate::storage_encryption`]). Updates are
+//! written in the order they arrive, off the async runtime's workers.
+//! Without a storage root, and for incognito clients, tables are kept in
+//! memory only. Updates are not mirrored while field encryption is set.
+
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fs,
+    path::PathBuf,
+    sync::Arc,
+};
+
+use anyhow::{bail, Context};
+use flutter_rust_bridge::frb;
+use log::warn;
+use parking_lot::Mutex;
+use rusqlite::{params, Connection};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use tokio::sync::mpsc;
+
+use crate::{
+    encryption::FieldEncryption, resubscribe::SubscriptionPriority, storage::ScopedStorage,
+    storage_encryption::StorageCipher, value::value_to_json_string, ClientError,
+    MobileConvexClient, QuerySubscriber, SubscriptionHandle,
+};
+
+/// Directory of the replica database in a storage partition.
+const REPLICA_DIR: &str = "replica";
+/// File name of the replica database.
+const DATABASE_FILE: &str = "replica.sqlite3";
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[frb(ignore)]
+struct ReplicaRow {
+    document: JsonValue,
+    /// Replicated queries whose last result contained the document.
+    sources: BTreeSet<String>,
+}
+
+type Table = BTreeMap<String, ReplicaRow>;
+
+/// Applies a result of the replicated query `source` to `table` and returns
+/// the IDs of the rows it inserted, changed or removed.
+fn apply_result(table: &mut Table, source: &str, result: JsonValue) -> BTreeSet<String> {
+    let documents = match result {
+        JsonValue::Array(documents) => documents,
+        JsonValue::Null => Vec::new(),
+        document => vec![document],
+    };
+    let mut returned = BTreeSet::new();
+    for document in documents {
+        let Some(id) = document.get("_id").and_then(JsonValue::as_str) else {
+            continue;
+        };
+        let id = id.to_owned();
+        let row = table.entry(id.clone()).or_default();
+        row.document = document;
+        row.sources.insert(source.to_owned());
+        returned.insert(id);
+    }
+    let mut touched = returned.clone();
+    table.retain(|id, row| {
+        if !returned.contains(id) && row.sources.remove(source) {
+            touched.insert(id.clone());
+        }
+        !row.sources.is_empty()
+    });
+    touched
+}
+
+/// Returns whether `document` has every field of `filter` with the given
+/// value.
+fn matches(document: &JsonValue, filter: &BTreeMap<String, JsonValue>) -> bool {
+    filter
+        .iter()
+        .all(|(field, value)| document.get(field) == Some(value))
+}
+
+fn check_table_name(table: &str) -> anyhow::Result<()> {
+    let valid = !table.is_empty() && table.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        bail!("`{table}` is not a valid replica table name");
+    }
+    Ok(())
+}
+
+/// The replica database of one identity, with the tables read from it.
+struct Store {
+    // `None` while tables are kept in memory only
+    connection: Option<Connection>,
+    tables: HashMap<String, Table>,
+}
+
+impl Store {
+    fn open(path: Option<&PathBuf>) -> anyhow::Result<Self> {
+        let connection = match path {
+            Some(path) => {
+                if let Some(dir) = path.parent() {
+                    fs::create_dir_all(dir)?;
+                }
+                let connection = Connection::open(path)
+                    .with_context(|| format!("opening {}", path.display()))?;
+                connection.execute_batch(
+                    "PRAGMA journal_mode = WAL;
+                     CREATE TABLE IF NOT EXISTS rows (
+                         tbl TEXT NOT NULL,
+                         id TEXT NOT NULL,
+                         row BLOB NOT NULL,
+                         PRIMARY KEY (tbl, id)
+                     ) WITHOUT ROWID;",
+                )?;
+                Some(connection)
+            }
+            None => None,
+        };
+        Ok(Store {
+            connection,
+            tables: HashMap::new(),
+        })
+    }
+
+    /// Returns the table, reading its rows from the database first if
+    /// needed.
+    fn table(&mut self, table: &str, cipher: &StorageCipher) -> anyhow::Result<&mut Table> {
+        if !self.tables.contains_key(table) {
+            let mut rows = Table::new();
+            if let Some(connection) = &self.connection {
+                let mut statement =
+                    connection.prepare("SELECT id, row FROM rows WHERE tbl = ?1")?;
+                let mut stored = statement.query(params![table])?;
+                while let Some(stored) = stored.next()? {
+                    let row = cipher.decrypt(stored.get(1)?)?;
+                    rows.insert(stored.get(0)?, serde_json::from_slice(&row)?);
+                }
+            }
+            self.tables.insert(table.to_owned(), rows);
+        }
+        Ok(self.tables.get_mut(table).expect("table was just loaded"))
+    }
+
+    /// Writes the rows `ids` of `table` to the database in one transaction.
+    fn write(
+        &mut self,
+        table: &str,
+        ids: &BTreeSet<String>,
+        cipher: &StorageCipher,
+    ) -> anyhow::Result<()> {
+        let Some(connection) = &mut self.connection else {
+            return Ok(());
+        };
+        let rows = &self.tables[table];
+        let transaction = connection.transaction()?;
+        for id in ids {
+            match rows.get(id) {
+                Some(row) => {
+                    let row = cipher.encrypt(serde_json::to_vec(row)?)?;
+                    transaction.execute(
+                        "INSERT OR REPLACE INTO rows (tbl, id, row) VALUES (?1, ?2, ?3)",
+                        params![table, id, row],
+                    )?;
+                }
+                None => {
+                    transaction.execute(
+                        "DELETE FROM rows WHERE tbl = ?1 AND id = ?2",
+                        params![table, id],
+                    )?;
+                }
+            }
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+}
+
+/// The replicated tables of the current identity.
+pub(crate) struct Replica {
+    storage: Option<ScopedStorage>,
+    identity: Arc<Mutex<Option<String>>>,
+    cipher: StorageCipher,
+    // Open databases, by file, or the in-memory tables under `None`
+    stores: Mutex<HashMap<Option<PathBuf>, Store>>,
+}
+
+impl Replica {
+    pub(crate) fn new(
+        storage: Option<ScopedStorage>,
+        identity: Arc<Mutex<Option<String>>>,
+        cipher: StorageCipher,
+    ) -> Arc<Self> {
+        Arc::new(Replica {
+            storage,
+            identity,
+            cipher,
+            stores: Mutex::default(),
+        })
+    }
+
+    /// Path of the current identity's database, or `None` without storage.
+    fn path(&self) -> Option<PathBuf> {
+        let storage = self.storage.as_ref()?;
+        let identity = self.identity.lock().clone();
+        Some(
+            storage
+                .partition(identity.as_deref())
+                .join(REPLICA_DIR)
+                .join(DATABASE_FILE),
+        )
+    }
+
+    /// Runs `f` on the current identity's store, opening it first if
+    /// needed. A database deleted with the identity's data is opened anew.
+    fn with_store<T>(&self, f: impl FnOnce(&mut Store) -> anyhow::Result<T>) -> anyhow::Result<T> {
+        let path = self.path();
+        let mut stores = self.stores.lock();
+        if path.as_ref().is_some_and(|path| !path.exists()) {
+            stores.remove(&path);
+        }
+        if !stores.contains_key(&path) {
+            let store = Store::open(path.as_ref())?;
+            stores.insert(path.clone(), store);
+        }
+        f(stores.get_mut(&path).expect("store was just opened"))
+    }
+
+    /// Runs `f` on the table, reading it from the database first if needed.
+    fn with_table<T>(&self, table: &str, f: impl FnOnce(&mut Table) -> T) -> anyhow::Result<T> {
+        self.with_store(|store| Ok(f(store.table(table, &self.cipher)?)))
+    }
+
+    /// Applies a result of `source` to `table` and writes the changed rows
+    /// while still holding the lock, so concurrent updates are written in
+    /// the order they were applied. Blocks on the database.
+    fn apply(&self, table: &str, source: &str, result: JsonValue) -> anyhow::Result<()> {
+        self.with_store(|store| {
+            let touched = apply_result(store.table(table, &self.cipher)?, source, result);
+            let written = store.write(table, &touched, &self.cipher);
+            if written.is_err() {
+                // Read the rows again rather than keep unwritten changes.
+                store.tables.remove(table);
+            }
+            written
+        })
+    }
+}
+
+/// Hands the results of a replicated query to the task writing them into
+/// its table.
+#[frb(ignore)]
+struct ReplicaSubscriber {
+    table: String,
+    results: mpsc::UnboundedSender<JsonValue>,
+    encryption: FieldEncryption,
+}
+
+impl QuerySubscriber for ReplicaSubscriber {
+    fn on_update(&self, value: String) {
+        if self.encryption.is_set() {
+            return;
+        }
+        let result = match serde_json::from_str(&value) {
+            Ok(result) => result,
+            Err(e) => return warn!("Not replicating an invalid result: {e}"),
+        };
+        // Fails only once the client is gone.
+        let _ = self.results.send(result);
+    }
+
+    fn on_error(&self, message: String, _value: Option<String>) {
+        // The table keeps the last result.
+        warn!("Replicated query for {} failed: {message}", self.table);
+    }
+}
+
+impl MobileConvexClient {
+    /// Subscribes to `query_name` with `query_args` and mirrors the returned
+    /// documents into the replica table `table`, as described in the
+    /// [module docs](crate::replication). Table names consist of ASCII
+    /// letters, digits and underscores. Cancelling the subscription stops
+    /// mirroring; the table keeps its rows.
+    #[frb]
+    pub async fn replicate_query(
+        &self,
+        table: String,
+        query_name: String,
+        query_args: HashMap<String, String>,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        check_table_name(&table)?;
+        let args = self.parse_args(query_args)?;
+        let source = format!(
+            "{query_name}:{}",
+            value_to_json_string(convex::Value::Object(args.clone()))
+        );
+        let (results, mut received) = mpsc::unbounded_channel();
+        let replica = self.replica.clone();
+        let name = table.clone();
+        // Ends once the subscriber is dropped.
+        self.rt.spawn(async move {
+            while let Some(result) = received.recv().await {
+                let (replica, table, source) = (replica.clone(), name.clone(), source.clone());
+                let applied =
+                    tokio::task::spawn_blocking(move || replica.apply(&table, &source, result))
+                        .await
+                        .map_err(anyhow::Error::from)
+                        .and_then(|applied| applied);
+                if let Err(e) = applied {
+                    warn!("Updating the replica table {name} failed: {e}");
+                }
+            }
+        });
+        let subscriber = Arc::new(ReplicaSubscriber {
+            table,
+            results,
+            encryption: self.field_encryption.clone(),
+        });
+        self.internal_subscribe(query_name, args, subscriber, SubscriptionPriority::Normal)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Returns the document with `id` from the replica table `table`,
+    /// serialized as JSON.
+    #[frb(sync)]
+    pub fn replica_get(&self, table: String, id: String) -> Result<Option<String>, ClientError> {
+        check_table_name(&table)?;
+        let document = self.replica.with_table(&table, |rows| {
+            rows.get(&id).map(|row| row.document.to_string())
+        })?;
+        Ok(document)
+    }
+
+    /// Returns the documents of the replica table `table`, serialized as
+    /// JSON and ordered by `_id`, whose fields equal those of `filter`. The
+    /// filter values are JSON, e.g. `{"done": "false"}`.
+    #[frb(sync)]
+    pub fn replica_query(
+        &self,
+        table: String,
+        filter: HashMap<String, String>,
+    ) -> Result<Vec<String>, ClientError> {
+        check_table_name(&table)?;
+        let filter = filter
+            .into_iter()
+            .map(|(field, value)| Ok((field, serde_json::from_str(&value)?)))
+            .collect::<anyhow::Result<BTreeMap<_, _>>>()?;
+        let documents = self.replica.with_table(&table, |rows| {
+            rows.values()
+                .filter(|row| matches(&row.document, &filter))
+                .map(|row| row.document.to_string())
+                .collect()
+        })?;
+        Ok(documents)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn rows_are_kept_while_a_replicated_query_returns_them() {
+        let root = tempfile::tempdir().unwrap();
+        let storage = || Some(ScopedStorage::new(root.path(), "https://a.convex.cloud"));
+        let identity = Arc::new(Mutex::new(Some("user-1".to_owned())));
+        let replica = Replica::new(storage(), identity.clone(), StorageCipher::new(false));
+        let open = json!([{"_id": "a", "done": false}, {"_id": "b", "done": false}]);
+        replica.apply("tasks", "tasks:open", open).unwrap();
+        replica
+            .apply("tasks", "tasks:get", json!({"_id": "b", "done": true}))
+            .unwrap();
+        replica
+            .apply("tasks", "tasks:open", json!([{"_id": "a", "done": false}]))
+            .unwrap();
+
+        // A restarted client reads the table from its database.
+        let restarted = Replica::new(storage(), identity.clone(), StorageCipher::new(false));
+        let rows = restarted.with_table("tasks", |rows| rows.clone()).unwrap();
+        assert_eq!(rows.keys().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(rows["b"].document, json!({"_id": "b", "done": true}));
+        let done = BTreeMap::from([("done".to_owned(), json!(true))]);
+        assert!(matches(&rows["b"].document, &done));
+        assert!(!matches(&rows["a"].document, &done));
+
+        restarted
+            .apply("tasks", "tasks:get", JsonValue::Null)
+            .unwrap();
+        let ids = restarted
+            .with_table("tasks", |rows| rows.keys().cloned().collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(ids, ["a"]);
+
+        // Rows that cannot be written are not kept in memory either.
+        let locked = Replica::new(storage(), identity, StorageCipher::new(true));
+        let result = locked.apply("tasks", "tasks:get", json!({"_id": "c"}));
+        assert!(result.is_err());
+        let ids = locked
+            .with_table("tasks", |rows| rows.keys().cloned().collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(ids, ["a"]);
+        assert!(check_table_name("tasks_2").is_ok());
+        assert!(check_table_name("../tasks").is_err());
+    }
+}
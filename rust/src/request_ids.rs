@@ -0,0 +1,139 @@
+//! Request IDs for correlating client reports with backend logs.
+//!
+//! A `ServerError` message alone gives support nothing to search for. Every
+//! query, mutation and action is therefore sent under a new request ID,
+//! which the debug logs mention and which is appended to the messages of
+//! server and internal errors as `[request <id>]`. With
+//! [`RequestIdOptions::arg_name`] set, the ID is also sent as that
+//! argument, for the backend function to log. The data of a `ConvexError`
+//! belongs to the app and is left unchanged.
+
+use std::{collections::BTreeMap, future::Future};
+
+use anyhow::bail;
+use convex::{FunctionResult, Value};
+use flutter_rust_bridge::frb;
+use log::debug;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{interceptors::CallKind, MobileConvexClient};
+
+/// Where request IDs are reported.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct RequestIdOptions {
+    /// Whether error messages end with the request ID.
+    pub in_errors: bool,
+    /// Argument receiving the request ID, e.g. `requestId`. Not sent when
+    /// `None`.
+    pub arg_name: Option<String>,
+}
+
+impl Default for RequestIdOptions {
+    fn default() -> Self {
+        RequestIdOptions {
+            in_errors: true,
+            arg_name: None,
+        }
+    }
+}
+
+/// Returns a new request ID of 16 hexadecimal digits.
+fn new_request_id() -> String {
+    Uuid::new_v4().simple().to_string()[..16].to_owned()
+}
+
+/// Adds `id` to `args` under `arg_name`, if set.
+fn with_request_id(
+    mut args: BTreeMap<String, Value>,
+    arg_name: Option<&str>,
+    id: &str,
+) -> anyhow::Result<BTreeMap<String, Value>> {
+    let Some(arg_name) = arg_name else {
+        return Ok(args);
+    };
+    if args.contains_key(arg_name) {
+        bail!("Argument `{arg_name}` is reserved for the request ID");
+    }
+    args.insert(arg_name.to_owned(), Value::String(id.to_owned()));
+    Ok(args)
+}
+
+/// Appends `id` to the message of a server or internal error in `result`.
+fn annotate(result: anyhow::Result<FunctionResult>, id: &str) -> anyhow::Result<FunctionResult> {
+    match result {
+        Ok(FunctionResult::ErrorMessage(message)) => Ok(FunctionResult::ErrorMessage(format!(
+            "{message} [request {id}]"
+        ))),
+        Err(error) => {
+            let message = format!("{error} [request {id}]");
+            Err(error.context(message))
+        }
+        other => other,
+    }
+}
+
+impl MobileConvexClient {
+    /// Sends a call of `name` with `send` under a new request ID, as
+    /// described in the [module docs](crate::request_ids). Calls on a
+    /// closed client fail without one, as they are never sent.
+    pub(crate) async fn send_with_request_id<F, Fut>(
+        &self,
+        kind: CallKind,
+        name: String,
+        args: BTreeMap<String, Value>,
+        send: F,
+    ) -> anyhow::Result<FunctionResult>
+    where
+        F: FnOnce(String, BTreeMap<String, Value>) -> Fut,
+        Fut: Future<Output = anyhow::Result<FunctionResult>>,
+    {
+        self.ensure_open()?;
+        let options = &self.options.request_ids;
+        let id = new_request_id();
+        debug!("Sending {kind:?} {name} as request {id}");
+        let result = match with_request_id(args, options.arg_name.as_deref(), &id) {
+            Ok(args) => send(name, args).await,
+            Err(error) => Err(error),
+        };
+        debug!("Request {id} finished");
+        if options.in_errors {
+            annotate(result, &id)
+        } else {
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::anyhow;
+
+    use super::*;
+
+    #[test]
+    fn ids_are_sent_and_appended_to_errors() {
+        let id = new_request_id();
+        assert_eq!(id.len(), 16);
+        assert_ne!(id, new_request_id());
+
+        let args = BTreeMap::from([("text".to_owned(), Value::String("hi".into()))]);
+        assert_eq!(with_request_id(args.clone(), None, &id).unwrap(), args);
+        let sent = with_request_id(args, Some("requestId"), &id).unwrap();
+        assert_eq!(sent["requestId"], Value::String(id.clone()));
+        assert!(with_request_id(sent, Some("requestId"), &id).is_err());
+
+        let failed = annotate(Ok(FunctionResult::ErrorMessage("Uncaught".into())), "ab12");
+        assert!(matches!(failed, Ok(FunctionResult::ErrorMessage(message))
+            if message == "Uncaught [request ab12]"));
+        let lost = annotate(Err(anyhow!("connection lost")), "ab12");
+        assert_eq!(
+            lost.unwrap_err().to_string(),
+            "connection lost [request ab12]"
+        );
+        let value = annotate(Ok(FunctionResult::Value(Value::Null)), "ab12");
+        assert!(matches!(value, Ok(FunctionResult::Value(Value::Null))));
+    }
+}
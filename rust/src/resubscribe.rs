@@ -0,0 +1,358 @@
+//! Prioritized re-establishment of subscriptions after a reconnect.
+//!
+//! The Convex client resends every active query as soon as the WebSocket
+//! reconnects, which can saturate slow links when many subscriptions are
+//! active. When [`crate::options::ClientOptions::max_concurrent_resubscribes`]
+//! is set, subscriptions drop their query while disconnected and re-subscribe
+//! through a [`ResubscribeScheduler`] once the connection is back: at most the
+//! configured number of subscriptions wait for their first result at a time,
+//! and higher priorities (e.g. the visible screen) go first.
+
+use std::{
+    cmp::Ordering as CmpOrdering,
+    collections::{BTreeMap, BinaryHeap},
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        Arc,
+    },
+};
+
+use convex::{ConvexClient, QuerySubscription, Value};
+use flutter_rust_bridge::frb;
+use futures::{channel::oneshot, pin_mut, select_biased, FutureExt, StreamExt};
+use log::debug;
+use parking_lot::Mutex;
+use tokio::sync::watch;
+
+use crate::{
+    deliver_to_subscriber, options::Int64Encoding, subscription::SubscriptionStateMachine,
+    QuerySubscriber, WebSocketConnectionState,
+};
+
+/// Order in which subscriptions are re-established after a reconnect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[frb]
+pub enum SubscriptionPriority {
+    Low,
+    #[default]
+    Normal,
+    /// Data shown on the visible screen.
+    High,
+}
+
+impl SubscriptionPriority {
+    pub(crate) fn from_u8(value: u8) -> Self {
+        match value {
+            0 => SubscriptionPriority::Low,
+            2 => SubscriptionPriority::High,
+            _ => SubscriptionPriority::Normal,
+        }
+    }
+}
+
+struct Waiter {
+    priority: SubscriptionPriority,
+    seq: u64,
+    grant: oneshot::Sender<ResubscribePermit>,
+}
+
+impl PartialEq for Waiter {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == CmpOrdering::Equal
+    }
+}
+
+impl Eq for Waiter {}
+
+impl PartialOrd for Waiter {
+    fn partial_cmp(&self, other: &Self) -> Option<CmpOrdering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Waiter {
+    // Max-heap: highest priority first, then the longest waiting.
+    fn cmp(&self, other: &Self) -> CmpOrdering {
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+struct SchedulerState {
+    in_flight: usize,
+    next_seq: u64,
+    waiting: BinaryHeap<Waiter>,
+}
+
+/// Limits how many subscriptions are re-established concurrently.
+pub(crate) struct ResubscribeScheduler {
+    max_concurrent: usize,
+    state: Mutex<SchedulerState>,
+}
+
+/// Held while a re-established subscription waits for its first result.
+/// Dropping it lets the next waiting subscription proceed.
+pub(crate) struct ResubscribePermit {
+    scheduler: Option<Arc<ResubscribeScheduler>>,
+}
+
+impl Drop for ResubscribePermit {
+    fn drop(&mut self) {
+        if let Some(scheduler) = self.scheduler.take() {
+            scheduler.release();
+        }
+    }
+}
+
+impl ResubscribeScheduler {
+    pub(crate) fn new(max_concurrent: u32) -> Arc<Self> {
+        Arc::new(ResubscribeScheduler {
+            max_concurrent: max_concurrent.max(1) as usize,
+            state: Mutex::new(SchedulerState {
+                in_flight: 0,
+                next_seq: 0,
+                waiting: BinaryHeap::new(),
+            }),
+        })
+    }
+
+    /// Waits until a re-subscription slot is free for `priority`.
+    pub(crate) async fn acquire(
+        self: &Arc<Self>,
+        priority: SubscriptionPriority,
+    ) -> ResubscribePermit {
+        let granted = {
+            let mut state = self.state.lock();
+            if state.in_flight < self.max_concurrent {
+                state.in_flight += 1;
+                return ResubscribePermit {
+                    scheduler: Some(self.clone()),
+                };
+            }
+            let (grant, granted) = oneshot::channel();
+            let seq = state.next_seq;
+            state.next_seq += 1;
+            state.waiting.push(Waiter {
+                priority,
+                seq,
+                grant,
+            });
+            granted
+        };
+        // The scheduler outlives its waiters, so the sender is never dropped
+        // without sending; fall back to an unaccounted permit regardless.
+        granted
+            .await
+            .unwrap_or(ResubscribePermit { scheduler: None })
+    }
+
+    /// Hands the released slot to the next waiter, skipping cancelled ones.
+    fn release(self: Arc<Self>) {
+        let mut state = self.state.lock();
+        let mut permit = ResubscribePermit {
+            scheduler: Some(self.clone()),
+        };
+        while let Some(waiter) = state.waiting.pop() {
+            match waiter.grant.send(permit) {
+                Ok(()) => return,
+                Err(returned) => permit = returned,
+            }
+        }
+        // Nobody is waiting; disarm so dropping does not release again.
+        permit.scheduler = None;
+        state.in_flight -= 1;
+    }
+}
+
+/// A subscription that is dropped on disconnect and re-established through
+/// the scheduler after reconnecting.
+pub(crate) struct ManagedSubscription {
+    pub(crate) client: ConvexClient,
+    pub(crate) name: String,
+    pub(crate) args: BTreeMap<String, Value>,
+    pub(crate) subscriber: Arc<dyn QuerySubscriber>,
+    pub(crate) priority: Arc<AtomicU8>,
+    pub(crate) scheduler: Arc<ResubscribeScheduler>,
+    pub(crate) state_rx: watch::Receiver<WebSocketConnectionState>,
+    pub(crate) int64_encoding: Int64Encoding,
+}
+
+impl ManagedSubscription {
+    pub(crate) async fn run(
+        mut self,
+        mut subscription: QuerySubscription,
+        cancel_receiver: oneshot::Receiver<()>,
+    ) {
+        let cancel_fut = cancel_receiver.fuse();
+        pin_mut!(cancel_fut);
+        let mut machine =
+            SubscriptionStateMachine::new(true).with_int64_encoding(self.int64_encoding);
+        let mut permit: Option<ResubscribePermit> = None;
+        self.state_rx.mark_unchanged();
+        'subscription: loop {
+            // Deliver results until the connection drops.
+            loop {
+                // `None` signals a connection state change, handled below once
+                // the borrow held by `changed()` is released.
+                let new_val = {
+                    let changed_fut = self.state_rx.changed().fuse();
+                    pin_mut!(changed_fut);
+                    select_biased! {
+                        _ = cancel_fut => break 'subscription,
+                        new_val = subscription.next().fuse() => Some(new_val),
+                        changed = changed_fut => {
+                            if changed.is_err() {
+                                break 'subscription;
+                            }
+                            None
+                        }
+                    }
+                };
+                match new_val {
+                    Some(Some(result)) => {
+                        permit = None;
+                        deliver_to_subscriber(
+                            &self.name,
+                            &*self.subscriber,
+                            machine.on_result(result),
+                        );
+                    }
+                    Some(None) => {
+                        log::warn!("Subscription stream ended for {}", &self.name);
+                        self.subscriber.on_done();
+                        break 'subscription;
+                    }
+                    None => {
+                        if *self.state_rx.borrow_and_update() != WebSocketConnectionState::Connected
+                        {
+                            break;
+                        }
+                    }
+                }
+            }
+
+            // Unsubscribe so the client does not resend this query on reconnect.
+            drop(subscription);
+            permit.take();
+            loop {
+                if *self.state_rx.borrow_and_update() == WebSocketConnectionState::Connected {
+                    break;
+                }
+                let changed_fut = self.state_rx.changed().fuse();
+                pin_mut!(changed_fut);
+                select_biased! {
+                    _ = cancel_fut => break 'subscription,
+                    changed = changed_fut => {
+                        if changed.is_err() {
+                            break 'subscription;
+                        }
+                    }
+                }
+            }
+
+            let priority = SubscriptionPriority::from_u8(self.priority.load(Ordering::SeqCst));
+            let acquire_fut = self.scheduler.acquire(priority).fuse();
+            pin_mut!(acquire_fut);
+            let slot = select_biased! {
+                _ = cancel_fut => break 'subscription,
+                slot = acquire_fut => slot,
+            };
+            debug!("Re-subscribing to {} ({priority:?})", &self.name);
+            subscription = match self.client.subscribe(&self.name, self.args.clone()).await {
+                Ok(subscription) => subscription,
+                Err(e) => {
+                    self.subscriber.on_error(e.to_string(), None);
+                    break 'subscription;
+                }
+            };
+            permit = Some(slot);
+            self.state_rx.mark_unchanged();
+        }
+        debug!("Subscription canceled");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    async fn try_acquire(
+        scheduler: &Arc<ResubscribeScheduler>,
+        priority: SubscriptionPriority,
+    ) -> Option<ResubscribePermit> {
+        tokio::time::timeout(Duration::from_millis(20), scheduler.acquire(priority))
+            .await
+            .ok()
+    }
+
+    #[tokio::test]
+    async fn grants_up_to_the_limit() {
+        let scheduler = ResubscribeScheduler::new(2);
+        let first = try_acquire(&scheduler, SubscriptionPriority::Normal).await;
+        let second = try_acquire(&scheduler, SubscriptionPriority::Normal).await;
+        assert!(first.is_some() && second.is_some());
+        assert!(try_acquire(&scheduler, SubscriptionPriority::High)
+            .await
+            .is_none());
+        drop(first);
+        assert!(try_acquire(&scheduler, SubscriptionPriority::Low)
+            .await
+            .is_some());
+    }
+
+    #[tokio::test]
+    async fn waiters_are_served_by_priority_then_arrival() {
+        let scheduler = ResubscribeScheduler::new(1);
+        let held = scheduler.acquire(SubscriptionPriority::Normal).await;
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut tasks = Vec::new();
+        for (label, priority) in [
+            ("low", SubscriptionPriority::Low),
+            ("normal-1", SubscriptionPriority::Normal),
+            ("high", SubscriptionPriority::High),
+            ("normal-2", SubscriptionPriority::Normal),
+        ] {
+            let scheduler = scheduler.clone();
+            let order = order.clone();
+            tasks.push(tokio::spawn(async move {
+                let _permit = scheduler.acquire(priority).await;
+                order.lock().push(label);
+            }));
+            // Make arrival order deterministic.
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+        drop(held);
+        for task in tasks {
+            task.await.unwrap();
+        }
+        assert_eq!(*order.lock(), vec!["high", "normal-1", "normal-2", "low"]);
+    }
+
+    #[tokio::test]
+    async fn cancelled_waiters_are_skipped() {
+        let scheduler = ResubscribeScheduler::new(1);
+        let held = scheduler.acquire(SubscriptionPriority::Normal).await;
+        assert!(try_acquire(&scheduler, SubscriptionPriority::High)
+            .await
+            .is_none());
+        drop(held);
+        assert!(try_acquire(&scheduler, SubscriptionPriority::Low)
+            .await
+            .is_some());
+        assert_eq!(scheduler.state.lock().in_flight, 0);
+    }
+
+    #[test]
+    fn priority_round_trips_through_u8() {
+        for priority in [
+            SubscriptionPriority::Low,
+            SubscriptionPriority::Normal,
+            SubscriptionPriority::High,
+        ] {
+            assert_eq!(SubscriptionPriority::from_u8(priority as u8), priority);
+        }
+    }
+}
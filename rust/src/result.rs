@@ -0,0 +1,100 @@
+//! Mapping of Convex function results onto FFI return values.
+
+use convex::FunctionResult;
+
+use crate::{
+    convex_value::ConvexValue, options::Int64Encoding, value::value_to_json_string_as, ClientError,
+};
+
+/// Utility function to handle and serialize FunctionResult into a string or error.
+pub(crate) fn handle_direct_function_result(
+    result: FunctionResult,
+    int64: Int64Encoding,
+) -> Result<String, ClientError> {
+    match result {
+        FunctionResult::Value(v) => Ok(value_to_json_string_as(v, int64)),
+        FunctionResult::ConvexError(e) => Err(ClientError::convex(e.message, e.data)),
+        FunctionResult::ErrorMessage(msg) => Err(ClientError::server(msg)),
+    }
+}
+
+/// Like [`handle_direct_function_result`], but keeps the value structured.
+pub(crate) fn handle_typed_function_result(
+    result: FunctionResult,
+) -> Result<ConvexValue, ClientError> {
+    match result {
+        FunctionResult::Value(v) => Ok(v.into()),
+        FunctionResult::ConvexError(e) => Err(ClientError::convex(e.message, e.data)),
+        FunctionResult::ErrorMessage(msg) => Err(ClientError::server(msg)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use convex::{ConvexError, Value};
+
+    use super::*;
+
+    #[test]
+    fn value_is_serialized() {
+        let result = handle_direct_function_result(
+            FunctionResult::Value(Value::Int64(3)),
+            Int64Encoding::Tagged,
+        );
+        assert_eq!(result.unwrap(), r#"{"$integer":"AwAAAAAAAAA="}"#);
+    }
+
+    #[test]
+    fn convex_error_carries_its_message_and_data() {
+        let result = handle_direct_function_result(
+            FunctionResult::ConvexError(ConvexError {
+                message: "nope".into(),
+                data: Value::String("code".into()),
+            }),
+            Int64Encoding::Tagged,
+        );
+        match result {
+            Err(ClientError::ConvexError { message, data, .. }) => {
+                assert_eq!(message, "nope");
+                assert_eq!(data, ConvexValue::String("code".into()));
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn typed_value_keeps_int64_and_bytes() {
+        let result = handle_typed_function_result(FunctionResult::Value(Value::Array(vec![
+            Value::Int64(i64::MAX),
+            Value::Bytes(vec![1]),
+        ])));
+        assert_eq!(
+            result.unwrap(),
+            ConvexValue::Array(vec![
+                ConvexValue::Int64(i64::MAX),
+                ConvexValue::Bytes(vec![1])
+            ])
+        );
+    }
+
+    #[test]
+    fn values_use_the_configured_int64_encoding() {
+        let result = handle_direct_function_result(
+            FunctionResult::Value(Value::Int64(1 << 60)),
+            Int64Encoding::String,
+        );
+        assert_eq!(result.unwrap(), r#""1152921504606846976""#);
+    }
+
+    #[test]
+    fn error_message_is_server_error() {
+        let result = handle_direct_function_result(
+            FunctionResult::ErrorMessage("boom".into()),
+            Int64Encoding::Tagged,
+        );
+        match result {
+            Err(ClientError::ServerError { msg, .. }) => assert_eq!(msg, "boom"),
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,171 @@
+//! Retrying mutations with idempotency keys.
+//!
+//! A WebSocket blip during checkout leaves the app unsure whether its
+//! mutation landed, and blindly sending it again may apply it twice.
+//! [`MobileConvexClient::mutation_idempotent`] adds an idempotency key to the
+//! arguments, under [`MutationRetryOptions::idempotency_key_arg`], and sends
+//! the mutation again with exponential backoff while it fails for reasons
+//! other than the function's own error, such as the connection. The
+//! mutation function is expected to skip keys it has already applied, so
+//! every attempt is safe. Attempts are reported under the key to
+//! [`MobileConvexClient::on_mutation_lifecycle`].
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Duration,
+};
+
+use anyhow::bail;
+use convex::Value;
+use flutter_rust_bridge::frb;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    backoff::exponential_backoff,
+    events::ClientEvent,
+    mutation_status::{final_status, report, MutationStatus},
+    ClientError, MobileConvexClient,
+};
+
+/// Retry policy of [`MobileConvexClient::mutation_idempotent`] and
+/// [`MobileConvexClient::mutation_tracked`].
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct MutationRetryOptions {
+    /// Delay before the first retry; doubled after every failed attempt.
+    pub initial_backoff_ms: u64,
+    /// Upper bound for the delay between attempts.
+    pub max_backoff_ms: u64,
+    /// Attempts of an idempotent mutation, including the first one.
+    pub max_attempts: u32,
+    /// Argument receiving the idempotency key.
+    pub idempotency_key_arg: String,
+}
+
+impl Default for MutationRetryOptions {
+    fn default() -> Self {
+        MutationRetryOptions {
+            initial_backoff_ms: 250,
+            max_backoff_ms: 8_000,
+            max_attempts: 5,
+            idempotency_key_arg: "idempotencyKey".into(),
+        }
+    }
+}
+
+impl MutationRetryOptions {
+    /// Delay after the failed attempt number `attempt` (starting at 1).
+    fn backoff(&self, attempt: u32) -> Duration {
+        exponential_backoff(self.initial_backoff_ms, self.max_backoff_ms, attempt)
+    }
+}
+
+/// The result of [`MobileConvexClient::mutation_idempotent`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct IdempotentMutationResult {
+    /// The mutation's return value, serialized as JSON.
+    pub value: String,
+    /// The key the mutation was sent with.
+    pub idempotency_key: String,
+    /// Attempts it took, including the successful one.
+    pub attempts: u32,
+}
+
+/// Adds the idempotency key to the mutation arguments.
+fn with_idempotency_key(
+    mut args: BTreeMap<String, Value>,
+    key_arg: &str,
+    key: &str,
+) -> anyhow::Result<BTreeMap<String, Value>> {
+    if args.contains_key(key_arg) {
+        bail!("Argument `{key_arg}` is reserved for the idempotency key");
+    }
+    args.insert(key_arg.to_owned(), Value::String(key.to_owned()));
+    Ok(args)
+}
+
+impl MobileConvexClient {
+    /// Sends a mutation up to `max_attempts` times while it fails for
+    /// reasons other than the function's own error, reporting its lifecycle
+    /// under `mutation_id`. Returns the result and the attempts made.
+    pub(crate) async fn send_with_retries(
+        &self,
+        mutation_id: &str,
+        name: &str,
+        args: BTreeMap<String, Value>,
+        max_attempts: u32,
+    ) -> (Result<String, ClientError>, u32) {
+        let emit = |status| report(&self.mutation_events, mutation_id, name, status);
+        let retry = &self.options.mutation_retry;
+        let mut attempt = 1;
+        let result = loop {
+            emit(MutationStatus::Sending);
+            match self.internal_mutation(name.to_owned(), args.clone()).await {
+                Ok(result) => break self.format_result(result),
+                Err(error) if attempt < max_attempts => {
+                    let error = format!("{error:#}");
+                    self.call_metrics.record_retry(name);
+                    self.events.emit(ClientEvent::MutationRetried {
+                        name: name.to_owned(),
+                        attempt,
+                        error: error.clone(),
+                    });
+                    emit(MutationStatus::Retrying { attempt, error });
+                    tokio::time::sleep(retry.backoff(attempt)).await;
+                    attempt += 1;
+                }
+                Err(error) => break Err(error.into()),
+            }
+        };
+        if result.is_ok() {
+            self.invalidate_after_mutation(name).await;
+        }
+        emit(final_status(&result));
+        (result, attempt)
+    }
+
+    /// Executes a mutation with an idempotency key, retrying it as
+    /// described in the [module docs](crate::retry). A new key is generated
+    /// unless `idempotency_key` is given, e.g. to retry across app
+    /// restarts.
+    #[frb]
+    pub async fn mutation_idempotent(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        idempotency_key: Option<String>,
+    ) -> Result<IdempotentMutationResult, ClientError> {
+        let key = idempotency_key.unwrap_or_else(|| Uuid::new_v4().to_string());
+        let retry = &self.options.mutation_retry;
+        let args = with_idempotency_key(self.parse_args(args)?, &retry.idempotency_key_arg, &key)?;
+        let (result, attempts) = self
+            .send_with_retries(&key, &name, args, retry.max_attempts)
+            .await;
+        Ok(IdempotentMutationResult {
+            value: result?,
+            idempotency_key: key,
+            attempts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_are_added_and_backoff_doubles_up_to_the_limit() {
+        let args = with_idempotency_key(BTreeMap::new(), "idempotencyKey", "k1").unwrap();
+        assert_eq!(args["idempotencyKey"], Value::String("k1".into()));
+        assert!(with_idempotency_key(args, "idempotencyKey", "k2").is_err());
+
+        let retry = MutationRetryOptions::default();
+        let delays: Vec<u64> = (1..=7)
+            .map(|attempt| retry.backoff(attempt).as_millis() as u64)
+            .collect();
+        assert_eq!(delays, [250, 500, 1_000, 2_000, 4_000, 8_000, 8_000]);
+    }
+}
@@ -0,0 +1,170 @@
+//! Sampling of telemetry.
+//!
+//! The audit log, call latency samples and the runtime probe each cost a
+//! little per event. [`TelemetrySampling`] decides centrally which share of
+//! events each of them records, per [`TelemetryClass`] and per function
+//! name, and which classes are only recorded in debug builds. It is set with
+//! [`crate::options::ClientOptions::telemetry_sampling`] and can be replaced
+//! at runtime with [`MobileConvexClient::set_telemetry_sampling`]. By default
+//! every event is recorded.
+
+use std::{
+    collections::HashMap,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use flutter_rust_bridge::frb;
+use parking_lot::RwLock;
+use serde::Deserialize;
+
+use crate::MobileConvexClient;
+
+/// A kind of telemetry event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[frb]
+pub enum TelemetryClass {
+    /// Entries of the audit log.
+    Audit,
+    /// Call latency and failure samples feeding
+    /// [`crate::quality::ConnectionQuality`] and the
+    /// [`crate::call_metrics::MetricsReport`].
+    CallLatency,
+    /// Runtime probe samples feeding [`crate::metrics::RuntimeMetrics`].
+    RuntimeProbe,
+}
+
+/// The share of events of a class that are recorded.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct ClassSampleRate {
+    pub class: TelemetryClass,
+    /// From 0 (none) to 1 (all).
+    pub rate: f64,
+}
+
+/// Which telemetry events are recorded.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct TelemetrySampling {
+    /// Share of events recorded per class. Classes not listed are recorded
+    /// in full.
+    pub class_rates: Vec<ClassSampleRate>,
+    /// Share of events about a function that are recorded, by function
+    /// name. Overrides the rate of the event's class.
+    pub function_rates: HashMap<String, f64>,
+    /// Classes recorded in debug builds only.
+    pub debug_only: Vec<TelemetryClass>,
+}
+
+/// Applies the current [`TelemetrySampling`].
+pub(crate) struct TelemetrySampler {
+    sampling: RwLock<TelemetrySampling>,
+    random: AtomicU64, // xorshift state
+}
+
+impl TelemetrySampler {
+    pub(crate) fn new(sampling: TelemetrySampling) -> Self {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64);
+        TelemetrySampler {
+            sampling: RwLock::new(sampling),
+            random: AtomicU64::new(seed | 1),
+        }
+    }
+
+    /// Returns whether to record an event of `class`, about `function` if
+    /// the event concerns one.
+    pub(crate) fn sample(&self, class: TelemetryClass, function: Option<&str>) -> bool {
+        let rate = {
+            let sampling = self.sampling.read();
+            if !cfg!(debug_assertions) && sampling.debug_only.contains(&class) {
+                return false;
+            }
+            function
+                .and_then(|function| sampling.function_rates.get(function).copied())
+                .or_else(|| {
+                    sampling
+                        .class_rates
+                        .iter()
+                        .find(|rate| rate.class == class)
+                        .map(|rate| rate.rate)
+                })
+                .unwrap_or(1.0)
+        };
+        if rate >= 1.0 {
+            true
+        } else if rate > 0.0 {
+            self.next_random() < rate
+        } else {
+            false
+        }
+    }
+
+    /// Returns a pseudo-random number in `0..1`.
+    fn next_random(&self) -> f64 {
+        let step = |mut x: u64| {
+            x ^= x << 13;
+            x ^= x >> 7;
+            x ^= x << 17;
+            x
+        };
+        let previous = self
+            .random
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |x| Some(step(x)))
+            .unwrap_or_default();
+        (step(previous) >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+impl MobileConvexClient {
+    /// Replaces the telemetry sampling configuration. Applies to events
+    /// recorded from now on.
+    #[frb(sync)]
+    pub fn set_telemetry_sampling(&self, sampling: TelemetrySampling) {
+        *self.telemetry_sampler.sampling.write() = sampling;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recorded(sampler: &TelemetrySampler, class: TelemetryClass, function: &str) -> usize {
+        (0..10_000)
+            .filter(|_| sampler.sample(class, Some(function)))
+            .count()
+    }
+
+    #[test]
+    fn rates_apply_per_class_and_function() {
+        let sampler = TelemetrySampler::new(TelemetrySampling::default());
+        assert_eq!(recorded(&sampler, TelemetryClass::Audit, "f"), 10_000);
+
+        *sampler.sampling.write() = TelemetrySampling {
+            class_rates: vec![ClassSampleRate {
+                class: TelemetryClass::CallLatency,
+                rate: 0.25,
+            }],
+            function_rates: HashMap::from([("hot".to_owned(), 0.0), ("rare".to_owned(), 1.0)]),
+            debug_only: vec![TelemetryClass::Audit],
+        };
+        let sampled = recorded(&sampler, TelemetryClass::CallLatency, "f");
+        assert!((2_000..3_000).contains(&sampled), "{sampled}");
+        assert_eq!(recorded(&sampler, TelemetryClass::CallLatency, "hot"), 0);
+        assert_eq!(
+            recorded(&sampler, TelemetryClass::CallLatency, "rare"),
+            10_000
+        );
+        assert!(sampler.sample(TelemetryClass::RuntimeProbe, None));
+        // Tests run in debug builds, where debug-only classes are recorded.
+        assert_eq!(
+            sampler.sample(TelemetryClass::Audit, None),
+            cfg!(debug_assertions)
+        );
+    }
+}
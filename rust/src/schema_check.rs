@@ -0,0 +1,215 @@
+//! Detecting backend deploys that change what functions return.
+//!
+//! Convex does not expose a schema hash to clients, so apps provide a query
+//! returning a value that changes whenever their functions' return types do
+//! (for example, a version constant bumped on such deploys). Whenever a
+//! Convex client is built, that query runs and the SHA-256 of its result is
+//! compared with the fingerprint seen last, which is persisted per deployment
+//! when a storage root is configured. On a mismatch, cached query and action
+//! results are optionally invalidated and a [`BackendChangedEvent`] is
+//! emitted, so apps can drop their own persisted data as well.
+
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use convex::{ConvexClient, FunctionResult};
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::{debug, warn};
+use parking_lot::Mutex;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use tokio::sync::broadcast;
+
+use crate::{
+    action_cache::ActionCache, query_cache::QueryCache, storage::ScopedStorage,
+    value::value_to_json_string, ClientError, MobileConvexClient,
+};
+
+/// Name of the file holding the last fingerprint in the deployment directory.
+const FINGERPRINT_FILE: &str = "backend_fingerprint";
+
+/// How the backend is checked for changes.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[frb]
+pub struct SchemaCheckOptions {
+    /// Query without arguments whose result identifies the backend version.
+    pub fingerprint_query: String,
+    /// Whether cached query and action results are dropped on a change.
+    #[serde(default)]
+    pub invalidate_cache: bool,
+}
+
+/// Emitted when the backend fingerprint differs from the one seen last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct BackendChangedEvent {
+    pub previous_fingerprint: String,
+    pub current_fingerprint: String,
+    /// Whether cached results were invalidated.
+    pub cache_invalidated: bool,
+}
+
+pub(crate) struct SchemaCheck {
+    options: SchemaCheckOptions,
+    fingerprint_file: Option<PathBuf>,
+    known: Mutex<Option<String>>,
+    events: broadcast::Sender<BackendChangedEvent>,
+}
+
+fn read_fingerprint(path: &Path) -> Option<String> {
+    let fingerprint = fs::read_to_string(path).ok()?;
+    Some(fingerprint.trim().to_owned()).filter(|fingerprint| !fingerprint.is_empty())
+}
+
+fn write_fingerprint(path: &Path, fingerprint: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, fingerprint)
+}
+
+impl SchemaCheck {
+    /// Creates the check, loading the fingerprint persisted in `storage`.
+    pub(crate) fn with_storage(
+        options: SchemaCheckOptions,
+        storage: Option<&ScopedStorage>,
+    ) -> Self {
+        let file = storage.map(|storage| storage.deployment_file(FINGERPRINT_FILE));
+        Self::new(options, file)
+    }
+
+    fn new(options: SchemaCheckOptions, fingerprint_file: Option<PathBuf>) -> Self {
+        let known = fingerprint_file.as_deref().and_then(read_fingerprint);
+        SchemaCheck {
+            options,
+            fingerprint_file,
+            known: Mutex::new(known),
+            events: broadcast::channel(4).0,
+        }
+    }
+
+    /// Records `current`, returning the previous fingerprint if it differs.
+    fn record(&self, current: &str) -> Option<String> {
+        let previous = self.known.lock().replace(current.to_owned());
+        if previous.as_deref() == Some(current) {
+            return None;
+        }
+        if let Some(path) = &self.fingerprint_file {
+            if let Err(e) = write_fingerprint(path, current) {
+                warn!("Failed to persist backend fingerprint: {e}");
+            }
+        }
+        previous
+    }
+
+    async fn run(
+        self: Arc<Self>,
+        mut client: ConvexClient,
+        query_cache: Arc<QueryCache>,
+        action_cache: Arc<ActionCache>,
+    ) {
+        let query = &self.options.fingerprint_query;
+        let value = match client.query(query, BTreeMap::new()).await {
+            Ok(FunctionResult::Value(value)) => value,
+            Ok(other) => {
+                warn!("Backend fingerprint query {query} failed: {other:?}");
+                return;
+            }
+            Err(e) => {
+                warn!("Backend fingerprint query {query} failed: {e}");
+                return;
+            }
+        };
+        let current = hex::encode(Sha256::digest(value_to_json_string(value).as_bytes()));
+        let Some(previous) = self.record(&current) else {
+            debug!("Backend fingerprint unchanged");
+            return;
+        };
+        if self.options.invalidate_cache {
+            query_cache.clear();
+            action_cache.clear();
+        }
+        warn!("Backend changed: {previous} -> {current}");
+        let _ = self.events.send(BackendChangedEvent {
+            previous_fingerprint: previous,
+            current_fingerprint: current,
+            cache_invalidated: self.options.invalidate_cache,
+        });
+    }
+}
+
+impl MobileConvexClient {
+    /// Checks the backend fingerprint with a freshly built client.
+    pub(crate) fn start_schema_check(&self, client: &ConvexClient) {
+        if let Some(check) = &self.schema_check {
+            self.rt.spawn(check.clone().run(
+                client.clone(),
+                self.query_cache.clone(),
+                self.action_cache.clone(),
+            ));
+        }
+    }
+
+    /// Registers a callback invoked whenever the backend fingerprint
+    /// changes. Requires [`crate::options::ClientOptions::schema_check`].
+    #[frb]
+    pub async fn on_backend_change(
+        &self,
+        on_change: impl Fn(BackendChangedEvent) -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        let check = self
+            .schema_check
+            .as_ref()
+            .ok_or_else(|| ClientError::internal("No schema check configured"))?;
+        let mut events = check.events.subscribe();
+        self.rt.spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(event) => on_change(event).await,
+                    Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                    Err(broadcast::error::RecvError::Closed) => break,
+                }
+            }
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> SchemaCheckOptions {
+        SchemaCheckOptions {
+            fingerprint_query: "meta:version".into(),
+            invalidate_cache: true,
+        }
+    }
+
+    #[test]
+    fn changes_are_detected_across_restarts() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("deployment").join(FINGERPRINT_FILE);
+
+        let check = SchemaCheck::new(options(), Some(file.clone()));
+        assert_eq!(check.record("v1"), None);
+        assert_eq!(check.record("v1"), None);
+
+        let restarted = SchemaCheck::new(options(), Some(file));
+        assert_eq!(restarted.record("v1"), None);
+        assert_eq!(restarted.record("v2"), Some("v1".into()));
+        assert_eq!(restarted.record("v2"), None);
+    }
+
+    #[test]
+    fn without_storage_changes_are_detected_within_the_session() {
+        let check = SchemaCheck::new(options(), None);
+        assert_eq!(check.record("v1"), None);
+        assert_eq!(check.record("v2"), Some("v1".into()));
+    }
+}
@@ -0,0 +1,157 @@
+//! Mutations executed strictly in order, without other mutations between.
+//!
+//! Flows such as "create a document, attach a file, then notify" break when
+//! mutations from elsewhere in the app land between their steps.
+//! [`MobileConvexClient::mutate_sequence`] waits for running mutations to
+//! finish, then runs its steps one after another while other mutations wait,
+//! and reports the outcome of every step in one result.
+
+use std::{collections::HashMap, future::Future};
+
+use convex::FunctionResult;
+use flutter_rust_bridge::frb;
+
+use crate::{value::value_to_json_string, ClientError, MobileConvexClient};
+
+/// One mutation of a sequence.
+#[derive(Debug, Clone)]
+#[frb]
+pub struct MutationCall {
+    pub name: String,
+    pub args: HashMap<String, String>,
+}
+
+/// Outcome of one step of a mutation sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum MutationStepStatus {
+    /// The mutation returned `value`, serialized as JSON.
+    Succeeded { value: String },
+    /// The mutation failed; `data` holds the serialized ConvexError payload,
+    /// if any.
+    Failed {
+        message: String,
+        data: Option<String>,
+    },
+    /// Not run because an earlier step failed.
+    Skipped,
+}
+
+/// Outcome of a whole mutation sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct MutationSequenceResult {
+    /// One status per call, in order.
+    pub steps: Vec<MutationStepStatus>,
+    /// Whether every step succeeded.
+    pub succeeded: bool,
+}
+
+fn failed_step(error: ClientError) -> MutationStepStatus {
+    let data = match &error {
+        ClientError::ConvexError { data, .. } => Some(value_to_json_string(data.clone().into())),
+        _ => None,
+    };
+    MutationStepStatus::Failed {
+        message: error.to_string(),
+        data,
+    }
+}
+
+/// Runs `calls` in order with `run`, skipping the rest after a failure if
+/// `stop_on_error` is set.
+async fn run_steps<T, F, Fut>(
+    calls: Vec<T>,
+    stop_on_error: bool,
+    mut run: F,
+) -> MutationSequenceResult
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = Result<String, ClientError>>,
+{
+    let mut steps = Vec::with_capacity(calls.len());
+    let mut succeeded = true;
+    for call in calls {
+        if !succeeded && stop_on_error {
+            steps.push(MutationStepStatus::Skipped);
+            continue;
+        }
+        steps.push(match run(call).await {
+            Ok(value) => MutationStepStatus::Succeeded { value },
+            Err(e) => {
+                succeeded = false;
+                failed_step(e)
+            }
+        });
+    }
+    MutationSequenceResult { steps, succeeded }
+}
+
+impl MobileConvexClient {
+    /// Executes `calls` strictly in order. No other mutation of this client
+    /// runs between the first and the last step. After a failed step, the
+    /// remaining ones are skipped if `stop_on_error` is set.
+    ///
+    /// Fails without running any step if the arguments of a call are invalid.
+    #[frb]
+    pub async fn mutate_sequence(
+        &self,
+        calls: Vec<MutationCall>,
+        stop_on_error: bool,
+    ) -> Result<MutationSequenceResult, ClientError> {
+        let calls = calls
+            .into_iter()
+            .map(|call| Ok((call.name, self.parse_args(call.args)?)))
+            .collect::<Result<Vec<_>, ClientError>>()?;
+        let _order = self.mutation_order.write().await;
+        Ok(run_steps(calls, stop_on_error, |(name, args)| async move {
+            let result = self.unordered_mutation(name.clone(), args).await?;
+            if matches!(result, FunctionResult::Value(_)) {
+                self.invalidate_after_mutation(&name).await;
+            }
+            self.format_result(result)
+        })
+        .await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::executor::block_on;
+
+    use super::*;
+
+    fn run(step: &str) -> Result<String, ClientError> {
+        match step {
+            "fail" => Err(ClientError::convex(
+                "Uncaught ConvexError: taken".into(),
+                convex::Value::from("taken"),
+            )),
+            other => Ok(other.to_owned()),
+        }
+    }
+
+    #[test]
+    fn failed_steps_skip_the_rest_only_when_asked() {
+        let calls = || vec!["a", "fail", "b"];
+        let stopped = block_on(run_steps(calls(), true, |step| async move { run(step) }));
+        assert!(!stopped.succeeded);
+        assert_eq!(
+            stopped.steps,
+            [
+                MutationStepStatus::Succeeded { value: "a".into() },
+                MutationStepStatus::Failed {
+                    message: "ConvexError: Uncaught ConvexError: taken".into(),
+                    data: Some("\"taken\"".into()),
+                },
+                MutationStepStatus::Skipped,
+            ]
+        );
+
+        let continued = block_on(run_steps(calls(), false, |step| async move { run(step) }));
+        assert_eq!(
+            continued.steps[2],
+            MutationStepStatus::Succeeded { value: "b".into() }
+        );
+    }
+}
@@ -0,0 +1,581 @@
+//! Sharding of many small per-document subscriptions into batch subscriptions.
+//!
+//! Apps that watch hundreds of documents one query at a time can register a
+//! [`ShardMapping`] for that query. Subscriptions to the query are then served
+//! by a backend batch query instead: watches that share all arguments except
+//! the key argument are grouped into shards of up to
+//! [`ShardMapping::max_keys_per_shard`] keys, and each shard holds a single
+//! server subscription called with all of its keys. The batch query must
+//! return an object mapping each requested key to what the per-document query
+//! would have returned; missing keys are delivered as `null`.
+//!
+//! For example, with a mapping from `messages:get` to `messages:getMany`,
+//! key argument `id` and batch argument `ids`, subscribing to
+//! `messages:get({id: "a"})` and `messages:get({id: "b"})` results in one
+//! server subscription to `messages:getMany({ids: ["a", "b"]})`.
+//!
+//! Each watcher only receives an update when the value of its own key
+//! changes. Adding or removing keys re-subscribes the shard after a short
+//! debounce, so bursts of subscriptions are batched. Sharded subscriptions
+//! are re-established by the Convex client itself after a reconnect and do
+//! not take part in priority-based re-subscription. Subscriptions whose key
+//! argument is not a string are not sharded.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+    time::Duration,
+};
+
+use convex::{ConvexClient, FunctionResult, Value};
+use flutter_rust_bridge::frb;
+use futures::{channel::oneshot, pin_mut, select_biased, FutureExt, StreamExt};
+use log::{debug, warn};
+use parking_lot::Mutex;
+use tokio::sync::Notify;
+
+use crate::{
+    deliver_to_subscriber,
+    options::Int64Encoding,
+    value::{value_to_json_string, value_to_json_string_as},
+    MobileConvexClient, QuerySubscriber, SubscriptionEvent,
+};
+
+/// Delay before a shard re-subscribes after its keys changed.
+const RESHARD_DEBOUNCE: Duration = Duration::from_millis(20);
+
+/// Maps a per-document query onto a backend batch query.
+#[derive(Debug, Clone)]
+#[frb]
+pub struct ShardMapping {
+    /// Batch query serving many keys at once.
+    pub batch_query: String,
+    /// Argument of the per-document query holding the document key.
+    pub key_arg: String,
+    /// Argument of the batch query receiving the array of keys.
+    pub batch_arg: String,
+    /// Maximum number of keys per server subscription.
+    pub max_keys_per_shard: u32,
+}
+
+/// Subscription counts with and without sharding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[frb]
+pub struct ShardingMetrics {
+    /// Sharded subscriptions requested by the app, i.e. the server
+    /// subscriptions that would exist without sharding.
+    pub logical_subscriptions: u32,
+    /// Server subscriptions actually serving them.
+    pub server_subscriptions: u32,
+}
+
+/// Query name and the canonical JSON of all arguments except the key.
+type GroupKey = (String, String);
+
+#[frb(ignore)]
+struct Shard {
+    group: GroupKey,
+    // Key -> watcher id -> subscriber.
+    watchers: BTreeMap<String, HashMap<u64, Arc<dyn QuerySubscriber>>>,
+    // Last value delivered per key.
+    last_values: HashMap<String, String>,
+    changed: Arc<Notify>,
+}
+
+/// A watcher added to a shard.
+struct Joined {
+    shard_id: u64,
+    watcher_id: u64,
+    /// Set if the shard is new and its task must be started.
+    new_shard: Option<NewShard>,
+    /// Whether the key was not yet part of the shard.
+    key_added: bool,
+    /// Last value of the key, if the shard already has one.
+    cached: Option<String>,
+    changed: Arc<Notify>,
+}
+
+struct NewShard {
+    mapping: ShardMapping,
+    base_args: BTreeMap<String, Value>,
+}
+
+/// Registered mappings and the live shards.
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct ShardRegistry {
+    mappings: HashMap<String, ShardMapping>,
+    shards: HashMap<u64, Shard>,
+    next_id: u64,
+    int64_encoding: Int64Encoding,
+}
+
+impl ShardRegistry {
+    pub(crate) fn new(int64_encoding: Int64Encoding) -> Self {
+        ShardRegistry {
+            int64_encoding,
+            ..Default::default()
+        }
+    }
+
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    /// Adds a watcher if `name` has a mapping and `args` a string key.
+    fn join(
+        &mut self,
+        name: &str,
+        args: &BTreeMap<String, Value>,
+        subscriber: Arc<dyn QuerySubscriber>,
+    ) -> Option<Joined> {
+        let mapping = self.mappings.get(name)?.clone();
+        let Some(Value::String(key)) = args.get(&mapping.key_arg) else {
+            return None;
+        };
+        let key = key.clone();
+        let mut base_args = args.clone();
+        base_args.remove(&mapping.key_arg);
+        let group = (
+            name.to_owned(),
+            value_to_json_string(Value::Object(base_args.clone())),
+        );
+        let max_keys = mapping.max_keys_per_shard.max(1) as usize;
+        let existing = self
+            .shards
+            .iter()
+            .filter(|(_, shard)| shard.group == group)
+            .find(|(_, shard)| shard.watchers.contains_key(&key) || shard.watchers.len() < max_keys)
+            .map(|(id, _)| *id);
+        let (shard_id, new_shard) = match existing {
+            Some(id) => (id, None),
+            None => {
+                let id = self.next_id();
+                self.shards.insert(
+                    id,
+                    Shard {
+                        group,
+                        watchers: BTreeMap::new(),
+                        last_values: HashMap::new(),
+                        changed: Arc::new(Notify::new()),
+                    },
+                );
+                (id, Some(NewShard { mapping, base_args }))
+            }
+        };
+        let watcher_id = self.next_id();
+        let shard = self.shards.get_mut(&shard_id)?;
+        let watchers = shard.watchers.entry(key.clone()).or_default();
+        let key_added = watchers.is_empty();
+        watchers.insert(watcher_id, subscriber);
+        Some(Joined {
+            shard_id,
+            watcher_id,
+            new_shard,
+            key_added,
+            cached: shard.last_values.get(&key).cloned(),
+            changed: shard.changed.clone(),
+        })
+    }
+
+    /// Removes a watcher; returns whether its key left the shard.
+    fn leave(&mut self, shard_id: u64, watcher_id: u64) -> bool {
+        let Some(shard) = self.shards.get_mut(&shard_id) else {
+            return false;
+        };
+        let Some(key) = shard
+            .watchers
+            .iter()
+            .find(|(_, watchers)| watchers.contains_key(&watcher_id))
+            .map(|(key, _)| key.clone())
+        else {
+            return false;
+        };
+        let watchers = shard.watchers.get_mut(&key).expect("key was just found");
+        watchers.remove(&watcher_id);
+        if !watchers.is_empty() {
+            return false;
+        }
+        shard.watchers.remove(&key);
+        shard.last_values.remove(&key);
+        true
+    }
+
+    /// Keys the shard should subscribe to; drops the shard once it is empty.
+    fn keys(&mut self, shard_id: u64) -> Option<Vec<String>> {
+        let shard = self.shards.get(&shard_id)?;
+        if shard.watchers.is_empty() {
+            self.shards.remove(&shard_id);
+            return None;
+        }
+        Some(shard.watchers.keys().cloned().collect())
+    }
+
+    /// Splits a batch result into the events to deliver per watcher.
+    fn dispatch(
+        &mut self,
+        shard_id: u64,
+        batch_query: &str,
+        result: FunctionResult,
+    ) -> Vec<(Arc<dyn QuerySubscriber>, SubscriptionEvent)> {
+        let Some(shard) = self.shards.get_mut(&shard_id) else {
+            return Vec::new();
+        };
+        let mut values = match result {
+            FunctionResult::Value(Value::Object(values)) => values,
+            other => {
+                let event = match other {
+                    FunctionResult::ConvexError(error) => SubscriptionEvent::Error {
+                        message: error.message,
+                        data: Some(value_to_json_string_as(error.data, self.int64_encoding)),
+                    },
+                    FunctionResult::ErrorMessage(message) => SubscriptionEvent::Error {
+                        message,
+                        data: None,
+                    },
+                    FunctionResult::Value(_) => SubscriptionEvent::Error {
+                        message: format!("Batch query {batch_query} must return an object"),
+                        data: None,
+                    },
+                };
+                // Deliver the next value again, even if unchanged.
+                shard.last_values.clear();
+                return shard
+                    .watchers
+                    .values()
+                    .flat_map(|watchers| watchers.values())
+                    .map(|subscriber| (subscriber.clone(), event.clone()))
+                    .collect();
+            }
+        };
+        let mut deliveries = Vec::new();
+        for (key, watchers) in &shard.watchers {
+            let value = values.remove(key).unwrap_or(Value::Null);
+            let value = value_to_json_string_as(value, self.int64_encoding);
+            if shard.last_values.get(key) == Some(&value) {
+                continue;
+            }
+            for subscriber in watchers.values() {
+                let event = SubscriptionEvent::Update {
+                    value: value.clone(),
+                };
+                deliveries.push((subscriber.clone(), event));
+            }
+            shard.last_values.insert(key.clone(), value);
+        }
+        deliveries
+    }
+
+    fn metrics(&self) -> ShardingMetrics {
+        let logical: usize = self
+            .shards
+            .values()
+            .flat_map(|shard| shard.watchers.values())
+            .map(HashMap::len)
+            .sum();
+        ShardingMetrics {
+            logical_subscriptions: logical as u32,
+            server_subscriptions: self.shards.len() as u32,
+        }
+    }
+}
+
+/// Serves a subscription from a shard if a mapping applies. Returns the
+/// sender cancelling the subscription, or `None` to subscribe directly.
+pub(crate) fn subscribe_sharded(
+    rt: &tokio::runtime::Handle,
+    registry: &Arc<Mutex<ShardRegistry>>,
+    client: &ConvexClient,
+    name: &str,
+    args: &BTreeMap<String, Value>,
+    subscriber: Arc<dyn QuerySubscriber>,
+) -> Option<oneshot::Sender<()>> {
+    let joined = registry.lock().join(name, args, subscriber.clone())?;
+    if let Some(NewShard { mapping, base_args }) = joined.new_shard {
+        rt.spawn(run_shard(
+            joined.shard_id,
+            registry.clone(),
+            client.clone(),
+            mapping,
+            base_args,
+            joined.changed.clone(),
+        ));
+    }
+    if joined.key_added {
+        joined.changed.notify_one();
+    } else if let Some(value) = joined.cached {
+        subscriber.on_update(value);
+    }
+
+    let (cancel_sender, cancel_receiver) = oneshot::channel::<()>();
+    let registry = registry.clone();
+    rt.spawn(async move {
+        let _ = cancel_receiver.await;
+        if registry.lock().leave(joined.shard_id, joined.watcher_id) {
+            joined.changed.notify_one();
+        }
+    });
+    Some(cancel_sender)
+}
+
+/// Keeps one server subscription covering all keys of a shard.
+async fn run_shard(
+    shard_id: u64,
+    registry: Arc<Mutex<ShardRegistry>>,
+    mut client: ConvexClient,
+    mapping: ShardMapping,
+    base_args: BTreeMap<String, Value>,
+    changed: Arc<Notify>,
+) {
+    changed.notified().await;
+    loop {
+        tokio::time::sleep(RESHARD_DEBOUNCE).await;
+        // Changes during the debounce are covered by this re-subscription.
+        let _ = changed.notified().now_or_never();
+        let Some(keys) = registry.lock().keys(shard_id) else {
+            break;
+        };
+        debug!(
+            "Subscribing shard {shard_id} of {} with {} keys",
+            mapping.batch_query,
+            keys.len()
+        );
+        let mut args = base_args.clone();
+        args.insert(
+            mapping.batch_arg.clone(),
+            Value::Array(keys.into_iter().map(Value::String).collect()),
+        );
+        let mut subscription = match client.subscribe(&mapping.batch_query, args).await {
+            Ok(subscription) => subscription,
+            Err(e) => {
+                warn!("Failed to subscribe to {}: {e}", mapping.batch_query);
+                changed.notified().await;
+                continue;
+            }
+        };
+        loop {
+            let result = {
+                let changed_fut = changed.notified().fuse();
+                pin_mut!(changed_fut);
+                select_biased! {
+                    _ = changed_fut => None,
+                    result = subscription.next().fuse() => Some(result),
+                }
+            };
+            match result {
+                None => break,
+                Some(None) => {
+                    warn!("Shard subscription to {} ended", mapping.batch_query);
+                    let shard = registry.lock().shards.remove(&shard_id);
+                    for watchers in shard.iter().flat_map(|shard| shard.watchers.values()) {
+                        for subscriber in watchers.values() {
+                            subscriber.on_done();
+                        }
+                    }
+                    return;
+                }
+                Some(Some(result)) => {
+                    let deliveries =
+                        registry
+                            .lock()
+                            .dispatch(shard_id, &mapping.batch_query, result);
+                    for (subscriber, event) in deliveries {
+                        deliver_to_subscriber(&mapping.batch_query, &*subscriber, Some(event));
+                    }
+                }
+            }
+        }
+    }
+    debug!("Shard {shard_id} of {} closed", mapping.batch_query);
+}
+
+impl MobileConvexClient {
+    /// Serves future subscriptions to `query_name` from `mapping.batch_query`,
+    /// grouping many watches into few server subscriptions.
+    #[frb(sync)]
+    pub fn register_shard_mapping(&self, query_name: String, mapping: ShardMapping) {
+        self.shards.lock().mappings.insert(query_name, mapping);
+    }
+
+    /// Returns how many sharded subscriptions are served by how many server
+    /// subscriptions.
+    #[frb(sync)]
+    pub fn sharding_metrics(&self) -> ShardingMetrics {
+        self.shards.lock().metrics()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use convex::ConvexError;
+    use maplit::btreemap;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl QuerySubscriber for Recorder {
+        fn on_update(&self, value: String) {
+            self.events.lock().push(value);
+        }
+
+        fn on_error(&self, message: String, _value: Option<String>) {
+            self.events.lock().push(format!("error: {message}"));
+        }
+    }
+
+    fn registry(max_keys_per_shard: u32) -> ShardRegistry {
+        let mut registry = ShardRegistry::default();
+        registry.mappings.insert(
+            "messages:get".into(),
+            ShardMapping {
+                batch_query: "messages:getMany".into(),
+                key_arg: "id".into(),
+                batch_arg: "ids".into(),
+                max_keys_per_shard,
+            },
+        );
+        registry
+    }
+
+    fn args(id: &str, channel: &str) -> BTreeMap<String, Value> {
+        btreemap! {
+            "id".to_string() => Value::String(id.into()),
+            "channel".to_string() => Value::String(channel.into()),
+        }
+    }
+
+    fn deliver(deliveries: Vec<(Arc<dyn QuerySubscriber>, SubscriptionEvent)>) {
+        for (subscriber, event) in deliveries {
+            deliver_to_subscriber("messages:getMany", &*subscriber, Some(event));
+        }
+    }
+
+    #[test]
+    fn watches_are_grouped_by_base_args_up_to_the_shard_size() {
+        let mut registry = registry(2);
+        let recorder: Arc<dyn QuerySubscriber> = Arc::new(Recorder::default());
+        let a = registry.join("messages:get", &args("a", "x"), recorder.clone());
+        let b = registry.join("messages:get", &args("b", "x"), recorder.clone());
+        let a_again = registry.join("messages:get", &args("a", "x"), recorder.clone());
+        let c = registry.join("messages:get", &args("c", "x"), recorder.clone());
+        let other = registry.join("messages:get", &args("a", "y"), recorder.clone());
+        let (a, b, a_again, c, other) = (
+            a.unwrap(),
+            b.unwrap(),
+            a_again.unwrap(),
+            c.unwrap(),
+            other.unwrap(),
+        );
+
+        assert!(a.new_shard.is_some() && a.key_added);
+        assert!(b.new_shard.is_none() && b.key_added);
+        assert_eq!(b.shard_id, a.shard_id);
+        assert!(!a_again.key_added);
+        assert_ne!(c.shard_id, a.shard_id);
+        assert_ne!(other.shard_id, a.shard_id);
+        assert_eq!(registry.keys(a.shard_id).unwrap(), ["a", "b"]);
+        assert_eq!(
+            registry.metrics(),
+            ShardingMetrics {
+                logical_subscriptions: 5,
+                server_subscriptions: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn unmapped_queries_and_non_string_keys_are_not_sharded() {
+        let mut registry = registry(10);
+        let recorder: Arc<dyn QuerySubscriber> = Arc::new(Recorder::default());
+        assert!(registry
+            .join("messages:list", &args("a", "x"), recorder.clone())
+            .is_none());
+        let numeric = btreemap! {"id".to_string() => Value::Float64(1.0)};
+        assert!(registry.join("messages:get", &numeric, recorder).is_none());
+    }
+
+    #[test]
+    fn only_watchers_of_changed_keys_are_notified() {
+        let mut registry = registry(10);
+        let first = Arc::new(Recorder::default());
+        let second = Arc::new(Recorder::default());
+        let shard = registry
+            .join("messages:get", &args("a", "x"), first.clone())
+            .unwrap()
+            .shard_id;
+        registry.join("messages:get", &args("b", "x"), second.clone());
+
+        let batch = |a: &str, b: &str| {
+            FunctionResult::Value(Value::Object(btreemap! {
+                "a".to_string() => Value::String(a.into()),
+                "b".to_string() => Value::String(b.into()),
+            }))
+        };
+        deliver(registry.dispatch(shard, "messages:getMany", batch("1", "1")));
+        deliver(registry.dispatch(shard, "messages:getMany", batch("2", "1")));
+        deliver(registry.dispatch(
+            shard,
+            "messages:getMany",
+            FunctionResult::Value(Value::Object(BTreeMap::new())),
+        ));
+
+        assert_eq!(*first.events.lock(), [r#""1""#, r#""2""#, "null"]);
+        assert_eq!(*second.events.lock(), [r#""1""#, "null"]);
+    }
+
+    #[test]
+    fn errors_reach_every_watcher() {
+        let mut registry = registry(10);
+        let recorder = Arc::new(Recorder::default());
+        let shard = registry
+            .join("messages:get", &args("a", "x"), recorder.clone())
+            .unwrap()
+            .shard_id;
+        registry.join("messages:get", &args("b", "x"), recorder.clone());
+        deliver(registry.dispatch(
+            shard,
+            "messages:getMany",
+            FunctionResult::ConvexError(ConvexError {
+                message: "denied".into(),
+                data: Value::Null,
+            }),
+        ));
+        deliver(registry.dispatch(
+            shard,
+            "messages:getMany",
+            FunctionResult::Value(Value::Null),
+        ));
+        assert_eq!(
+            *recorder.events.lock(),
+            [
+                "error: denied",
+                "error: denied",
+                "error: Batch query messages:getMany must return an object",
+                "error: Batch query messages:getMany must return an object",
+            ]
+        );
+    }
+
+    #[test]
+    fn shards_close_once_their_last_watcher_leaves() {
+        let mut registry = registry(10);
+        let recorder: Arc<dyn QuerySubscriber> = Arc::new(Recorder::default());
+        let a = registry
+            .join("messages:get", &args("a", "x"), recorder.clone())
+            .unwrap();
+        let a_again = registry
+            .join("messages:get", &args("a", "x"), recorder)
+            .unwrap();
+
+        assert!(!registry.leave(a.shard_id, a.watcher_id));
+        assert!(registry.leave(a.shard_id, a_again.watcher_id));
+        assert!(!registry.leave(a.shard_id, a_again.watcher_id));
+        assert_eq!(registry.keys(a.shard_id), None);
+        assert_eq!(registry.metrics(), ShardingMetrics::default());
+    }
+}
@@ -0,0 +1,392 @@
+//! Tracing spans for Convex calls and their export over OTLP.
+//!
+//! Queries, mutations, actions, subscriptions and HTTP actions each record
+//! a `tracing` span named `convex.call` with the operation, the function
+//! name, the size of the arguments, the duration and the result status,
+//! for apps that install a `tracing` subscriber.
+//! [`MobileConvexClient::enable_trace_export`] also sends these spans to an
+//! OpenTelemetry collector, batched as OTLP/HTTP JSON. HTTP actions then
+//! carry a W3C `traceparent` header, so the spans of the backend join the
+//! client's trace; the sync protocol has no place for one, so every other
+//! call starts a trace of its own.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::anyhow;
+use convex::Value;
+use flutter_rust_bridge::frb;
+use log::warn;
+use serde_json::{json, Value as JsonValue};
+use tokio::sync::mpsc;
+use tracing::field::Empty;
+use uuid::Uuid;
+
+use crate::{
+    audit::AuditStatus, http::request, value::value_to_json_string, ClientError, MobileConvexClient,
+};
+
+/// Spans queued for export; more are dropped.
+const QUEUE_CAPACITY: usize = 2048;
+/// Most spans sent in one export request.
+const MAX_BATCH: usize = 256;
+/// How long a span waits at most before it is exported.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(5);
+/// OTLP `SPAN_KIND_CLIENT`.
+const SPAN_KIND_CLIENT: u8 = 3;
+
+/// Where and how spans are exported.
+#[derive(Debug, Clone)]
+#[frb]
+pub struct TraceExportOptions {
+    /// URL the spans are posted to, e.g.
+    /// `https://collector.example.com/v1/traces`.
+    pub endpoint: String,
+    /// Headers sent with every export, e.g. an API key.
+    pub headers: HashMap<String, String>,
+    /// `service.name` of the exported spans, e.g. the app's name.
+    pub service_name: String,
+}
+
+/// A kind of traced call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SpanOperation {
+    Query,
+    Mutation,
+    Action,
+    Subscribe,
+    HttpAction,
+}
+
+impl SpanOperation {
+    fn name(self) -> &'static str {
+        match self {
+            SpanOperation::Query => "query",
+            SpanOperation::Mutation => "mutation",
+            SpanOperation::Action => "action",
+            SpanOperation::Subscribe => "subscribe",
+            SpanOperation::HttpAction => "http_action",
+        }
+    }
+}
+
+fn status_name(status: AuditStatus) -> &'static str {
+    match status {
+        AuditStatus::Success => "success",
+        AuditStatus::ConvexError => "convex_error",
+        AuditStatus::ServerError => "server_error",
+        AuditStatus::TransportError => "transport_error",
+    }
+}
+
+fn unix_nanos(at: SystemTime) -> u64 {
+    at.duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+}
+
+/// A finished span, as exported.
+#[derive(Debug, Clone, PartialEq)]
+struct FinishedSpan {
+    trace_id: [u8; 16],
+    span_id: [u8; 8],
+    operation: SpanOperation,
+    function: String,
+    args_bytes: Option<u64>,
+    start_unix_nanos: u64,
+    end_unix_nanos: u64,
+    status: AuditStatus,
+}
+
+impl FinishedSpan {
+    fn to_otlp(&self) -> JsonValue {
+        let mut attributes = vec![
+            json!({"key": "convex.operation", "value": {"stringValue": self.operation.name()}}),
+            json!({"key": "convex.function", "value": {"stringValue": self.function}}),
+            json!({"key": "convex.status", "value": {"stringValue": status_name(self.status)}}),
+        ];
+        if let Some(bytes) = self.args_bytes {
+            attributes.push(
+                json!({"key": "convex.args_bytes", "value": {"intValue": bytes.to_string()}}),
+            );
+        }
+        // OTLP `STATUS_CODE_OK` and `STATUS_CODE_ERROR`.
+        let code = if self.status == AuditStatus::Success {
+            1
+        } else {
+            2
+        };
+        json!({
+            "traceId": hex::encode(self.trace_id),
+            "spanId": hex::encode(self.span_id),
+            "name": format!("{} {}", self.operation.name(), self.function),
+            "kind": SPAN_KIND_CLIENT,
+            "startTimeUnixNano": self.start_unix_nanos.to_string(),
+            "endTimeUnixNano": self.end_unix_nanos.to_string(),
+            "attributes": attributes,
+            "status": {"code": code},
+        })
+    }
+}
+
+/// Renders an OTLP/HTTP JSON export request of `spans`.
+fn export_request(service_name: &str, spans: &[FinishedSpan]) -> JsonValue {
+    json!({
+        "resourceSpans": [{
+            "resource": {
+                "attributes": [
+                    {"key": "service.name", "value": {"stringValue": service_name}},
+                ],
+            },
+            "scopeSpans": [{
+                "scope": {"name": "convex_flutter", "version": env!("CARGO_PKG_VERSION")},
+                "spans": spans.iter().map(FinishedSpan::to_otlp).collect::<Vec<_>>(),
+            }],
+        }],
+    })
+}
+
+/// Sends the spans it receives to the collector in batches.
+pub(crate) struct TraceExporter {
+    spans: mpsc::Sender<FinishedSpan>,
+}
+
+impl TraceExporter {
+    /// Starts exporting on `rt` until the exporter is dropped.
+    fn start(rt: &tokio::runtime::Handle, options: TraceExportOptions) -> Self {
+        let (spans, queued) = mpsc::channel(QUEUE_CAPACITY);
+        rt.spawn(run_export(options, queued));
+        TraceExporter { spans }
+    }
+}
+
+async fn run_export(options: TraceExportOptions, mut queued: mpsc::Receiver<FinishedSpan>) {
+    let mut headers = vec![("Content-Type".to_owned(), "application/json".to_owned())];
+    headers.extend(options.headers.clone());
+    let mut batch = Vec::new();
+    let mut flush = tokio::time::interval(FLUSH_INTERVAL);
+    loop {
+        let ended = tokio::select! {
+            span = queued.recv() => match span {
+                Some(span) => {
+                    batch.push(span);
+                    if batch.len() < MAX_BATCH {
+                        continue;
+                    }
+                    false
+                }
+                None => true,
+            },
+            _ = flush.tick() => false,
+        };
+        if !batch.is_empty() {
+            let body = export_request(&options.service_name, &batch).to_string();
+            batch.clear();
+            match request(&options.endpoint, "POST", "", &headers, body.as_bytes()).await {
+                Ok(response) if (200..300).contains(&response.status) => {}
+                Ok(response) => warn!("Exporting spans failed with status {}", response.status),
+                Err(e) => warn!("Exporting spans failed: {e:#}"),
+            }
+        }
+        if ended {
+            break;
+        }
+    }
+}
+
+/// A call being traced.
+pub(crate) struct CallSpan {
+    span: tracing::Span,
+    exporter: Option<mpsc::Sender<FinishedSpan>>,
+    trace_id: [u8; 16],
+    span_id: [u8; 8],
+    operation: SpanOperation,
+    function: String,
+    args_bytes: Option<u64>,
+    started_at: SystemTime,
+    started: Instant,
+}
+
+impl CallSpan {
+    /// The W3C `traceparent` header continuing this span's trace, if the
+    /// span is exported.
+    pub(crate) fn traceparent(&self) -> Option<String> {
+        self.exporter.as_ref()?;
+        Some(format!(
+            "00-{}-{}-01",
+            hex::encode(self.trace_id),
+            hex::encode(self.span_id)
+        ))
+    }
+
+    /// Ends the span with `status`.
+    pub(crate) fn finish(self, status: AuditStatus) {
+        let elapsed = self.started.elapsed();
+        self.span.record("status", status_name(status));
+        self.span
+            .record("duration_ms", elapsed.as_secs_f64() * 1000.0);
+        let Some(exporter) = self.exporter else {
+            return;
+        };
+        let span = FinishedSpan {
+            trace_id: self.trace_id,
+            span_id: self.span_id,
+            operation: self.operation,
+            function: self.function,
+            args_bytes: self.args_bytes,
+            start_unix_nanos: unix_nanos(self.started_at),
+            end_unix_nanos: unix_nanos(self.started_at + elapsed),
+            status,
+        };
+        // Fails when the queue is full or the export was disabled.
+        let _ = exporter.try_send(span);
+    }
+}
+
+impl MobileConvexClient {
+    /// Starts a span for a call of `name` with `args`, if given.
+    pub(crate) fn begin_span(
+        &self,
+        operation: SpanOperation,
+        name: &str,
+        args: Option<&BTreeMap<String, Value>>,
+    ) -> CallSpan {
+        let exporter = self
+            .trace_exporter
+            .lock()
+            .as_ref()
+            .map(|exporter| exporter.spans.clone());
+        let span = tracing::info_span!(
+            "convex.call",
+            operation = operation.name(),
+            function = name,
+            args_bytes = Empty,
+            status = Empty,
+            duration_ms = Empty,
+        );
+        let measured = exporter.is_some() || !span.is_disabled();
+        let args_bytes = args
+            .filter(|_| measured)
+            .map(|args| value_to_json_string(Value::Object(args.clone())).len() as u64);
+        if let Some(bytes) = args_bytes {
+            span.record("args_bytes", bytes);
+        }
+        let ids = Uuid::new_v4().into_bytes();
+        CallSpan {
+            span,
+            exporter,
+            trace_id: Uuid::new_v4().into_bytes(),
+            span_id: ids[..8].try_into().expect("a UUID has 16 bytes"),
+            operation,
+            function: name.to_owned(),
+            args_bytes,
+            started_at: SystemTime::now(),
+            started: Instant::now(),
+        }
+    }
+
+    /// Exports the spans of all calls from now on, as described in the
+    /// [module docs](crate::spans). Replaces the previous export, if any.
+    #[frb(sync)]
+    pub fn enable_trace_export(&self, options: TraceExportOptions) -> Result<(), ClientError> {
+        self.ensure_open()?;
+        if !options.endpoint.starts_with("https://") && !options.endpoint.starts_with("http://") {
+            let endpoint = &options.endpoint;
+            return Err(anyhow!("unsupported trace export endpoint `{endpoint}`").into());
+        }
+        if options.service_name.is_empty() {
+            return Err(anyhow!("the trace export needs a service name").into());
+        }
+        *self.trace_exporter.lock() = Some(TraceExporter::start(&self.rt, options));
+        Ok(())
+    }
+
+    /// Stops exporting spans once the queued ones were sent.
+    #[frb(sync)]
+    pub fn disable_trace_export(&self) {
+        self.trace_exporter.lock().take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn finished_spans_are_posted_as_otlp_json() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}/v1/traces", listener.local_addr().unwrap());
+        let (spans, queued) = mpsc::channel(8);
+        let options = TraceExportOptions {
+            endpoint,
+            headers: HashMap::from([("x-api-key".to_owned(), "secret".to_owned())]),
+            service_name: "chat".into(),
+        };
+        let export = tokio::spawn(run_export(options, queued));
+        spans
+            .send(FinishedSpan {
+                trace_id: [1; 16],
+                span_id: [2; 8],
+                operation: SpanOperation::Query,
+                function: "messages:list".into(),
+                args_bytes: Some(12),
+                start_unix_nanos: 1_000,
+                end_unix_nanos: 3_000,
+                status: AuditStatus::ServerError,
+            })
+            .await
+            .unwrap();
+        drop(spans);
+
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut received = Vec::new();
+        let mut buffer = [0u8; 4096];
+        let head_end = loop {
+            let read = stream.read(&mut buffer).await.unwrap();
+            received.extend_from_slice(&buffer[..read]);
+            if let Some(end) = received.windows(4).position(|w| w == b"\r\n\r\n") {
+                break end + 4;
+            }
+        };
+        let head = String::from_utf8_lossy(&received[..head_end]).to_string();
+        let length: usize = head
+            .lines()
+            .filter_map(|line| line.split_once(": "))
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+            .map(|(_, length)| length)
+            .unwrap()
+            .parse()
+            .unwrap();
+        while received.len() < head_end + length {
+            let read = stream.read(&mut buffer).await.unwrap();
+            received.extend_from_slice(&buffer[..read]);
+        }
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}")
+            .await
+            .unwrap();
+        drop(stream);
+        export.await.unwrap();
+
+        assert!(head.starts_with("POST /v1/traces HTTP/1.1\r\n"));
+        assert!(head.contains("x-api-key: secret\r\n"));
+        let body: JsonValue = serde_json::from_slice(&received[head_end..]).unwrap();
+        let resource = &body["resourceSpans"][0];
+        assert_eq!(
+            resource["resource"]["attributes"][0]["value"]["stringValue"],
+            "chat"
+        );
+        let span = &resource["scopeSpans"][0]["spans"][0];
+        assert_eq!(span["traceId"], "01".repeat(16));
+        assert_eq!(span["spanId"], "02".repeat(8));
+        assert_eq!(span["name"], "query messages:list");
+        assert_eq!(span["endTimeUnixNano"], "3000");
+        assert_eq!(span["status"]["code"], 2);
+    }
+}
@@ -0,0 +1,176 @@
+//! Per-deployment, per-identity storage partitions.
+//!
+//! All on-disk client state lives below
+//! `<storage_root>/<deployment>/<identity>/`, where both components are
+//! hashes of the deployment URL and the auth token's subject (or `anonymous`).
+//! Switching environments or accounts therefore never exposes another
+//! tenant's data, and [`MobileConvexClient::clear_user_data`] can wipe exactly
+//! the current identity's partition. The audit log is kept outside the
+//! partitions in its own configured directory, since it must survive
+//! account switches.
+//!
+//! Clients created with [`crate::options::ClientOptions::incognito`] have
+//! neither: they keep all state in memory and never write to disk.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use flutter_rust_bridge::frb;
+use sha2::{Digest, Sha256};
+
+use crate::{ClientError, MobileConvexClient};
+
+const ANONYMOUS: &str = "anonymous";
+
+/// Storage root scoped to one deployment.
+#[derive(Clone)]
+pub(crate) struct ScopedStorage {
+    deployment_dir: PathBuf,
+}
+
+/// Short, filesystem-safe, stable name for an arbitrary string.
+fn partition_name(prefix: &str, value: &str) -> String {
+    let digest = hex::encode(Sha256::digest(value.as_bytes()));
+    format!("{prefix}-{}", &digest[..16])
+}
+
+impl ScopedStorage {
+    pub(crate) fn new(root: impl AsRef<Path>, deployment_url: &str) -> Self {
+        let url = deployment_url.trim_end_matches('/');
+        ScopedStorage {
+            deployment_dir: root.as_ref().join(partition_name("deployment", url)),
+        }
+    }
+
+    /// Directory for the given identity; not created until used.
+    pub(crate) fn partition(&self, identity: Option<&str>) -> PathBuf {
+        match identity {
+            Some(identity) => self.deployment_dir.join(partition_name("user", identity)),
+            None => self.deployment_dir.join(ANONYMOUS),
+        }
+    }
+
+    /// Path of a file shared by all identities of the deployment.
+    pub(crate) fn deployment_file(&self, name: &str) -> PathBuf {
+        self.deployment_dir.join(name)
+    }
+
+    /// Removes everything stored for the given identity.
+    pub(crate) fn clear(&self, identity: Option<&str>) -> io::Result<()> {
+        match fs::remove_dir_all(self.partition(identity)) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl MobileConvexClient {
+    pub(crate) fn scoped_storage(&self) -> Result<&ScopedStorage, ClientError> {
+        self.storage.as_ref().ok_or_else(|| {
+            let msg = if self.options.incognito {
+                "Incognito clients have no storage"
+            } else {
+                "No storage root configured"
+            };
+            ClientError::internal(msg)
+        })
+    }
+
+    /// Deletes the on-disk data of `identity`; incognito clients have none.
+    pub(crate) fn clear_identity_data(&self, identity: Option<&str>) -> Result<(), ClientError> {
+        if self.options.incognito {
+            return Ok(());
+        }
+        self.scoped_storage()?
+            .clear(identity)
+            .map_err(anyhow::Error::from)?;
+        Ok(())
+    }
+
+    /// Returns whether the client keeps all state in memory.
+    #[frb(sync)]
+    pub fn is_incognito(&self) -> bool {
+        self.options.incognito
+    }
+
+    /// Returns the storage directory of the current deployment and identity,
+    /// creating it if needed. Apps can keep their own per-user files there.
+    #[frb]
+    pub async fn storage_directory(&self) -> Result<String, ClientError> {
+        let identity = self.auth_identity.lock().clone();
+        let dir = self.scoped_storage()?.partition(identity.as_deref());
+        fs::create_dir_all(&dir).map_err(anyhow::Error::from)?;
+        Ok(dir.to_string_lossy().into_owned())
+    }
+
+    /// Deletes all on-disk data of the current identity on this deployment.
+    /// Data of other identities and deployments is left untouched.
+    #[frb]
+    pub async fn clear_user_data(&self) -> Result<(), ClientError> {
+        let identity = self.auth_identity.lock().clone();
+        self.clear_identity_data(identity.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partitions_are_isolated_by_deployment_and_identity() {
+        let prod = ScopedStorage::new("/data", "https://prod.convex.cloud");
+        let dev = ScopedStorage::new("/data", "https://dev.convex.cloud/");
+        assert_ne!(prod.partition(None), dev.partition(None));
+        assert_ne!(prod.partition(Some("alice")), prod.partition(Some("bob")));
+        assert_ne!(prod.partition(Some("alice")), prod.partition(None));
+        assert_eq!(
+            prod.partition(Some("alice")),
+            ScopedStorage::new("/data", "https://prod.convex.cloud/").partition(Some("alice"))
+        );
+        assert!(prod.partition(Some("../../etc")).starts_with("/data"));
+    }
+
+    #[test]
+    fn clear_only_removes_the_identity_partition() {
+        let root = tempfile::tempdir().unwrap();
+        let storage = ScopedStorage::new(root.path(), "https://prod.convex.cloud");
+        for identity in [Some("alice"), Some("bob"), None] {
+            let dir = storage.partition(identity);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("cache"), b"data").unwrap();
+        }
+
+        storage.clear(Some("alice")).unwrap();
+        assert!(!storage.partition(Some("alice")).exists());
+        assert!(storage.partition(Some("bob")).join("cache").exists());
+        assert!(storage.partition(None).join("cache").exists());
+        storage.clear(Some("alice")).unwrap();
+    }
+
+    #[test]
+    fn incognito_clients_never_touch_the_disk() {
+        let root = tempfile::tempdir().unwrap();
+        let options = crate::options::ClientOptions {
+            incognito: true,
+            storage_root: Some(root.path().to_string_lossy().into_owned()),
+            audit_log: Some(crate::audit::AuditLogOptions {
+                directory: root.path().join("audit").to_string_lossy().into_owned(),
+                max_file_bytes: 1024,
+                max_files: 1,
+            }),
+            ..Default::default()
+        };
+        let client = MobileConvexClient::new_with_options(
+            "https://prod.convex.cloud".into(),
+            "test".into(),
+            options,
+        );
+        assert!(client.is_incognito());
+        assert!(client.rt.block_on(client.storage_directory()).is_err());
+        assert!(client.rt.block_on(client.export_audit_log()).is_err());
+        client.rt.block_on(client.clear_user_data()).unwrap();
+        assert_eq!(fs::read_dir(root.path()).unwrap().count(), 0);
+    }
+}
@@ -0,0 +1,185 @@
+//! Encryption at rest of the files in storage partitions.
+//!
+//! Health and finance apps must not keep server data in plaintext in app
+//! storage. [`MobileConvexClient::set_storage_key`] takes a 32-byte key held
+//! by the app, e.g. in the Keychain or Keystore, and from then on the
+//! mutations queued in the offline outbox (see [`crate::outbox`]) and the
+//! persisted subscription results (see [`crate::persisted_results`]) are
+//! written encrypted with AES-256-GCM. Plaintext written before is still
+//! read and is encrypted when next written.
+//!
+//! Until the key is set, encrypted files cannot be read: persisted results
+//! are loaded once it is, and queued mutations are replayed then. With
+//! [`crate::options::ClientOptions::require_storage_key`], nothing is
+//! written until the key is set, so plaintext never reaches the disk.
+
+use std::{
+    fs,
+    io::{self, Write},
+    path::Path,
+    sync::Arc,
+};
+
+use anyhow::anyhow;
+use flutter_rust_bridge::frb;
+use parking_lot::RwLock;
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    rand::{SecureRandom, SystemRandom},
+};
+
+use crate::{ClientError, MobileConvexClient};
+
+/// Header of encrypted files, followed by the nonce and the ciphertext.
+const ENCRYPTED_HEADER: &[u8] = b"convex-flutter:aes-256-gcm:v1\n";
+
+fn locked() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::PermissionDenied,
+        "The storage key has not been set",
+    )
+}
+
+/// Returns whether `error` means the file cannot be accessed before the
+/// storage key is set.
+pub(crate) fn is_locked(error: &io::Error) -> bool {
+    error.kind() == io::ErrorKind::PermissionDenied
+}
+
+/// Encrypts and decrypts files once the app has set the storage key.
+#[derive(Clone)]
+pub(crate) struct StorageCipher {
+    key: Arc<RwLock<Option<Arc<LessSafeKey>>>>,
+    required: bool, // Whether plaintext must never be written
+    random: SystemRandom,
+}
+
+impl StorageCipher {
+    pub(crate) fn new(required: bool) -> Self {
+        StorageCipher {
+            key: Arc::default(),
+            required,
+            random: SystemRandom::new(),
+        }
+    }
+
+    fn set_key(&self, key: &[u8]) -> anyhow::Result<()> {
+        let key = UnboundKey::new(&AES_256_GCM, key)
+            .map_err(|_| anyhow!("The storage key must be 32 bytes long"))?;
+        *self.key.write() = Some(Arc::new(LessSafeKey::new(key)));
+        Ok(())
+    }
+
+    /// Encrypts `contents` if the key is set, e.g. for a database row.
+    pub(crate) fn encrypt(&self, mut contents: Vec<u8>) -> io::Result<Vec<u8>> {
+        let key = self.key.read().clone();
+        let Some(key) = key else {
+            return if self.required {
+                Err(locked())
+            } else {
+                Ok(contents)
+            };
+        };
+        let mut nonce = [0; NONCE_LEN];
+        self.random
+            .fill(&mut nonce)
+            .map_err(|_| io::Error::other("No randomness available for a nonce"))?;
+        key.seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut contents,
+        )
+        .map_err(|_| io::Error::other("Encryption failed"))?;
+        let mut encrypted = ENCRYPTED_HEADER.to_vec();
+        encrypted.extend_from_slice(&nonce);
+        encrypted.append(&mut contents);
+        Ok(encrypted)
+    }
+
+    /// Decrypts what [`StorageCipher::encrypt`] returned, or plaintext.
+    pub(crate) fn decrypt(&self, contents: Vec<u8>) -> io::Result<Vec<u8>> {
+        let Some(sealed) = contents.strip_prefix(ENCRYPTED_HEADER) else {
+            return Ok(contents);
+        };
+        let key = self.key.read().clone().ok_or_else(locked)?;
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "Decryption failed");
+        if sealed.len() < NONCE_LEN {
+            return Err(invalid());
+        }
+        let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| invalid())?;
+        let mut ciphertext = ciphertext.to_vec();
+        let plaintext = key
+            .open_in_place(nonce, Aad::empty(), &mut ciphertext)
+            .map_err(|_| invalid())?;
+        Ok(plaintext.to_vec())
+    }
+
+    /// Reads and decrypts `file`.
+    pub(crate) fn read(&self, file: &Path) -> io::Result<Vec<u8>> {
+        self.decrypt(fs::read(file)?)
+    }
+
+    /// Encrypts `contents` and replaces `file` with them atomically, so a
+    /// crash or power loss never leaves half a file.
+    pub(crate) fn write(&self, file: &Path, contents: Vec<u8>) -> io::Result<()> {
+        let contents = self.encrypt(contents)?;
+        if let Some(dir) = file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let mut temporary = file.as_os_str().to_owned();
+        temporary.push(".tmp");
+        let mut written = fs::File::create(&temporary)?;
+        written.write_all(&contents)?;
+        written.sync_all()?;
+        fs::rename(temporary, file)?;
+        // Persists the rename; directories cannot be opened on Windows.
+        #[cfg(unix)]
+        if let Some(dir) = file.parent() {
+            fs::File::open(dir)?.sync_all()?;
+        }
+        Ok(())
+    }
+}
+
+impl MobileConvexClient {
+    /// Sets the 32-byte key encrypting the client's files, as described in
+    /// the [module docs](crate::storage_encryption), and replays mutations
+    /// queued in an outbox that could not be read without it.
+    #[frb(sync)]
+    pub fn set_storage_key(&self, key: Vec<u8>) -> Result<(), ClientError> {
+        self.storage_cipher.set_key(&key)?;
+        self.outbox.wake_replay();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn files_are_unreadable_without_the_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("partition").join("outbox.json");
+        let cipher = StorageCipher::new(false);
+        cipher.write(&file, b"[1]".to_vec()).unwrap();
+        assert_eq!(fs::read(&file).unwrap(), b"[1]");
+
+        cipher.set_key(&[7; 32]).unwrap();
+        assert_eq!(cipher.read(&file).unwrap(), b"[1]");
+        cipher.write(&file, b"[2]".to_vec()).unwrap();
+        assert!(fs::read(&file).unwrap().starts_with(ENCRYPTED_HEADER));
+        assert_eq!(cipher.read(&file).unwrap(), b"[2]");
+
+        let restarted = StorageCipher::new(true);
+        assert!(is_locked(&restarted.read(&file).unwrap_err()));
+        assert!(is_locked(
+            &restarted.write(&file, b"[3]".to_vec()).unwrap_err()
+        ));
+        restarted.set_key(&[8; 32]).unwrap();
+        let wrong_key = restarted.read(&file).unwrap_err();
+        assert_eq!(wrong_key.kind(), io::ErrorKind::InvalidData);
+        assert!(restarted.set_key(&[1; 16]).is_err());
+    }
+}
@@ -0,0 +1,529 @@
+//! Convex-protocol-compatible stub server for integration tests.
+//!
+//! Built with the `stub-server` feature, it speaks enough of the WebSocket
+//! sync protocol for the client to connect to `http://127.0.0.1:<port>` and
+//! run queries, subscriptions, mutations, actions and authentication against
+//! a [`StubScript`] instead of a deployment, so the Flutter integration tests
+//! can run end to end without network access. The `convex_stub_server`
+//! binary serves a script file and prints the URL to connect to.
+//!
+//! Scripts are JSON with values in the Convex JSON encoding:
+//!
+//! ```json
+//! {
+//!   "functions": [
+//!     {"name": "messages:list", "result": []},
+//!     {"name": "messages:send", "args": {"body": "hi"}, "result": null,
+//!      "updates": {"messages:list": [{"body": "hi"}]}},
+//!     {"name": "messages:remove", "error": "Not allowed"}
+//!   ],
+//!   "disconnects": [2],
+//!   "auth": {"acceptedTokens": ["valid"], "expireAfterMs": 5000}
+//! }
+//! ```
+//!
+//! Query results replaced by `updates` are shared by all connections and
+//! pushed to their subscribers.
+
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use anyhow::Context;
+use convex_sync_types::{
+    types::SerializedArgs, AuthenticationToken, ClientMessage, ErrorPayload, LogLinesMessage,
+    QueryId, QuerySetModification, ServerMessage, StateModification, StateVersion, Timestamp,
+    UdfPath,
+};
+use flutter_rust_bridge::frb;
+use futures::{future, pin_mut, select_biased, FutureExt, SinkExt, StreamExt};
+use log::{debug, info, warn};
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde_json::Value as JsonValue;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::broadcast,
+    time::Instant,
+};
+use tokio_tungstenite::tungstenite::Message;
+
+/// Scripted behavior of a stub server.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb(ignore)]
+pub struct StubScript {
+    /// Responses to queries, mutations and actions. The first function
+    /// matching the name and arguments is used.
+    pub functions: Vec<StubFunction>,
+    /// For each connection in order, the number of client messages after
+    /// which it is closed without answering the last one. Connections past
+    /// the end of the list stay open.
+    pub disconnects: Vec<u32>,
+    pub auth: StubAuth,
+}
+
+impl StubScript {
+    pub fn from_json(json: &str) -> anyhow::Result<Self> {
+        serde_json::from_str(json).context("Invalid stub script")
+    }
+}
+
+/// Scripted response of a function.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb(ignore)]
+pub struct StubFunction {
+    /// Function path, e.g. `messages:list`.
+    pub name: String,
+    /// Arguments to match. Any arguments match when `None`.
+    pub args: Option<JsonValue>,
+    /// Value returned unless `error` is set.
+    pub result: JsonValue,
+    /// Error message returned instead of `result`.
+    pub error: Option<String>,
+    /// Data of a `ConvexError` carrying `error` as its message.
+    pub error_data: Option<JsonValue>,
+    /// Query results replaced once the function ran, by query name.
+    pub updates: BTreeMap<String, JsonValue>,
+}
+
+/// Scripted authentication.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+#[frb(ignore)]
+pub struct StubAuth {
+    /// Tokens accepted. Any token is accepted when empty.
+    pub accepted_tokens: Vec<String>,
+    /// Time after which an accepted token is reported expired, making the
+    /// client reconnect and authenticate again.
+    pub expire_after_ms: Option<u64>,
+}
+
+/// State of one client connection.
+struct Connection {
+    version: StateVersion,
+    queries: BTreeMap<QueryId, (String, JsonValue)>,
+    auth_expiry: Option<Instant>,
+}
+
+impl Connection {
+    fn new() -> Self {
+        Connection {
+            version: StateVersion::initial(),
+            queries: BTreeMap::new(),
+            auth_expiry: None,
+        }
+    }
+
+    fn transition(
+        &mut self,
+        end_version: StateVersion,
+        modifications: Vec<StateModification<JsonValue>>,
+    ) -> ServerMessage<JsonValue> {
+        let start_version = std::mem::replace(&mut self.version, end_version);
+        ServerMessage::Transition {
+            start_version,
+            end_version,
+            modifications,
+            client_clock_skew: None,
+            server_ts: None,
+        }
+    }
+}
+
+struct StubServer {
+    script: StubScript,
+    results: Mutex<BTreeMap<String, JsonValue>>, // Query results replaced by `updates`
+    ts: AtomicU64,
+    connections: AtomicUsize,
+    changes: broadcast::Sender<()>,
+}
+
+fn no_logs() -> LogLinesMessage {
+    LogLinesMessage(Vec::new())
+}
+
+/// Returns `name` in the canonical form the client sends, e.g.
+/// `messages.js:list` for `messages:list`.
+fn canonical(name: &str) -> String {
+    match name.parse::<UdfPath>() {
+        Ok(path) => path.canonicalize().to_string(),
+        Err(_) => name.to_owned(),
+    }
+}
+
+/// Returns the arguments object of a call.
+fn call_args(args: &SerializedArgs) -> JsonValue {
+    serde_json::from_str::<Vec<JsonValue>>(args.0.get())
+        .ok()
+        .and_then(|args| args.into_iter().next())
+        .unwrap_or_else(|| JsonValue::Object(Default::default()))
+}
+
+impl StubServer {
+    fn new(script: StubScript) -> Self {
+        StubServer {
+            script,
+            results: Mutex::new(BTreeMap::new()),
+            ts: AtomicU64::new(0),
+            connections: AtomicUsize::new(0),
+            changes: broadcast::channel(16).0,
+        }
+    }
+
+    fn next_ts(&self) -> Timestamp {
+        let ts = self.ts.fetch_add(1, Ordering::SeqCst) + 1;
+        Timestamp::try_from(ts).unwrap_or(Timestamp::MAX)
+    }
+
+    fn function(&self, name: &str, args: &JsonValue) -> Option<&StubFunction> {
+        self.script.functions.iter().find(|function| {
+            canonical(&function.name) == name && function.args.as_ref().is_none_or(|a| a == args)
+        })
+    }
+
+    fn query(&self, name: &str, args: &JsonValue) -> Result<JsonValue, ErrorPayload<JsonValue>> {
+        if let Some(result) = self.results.lock().get(name) {
+            return Ok(result.clone());
+        }
+        let function = self
+            .function(name, args)
+            .ok_or_else(|| ErrorPayload::Message(format!("Could not find function {name}")))?;
+        match (&function.error, &function.error_data) {
+            (Some(message), Some(data)) => Err(ErrorPayload::ErrorData {
+                message: message.clone(),
+                data: data.clone(),
+            }),
+            (Some(message), None) => Err(ErrorPayload::Message(message.clone())),
+            _ => Ok(function.result.clone()),
+        }
+    }
+
+    /// Runs a mutation or action, applying its updates if it succeeded.
+    fn call(&self, name: &str, args: &JsonValue) -> Result<JsonValue, ErrorPayload<JsonValue>> {
+        let result = self.query(name, args);
+        let updates = self
+            .function(name, args)
+            .map(|function| &function.updates)
+            .filter(|updates| !updates.is_empty());
+        if let (Ok(_), Some(updates)) = (&result, updates) {
+            let updates = updates
+                .iter()
+                .map(|(query, result)| (canonical(query), result.clone()));
+            self.results.lock().extend(updates);
+            let _ = self.changes.send(());
+        }
+        result
+    }
+
+    fn query_modification(
+        &self,
+        query_id: QueryId,
+        name: &str,
+        args: &JsonValue,
+    ) -> StateModification<JsonValue> {
+        match self.query(name, args) {
+            Ok(value) => StateModification::QueryUpdated {
+                query_id,
+                value,
+                log_lines: no_logs(),
+                journal: None,
+            },
+            Err(payload) => StateModification::QueryFailed {
+                query_id,
+                error_message: payload.get_message().to_owned(),
+                log_lines: no_logs(),
+                journal: None,
+                error_data: payload.get_data().cloned(),
+            },
+        }
+    }
+
+    /// Sends the current result of every query of `connection`.
+    fn refresh(&self, connection: &mut Connection, ts: Timestamp) -> ServerMessage<JsonValue> {
+        let modifications = connection
+            .queries
+            .iter()
+            .map(|(&query_id, (name, args))| self.query_modification(query_id, name, args))
+            .collect();
+        let end_version = StateVersion {
+            ts,
+            ..connection.version
+        };
+        connection.transition(end_version, modifications)
+    }
+
+    fn accepts(&self, token: &AuthenticationToken) -> bool {
+        let accepted = &self.script.auth.accepted_tokens;
+        match token {
+            AuthenticationToken::User(token) => accepted.is_empty() || accepted.contains(token),
+            AuthenticationToken::Admin(..) | AuthenticationToken::None => true,
+        }
+    }
+
+    /// Returns the messages answering `message`.
+    fn respond(
+        &self,
+        connection: &mut Connection,
+        message: ClientMessage,
+    ) -> Vec<ServerMessage<JsonValue>> {
+        match message {
+            ClientMessage::ModifyQuerySet {
+                base_version,
+                new_version,
+                modifications,
+            } => {
+                if base_version != connection.version.query_set {
+                    return vec![ServerMessage::FatalError {
+                        error_message: format!("Unexpected query set version {base_version}"),
+                    }];
+                }
+                let modifications = modifications
+                    .into_iter()
+                    .map(|modification| match modification {
+                        QuerySetModification::Add(query) => {
+                            let name = query.udf_path.canonicalize().to_string();
+                            let args = call_args(&query.args);
+                            let modification =
+                                self.query_modification(query.query_id, &name, &args);
+                            connection.queries.insert(query.query_id, (name, args));
+                            modification
+                        }
+                        QuerySetModification::Remove { query_id } => {
+                            connection.queries.remove(&query_id);
+                            StateModification::QueryRemoved { query_id }
+                        }
+                    })
+                    .collect();
+                let end_version = StateVersion {
+                    query_set: new_version,
+                    ts: self.next_ts(),
+                    ..connection.version
+                };
+                vec![connection.transition(end_version, modifications)]
+            }
+            ClientMessage::Mutation {
+                request_id,
+                udf_path,
+                args,
+                ..
+            } => {
+                let result = self.call(&udf_path.canonicalize().to_string(), &call_args(&args));
+                let ts = self.next_ts();
+                // The client completes a mutation once it saw a transition
+                // at or past its timestamp.
+                vec![
+                    ServerMessage::MutationResponse {
+                        request_id,
+                        result,
+                        ts: Some(ts),
+                        log_lines: no_logs(),
+                    },
+                    self.refresh(connection, ts),
+                ]
+            }
+            ClientMessage::Action {
+                request_id,
+                udf_path,
+                args,
+                ..
+            } => vec![ServerMessage::ActionResponse {
+                request_id,
+                result: self.call(&udf_path.canonicalize().to_string(), &call_args(&args)),
+                log_lines: no_logs(),
+            }],
+            ClientMessage::Authenticate {
+                base_version,
+                token,
+            } => {
+                if base_version != connection.version.identity || !self.accepts(&token) {
+                    return vec![ServerMessage::AuthError {
+                        error_message: "Invalid authentication token".into(),
+                        base_version: Some(base_version),
+                        auth_update_attempted: Some(true),
+                    }];
+                }
+                connection.auth_expiry = match (&token, self.script.auth.expire_after_ms) {
+                    (AuthenticationToken::User(_), Some(ms)) => {
+                        Some(Instant::now() + Duration::from_millis(ms))
+                    }
+                    _ => None,
+                };
+                let end_version = StateVersion {
+                    identity: base_version + 1,
+                    ts: self.next_ts(),
+                    ..connection.version
+                };
+                vec![connection.transition(end_version, Vec::new())]
+            }
+            ClientMessage::Connect { .. } | ClientMessage::Event(_) => Vec::new(),
+        }
+    }
+
+    async fn handle(&self, stream: TcpStream) -> anyhow::Result<()> {
+        let index = self.connections.fetch_add(1, Ordering::SeqCst);
+        let close_after = self.script.disconnects.get(index).copied();
+        let mut socket = tokio_tungstenite::accept_async(stream).await?;
+        let mut changes = self.changes.subscribe();
+        let mut connection = Connection::new();
+        let mut received = 0;
+        loop {
+            let auth_expiry = connection.auth_expiry;
+            let expiry_fut = async move {
+                match auth_expiry {
+                    Some(at) => tokio::time::sleep_until(at).await,
+                    None => future::pending().await,
+                }
+            }
+            .fuse();
+            pin_mut!(expiry_fut);
+            let replies = select_biased! {
+                message = socket.next() => {
+                    let text = match message {
+                        Some(Ok(Message::Text(text))) => text,
+                        Some(Ok(Message::Close(_))) | None => return Ok(()),
+                        Some(Ok(_)) => continue,
+                        Some(Err(e)) => return Err(e.into()),
+                    };
+                    received += 1;
+                    if close_after == Some(received) {
+                        debug!("Closing stub connection {index} as scripted");
+                        return Ok(socket.close(None).await?);
+                    }
+                    let json: JsonValue = serde_json::from_str(text.as_str())?;
+                    self.respond(&mut connection, ClientMessage::try_from(json)?)
+                },
+                changed = changes.recv().fuse() => match changed {
+                    Ok(()) | Err(broadcast::error::RecvError::Lagged(_)) => {
+                        vec![self.refresh(&mut connection, self.next_ts())]
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return Ok(()),
+                },
+                _ = expiry_fut => {
+                    connection.auth_expiry = None;
+                    vec![ServerMessage::AuthError {
+                        error_message: "Authentication token expired".into(),
+                        base_version: Some(connection.version.identity),
+                        auth_update_attempted: Some(false),
+                    }]
+                },
+            };
+            for reply in replies {
+                let text = JsonValue::from(reply).to_string();
+                socket.send(Message::Text(text.into())).await?;
+            }
+        }
+    }
+}
+
+/// Serves `script` to every client connecting to `listener`, until the
+/// runtime stops.
+#[frb(ignore)]
+pub async fn serve(listener: TcpListener, script: StubScript) {
+    let server = Arc::new(StubServer::new(script));
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(accepted) => accepted,
+            Err(e) => {
+                warn!("Stub server failed to accept a connection: {e}");
+                continue;
+            }
+        };
+        let server = server.clone();
+        tokio::spawn(async move {
+            if let Err(e) = server.handle(stream).await {
+                warn!("Stub connection from {peer} failed: {e:#}");
+            }
+        });
+    }
+}
+
+/// Serves `script` on an unused local port in the background, returning
+/// the deployment URL to connect to.
+#[frb(ignore)]
+pub async fn start(script: StubScript) -> std::io::Result<String> {
+    let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+    let url = format!("http://{}", listener.local_addr()?);
+    info!("Stub server listening on {url}");
+    tokio::spawn(serve(listener, script));
+    Ok(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use convex::{ConvexClient, FunctionResult, Value};
+    use serde_json::json;
+
+    use super::*;
+
+    fn script() -> StubScript {
+        StubScript::from_json(
+            &json!({
+                "functions": [
+                    {"name": "messages:list", "result": []},
+                    {
+                        "name": "messages:send",
+                        "result": null,
+                        "updates": {"messages:list": [{"body": "hi"}]},
+                    },
+                ],
+                "auth": {"acceptedTokens": ["valid"]},
+            })
+            .to_string(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn only_accepted_tokens_authenticate() {
+        let server = StubServer::new(script());
+        let mut connection = Connection::new();
+        let authenticate = |token: &str| ClientMessage::Authenticate {
+            base_version: 0,
+            token: AuthenticationToken::User(token.into()),
+        };
+
+        let replies = server.respond(&mut connection, authenticate("expired"));
+        assert!(matches!(replies[..], [ServerMessage::AuthError { .. }]));
+        let replies = server.respond(&mut connection, authenticate("valid"));
+        assert!(matches!(replies[..], [ServerMessage::Transition { .. }]));
+        assert_eq!(connection.version.identity, 1);
+    }
+
+    #[tokio::test]
+    async fn clients_see_scripted_results_across_reconnects() {
+        let mut script = script();
+        // Drop the first subscription request; the client resends it.
+        script.disconnects = vec![2];
+        let url = start(script).await.unwrap();
+        let mut client = ConvexClient::new(&url).await.unwrap();
+
+        let mut subscription = client
+            .subscribe("messages:list", BTreeMap::new())
+            .await
+            .unwrap();
+        assert_eq!(
+            subscription.next().await,
+            Some(FunctionResult::Value(Value::Array(vec![])))
+        );
+        assert_eq!(
+            client
+                .mutation("messages:send", BTreeMap::new())
+                .await
+                .unwrap(),
+            FunctionResult::Value(Value::Null)
+        );
+        let Some(FunctionResult::Value(Value::Array(messages))) = subscription.next().await else {
+            panic!("expected the updated list");
+        };
+        assert_eq!(messages.len(), 1);
+    }
+}
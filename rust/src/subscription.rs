@@ -0,0 +1,341 @@
+//! Subscription lifecycle: the options of a subscription, the events
+//! delivered to Dart and the state machine that derives them from query
+//! results and connection changes.
+
+use std::{collections::HashMap, sync::Arc};
+
+use convex::FunctionResult;
+use flutter_rust_bridge::frb;
+use tokio::sync::mpsc;
+
+use crate::{
+    backpressure::{validate_backpressure, Backpressure},
+    convex_value::ConvexValue,
+    distinct::DistinctSubscriber,
+    options::Int64Encoding,
+    projection::{validate_projection, ProjectingSubscriber},
+    resubscribe::SubscriptionPriority,
+    value::value_to_json_string_as,
+    ClientError, QuerySubscriber,
+};
+
+/// Options of [`crate::MobileConvexClient::subscribe`] and
+/// [`crate::MobileConvexClient::subscribe_with_events`]. They can be
+/// combined freely; the defaults deliver every result as it arrives.
+#[derive(Debug, Clone, Default)]
+#[frb]
+pub struct SubscribeOptions {
+    /// Order in which the subscription is re-established after a reconnect.
+    /// Only takes effect when
+    /// [`crate::options::ClientOptions::max_concurrent_resubscribes`] is set.
+    pub priority: SubscriptionPriority,
+    /// Arguments with structured values, added to the JSON arguments.
+    pub value_args: HashMap<String, ConvexValue>,
+    /// Skips updates identical to the one delivered before, as described in
+    /// the [distinct docs](crate::distinct).
+    pub distinct: bool,
+    /// JSON pointers selecting the fields to deliver, as described in the
+    /// [projection docs](crate::projection). Updates leaving them unchanged
+    /// are skipped.
+    pub projection: Option<Vec<String>>,
+    /// How updates arriving faster than `on_update` completes are
+    /// delivered. Not supported by `subscribe_with_events`, whose callback
+    /// is always awaited before the next event.
+    pub backpressure: Option<Backpressure>,
+}
+
+impl SubscribeOptions {
+    /// Fails if the options cannot be applied.
+    pub(crate) fn validate(&self) -> Result<(), ClientError> {
+        if let Some(projection) = &self.projection {
+            validate_projection(projection)?;
+        }
+        if let Some(backpressure) = self.backpressure {
+            validate_backpressure(backpressure)?;
+        }
+        Ok(())
+    }
+
+    /// Wraps `delivery` in the subscribers filtering what reaches it.
+    pub(crate) fn wrap(&self, delivery: Arc<dyn QuerySubscriber>) -> Arc<dyn QuerySubscriber> {
+        let mut subscriber = delivery;
+        if self.distinct {
+            subscriber = Arc::new(DistinctSubscriber::new(subscriber));
+        }
+        if let Some(projection) = &self.projection {
+            subscriber = Arc::new(ProjectingSubscriber::new(subscriber, projection.clone()));
+        }
+        subscriber
+    }
+}
+
+/// Why a subscription stopped producing events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub enum SubscriptionCloseReason {
+    /// The subscription was cancelled through its handle.
+    Cancelled,
+    /// The underlying subscription stream ended; no more updates will arrive.
+    StreamEnded,
+}
+
+/// Current state of a subscription, as reported by its handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum SubscriptionState {
+    /// Updates are still being delivered.
+    Active,
+    /// The subscription stream ended; no more updates will arrive.
+    Ended,
+    /// The subscription was cancelled.
+    Cancelled,
+}
+
+/// A single lifecycle signal of a subscription, delivered in order.
+#[derive(Debug, Clone, PartialEq)]
+#[frb]
+pub enum SubscriptionEvent {
+    /// A new result value, serialized as JSON.
+    Update { value: String },
+    /// The query failed; `data` holds the serialized ConvexError payload, if any.
+    Error {
+        message: String,
+        data: Option<String>,
+    },
+    /// The connection was restored and the subscription is live again.
+    Resubscribed,
+    /// Delivery is suspended by [`crate::MobileConvexClient::pause`]; no
+    /// updates until resumed.
+    Paused,
+    /// [`crate::MobileConvexClient::resume`] re-establishes the subscription
+    /// after a pause.
+    Resumed,
+    /// The connection was lost; the last delivered value may be outdated.
+    Stale,
+    /// The subscription has ended. This is always the last event.
+    Closed { reason: SubscriptionCloseReason },
+}
+
+/// Hands what a subscription delivers to a task producing
+/// [`SubscriptionEvent`]s, as updates, errors and `Closed` once it ended.
+#[frb(ignore)]
+pub(crate) struct EventForwarder(pub(crate) mpsc::UnboundedSender<SubscriptionEvent>);
+
+impl QuerySubscriber for EventForwarder {
+    fn on_update(&self, value: String) {
+        // Fails only once the task is gone.
+        let _ = self.0.send(SubscriptionEvent::Update { value });
+    }
+
+    fn on_error(&self, message: String, data: Option<String>) {
+        let _ = self.0.send(SubscriptionEvent::Error { message, data });
+    }
+
+    fn on_done(&self) {
+        let _ = self.0.send(SubscriptionEvent::Closed {
+            reason: SubscriptionCloseReason::StreamEnded,
+        });
+    }
+}
+
+/// Tracks a single subscription and turns its inputs into [`SubscriptionEvent`]s.
+///
+/// Once closed, every further input is ignored so `Closed` stays the last event.
+#[derive(Debug)]
+pub(crate) struct SubscriptionStateMachine {
+    connected: bool,
+    paused: bool,
+    closed: bool,
+    int64_encoding: Int64Encoding,
+}
+
+impl SubscriptionStateMachine {
+    pub(crate) fn new(connected: bool) -> Self {
+        SubscriptionStateMachine {
+            connected,
+            paused: false,
+            closed: false,
+            int64_encoding: Int64Encoding::default(),
+        }
+    }
+
+    /// Sets how `Int64`s are written into update values.
+    pub(crate) fn with_int64_encoding(mut self, int64_encoding: Int64Encoding) -> Self {
+        self.int64_encoding = int64_encoding;
+        self
+    }
+
+    /// Handles a new result from the server.
+    pub(crate) fn on_result(&mut self, result: FunctionResult) -> Option<SubscriptionEvent> {
+        if self.closed {
+            return None;
+        }
+        Some(match result {
+            FunctionResult::Value(value) => SubscriptionEvent::Update {
+                value: value_to_json_string_as(value, self.int64_encoding),
+            },
+            FunctionResult::ErrorMessage(message) => SubscriptionEvent::Error {
+                message,
+                data: None,
+            },
+            FunctionResult::ConvexError(error) => SubscriptionEvent::Error {
+                message: error.message,
+                data: Some(value_to_json_string_as(error.data, self.int64_encoding)),
+            },
+        })
+    }
+
+    /// Handles an update or error delivered by a subscriber, or the end of
+    /// its stream.
+    pub(crate) fn on_delivery(&mut self, event: SubscriptionEvent) -> Option<SubscriptionEvent> {
+        match event {
+            SubscriptionEvent::Closed { reason } => self.close(reason),
+            _ if self.closed => None,
+            event => Some(event),
+        }
+    }
+
+    /// Handles the client pausing or resuming; repeated states produce no
+    /// event.
+    pub(crate) fn on_pause_change(&mut self, paused: bool) -> Option<SubscriptionEvent> {
+        if self.closed || paused == self.paused {
+            return None;
+        }
+        self.paused = paused;
+        Some(if paused {
+            SubscriptionEvent::Paused
+        } else {
+            SubscriptionEvent::Resumed
+        })
+    }
+
+    /// Handles a WebSocket state change; repeated states, and those while
+    /// paused, produce no event.
+    pub(crate) fn on_connection_change(&mut self, connected: bool) -> Option<SubscriptionEvent> {
+        if self.closed || connected == self.connected {
+            return None;
+        }
+        self.connected = connected;
+        if self.paused {
+            return None;
+        }
+        Some(if connected {
+            SubscriptionEvent::Resubscribed
+        } else {
+            SubscriptionEvent::Stale
+        })
+    }
+
+    /// Closes the subscription; only the first call produces an event.
+    pub(crate) fn close(&mut self, reason: SubscriptionCloseReason) -> Option<SubscriptionEvent> {
+        if self.closed {
+            return None;
+        }
+        self.closed = true;
+        Some(SubscriptionEvent::Closed { reason })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use convex::{ConvexError, Value};
+
+    use super::*;
+
+    #[test]
+    fn results_map_to_update_and_error_events() {
+        let mut machine = SubscriptionStateMachine::new(true);
+        assert_eq!(
+            machine.on_result(FunctionResult::Value(Value::Boolean(true))),
+            Some(SubscriptionEvent::Update {
+                value: "true".into()
+            })
+        );
+        assert_eq!(
+            machine.on_result(FunctionResult::ErrorMessage("boom".into())),
+            Some(SubscriptionEvent::Error {
+                message: "boom".into(),
+                data: None
+            })
+        );
+        assert_eq!(
+            machine.on_result(FunctionResult::ConvexError(ConvexError {
+                message: "app".into(),
+                data: Value::Null,
+            })),
+            Some(SubscriptionEvent::Error {
+                message: "app".into(),
+                data: Some("null".into())
+            })
+        );
+    }
+
+    #[test]
+    fn connection_changes_emit_stale_then_resubscribed() {
+        let mut machine = SubscriptionStateMachine::new(true);
+        assert_eq!(machine.on_connection_change(true), None);
+        assert_eq!(
+            machine.on_connection_change(false),
+            Some(SubscriptionEvent::Stale)
+        );
+        assert_eq!(machine.on_connection_change(false), None);
+        assert_eq!(
+            machine.on_connection_change(true),
+            Some(SubscriptionEvent::Resubscribed)
+        );
+    }
+
+    #[test]
+    fn starting_disconnected_reports_resubscribed_on_connect() {
+        let mut machine = SubscriptionStateMachine::new(false);
+        assert_eq!(
+            machine.on_connection_change(true),
+            Some(SubscriptionEvent::Resubscribed)
+        );
+    }
+
+    #[test]
+    fn pauses_hide_the_connection_closing() {
+        let mut machine = SubscriptionStateMachine::new(true);
+        assert_eq!(
+            machine.on_pause_change(true),
+            Some(SubscriptionEvent::Paused)
+        );
+        assert_eq!(machine.on_pause_change(true), None);
+        assert_eq!(machine.on_connection_change(false), None);
+        assert_eq!(
+            machine.on_pause_change(false),
+            Some(SubscriptionEvent::Resumed)
+        );
+        assert_eq!(
+            machine.on_connection_change(true),
+            Some(SubscriptionEvent::Resubscribed)
+        );
+        let update = SubscriptionEvent::Update { value: "1".into() };
+        assert_eq!(machine.on_delivery(update.clone()), Some(update.clone()));
+        assert_eq!(
+            machine.on_delivery(SubscriptionEvent::Closed {
+                reason: SubscriptionCloseReason::StreamEnded
+            }),
+            Some(SubscriptionEvent::Closed {
+                reason: SubscriptionCloseReason::StreamEnded
+            })
+        );
+        assert_eq!(machine.on_delivery(update), None);
+    }
+
+    #[test]
+    fn closed_is_terminal() {
+        let mut machine = SubscriptionStateMachine::new(true);
+        assert_eq!(
+            machine.close(SubscriptionCloseReason::Cancelled),
+            Some(SubscriptionEvent::Closed {
+                reason: SubscriptionCloseReason::Cancelled
+            })
+        );
+        assert_eq!(machine.close(SubscriptionCloseReason::StreamEnded), None);
+        assert_eq!(machine.on_result(FunctionResult::Value(Value::Null)), None);
+        assert_eq!(machine.on_connection_change(false), None);
+        assert_eq!(machine.on_pause_change(true), None);
+    }
+}
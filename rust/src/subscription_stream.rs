@@ -0,0 +1,125 @@
+//! Subscriptions delivered as a Dart `Stream`.
+//!
+//! [`MobileConvexClient::subscribe`] calls a Dart closure per update, each
+//! call awaited on its own task. [`MobileConvexClient::subscribe_stream`]
+//! adds the results to a flutter_rust_bridge `StreamSink` instead, which
+//! Dart sees as a native `Stream<String>`: updates arrive in the order they
+//! were received, and failures arrive as stream errors carrying the same
+//! [`ClientError`] the other calls throw.
+//!
+//! Adding to the sink does not wait for Dart to take the event, so the
+//! stream applies no backpressure: a slow or paused listener buffers every
+//! update on the Dart side. Listeners that cannot keep up should use
+//! [`MobileConvexClient::subscribe`] with a
+//! [`crate::backpressure::Backpressure`] policy instead, whose callbacks
+//! are awaited.
+
+use std::{collections::HashMap, sync::Arc};
+
+use flutter_rust_bridge::frb;
+
+use crate::{
+    frb_generated::StreamSink, resubscribe::SubscriptionPriority, ClientError, MobileConvexClient,
+    QuerySubscriber, SubscriptionHandle,
+};
+
+/// Where a [`SinkSubscriber`] adds its events.
+#[frb(ignore)]
+trait EventSink: Send + Sync {
+    fn send_value(&self, value: String);
+    fn send_error(&self, error: ClientError);
+}
+
+impl EventSink for StreamSink<String> {
+    fn send_value(&self, value: String) {
+        // Fails only once Dart stopped listening.
+        let _ = self.add(value);
+    }
+
+    fn send_error(&self, error: ClientError) {
+        let _ = self.add_error(error);
+    }
+}
+
+/// Adds the events of a subscription to a sink as they arrive.
+struct SinkSubscriber<S> {
+    sink: S,
+}
+
+impl<S: EventSink> QuerySubscriber for SinkSubscriber<S> {
+    fn on_update(&self, value: String) {
+        self.sink.send_value(value);
+    }
+
+    fn on_error(&self, message: String, value: Option<String>) {
+        self.sink.send_error(match value {
+            Some(data) => ClientError::convex_json(message, &data),
+            None => ClientError::server(message),
+        });
+    }
+}
+
+impl MobileConvexClient {
+    /// Subscribes to a Convex query, adding every result, serialized as
+    /// JSON, to `sink`, as described in the
+    /// [module docs](crate::subscription_stream). Updates are not dropped
+    /// or coalesced for a slow listener. Cancelling the Dart stream
+    /// subscription does not cancel the query; the returned handle does.
+    #[frb]
+    pub async fn subscribe_stream(
+        &self,
+        name: String,
+        args: HashMap<String, String>,
+        sink: StreamSink<String>,
+    ) -> Result<SubscriptionHandle, ClientError> {
+        let args = self.parse_args(args)?;
+        let subscriber = Arc::new(SinkSubscriber { sink });
+        self.internal_subscribe(name, args, subscriber, SubscriptionPriority::Normal)
+            .await
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use parking_lot::Mutex;
+
+    use crate::convex_value::ConvexValue;
+
+    use super::*;
+
+    #[derive(Default)]
+    struct RecordingSink(Mutex<Vec<Result<String, ClientError>>>);
+
+    impl EventSink for Arc<RecordingSink> {
+        fn send_value(&self, value: String) {
+            self.0.lock().push(Ok(value));
+        }
+
+        fn send_error(&self, error: ClientError) {
+            self.0.lock().push(Err(error));
+        }
+    }
+
+    #[test]
+    fn events_are_added_in_order() {
+        let sink = Arc::new(RecordingSink::default());
+        let subscriber = SinkSubscriber { sink: sink.clone() };
+        subscriber.on_update("1".into());
+        subscriber.on_error("Uncaught".into(), None);
+        subscriber.on_error("Conflict".into(), Some("{\"code\":1}".into()));
+        subscriber.on_update("2".into());
+
+        let events = sink.0.lock();
+        assert_eq!(events[0].as_ref().unwrap(), "1");
+        assert!(
+            matches!(&events[1], Err(ClientError::ServerError { msg, .. }) if msg == "Uncaught")
+        );
+        let code = ConvexValue::Object([("code".to_owned(), ConvexValue::Float64(1.0))].into());
+        assert!(
+            matches!(&events[2], Err(ClientError::ConvexError { message, data, .. })
+            if message == "Conflict" && *data == code)
+        );
+        assert_eq!(events[3].as_ref().unwrap(), "2");
+    }
+}
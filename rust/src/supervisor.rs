@@ -0,0 +1,238 @@
+//! Supervision of the Dart isolate receiving callbacks.
+//!
+//! When the isolate holding the callbacks dies without closing the client,
+//! e.g. after a crash in a background isolate, Rust keeps serving
+//! subscriptions whose updates reach nobody, and the UI of a new isolate
+//! never updates. [`MobileConvexClient::attach_dart_keepalive`] registers a
+//! cheap Dart callback that is pinged periodically. After
+//! [`DartKeepaliveOptions::max_missed_pings`] pings in a row go unanswered,
+//! the isolate is considered gone: subscriptions are paused as with
+//! [`MobileConvexClient::pause`] and reported as orphaned by
+//! [`MobileConvexClient::dart_liveness`]. The next isolate calling
+//! `attach_dart_keepalive` takes over and resumes them; subscriptions that
+//! were already paused by the app stay paused.
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+use flutter_rust_bridge::{frb, DartFnFuture};
+use log::{debug, warn};
+use parking_lot::Mutex;
+
+use crate::{lifecycle::Lifecycle, presence::now_millis, ClientError, MobileConvexClient};
+
+/// How the Dart keepalive callback is pinged.
+#[derive(Debug, Clone)]
+#[frb]
+pub struct DartKeepaliveOptions {
+    /// Time between pings.
+    pub interval_ms: u64,
+    /// Time a ping may take before it counts as missed.
+    pub timeout_ms: u64,
+    /// Missed pings in a row after which the isolate is considered gone.
+    pub max_missed_pings: u32,
+}
+
+impl Default for DartKeepaliveOptions {
+    fn default() -> Self {
+        DartKeepaliveOptions {
+            interval_ms: 5_000,
+            timeout_ms: 2_000,
+            max_missed_pings: 3,
+        }
+    }
+}
+
+/// Whether the attached Dart isolate responds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[frb]
+pub enum DartLivenessState {
+    /// No keepalive callback was attached.
+    Unsupervised,
+    /// The last ping was answered.
+    Alive,
+    /// Recent pings went unanswered.
+    Unresponsive,
+    /// The isolate is considered gone and subscriptions are paused until
+    /// another isolate attaches.
+    Orphaned,
+}
+
+/// Liveness of the Dart isolate, as returned by
+/// [`MobileConvexClient::dart_liveness`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[frb]
+pub struct DartLiveness {
+    pub state: DartLivenessState,
+    /// Unix time in milliseconds of the last answered ping, if any.
+    pub last_response_ms: Option<i64>,
+    /// Unanswered pings in a row.
+    pub missed_pings: u32,
+    /// Subscriptions paused when the isolate was considered gone.
+    pub orphaned_subscriptions: u32,
+}
+
+struct SupervisorState {
+    liveness: DartLiveness,
+    // Whether the orphaned subscriptions were paused by the supervisor
+    paused_orphans: bool,
+}
+
+/// Pings the attached keepalive callback; attaching again supersedes it.
+#[frb(ignore)]
+pub(crate) struct DartSupervisor {
+    generation: AtomicU64,
+    state: Mutex<SupervisorState>,
+}
+
+impl Default for DartSupervisor {
+    fn default() -> Self {
+        DartSupervisor {
+            generation: AtomicU64::new(0),
+            state: Mutex::new(SupervisorState {
+                liveness: DartLiveness {
+                    state: DartLivenessState::Unsupervised,
+                    last_response_ms: None,
+                    missed_pings: 0,
+                    orphaned_subscriptions: 0,
+                },
+                paused_orphans: false,
+            }),
+        }
+    }
+}
+
+impl DartSupervisor {
+    /// Starts supervising a newly attached isolate, returning its
+    /// generation and whether the subscriptions orphaned by the previous
+    /// one need resuming.
+    fn attach(&self) -> (u64, bool) {
+        let generation = self.generation.fetch_add(1, Ordering::SeqCst) + 1;
+        let mut state = self.state.lock();
+        let resume = std::mem::take(&mut state.paused_orphans);
+        state.liveness = DartLiveness {
+            state: DartLivenessState::Alive,
+            last_response_ms: Some(now_millis()),
+            missed_pings: 0,
+            orphaned_subscriptions: 0,
+        };
+        (generation, resume)
+    }
+
+    fn is_current(&self, generation: u64) -> bool {
+        self.generation.load(Ordering::SeqCst) == generation
+    }
+
+    fn answered(&self) {
+        let mut state = self.state.lock();
+        state.liveness.state = DartLivenessState::Alive;
+        state.liveness.last_response_ms = Some(now_millis());
+        state.liveness.missed_pings = 0;
+    }
+
+    /// Records a missed ping, returning whether the isolate is now
+    /// considered gone.
+    fn missed(&self, max_missed_pings: u32) -> bool {
+        let mut state = self.state.lock();
+        state.liveness.missed_pings += 1;
+        state.liveness.state = DartLivenessState::Unresponsive;
+        state.liveness.missed_pings >= max_missed_pings.max(1)
+    }
+
+    /// Pauses the subscriptions of a gone isolate.
+    fn orphan(&self, lifecycle: &Lifecycle) {
+        let mut state = self.state.lock();
+        state.paused_orphans = !lifecycle.is_paused();
+        state.liveness.state = DartLivenessState::Orphaned;
+        state.liveness.orphaned_subscriptions = lifecycle.pause() as u32;
+        warn!(
+            "Dart stopped answering keepalive pings; paused {} subscriptions",
+            state.liveness.orphaned_subscriptions
+        );
+    }
+}
+
+impl MobileConvexClient {
+    /// Supervises the calling isolate through `keepalive`, which should
+    /// return right away, as described in the
+    /// [module docs](crate::supervisor). Replaces the callback of a
+    /// previously attached isolate and resumes the subscriptions paused
+    /// because it stopped responding.
+    #[frb]
+    pub async fn attach_dart_keepalive(
+        &self,
+        options: DartKeepaliveOptions,
+        keepalive: impl Fn() -> DartFnFuture<()> + Send + Sync + 'static,
+    ) -> Result<(), ClientError> {
+        self.ensure_open()?;
+        let (generation, resume) = self.dart_supervisor.attach();
+        if resume {
+            debug!("Resuming subscriptions orphaned by a previous isolate");
+            self.resume().await?;
+        }
+        let supervisor = self.dart_supervisor.clone();
+        let lifecycle = self.lifecycle.clone();
+        let interval = Duration::from_millis(options.interval_ms.max(1));
+        let timeout = Duration::from_millis(options.timeout_ms);
+        self.rt.spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                if !supervisor.is_current(generation) {
+                    break;
+                }
+                let answered = tokio::time::timeout(timeout, keepalive()).await.is_ok();
+                if !supervisor.is_current(generation) {
+                    break;
+                }
+                if answered {
+                    supervisor.answered();
+                } else if supervisor.missed(options.max_missed_pings) {
+                    supervisor.orphan(&lifecycle);
+                    break;
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Returns whether the attached Dart isolate responds to keepalive
+    /// pings.
+    #[frb(sync)]
+    pub fn dart_liveness(&self) -> DartLiveness {
+        self.dart_supervisor.state.lock().liveness.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn silent_isolates_orphan_subscriptions_until_the_next_attaches() {
+        let supervisor = DartSupervisor::default();
+        let lifecycle = Lifecycle::default();
+        let (first, resume) = supervisor.attach();
+        assert!(!resume);
+        assert!(!supervisor.missed(2));
+        supervisor.answered();
+        assert!(!supervisor.missed(2));
+        assert!(supervisor.missed(2));
+        supervisor.orphan(&lifecycle);
+        assert!(lifecycle.is_paused());
+        assert_eq!(
+            supervisor.state.lock().liveness.state,
+            DartLivenessState::Orphaned
+        );
+
+        let (second, resume) = supervisor.attach();
+        assert!(resume);
+        assert!(!supervisor.is_current(first));
+        assert!(supervisor.is_current(second));
+        assert_eq!(
+            supervisor.state.lock().liveness.state,
+            DartLivenessState::Alive
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! Timeouts for single calls.
+//!
+//! A query, mutation or action waits for the backend as long as it takes,
+//! which leaves the Dart future pending forever when the backend hangs.
+//! Given a `timeout_ms`, [`crate::MobileConvexClient::query`],
+//! [`crate::MobileConvexClient::mutation`] and
+//! [`crate::MobileConvexClient::action`] give up after that many
+//! milliseconds with [`ClientError::Timeout`]. So do their `_with_values`
+//! and `_cancellable` variants, [`crate::MobileConvexClient::query_batch`]
+//! and [`crate::MobileConvexClient::query_streamed`]. As with cancellation,
+//! a mutation or action that timed out may still run on the backend.
+
+use std::{future::Future, time::Duration};
+
+use crate::ClientError;
+
+/// Awaits `call` for at most `timeout_ms` milliseconds, or as long as it
+/// takes if `None`.
+pub(crate) async fn with_timeout<T>(
+    timeout_ms: Option<u64>,
+    call: impl Future<Output = Result<T, ClientError>>,
+) -> Result<T, ClientError> {
+    let Some(timeout_ms) = timeout_ms else {
+        return call.await;
+    };
+    tokio::time::timeout(Duration::from_millis(timeout_ms), call)
+        .await
+        .unwrap_or(Err(ClientError::timeout(timeout_ms)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn hung_calls_time_out() {
+        let hung = std::future::pending::<Result<(), ClientError>>();
+        match with_timeout(Some(20), hung).await {
+            Err(ClientError::Timeout { timeout_ms, .. }) => assert_eq!(timeout_ms, 20),
+            other => panic!("expected a timeout, got {other:?}"),
+        }
+        let quick = async { Ok(3) };
+        assert_eq!(with_timeout(Some(1_000), quick).await.unwrap(), 3);
+        assert_eq!(with_timeout(None, async { Ok(4) }).await.unwrap(), 4);
+    }
+}
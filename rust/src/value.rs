@@ -0,0 +1,205 @@
+//! Conversions between Convex values and the JSON text that crosses the FFI.
+//!
+//! Convex values are encoded with Convex's JSON format, so `Int64` and `Bytes`
+//! survive as `{"$integer": ...}` and `{"$bytes": ...}` objects.
+
+use convex::Value;
+
+use crate::options::Int64Encoding;
+
+/// Serializes a Convex value to its JSON text form.
+pub(crate) fn value_to_json_string(value: Value) -> String {
+    serde_json::Value::from(value).to_string()
+}
+
+/// Serializes a Convex value with `Int64`s written as configured.
+pub(crate) fn value_to_json_string_as(value: Value, int64: Int64Encoding) -> String {
+    match int64 {
+        Int64Encoding::Tagged => value_to_json_string(value),
+        _ => value_to_json(value, int64).to_string(),
+    }
+}
+
+fn value_to_json(value: Value, int64: Int64Encoding) -> serde_json::Value {
+    match value {
+        Value::Int64(i) if int64 == Int64Encoding::Number => i.into(),
+        Value::Int64(i) if int64 == Int64Encoding::String => i.to_string().into(),
+        Value::Array(items) => items
+            .into_iter()
+            .map(|item| value_to_json(item, int64))
+            .collect(),
+        Value::Object(fields) => fields
+            .into_iter()
+            .map(|(name, value)| (name, value_to_json(value, int64)))
+            .collect(),
+        other => other.into(),
+    }
+}
+
+/// Converts parsed JSON into a Convex value.
+pub(crate) fn json_to_value(json: serde_json::Value) -> anyhow::Result<Value> {
+    Value::try_from(json)
+}
+
+/// Converts JSON written with `Int64`s encoded as `int64` back into a Convex
+/// value. Integers written as numbers become `Int64`s again, since `Float64`s
+/// are always written with a fraction or exponent; integers written as
+/// strings cannot be told apart from strings and stay strings.
+pub(crate) fn json_to_value_as(
+    json: serde_json::Value,
+    int64: Int64Encoding,
+) -> anyhow::Result<Value> {
+    match int64 {
+        Int64Encoding::Number => json_to_value(tag_integers(json)),
+        _ => json_to_value(json),
+    }
+}
+
+fn tag_integers(json: serde_json::Value) -> serde_json::Value {
+    match json {
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::Int64(i).into(),
+            None => serde_json::Value::Number(n),
+        },
+        serde_json::Value::Array(items) => items.into_iter().map(tag_integers).collect(),
+        serde_json::Value::Object(fields) => fields
+            .into_iter()
+            .map(|(name, value)| (name, tag_integers(value)))
+            .collect(),
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn json_str_to_value(json: &str) -> anyhow::Result<Value> {
+        json_to_value(serde_json::from_str(json)?)
+    }
+
+    #[test]
+    fn int64_survives_round_trip() {
+        let json = value_to_json_string(Value::Int64(i64::MAX));
+        assert!(json.contains("$integer"));
+        assert_eq!(json_str_to_value(&json).unwrap(), Value::Int64(i64::MAX));
+    }
+
+    #[test]
+    fn bytes_survive_round_trip() {
+        let json = value_to_json_string(Value::Bytes(vec![0, 1, 254, 255]));
+        assert!(json.contains("$bytes"));
+        assert_eq!(
+            json_str_to_value(&json).unwrap(),
+            Value::Bytes(vec![0, 1, 254, 255])
+        );
+    }
+
+    #[test]
+    fn int64_encoding_is_configurable() {
+        let value = Value::Object(BTreeMap::from([(
+            "ids".to_string(),
+            Value::Array(vec![Value::Int64(i64::MAX), Value::Float64(1.5)]),
+        )]));
+        assert_eq!(
+            value_to_json_string_as(value.clone(), Int64Encoding::Number),
+            r#"{"ids":[9223372036854775807,1.5]}"#
+        );
+        assert_eq!(
+            value_to_json_string_as(value.clone(), Int64Encoding::String),
+            r#"{"ids":["9223372036854775807",1.5]}"#
+        );
+        assert_eq!(
+            value_to_json_string_as(value.clone(), Int64Encoding::Tagged),
+            value_to_json_string(value)
+        );
+    }
+
+    #[test]
+    fn number_encoded_int64s_convert_back() {
+        let value = Value::Object(BTreeMap::from([(
+            "ids".to_string(),
+            Value::Array(vec![
+                Value::Int64(i64::MIN),
+                Value::Float64(2.0),
+                Value::Float64(1e300),
+                Value::Bytes(vec![1]),
+            ]),
+        )]));
+        let json = value_to_json_string_as(value.clone(), Int64Encoding::Number);
+        let parsed = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            json_to_value_as(parsed, Int64Encoding::Number).unwrap(),
+            value
+        );
+        let tagged = serde_json::from_str(&value_to_json_string(value.clone())).unwrap();
+        assert_eq!(
+            json_to_value_as(tagged, Int64Encoding::Tagged).unwrap(),
+            value
+        );
+    }
+
+    #[test]
+    fn plain_json_numbers_become_float64() {
+        assert_eq!(json_str_to_value("42").unwrap(), Value::Float64(42.0));
+    }
+
+    #[test]
+    fn special_floats_are_tagged() {
+        let json = value_to_json_string(Value::Float64(f64::INFINITY));
+        assert!(json.contains("$float"));
+        assert_eq!(
+            json_str_to_value(&json).unwrap(),
+            Value::Float64(f64::INFINITY)
+        );
+    }
+
+    #[test]
+    fn invalid_json_is_an_error() {
+        assert!(json_str_to_value("{not json").is_err());
+        assert!(json_str_to_value("").is_err());
+    }
+
+    #[test]
+    fn unsupported_tagged_objects_are_errors() {
+        assert!(json_str_to_value(r#"{"$set": []}"#).is_err());
+        assert!(json_str_to_value(r#"{"$integer": "nope"}"#).is_err());
+    }
+
+    /// Strategy for arbitrary Convex values, mirroring the upstream crate's.
+    fn arb_value() -> impl Strategy<Value = Value> {
+        let leaf = prop_oneof![
+            Just(Value::Null),
+            any::<i64>().prop_map(Value::Int64),
+            any::<f64>().prop_map(Value::Float64),
+            any::<bool>().prop_map(Value::Boolean),
+            any::<String>().prop_map(Value::String),
+            any::<Vec<u8>>().prop_map(Value::Bytes),
+        ];
+        leaf.prop_recursive(4, 32, 8, |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 0..8).prop_map(Value::Array),
+                // Field names starting with `$` are reserved for tagged values.
+                prop::collection::btree_map("[a-zA-Z_][a-zA-Z0-9_]{0,8}", inner, 0..8)
+                    .prop_map(|fields: BTreeMap<String, Value>| Value::Object(fields)),
+            ]
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn json_round_trip_preserves_values(value in arb_value()) {
+            let json = value_to_json_string(value.clone());
+            prop_assert_eq!(json_str_to_value(&json).unwrap(), value);
+        }
+
+        #[test]
+        fn arbitrary_text_never_panics(text in ".*") {
+            let _ = json_str_to_value(&text);
+        }
+    }
+}
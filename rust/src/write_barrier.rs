@@ -0,0 +1,111 @@
+//! Waiting for the client's own writes.
+//!
+//! A flow like "create an item, then open its detail page" races the
+//! subscription update carrying the new item.
+//! [`MobileConvexClient::await_pending_writes`] resolves once every mutation
+//! sent before the call has completed. The Convex client completes a
+//! mutation only after the query results at or after its commit timestamp
+//! arrived, and those results are handed to the active subscriptions at the
+//! same time, so they reflect the writes by then. Mutations still queued as
+//! deferred mutations or in the offline outbox are not waited for.
+
+use std::{
+    collections::BTreeSet,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+};
+
+use flutter_rust_bridge::frb;
+use tokio::sync::watch;
+
+use crate::{timeout::with_timeout, ClientError, MobileConvexClient};
+
+/// The sequence numbers of the mutations in flight.
+#[derive(Default)]
+#[frb(ignore)]
+pub(crate) struct WriteBarrier {
+    next: AtomicU64,
+    in_flight: watch::Sender<BTreeSet<u64>>,
+}
+
+/// Counts a mutation as in flight until dropped.
+pub(crate) struct PendingWrite {
+    barrier: Arc<WriteBarrier>,
+    sequence: u64,
+}
+
+impl Drop for PendingWrite {
+    fn drop(&mut self) {
+        self.barrier.in_flight.send_modify(|in_flight| {
+            in_flight.remove(&self.sequence);
+        });
+    }
+}
+
+impl WriteBarrier {
+    pub(crate) fn write_started(self: &Arc<Self>) -> PendingWrite {
+        let mut pending = None;
+        // Numbered under the lock, so a barrier sees every earlier write.
+        self.in_flight.send_modify(|in_flight| {
+            let sequence = self.next.fetch_add(1, Ordering::SeqCst);
+            in_flight.insert(sequence);
+            pending = Some(sequence);
+        });
+        PendingWrite {
+            barrier: self.clone(),
+            sequence: pending.expect("the sequence number was just assigned"),
+        }
+    }
+
+    /// Waits until the writes started before the call have completed.
+    async fn settled(&self) {
+        let mut in_flight = self.in_flight.subscribe();
+        let before = self.next.load(Ordering::SeqCst);
+        // The sender lives as long as `self`.
+        let _ = in_flight
+            .wait_for(|in_flight| in_flight.first().is_none_or(|first| *first >= before))
+            .await;
+    }
+}
+
+impl MobileConvexClient {
+    /// Resolves once all mutations sent so far are reflected in the active
+    /// subscriptions, as described in the [module docs](crate::write_barrier).
+    /// Fails with [`ClientError::Timeout`] after `timeout_ms` milliseconds,
+    /// if given.
+    #[frb]
+    pub async fn await_pending_writes(&self, timeout_ms: Option<u64>) -> Result<(), ClientError> {
+        self.ensure_open()?;
+        with_timeout(timeout_ms, async {
+            self.write_barrier.settled().await;
+            Ok(())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn later_writes_are_not_waited_for() {
+        let barrier = Arc::new(WriteBarrier::default());
+        let first = barrier.write_started();
+        let second = barrier.write_started();
+        let settled = barrier.settled();
+        tokio::pin!(settled);
+        assert!((&mut settled).now_or_never().is_none());
+
+        let later = barrier.write_started();
+        drop(second);
+        assert!((&mut settled).now_or_never().is_none());
+        drop(first);
+        assert!(settled.now_or_never().is_some());
+        drop(later);
+    }
+}